@@ -0,0 +1,211 @@
+use saba_core::error::Error;
+use saba_core::http::transport::Connection;
+use saba_core::http::transport::Transport;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+use std::time::SystemTime;
+
+// 標準ライブラリのTCP接続でサーバーと通信するHttpClient
+pub type HttpClient = saba_core::http::client::HttpClient<StdTransport>;
+
+// 応答のないサーバーを待ち続けないよう、受信に時間がかかりすぎた場合は失敗にする
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// std::net::TcpStreamとOSの名前解決を使うTransport
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdTransport;
+
+impl Transport for StdTransport {
+    type Connection = StdConnection;
+
+    fn connect(&self, host: &str, port: u16) -> Result<StdConnection, Error> {
+        // URLのホストはIPv6アドレスを"[::1]"のように角括弧で囲むので、外してから解決する
+        let host = host
+            .strip_prefix('[')
+            .and_then(|h| h.strip_suffix(']'))
+            .unwrap_or(host);
+        let addrs: Vec<_> = match (host, port).to_socket_addrs() {
+            Ok(addrs) => addrs.collect(),
+            Err(e) => {
+                return Err(Error::Network(format!(
+                    "Failed to find IP addresses: {}",
+                    e
+                )))
+            }
+        };
+        if addrs.is_empty() {
+            return Err(Error::Network("Failed to find IP addresses".to_string()));
+        }
+
+        // 解決できたアドレスを順に試す
+        let stream = match TcpStream::connect(&addrs[..]) {
+            Ok(stream) => stream,
+            Err(e) => {
+                return Err(Error::Network(format!(
+                    "Failed to connect to TCP stream: {}",
+                    e
+                )))
+            }
+        };
+        if stream.set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+            return Err(Error::Network("Failed to configure TCP stream".to_string()));
+        }
+        Ok(StdConnection(stream))
+    }
+
+    fn now(&self) -> Option<u64> {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs())
+    }
+}
+
+pub struct StdConnection(TcpStream);
+
+impl Connection for StdConnection {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self.0
+            .write_all(buf)
+            .map_err(|e| Error::Network(format!("Failed to send a request to TCP stream: {}", e)))
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        self.0.read(buf).map_err(|e| {
+            Error::Network(format!(
+                "Failed to receive a request from TCP stream: {}",
+                e
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use saba_core::http::range::Download;
+    use saba_core::http::HttpRequest;
+    use saba_core::http::Method;
+    use saba_core::url::Url;
+    use std::net::TcpListener;
+    use std::thread;
+
+    // 127.0.0.1で待ち受け、接続ごとにリクエストを1つ読んでから、responsesを順に返すサーバー
+    // 受け取ったリクエストのリクエストラインとヘッダーを返す
+    fn serve(responses: Vec<Vec<u8>>) -> (u16, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+        serve_on(listener, responses)
+    }
+
+    fn serve_on(
+        listener: TcpListener,
+        responses: Vec<Vec<u8>>,
+    ) -> (u16, thread::JoinHandle<Vec<String>>) {
+        let port = listener.local_addr().expect("failed to get address").port();
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().expect("failed to accept");
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).expect("failed to read");
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                requests.push(String::from_utf8_lossy(&request).into_owned());
+                stream.write_all(&response).expect("failed to write");
+                // 接続を閉じて、次の接続を待つ
+            }
+            requests
+        });
+        (port, handle)
+    }
+
+    #[test]
+    fn test_fetch_from_local_server() {
+        let (port, server) = serve(vec![
+            b"HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello".to_vec(),
+        ]);
+        let client = HttpClient::new(StdTransport);
+        let page = Url::new(format!("http://127.0.0.1:{}/index.html", port))
+            .parse()
+            .expect("failed to parse url");
+        let (_, response) = client
+            .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+            .expect("failed to fetch");
+        assert_eq!(response.status_code(), 200);
+        assert_eq!(response.body(), b"hello".to_vec());
+
+        let requests = server.join().expect("server panicked");
+        assert!(requests[0].starts_with("GET /index.html HTTP/1.1\r\n"));
+        assert!(requests[0].contains(&format!("Host: 127.0.0.1:{}", port)));
+    }
+
+    #[test]
+    fn test_resume_from_local_server() {
+        // 1回目は途中で接続を閉じ、2回目は続きを返す
+        let (port, server) = serve(vec![
+            b"HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 10\r\n\r\n0123".to_vec(),
+            b"HTTP/1.1 206 Partial Content\r\nConnection: close\r\nContent-Range: bytes 4-9/10\r\nContent-Length: 6\r\n\r\n456789".to_vec(),
+        ]);
+        let client = HttpClient::new(StdTransport);
+        let url = Url::new(format!("http://127.0.0.1:{}/large.bin", port))
+            .parse()
+            .expect("failed to parse url");
+        let mut download = Download::new(url);
+        client
+            .download(&mut download, 2)
+            .expect("failed to download");
+        assert_eq!(download.body(), b"0123456789".to_vec());
+
+        let requests = server.join().expect("server panicked");
+        assert!(requests[1].contains("Range: bytes=4-"));
+        assert!(requests[1].contains("If-Range: \"v1\""));
+    }
+
+    #[test]
+    fn test_fetch_from_ipv6_server() {
+        // IPv6が使えない環境では試せない
+        let listener = match TcpListener::bind("[::1]:0") {
+            Ok(listener) => listener,
+            Err(_) => return,
+        };
+        let (port, server) = serve_on(
+            listener,
+            vec![b"HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 2\r\n\r\nv6".to_vec()],
+        );
+        let client = HttpClient::new(StdTransport);
+        let page = Url::new(format!("http://[::1]:{}/", port))
+            .parse()
+            .expect("failed to parse url");
+        let (_, response) = client
+            .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+            .expect("failed to fetch");
+        assert_eq!(response.body(), b"v6".to_vec());
+
+        let requests = server.join().expect("server panicked");
+        assert!(requests[0].contains(&format!("Host: [::1]:{}", port)));
+    }
+
+    #[test]
+    fn test_now() {
+        // 2020年1月1日より後
+        assert!(StdTransport.now().is_some_and(|now| now > 1_577_836_800));
+    }
+
+    #[test]
+    fn test_connect_error() {
+        // 待ち受けていないポート
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+        let port = listener.local_addr().expect("failed to get address").port();
+        drop(listener);
+        assert!(StdTransport.connect("127.0.0.1", port).is_err());
+        assert!(StdTransport.connect("invalid host name", 80).is_err());
+    }
+}
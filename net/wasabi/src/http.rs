@@ -1,23 +1,26 @@
 extern crate alloc;
 use alloc::format;
-use alloc::string::String;
 use alloc::string::ToString;
-use alloc::vec::Vec;
 use noli::net::lookup_host;
 use noli::net::SocketAddr;
 use noli::net::TcpStream;
 use saba_core::error::Error;
-use saba_core::http::HttpResponse;
+use saba_core::http::transport::Connection;
+use saba_core::http::transport::Transport;
 
-pub struct HttpClient {}
+// noliのTCP接続でサーバーと通信するHttpClient
+pub type HttpClient = saba_core::http::client::HttpClient<NoliTransport>;
 
-impl HttpClient {
-    pub fn new() -> Self {
-        Self {}
-    }
+/// noliのTCP接続とDNSの名前解決を使うTransport
+// 時計は使えないので、nowは既定のNoneを返す
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoliTransport;
+
+impl Transport for NoliTransport {
+    type Connection = NoliConnection;
 
-    pub fn get(&self, host: String, port: u16, path: String) -> Result<HttpResponse, Error> {
-        let ips = match lookup_host(&host) {
+    fn connect(&self, host: &str, port: u16) -> Result<NoliConnection, Error> {
+        let ips = match lookup_host(host) {
             Ok(ips) => ips,
             Err(e) => {
                 return Err(Error::Network(format!(
@@ -33,59 +36,39 @@ impl HttpClient {
 
         let socket_addr: SocketAddr = (ips[0], port).into();
 
-        let mut stream = match TcpStream::connect(socket_addr) {
-            Ok(stream) => stream,
-            Err(_) => {
-                return Err(Error::Network(
-                    "Failed to connect to TCP stream".to_string(),
-                ))
-            }
-        };
-
-        let mut request = String::from("GET /");
-        request.push_str(&path);
-        request.push_str(" HTTP/1.1\n");
+        match TcpStream::connect(socket_addr) {
+            Ok(stream) => Ok(NoliConnection(stream)),
+            Err(_) => Err(Error::Network(
+                "Failed to connect to TCP stream".to_string(),
+            )),
+        }
+    }
+}
 
-        // ヘッダーを追加
-        request.push_str("Host: ");
-        request.push_str(&host);
-        request.push('\n');
-        request.push_str("Accept: text/html\n");
-        request.push_str("Connection: close\n");
-        request.push('\n');
+pub struct NoliConnection(TcpStream);
 
-        // リクエストを送信
-        let _bytes_written = match stream.write(request.as_bytes()) {
-            Ok(bytes) => bytes,
-            Err(_) => {
-                return Err(Error::Network(
-                    "Failed to send a request to TCP stream".to_string(),
-                ))
-            }
-        };
-        // レスポンスの受信
-        let mut received = Vec::new();
-        loop {
-            // httpレスポンスを格納するためのバッファ
-            let mut buf = [0u8; 4096];
-            let bytes_read = match stream.read(&mut buf) {
-                Ok(bytes) => bytes,
-                Err(_) => {
+impl Connection for NoliConnection {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let mut written = 0;
+        while written < buf.len() {
+            match self.0.write(&buf[written..]) {
+                Ok(0) | Err(_) => {
                     return Err(Error::Network(
-                        "Failed to receive a request from TCP stream".to_string(),
+                        "Failed to send a request to TCP stream".to_string(),
                     ))
                 }
-            };
-            // ストリームから読み込むバイトがなくなるまでroopを回す
-            if bytes_read == 0 {
-                break;
+                Ok(bytes) => written += bytes,
             }
-            received.extend_from_slice(&buf[..bytes_read]);
         }
+        Ok(())
+    }
 
-        match core::str::from_utf8(&received) {
-            Ok(response) => HttpResponse::new(response.to_string()),
-            Err(e) => Err(Error::Network(format!("Invalid received response: {}", e))),
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        match self.0.read(buf) {
+            Ok(bytes) => Ok(bytes),
+            Err(_) => Err(Error::Network(
+                "Failed to receive a request from TCP stream".to_string(),
+            )),
         }
     }
 }
@@ -0,0 +1,24 @@
+//! 任意のバイト列をレスポンスとして解析し、パニックしないことを確かめる
+//!
+//! cargo +nightly fuzz run http_response ../testdata/http_response
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use saba_core::http::framing::message_end;
+use saba_core::http::parser::ResponseParser;
+use saba_core::http::HttpResponse;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = HttpResponse::parse(data);
+    let _ = message_end(data);
+
+    // 受信したバイト列がどこで区切られていても同じように解析できる
+    let split = data.first().map_or(1, |b| *b as usize % 7 + 1);
+    let mut parser = ResponseParser::new();
+    for chunk in data.chunks(split) {
+        if parser.feed(chunk).is_err() {
+            return;
+        }
+    }
+    let _ = parser.finish();
+});
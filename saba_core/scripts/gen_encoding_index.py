@@ -0,0 +1,74 @@
+#!/usr/bin/env python3
+"""Generate saba_core/src/encoding/index.rs.
+
+Usage:
+    python3 gen_encoding_index.py > ../src/encoding/index.rs
+
+The tables follow the indexes of the Encoding Standard
+(https://encoding.spec.whatwg.org/#indexes):
+
+index jis0208
+    Built from Python's "cp932" codec, which is the Windows code page the
+    WHATWG index was taken from, including the NEC and IBM extensions.
+    Pointers 8836 to 10715 are the user-defined area and are handled by the
+    Shift_JIS decoder itself, so they are left empty.
+
+index jis0212
+    Built from the JIS X 0212 part of Python's "euc_jp" codec, with 0x2237
+    mapped to U+FF5E as in the WHATWG index.
+
+Each table is indexed by pointer and holds 0 where the pointer has no code
+point.
+"""
+
+JIS0208_LENGTH = 11104
+JIS0212_LENGTH = 94 * 94
+
+
+def jis0208():
+    table = [0] * JIS0208_LENGTH
+    for pointer in range(JIS0208_LENGTH):
+        if 8836 <= pointer <= 10715:
+            continue
+        lead = pointer // 188
+        trail = pointer % 188
+        sjis = bytes([lead + (0x81 if lead < 0x1F else 0xC1), trail + (0x40 if trail < 0x3F else 0x41)])
+        try:
+            decoded = sjis.decode("cp932")
+        except UnicodeDecodeError:
+            continue
+        table[pointer] = ord(decoded)
+    return table
+
+
+def jis0212():
+    table = [0] * JIS0212_LENGTH
+    for pointer in range(JIS0212_LENGTH):
+        euc = bytes([0x8F, 0xA1 + pointer // 94, 0xA1 + pointer % 94])
+        try:
+            decoded = euc.decode("euc_jp")
+        except UnicodeDecodeError:
+            continue
+        table[pointer] = ord(decoded)
+    table[(0x22 - 0x21) * 94 + (0x37 - 0x21)] = 0xFF5E
+    return table
+
+
+def print_table(comment, name, table):
+    print(comment)
+    print("pub static %s: [u16; %d] = [" % (name, len(table)))
+    for i in range(0, len(table), 12):
+        print("    " + " ".join("0x%04X," % c for c in table[i : i + 12]))
+    print("];")
+
+
+def main():
+    print("// This file is generated by scripts/gen_encoding_index.py. Do not edit it by hand.")
+    print()
+    print_table("/// https://encoding.spec.whatwg.org/#index-jis0208", "JIS0208", jis0208())
+    print()
+    print_table("/// https://encoding.spec.whatwg.org/#index-jis0212", "JIS0212", jis0212())
+
+
+if __name__ == "__main__":
+    main()
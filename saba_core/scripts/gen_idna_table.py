@@ -0,0 +1,152 @@
+#!/usr/bin/env python3
+"""Generate saba_core/src/url/idna_table.rs.
+
+Usage:
+    python3 gen_idna_table.py IdnaMappingTable.txt > ../src/url/idna_table.rs
+
+IdnaMappingTable.txt is the UTS #46 data file published at
+https://www.unicode.org/Public/idna/latest/IdnaMappingTable.txt
+
+The NFC normalization data (canonical combining classes, canonical
+decompositions and primary composites) comes from Python's `unicodedata`
+module, so run this with a Python whose `unicodedata.unidata_version`
+matches the version of IdnaMappingTable.txt.
+
+The statuses are resolved the way the URL Standard calls UTS #46
+(UseSTD3ASCIIRules=false, Transitional_Processing=false):
+disallowed_STD3_valid and deviation become valid, and
+disallowed_STD3_mapped becomes mapped.
+"""
+
+import sys
+import unicodedata
+
+HANGUL_S_BASE = 0xAC00
+HANGUL_S_COUNT = 11172
+
+
+def parse_mapping_table(path):
+    entries = []
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            code_points = fields[0].split("..")
+            start = int(code_points[0], 16)
+            end = int(code_points[-1], 16)
+            status = fields[1]
+            mapping = fields[2] if len(fields) > 2 else ""
+
+            if status in ("valid", "deviation", "disallowed_STD3_valid"):
+                entries.append((start, end, "Valid", None))
+            elif status == "ignored":
+                entries.append((start, end, "Ignored", None))
+            elif status in ("mapped", "disallowed_STD3_mapped"):
+                text = "".join(chr(int(c, 16)) for c in mapping.split())
+                entries.append((start, end, "Mapped", text))
+            else:
+                entries.append((start, end, "Disallowed", None))
+
+    # 同じ状態が続く範囲はまとめる
+    merged = []
+    for entry in entries:
+        if merged:
+            last = merged[-1]
+            if last[1] + 1 == entry[0] and last[2] == entry[2] and last[3] == entry[3]:
+                merged[-1] = (last[0], entry[1], last[2], last[3])
+                continue
+        merged.append(entry)
+    return merged
+
+
+def rust_str(text):
+    out = '"'
+    for c in text:
+        if 0x20 <= ord(c) < 0x7F and c not in '"\\':
+            out += c
+        else:
+            out += "\\u{%X}" % ord(c)
+    return out + '"'
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__)
+
+    entries = parse_mapping_table(sys.argv[1])
+
+    combining_classes = []
+    decompositions = []
+    compositions = []
+    for cp in range(0x110000):
+        if HANGUL_S_BASE <= cp < HANGUL_S_BASE + HANGUL_S_COUNT:
+            # ハングル音節は計算で分解・合成する
+            continue
+        c = chr(cp)
+
+        ccc = unicodedata.combining(c)
+        if ccc:
+            if combining_classes and combining_classes[-1][1] + 1 == cp and combining_classes[-1][2] == ccc:
+                combining_classes[-1] = (combining_classes[-1][0], cp, ccc)
+            else:
+                combining_classes.append((cp, cp, ccc))
+
+        decomposition = unicodedata.decomposition(c)
+        if not decomposition or decomposition.startswith("<"):
+            continue
+        parts = [int(p, 16) for p in decomposition.split()]
+        first = parts[0]
+        second = parts[1] if len(parts) > 1 else 0
+        decompositions.append((cp, first, second))
+
+        # 合成の除外対象でない（NFCで再び合成される）ものだけを合成表に入れる
+        if second and unicodedata.normalize("NFC", chr(first) + chr(second)) == c:
+            compositions.append((first, second, cp))
+
+    compositions.sort()
+
+    print("// This file is generated by scripts/gen_idna_table.py. Do not edit it by hand.")
+    print("// IdnaMappingTable.txt / Unicode %s" % unicodedata.unidata_version)
+    print()
+    print("/// https://www.unicode.org/reports/tr46/#IDNA_Mapping_Table")
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    print("pub enum Status {")
+    print("    Valid,")
+    print("    Ignored,")
+    print("    Mapped(&'static str),")
+    print("    Disallowed,")
+    print("}")
+    print()
+    print("// (最初のコードポイント, 最後のコードポイント, 状態)")
+    print("pub static MAPPING_TABLE: &[(u32, u32, Status)] = &[")
+    for start, end, status, mapping in entries:
+        if status == "Mapped":
+            value = "Status::Mapped(%s)" % rust_str(mapping)
+        else:
+            value = "Status::%s" % status
+        print("    (0x%X, 0x%X, %s)," % (start, end, value))
+    print("];")
+    print()
+    print("// (最初のコードポイント, 最後のコードポイント, 結合クラス)")
+    print("pub static COMBINING_CLASS: &[(u32, u32, u8)] = &[")
+    for start, end, ccc in combining_classes:
+        print("    (0x%X, 0x%X, %d)," % (start, end, ccc))
+    print("];")
+    print()
+    print("// (コードポイント, 分解後の1文字目, 分解後の2文字目または0)")
+    print("pub static CANONICAL_DECOMPOSITION: &[(u32, u32, u32)] = &[")
+    for cp, first, second in decompositions:
+        print("    (0x%X, 0x%X, 0x%X)," % (cp, first, second))
+    print("];")
+    print()
+    print("// (1文字目, 2文字目, 合成後のコードポイント)")
+    print("pub static CANONICAL_COMPOSITION: &[(u32, u32, u32)] = &[")
+    for first, second, cp in compositions:
+        print("    (0x%X, 0x%X, 0x%X)," % (first, second, cp))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
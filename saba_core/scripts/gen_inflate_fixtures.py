@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+"""Generate the fixtures in saba_core/testdata/inflate/ used by src/inflate.rs.
+
+Usage:
+    python3 gen_inflate_fixtures.py ../testdata/inflate
+
+sample.html is compressed with Python's zlib and gzip modules into:
+    sample.html.gz         gzip, level 9 (dynamic Huffman blocks)
+    sample.html.stored.gz  gzip, level 0 (stored blocks) with the FNAME field set
+    sample.html.zlib       zlib, level 9
+    sample.html.deflate    raw DEFLATE without any wrapper
+"""
+
+import gzip
+import io
+import os
+import sys
+import zlib
+
+
+def sample_html():
+    parts = [
+        '<!DOCTYPE html>\n<html>\n<head>\n<meta charset="utf-8">\n'
+        "<title>saba</title>\n</head>\n<body>\n<h1>Compressed page</h1>\n"
+    ]
+    for i in range(60):
+        parts.append(
+            '<p id="p%d">Paragraph %d: the quick brown fox jumps over the lazy dog. %s</p>\n'
+            % (i, i, "ぽかぽか" * (i % 4))
+        )
+    parts.append("</body>\n</html>\n")
+    return "".join(parts).encode("utf-8")
+
+
+def main():
+    out = sys.argv[1]
+    data = sample_html()
+
+    def write(name, content):
+        with open(os.path.join(out, name), "wb") as f:
+            f.write(content)
+
+    write("sample.html", data)
+    # mtimeを0にして、生成するたびに同じバイト列になるようにする
+    write("sample.html.gz", gzip.compress(data, compresslevel=9, mtime=0))
+
+    buf = io.BytesIO()
+    with gzip.GzipFile(filename="sample.html", mode="wb", fileobj=buf, compresslevel=0, mtime=0) as f:
+        f.write(data)
+    write("sample.html.stored.gz", buf.getvalue())
+
+    write("sample.html.zlib", zlib.compress(data, 9))
+
+    compressor = zlib.compressobj(9, zlib.DEFLATED, -15)
+    write("sample.html.deflate", compressor.compress(data) + compressor.flush())
+
+
+if __name__ == "__main__":
+    main()
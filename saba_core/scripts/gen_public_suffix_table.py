@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+"""Generate saba_core/src/url/public_suffix_table.rs.
+
+Usage:
+    python3 gen_public_suffix_table.py public_suffix_list.dat > ../src/url/public_suffix_table.rs
+
+public_suffix_list.dat is the Public Suffix List published at
+https://publicsuffix.org/list/public_suffix_list.dat
+
+Both the ICANN and the private sections are used, as the URL Standard
+does. Rules are stored in their ASCII form ("xn--" labels) because hosts
+are matched after domain to ASCII, and each table is sorted so that it
+can be binary searched.
+"""
+
+import sys
+
+
+def to_ascii(rule):
+    labels = []
+    for label in rule.split("."):
+        if label.isascii():
+            labels.append(label.lower())
+        else:
+            labels.append("xn--" + label.encode("punycode").decode("ascii"))
+    return ".".join(labels)
+
+
+def parse_list(path):
+    rules = set()
+    wildcards = set()
+    exceptions = set()
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            # 各行の最初の空白までがルール
+            line = line.strip()
+            if not line or line.startswith("//"):
+                continue
+            rule = line.split()[0]
+
+            if rule.startswith("!"):
+                exceptions.add(to_ascii(rule[1:]))
+            elif rule.startswith("*."):
+                wildcards.add(to_ascii(rule[2:]))
+            else:
+                rules.add(to_ascii(rule))
+    return sorted(rules), sorted(wildcards), sorted(exceptions)
+
+
+def print_table(comment, name, entries):
+    print(comment)
+    print("pub static %s: &[&str] = &[" % name)
+    for entry in entries:
+        print('    "%s",' % entry)
+    print("];")
+
+
+def main():
+    rules, wildcards, exceptions = parse_list(sys.argv[1])
+
+    print("// This file is generated by scripts/gen_public_suffix_table.py. Do not edit it by hand.")
+    print("// public_suffix_list.dat / %d rules" % (len(rules) + len(wildcards) + len(exceptions)))
+    print()
+    print_table("// \"com\"や\"co.jp\"のような通常のルール", "RULES", rules)
+    print()
+    print_table("// \"*.kawasaki.jp\"のようなワイルドカードのルールから\"*.\"を除いたもの", "WILDCARD_RULES", wildcards)
+    print()
+    print_table("// \"!city.kawasaki.jp\"のような例外のルールから\"!\"を除いたもの", "EXCEPTION_RULES", exceptions)
+
+
+if __name__ == "__main__":
+    main()
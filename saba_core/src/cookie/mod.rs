@@ -0,0 +1,643 @@
+//! https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html
+
+pub mod parser;
+
+use crate::cookie::parser::SetCookie;
+use crate::http::headers::HeaderMap;
+use crate::http::Method;
+use crate::url::host::Host;
+use crate::url::percent_encoding::percent_decode_str;
+use crate::url::percent_encoding::percent_encode_byte;
+use crate::url::public_suffix::public_suffix;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+// Cookieを保存しておける最長の期間（400日）
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-cookie-lifetime-limits
+const MAX_LIFETIME: u64 = 400 * 24 * 60 * 60;
+
+/// 別のサイトから送られるリクエストにCookieを付けるかどうか
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-the-samesite-attribute-2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    // 同じサイトからのリクエストにだけ付ける
+    Strict,
+    // 別のサイトからのトップレベルのGETによる移動でも付ける
+    Lax,
+    // 常に付ける
+    None,
+}
+
+impl SameSite {
+    fn from_value(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("Strict") {
+            Some(SameSite::Strict)
+        } else if value.eq_ignore_ascii_case("Lax") {
+            Some(SameSite::Lax)
+        } else if value.eq_ignore_ascii_case("None") {
+            Some(SameSite::None)
+        } else {
+            None
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// リクエストを送る状況。SameSite属性によってCookieを付けるかどうかが変わる
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-same-site-and-cross-site-re
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestContext {
+    SameSite,
+    // 別のサイトのページからのトップレベルの移動で、安全なメソッドを使うもの
+    CrossSiteNavigation,
+    CrossSite,
+}
+
+impl RequestContext {
+    // initiatorはリクエストを起こしたページのURL。アドレスバーへの入力のようにページによらない場合はNone
+    pub fn new(initiator: Option<&Url>, url: &Url, top_level: bool, method: Method) -> Self {
+        match initiator {
+            None => RequestContext::SameSite,
+            Some(initiator) if initiator.origin().is_same_site(&url.origin()) => {
+                RequestContext::SameSite
+            }
+            Some(_)
+                if top_level && matches!(method, Method::Get | Method::Head | Method::Options) =>
+            {
+                RequestContext::CrossSiteNavigation
+            }
+            Some(_) => RequestContext::CrossSite,
+        }
+    }
+}
+
+/// 保存されたCookie
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-storage-model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    name: String,
+    value: String,
+    domain: String,
+    // trueの場合はdomainと完全に一致するホストにだけ送る
+    host_only: bool,
+    path: String,
+    // 期限（1970年1月1日からの秒数）。Noneの場合はブラウザを閉じるまで
+    expiry: Option<u64>,
+    secure: bool,
+    http_only: bool,
+    same_site: SameSite,
+    creation_time: u64,
+}
+
+impl Cookie {
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    pub fn domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    pub fn host_only(&self) -> bool {
+        self.host_only
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn expiry(&self) -> Option<u64> {
+        self.expiry
+    }
+
+    pub fn secure(&self) -> bool {
+        self.secure
+    }
+
+    pub fn http_only(&self) -> bool {
+        self.http_only
+    }
+
+    pub fn same_site(&self) -> SameSite {
+        self.same_site
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiry.is_some_and(|expiry| expiry <= now)
+    }
+
+    // ブラウザを閉じた後も残すCookieかどうか
+    pub fn is_persistent(&self) -> bool {
+        self.expiry.is_some()
+    }
+
+    fn matches_host(&self, host: &str) -> bool {
+        if self.host_only {
+            self.domain == host
+        } else {
+            domain_match(host, &self.domain)
+        }
+    }
+
+    // 同じCookieとして置き換える対象かどうか
+    fn is_same_entry(&self, other: &Cookie) -> bool {
+        self.name == other.name
+            && self.domain == other.domain
+            && self.host_only == other.host_only
+            && self.path == other.path
+    }
+}
+
+/// Cookieの保存場所。時刻は1970年1月1日からの秒数で受け取る
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CookieJar {
+    // 保存した順に並ぶ
+    cookies: Vec<Cookie>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        Self {
+            cookies: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cookies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Cookie> {
+        self.cookies.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.cookies.clear();
+    }
+
+    // レスポンスのSet-Cookieヘッダーをすべて保存する
+    pub fn store_response_cookies(&mut self, url: &Url, headers: &HeaderMap, now: Option<u64>) {
+        for header in headers.get_all("Set-Cookie") {
+            self.set_cookie(url, &header, now);
+        }
+    }
+
+    // urlへのリクエストで受け取ったSet-Cookieヘッダーを1つ保存する
+    // 保存しなかった場合はfalse。期限切れのCookieで既存のものを消した場合もfalseになる
+    // 現在時刻が分からない場合はnowをNoneにする
+    /// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-storage-model
+    pub fn set_cookie(&mut self, url: &Url, header: &str, now: Option<u64>) -> bool {
+        let set_cookie = match SetCookie::parse(header) {
+            Some(set_cookie) => set_cookie,
+            None => return false,
+        };
+        let host = url.host();
+        let secure_request = url.scheme() == "https";
+
+        // Max-AgeはExpiresより優先する
+        // 時刻が分からない場合は、Max-Ageを数え始める時刻も上限もないので、
+        // Max-AgeのCookieはブラウザを閉じるまでのものとして扱い、Expiresはそのまま使う
+        let expiry = match (set_cookie.max_age(), set_cookie.expires(), now) {
+            (Some(max_age), _, _) if max_age <= 0 => Some(0),
+            (Some(max_age), _, Some(now)) => {
+                Some(now.saturating_add((max_age as u64).min(MAX_LIFETIME)))
+            }
+            (Some(_), _, None) => None,
+            (None, Some(expires), Some(now)) => Some(expires.min(now.saturating_add(MAX_LIFETIME))),
+            (None, Some(expires), None) => Some(expires),
+            (None, None, _) => None,
+        };
+        let now = now.unwrap_or(0);
+
+        let (domain, host_only) = match set_cookie.domain() {
+            Some(domain) => {
+                let domain = match Host::parse(&domain, false) {
+                    Ok(domain) => domain.to_string(),
+                    Err(_) => return false,
+                };
+                // "com"のような公開サフィックスに対するCookieは受け付けない
+                // ホストそのものが公開サフィックスの場合は、そのホストだけに送るCookieにする
+                if public_suffix(&domain) == domain {
+                    if domain != host {
+                        return false;
+                    }
+                    (domain, true)
+                } else if domain_match(&host, &domain) {
+                    (domain, false)
+                } else {
+                    return false;
+                }
+            }
+            None => (host, true),
+        };
+
+        let cookie = Cookie {
+            name: set_cookie.name(),
+            value: set_cookie.value(),
+            domain,
+            host_only,
+            path: set_cookie.path().unwrap_or_else(|| default_path(url)),
+            expiry,
+            secure: set_cookie.secure(),
+            http_only: set_cookie.http_only(),
+            same_site: set_cookie.same_site().unwrap_or(SameSite::Lax),
+            creation_time: now,
+        };
+
+        if cookie.secure && !secure_request {
+            return false;
+        }
+        if !has_valid_prefix(&cookie, set_cookie.domain().is_some()) {
+            return false;
+        }
+        // 安全でない接続から、同じ名前のSecureなCookieを上書きすることはできない
+        if !secure_request
+            && self.cookies.iter().any(|c| {
+                c.secure
+                    && c.name == cookie.name
+                    && (domain_match(&c.domain, &cookie.domain)
+                        || domain_match(&cookie.domain, &c.domain))
+                    && path_match(&cookie.path, &c.path)
+            })
+        {
+            return false;
+        }
+
+        // 同じCookieがあれば、作成時刻を引き継いで置き換える
+        let old = self.cookies.iter().position(|c| c.is_same_entry(&cookie));
+        if cookie.is_expired(now) {
+            if let Some(i) = old {
+                self.cookies.remove(i);
+            }
+            return false;
+        }
+        match old {
+            Some(i) => {
+                let creation_time = self.cookies[i].creation_time;
+                self.cookies[i] = Cookie {
+                    creation_time,
+                    ..cookie
+                };
+            }
+            None => self.cookies.push(cookie),
+        }
+        true
+    }
+
+    // urlへのリクエストに付けるCookieヘッダーの値。送るCookieがない場合はNone
+    /// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-retrieval-algorithm
+    pub fn cookie_header(&self, url: &Url, context: RequestContext, now: u64) -> Option<String> {
+        let host = url.host();
+        let path = request_path(url);
+        let secure_request = url.scheme() == "https";
+
+        let mut cookies: Vec<&Cookie> = self
+            .cookies
+            .iter()
+            .filter(|c| !c.is_expired(now))
+            .filter(|c| c.matches_host(&host) && path_match(&path, &c.path))
+            .filter(|c| !c.secure || secure_request)
+            .filter(|c| match c.same_site {
+                SameSite::Strict => context == RequestContext::SameSite,
+                SameSite::Lax => context != RequestContext::CrossSite,
+                SameSite::None => true,
+            })
+            .collect();
+        if cookies.is_empty() {
+            return None;
+        }
+
+        // パスが長いものを先に、同じ長さなら先に作られたものを先に並べる
+        cookies.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.creation_time.cmp(&b.creation_time))
+        });
+        let pairs: Vec<String> = cookies
+            .iter()
+            .map(|c| {
+                if c.name.is_empty() {
+                    c.value.clone()
+                } else {
+                    format!("{}={}", c.name, c.value)
+                }
+            })
+            .collect();
+        Some(pairs.join("; "))
+    }
+
+    pub fn remove_expired(&mut self, now: u64) {
+        self.cookies.retain(|c| !c.is_expired(now));
+    }
+
+    // ブラウザを閉じた後も残すCookieを、1行に1つずつタブ区切りのテキストにする
+    // 期限のないCookieは書き出さない
+    pub fn serialize(&self) -> String {
+        let mut result = String::new();
+        for cookie in self.cookies.iter().filter(|c| c.is_persistent()) {
+            let fields = [
+                escape(&cookie.name),
+                escape(&cookie.value),
+                escape(&cookie.domain),
+                bool_field(cookie.host_only).to_string(),
+                escape(&cookie.path),
+                cookie.expiry.unwrap_or_default().to_string(),
+                bool_field(cookie.secure).to_string(),
+                bool_field(cookie.http_only).to_string(),
+                cookie.same_site.name().to_string(),
+                cookie.creation_time.to_string(),
+            ];
+            result.push_str(&fields.join("\t"));
+            result.push('\n');
+        }
+        result
+    }
+
+    // serializeで書き出したテキストを読み込む。読めない行は無視する
+    pub fn deserialize(text: &str) -> Self {
+        let cookies = text.lines().filter_map(deserialize_cookie).collect();
+        Self { cookies }
+    }
+}
+
+fn deserialize_cookie(line: &str) -> Option<Cookie> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() != 10 {
+        return None;
+    }
+    Some(Cookie {
+        name: percent_decode_str(fields[0]),
+        value: percent_decode_str(fields[1]),
+        domain: percent_decode_str(fields[2]),
+        host_only: parse_bool_field(fields[3])?,
+        path: percent_decode_str(fields[4]),
+        expiry: Some(fields[5].parse().ok()?),
+        secure: parse_bool_field(fields[6])?,
+        http_only: parse_bool_field(fields[7])?,
+        same_site: SameSite::from_value(fields[8])?,
+        creation_time: fields[9].parse().ok()?,
+    })
+}
+
+// タブと改行、"%"をパーセントエンコードする
+fn escape(s: &str) -> String {
+    let mut result = String::new();
+    for c in s.chars() {
+        if c == '%' || c.is_ascii_control() {
+            percent_encode_byte(c as u8, &mut result);
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn bool_field(b: bool) -> &'static str {
+    if b {
+        "1"
+    } else {
+        "0"
+    }
+}
+
+fn parse_bool_field(s: &str) -> Option<bool> {
+    match s {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+// "__Secure-"と"__Host-"で始まる名前のCookieに課される条件
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-cookie-name-prefixes
+fn has_valid_prefix(cookie: &Cookie, has_domain_attribute: bool) -> bool {
+    let starts_with = |s: &str, prefix: &str| {
+        s.get(..prefix.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+    };
+    // 名前のないCookieで接頭辞を偽装することはできない
+    if cookie.name.is_empty()
+        && (starts_with(&cookie.value, "__Secure-") || starts_with(&cookie.value, "__Host-"))
+    {
+        return false;
+    }
+    if starts_with(&cookie.name, "__Secure-") && !cookie.secure {
+        return false;
+    }
+    if starts_with(&cookie.name, "__Host-")
+        && !(cookie.secure && !has_domain_attribute && cookie.path == "/")
+    {
+        return false;
+    }
+    true
+}
+
+// hostがdomainそのものか、domainのサブドメインかどうか。IPアドレスは完全に一致する場合だけ
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-domain-matching
+fn domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+    host.strip_suffix(domain)
+        .is_some_and(|rest| rest.ends_with('.'))
+        && matches!(Host::parse(host, false), Ok(Host::Domain(_)))
+}
+
+// request_pathがcookie_pathの下にあるかどうか
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-paths-and-path-match
+fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    match request_path.strip_prefix(cookie_path) {
+        Some(rest) => rest.is_empty() || cookie_path.ends_with('/') || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+fn request_path(url: &Url) -> String {
+    format!("/{}", url.path())
+}
+
+// Path属性がない場合は、リクエストのパスの最後の"/"より前を使う
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-paths-and-path-match
+fn default_path(url: &Url) -> String {
+    let path = request_path(url);
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(i) => path[..i].to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::url;
+
+    fn header(jar: &CookieJar, s: &str) -> Option<String> {
+        jar.cookie_header(&url(s), RequestContext::SameSite, 100)
+    }
+
+    #[test]
+    fn test_domain_and_path() {
+        let mut jar = CookieJar::new();
+        let origin = url("http://www.example.com/app/login");
+        assert!(jar.set_cookie(&origin, "host=1", Some(0)));
+        assert!(jar.set_cookie(&origin, "shared=2; Domain=example.com; Path=/", Some(0)));
+        assert!(jar.set_cookie(&origin, "deep=3; Path=/app/admin", Some(0)));
+        // 公開サフィックスや無関係のドメインには設定できない
+        assert!(!jar.set_cookie(&origin, "a=1; Domain=com", Some(0)));
+        assert!(!jar.set_cookie(&origin, "a=1; Domain=other.com", Some(0)));
+        assert!(jar.set_cookie(
+            &url("http://localhost/"),
+            "local=1; Domain=localhost",
+            Some(0)
+        ));
+        assert!(jar.iter().last().is_some_and(|c| c.host_only()));
+
+        assert_eq!(
+            header(&jar, "http://www.example.com/app/admin/users"),
+            Some("deep=3; host=1; shared=2".to_string())
+        );
+        assert_eq!(
+            header(&jar, "http://www.example.com/application"),
+            Some("shared=2".to_string())
+        );
+        assert_eq!(
+            header(&jar, "http://sub.example.com/app/x"),
+            Some("shared=2".to_string())
+        );
+        assert_eq!(header(&jar, "http://example.org/"), None);
+    }
+
+    #[test]
+    fn test_replace_and_expire() {
+        let mut jar = CookieJar::new();
+        let origin = url("http://example.com/");
+        jar.set_cookie(&origin, "a=1; Max-Age=10", Some(0));
+        jar.set_cookie(
+            &origin,
+            "b=2; Expires=Thu, 01 Jan 1970 00:01:00 GMT",
+            Some(0),
+        );
+        jar.set_cookie(&origin, "a=3; Max-Age=1000", Some(50));
+        assert_eq!(jar.len(), 2);
+        assert_eq!(jar.iter().next().map(|c| c.creation_time), Some(0));
+        assert_eq!(header(&jar, "http://example.com/"), Some("a=3".to_string()));
+
+        // 期限切れのCookieを設定すると削除される
+        assert!(!jar.set_cookie(&origin, "a=; Max-Age=0", Some(100)));
+        assert_eq!(jar.len(), 1);
+        jar.remove_expired(100);
+        assert!(jar.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_time() {
+        let mut jar = CookieJar::new();
+        let origin = url("http://example.com/");
+        // 時刻が分からないうちは、Max-Ageを1970年から数えたり、Expiresを1971年までにしたりしない
+        assert!(jar.set_cookie(&origin, "a=1; Max-Age=3600", None));
+        assert!(jar.set_cookie(&origin, "b=2; Expires=Wed, 01 Jan 2031 00:00:00 GMT", None));
+        assert!(!jar.set_cookie(&origin, "c=3; Max-Age=0", None));
+        let a = jar.iter().next().expect("no cookie");
+        assert_eq!(a.expiry(), None);
+        assert!(!a.is_persistent());
+
+        // 後から時刻が分かっても、消えずに送られる
+        // "Thu, 01 Jan 2026 00:00:00 GMT"
+        jar.remove_expired(1_767_225_600);
+        assert_eq!(
+            jar.cookie_header(&origin, RequestContext::SameSite, 1_767_225_600),
+            Some("a=1; b=2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_secure_and_prefixes() {
+        let mut jar = CookieJar::new();
+        let secure = url("https://example.com/");
+        let insecure = url("http://example.com/");
+        assert!(!jar.set_cookie(&insecure, "s=1; Secure", Some(0)));
+        assert!(jar.set_cookie(&secure, "s=1; Secure", Some(0)));
+        assert!(!jar.set_cookie(&insecure, "s=2", Some(0)));
+        assert_eq!(header(&jar, "http://example.com/"), None);
+        assert_eq!(
+            header(&jar, "https://example.com/"),
+            Some("s=1".to_string())
+        );
+
+        assert!(!jar.set_cookie(&secure, "__Secure-a=1", Some(0)));
+        assert!(!jar.set_cookie(&secure, "__Host-a=1; Secure; Domain=example.com", Some(0)));
+        assert!(jar.set_cookie(&secure, "__Host-a=1; Secure; Path=/", Some(0)));
+        assert!(!jar.set_cookie(&secure, "__Host-x", Some(0)));
+    }
+
+    #[test]
+    fn test_same_site() {
+        let mut jar = CookieJar::new();
+        let target = url("https://example.com/");
+        jar.set_cookie(&target, "strict=1; SameSite=Strict", Some(0));
+        jar.set_cookie(&target, "lax=2", Some(0));
+        jar.set_cookie(&target, "none=3; SameSite=None; Secure", Some(0));
+
+        let other = url("https://other.test/");
+        let navigation = RequestContext::new(Some(&other), &target, true, Method::Get);
+        assert_eq!(navigation, RequestContext::CrossSiteNavigation);
+        assert_eq!(
+            jar.cookie_header(&target, navigation, 0),
+            Some("lax=2; none=3".to_string())
+        );
+        let post = RequestContext::new(Some(&other), &target, true, Method::Post);
+        assert_eq!(
+            jar.cookie_header(&target, post, 0),
+            Some("none=3".to_string())
+        );
+        let same_site = RequestContext::new(
+            Some(&url("https://www.example.com/")),
+            &target,
+            false,
+            Method::Post,
+        );
+        assert_eq!(
+            jar.cookie_header(&target, same_site, 0),
+            Some("strict=1; lax=2; none=3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut jar = CookieJar::new();
+        let origin = url("https://example.com/app/");
+        jar.set_cookie(&origin, "session=1", Some(10));
+        jar.set_cookie(
+            &origin,
+            "p=a\tb%c; Max-Age=60; Secure; HttpOnly; SameSite=None",
+            Some(10),
+        );
+        let text = jar.serialize();
+        assert_eq!(
+            text,
+            "p\ta%09b%25c\texample.com\t1\t/app\t70\t1\t1\tNone\t10\n"
+        );
+
+        let restored = CookieJar::deserialize(&(text + "broken line\n"));
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored.iter().next(), jar.iter().nth(1));
+    }
+}
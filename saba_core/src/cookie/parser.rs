@@ -0,0 +1,209 @@
+//! https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-the-set-cookie-header-field
+
+use crate::cookie::SameSite;
+use crate::http::date::parse_http_date;
+use alloc::string::String;
+use alloc::string::ToString;
+
+// 名前と値を合わせた長さ、属性の値の長さの上限
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-cookie-lifetime-limits
+const MAX_NAME_VALUE_LENGTH: usize = 4096;
+const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 1024;
+
+/// Set-Cookieヘッダーを解析した結果。保存するかどうかはリクエストのURLと合わせて判断する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCookie {
+    name: String,
+    value: String,
+    // 1970年1月1日からの秒数
+    expires: Option<u64>,
+    // 秒数。負の値はすぐに期限切れになる
+    max_age: Option<i64>,
+    domain: Option<String>,
+    path: Option<String>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl SetCookie {
+    // 保存してはいけないSet-Cookieの場合はNone
+    /// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-the-set-cookie-header-field
+    pub fn parse(header: &str) -> Option<Self> {
+        // 制御文字（タブを除く）を含む場合はすべて無視する
+        if header
+            .bytes()
+            .any(|b| (b < 0x20 && b != b'\t') || b == 0x7F)
+        {
+            return None;
+        }
+
+        let (pair, attributes) = match header.split_once(';') {
+            Some((pair, attributes)) => (pair, Some(attributes)),
+            None => (header, None),
+        };
+        // "="がない場合は名前が空のCookieになる
+        let (name, value) = match pair.split_once('=') {
+            Some((name, value)) => (trim(name), trim(value)),
+            None => ("", trim(pair)),
+        };
+        if name.is_empty() && value.is_empty() {
+            return None;
+        }
+        if name.len() + value.len() > MAX_NAME_VALUE_LENGTH {
+            return None;
+        }
+
+        let mut cookie = Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            expires: None,
+            max_age: None,
+            domain: None,
+            path: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        };
+
+        for attribute in attributes.into_iter().flat_map(|a| a.split(';')) {
+            let (name, value) = match attribute.split_once('=') {
+                Some((name, value)) => (trim(name), trim(value)),
+                None => (trim(attribute), ""),
+            };
+            if value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+                continue;
+            }
+
+            // 同じ属性が複数ある場合は最後のものを使う
+            if name.eq_ignore_ascii_case("Expires") {
+                if let Some(expires) = parse_http_date(value) {
+                    cookie.expires = Some(expires);
+                }
+            } else if name.eq_ignore_ascii_case("Max-Age") {
+                if let Some(max_age) = parse_max_age(value) {
+                    cookie.max_age = Some(max_age);
+                }
+            } else if name.eq_ignore_ascii_case("Domain") {
+                if !value.is_empty() {
+                    let domain = value.strip_prefix('.').unwrap_or(value);
+                    cookie.domain = Some(domain.to_ascii_lowercase());
+                }
+            } else if name.eq_ignore_ascii_case("Path") {
+                // "/"で始まらない場合はリクエストのURLから決める
+                cookie.path = if value.starts_with('/') {
+                    Some(value.to_string())
+                } else {
+                    None
+                };
+            } else if name.eq_ignore_ascii_case("Secure") {
+                cookie.secure = true;
+            } else if name.eq_ignore_ascii_case("HttpOnly") {
+                cookie.http_only = true;
+            } else if name.eq_ignore_ascii_case("SameSite") {
+                cookie.same_site = SameSite::from_value(value);
+            }
+        }
+
+        Some(cookie)
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    pub fn expires(&self) -> Option<u64> {
+        self.expires
+    }
+
+    pub fn max_age(&self) -> Option<i64> {
+        self.max_age
+    }
+
+    pub fn domain(&self) -> Option<String> {
+        self.domain.clone()
+    }
+
+    pub fn path(&self) -> Option<String> {
+        self.path.clone()
+    }
+
+    pub fn secure(&self) -> bool {
+        self.secure
+    }
+
+    pub fn http_only(&self) -> bool {
+        self.http_only
+    }
+
+    pub fn same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+}
+
+fn trim(s: &str) -> &str {
+    s.trim_matches(|c| c == ' ' || c == '\t')
+}
+
+// 数字の並び。先頭だけは"-"でもよい
+/// https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#name-the-max-age-attribute-2
+fn parse_max_age(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // 大きすぎる値は上限に丸める
+    match value.parse::<i64>() {
+        Ok(max_age) => Some(max_age),
+        Err(_) if value.starts_with('-') => Some(i64::MIN),
+        Err(_) => Some(i64::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_attributes() {
+        let cookie = SetCookie::parse(
+            " sid = abc123 ; Path=/app; Domain=.Example.COM; Secure; HttpOnly; SameSite=strict; Max-Age=60; Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+        )
+        .expect("failed to parse cookie");
+        assert_eq!(cookie.name(), "sid");
+        assert_eq!(cookie.value(), "abc123");
+        assert_eq!(cookie.path(), Some("/app".to_string()));
+        assert_eq!(cookie.domain(), Some("example.com".to_string()));
+        assert!(cookie.secure());
+        assert!(cookie.http_only());
+        assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+        assert_eq!(cookie.max_age(), Some(60));
+        assert_eq!(cookie.expires(), Some(1445412480));
+    }
+
+    #[test]
+    fn test_parse_edge_cases() {
+        let cookie = SetCookie::parse("token").expect("failed to parse cookie");
+        assert_eq!(cookie.name(), "");
+        assert_eq!(cookie.value(), "token");
+
+        let cookie =
+            SetCookie::parse("a=b; Path=relative; Max-Age=1x; Expires=never; SameSite=bogus")
+                .expect("failed to parse cookie");
+        assert_eq!(cookie.path(), None);
+        assert_eq!(cookie.max_age(), None);
+        assert_eq!(cookie.expires(), None);
+        assert_eq!(cookie.same_site(), None);
+
+        assert_eq!(
+            SetCookie::parse("a=b; Max-Age=-5").and_then(|c| c.max_age()),
+            Some(-5)
+        );
+        assert_eq!(SetCookie::parse("=; Path=/"), None);
+        assert_eq!(SetCookie::parse("a=b\x01"), None);
+    }
+}
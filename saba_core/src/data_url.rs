@@ -0,0 +1,221 @@
+//! https://fetch.spec.whatwg.org/#data-urls
+
+use crate::http::headers::HeaderMap;
+use crate::http::HttpResponse;
+use crate::mime::MimeType;
+use crate::url::percent_encoding::percent_decode;
+use crate::url::Scheme;
+use crate::url::Url;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataUrlError {
+    // "data:"以外のURL
+    NotDataUrl,
+    // MIMEタイプとデータを区切る","がない
+    MissingComma,
+    // ";base64"が指定されているのにBase64としてデコードできない
+    InvalidBase64,
+}
+
+/// "data:[<mediatype>][;base64],<data>"をデコードした結果
+/// https://fetch.spec.whatwg.org/#data-url-struct
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataUrl {
+    mime_type: MimeType,
+    body: Vec<u8>,
+}
+
+impl DataUrl {
+    /// https://fetch.spec.whatwg.org/#data-url-processor
+    pub fn parse(url: &Url) -> Result<Self, DataUrlError> {
+        if url.scheme_kind() != Some(Scheme::Data) {
+            return Err(DataUrlError::NotDataUrl);
+        }
+
+        // フラグメントを除いて"data:"を取り除く
+        let input = url.without_fragment().to_string();
+        let input = &input["data:".len()..];
+
+        let (mime_type, encoded_body) = match input.split_once(',') {
+            Some((m, b)) => (m.trim_matches(|c: char| c.is_ascii_whitespace()), b),
+            None => return Err(DataUrlError::MissingComma),
+        };
+
+        let mut body = percent_decode(encoded_body.as_bytes());
+        let mut mime_type = String::from(mime_type);
+
+        // ";base64"で終わる場合はBase64としてデコードする
+        if let Some(stripped) = strip_base64_suffix(&mime_type) {
+            let string_body: String = body.iter().map(|b| *b as char).collect();
+            body = forgiving_base64_decode(&string_body).ok_or(DataUrlError::InvalidBase64)?;
+            mime_type = stripped.to_string();
+        }
+
+        if mime_type.starts_with(';') {
+            mime_type.insert_str(0, "text/plain");
+        }
+
+        let mime_type = match MimeType::parse(&mime_type) {
+            Some(m) => m,
+            None => {
+                let mut m = MimeType::new("text", "plain");
+                m.set_parameter("charset", "US-ASCII");
+                m
+            }
+        };
+
+        Ok(Self { mime_type, body })
+    }
+
+    pub fn mime_type(&self) -> MimeType {
+        self.mime_type.clone()
+    }
+
+    pub fn charset(&self) -> Option<String> {
+        self.mime_type.charset()
+    }
+
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    // ネットワークから取得したときと同じように扱えるレスポンスにする
+    /// https://fetch.spec.whatwg.org/#scheme-fetch
+    pub fn to_response(&self) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        headers.append("Content-Type", &self.mime_type.to_string());
+        HttpResponse::from_parts(
+            String::new(),
+            200,
+            String::from("OK"),
+            headers,
+            self.body.clone(),
+        )
+    }
+}
+
+// "text/plain;base64"や"; base64"のように、";"と空白のあとに"base64"で終わっていれば、それを取り除いた文字列を返す
+fn strip_base64_suffix(mime_type: &str) -> Option<&str> {
+    let split = mime_type.len().checked_sub(6)?;
+    if !mime_type.get(split..)?.eq_ignore_ascii_case("base64") {
+        return None;
+    }
+    let rest = mime_type[..split].trim_end_matches(' ');
+    rest.strip_suffix(';')
+}
+
+/// https://infra.spec.whatwg.org/#forgiving-base64-decode
+pub fn forgiving_base64_decode(input: &str) -> Option<Vec<u8>> {
+    let mut data: Vec<u8> = input
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+
+    if data.len() % 4 == 0 {
+        if data.ends_with(b"==") {
+            data.truncate(data.len() - 2);
+        } else if data.ends_with(b"=") {
+            data.truncate(data.len() - 1);
+        }
+    }
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for b in data {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(url: &str) -> Result<DataUrl, DataUrlError> {
+        let url = Url::new(url.to_string()).parse().expect("failed to parse url");
+        DataUrl::parse(&url)
+    }
+
+    #[test]
+    fn test_default_mime_type() {
+        let data = parse("data:,Hello%2C%20World%21").expect("failed to parse data url");
+        assert_eq!(data.mime_type().to_string(), "text/plain;charset=US-ASCII");
+        assert_eq!(data.body(), b"Hello, World!".to_vec());
+    }
+
+    #[test]
+    fn test_base64() {
+        let data = parse("data:text/plain;base64,SGVsbG8sIFdvcmxkIQ==#ignored").expect("failed to parse data url");
+        assert_eq!(data.mime_type().essence(), "text/plain");
+        assert_eq!(data.body(), b"Hello, World!".to_vec());
+
+        // 空白やパディングの省略は許容する
+        let data = parse("data:text/plain ; BASE64,SGVs bG8").expect("failed to parse data url");
+        assert_eq!(data.mime_type().to_string(), "text/plain");
+        assert_eq!(data.body(), b"Hello".to_vec());
+
+        assert_eq!(parse("data:;base64,SGVsb"), Err(DataUrlError::InvalidBase64));
+        assert_eq!(parse("data:;base64,SG=sbG8="), Err(DataUrlError::InvalidBase64));
+    }
+
+    #[test]
+    fn test_binary_body() {
+        let data = parse("data:image/png;base64,iVBORw0KGgo=").expect("failed to parse data url");
+        assert_eq!(data.mime_type().essence(), "image/png");
+        assert_eq!(data.body(), [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'].to_vec());
+    }
+
+    #[test]
+    fn test_charset() {
+        let data = parse("data:text/html;charset=Shift_JIS,%82%A0").expect("failed to parse data url");
+        assert_eq!(data.charset(), Some("Shift_JIS".to_string()));
+        assert_eq!(data.body(), [0x82, 0xA0].to_vec());
+    }
+
+    #[test]
+    fn test_to_response() {
+        let response = parse("data:text/html,<h1>saba</h1>")
+            .expect("failed to parse data url")
+            .to_response();
+        assert_eq!(response.status_code(), 200);
+        assert_eq!(response.header_value("content-type"), Some("text/html".to_string()));
+        assert_eq!(response.text(), "<h1>saba</h1>".to_string());
+
+        // バイナリのボディもそのまま保持する
+        let response = parse("data:image/png;base64,iVBORw0KGgo=")
+            .expect("failed to parse data url")
+            .to_response();
+        assert_eq!(
+            response.body(),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_not_data_url() {
+        let url = Url::new("http://example.com/".to_string()).parse().expect("failed to parse url");
+        assert_eq!(DataUrl::parse(&url), Err(DataUrlError::NotDataUrl));
+    }
+}
@@ -0,0 +1,340 @@
+//! https://encoding.spec.whatwg.org/#legacy-multi-byte-japanese-encodings
+
+use crate::encoding::index::JIS0208;
+use crate::encoding::index::JIS0212;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// 入力の先頭にバイトを戻せるバイト列
+/// https://encoding.spec.whatwg.org/#concept-stream-prepend
+struct ByteQueue<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    // 戻されたバイト。最後の要素から取り出す
+    prepended: Vec<u8>,
+}
+
+impl<'a> ByteQueue<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            prepended: Vec::new(),
+        }
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        if let Some(b) = self.prepended.pop() {
+            return Some(b);
+        }
+        let b = self.bytes.get(self.pos).copied()?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn prepend(&mut self, b: u8) {
+        self.prepended.push(b);
+    }
+}
+
+// インデックスのポインタから文字を求める。割り当てのないポインタはNone
+/// https://encoding.spec.whatwg.org/#index-code-point
+fn index_code_point(index: &[u16], pointer: usize) -> Option<char> {
+    match index.get(pointer) {
+        Some(0) | None => None,
+        Some(code_point) => char::from_u32(*code_point as u32),
+    }
+}
+
+// 半角カタカナ。0xA1がU+FF61になる
+fn halfwidth_katakana(b: u8) -> char {
+    char::from_u32(0xFF61 - 0xA1 + b as u32).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// https://encoding.spec.whatwg.org/#shift_jis-decoder
+pub(crate) fn decode_shift_jis(bytes: &[u8]) -> String {
+    let mut queue = ByteQueue::new(bytes);
+    let mut result = String::new();
+    let mut lead: u8 = 0;
+
+    while let Some(b) = queue.next() {
+        if lead != 0 {
+            let l = lead as usize;
+            lead = 0;
+            let offset = if b < 0x7F { 0x40 } else { 0x41 };
+            let lead_offset = if l < 0xA0 { 0x81 } else { 0xC1 };
+            let pointer = match b {
+                0x40..=0x7E | 0x80..=0xFC => Some((l - lead_offset) * 188 + b as usize - offset),
+                _ => None,
+            };
+
+            // 外字の領域は私用領域に割り当てる
+            if let Some(pointer @ 8836..=10715) = pointer {
+                result.push(
+                    char::from_u32(0xE000 - 8836 + pointer as u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER),
+                );
+                continue;
+            }
+            match pointer.and_then(|p| index_code_point(&JIS0208, p)) {
+                Some(c) => result.push(c),
+                None => {
+                    if b.is_ascii() {
+                        queue.prepend(b);
+                    }
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            }
+            continue;
+        }
+
+        match b {
+            0x00..=0x80 => result.push(b as char),
+            0xA1..=0xDF => result.push(halfwidth_katakana(b)),
+            0x81..=0x9F | 0xE0..=0xFC => lead = b,
+            _ => result.push(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    if lead != 0 {
+        result.push(char::REPLACEMENT_CHARACTER);
+    }
+    result
+}
+
+/// https://encoding.spec.whatwg.org/#euc-jp-decoder
+pub(crate) fn decode_euc_jp(bytes: &[u8]) -> String {
+    let mut queue = ByteQueue::new(bytes);
+    let mut result = String::new();
+    let mut lead: u8 = 0;
+    // JIS X 0212の文字を読んでいる途中かどうか
+    let mut jis0212 = false;
+
+    while let Some(b) = queue.next() {
+        if lead == 0x8E && (0xA1..=0xDF).contains(&b) {
+            lead = 0;
+            result.push(halfwidth_katakana(b));
+            continue;
+        }
+        if lead == 0x8F && (0xA1..=0xFE).contains(&b) {
+            jis0212 = true;
+            lead = b;
+            continue;
+        }
+        if lead != 0 {
+            let l = lead;
+            lead = 0;
+            let mut code_point = None;
+            if (0xA1..=0xFE).contains(&l) && (0xA1..=0xFE).contains(&b) {
+                let pointer = (l as usize - 0xA1) * 94 + b as usize - 0xA1;
+                let index: &[u16] = if jis0212 { &JIS0212 } else { &JIS0208 };
+                code_point = index_code_point(index, pointer);
+            }
+            jis0212 = false;
+            match code_point {
+                Some(c) => result.push(c),
+                None => {
+                    if b.is_ascii() {
+                        queue.prepend(b);
+                    }
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            }
+            continue;
+        }
+
+        match b {
+            0x00..=0x7F => result.push(b as char),
+            0x8E | 0x8F | 0xA1..=0xFE => lead = b,
+            _ => result.push(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    if lead != 0 {
+        result.push(char::REPLACEMENT_CHARACTER);
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Iso2022JpState {
+    Ascii,
+    Roman,
+    Katakana,
+    LeadByte,
+    TrailByte,
+    EscapeStart,
+    Escape,
+}
+
+/// https://encoding.spec.whatwg.org/#iso-2022-jp-decoder
+pub(crate) fn decode_iso_2022_jp(bytes: &[u8]) -> String {
+    let mut queue = ByteQueue::new(bytes);
+    let mut result = String::new();
+    let mut state = Iso2022JpState::Ascii;
+    // エスケープシーケンスの後に戻る状態
+    let mut output_state = Iso2022JpState::Ascii;
+    let mut lead: u8 = 0;
+    // 直前がエスケープシーケンスだったかどうか
+    let mut output = false;
+
+    loop {
+        let byte = queue.next();
+        match state {
+            Iso2022JpState::Ascii | Iso2022JpState::Roman => match byte {
+                None => break,
+                Some(0x1B) => state = Iso2022JpState::EscapeStart,
+                Some(0x0E | 0x0F) | Some(0x80..=0xFF) => {
+                    output = false;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+                Some(b) => {
+                    output = false;
+                    let c = match (state, b) {
+                        (Iso2022JpState::Roman, 0x5C) => '\u{A5}',
+                        (Iso2022JpState::Roman, 0x7E) => '\u{203E}',
+                        _ => b as char,
+                    };
+                    result.push(c);
+                }
+            },
+            Iso2022JpState::Katakana => match byte {
+                None => break,
+                Some(0x1B) => state = Iso2022JpState::EscapeStart,
+                Some(b @ 0x21..=0x5F) => {
+                    output = false;
+                    result.push(halfwidth_katakana(b - 0x21 + 0xA1));
+                }
+                Some(_) => {
+                    output = false;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            },
+            Iso2022JpState::LeadByte => match byte {
+                None => break,
+                Some(0x1B) => state = Iso2022JpState::EscapeStart,
+                Some(b @ 0x21..=0x7E) => {
+                    output = false;
+                    lead = b;
+                    state = Iso2022JpState::TrailByte;
+                }
+                Some(_) => {
+                    output = false;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            },
+            Iso2022JpState::TrailByte => match byte {
+                None => {
+                    state = Iso2022JpState::LeadByte;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+                Some(0x1B) => {
+                    state = Iso2022JpState::EscapeStart;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+                Some(b @ 0x21..=0x7E) => {
+                    state = Iso2022JpState::LeadByte;
+                    let pointer = (lead as usize - 0x21) * 94 + b as usize - 0x21;
+                    result.push(
+                        index_code_point(&JIS0208, pointer).unwrap_or(char::REPLACEMENT_CHARACTER),
+                    );
+                }
+                Some(_) => {
+                    state = Iso2022JpState::LeadByte;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            },
+            Iso2022JpState::EscapeStart => match byte {
+                Some(b @ (0x24 | 0x28)) => {
+                    lead = b;
+                    state = Iso2022JpState::Escape;
+                }
+                _ => {
+                    if let Some(b) = byte {
+                        queue.prepend(b);
+                    }
+                    output = false;
+                    state = output_state;
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            },
+            Iso2022JpState::Escape => {
+                let l = lead;
+                lead = 0;
+                let next = match (l, byte) {
+                    (0x28, Some(0x42)) => Some(Iso2022JpState::Ascii),
+                    (0x28, Some(0x4A)) => Some(Iso2022JpState::Roman),
+                    (0x28, Some(0x49)) => Some(Iso2022JpState::Katakana),
+                    (0x24, Some(0x40 | 0x42)) => Some(Iso2022JpState::LeadByte),
+                    _ => None,
+                };
+                match next {
+                    Some(next) => {
+                        state = next;
+                        output_state = next;
+                        // エスケープシーケンスが連続した場合はエラー
+                        if output {
+                            result.push(char::REPLACEMENT_CHARACTER);
+                        }
+                        output = true;
+                    }
+                    None => {
+                        if let Some(b) = byte {
+                            queue.prepend(b);
+                        }
+                        queue.prepend(l);
+                        output = false;
+                        state = output_state;
+                        result.push(char::REPLACEMENT_CHARACTER);
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_jis() {
+        assert_eq!(
+            decode_shift_jis(
+                b"\x93\xfa\x96{\x8c\xea\x82\xcc\x83e\x83L\x83X\x83g\x81A\xb6\xc0\xb6\xc5"
+            ),
+            "日本語のテキスト、ｶﾀｶﾅ"
+        );
+        // NECとIBMの拡張文字、外字
+        assert_eq!(decode_shift_jis(b"\x87\x40\xfa\x40\xf0\x40"), "①ⅰ\u{E000}");
+        // 2バイト目がASCIIの範囲で割り当てがない場合、そのバイトは読み直す
+        assert_eq!(decode_shift_jis(b"\x81\x20a\x82"), "\u{FFFD} a\u{FFFD}");
+    }
+
+    #[test]
+    fn test_euc_jp() {
+        assert_eq!(
+            decode_euc_jp(
+                b"\xc6\xfc\xcb\xdc\xb8\xec\xa4\xce\xa5\xc6\xa5\xad\xa5\xb9\xa5\xc8\xa1\xa2\x8e\xb6\x8e\xc0\x8e\xb6\x8e\xc5"
+            ),
+            "日本語のテキスト、ｶﾀｶﾅ"
+        );
+        // JIS X 0212の文字
+        assert_eq!(decode_euc_jp(b"\x8f\xb0\xa1"), "丂");
+        assert_eq!(decode_euc_jp(b"\xa4a\xa4"), "\u{FFFD}a\u{FFFD}");
+    }
+
+    #[test]
+    fn test_iso_2022_jp() {
+        assert_eq!(decode_iso_2022_jp(b"\x1b$BF|K\\8l\x1b(B abc"), "日本語 abc");
+        assert_eq!(
+            decode_iso_2022_jp(b"\x1b(I6@\x1b(J\\~\x1b(B\\"),
+            "ｶﾀ\u{A5}\u{203E}\\"
+        );
+        // 知らないエスケープシーケンスはそのまま出力し、連続したエスケープシーケンスはエラー
+        assert_eq!(decode_iso_2022_jp(b"\x1b(Za"), "\u{FFFD}(Za");
+        assert_eq!(decode_iso_2022_jp(b"\x1b(B\x1b(Ba"), "\u{FFFD}a");
+        assert_eq!(decode_iso_2022_jp(b"\x1b$BF"), "\u{FFFD}");
+    }
+}
@@ -0,0 +1,161 @@
+//! https://encoding.spec.whatwg.org/
+
+mod index;
+mod japanese;
+mod single_byte;
+pub mod sniff;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// 対応している文字エンコーディング
+/// https://encoding.spec.whatwg.org/#names-and-labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16Be,
+    Utf16Le,
+    ShiftJis,
+    EucJp,
+    Iso2022Jp,
+    Windows1252,
+}
+
+impl Encoding {
+    // "Shift_JIS"や"sjis"のようなラベルから文字エンコーディングを求める
+    // 対応していないラベルの場合はNone
+    /// https://encoding.spec.whatwg.org/#concept-encoding-get
+    pub fn for_label(label: &str) -> Option<Self> {
+        let label = label
+            .trim_matches(|c: char| c.is_ascii_whitespace())
+            .to_ascii_lowercase();
+        let encoding = match label.as_str() {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Encoding::Utf8,
+            "unicodefffe" | "utf-16be" => Encoding::Utf16Be,
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Encoding::Utf16Le,
+            "csshiftjis" | "ms932" | "ms_kanji" | "shift-jis" | "shift_jis" | "sjis"
+            | "windows-31j" | "x-sjis" => Encoding::ShiftJis,
+            "cseucpkdfmtjapanese" | "euc-jp" | "x-euc-jp" => Encoding::EucJp,
+            "csiso2022jp" | "iso-2022-jp" => Encoding::Iso2022Jp,
+            "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+            | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+            | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252" | "x-cp1252" => {
+                Encoding::Windows1252
+            }
+            _ => return None,
+        };
+        Some(encoding)
+    }
+
+    /// https://encoding.spec.whatwg.org/#concept-encoding-name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::ShiftJis => "Shift_JIS",
+            Encoding::EucJp => "EUC-JP",
+            Encoding::Iso2022Jp => "ISO-2022-JP",
+            Encoding::Windows1252 => "windows-1252",
+        }
+    }
+
+    // BOMを見ずにこの文字エンコーディングとしてデコードする
+    // デコードできないバイト列はU+FFFDに置き換える
+    /// https://encoding.spec.whatwg.org/#decode-without-bom
+    pub fn decode_without_bom(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Encoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+            Encoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            Encoding::ShiftJis => japanese::decode_shift_jis(bytes),
+            Encoding::EucJp => japanese::decode_euc_jp(bytes),
+            Encoding::Iso2022Jp => japanese::decode_iso_2022_jp(bytes),
+            Encoding::Windows1252 => single_byte::decode_windows_1252(bytes),
+        }
+    }
+}
+
+// 先頭のBOMから文字エンコーディングを求め、BOMの長さと一緒に返す
+/// https://encoding.spec.whatwg.org/#bom-sniff
+pub fn bom_sniff(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        Some((Encoding::Utf8, 3))
+    } else if bytes.starts_with(&[0xFE, 0xFF]) {
+        Some((Encoding::Utf16Be, 2))
+    } else if bytes.starts_with(&[0xFF, 0xFE]) {
+        Some((Encoding::Utf16Le, 2))
+    } else {
+        None
+    }
+}
+
+// BOMがあればそれに従い、なければfallbackとしてデコードする
+// 実際に使った文字エンコーディングも返す
+/// https://encoding.spec.whatwg.org/#decode
+pub fn decode(bytes: &[u8], fallback: Encoding) -> (String, Encoding) {
+    match bom_sniff(bytes) {
+        Some((encoding, length)) => (encoding.decode_without_bom(&bytes[length..]), encoding),
+        None => (fallback.decode_without_bom(bytes), fallback),
+    }
+}
+
+/// https://encoding.spec.whatwg.org/#shared-utf-16-decoder
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| to_unit([pair[0], pair[1]]))
+        .collect();
+    let mut result: String = char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    // 2バイトにそろわない末尾の1バイト
+    if bytes.len() % 2 == 1 {
+        result.push(char::REPLACEMENT_CHARACTER);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_label() {
+        assert_eq!(
+            Encoding::for_label(" Shift_JIS\t"),
+            Some(Encoding::ShiftJis)
+        );
+        assert_eq!(Encoding::for_label("windows-31j"), Some(Encoding::ShiftJis));
+        assert_eq!(Encoding::for_label("x-euc-jp"), Some(Encoding::EucJp));
+        assert_eq!(
+            Encoding::for_label("ISO-8859-1"),
+            Some(Encoding::Windows1252)
+        );
+        assert_eq!(Encoding::for_label("utf-16"), Some(Encoding::Utf16Le));
+        assert_eq!(Encoding::for_label("utf-7"), None);
+        assert_eq!(Encoding::EucJp.name(), "EUC-JP");
+    }
+
+    #[test]
+    fn test_decode_with_bom() {
+        assert_eq!(
+            decode(b"\xEF\xBB\xBFabc", Encoding::ShiftJis),
+            ("abc".into(), Encoding::Utf8)
+        );
+        assert_eq!(
+            decode(b"\xFF\xFEa\x00\x42\x30", Encoding::Utf8),
+            ("aあ".into(), Encoding::Utf16Le)
+        );
+        assert_eq!(
+            decode(b"\xFE\xFF\xD8\x3D\xDE\x00\x00", Encoding::Utf8),
+            ("😀\u{FFFD}".into(), Encoding::Utf16Be)
+        );
+        assert_eq!(
+            decode(b"caf\xE9", Encoding::Windows1252),
+            ("café".into(), Encoding::Windows1252)
+        );
+    }
+}
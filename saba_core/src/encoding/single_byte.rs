@@ -0,0 +1,35 @@
+//! https://encoding.spec.whatwg.org/#single-byte-decoder
+
+use alloc::string::String;
+
+// 0x80から0x9Fまでのバイトに対応する文字。それ以外のバイトはISO-8859-1と同じ
+/// https://encoding.spec.whatwg.org/index-windows-1252.txt
+const WINDOWS_1252_HIGH: [u16; 32] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039,
+    0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+];
+
+pub(crate) fn decode_windows_1252(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| match b {
+            0x80..=0x9F => char::from_u32(WINDOWS_1252_HIGH[(b - 0x80) as usize] as u32)
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+            _ => b as char,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_1252() {
+        assert_eq!(
+            decode_windows_1252(b"\x80 \x93quoted\x94 \x81 \xFF"),
+            "€ \u{201C}quoted\u{201D} \u{81} ÿ"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! https://html.spec.whatwg.org/multipage/parsing.html#determining-the-character-encoding
+
+use crate::encoding::bom_sniff;
+use crate::encoding::decode;
+use crate::encoding::Encoding;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// <meta>を探すのは先頭の1024バイトまで
+const PRESCAN_LENGTH: usize = 1024;
+
+// どこからも文字エンコーディングが分からない場合に使う。日本語の環境での既定値
+/// https://html.spec.whatwg.org/multipage/parsing.html#determining-the-character-encoding
+const DEFAULT_ENCODING: Encoding = Encoding::ShiftJis;
+
+// HTML文書の文字エンコーディングを決める
+// BOM、Content-Typeのcharset、<meta>の順に調べ、どれもなければバイト列から推測する
+/// https://html.spec.whatwg.org/multipage/parsing.html#encoding-sniffing-algorithm
+pub fn sniff_html(bytes: &[u8], transport_charset: Option<&str>) -> Encoding {
+    if let Some((encoding, _)) = bom_sniff(bytes) {
+        return encoding;
+    }
+    if let Some(encoding) = transport_charset.and_then(Encoding::for_label) {
+        return encoding;
+    }
+    if let Some(encoding) = prescan(&bytes[..bytes.len().min(PRESCAN_LENGTH)]) {
+        return encoding;
+    }
+    // ASCII以外の文字を含む正しいUTF-8であれば、UTF-8として扱う
+    if !bytes.is_ascii() && core::str::from_utf8(bytes).is_ok() {
+        return Encoding::Utf8;
+    }
+    DEFAULT_ENCODING
+}
+
+// 文字エンコーディングを決めて、HTML文書を文字列にデコードする
+pub fn decode_html(bytes: &[u8], transport_charset: Option<&str>) -> String {
+    decode(bytes, sniff_html(bytes, transport_charset)).0
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, 0x09 | 0x0A | 0x0C | 0x0D | 0x20)
+}
+
+fn starts_with_ignore_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+// バイト列を1バイトずつ文字にしたもの
+fn to_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| *b as char).collect()
+}
+
+// <meta charset>や<meta http-equiv="Content-Type">から文字エンコーディングを探す
+/// https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding
+fn prescan(bytes: &[u8]) -> Option<Encoding> {
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let rest = &bytes[pos..];
+        if rest.starts_with(b"<!--") {
+            // "<!-->"のように"--"を共有する場合も閉じたものとして扱う
+            pos += 2 + find(&rest[2..], b"-->")? + 3;
+            continue;
+        }
+
+        if starts_with_ignore_case(rest, b"<meta")
+            && rest.get(5).is_some_and(|b| is_whitespace(*b) || *b == b'/')
+        {
+            pos += 6;
+            if let Some(encoding) = prescan_meta(bytes, &mut pos) {
+                return Some(encoding);
+            }
+            continue;
+        }
+
+        let is_tag = match rest {
+            [b'<', b, ..] if b.is_ascii_alphabetic() => true,
+            [b'<', b'/', b, ..] if b.is_ascii_alphabetic() => true,
+            _ => false,
+        };
+        if is_tag {
+            // タグ名を読み飛ばし、属性を読み捨てる
+            while pos < bytes.len() && !is_whitespace(bytes[pos]) && bytes[pos] != b'>' {
+                pos += 1;
+            }
+            while get_attribute(bytes, &mut pos).is_some() {}
+            pos += 1;
+            continue;
+        }
+
+        if rest.starts_with(b"<!") || rest.starts_with(b"</") || rest.starts_with(b"<?") {
+            pos += find(rest, b">")? + 1;
+            continue;
+        }
+
+        pos += 1;
+    }
+    None
+}
+
+// <meta>の属性を読み、文字エンコーディングが指定されていれば返す
+fn prescan_meta(bytes: &[u8], pos: &mut usize) -> Option<Encoding> {
+    let mut names: Vec<String> = Vec::new();
+    let mut got_pragma = false;
+    let mut need_pragma = None;
+    let mut charset = None;
+
+    while let Some((name, value)) = get_attribute(bytes, pos) {
+        if names.contains(&name) {
+            continue;
+        }
+        match name.as_str() {
+            "http-equiv" => {
+                if value == "content-type" {
+                    got_pragma = true;
+                }
+            }
+            "content" => {
+                let encoding = extract_charset(&value).and_then(|l| Encoding::for_label(&l));
+                if let (Some(encoding), None) = (encoding, charset) {
+                    charset = Some(Some(encoding));
+                    need_pragma = Some(true);
+                }
+            }
+            "charset" => {
+                charset = Some(Encoding::for_label(&value));
+                need_pragma = Some(false);
+            }
+            _ => {}
+        }
+        names.push(name);
+    }
+
+    match need_pragma {
+        None => return None,
+        Some(true) if !got_pragma => return None,
+        _ => {}
+    }
+    // <meta>はデコードした後の文字で書かれているので、UTF-16と書かれていてもUTF-8として扱う
+    match charset?? {
+        Encoding::Utf16Be | Encoding::Utf16Le => Some(Encoding::Utf8),
+        encoding => Some(encoding),
+    }
+}
+
+// 属性の名前と値を1つ読む。タグの終わりに着いた場合はNone
+// 名前と値は小文字にして返す
+/// https://html.spec.whatwg.org/multipage/parsing.html#concept-get-attributes-when-sniffing
+fn get_attribute(bytes: &[u8], pos: &mut usize) -> Option<(String, String)> {
+    while *pos < bytes.len() && (is_whitespace(bytes[*pos]) || bytes[*pos] == b'/') {
+        *pos += 1;
+    }
+    if *bytes.get(*pos)? == b'>' {
+        return None;
+    }
+
+    let mut name = Vec::new();
+    loop {
+        let b = *bytes.get(*pos)?;
+        if b == b'=' && !name.is_empty() {
+            *pos += 1;
+            break;
+        }
+        if is_whitespace(b) {
+            // 名前の後の空白を読み飛ばし、"="が続かなければ値のない属性
+            while is_whitespace(*bytes.get(*pos)?) {
+                *pos += 1;
+            }
+            if bytes[*pos] != b'=' {
+                return Some((to_string(&name), String::new()));
+            }
+            *pos += 1;
+            break;
+        }
+        if b == b'/' || b == b'>' {
+            return Some((to_string(&name), String::new()));
+        }
+        name.push(b.to_ascii_lowercase());
+        *pos += 1;
+    }
+
+    while is_whitespace(*bytes.get(*pos)?) {
+        *pos += 1;
+    }
+
+    let mut value = Vec::new();
+    let b = *bytes.get(*pos)?;
+    if b == b'"' || b == b'\'' {
+        loop {
+            *pos += 1;
+            let c = *bytes.get(*pos)?;
+            if c == b {
+                *pos += 1;
+                return Some((to_string(&name), to_string(&value)));
+            }
+            value.push(c.to_ascii_lowercase());
+        }
+    }
+    if b == b'>' {
+        return Some((to_string(&name), String::new()));
+    }
+    loop {
+        let c = *bytes.get(*pos)?;
+        if is_whitespace(c) || c == b'>' {
+            return Some((to_string(&name), to_string(&value)));
+        }
+        value.push(c.to_ascii_lowercase());
+        *pos += 1;
+    }
+}
+
+// "text/html; charset=Shift_JIS"のようなcontent属性からcharsetの値を取り出す
+/// https://html.spec.whatwg.org/multipage/urls-and-fetching.html#algorithm-for-extracting-a-character-encoding-from-a-meta-element
+fn extract_charset(content: &str) -> Option<String> {
+    let bytes = content.as_bytes();
+    let mut pos = 0;
+    loop {
+        pos += find_ignore_case(&bytes[pos..], b"charset")? + 7;
+        while bytes.get(pos).is_some_and(|b| is_whitespace(*b)) {
+            pos += 1;
+        }
+        if bytes.get(pos) == Some(&b'=') {
+            pos += 1;
+            break;
+        }
+    }
+    while bytes.get(pos).is_some_and(|b| is_whitespace(*b)) {
+        pos += 1;
+    }
+
+    let rest = &content[pos..];
+    match rest.as_bytes().first()? {
+        q @ (b'"' | b'\'') => {
+            let end = rest[1..].find(*q as char)?;
+            Some(rest[1..1 + end].into())
+        }
+        _ => {
+            let end = rest
+                .find(|c: char| c == ';' || (c.is_ascii() && is_whitespace(c as u8)))
+                .unwrap_or(rest.len());
+            Some(rest[..end].into())
+        }
+    }
+}
+
+fn find(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes.windows(needle.len()).position(|w| w == needle)
+}
+
+fn find_ignore_case(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_order() {
+        let html = b"<meta charset=euc-jp>";
+        assert_eq!(
+            sniff_html(b"\xEF\xBB\xBF<p>", Some("EUC-JP")),
+            Encoding::Utf8
+        );
+        assert_eq!(sniff_html(html, Some("Shift_JIS")), Encoding::ShiftJis);
+        assert_eq!(sniff_html(html, Some("unknown")), Encoding::EucJp);
+        assert_eq!(sniff_html("<p>日本語".as_bytes(), None), Encoding::Utf8);
+        assert_eq!(sniff_html(b"<p>\x93\xfa\x96{", None), Encoding::ShiftJis);
+    }
+
+    #[test]
+    fn test_prescan() {
+        assert_eq!(
+            prescan(b"<!DOCTYPE html><html><head><META CHARSET=\"Shift_JIS\">"),
+            Some(Encoding::ShiftJis)
+        );
+        assert_eq!(
+            prescan(b"<meta http-equiv=Content-Type content='text/html; charset=euc-jp'>"),
+            Some(Encoding::EucJp)
+        );
+        // http-equivがない場合は使わない
+        assert_eq!(prescan(b"<meta content='text/html; charset=euc-jp'>"), None);
+        assert_eq!(prescan(b"<meta charset=utf-16le>"), Some(Encoding::Utf8));
+        // コメントや属性の値の中は読み飛ばす
+        assert_eq!(
+            prescan(b"<!-- <meta charset=euc-jp> --><div title='<meta charset=euc-jp>'><meta charset=iso-2022-jp>"),
+            Some(Encoding::Iso2022Jp)
+        );
+        assert_eq!(prescan(b"<!-- <meta charset=euc-jp>"), None);
+    }
+
+    #[test]
+    fn test_extract_charset() {
+        assert_eq!(
+            extract_charset("text/html; charset=Shift_JIS"),
+            Some("Shift_JIS".into())
+        );
+        assert_eq!(
+            extract_charset("text/html; charsetx; charset = \"euc-jp\" ; x"),
+            Some("euc-jp".into())
+        );
+        assert_eq!(extract_charset("charset='utf-8"), None);
+        assert_eq!(extract_charset("text/html"), None);
+    }
+
+    #[test]
+    fn test_decode_html() {
+        let html = b"<meta charset=shift_jis><p>\x93\xfa\x96{\x8c\xea</p>";
+        assert_eq!(
+            decode_html(html, None),
+            "<meta charset=shift_jis><p>日本語</p>"
+        );
+    }
+}
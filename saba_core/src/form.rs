@@ -0,0 +1,388 @@
+//! https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#form-submission-algorithm
+
+use crate::http::HttpRequest;
+use crate::http::Method;
+use crate::url::search_params::UrlSearchParams;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// form要素のenctype属性
+/// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#attr-fs-enctype
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Enctype {
+    Urlencoded,
+    Multipart,
+}
+
+impl Enctype {
+    // 知らない値や空の値はapplication/x-www-form-urlencodedとして扱う
+    pub fn from_attribute(value: &str) -> Self {
+        if value.trim().eq_ignore_ascii_case("multipart/form-data") {
+            Enctype::Multipart
+        } else {
+            Enctype::Urlencoded
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Enctype::Urlencoded => "application/x-www-form-urlencoded",
+            Enctype::Multipart => "multipart/form-data",
+        }
+    }
+}
+
+/// input type="file"で選ばれたファイル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormFile {
+    filename: String,
+    content_type: String,
+    body: Vec<u8>,
+}
+
+impl FormFile {
+    // content_typeに改行などの表示できない文字が含まれている場合は、種類が分からないファイルとして扱う
+    // そのままmultipart/form-dataのヘッダーに書くと、別のヘッダーやboundaryを差し込めてしまう
+    /// https://w3c.github.io/FileAPI/#dfn-type
+    pub fn new(filename: &str, content_type: &str, body: Vec<u8>) -> Self {
+        let content_type = if content_type.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+            content_type.to_ascii_lowercase()
+        } else {
+            String::new()
+        };
+        Self {
+            filename: filename.to_string(),
+            content_type,
+            body,
+        }
+    }
+
+    pub fn filename(&self) -> String {
+        self.filename.clone()
+    }
+
+    pub fn content_type(&self) -> String {
+        self.content_type.clone()
+    }
+
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+}
+
+/// 送信する値。文字列かファイルのどちらか
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormValue {
+    Text(String),
+    File(FormFile),
+}
+
+/// フォームから送信する名前と値の組のリスト（entry list）
+/// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#entry-list
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FormData {
+    entries: Vec<(String, FormValue)>,
+}
+
+impl FormData {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> Vec<(String, FormValue)> {
+        self.entries.clone()
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.entries
+            .push((name.to_string(), FormValue::Text(value.to_string())));
+    }
+
+    pub fn append_file(&mut self, name: &str, file: FormFile) {
+        self.entries.push((name.to_string(), FormValue::File(file)));
+    }
+
+    // application/x-www-form-urlencodedの名前と値の組にする
+    // ファイルはファイル名だけを送り、改行はCRLFにそろえる
+    /// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#convert-to-a-list-of-name-value-pairs
+    pub fn to_search_params(&self) -> UrlSearchParams {
+        let mut params = UrlSearchParams::new();
+        for (name, value) in &self.entries {
+            let value = match value {
+                FormValue::Text(text) => normalize_newlines(text),
+                FormValue::File(file) => file.filename(),
+            };
+            params.append(&normalize_newlines(name), &value);
+        }
+        params
+    }
+
+    // "a=1&b=2"のような文字列
+    pub fn to_urlencoded(&self) -> String {
+        self.to_search_params().to_string()
+    }
+
+    // multipart/form-dataのボディ。boundaryは値の中に現れない文字列でなければならない
+    /// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data
+    pub fn to_multipart(&self, boundary: &str) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (name, value) in &self.entries {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            let name = escape_field_name(&normalize_newlines(name));
+            match value {
+                FormValue::Text(text) => {
+                    body.extend_from_slice(
+                        format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", name)
+                            .as_bytes(),
+                    );
+                    body.extend_from_slice(normalize_newlines(text).as_bytes());
+                }
+                FormValue::File(file) => {
+                    // 種類が分からないファイルはバイナリとして送る
+                    let content_type = if file.content_type.is_empty() {
+                        "application/octet-stream"
+                    } else {
+                        &file.content_type
+                    };
+                    body.extend_from_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                            name,
+                            escape_field_name(&file.filename),
+                            content_type
+                        )
+                        .as_bytes(),
+                    );
+                    body.extend_from_slice(&file.body);
+                }
+            }
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+        body
+    }
+
+    // 値の中に現れないboundaryを作る
+    // 乱数が使えないので、値から計算したハッシュを使い、現れた場合は作り直す
+    pub fn multipart_boundary(&self) -> String {
+        let mut seed = FNV_OFFSET_BASIS;
+        for (name, value) in &self.entries {
+            seed = fnv1a(seed, name.as_bytes());
+            seed = match value {
+                FormValue::Text(text) => fnv1a(seed, text.as_bytes()),
+                FormValue::File(file) => fnv1a(fnv1a(seed, file.filename.as_bytes()), &file.body),
+            };
+        }
+
+        loop {
+            let boundary = format!("----SabaFormBoundary{:016x}", seed);
+            if !self.contains_bytes(boundary.as_bytes()) {
+                return boundary;
+            }
+            seed = fnv1a(seed, boundary.as_bytes());
+        }
+    }
+
+    // フォームを送信するリクエストと、その送り先のURLを作る
+    // GETの場合は値をURLのクエリにし、POSTの場合はenctypeに従ってボディにする
+    /// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#form-submission-algorithm
+    pub fn to_request(&self, action: &Url, method: Method, enctype: Enctype) -> (Url, HttpRequest) {
+        if method != Method::Post {
+            // 送信先のクエリは置き換える
+            // https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#submit-mutate-action
+            let mut url = action.clone();
+            url.set_search_params(&self.to_search_params());
+            let request = HttpRequest::for_url(Method::Get, &url);
+            return (url, request);
+        }
+
+        let (content_type, body) = match enctype {
+            Enctype::Urlencoded => (
+                enctype.mime_type().to_string(),
+                self.to_urlencoded().into_bytes(),
+            ),
+            Enctype::Multipart => {
+                let boundary = self.multipart_boundary();
+                (
+                    format!("{}; boundary={}", enctype.mime_type(), boundary),
+                    self.to_multipart(&boundary),
+                )
+            }
+        };
+        let request = HttpRequest::for_url(Method::Post, action)
+            .with_header("Content-Type", &content_type)
+            .with_body(body);
+        (action.clone(), request)
+    }
+
+    fn contains_bytes(&self, needle: &[u8]) -> bool {
+        let contains = |haystack: &[u8]| haystack.windows(needle.len()).any(|w| w == needle);
+        self.entries.iter().any(|(name, value)| {
+            contains(name.as_bytes())
+                || match value {
+                    FormValue::Text(text) => contains(text.as_bytes()),
+                    FormValue::File(file) => {
+                        contains(file.filename.as_bytes()) || contains(&file.body)
+                    }
+                }
+        })
+    }
+}
+
+// 単独のCRやLFをCRLFにする
+/// https://infra.spec.whatwg.org/#normalize-newlines
+fn normalize_newlines(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                out.push_str("\r\n");
+            }
+            '\n' => out.push_str("\r\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Content-Dispositionの名前とファイル名では、改行と'"'をパーセントエンコードする
+/// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart/form-data-encoding-algorithm
+fn escape_field_name(name: &str) -> String {
+    name.replace('\n', "%0A")
+        .replace('\r', "%0D")
+        .replace('"', "%22")
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// http://www.isthe.com/chongo/tech/comp/fnv/index.html#FNV-1a
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::url;
+
+    fn form() -> FormData {
+        let mut form = FormData::new();
+        form.append("q", "saba browser");
+        form.append("memo", "line1\nline2");
+        form.append_file("file", FormFile::new("a\"b.txt", "", b"hello".to_vec()));
+        form
+    }
+
+    #[test]
+    fn test_urlencoded() {
+        assert_eq!(
+            form().to_urlencoded(),
+            "q=saba+browser&memo=line1%0D%0Aline2&file=a%22b.txt"
+        );
+    }
+
+    #[test]
+    fn test_multipart() {
+        let body = form().to_multipart("XyZ");
+        assert_eq!(
+            String::from_utf8(body).expect("invalid utf-8"),
+            "--XyZ\r\n\
+             Content-Disposition: form-data; name=\"q\"\r\n\r\n\
+             saba browser\r\n\
+             --XyZ\r\n\
+             Content-Disposition: form-data; name=\"memo\"\r\n\r\n\
+             line1\r\nline2\r\n\
+             --XyZ\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"a%22b.txt\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             hello\r\n\
+             --XyZ--\r\n"
+        );
+    }
+
+    #[test]
+    fn test_file_content_type() {
+        let file = FormFile::new("a.txt", "Text/Plain", b"a".to_vec());
+        assert_eq!(file.content_type(), "text/plain");
+
+        // 改行でヘッダーを差し込むことはできない
+        let file = FormFile::new(
+            "a.txt",
+            "text/plain\r\nX-Injected: 1\r\n\r\n--XyZ",
+            b"a".to_vec(),
+        );
+        assert_eq!(file.content_type(), "");
+        let mut form = FormData::new();
+        form.append_file("file", file);
+        let body = String::from_utf8(form.to_multipart("XyZ")).expect("invalid utf-8");
+        assert!(!body.contains("X-Injected"));
+        assert!(body.contains("Content-Type: application/octet-stream\r\n"));
+    }
+
+    #[test]
+    fn test_boundary_not_in_values() {
+        let form = form();
+        let boundary = form.multipart_boundary();
+        assert!(boundary.starts_with("----SabaFormBoundary"));
+        assert_eq!(boundary, form.multipart_boundary());
+
+        // 値にboundaryが含まれている場合は別のものを作る
+        let mut tricky = form.clone();
+        tricky.append("x", &boundary);
+        let other = tricky.multipart_boundary();
+        assert!(!tricky.contains_bytes(other.as_bytes()));
+    }
+
+    #[test]
+    fn test_to_request() {
+        let action = url("http://example.com/search?old=1#top");
+        let (target, request) = form().to_request(&action, Method::Get, Enctype::Multipart);
+        assert_eq!(
+            target.to_string(),
+            "http://example.com/search?q=saba+browser&memo=line1%0D%0Aline2&file=a%22b.txt#top"
+        );
+        assert_eq!(request.method(), Method::Get);
+        assert!(request.body().is_empty());
+
+        let (target, request) = form().to_request(&action, Method::Post, Enctype::Urlencoded);
+        assert_eq!(target, action);
+        assert_eq!(request.target(), "/search?old=1");
+        assert_eq!(
+            request.headers().get("Content-Type"),
+            Some("application/x-www-form-urlencoded".to_string())
+        );
+        assert_eq!(request.body(), form().to_urlencoded().into_bytes());
+
+        let (_, request) = form().to_request(&action, Method::Post, Enctype::Multipart);
+        let content_type = request.headers().get("Content-Type").unwrap_or_default();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .expect("missing boundary");
+        assert_eq!(request.body(), form().to_multipart(boundary));
+        assert_eq!(
+            Enctype::from_attribute(" Multipart/Form-Data "),
+            Enctype::Multipart
+        );
+    }
+}
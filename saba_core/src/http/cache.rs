@@ -0,0 +1,622 @@
+//! https://httpwg.org/specs/rfc9111.html
+
+use crate::http::date::parse_http_date;
+use crate::http::headers::HeaderMap;
+use crate::http::HttpRequest;
+use crate::http::HttpResponse;
+use crate::http::Method;
+use crate::url::Url;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+// 保存しておくレスポンスの数。超えた場合は古いものから捨てる
+pub const MAX_ENTRIES: usize = 64;
+
+// Last-Modifiedから推測する有効期間の上限（1日）
+/// https://httpwg.org/specs/rfc9111.html#heuristic.freshness
+const MAX_HEURISTIC_LIFETIME: u64 = 24 * 60 * 60;
+
+// 304で受け取っても、保存したレスポンスのものを残すヘッダー
+/// https://httpwg.org/specs/rfc9111.html#update
+const KEEP_ON_UPDATE: [&str; 5] = [
+    "Content-Length",
+    "Content-Encoding",
+    "Transfer-Encoding",
+    "Connection",
+    "Keep-Alive",
+];
+
+/// Cache-Controlヘッダーの指示のうち、ブラウザのキャッシュが使うもの
+/// https://httpwg.org/specs/rfc9111.html#field.cache-control
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheControl {
+    max_age: Option<u64>,
+    no_cache: bool,
+    no_store: bool,
+    must_revalidate: bool,
+    public: bool,
+}
+
+impl CacheControl {
+    // 指示の名前は大文字と小文字を区別しない。知らない指示は無視する
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut cache_control = Self::default();
+        for directive in headers.get_list("Cache-Control") {
+            let (name, value) = match directive.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+                None => (directive.as_str(), None),
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "max-age" => {
+                    // 大きすぎる値は上限に丸める
+                    cache_control.max_age = value
+                        .filter(|v| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit()))
+                        .map(|v| v.parse::<u64>().unwrap_or(u64::MAX));
+                }
+                "no-cache" => cache_control.no_cache = true,
+                "no-store" => cache_control.no_store = true,
+                "must-revalidate" => cache_control.must_revalidate = true,
+                "public" => cache_control.public = true,
+                _ => {}
+            }
+        }
+        cache_control
+    }
+
+    pub fn max_age(&self) -> Option<u64> {
+        self.max_age
+    }
+
+    pub fn no_cache(&self) -> bool {
+        self.no_cache
+    }
+
+    pub fn no_store(&self) -> bool {
+        self.no_store
+    }
+
+    pub fn must_revalidate(&self) -> bool {
+        self.must_revalidate
+    }
+
+    pub fn public(&self) -> bool {
+        self.public
+    }
+}
+
+/// キャッシュを探した結果
+#[derive(Debug, Clone)]
+pub enum CacheLookup {
+    // そのまま使えるレスポンス
+    Fresh(HttpResponse),
+    // 期限切れなので、このヘッダーを付けてサーバーに確認する
+    // 304が返ってきたらHttpCache::update_from_not_modifiedで保存したレスポンスを取り出す
+    Stale(HeaderMap),
+    Miss,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    key: String,
+    // Varyヘッダーで指定されたリクエストヘッダーの値
+    vary: Vec<(String, Option<String>)>,
+    response: HttpResponse,
+    // リクエストを送った時刻とレスポンスを受け取った時刻（1970年1月1日からの秒数）
+    request_time: u64,
+    response_time: u64,
+}
+
+impl CacheEntry {
+    fn matches(&self, key: &str, request: &HttpRequest) -> bool {
+        let headers = request.headers();
+        self.key == key
+            && self
+                .vary
+                .iter()
+                .all(|(name, value)| normalize(headers.get(name)) == *value)
+    }
+
+    // レスポンスが作られてからの経過時間
+    /// https://httpwg.org/specs/rfc9111.html#age.calculations
+    fn current_age(&self, now: u64) -> u64 {
+        let headers = self.response.headers();
+        let date = date_value(&headers).unwrap_or(self.response_time);
+        let age = headers
+            .get("Age")
+            .and_then(|a| a.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+
+        let apparent_age = self.response_time.saturating_sub(date);
+        let response_delay = self.response_time.saturating_sub(self.request_time);
+        let corrected_age = age.saturating_add(response_delay);
+        let initial_age = apparent_age.max(corrected_age);
+        let resident_time = now.saturating_sub(self.response_time);
+        initial_age.saturating_add(resident_time)
+    }
+
+    /// https://httpwg.org/specs/rfc9111.html#calculating.freshness.lifetime
+    fn freshness_lifetime(&self) -> u64 {
+        let headers = self.response.headers();
+        if let Some(max_age) = CacheControl::from_headers(&headers).max_age() {
+            return max_age;
+        }
+        let date = date_value(&headers).unwrap_or(self.response_time);
+        if let Some(expires) = headers.get("Expires") {
+            // 解析できないExpiresは期限切れとして扱う
+            return parse_http_date(&expires)
+                .map(|expires| expires.saturating_sub(date))
+                .unwrap_or(0);
+        }
+
+        // 期限の指定がなければ、最後に更新されてからの期間の10%を有効期間とみなす
+        let last_modified = headers
+            .get("Last-Modified")
+            .and_then(|l| parse_http_date(&l));
+        match last_modified {
+            Some(last_modified) if is_heuristically_cacheable(self.response.status_code()) => {
+                (date.saturating_sub(last_modified) / 10).min(MAX_HEURISTIC_LIFETIME)
+            }
+            _ => 0,
+        }
+    }
+
+    // 保存したレスポンスを確かめるためのヘッダー
+    /// https://httpwg.org/specs/rfc9111.html#validation.sent
+    fn validators(&self) -> HeaderMap {
+        let headers = self.response.headers();
+        let mut validators = HeaderMap::new();
+        if let Some(etag) = headers.get("ETag") {
+            validators.append("If-None-Match", &etag);
+        }
+        if let Some(last_modified) = headers.get("Last-Modified") {
+            validators.append("If-Modified-Since", &last_modified);
+        }
+        validators
+    }
+}
+
+/// GETリクエストへのレスポンスを、URLごとに保存しておく
+/// 時刻は1970年1月1日からの秒数で受け取る
+#[derive(Debug, Clone, Default)]
+pub struct HttpCache {
+    // 保存した順に並ぶ
+    entries: Vec<CacheEntry>,
+}
+
+impl HttpCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    // 保存したレスポンスを探す
+    // 使えるものがあればAgeヘッダーを付けて返し、期限切れであれば確認のためのヘッダーを返す
+    // 現在時刻が分からない場合は、期限内かどうか判断できないので期限切れとして扱う
+    /// https://httpwg.org/specs/rfc9111.html#constructing.responses.from.caches
+    pub fn lookup(&self, url: &Url, request: &HttpRequest, now: Option<u64>) -> CacheLookup {
+        // 範囲を指定したリクエストには、保存したレスポンス全体を返せないのでサーバーに送る
+        if request.method() != Method::Get || request.headers().contains("Range") {
+            return CacheLookup::Miss;
+        }
+        let key = cache_key(url);
+        let entry = match self.entries.iter().rev().find(|e| e.matches(&key, request)) {
+            Some(entry) => entry,
+            None => return CacheLookup::Miss,
+        };
+
+        let request_headers = request.headers();
+        let request_cache_control = CacheControl::from_headers(&request_headers);
+        // Cache-Controlがない場合は、古い"Pragma: no-cache"も同じ意味として扱う
+        let request_no_cache = request_cache_control.no_cache()
+            || request_cache_control.max_age() == Some(0)
+            || (!request_headers.contains("Cache-Control")
+                && request_headers
+                    .get_list("Pragma")
+                    .iter()
+                    .any(|p| p.eq_ignore_ascii_case("no-cache")));
+        let response_cache_control = CacheControl::from_headers(&entry.response.headers());
+
+        if let Some(now) = now {
+            let age = entry.current_age(now);
+            let fresh = entry.freshness_lifetime() > age
+                && !response_cache_control.no_cache()
+                && !request_no_cache
+                && request_cache_control
+                    .max_age()
+                    .map_or(true, |max_age| age <= max_age);
+            if fresh {
+                let mut headers = entry.response.headers();
+                headers.set("Age", &age.to_string());
+                return CacheLookup::Fresh(with_headers(&entry.response, headers));
+            }
+        }
+
+        let validators = entry.validators();
+        if validators.is_empty() {
+            CacheLookup::Miss
+        } else {
+            CacheLookup::Stale(validators)
+        }
+    }
+
+    // レスポンスを保存できる場合は保存してtrueを返す
+    /// https://httpwg.org/specs/rfc9111.html#response.cacheability
+    pub fn store(
+        &mut self,
+        url: &Url,
+        request: &HttpRequest,
+        response: &HttpResponse,
+        request_time: u64,
+        response_time: u64,
+    ) -> bool {
+        // 一部分だけのレスポンスは、URL全体のレスポンスとして保存できない
+        // 304はボディを持たないので、保存したレスポンスの更新にだけ使う
+        let request_headers = request.headers();
+        if request.method() != Method::Get
+            || request_headers.contains("Range")
+            || matches!(response.status_code(), 206 | 304)
+        {
+            return false;
+        }
+        let headers = response.headers();
+        let cache_control = CacheControl::from_headers(&headers);
+        if cache_control.no_store() || CacheControl::from_headers(&request_headers).no_store() {
+            return false;
+        }
+        // 認証が必要なレスポンスは、明示的に許可されている場合だけ保存する
+        if request_headers.contains("Authorization")
+            && !cache_control.public()
+            && !cache_control.must_revalidate()
+        {
+            return false;
+        }
+        // 期限の指定がなく、既定で保存できるステータスでもない場合は保存しない
+        let explicit = cache_control.max_age().is_some() || headers.contains("Expires");
+        if !explicit && !is_heuristically_cacheable(response.status_code()) {
+            return false;
+        }
+
+        let vary_names = headers.get_list("Vary");
+        if vary_names.iter().any(|v| v == "*") {
+            return false;
+        }
+        let vary = vary_names
+            .iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| (name.to_string(), normalize(request_headers.get(name))))
+            .collect();
+
+        let entry = CacheEntry {
+            key: cache_key(url),
+            vary,
+            response: response.clone(),
+            request_time,
+            response_time,
+        };
+        self.entries
+            .retain(|e| !(e.key == entry.key && e.vary == entry.vary));
+        self.entries.push(entry);
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        true
+    }
+
+    // 304 Not Modifiedを受け取ったときに、保存したレスポンスのヘッダーを更新して返す
+    // 対応するレスポンスが保存されていない場合はNone
+    /// https://httpwg.org/specs/rfc9111.html#freshening.responses
+    pub fn update_from_not_modified(
+        &mut self,
+        url: &Url,
+        request: &HttpRequest,
+        not_modified: &HttpResponse,
+        request_time: u64,
+        response_time: u64,
+    ) -> Option<HttpResponse> {
+        let key = cache_key(url);
+        let new_headers = not_modified.headers();
+        let etag = new_headers.get("ETag");
+        let entry = self.entries.iter_mut().rev().find(|e| {
+            e.matches(&key, request)
+                && etag.as_ref().map_or(true, |etag| {
+                    e.response.headers().get("ETag").as_ref() == Some(etag)
+                })
+        })?;
+
+        let mut headers = entry.response.headers();
+        for header in new_headers.iter() {
+            let name = header.name();
+            if KEEP_ON_UPDATE.iter().any(|k| k.eq_ignore_ascii_case(&name)) {
+                continue;
+            }
+            headers.set(&name, &header.value());
+        }
+        entry.response = with_headers(&entry.response, headers);
+        entry.request_time = request_time;
+        entry.response_time = response_time;
+        Some(entry.response.clone())
+    }
+
+    // POSTなどでサーバーの状態が変わった後は、そのURLの保存したレスポンスを捨てる
+    /// https://httpwg.org/specs/rfc9111.html#invalidation
+    pub fn invalidate(&mut self, url: &Url) {
+        let key = cache_key(url);
+        self.entries.retain(|e| e.key != key);
+    }
+}
+
+// フラグメントはサーバーに送らないので、キャッシュのキーにも含めない
+fn cache_key(url: &Url) -> String {
+    url.without_fragment().to_string()
+}
+
+fn date_value(headers: &HeaderMap) -> Option<u64> {
+    headers.get("Date").and_then(|d| parse_http_date(&d))
+}
+
+// Varyで比べるヘッダーの値。前後の空白の違いは無視する
+fn normalize(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string())
+}
+
+fn with_headers(response: &HttpResponse, headers: HeaderMap) -> HttpResponse {
+    HttpResponse::from_parts(
+        response.version(),
+        response.status_code(),
+        response.reason(),
+        headers,
+        response.body(),
+    )
+}
+
+// 期限の指定がなくても保存してよいステータス
+/// https://httpwg.org/specs/rfc9110.html#overview.of.status.codes
+fn is_heuristically_cacheable(status_code: u32) -> bool {
+    matches!(
+        status_code,
+        200 | 203 | 204 | 300 | 301 | 308 | 404 | 405 | 410 | 414 | 501
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::range::ByteRange;
+    use crate::test_helpers::response;
+    use crate::test_helpers::response_with_body;
+    use crate::test_helpers::url;
+
+    // "Thu, 01 Jan 1970 00:16:40 GMT"は1000秒
+    const DATE: &str = "Thu, 01 Jan 1970 00:16:40 GMT";
+
+    #[test]
+    fn test_cache_control() {
+        let mut headers = HeaderMap::new();
+        headers.append("Cache-Control", "Max-Age=\"60\", no-cache");
+        headers.append("cache-control", "private, no-store, foo=bar");
+        let cache_control = CacheControl::from_headers(&headers);
+        assert_eq!(cache_control.max_age(), Some(60));
+        assert!(cache_control.no_cache());
+        assert!(cache_control.no_store());
+        assert!(!cache_control.public());
+    }
+
+    #[test]
+    fn test_fresh_and_stale() {
+        let mut cache = HttpCache::new();
+        let page = url("http://example.com/index.html#top");
+        let request = HttpRequest::for_url(Method::Get, &page);
+        let stored = response_with_body(
+            200,
+            &[
+                ("Date", DATE),
+                ("Cache-Control", "max-age=60"),
+                ("ETag", "\"v1\""),
+            ],
+            b"hello",
+        );
+        assert!(cache.store(&page, &request, &stored, 1000, 1000));
+
+        let other_fragment = url("http://example.com/index.html");
+        match cache.lookup(&other_fragment, &request, Some(1030)) {
+            CacheLookup::Fresh(response) => {
+                assert_eq!(response.body(), b"hello".to_vec());
+                assert_eq!(response.headers().get("Age"), Some("30".to_string()));
+            }
+            e => panic!("unexpected lookup result {:?}", e),
+        }
+
+        match cache.lookup(&page, &request, Some(1061)) {
+            CacheLookup::Stale(validators) => {
+                assert_eq!(validators.get("If-None-Match"), Some("\"v1\"".to_string()));
+            }
+            e => panic!("unexpected lookup result {:?}", e),
+        }
+
+        // リクエストでno-cacheを指定した場合も確認が必要
+        let no_cache = HttpRequest::for_url(Method::Get, &page).with_header("Pragma", "no-cache");
+        assert!(matches!(
+            cache.lookup(&page, &no_cache, Some(1000)),
+            CacheLookup::Stale(_)
+        ));
+
+        // 時刻が分からない場合は、期限内でも確認が必要
+        assert!(matches!(
+            cache.lookup(&page, &request, None),
+            CacheLookup::Stale(_)
+        ));
+    }
+
+    #[test]
+    fn test_expires_and_heuristic() {
+        let mut cache = HttpCache::new();
+        let page = url("http://example.com/a");
+        let request = HttpRequest::for_url(Method::Get, &page);
+        let stored = response_with_body(
+            200,
+            &[("Date", DATE), ("Expires", "Thu, 01 Jan 1970 00:17:40 GMT")],
+            b"a",
+        );
+        cache.store(&page, &request, &stored, 1000, 1000);
+        assert!(matches!(
+            cache.lookup(&page, &request, Some(1059)),
+            CacheLookup::Fresh(_)
+        ));
+        // 確認に使うヘッダーがなければ保存していないのと同じ
+        assert!(matches!(
+            cache.lookup(&page, &request, Some(1060)),
+            CacheLookup::Miss
+        ));
+
+        // 最後の更新から1000秒経っているので、100秒間は新しいとみなす
+        let stored = response_with_body(
+            200,
+            &[
+                ("Date", DATE),
+                ("Last-Modified", "Thu, 01 Jan 1970 00:00:00 GMT"),
+            ],
+            b"a",
+        );
+        cache.store(&page, &request, &stored, 1000, 1000);
+        assert_eq!(cache.len(), 1);
+        assert!(matches!(
+            cache.lookup(&page, &request, Some(1099)),
+            CacheLookup::Fresh(_)
+        ));
+        assert!(matches!(
+            cache.lookup(&page, &request, Some(1100)),
+            CacheLookup::Stale(_)
+        ));
+    }
+
+    #[test]
+    fn test_not_stored() {
+        let mut cache = HttpCache::new();
+        let page = url("http://example.com/");
+        let get = HttpRequest::for_url(Method::Get, &page);
+        let no_store = response(200, &[("Cache-Control", "no-store, max-age=60")]);
+        assert!(!cache.store(&page, &get, &no_store, 0, 0));
+        let vary_all = response(200, &[("Cache-Control", "max-age=60"), ("Vary", "*")]);
+        assert!(!cache.store(&page, &get, &vary_all, 0, 0));
+        let created = response(201, &[]);
+        assert!(!cache.store(&page, &get, &created, 0, 0));
+        let post = HttpRequest::post("/", "text/plain", Vec::new());
+        let ok = response(200, &[("Cache-Control", "max-age=60")]);
+        assert!(!cache.store(&page, &post, &ok, 0, 0));
+        let authorized = get.clone().with_header("Authorization", "Basic eDp5");
+        assert!(!cache.store(&page, &authorized, &ok, 0, 0));
+
+        // Rangeを指定したリクエストや206のレスポンスは保存しない
+        let range = get.clone().with_range(ByteRange::From(5));
+        let partial = response_with_body(
+            206,
+            &[
+                ("Cache-Control", "max-age=60"),
+                ("Content-Range", "bytes 5-9/10"),
+            ],
+            b"56789",
+        );
+        assert!(!cache.store(&page, &get, &partial, 0, 0));
+        assert!(!cache.store(&page, &range, &ok, 0, 0));
+        assert!(cache.is_empty());
+
+        // 保存したレスポンス全体を、範囲を指定したリクエストに返さない
+        let full = response_with_body(200, &[("Cache-Control", "max-age=60")], b"0123456789");
+        assert!(cache.store(&page, &get, &full, 0, 0));
+        assert!(matches!(
+            cache.lookup(&page, &get, Some(0)),
+            CacheLookup::Fresh(_)
+        ));
+        assert!(matches!(
+            cache.lookup(&page, &range, Some(0)),
+            CacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn test_vary() {
+        let mut cache = HttpCache::new();
+        let page = url("http://example.com/");
+        let english = HttpRequest::for_url(Method::Get, &page).with_header("Accept-Language", "en");
+        let japanese =
+            HttpRequest::for_url(Method::Get, &page).with_header("Accept-Language", "ja");
+        let stored = response_with_body(
+            200,
+            &[("Cache-Control", "max-age=60"), ("Vary", "Accept-Language")],
+            b"hello",
+        );
+        cache.store(&page, &english, &stored, 0, 0);
+        assert!(matches!(
+            cache.lookup(&page, &english, Some(0)),
+            CacheLookup::Fresh(_)
+        ));
+        assert!(matches!(
+            cache.lookup(&page, &japanese, Some(0)),
+            CacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn test_not_modified() {
+        let mut cache = HttpCache::new();
+        let page = url("http://example.com/");
+        let request = HttpRequest::for_url(Method::Get, &page);
+        let stored = response_with_body(
+            200,
+            &[
+                ("Cache-Control", "max-age=10"),
+                ("ETag", "\"v1\""),
+                ("Content-Length", "5"),
+            ],
+            b"hello",
+        );
+        cache.store(&page, &request, &stored, 0, 0);
+
+        let not_modified = response(
+            304,
+            &[
+                ("Cache-Control", "max-age=100"),
+                ("ETag", "\"v1\""),
+                ("Content-Length", "0"),
+            ],
+        );
+        let updated = cache
+            .update_from_not_modified(&page, &request, &not_modified, 50, 50)
+            .expect("failed to update cached response");
+        assert_eq!(updated.status_code(), 200);
+        assert_eq!(updated.body(), b"hello".to_vec());
+        assert_eq!(
+            updated.headers().get("Content-Length"),
+            Some("5".to_string())
+        );
+        assert!(matches!(
+            cache.lookup(&page, &request, Some(120)),
+            CacheLookup::Fresh(_)
+        ));
+
+        let changed = response(304, &[("Cache-Control", "max-age=100"), ("ETag", "\"v2\"")]);
+        assert!(cache
+            .update_from_not_modified(&page, &request, &changed, 60, 60)
+            .is_none());
+        // 期限が指定されていても、304そのものは保存しない
+        assert!(!cache.store(&page, &request, &changed, 60, 60));
+        assert_eq!(cache.len(), 1);
+
+        cache.invalidate(&page);
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,188 @@
+//! https://httpwg.org/specs/rfc9112.html#chunked.encoding
+
+use crate::http::headers::HeaderMap;
+use crate::http::parse_header_line;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkedError {
+    // 最後のチャンクとトレーラーまで届いていない
+    Incomplete,
+    // チャンクサイズが16進数でない、データの後ろに改行がないなど
+    Invalid,
+}
+
+/// "Transfer-Encoding: chunked"のボディをデコードした結果
+#[derive(Debug, Clone)]
+pub struct ChunkedBody {
+    body: Vec<u8>,
+    trailers: HeaderMap,
+    consumed: usize,
+}
+
+impl ChunkedBody {
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    pub fn trailers(&self) -> HeaderMap {
+        self.trailers.clone()
+    }
+
+    // 入力のうちチャンク形式のボディが占めるバイト数。これより後ろは次のレスポンスになる
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+}
+
+/// https://httpwg.org/specs/rfc9112.html#decoding.chunked
+pub fn decode_chunked(input: &[u8]) -> Result<ChunkedBody, ChunkedError> {
+    let mut position = 0;
+    let mut body = Vec::new();
+
+    loop {
+        // chunk-size [ chunk-ext ] CRLF
+        let size = parse_chunk_size_line(read_line(input, &mut position)?)?;
+        if size == 0 {
+            break;
+        }
+
+        // chunk-data CRLF
+        let end = position.checked_add(size).ok_or(ChunkedError::Invalid)?;
+        if end > input.len() {
+            return Err(ChunkedError::Incomplete);
+        }
+        body.extend_from_slice(&input[position..end]);
+        position = end;
+        if !read_line(input, &mut position)?.is_empty() {
+            return Err(ChunkedError::Invalid);
+        }
+    }
+
+    // trailer-section CRLF
+    let mut trailers = HeaderMap::new();
+    loop {
+        let line = read_line(input, &mut position)?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some(header) = parse_header_line(line) {
+            trailers.append(&header.name(), &header.value());
+        }
+    }
+
+    Ok(ChunkedBody {
+        body,
+        trailers,
+        consumed: position,
+    })
+}
+
+// "1a;name=value"のような行からチャンクサイズを取り出す
+pub(crate) fn parse_chunk_size_line(line: &[u8]) -> Result<usize, ChunkedError> {
+    let size_end = line
+        .iter()
+        .position(|b| !b.is_ascii_hexdigit())
+        .unwrap_or(line.len());
+    // チャンク拡張は使わないので読み飛ばす。ただしサイズの直後は";"か空白でなければならない
+    if let Some(b) = line.get(size_end) {
+        if !matches!(b, b';' | b' ' | b'\t') {
+            return Err(ChunkedError::Invalid);
+        }
+    }
+    parse_chunk_size(&line[..size_end])
+}
+
+fn parse_chunk_size(digits: &[u8]) -> Result<usize, ChunkedError> {
+    if digits.is_empty() {
+        return Err(ChunkedError::Invalid);
+    }
+
+    let mut size: usize = 0;
+    for b in digits {
+        let digit = (*b as char).to_digit(16).ok_or(ChunkedError::Invalid)? as usize;
+        size = size
+            .checked_mul(16)
+            .and_then(|s| s.checked_add(digit))
+            .ok_or(ChunkedError::Invalid)?;
+    }
+    Ok(size)
+}
+
+// 改行までを1行として取り出す。改行はCRLFとLFのどちらも受け付ける
+fn read_line<'a>(input: &'a [u8], position: &mut usize) -> Result<&'a [u8], ChunkedError> {
+    let rest = &input[*position..];
+    let end = rest
+        .iter()
+        .position(|b| *b == b'\n')
+        .ok_or(ChunkedError::Incomplete)?;
+    *position += end + 1;
+    let line = &rest[..end];
+    Ok(line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let input = b"7\r\nMozilla\r\n11\r\nDeveloper Network\r\n0\r\n\r\n";
+        let decoded = decode_chunked(input).expect("failed to decode chunked body");
+        assert_eq!(decoded.body(), b"MozillaDeveloper Network".to_vec());
+        assert!(decoded.trailers().is_empty());
+        assert_eq!(decoded.consumed(), input.len());
+    }
+
+    #[test]
+    fn test_extensions_and_trailers() {
+        let input = b"4;name=value\r\nWiki\r\n5 ; a=\"b\"\r\npedia\r\n0;last\r\nExpires: never\r\nX-Sum:  42\r\n\r\nHTTP/1.1";
+        let decoded = decode_chunked(input).expect("failed to decode chunked body");
+        assert_eq!(decoded.body(), b"Wikipedia".to_vec());
+        let trailers = decoded.trailers();
+        assert_eq!(trailers.len(), 2);
+        assert_eq!(trailers.get("Expires"), Some("never".into()));
+        assert_eq!(trailers.get("X-Sum"), Some("42".into()));
+        // 次のレスポンスの手前で止まる
+        assert_eq!(&input[decoded.consumed()..], b"HTTP/1.1");
+    }
+
+    #[test]
+    fn test_binary_chunk() {
+        let decoded = decode_chunked(b"3\n\r\n\x00\n0\n\n").expect("failed to decode chunked body");
+        assert_eq!(decoded.body(), b"\r\n\x00".to_vec());
+    }
+
+    #[test]
+    fn test_incomplete() {
+        assert_eq!(
+            decode_chunked(b"5\r\nabc").unwrap_err(),
+            ChunkedError::Incomplete
+        );
+        assert_eq!(
+            decode_chunked(b"3\r\nabc\r\n0\r\n").unwrap_err(),
+            ChunkedError::Incomplete
+        );
+        assert_eq!(decode_chunked(b"").unwrap_err(), ChunkedError::Incomplete);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            decode_chunked(b"xyz\r\n").unwrap_err(),
+            ChunkedError::Invalid
+        );
+        assert_eq!(
+            decode_chunked(b"3\r\nabcd\r\n0\r\n\r\n").unwrap_err(),
+            ChunkedError::Invalid
+        );
+        assert_eq!(
+            decode_chunked(b"3x\r\nabc\r\n0\r\n\r\n").unwrap_err(),
+            ChunkedError::Invalid
+        );
+        assert_eq!(
+            decode_chunked(b"fffffffffffffffffffff\r\n").unwrap_err(),
+            ChunkedError::Invalid
+        );
+    }
+}
@@ -0,0 +1,668 @@
+//! https://fetch.spec.whatwg.org/#http-network-or-cache-fetch
+
+use crate::cookie::CookieJar;
+use crate::cookie::RequestContext;
+use crate::error::Error;
+use crate::form::Enctype;
+use crate::form::FormData;
+use crate::http::cache::CacheLookup;
+use crate::http::cache::HttpCache;
+use crate::http::date::parse_http_date;
+use crate::http::parser::ResponseEvent;
+use crate::http::parser::ResponseParser;
+use crate::http::range::Download;
+use crate::http::redirect::Redirects;
+use crate::http::transport::Connection;
+use crate::http::transport::Transport;
+use crate::http::HttpRequest;
+use crate::http::HttpResponse;
+use crate::http::Method;
+use crate::url::Scheme;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::cell::RefCell;
+
+/// HTTP/1.1でリクエストを送り、レスポンスを受け取る
+/// 接続の方法はTransportで差し替えられる
+pub struct HttpClient<T: Transport> {
+    transport: T,
+    // レスポンスを受け取り終えて、次のリクエストに使える接続（ホスト, ポート, 接続）
+    connections: RefCell<Vec<(String, u16, T::Connection)>>,
+    cookies: RefCell<CookieJar>,
+    cache: RefCell<HttpCache>,
+    // 受け取ったレスポンスのDateヘッダーのうち最も新しいもの
+    // Transportが時計を持たない場合は、これを現在時刻の代わりにする
+    latest_date: Cell<Option<u64>>,
+}
+
+impl<T: Transport> HttpClient<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            connections: RefCell::new(Vec::new()),
+            cookies: RefCell::new(CookieJar::new()),
+            cache: RefCell::new(HttpCache::new()),
+            latest_date: Cell::new(None),
+        }
+    }
+
+    // 現在時刻（1970年1月1日からの秒数）。時計もなく、Dateヘッダーもまだ受け取っていなければNone
+    fn now(&self) -> Option<u64> {
+        self.transport.now().or(self.latest_date.get())
+    }
+
+    pub fn cookies(&self) -> CookieJar {
+        self.cookies.borrow().clone()
+    }
+
+    // 保存しておいたCookieを読み込む
+    pub fn set_cookies(&self, cookies: CookieJar) {
+        *self.cookies.borrow_mut() = cookies;
+    }
+
+    // 保存したレスポンスをすべて捨てる
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
+    pub fn get(&self, host: String, port: u16, path: String) -> Result<HttpResponse, Error> {
+        self.send(host, port, HttpRequest::get(&path))
+    }
+
+    // ヘッダーだけを受け取る。レスポンスのボディは空になる
+    pub fn head(&self, host: String, port: u16, path: String) -> Result<HttpResponse, Error> {
+        self.send(host, port, HttpRequest::head(&path))
+    }
+
+    pub fn post(
+        &self,
+        host: String,
+        port: u16,
+        path: String,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse, Error> {
+        self.send(host, port, HttpRequest::post(&path, content_type, body))
+    }
+
+    // URLにリクエストを送り、リダイレクトをたどった先のレスポンスを返す
+    // 最終的なURLも返すので、アドレスバーの表示や相対URLの解決にはそちらを使う
+    // ページの移動として扱い、Cookieを送受信する
+    pub fn fetch(&self, url: Url, request: HttpRequest) -> Result<(Url, HttpResponse), Error> {
+        let mut redirects = Redirects::new(url, request);
+        loop {
+            let url = redirects.url();
+            let port = http_port(&url)?;
+
+            // Cookieはリダイレクト先のURLごとに付け直し、途中のレスポンスのSet-Cookieも保存する
+            let mut request = redirects.request();
+            let cookie = self.cookies.borrow().cookie_header(
+                &url,
+                RequestContext::SameSite,
+                self.now().unwrap_or(0),
+            );
+            if let Some(cookie) = cookie {
+                request.set_default_header("Cookie", &cookie);
+            }
+
+            let response = self.send_with_cache(&url, port, request)?;
+            if !redirects.follow(&response)? {
+                return Ok((url, response));
+            }
+        }
+    }
+
+    // 保存したレスポンスが使えればそれを返し、期限切れであればサーバーに確認してから返す
+    // サーバーから受け取ったレスポンスのCookieを保存し、使い回せるものはキャッシュに入れる
+    fn send_with_cache(
+        &self,
+        url: &Url,
+        port: u16,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, Error> {
+        // Dateヘッダーから推測した時刻は、キャッシュから返している間は進まないので、
+        // 期限内かどうかはTransportの時計がある場合だけ判断する
+        let lookup = self
+            .cache
+            .borrow()
+            .lookup(url, &request, self.transport.now());
+        let mut conditional = request.clone();
+        // 呼び出し元ではなく、ここで確認のためのヘッダーを付けたかどうか
+        let mut revalidating = false;
+        match lookup {
+            CacheLookup::Fresh(response) => return Ok(response),
+            CacheLookup::Stale(validators) => {
+                for header in validators.iter() {
+                    let name = header.name();
+                    if !request.headers().contains(&name) {
+                        conditional.set_default_header(&name, &header.value());
+                        revalidating = true;
+                    }
+                }
+            }
+            CacheLookup::Miss => {}
+        }
+
+        let (mut response, mut request_time, mut response_time) =
+            self.send_and_store_cookies(url, port, conditional)?;
+        if response.status_code() == 304 {
+            if let Some(cached) = self.cache.borrow_mut().update_from_not_modified(
+                url,
+                &request,
+                &response,
+                request_time,
+                response_time,
+            ) {
+                return Ok(cached);
+            }
+            // ETagが変わった、保存したレスポンスが捨てられたなどで304に対応するものがない場合は、
+            // 条件付きのリクエストを送っていない呼び出し元に304を返さないよう、条件を外して送り直す
+            if revalidating {
+                (response, request_time, response_time) =
+                    self.send_and_store_cookies(url, port, request.clone())?;
+            }
+        }
+
+        let mut cache = self.cache.borrow_mut();
+        if !request.method().is_safe() && (200..400).contains(&response.status_code()) {
+            cache.invalidate(url);
+        }
+        cache.store(url, &request, &response, request_time, response_time);
+        Ok(response)
+    }
+
+    // リクエストを送り、レスポンスのDateヘッダーとSet-Cookieを記録する
+    // レスポンスと、送った時刻と受け取った時刻を返す
+    fn send_and_store_cookies(
+        &self,
+        url: &Url,
+        port: u16,
+        request: HttpRequest,
+    ) -> Result<(HttpResponse, u64, u64), Error> {
+        let request_time = self.now().unwrap_or(0);
+        let response = self.send(url.host(), port, request)?;
+        if let Some(date) = response
+            .headers()
+            .get("Date")
+            .and_then(|d| parse_http_date(&d))
+        {
+            let latest = self.latest_date.get();
+            self.latest_date
+                .set(Some(latest.map_or(date, |latest| latest.max(date))));
+        }
+        let response_time = self.now().unwrap_or(0);
+        {
+            // 時刻が分からないうちは、期限切れかどうかも分からないので消さない
+            let mut cookies = self.cookies.borrow_mut();
+            cookies.store_response_cookies(url, &response.headers(), self.now());
+            if let Some(now) = self.now() {
+                cookies.remove_expired(now);
+            }
+        }
+        Ok((response, request_time, response_time))
+    }
+
+    // フォームの値を送信し、リダイレクトをたどった先のレスポンスを返す
+    // GETの場合は値をactionのクエリにし、POSTの場合はenctypeに従ってボディにする
+    pub fn submit_form(
+        &self,
+        action: Url,
+        method: Method,
+        enctype: Enctype,
+        form: &FormData,
+    ) -> Result<(Url, HttpResponse), Error> {
+        let (url, request) = form.to_request(&action, method, enctype);
+        self.fetch(url, request)
+    }
+
+    // ボディを受け取りながらdownloadに加える。途中で接続が切れた場合は、
+    // 受け取った分の続きからRangeリクエストで再開し、最大でmax_attempts回まで試す
+    // 失敗してもdownloadには受け取った分が残るので、後でもう一度呼べば続きから再開できる
+    pub fn download(&self, download: &mut Download, max_attempts: usize) -> Result<(), Error> {
+        let url = download.url();
+        let port = http_port(&url)?;
+
+        let mut attempts = 0;
+        while !download.is_done() {
+            attempts += 1;
+            // ステータスコードが想定外の場合などは、再開しても同じなのですぐに返す
+            let mut rejected: Option<Error> = None;
+            let mut finished: Result<(), Error> = Ok(());
+            let result = self.send_streaming(url.host(), port, download.request(), |event| {
+                if rejected.is_some() {
+                    return;
+                }
+                match event {
+                    ResponseEvent::Head(head) => {
+                        if let Err(e) = download.start(&head) {
+                            rejected = Some(e);
+                        }
+                    }
+                    ResponseEvent::Body(chunk) => download.append(&chunk),
+                    ResponseEvent::Trailers(_) => {}
+                    ResponseEvent::End => finished = download.finish(),
+                }
+            });
+            if let Some(e) = rejected {
+                return Err(e);
+            }
+            match result.and(finished) {
+                Ok(()) => {}
+                Err(e) if attempts >= max_attempts => return Err(e),
+                Err(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    // リクエストを送り、レスポンス全体を受け取って返す。圧縮されたボディは展開する
+    pub fn send(
+        &self,
+        host: String,
+        port: u16,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, Error> {
+        let mut response: Option<HttpResponse> = None;
+        self.send_streaming(host, port, request, |event| match event {
+            ResponseEvent::Head(head) => response = Some(head),
+            ResponseEvent::Body(chunk) => {
+                if let Some(response) = response.as_mut() {
+                    response.append_body(&chunk);
+                }
+            }
+            ResponseEvent::Trailers(trailers) => {
+                if let Some(response) = response.as_mut() {
+                    response.append_trailers(trailers);
+                }
+            }
+            ResponseEvent::End => {}
+        })?;
+
+        match response {
+            Some(mut response) => {
+                response.decode_content_encoding()?;
+                Ok(response)
+            }
+            None => Err(Error::Network(
+                "Connection closed before receiving a response".to_string(),
+            )),
+        }
+    }
+
+    // レスポンス全体を受け取るのを待たずに、届いた部分から順にhandlerへ渡す
+    // 圧縮されたボディは展開せずに渡すので、必要であれば受け取った側で展開する
+    pub fn get_streaming(
+        &self,
+        host: String,
+        port: u16,
+        path: String,
+        handler: impl FnMut(ResponseEvent),
+    ) -> Result<(), Error> {
+        self.send_streaming(host, port, HttpRequest::get(&path), handler)
+    }
+
+    pub fn send_streaming(
+        &self,
+        host: String,
+        port: u16,
+        mut request: HttpRequest,
+        mut handler: impl FnMut(ResponseEvent),
+    ) -> Result<(), Error> {
+        // 指定されていないヘッダーを追加
+        // HTTP/1.1では接続を閉じるよう指定しなければ、同じ接続を次のリクエストに使える
+        if port == 80 {
+            request.set_default_header("Host", &host);
+        } else {
+            request.set_default_header("Host", &format!("{}:{}", host, port));
+        }
+        request.set_default_header("Accept", "text/html");
+        request.set_default_header("Accept-Encoding", "gzip, deflate");
+        let method = request.method();
+        let request = request.to_bytes()?;
+
+        // 使い回す接続はサーバー側で既に閉じられていることがあるので、
+        // 何も受け取らないうちに失敗した場合は新しい接続でやり直す
+        // POSTのように送り直すと結果が変わるリクエストは、はじめから新しい接続で送る
+        if method.is_idempotent() {
+            if let Some(stream) = self.take_connection(&host, port) {
+                let mut started = false;
+                let result = self.exchange(stream, &host, port, method, &request, &mut |event| {
+                    started = true;
+                    handler(event);
+                });
+                match result {
+                    Ok(true) => return Ok(()),
+                    Err(e) if started => return Err(e),
+                    _ => {}
+                }
+            }
+        }
+
+        let stream = self.transport.connect(&host, port)?;
+        if self.exchange(stream, &host, port, method, &request, &mut handler)? {
+            Ok(())
+        } else {
+            Err(Error::Network(
+                "Connection closed before receiving a response".to_string(),
+            ))
+        }
+    }
+
+    fn take_connection(&self, host: &str, port: u16) -> Option<T::Connection> {
+        let mut connections = self.connections.borrow_mut();
+        let index = connections
+            .iter()
+            .position(|(h, p, _)| h == host && *p == port)?;
+        Some(connections.remove(index).2)
+    }
+
+    // リクエストを送り、レスポンスを1つ受け取る
+    // 何も受け取らないうちに接続が閉じられた場合はfalseを返す
+    fn exchange(
+        &self,
+        mut stream: T::Connection,
+        host: &str,
+        port: u16,
+        method: Method,
+        request: &[u8],
+        handler: &mut dyn FnMut(ResponseEvent),
+    ) -> Result<bool, Error> {
+        // リクエストを送信
+        stream.write_all(request)?;
+
+        // レスポンスの受信
+        let mut parser = ResponseParser::for_method(method);
+        let mut received_any = false;
+        let mut keep_alive = false;
+        loop {
+            // httpレスポンスを格納するためのバッファ
+            let mut buf = [0u8; 4096];
+            let bytes_read = stream.read(&mut buf)?;
+
+            // 接続が閉じられた場合は、長さの分からないボディがそこで終わる
+            let events = if bytes_read == 0 {
+                if !received_any {
+                    return Ok(false);
+                }
+                parser.finish()?
+            } else {
+                received_any = true;
+                parser.feed(&buf[..bytes_read])?
+            };
+
+            for event in events {
+                if let ResponseEvent::Head(head) = &event {
+                    keep_alive = head.is_keep_alive();
+                }
+                handler(event);
+            }
+
+            // Content-Lengthやチャンク形式で終わりが分かれば、接続が閉じるのを待たずに返す
+            if parser.is_done() {
+                if keep_alive && bytes_read != 0 {
+                    self.connections
+                        .borrow_mut()
+                        .push((host.to_string(), port, stream));
+                }
+                return Ok(true);
+            }
+        }
+    }
+}
+
+// httpのURLの接続先のポート番号
+fn http_port(url: &Url) -> Result<u16, Error> {
+    if url.scheme_kind() != Some(Scheme::Http) {
+        return Err(Error::Network(format!(
+            "Unsupported scheme: {}",
+            url.scheme()
+        )));
+    }
+    match url.port().parse::<u16>() {
+        Ok(port) => Ok(port),
+        Err(_) => Err(Error::Network(format!("Invalid port: {}", url.port()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::transport::ScriptedTransport;
+    use crate::test_helpers::url;
+
+    // 送信されたリクエストのリクエストラインとヘッダー
+    fn request_head(request: &(String, u16, Vec<u8>)) -> String {
+        let text = String::from_utf8_lossy(&request.2).into_owned();
+        match text.split_once("\r\n\r\n") {
+            Some((head, _)) => head.to_string(),
+            None => text,
+        }
+    }
+
+    #[test]
+    fn test_fetch_redirect_and_keep_alive() {
+        let transport = ScriptedTransport::new();
+        transport.add_connection(
+            "example.com",
+            80,
+            &[
+                b"HTTP/1.1 302 Found\r\nLocation: /b\r\nSet-Cookie: sid=1\r\nContent-Length: 0\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+            ],
+        );
+        let client = HttpClient::new(transport.clone());
+        let start = url("http://example.com/a");
+        let (final_url, response) = client
+            .fetch(start.clone(), HttpRequest::for_url(Method::Get, &start))
+            .expect("failed to fetch");
+        assert_eq!(final_url.to_string(), "http://example.com/b");
+        assert_eq!(response.body(), b"ok".to_vec());
+
+        // 同じ接続でリダイレクト先にリクエストし、Cookieを付ける
+        assert_eq!(transport.connection_count(), 1);
+        let requests = transport.requests();
+        assert!(request_head(&requests[0]).starts_with("GET /a HTTP/1.1\r\nHost: example.com\r\n"));
+        let second = request_head(&requests[1]);
+        assert!(second.starts_with("GET /b HTTP/1.1\r\n"));
+        assert!(second.contains("\r\nCookie: sid=1"));
+    }
+
+    #[test]
+    fn test_cookie_before_date() {
+        let transport = ScriptedTransport::new();
+        transport.add_connection(
+            "example.com",
+            80,
+            &[
+                b"HTTP/1.1 200 OK\r\nSet-Cookie: sid=1; Max-Age=3600\r\nSet-Cookie: lang=ja; Expires=Wed, 01 Jan 2031 00:00:00 GMT\r\nContent-Length: 0\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\nDate: Thu, 01 Jan 2026 00:00:00 GMT\r\nContent-Length: 0\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+            ],
+        );
+        let client = HttpClient::new(transport.clone());
+        let page = url("http://example.com/");
+        for _ in 0..3 {
+            client
+                .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+                .expect("failed to fetch");
+        }
+
+        // Dateヘッダーを受け取った後も、Dateのないレスポンスで設定したCookieを送る
+        assert_eq!(client.cookies().len(), 2);
+        let requests = transport.requests();
+        assert!(request_head(&requests[2]).contains("\r\nCookie: sid=1; lang=ja"));
+    }
+
+    #[test]
+    fn test_retry_closed_connection() {
+        let transport = ScriptedTransport::new();
+        transport.add_connection(
+            "example.com",
+            8000,
+            &[b"HTTP/1.1 200 OK\r\nContent-Length: 1\r\n\r\na"],
+        );
+        // 使い回した接続はサーバー側で閉じられている
+        transport.add_connection(
+            "example.com",
+            8000,
+            &[b"HTTP/1.1 200 OK\r\nContent-Length: 1\r\n\r\nb"],
+        );
+        let client = HttpClient::new(transport.clone());
+        let first = client
+            .get("example.com".to_string(), 8000, "/".to_string())
+            .expect("failed to get");
+        assert_eq!(first.body(), b"a".to_vec());
+        let second = client
+            .get("example.com".to_string(), 8000, "/".to_string())
+            .expect("failed to get");
+        assert_eq!(second.body(), b"b".to_vec());
+        assert_eq!(transport.connection_count(), 2);
+        assert!(request_head(&transport.requests()[2]).contains("Host: example.com:8000"));
+
+        assert!(client
+            .get("other.example".to_string(), 80, "/".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_cache_and_revalidation() {
+        let transport = ScriptedTransport::new();
+        transport.set_now(Some(1010));
+        transport.add_connection(
+            "example.com",
+            80,
+            &[
+                b"HTTP/1.1 200 OK\r\nDate: Thu, 01 Jan 1970 00:16:40 GMT\r\nCache-Control: max-age=0\r\nETag: \"v1\"\r\nContent-Length: 5\r\n\r\nhello",
+                b"HTTP/1.1 304 Not Modified\r\nDate: Thu, 01 Jan 1970 00:16:50 GMT\r\nCache-Control: max-age=60\r\nETag: \"v1\"\r\n\r\n",
+            ],
+        );
+        let client = HttpClient::new(transport.clone());
+        let page = url("http://example.com/");
+        for _ in 0..3 {
+            let (_, response) = client
+                .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+                .expect("failed to fetch");
+            assert_eq!(response.status_code(), 200);
+            assert_eq!(response.body(), b"hello".to_vec());
+        }
+
+        // 2回目は確認のリクエストを送り、3回目はキャッシュから返す
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(request_head(&requests[1]).contains("If-None-Match: \"v1\""));
+    }
+
+    #[test]
+    fn test_revalidation_with_changed_etag() {
+        let transport = ScriptedTransport::new();
+        transport.set_now(Some(1000));
+        transport.add_connection(
+            "example.com",
+            80,
+            &[
+                b"HTTP/1.1 200 OK\r\nCache-Control: max-age=0\r\nETag: \"v1\"\r\nContent-Length: 3\r\n\r\nold",
+                b"HTTP/1.1 304 Not Modified\r\nCache-Control: max-age=60\r\nETag: \"v2\"\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\nCache-Control: max-age=60\r\nETag: \"v2\"\r\nContent-Length: 3\r\n\r\nnew",
+            ],
+        );
+        let client = HttpClient::new(transport.clone());
+        let page = url("http://example.com/");
+        let fetch = || {
+            let (_, response) = client
+                .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+                .expect("failed to fetch");
+            response
+        };
+        assert_eq!(fetch().body(), b"old".to_vec());
+
+        // 保存したものと違うETagの304は返さず、条件を外して送り直す
+        let response = fetch();
+        assert_eq!(response.status_code(), 200);
+        assert_eq!(response.body(), b"new".to_vec());
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(request_head(&requests[1]).contains("If-None-Match: \"v1\""));
+        assert!(!request_head(&requests[2]).contains("If-None-Match"));
+
+        // 304ではなく、送り直して受け取ったレスポンスが保存されている
+        assert_eq!(fetch().body(), b"new".to_vec());
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_cache_expires_without_responses() {
+        let response: &[u8] = b"HTTP/1.1 200 OK\r\nDate: Thu, 01 Jan 1970 00:16:40 GMT\r\nCache-Control: max-age=60\r\nETag: \"v1\"\r\nContent-Length: 5\r\n\r\nhello";
+        let not_modified: &[u8] = b"HTTP/1.1 304 Not Modified\r\nDate: Thu, 01 Jan 1970 00:16:40 GMT\r\nETag: \"v1\"\r\n\r\n";
+        let page = url("http://example.com/");
+        let fetch = |client: &HttpClient<ScriptedTransport>| {
+            let (_, response) = client
+                .fetch(page.clone(), HttpRequest::for_url(Method::Get, &page))
+                .expect("failed to fetch");
+            assert_eq!(response.body(), b"hello".to_vec());
+        };
+
+        // 他のレスポンスを受け取らなくても、時間が経てば期限切れになる
+        let transport = ScriptedTransport::new();
+        transport.add_connection("example.com", 80, &[response, not_modified]);
+        transport.set_now(Some(1000));
+        let client = HttpClient::new(transport.clone());
+        fetch(&client);
+        transport.set_now(Some(1059));
+        fetch(&client);
+        assert_eq!(transport.requests().len(), 1);
+        transport.set_now(Some(1061));
+        fetch(&client);
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(request_head(&requests[1]).contains("If-None-Match: \"v1\""));
+
+        // 時計がない場合は、期限内かどうか分からないので毎回確認する
+        let transport = ScriptedTransport::new();
+        transport.add_connection("example.com", 80, &[response, not_modified]);
+        let client = HttpClient::new(transport.clone());
+        fetch(&client);
+        fetch(&client);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_resume_download() {
+        let transport = ScriptedTransport::new();
+        transport.add_broken_connection(
+            "example.com",
+            80,
+            b"HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 10\r\n\r\n0123456789",
+            55,
+        );
+        transport.add_connection(
+            "example.com",
+            80,
+            &[b"HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 4-9/10\r\nContent-Length: 6\r\n\r\n456789"],
+        );
+        let client = HttpClient::new(transport.clone());
+        let mut download = Download::new(url("http://example.com/large.bin"));
+        client
+            .download(&mut download, 3)
+            .expect("failed to download");
+        assert_eq!(download.body(), b"0123456789".to_vec());
+
+        let resumed = request_head(&transport.requests()[1]);
+        assert!(resumed.contains("Range: bytes=4-"));
+        assert!(resumed.contains("If-Range: \"v1\""));
+
+        // 再開できない場合は、受け取った分を残して失敗する
+        transport.add_broken_connection(
+            "example.com",
+            80,
+            b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n0123456789",
+            41,
+        );
+        let mut download = Download::new(url("http://example.com/large.bin"));
+        assert!(client.download(&mut download, 1).is_err());
+        assert_eq!(download.body(), b"01".to_vec());
+    }
+}
@@ -0,0 +1,151 @@
+//! https://httpwg.org/specs/rfc6265.html#cookie-date
+
+// "Sun, 06 Nov 1994 08:49:37 GMT"のような日時を、1970年1月1日からの秒数にする
+// CookieのExpires属性に使われる寛容な書式で解析するので、HTTPの3種類の日時の書式もすべて受け付ける
+// 1970年より前の日時は0になる。解析できない場合はNone
+/// https://httpwg.org/specs/rfc6265.html#cookie-date
+pub fn parse_http_date(input: &str) -> Option<u64> {
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+
+    for token in input.split(is_delimiter).filter(|t| !t.is_empty()) {
+        if time.is_none() {
+            if let Some(t) = parse_time(token) {
+                time = Some(t);
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some((d, _)) = leading_number(token, 1, 2) {
+                day = Some(d);
+                continue;
+            }
+        }
+        if month.is_none() {
+            if let Some(m) = parse_month(token) {
+                month = Some(m);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some((y, _)) = leading_number(token, 2, 4) {
+                year = Some(y);
+                continue;
+            }
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let (day, month, mut year) = (day?, month?, year?);
+    // 2桁の年は1970年から2069年のいずれかとみなす
+    if (70..=99).contains(&year) {
+        year += 1900;
+    } else if year <= 69 {
+        year += 2000;
+    }
+    if year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    if day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let days = days_from_civil(year as i64, month, day);
+    let seconds = days * 86400 + (hour * 3600 + minute * 60 + second) as i64;
+    Some(seconds.max(0) as u64)
+}
+
+/// https://httpwg.org/specs/rfc6265.html#cookie-date
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '\t' | ' '..='/' | ';'..='@' | '['..='`' | '{'..='~')
+}
+
+// 先頭の数字を読み、min桁からmax桁であればその値と残りを返す
+fn leading_number(token: &str, min: usize, max: usize) -> Option<(u32, &str)> {
+    let digits = token.bytes().take_while(|b| b.is_ascii_digit()).count();
+    if digits < min || digits > max {
+        return None;
+    }
+    let value = token[..digits].parse::<u32>().ok()?;
+    Some((value, &token[digits..]))
+}
+
+// "08:49:37"のような時刻
+fn parse_time(token: &str) -> Option<(u32, u32, u32)> {
+    let (hour, rest) = leading_number(token, 1, 2)?;
+    let (minute, rest) = leading_number(rest.strip_prefix(':')?, 1, 2)?;
+    let (second, _) = leading_number(rest.strip_prefix(':')?, 1, 2)?;
+    Some((hour, minute, second))
+}
+
+// 先頭の3文字が月の名前であれば1から12の値を返す
+fn parse_month(token: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let prefix = token.get(..3)?;
+    MONTHS
+        .iter()
+        .position(|m| m.eq_ignore_ascii_case(prefix))
+        .map(|i| i as u32 + 1)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// グレゴリオ暦の日付から1970年1月1日までの日数を求める
+/// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    // 3月を0とした月
+    let month_index = ((month + 9) % 12) as i64;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_date_formats() {
+        // IMF-fixdate、RFC 850、asctimeの書式
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784111777)
+        );
+        assert_eq!(
+            parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"),
+            Some(784111777)
+        );
+        assert_eq!(parse_http_date("Sun Nov  6 08:49:37 1994"), Some(784111777));
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_http_date("Tue, 29 Feb 2028 23:59:59 GMT"),
+            Some(1835481599)
+        );
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert_eq!(parse_http_date("Wed, 30 Feb 2028 00:00:00 GMT"), None);
+        assert_eq!(parse_http_date("Wed, 01 Jan 1600 00:00:00 GMT"), None);
+        assert_eq!(parse_http_date("Wed, 01 Jan 2025 24:00:00 GMT"), None);
+        assert_eq!(parse_http_date("Wed, 01 Jan 2025"), None);
+        assert_eq!(parse_http_date("0"), None);
+        // 1970年より前は0にする
+        assert_eq!(parse_http_date("Fri, 31 Dec 1965 23:59:59 GMT"), Some(0));
+    }
+}
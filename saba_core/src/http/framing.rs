@@ -0,0 +1,151 @@
+//! https://httpwg.org/specs/rfc9112.html#message.body.length
+
+use crate::error::Error;
+use crate::http::chunked::decode_chunked;
+use crate::http::chunked::ChunkedError;
+use crate::http::headers::HeaderMap;
+use crate::http::HttpResponse;
+use crate::http::ResponseError;
+
+/// レスポンスのボディの長さの決まり方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyLength {
+    // 1xx、204、304のレスポンスはボディを持たない
+    Empty,
+    // Content-Lengthで指定されたバイト数
+    Fixed(usize),
+    // "Transfer-Encoding: chunked"の最後のチャンクまで
+    Chunked,
+    // サーバーが接続を閉じるまで
+    UntilClose,
+}
+
+/// https://httpwg.org/specs/rfc9112.html#message.body.length
+pub fn body_length(status_code: u32, headers: &HeaderMap) -> Result<BodyLength, ResponseError> {
+    if (100..200).contains(&status_code) || status_code == 204 || status_code == 304 {
+        return Ok(BodyLength::Empty);
+    }
+
+    // Transfer-EncodingはContent-Lengthより優先する
+    // 最後のコーディングがchunkedでなければ、接続が閉じるまでがボディになる
+    if let Some(coding) = headers.get_list("Transfer-Encoding").last() {
+        if coding.eq_ignore_ascii_case("chunked") {
+            return Ok(BodyLength::Chunked);
+        }
+        return Ok(BodyLength::UntilClose);
+    }
+
+    if !headers.contains("Content-Length") {
+        return Ok(BodyLength::UntilClose);
+    }
+    match headers.content_length() {
+        Some(length) => Ok(BodyLength::Fixed(length)),
+        None => Err(ResponseError::InvalidContentLength(
+            headers.get_all("Content-Length").join(", "),
+        )),
+    }
+}
+
+// 受信済みのバイト列のうち、最初のレスポンスが終わる位置を返す
+// まだ最後まで届いていない場合や、接続が閉じるまで終わりが分からない場合はNone
+pub fn message_end(raw_response: &[u8]) -> Result<Option<usize>, Error> {
+    if !has_complete_head(raw_response) {
+        return Ok(None);
+    }
+
+    let (response, remaining) = HttpResponse::parse_head(raw_response)?;
+    let head_length = raw_response.len() - remaining.len();
+
+    match body_length(response.status_code(), &response.headers)? {
+        BodyLength::Empty => Ok(Some(head_length)),
+        BodyLength::Fixed(length) => {
+            if remaining.len() >= length {
+                Ok(Some(head_length + length))
+            } else {
+                Ok(None)
+            }
+        }
+        BodyLength::Chunked => match decode_chunked(remaining) {
+            Ok(decoded) => Ok(Some(head_length + decoded.consumed())),
+            Err(ChunkedError::Incomplete) => Ok(None),
+            Err(e) => Err(ResponseError::InvalidChunkedBody(e).into()),
+        },
+        BodyLength::UntilClose => Ok(None),
+    }
+}
+
+// ステータスラインとヘッダーの後ろの空行まで届いているかどうか
+pub(crate) fn has_complete_head(raw_response: &[u8]) -> bool {
+    let start = raw_response
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(raw_response.len());
+    let head = &raw_response[start..];
+    head.windows(2).any(|w| w == b"\n\n") || head.windows(3).any(|w| w == b"\n\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::headers;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_body_length() {
+        assert_eq!(body_length(200, &headers(&[])), Ok(BodyLength::UntilClose));
+        assert_eq!(
+            body_length(200, &headers(&[("content-length", "42")])),
+            Ok(BodyLength::Fixed(42))
+        );
+        assert_eq!(
+            body_length(200, &headers(&[("Content-Length", "42, 42")])),
+            Ok(BodyLength::Fixed(42))
+        );
+        assert!(body_length(200, &headers(&[("Content-Length", "42, 43")])).is_err());
+        assert!(body_length(200, &headers(&[("Content-Length", "-1")])).is_err());
+        assert_eq!(
+            body_length(204, &headers(&[("Content-Length", "42")])),
+            Ok(BodyLength::Empty)
+        );
+        assert_eq!(
+            body_length(
+                200,
+                &headers(&[("Content-Length", "42"), ("Transfer-Encoding", "chunked")])
+            ),
+            Ok(BodyLength::Chunked)
+        );
+        assert_eq!(
+            body_length(200, &headers(&[("Transfer-Encoding", "chunked, gzip")])),
+            Ok(BodyLength::UntilClose)
+        );
+    }
+
+    #[test]
+    fn test_message_end() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHelloHTTP/1.1";
+        assert_eq!(message_end(raw), Ok(Some(raw.len() - 8)));
+        assert_eq!(message_end(&raw[..30]), Ok(None));
+        assert_eq!(message_end(&raw[..40]), Ok(None));
+
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nHello\r\n0\r\n\r\n";
+        assert_eq!(message_end(raw), Ok(Some(raw.len())));
+        assert_eq!(message_end(&raw[..raw.len() - 2]), Ok(None));
+
+        let raw = b"HTTP/1.1 304 Not Modified\n\n";
+        assert_eq!(message_end(raw), Ok(Some(raw.len())));
+
+        // 長さが分からないので、接続が閉じるまで待つ
+        let raw = b"HTTP/1.1 200 OK\r\n\r\nHello";
+        assert_eq!(message_end(raw), Ok(None));
+    }
+
+    #[test]
+    fn test_message_end_after_pipelined_response() {
+        let mut raw: Vec<u8> = Vec::new();
+        raw.extend_from_slice(b"HTTP/1.1 204 No Content\r\n\r\n");
+        let first = raw.len();
+        raw.extend_from_slice(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+        assert_eq!(message_end(&raw), Ok(Some(first)));
+        assert_eq!(message_end(&raw[first..]), Ok(Some(raw.len() - first)));
+    }
+}
@@ -0,0 +1,227 @@
+//! https://fetch.spec.whatwg.org/#concept-header-list
+
+use crate::http::Header;
+use crate::mime::MimeType;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::slice::Iter;
+
+/// 受け取った順序を保ったヘッダーの一覧
+/// 名前は大文字と小文字を区別せずに扱い、同じ名前のヘッダーが複数あってもすべて保持する
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeaderMap {
+    headers: Vec<Header>,
+}
+
+impl HeaderMap {
+    pub fn new() -> Self {
+        Self {
+            headers: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    // 受け取った順に並んだヘッダー
+    pub fn iter(&self) -> Iter<'_, Header> {
+        self.headers.iter()
+    }
+
+    /// https://fetch.spec.whatwg.org/#concept-header-list-append
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.headers
+            .push(Header::new(name.to_string(), value.to_string()));
+    }
+
+    // 同じ名前のヘッダーをすべて置き換える。最初のヘッダーの位置に値を入れる
+    /// https://fetch.spec.whatwg.org/#concept-header-list-set
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.position(name) {
+            Some(i) => {
+                self.headers[i].value = value.to_string();
+                let mut rest = self.headers.split_off(i + 1);
+                rest.retain(|h| !h.name.eq_ignore_ascii_case(name));
+                self.headers.extend(rest);
+            }
+            None => self.append(name, value),
+        }
+    }
+
+    /// https://fetch.spec.whatwg.org/#concept-header-list-delete
+    pub fn remove(&mut self, name: &str) {
+        self.headers.retain(|h| !h.name.eq_ignore_ascii_case(name));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    // 最初に見つかったヘッダーの値
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.position(name).map(|i| self.headers[i].value.clone())
+    }
+
+    // "Set-Cookie"のように繰り返し送られるヘッダーの値をすべて返す
+    pub fn get_all(&self, name: &str) -> Vec<String> {
+        self.headers
+            .iter()
+            .filter(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.clone())
+            .collect()
+    }
+
+    // "Connection: keep-alive, Upgrade"のようなカンマ区切りの値を、すべてのヘッダーについて分割して返す
+    /// https://fetch.spec.whatwg.org/#concept-header-list-get-decode-split
+    pub fn get_list(&self, name: &str) -> Vec<String> {
+        self.headers
+            .iter()
+            .filter(|h| h.name.eq_ignore_ascii_case(name))
+            .flat_map(|h| h.value.split(','))
+            .map(|v| v.trim_matches(|c| c == ' ' || c == '\t').to_string())
+            .collect()
+    }
+
+    pub fn extend(&mut self, other: HeaderMap) {
+        self.headers.extend(other.headers);
+    }
+
+    // "Content-Length: 42, 42"のように同じ値が並ぶ場合は受け付ける
+    // 値が数値でない、または異なる値が並ぶ場合はNone
+    /// https://fetch.spec.whatwg.org/#header-list-extract-a-length
+    pub fn content_length(&self) -> Option<usize> {
+        let mut length = None;
+        for value in self.get_list("Content-Length") {
+            if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let value = value.parse::<usize>().ok()?;
+            if length.is_some_and(|length| length != value) {
+                return None;
+            }
+            length = Some(value);
+        }
+        length
+    }
+
+    // 複数ある場合は最後に解析できたもの
+    /// https://fetch.spec.whatwg.org/#concept-header-extract-mime-type
+    pub fn content_type(&self) -> Option<MimeType> {
+        self.get_all("Content-Type")
+            .iter()
+            .filter_map(|v| MimeType::parse(v))
+            .last()
+    }
+
+    // リダイレクト先。相対URLのこともあるので、解決はリクエストしたURLを基準に行う
+    pub fn location(&self) -> Option<String> {
+        self.get("Location")
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|h| h.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl FromIterator<Header> for HeaderMap {
+    fn from_iter<I: IntoIterator<Item = Header>>(iter: I) -> Self {
+        Self {
+            headers: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::headers;
+
+    #[test]
+    fn test_case_insensitive() {
+        let headers = headers(&[("Content-Length", "42")]);
+        assert_eq!(headers.get("content-length"), Some("42".to_string()));
+        assert_eq!(headers.get("CONTENT-LENGTH"), Some("42".to_string()));
+        assert!(headers.contains("Content-length"));
+        assert_eq!(headers.get("Content-Type"), None);
+    }
+
+    #[test]
+    fn test_multiple_values() {
+        let headers = headers(&[
+            ("Set-Cookie", "a=1"),
+            ("Date", "Mon, 04 Aug 2025 00:00:00 GMT"),
+            ("set-cookie", "b=2"),
+        ]);
+        assert_eq!(headers.get("Set-Cookie"), Some("a=1".to_string()));
+        assert_eq!(headers.get_all("Set-Cookie"), ["a=1", "b=2"]);
+        let names: Vec<String> = headers.iter().map(|h| h.name()).collect();
+        assert_eq!(names, ["Set-Cookie", "Date", "set-cookie"]);
+    }
+
+    #[test]
+    fn test_set_and_remove() {
+        let mut headers = headers(&[("A", "1"), ("B", "2"), ("a", "3")]);
+        headers.set("a", "4");
+        assert_eq!(headers.get_all("A"), ["4"]);
+        assert_eq!(
+            headers.iter().next().map(|h| h.value()),
+            Some("4".to_string())
+        );
+        headers.set("C", "5");
+        assert_eq!(headers.len(), 3);
+        headers.remove("b");
+        assert_eq!(headers.len(), 2);
+        assert!(!headers.contains("B"));
+    }
+
+    #[test]
+    fn test_get_list() {
+        let headers = headers(&[
+            ("Connection", "keep-alive, Upgrade"),
+            ("connection", "\tclose"),
+        ]);
+        assert_eq!(
+            headers.get_list("Connection"),
+            ["keep-alive", "Upgrade", "close"]
+        );
+    }
+
+    #[test]
+    fn test_content_length() {
+        assert_eq!(
+            headers(&[("Content-Length", "42")]).content_length(),
+            Some(42)
+        );
+        assert_eq!(
+            headers(&[("Content-Length", "42"), ("Content-Length", "42, 42")]).content_length(),
+            Some(42)
+        );
+        assert_eq!(
+            headers(&[("Content-Length", "42, 43")]).content_length(),
+            None
+        );
+        assert_eq!(headers(&[("Content-Length", "+42")]).content_length(), None);
+        assert_eq!(headers(&[]).content_length(), None);
+    }
+
+    #[test]
+    fn test_content_type_and_location() {
+        let headers = headers(&[
+            ("Content-Type", "text/html; charset=Shift_JIS"),
+            ("Content-Type", "invalid"),
+            ("Location", "/next"),
+        ]);
+        let mime = headers.content_type().expect("failed to extract mime type");
+        assert_eq!(mime.essence(), "text/html");
+        assert_eq!(mime.charset(), Some("Shift_JIS".to_string()));
+        assert_eq!(headers.location(), Some("/next".to_string()));
+    }
+}
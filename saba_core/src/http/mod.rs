@@ -0,0 +1,674 @@
+pub mod cache;
+pub mod chunked;
+pub mod client;
+pub mod date;
+pub mod framing;
+pub mod headers;
+pub mod parser;
+pub mod range;
+pub mod redirect;
+pub mod request;
+pub mod transport;
+
+pub use crate::http::request::HttpRequest;
+pub use crate::http::request::Method;
+
+use crate::error::Error;
+use crate::http::chunked::decode_chunked;
+use crate::http::chunked::ChunkedError;
+use crate::http::framing::body_length;
+use crate::http::framing::BodyLength;
+use crate::http::headers::HeaderMap;
+use crate::http::request::is_valid_header_name;
+use crate::inflate::deflate_decode;
+use crate::inflate::gzip_decode;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// レスポンスを解析できなかった理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseError {
+    // ステータスラインがない
+    Empty,
+    // "HTTP/1.1"の形式でないバージョン
+    InvalidVersion(String),
+    // 100から999までの3桁の数字でないステータスコード
+    InvalidStatusCode(String),
+    // ":"がない、名前に使えない文字を含む、前のヘッダーがないのに続きの行があるなど
+    InvalidHeader(String),
+    // 数字でない、または複数の異なる値を持つContent-Length
+    InvalidContentLength(String),
+    // Content-Lengthのバイト数を受け取る前にボディが終わっている（Content-Length, 受け取ったバイト数）
+    TruncatedBody(usize, usize),
+    InvalidChunkedBody(ChunkedError),
+    // 圧縮されたボディを展開できなかった
+    InvalidContentEncoding(String),
+}
+
+impl From<ResponseError> for Error {
+    fn from(e: ResponseError) -> Self {
+        let message = match e {
+            ResponseError::Empty => "Empty HTTP response".to_string(),
+            ResponseError::InvalidVersion(version) => format!("Invalid HTTP version: {}", version),
+            ResponseError::InvalidStatusCode(code) => format!("Invalid status code: {}", code),
+            ResponseError::InvalidHeader(line) => format!("Invalid header: {}", line),
+            ResponseError::InvalidContentLength(length) => {
+                format!("Invalid Content-Length: {}", length)
+            }
+            ResponseError::TruncatedBody(expected, received) => {
+                format!("Body ended after {} of {} bytes", received, expected)
+            }
+            ResponseError::InvalidChunkedBody(e) => format!("Invalid chunked body: {:?}", e),
+            ResponseError::InvalidContentEncoding(e) => format!("Failed to decode body: {}", e),
+        };
+        Error::UnexpectedResponse(message)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    version: String,
+    status_code: u32,
+    reason: String,
+    headers: HeaderMap,
+    trailers: HeaderMap,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    name: String,
+    value: String,
+}
+
+impl Header {
+    pub fn new(name: String, value: String) -> Self {
+        Self { name, value }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl HttpResponse {
+    // 受信したバイト列をそのまま解析する。ボディは画像などのバイナリのこともあるので、文字列にはしない
+    pub fn new(raw_response: &[u8]) -> Result<Self, Error> {
+        Ok(Self::parse(raw_response)?)
+    }
+
+    // newと同じだが、解析できなかった理由を区別して返す
+    // どのような入力に対してもパニックせず、ResponseErrorを返す
+    pub fn parse(raw_response: &[u8]) -> Result<Self, ResponseError> {
+        let (mut response, remaining) = Self::parse_head(raw_response)?;
+
+        // ボディの長さはContent-LengthやTransfer-Encodingで決まる
+        match body_length(response.status_code, &response.headers)? {
+            BodyLength::Empty => {}
+            BodyLength::Fixed(length) => {
+                // 途中で接続が切れた場合は、チャンク形式と同じく不完全なボディとして失敗にする
+                if remaining.len() < length {
+                    return Err(ResponseError::TruncatedBody(length, remaining.len()));
+                }
+                response.body = remaining[..length].to_vec();
+            }
+            BodyLength::Chunked => {
+                // チャンク形式のボディはデコードし、トレーラーはヘッダーの後ろに加える
+                let decoded =
+                    decode_chunked(remaining).map_err(ResponseError::InvalidChunkedBody)?;
+                response.append_trailers(decoded.trailers());
+                response.body = decoded.body();
+            }
+            BodyLength::UntilClose => response.body = remaining.to_vec(),
+        }
+
+        response.decode_content_encoding()?;
+        Ok(response)
+    }
+
+    // ステータスラインとヘッダーを解析し、ボディが空のレスポンスと残りのバイト列を返す
+    /// https://httpwg.org/specs/rfc9112.html#message.format
+    pub(crate) fn parse_head(raw_response: &[u8]) -> Result<(Self, &[u8]), ResponseError> {
+        // レスポンスの前の空白や空行は読み飛ばす
+        let start = raw_response
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .unwrap_or(raw_response.len());
+        let mut remaining = &raw_response[start..];
+
+        let status_line = match next_line(&mut remaining) {
+            Some(line) => isomorphic_decode(line),
+            None => return Err(ResponseError::Empty),
+        };
+        let (version, status_code, reason) = parse_status_line(&status_line)?;
+
+        // 空行までがヘッダー
+        let mut fields: Vec<Header> = Vec::new();
+        while let Some(line) = next_line(&mut remaining) {
+            if line.is_empty() {
+                break;
+            }
+            // 空白やタブで始まる行は前のヘッダーの値の続き（obs-fold）なので、空白1つでつなぐ
+            // https://httpwg.org/specs/rfc9112.html#line.folding
+            if line[0] == b' ' || line[0] == b'\t' {
+                let continuation = isomorphic_decode(line);
+                let previous = fields
+                    .last_mut()
+                    .ok_or_else(|| ResponseError::InvalidHeader(continuation.clone()))?;
+                let continuation = continuation.trim_matches(|c| c == ' ' || c == '\t');
+                if !continuation.is_empty() {
+                    if !previous.value.is_empty() {
+                        previous.value.push(' ');
+                    }
+                    previous.value.push_str(continuation);
+                }
+                continue;
+            }
+            match parse_header_line(line) {
+                Some(header) if is_valid_header_name(&header.name) => fields.push(header),
+                _ => return Err(ResponseError::InvalidHeader(isomorphic_decode(line))),
+            }
+        }
+
+        let mut headers = HeaderMap::new();
+        for header in fields {
+            headers.append(&header.name, &header.value);
+        }
+        let response = Self {
+            version,
+            status_code,
+            reason,
+            headers,
+            trailers: HeaderMap::new(),
+            body: Vec::new(),
+        };
+        Ok((response, remaining))
+    }
+
+    // 解析済みの値からレスポンスを組み立てる（data: URLなど、ネットワークを経由しない場合に使う）
+    pub fn from_parts(
+        version: String,
+        status_code: u32,
+        reason: String,
+        headers: HeaderMap,
+        body: Vec<u8>,
+    ) -> Self {
+        Self {
+            version,
+            status_code,
+            reason,
+            headers,
+            trailers: HeaderMap::new(),
+            body,
+        }
+    }
+
+    // ゲッタメソッド
+    pub fn version(&self) -> String {
+        self.version.clone()
+    }
+
+    pub fn status_code(&self) -> u32 {
+        self.status_code
+    }
+
+    pub fn reason(&self) -> String {
+        self.reason.clone()
+    }
+
+    pub fn headers(&self) -> HeaderMap {
+        self.headers.clone()
+    }
+
+    // チャンク形式のボディの後ろで送られたヘッダー。headers()にも含まれる
+    pub fn trailers(&self) -> HeaderMap {
+        self.trailers.clone()
+    }
+
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    // ボディを文字列として取り出す。UTF-8として不正なバイトはU+FFFDに置き換える
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    // ResponseParserで少しずつ受け取ったボディを後ろに加える
+    pub fn append_body(&mut self, chunk: &[u8]) {
+        self.body.extend_from_slice(chunk);
+    }
+
+    // トレーラーはheaders()からも参照できるようにする
+    pub fn append_trailers(&mut self, trailers: HeaderMap) {
+        self.headers.extend(trailers.clone());
+        self.trailers.extend(trailers);
+    }
+
+    // "Content-Encoding: gzip"などで圧縮されたボディを展開する
+    // 展開した後はContent-Encodingを取り除くので、2回呼んでも結果は変わらない
+    /// https://httpwg.org/specs/rfc9110.html#field.content-encoding
+    pub fn decode_content_encoding(&mut self) -> Result<(), ResponseError> {
+        if self.body.is_empty() {
+            return Ok(());
+        }
+
+        // 複数のコーディングは適用された順に並んでいるので、後ろから戻す
+        let mut body = self.body.clone();
+        for coding in self.headers.get_list("Content-Encoding").iter().rev() {
+            let decoded = match coding.to_ascii_lowercase().as_str() {
+                "gzip" | "x-gzip" => gzip_decode(&body),
+                "deflate" => deflate_decode(&body),
+                "identity" | "" => continue,
+                // 対応していないコーディングの場合は、圧縮されたまま残す
+                _ => return Ok(()),
+            };
+            body = match decoded {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    return Err(ResponseError::InvalidContentEncoding(format!(
+                        "{}: {:?}",
+                        coding, e
+                    )))
+                }
+            };
+        }
+
+        self.body = body;
+        self.headers.remove("Content-Encoding");
+        Ok(())
+    }
+
+    // ヘッダー名の大文字と小文字は区別しない。同じ名前のヘッダーが複数ある場合は最初の値を返す
+    pub fn header_value(&self, name: &str) -> Option<String> {
+        self.headers.get(name)
+    }
+
+    // 同じ接続で次のリクエストを送れるかどうか
+    /// https://httpwg.org/specs/rfc9112.html#persistent.connections
+    pub fn is_keep_alive(&self) -> bool {
+        // 接続を閉じるまでボディが続く場合は再利用できない
+        match body_length(self.status_code, &self.headers) {
+            Ok(BodyLength::UntilClose) | Err(_) => return false,
+            _ => {}
+        }
+
+        let options = self.headers.get_list("Connection");
+        let has_option = |option: &str| options.iter().any(|o| o.eq_ignore_ascii_case(option));
+        if has_option("close") {
+            return false;
+        }
+        if self.version == "HTTP/1.0" {
+            return has_option("keep-alive");
+        }
+        true
+    }
+}
+
+// "HTTP/1.1 200 OK"のようなステータスライン。理由句は空でもよい
+/// https://httpwg.org/specs/rfc9112.html#status.line
+fn parse_status_line(line: &str) -> Result<(String, u32, String), ResponseError> {
+    let mut parts = line.splitn(3, ' ');
+    let version = parts.next().unwrap_or("");
+    let is_valid_version = match version.strip_prefix("HTTP/") {
+        Some(number) => {
+            let number = number.as_bytes();
+            number.len() == 3
+                && number[0].is_ascii_digit()
+                && number[1] == b'.'
+                && number[2].is_ascii_digit()
+        }
+        None => false,
+    };
+    if !is_valid_version {
+        return Err(ResponseError::InvalidVersion(version.to_string()));
+    }
+
+    // ステータスコードは3桁の数字
+    let status_code = parts.next().unwrap_or("");
+    if status_code.len() != 3 || !status_code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ResponseError::InvalidStatusCode(status_code.to_string()));
+    }
+    let code = status_code.parse::<u32>().unwrap_or(0);
+    if code < 100 {
+        return Err(ResponseError::InvalidStatusCode(status_code.to_string()));
+    }
+
+    let reason = parts.next().unwrap_or("");
+    Ok((version.to_string(), code, reason.to_string()))
+}
+
+// 次の行を取り出し、残りを進める。改行はCRLFとLFのどちらも受け付ける
+// 入力が尽きている場合はNoneを返す
+fn next_line<'a>(remaining: &mut &'a [u8]) -> Option<&'a [u8]> {
+    if remaining.is_empty() {
+        return None;
+    }
+
+    let (line, rest) = match remaining.iter().position(|b| *b == b'\n') {
+        Some(i) => (&remaining[..i], &remaining[i + 1..]),
+        None => (*remaining, &remaining[remaining.len()..]),
+    };
+    *remaining = rest;
+    Some(line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+// "Name: value"の形式の行をヘッダーにする。":"がない行はNone
+pub(crate) fn parse_header_line(line: &[u8]) -> Option<Header> {
+    let header = isomorphic_decode(line);
+    let (name, value) = header.split_once(':')?;
+    // 値の前後の空白やタブは取り除く
+    let value = value.trim_matches(|c| c == ' ' || c == '\t');
+    Some(Header::new(name.to_string(), value.to_string()))
+}
+
+// ヘッダーのバイト列は1バイトを1文字として扱う
+/// https://infra.spec.whatwg.org/#isomorphic-decode
+fn isomorphic_decode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| *b as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_line_only() {
+        let raw = "HTTP/1.1 200 OK\n".to_string();
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.version(), "HTTP/1.1");
+        assert_eq!(res.status_code(), 200);
+        assert_eq!(res.reason(), "OK");
+        assert_eq!(res.headers().len(), 0);
+    }
+
+    #[test]
+    fn test_one_header() {
+        let raw = "HTTP/1.1 200 OK\nDate: Mon, 04 Aug 2025 00:00:00 GMT\n\n".to_string();
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.version(), "HTTP/1.1");
+        assert_eq!(res.status_code(), 200);
+        assert_eq!(res.reason(), "OK");
+        assert_eq!(res.headers().len(), 1);
+
+        assert_eq!(
+            res.header_value("Date").unwrap(),
+            "Mon, 04 Aug 2025 00:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_two_headers_with_white_space() {
+        let mut raw = "HTTP/1.1 200 OK\nDate: Mon, 04 Aug 2025 00:00:00 GMT\nContent-length:42\n\n"
+            .to_string();
+        // Content-Lengthより短いボディは不完全なので、その分だけ続ける
+        raw.push_str(&"a".repeat(42));
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.version(), "HTTP/1.1");
+        assert_eq!(res.status_code(), 200);
+        assert_eq!(res.reason(), "OK");
+        assert_eq!(res.headers().len(), 2);
+        assert_eq!(
+            res.header_value("Date").unwrap(),
+            "Mon, 04 Aug 2025 00:00:00 GMT"
+        );
+        assert_eq!(
+            res.header_value("Content-length").unwrap(),
+            "42".to_string()
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        let raw = "HTTP/1.1 200 OK\n\nHello, World!".to_string();
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.version(), "HTTP/1.1");
+        assert_eq!(res.status_code(), 200);
+        assert_eq!(res.reason(), "OK");
+        assert_eq!(res.headers().len(), 0);
+        assert_eq!(res.body(), b"Hello, World!".to_vec());
+        assert_eq!(res.text(), "Hello, World!".to_string());
+    }
+
+    #[test]
+    fn test_header_case_and_repetition() {
+        let raw =
+            "HTTP/1.1 200 OK\r\ncontent-length: 0\r\nSet-Cookie: a=1\r\nSet-Cookie: b=2\r\n\r\n";
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.header_value("Content-Length"), Some("0".to_string()));
+        assert_eq!(res.headers().content_length(), Some(0));
+        assert_eq!(res.headers().get_all("set-cookie"), ["a=1", "b=2"]);
+        assert_eq!(res.header_value("Location"), None);
+    }
+
+    #[test]
+    fn test_binary_body() {
+        let mut raw = b"HTTP/1.1 200 OK\r\nContent-Type: image/png\r\n\r\n".to_vec();
+        raw.extend_from_slice(&[
+            0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', 0xFF, 0x00,
+        ]);
+        let res = HttpResponse::new(&raw).expect("Failed to parse http response");
+        assert_eq!(res.status_code(), 200);
+        assert_eq!(res.header_value("Content-Type").unwrap(), "image/png");
+        assert_eq!(
+            res.body(),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', 0xFF, 0x00].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_non_utf8_header_and_body() {
+        // Shift_JISの"あ"はUTF-8としては不正なバイト列
+        let raw = b"HTTP/1.1 200 OK\r\nX-Name: \x82\xA0\r\n\r\n\x82\xA0";
+        let res = HttpResponse::new(raw).expect("Failed to parse http response");
+        assert_eq!(res.header_value("X-Name").unwrap(), "\u{82}\u{A0}");
+        assert_eq!(res.body(), [0x82, 0xA0].to_vec());
+    }
+
+    #[test]
+    fn test_chunked_body() {
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nTrailer: Expires\r\n\r\n5;ext=1\r\nHello\r\n8\r\n, World!\r\n0\r\nExpires: never\r\n\r\n";
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.text(), "Hello, World!");
+        assert_eq!(res.headers().len(), 3);
+        assert_eq!(res.trailers().len(), 1);
+        assert_eq!(res.header_value("Expires").unwrap(), "never");
+        assert_eq!(res.trailers().get("expires"), Some("never".to_string()));
+    }
+
+    #[test]
+    fn test_chunked_applies_only_to_last_coding() {
+        let raw = "HTTP/1.1 200 OK\r\ntransfer-encoding: gzip, CHUNKED\r\n\r\n1\r\na\r\n0\r\n\r\n";
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.body(), b"a".to_vec());
+
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nzz\r\n";
+        assert!(HttpResponse::new(raw.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_content_length() {
+        // Content-Lengthより後ろのバイトはボディに含めない
+        let raw = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello, World!";
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.text(), "Hello");
+
+        let raw = "HTTP/1.1 304 Not Modified\r\nContent-Length: 5\r\n\r\n";
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert!(res.body().is_empty());
+
+        let raw = "HTTP/1.1 200 OK\r\nContent-Length: abc\r\n\r\n";
+        assert!(HttpResponse::new(raw.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_keep_alive() {
+        let res = HttpResponse::new(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").unwrap();
+        assert!(res.is_keep_alive());
+        let res =
+            HttpResponse::new(b"HTTP/1.1 200 OK\r\nConnection: Close\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        assert!(!res.is_keep_alive());
+        let res = HttpResponse::new(b"HTTP/1.0 200 OK\r\nContent-Length: 0\r\n\r\n").unwrap();
+        assert!(!res.is_keep_alive());
+        let res = HttpResponse::new(
+            b"HTTP/1.0 200 OK\r\nConnection: keep-alive\r\nContent-Length: 0\r\n\r\n",
+        )
+        .unwrap();
+        assert!(res.is_keep_alive());
+        // 長さが分からないボディは接続を閉じて終わる
+        let res = HttpResponse::new(b"HTTP/1.1 200 OK\r\n\r\nbody").unwrap();
+        assert!(!res.is_keep_alive());
+    }
+
+    #[test]
+    fn test_content_encoding() {
+        let html = include_bytes!("../../testdata/inflate/sample.html");
+
+        let mut raw =
+            b"HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\nContent-Length: 550\r\n\r\n".to_vec();
+        raw.extend_from_slice(include_bytes!("../../testdata/inflate/sample.html.gz"));
+        let res = HttpResponse::new(&raw).expect("Failed to parse http response");
+        assert_eq!(res.body(), html.to_vec());
+        assert_eq!(res.header_value("Content-Encoding"), None);
+
+        let mut raw = b"HTTP/1.1 200 OK\r\nContent-Encoding: DEFLATE\r\n\r\n".to_vec();
+        raw.extend_from_slice(include_bytes!("../../testdata/inflate/sample.html.zlib"));
+        let res = HttpResponse::new(&raw).expect("Failed to parse http response");
+        assert_eq!(res.body(), html.to_vec());
+
+        // 対応していないコーディングはそのまま
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Encoding: br\r\n\r\n\x0b\x01\x80";
+        let res = HttpResponse::new(raw).expect("Failed to parse http response");
+        assert_eq!(res.body(), b"\x0b\x01\x80".to_vec());
+        assert_eq!(res.header_value("Content-Encoding"), Some("br".to_string()));
+
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\n\r\nnot gzip";
+        assert!(HttpResponse::new(raw).is_err());
+    }
+
+    #[test]
+    fn test_status_line_without_reason() {
+        let raw = "HTTP/1.1 204\r\n\r\n".to_string();
+        let res = HttpResponse::new(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.status_code(), 204);
+        assert_eq!(res.reason(), "");
+        assert!(res.body().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let raw = "Invalid HTTP response".to_string();
+
+        assert!(HttpResponse::new(raw.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_obs_fold() {
+        let raw = "HTTP/1.1 200 OK\r\nX-Folded: a\r\n  b\r\n\tc\r\nContent-Length: 0\r\n\r\n";
+        let res = HttpResponse::parse(raw.as_bytes()).expect("Failed to parse http response");
+        assert_eq!(res.header_value("X-Folded"), Some("a b c".to_string()));
+        assert_eq!(res.headers().len(), 2);
+
+        let raw = "HTTP/1.1 200 OK\r\n folded\r\n\r\n";
+        assert_eq!(
+            HttpResponse::parse(raw.as_bytes()).unwrap_err(),
+            ResponseError::InvalidHeader(" folded".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases: [(&[u8], ResponseError); 9] = [
+            (b"", ResponseError::Empty),
+            (b"\r\n \t", ResponseError::Empty),
+            (
+                b"HTTP/x 200 OK\r\n\r\n",
+                ResponseError::InvalidVersion("HTTP/x".to_string()),
+            ),
+            (
+                b"HTTP/1.1\r\n\r\n",
+                ResponseError::InvalidStatusCode("".to_string()),
+            ),
+            (
+                b"HTTP/1.1 20 OK\r\n\r\n",
+                ResponseError::InvalidStatusCode("20".to_string()),
+            ),
+            (
+                b"HTTP/1.1 abc OK\r\n\r\n",
+                ResponseError::InvalidStatusCode("abc".to_string()),
+            ),
+            (
+                b"HTTP/1.1 200 OK\r\nContent-Length : 5\r\n\r\nhello",
+                ResponseError::InvalidHeader("Content-Length : 5".to_string()),
+            ),
+            (
+                b"HTTP/1.1 200 OK\r\nContent-Length: 1\r\nContent-Length: 2\r\n\r\nab",
+                ResponseError::InvalidContentLength("1, 2".to_string()),
+            ),
+            (
+                include_bytes!("../../testdata/http_response/truncated_content_length"),
+                ResponseError::TruncatedBody(10, 5),
+            ),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(HttpResponse::parse(raw).unwrap_err(), expected);
+        }
+        assert_eq!(
+            HttpResponse::new(b"HTTP/1.1 999999999999 OK\r\n\r\n").unwrap_err(),
+            Error::UnexpectedResponse("Invalid status code: 999999999999".to_string())
+        );
+    }
+
+    // 過去にパニックの原因になりうると分かった入力。fuzz/でも初期の入力として使う
+    #[test]
+    fn test_regression_corpus() {
+        let corpus: [&[u8]; 21] = [
+            include_bytes!("../../testdata/http_response/bad_gzip"),
+            include_bytes!("../../testdata/http_response/bad_version"),
+            include_bytes!("../../testdata/http_response/conflicting_content_length"),
+            include_bytes!("../../testdata/http_response/empty"),
+            include_bytes!("../../testdata/http_response/fold_first"),
+            include_bytes!("../../testdata/http_response/huge_chunk"),
+            include_bytes!("../../testdata/http_response/huge_content_length"),
+            include_bytes!("../../testdata/http_response/long_status"),
+            include_bytes!("../../testdata/http_response/no_colon"),
+            include_bytes!("../../testdata/http_response/no_reason"),
+            include_bytes!("../../testdata/http_response/non_ascii_header"),
+            include_bytes!("../../testdata/http_response/non_numeric_status"),
+            include_bytes!("../../testdata/http_response/obs_fold"),
+            include_bytes!("../../testdata/http_response/short_status"),
+            include_bytes!("../../testdata/http_response/space_before_colon"),
+            include_bytes!("../../testdata/http_response/status_line_only"),
+            include_bytes!("../../testdata/http_response/truncated_chunk"),
+            include_bytes!("../../testdata/http_response/truncated_content_length"),
+            include_bytes!("../../testdata/http_response/truncated_gzip"),
+            include_bytes!("../../testdata/http_response/version_only"),
+            include_bytes!("../../testdata/http_response/whitespace_only"),
+        ];
+        for raw in corpus {
+            let _ = HttpResponse::parse(raw);
+            let _ = crate::http::framing::message_end(raw);
+            for split in 1..4 {
+                let mut parser = crate::http::parser::ResponseParser::new();
+                for chunk in raw.chunks(split) {
+                    if parser.feed(chunk).is_err() {
+                        break;
+                    }
+                }
+                let _ = parser.finish();
+            }
+        }
+
+        let res = HttpResponse::parse(include_bytes!("../../testdata/http_response/no_reason"))
+            .expect("Failed to parse http response");
+        assert_eq!(res.reason(), "");
+        assert_eq!(res.body(), b"ok".to_vec());
+    }
+}
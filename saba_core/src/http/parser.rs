@@ -0,0 +1,351 @@
+//! https://httpwg.org/specs/rfc9112.html#message.format
+
+use crate::error::Error;
+use crate::http::chunked::parse_chunk_size_line;
+use crate::http::framing::body_length;
+use crate::http::framing::has_complete_head;
+use crate::http::framing::BodyLength;
+use crate::http::headers::HeaderMap;
+use crate::http::parse_header_line;
+use crate::http::HttpResponse;
+use crate::http::Method;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// ResponseParserが受信の途中で返す出来事
+#[derive(Debug, Clone)]
+pub enum ResponseEvent {
+    // ステータスラインとヘッダー。ボディは空
+    Head(HttpResponse),
+    // 届いた分のボディ。チャンク形式の場合はデコード済み
+    Body(Vec<u8>),
+    // チャンク形式のボディの後ろで送られたヘッダー
+    Trailers(HeaderMap),
+    // レスポンスの終わり
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Head,
+    // 残りのバイト数
+    FixedBody(usize),
+    ChunkSize,
+    // チャンクの残りのバイト数
+    ChunkData(usize),
+    // チャンクのデータの後ろの改行
+    ChunkDataEnd,
+    Trailers,
+    UntilClose,
+    Done,
+}
+
+/// 受信したバイト列を届いた分から少しずつ解析する
+/// レスポンス全体がそろうのを待たずに、ヘッダーやボディの一部を取り出せる
+#[derive(Debug, Clone)]
+pub struct ResponseParser {
+    state: State,
+    // 受信済みで、まだ解析していないバイト列
+    buffer: Vec<u8>,
+    trailers: HeaderMap,
+    // HEADリクエストへのレスポンスにはボディがない
+    head_request: bool,
+}
+
+impl ResponseParser {
+    pub fn new() -> Self {
+        Self {
+            state: State::Head,
+            buffer: Vec::new(),
+            trailers: HeaderMap::new(),
+            head_request: false,
+        }
+    }
+
+    // リクエストのメソッドによってボディの有無が変わるので、それを考慮して解析する
+    /// https://httpwg.org/specs/rfc9112.html#message.body.length
+    pub fn for_method(method: Method) -> Self {
+        Self {
+            head_request: method == Method::Head,
+            ..Self::new()
+        }
+    }
+
+    // レスポンスの終わりまで解析したかどうか
+    pub fn is_done(&self) -> bool {
+        self.state == State::Done
+    }
+
+    // 受信したバイト列を渡し、それによって分かったことを順に返す
+    pub fn feed(&mut self, input: &[u8]) -> Result<Vec<ResponseEvent>, Error> {
+        self.buffer.extend_from_slice(input);
+
+        let mut events = Vec::new();
+        while self.step(&mut events)? {}
+        Ok(events)
+    }
+
+    // 接続が閉じられたときに呼ぶ。長さの分からないボディはここで終わる
+    pub fn finish(&mut self) -> Result<Vec<ResponseEvent>, Error> {
+        match self.state {
+            State::Done => Ok(Vec::new()),
+            State::UntilClose => {
+                self.state = State::Done;
+                Ok(Vec::from([ResponseEvent::End]))
+            }
+            _ => Err(Error::UnexpectedResponse(
+                "Connection closed before the response was complete".to_string(),
+            )),
+        }
+    }
+
+    // 解析を1段階進める。入力が足りずに進めない場合はfalseを返す
+    fn step(&mut self, events: &mut Vec<ResponseEvent>) -> Result<bool, Error> {
+        match self.state {
+            State::Head => {
+                if !has_complete_head(&self.buffer) {
+                    return Ok(false);
+                }
+                let (response, remaining) = HttpResponse::parse_head(&self.buffer)?;
+                let consumed = self.buffer.len() - remaining.len();
+                self.buffer.drain(..consumed);
+
+                // "100 Continue"のような途中経過のレスポンスは読み飛ばし、次のレスポンスを待つ
+                let status_code = response.status_code();
+                if (100..200).contains(&status_code) && status_code != 101 {
+                    return Ok(true);
+                }
+
+                let length = if self.head_request {
+                    BodyLength::Empty
+                } else {
+                    body_length(status_code, &response.headers())?
+                };
+                self.state = match length {
+                    BodyLength::Empty | BodyLength::Fixed(0) => State::Done,
+                    BodyLength::Fixed(length) => State::FixedBody(length),
+                    BodyLength::Chunked => State::ChunkSize,
+                    BodyLength::UntilClose => State::UntilClose,
+                };
+                events.push(ResponseEvent::Head(response));
+                if self.state == State::Done {
+                    events.push(ResponseEvent::End);
+                }
+                Ok(true)
+            }
+            State::FixedBody(length) => {
+                if self.buffer.is_empty() {
+                    return Ok(false);
+                }
+                let rest = self.take_body(length, events);
+                if rest == 0 {
+                    self.state = State::Done;
+                    events.push(ResponseEvent::End);
+                } else {
+                    self.state = State::FixedBody(rest);
+                }
+                Ok(true)
+            }
+            State::ChunkSize => {
+                let line = match self.take_line() {
+                    Some(line) => line,
+                    None => return Ok(false),
+                };
+                let size = parse_chunk_size_line(&line).map_err(|e| {
+                    Error::UnexpectedResponse(format!("Invalid chunked body: {:?}", e))
+                })?;
+                self.state = if size == 0 {
+                    State::Trailers
+                } else {
+                    State::ChunkData(size)
+                };
+                Ok(true)
+            }
+            State::ChunkData(length) => {
+                if self.buffer.is_empty() {
+                    return Ok(false);
+                }
+                let rest = self.take_body(length, events);
+                self.state = if rest == 0 {
+                    State::ChunkDataEnd
+                } else {
+                    State::ChunkData(rest)
+                };
+                Ok(true)
+            }
+            State::ChunkDataEnd => {
+                let line = match self.take_line() {
+                    Some(line) => line,
+                    None => return Ok(false),
+                };
+                if !line.is_empty() {
+                    return Err(Error::UnexpectedResponse(
+                        "Invalid chunked body: missing line break after chunk data".to_string(),
+                    ));
+                }
+                self.state = State::ChunkSize;
+                Ok(true)
+            }
+            State::Trailers => {
+                let line = match self.take_line() {
+                    Some(line) => line,
+                    None => return Ok(false),
+                };
+                if !line.is_empty() {
+                    if let Some(header) = parse_header_line(&line) {
+                        self.trailers.append(&header.name(), &header.value());
+                    }
+                    return Ok(true);
+                }
+
+                if !self.trailers.is_empty() {
+                    let trailers = core::mem::take(&mut self.trailers);
+                    events.push(ResponseEvent::Trailers(trailers));
+                }
+                self.state = State::Done;
+                events.push(ResponseEvent::End);
+                Ok(true)
+            }
+            State::UntilClose => {
+                if self.buffer.is_empty() {
+                    return Ok(false);
+                }
+                let body = core::mem::take(&mut self.buffer);
+                events.push(ResponseEvent::Body(body));
+                Ok(true)
+            }
+            // レスポンスの後ろに届いたバイト列は解析しない
+            State::Done => Ok(false),
+        }
+    }
+
+    // 最大でlengthバイトのボディを取り出し、残りのバイト数を返す
+    fn take_body(&mut self, length: usize, events: &mut Vec<ResponseEvent>) -> usize {
+        let n = length.min(self.buffer.len());
+        events.push(ResponseEvent::Body(self.buffer.drain(..n).collect()));
+        length - n
+    }
+
+    // 改行までを1行として取り出す。改行はCRLFとLFのどちらも受け付ける
+    // 改行がまだ届いていない場合はNone
+    fn take_line(&mut self) -> Option<Vec<u8>> {
+        let end = self.buffer.iter().position(|b| *b == b'\n')?;
+        let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Some(line)
+    }
+}
+
+impl Default for ResponseParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1バイトずつ渡して、ボディをつなげたものとイベントの一覧を返す
+    fn feed_bytewise(input: &[u8]) -> (Vec<ResponseEvent>, Vec<u8>) {
+        let mut parser = ResponseParser::new();
+        let mut events = Vec::new();
+        for b in input {
+            events.extend(parser.feed(&[*b]).expect("failed to parse response"));
+        }
+        events.extend(parser.finish().expect("failed to finish response"));
+
+        let mut body = Vec::new();
+        for event in &events {
+            if let ResponseEvent::Body(chunk) = event {
+                body.extend_from_slice(chunk);
+            }
+        }
+        (events, body)
+    }
+
+    #[test]
+    fn test_head_before_body() {
+        let mut parser = ResponseParser::new();
+        let events = parser
+            .feed(b"HTTP/1.1 200 OK\r\nContent-Length: 12\r\n\r\nHello")
+            .expect("failed to parse response");
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            ResponseEvent::Head(head) => {
+                assert_eq!(head.status_code(), 200);
+                assert_eq!(head.headers().content_length(), Some(12));
+            }
+            e => panic!("unexpected event {:?}", e),
+        }
+        assert!(matches!(&events[1], ResponseEvent::Body(b) if b == b"Hello"));
+        assert!(!parser.is_done());
+
+        let events = parser.feed(b", World").expect("failed to parse response");
+        assert!(matches!(&events[0], ResponseEvent::Body(b) if b == b", World"));
+        assert!(matches!(&events[1], ResponseEvent::End));
+        assert!(parser.is_done());
+    }
+
+    #[test]
+    fn test_chunked_bytewise() {
+        let (events, body) = feed_bytewise(
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5;ext\r\nHello\r\n8\r\n, World!\r\n0\r\nExpires: never\r\n\r\n",
+        );
+        assert_eq!(body, b"Hello, World!".to_vec());
+        let trailers = events.iter().find_map(|e| match e {
+            ResponseEvent::Trailers(t) => Some(t.clone()),
+            _ => None,
+        });
+        assert_eq!(
+            trailers.and_then(|t| t.get("expires")),
+            Some("never".to_string())
+        );
+        assert!(matches!(events.last(), Some(ResponseEvent::End)));
+    }
+
+    #[test]
+    fn test_until_close() {
+        let (events, body) = feed_bytewise(b"HTTP/1.0 200 OK\n\n<html></html>");
+        assert_eq!(body, b"<html></html>".to_vec());
+        assert!(matches!(events.last(), Some(ResponseEvent::End)));
+    }
+
+    #[test]
+    fn test_skip_continue() {
+        let (events, body) =
+            feed_bytewise(b"HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 204 No Content\r\n\r\n");
+        assert!(body.is_empty());
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], ResponseEvent::Head(h) if h.status_code() == 204));
+    }
+
+    #[test]
+    fn test_head_request() {
+        let mut parser = ResponseParser::for_method(Method::Head);
+        let events = parser
+            .feed(b"HTTP/1.1 200 OK\r\nContent-Length: 1024\r\n\r\n")
+            .expect("failed to parse response");
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[1], ResponseEvent::End));
+        assert!(parser.is_done());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut parser = ResponseParser::new();
+        parser
+            .feed(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nabc")
+            .expect("failed to parse response");
+        assert!(parser.finish().is_err());
+
+        let mut parser = ResponseParser::new();
+        assert!(parser
+            .feed(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nabc\r\n")
+            .is_err());
+    }
+}
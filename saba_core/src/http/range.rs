@@ -0,0 +1,346 @@
+//! https://httpwg.org/specs/rfc9110.html#range.requests
+
+use crate::error::Error;
+use crate::http::HttpRequest;
+use crate::http::HttpResponse;
+use crate::http::Method;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Rangeヘッダーで要求するバイトの範囲
+/// https://httpwg.org/specs/rfc9110.html#byte.ranges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    // firstからlastまで（lastを含む）
+    Bounded(u64, u64),
+    // firstから最後まで
+    From(u64),
+    // 最後のnバイト
+    Suffix(u64),
+}
+
+impl ByteRange {
+    // "bytes=0-499"のようなRangeヘッダーの値
+    pub fn header_value(&self) -> String {
+        match self {
+            ByteRange::Bounded(first, last) => format!("bytes={}-{}", first, last),
+            ByteRange::From(first) => format!("bytes={}-", first),
+            ByteRange::Suffix(length) => format!("bytes=-{}", length),
+        }
+    }
+}
+
+/// 206 Partial ContentのContent-Rangeヘッダー
+/// https://httpwg.org/specs/rfc9110.html#field.content-range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    first: u64,
+    last: u64,
+    // "*"の場合は全体の長さが分からない
+    complete_length: Option<u64>,
+}
+
+impl ContentRange {
+    // "bytes 0-499/1234"や"bytes 0-499/*"を解析する
+    // 範囲が逆順である、全体の長さを超えているなど、不正な値の場合はNone
+    pub fn parse(value: &str) -> Option<Self> {
+        let (range, complete_length) = strip_bytes_unit(value)?.split_once('/')?;
+        let (first, last) = range.split_once('-')?;
+        let first = parse_number(first)?;
+        let last = parse_number(last)?;
+        let complete_length = match complete_length {
+            "*" => None,
+            length => Some(parse_number(length)?),
+        };
+        if first > last || complete_length.is_some_and(|length| last >= length) {
+            return None;
+        }
+        Some(Self {
+            first,
+            last,
+            complete_length,
+        })
+    }
+
+    // 416 Range Not Satisfiableの"bytes */1234"から全体の長さを取り出す
+    pub fn parse_unsatisfied(value: &str) -> Option<u64> {
+        parse_number(strip_bytes_unit(value)?.strip_prefix("*/")?)
+    }
+
+    pub fn first(&self) -> u64 {
+        self.first
+    }
+
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+
+    pub fn complete_length(&self) -> Option<u64> {
+        self.complete_length
+    }
+
+    // この範囲に含まれるバイト数
+    pub fn length(&self) -> u64 {
+        self.last - self.first + 1
+    }
+}
+
+/// 途中で切れても、受け取った分の続きから再開できるダウンロード
+/// 2回目以降のリクエストでは、受け取ったバイト数をRangeで、元のレスポンスをIf-Rangeで指定する
+/// https://httpwg.org/specs/rfc9110.html#field.if-range
+#[derive(Debug, Clone)]
+pub struct Download {
+    url: Url,
+    body: Vec<u8>,
+    // 最初のレスポンスのETagまたはLast-Modified。変わっていれば最初から受け取り直す
+    validator: Option<String>,
+    complete_length: Option<u64>,
+    done: bool,
+}
+
+impl Download {
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            body: Vec::new(),
+            validator: None,
+            complete_length: None,
+            done: false,
+        }
+    }
+
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    // これまでに受け取ったボディ
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    pub fn received(&self) -> u64 {
+        self.body.len() as u64
+    }
+
+    // 分かっている場合は全体のバイト数
+    pub fn complete_length(&self) -> Option<u64> {
+        self.complete_length
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    // 次に送るリクエスト。範囲がずれないよう、圧縮せずに送ってもらう
+    pub fn request(&self) -> HttpRequest {
+        let mut request =
+            HttpRequest::for_url(Method::Get, &self.url).with_header("Accept-Encoding", "identity");
+        if !self.body.is_empty() {
+            request = request.with_range(ByteRange::From(self.received()));
+            if let Some(validator) = &self.validator {
+                request = request.with_header("If-Range", validator);
+            }
+        }
+        request
+    }
+
+    // ステータスラインとヘッダーを受け取ったときに呼ぶ
+    // 206であれば続きを受け取り、200であれば最初から受け取り直す
+    pub fn start(&mut self, head: &HttpResponse) -> Result<(), Error> {
+        let headers = head.headers();
+        match head.status_code() {
+            200 => {
+                // 範囲を無視された、またはIf-Rangeが一致しなかった
+                self.body.clear();
+                self.complete_length = headers.content_length().map(|length| length as u64);
+            }
+            206 => {
+                let value = headers.get("Content-Range").unwrap_or_default();
+                let range = ContentRange::parse(&value).ok_or_else(|| {
+                    Error::UnexpectedResponse(format!("Invalid Content-Range: {}", value))
+                })?;
+                if range.first() != self.received() {
+                    return Err(Error::UnexpectedResponse(format!(
+                        "Content-Range {} does not continue from byte {}",
+                        value,
+                        self.received()
+                    )));
+                }
+                self.complete_length = range.complete_length().or(self.complete_length);
+            }
+            416 => {
+                // すでに最後まで受け取っていた場合は、要求した位置から先が存在しない
+                let length = headers
+                    .get("Content-Range")
+                    .and_then(|v| ContentRange::parse_unsatisfied(&v));
+                if length.is_none() || length != Some(self.received()) {
+                    return Err(Error::UnexpectedResponse(format!(
+                        "Range not satisfiable at byte {}",
+                        self.received()
+                    )));
+                }
+                self.complete_length = length;
+                self.done = true;
+                return Ok(());
+            }
+            status_code => {
+                return Err(Error::UnexpectedResponse(format!(
+                    "Unexpected status code for a download: {}",
+                    status_code
+                )))
+            }
+        }
+
+        // 弱いETagはIf-Rangeに使えない
+        self.validator = headers
+            .get("ETag")
+            .filter(|etag| !etag.starts_with("W/"))
+            .or_else(|| headers.get("Last-Modified"));
+        Ok(())
+    }
+
+    // 受け取ったボディを後ろに加える
+    pub fn append(&mut self, chunk: &[u8]) {
+        if !self.done {
+            self.body.extend_from_slice(chunk);
+        }
+    }
+
+    // レスポンスの終わりまで受け取ったときに呼ぶ
+    pub fn finish(&mut self) -> Result<(), Error> {
+        if let Some(length) = self.complete_length {
+            if self.received() != length {
+                return Err(Error::UnexpectedResponse(format!(
+                    "Received {} bytes, expected {}",
+                    self.received(),
+                    length
+                )));
+            }
+        }
+        self.done = true;
+        Ok(())
+    }
+}
+
+fn strip_bytes_unit(value: &str) -> Option<&str> {
+    let value = value.trim();
+    let unit = value.get(..6)?;
+    if unit.eq_ignore_ascii_case("bytes ") {
+        Some(value[6..].trim_start())
+    } else {
+        None
+    }
+}
+
+fn parse_number(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse::<u64>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::response;
+    use crate::test_helpers::url;
+    use alloc::string::ToString;
+
+    fn download() -> Download {
+        Download::new(url("http://example.com/large.bin"))
+    }
+
+    #[test]
+    fn test_byte_range() {
+        assert_eq!(ByteRange::Bounded(0, 499).header_value(), "bytes=0-499");
+        assert_eq!(ByteRange::From(500).header_value(), "bytes=500-");
+        assert_eq!(ByteRange::Suffix(10).header_value(), "bytes=-10");
+    }
+
+    #[test]
+    fn test_content_range() {
+        let range = ContentRange::parse("bytes 21010-47021/47022").expect("failed to parse");
+        assert_eq!(range.first(), 21010);
+        assert_eq!(range.last(), 47021);
+        assert_eq!(range.complete_length(), Some(47022));
+        assert_eq!(range.length(), 26012);
+
+        let range = ContentRange::parse("Bytes 0-0/*").expect("failed to parse");
+        assert_eq!(range.complete_length(), None);
+
+        assert_eq!(ContentRange::parse("bytes 5-4/10"), None);
+        assert_eq!(ContentRange::parse("bytes 0-10/10"), None);
+        assert_eq!(ContentRange::parse("bytes -1-5/10"), None);
+        assert_eq!(ContentRange::parse("items 0-1/2"), None);
+        assert_eq!(
+            ContentRange::parse_unsatisfied("bytes */47022"),
+            Some(47022)
+        );
+        assert_eq!(ContentRange::parse_unsatisfied("bytes 0-1/2"), None);
+    }
+
+    #[test]
+    fn test_resume() {
+        let mut download = download();
+        assert!(!download.request().headers().contains("Range"));
+        download
+            .start(&response(
+                200,
+                &[("Content-Length", "10"), ("ETag", "\"v1\"")],
+            ))
+            .expect("failed to start download");
+        download.append(b"0123");
+        // ここで接続が切れた
+        assert!(download.finish().is_err());
+
+        let request = download.request();
+        assert_eq!(request.headers().get("Range"), Some("bytes=4-".to_string()));
+        assert_eq!(
+            request.headers().get("If-Range"),
+            Some("\"v1\"".to_string())
+        );
+        assert_eq!(
+            request.headers().get("Accept-Encoding"),
+            Some("identity".to_string())
+        );
+
+        download
+            .start(&response(206, &[("Content-Range", "bytes 4-9/10")]))
+            .expect("failed to resume download");
+        download.append(b"456789");
+        download.finish().expect("failed to finish download");
+        assert!(download.is_done());
+        assert_eq!(download.body(), b"0123456789".to_vec());
+    }
+
+    #[test]
+    fn test_restart_and_errors() {
+        let mut download = download();
+        download
+            .start(&response(200, &[("ETag", "W/\"weak\"")]))
+            .expect("failed to start download");
+        download.append(b"old");
+        assert!(!download.request().headers().contains("If-Range"));
+
+        // 範囲を無視して全体が送られてきた場合は最初から受け取り直す
+        download
+            .start(&response(200, &[("Content-Length", "3")]))
+            .expect("failed to restart download");
+        download.append(b"new");
+        download.finish().expect("failed to finish download");
+        assert_eq!(download.body(), b"new".to_vec());
+
+        let mut download = self::download();
+        download.append(b"abc");
+        assert!(download
+            .start(&response(206, &[("Content-Range", "bytes 0-5/6")]))
+            .is_err());
+        download
+            .start(&response(416, &[("Content-Range", "bytes */3")]))
+            .expect("failed to finish download");
+        assert!(download.is_done());
+        assert!(download.start(&response(404, &[])).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! https://fetch.spec.whatwg.org/#http-redirect-fetch
+
+use crate::error::Error;
+use crate::http::HttpRequest;
+use crate::http::HttpResponse;
+use crate::http::Method;
+use crate::url::Scheme;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+// たどるリダイレクトの最大数
+/// https://fetch.spec.whatwg.org/#http-redirect-fetch
+pub const MAX_REDIRECTS: usize = 20;
+
+// リクエストのボディについて説明するヘッダー。ボディを送らなくなった場合は取り除く
+/// https://fetch.spec.whatwg.org/#request-body-header-name
+const REQUEST_BODY_HEADERS: [&str; 5] = [
+    "Content-Encoding",
+    "Content-Language",
+    "Content-Location",
+    "Content-Type",
+    "Content-Length",
+];
+
+/// https://fetch.spec.whatwg.org/#redirect-status
+pub fn is_redirect(status_code: u32) -> bool {
+    matches!(status_code, 301 | 302 | 303 | 307 | 308)
+}
+
+/// リダイレクトをたどりながら、次に送るリクエストとそのURLを管理する
+#[derive(Debug, Clone)]
+pub struct Redirects {
+    url: Url,
+    request: HttpRequest,
+    // これまでに送ったリクエストのメソッドとURL
+    history: Vec<(Method, String)>,
+}
+
+impl Redirects {
+    pub fn new(url: Url, request: HttpRequest) -> Self {
+        let history = Vec::from([(request.method(), url.without_fragment().to_string())]);
+        Self {
+            url,
+            request,
+            history,
+        }
+    }
+
+    // 次に送るリクエストのURL。すべてたどり終えた後は最終的なURLになる
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    pub fn request(&self) -> HttpRequest {
+        self.request.clone()
+    }
+
+    // これまでにたどったリダイレクトの数
+    pub fn count(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    // レスポンスがリダイレクトであれば次のリクエストを用意してtrueを返す
+    // リダイレクトでない、またはLocationヘッダーがない場合はfalse
+    pub fn follow(&mut self, response: &HttpResponse) -> Result<bool, Error> {
+        if !is_redirect(response.status_code()) {
+            return Ok(false);
+        }
+        let location = match response.headers().location() {
+            Some(location) => location,
+            None => return Ok(false),
+        };
+
+        let mut next = self.url.join(&location).map_err(|e| {
+            Error::UnexpectedResponse(format!("Invalid redirect location {}: {:?}", location, e))
+        })?;
+        // TLSには対応していないので、httpsへのリダイレクトもここで失敗にする
+        if next.scheme_kind() != Some(Scheme::Http) {
+            return Err(Error::UnexpectedResponse(format!(
+                "Redirect to unsupported scheme: {}",
+                next.scheme()
+            )));
+        }
+        if self.count() >= MAX_REDIRECTS {
+            return Err(Error::Network(format!(
+                "Too many redirects (more than {})",
+                MAX_REDIRECTS
+            )));
+        }
+        // Locationにフラグメントがなければ、元のURLのフラグメントを引き継ぐ
+        if next.fragment().is_empty() && !self.url.fragment().is_empty() {
+            next = next
+                .join(&format!("#{}", self.url.fragment()))
+                .unwrap_or(next);
+        }
+
+        let request = self.redirect_request(response.status_code(), &next);
+        // ログインのページを経由して元のページに戻るように、同じURLを再び訪れるのは正しい使い方なので、
+        // 同じURLに直接リダイレクトされた場合だけをループとし、それ以外はMAX_REDIRECTSで止める
+        let key = (request.method(), next.without_fragment().to_string());
+        if self.history.last() == Some(&key) {
+            return Err(Error::Network(format!(
+                "Redirect loop detected at {}",
+                key.1
+            )));
+        }
+
+        self.history.push(key);
+        self.url = next;
+        self.request = request;
+        Ok(true)
+    }
+
+    // リダイレクト先に送るリクエストを作る
+    // 303の場合と、301と302でPOSTだった場合はボディなしのGETに変える
+    fn redirect_request(&self, status_code: u32, next: &Url) -> HttpRequest {
+        let method = self.request.method();
+        let to_get = (matches!(status_code, 301 | 302) && method == Method::Post)
+            || (status_code == 303 && !matches!(method, Method::Get | Method::Head));
+        let method = if to_get { Method::Get } else { method };
+        let cross_origin = !self.url.origin().is_same_origin(&next.origin());
+
+        let mut request = HttpRequest::for_url(method, next);
+        for header in self.request.headers().iter() {
+            let name = header.name();
+            // Hostはリダイレクト先のホストに合わせて付け直す
+            if name.eq_ignore_ascii_case("Host") {
+                continue;
+            }
+            if to_get
+                && REQUEST_BODY_HEADERS
+                    .iter()
+                    .any(|h| h.eq_ignore_ascii_case(&name))
+            {
+                continue;
+            }
+            // 認証情報は別のオリジンに送らない
+            if cross_origin && name.eq_ignore_ascii_case("Authorization") {
+                continue;
+            }
+            request = request.with_header(&name, &header.value());
+        }
+        if !to_get && !self.request.body().is_empty() {
+            request = request.with_body(self.request.body());
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::response;
+    use crate::test_helpers::url;
+
+    fn redirect(status_code: u32, location: &str) -> HttpResponse {
+        response(status_code, &[("Location", location)])
+    }
+
+    #[test]
+    fn test_follow_relative() {
+        let start = url("http://example.com/a/b.html#top");
+        let mut redirects =
+            Redirects::new(start.clone(), HttpRequest::for_url(Method::Get, &start));
+        assert!(redirects
+            .follow(&redirect(301, "../c.html?q=1"))
+            .expect("failed to follow redirect"));
+        assert_eq!(
+            redirects.url().to_string(),
+            "http://example.com/c.html?q=1#top"
+        );
+        assert_eq!(redirects.request().target(), "/c.html?q=1");
+        assert_eq!(redirects.count(), 1);
+
+        let ok = redirect(200, "/ignored");
+        assert!(!redirects.follow(&ok).expect("failed to follow redirect"));
+    }
+
+    #[test]
+    fn test_method_rewriting() {
+        let start = url("http://example.com/form");
+        let post = HttpRequest::post("/form", "text/plain", b"a=1".to_vec())
+            .with_header("Authorization", "Basic eDp5");
+
+        let mut redirects = Redirects::new(start.clone(), post.clone());
+        redirects
+            .follow(&redirect(303, "/done"))
+            .expect("failed to follow redirect");
+        let request = redirects.request();
+        assert_eq!(request.method(), Method::Get);
+        assert!(request.body().is_empty());
+        assert!(!request.headers().contains("Content-Type"));
+        assert!(request.headers().contains("Authorization"));
+
+        for status_code in [307, 308] {
+            let mut redirects = Redirects::new(start.clone(), post.clone());
+            redirects
+                .follow(&redirect(status_code, "http://other.example/form"))
+                .expect("failed to follow redirect");
+            let request = redirects.request();
+            assert_eq!(request.method(), Method::Post);
+            assert_eq!(request.body(), b"a=1".to_vec());
+            assert_eq!(request.headers().content_length(), Some(3));
+            // 別のオリジンには認証情報を送らない
+            assert!(!request.headers().contains("Authorization"));
+        }
+    }
+
+    #[test]
+    fn test_loop_and_limit() {
+        let start = url("http://example.com/a");
+        let mut redirects =
+            Redirects::new(start.clone(), HttpRequest::for_url(Method::Get, &start));
+        redirects
+            .follow(&redirect(302, "/login"))
+            .expect("failed to follow redirect");
+        // 別のページを経由して元のページに戻るのはループではない
+        redirects
+            .follow(&redirect(302, "/a"))
+            .expect("failed to follow redirect");
+        assert_eq!(redirects.count(), 2);
+        assert!(redirects.follow(&redirect(302, "/a#x")).is_err());
+
+        let mut redirects =
+            Redirects::new(start.clone(), HttpRequest::for_url(Method::Get, &start));
+        for i in 0..MAX_REDIRECTS {
+            redirects
+                .follow(&redirect(302, &format!("/{}", i)))
+                .expect("failed to follow redirect");
+        }
+        assert!(redirects.follow(&redirect(302, "/last")).is_err());
+
+        assert!(redirects
+            .follow(&redirect(302, "ftp://example.com/"))
+            .is_err());
+        let mut redirects =
+            Redirects::new(start.clone(), HttpRequest::for_url(Method::Get, &start));
+        assert_eq!(
+            redirects.follow(&redirect(301, "https://example.com/a")),
+            Err(Error::UnexpectedResponse(
+                "Redirect to unsupported scheme: https".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! https://httpwg.org/specs/rfc9112.html#request.line
+
+use crate::error::Error;
+use crate::http::headers::HeaderMap;
+use crate::http::range::ByteRange;
+use crate::url::Url;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// リクエストメソッド
+/// https://httpwg.org/specs/rfc9110.html#methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Options,
+    Patch,
+}
+
+impl Method {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+            Method::Options => "OPTIONS",
+            Method::Patch => "PATCH",
+        }
+    }
+
+    // 同じリクエストを何度送っても結果が変わらないメソッドかどうか
+    // 接続が切れた場合に自動で送り直してよいのはこれらのメソッドだけ
+    /// https://httpwg.org/specs/rfc9110.html#idempotent.methods
+    pub fn is_idempotent(&self) -> bool {
+        !matches!(self, Method::Post | Method::Patch)
+    }
+
+    // サーバーの状態を変えないメソッドかどうか
+    // これ以外のメソッドが成功した場合は、キャッシュしたレスポンスを捨てる
+    /// https://httpwg.org/specs/rfc9110.html#safe.methods
+    pub fn is_safe(&self) -> bool {
+        matches!(self, Method::Get | Method::Head | Method::Options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    method: Method,
+    target: String,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    // targetは"/index.html?q=1"のようなパスとクエリ。先頭の"/"はなくてもよい
+    // フラグメントはサーバーに送らないので取り除く
+    pub fn new(method: Method, target: &str) -> Self {
+        let target = match target.split_once('#') {
+            Some((t, _)) => t,
+            None => target,
+        };
+        let target = if target.starts_with('/') || target == "*" || is_absolute_form(target) {
+            target.to_string()
+        } else {
+            format!("/{}", target)
+        };
+
+        Self {
+            method,
+            target,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    // URLのパスとクエリをtargetにする
+    pub fn for_url(method: Method, url: &Url) -> Self {
+        let mut target = url.pathname();
+        if !url.searchpart().is_empty() {
+            target.push('?');
+            target.push_str(&url.searchpart());
+        }
+        Self::new(method, &target)
+    }
+
+    pub fn get(target: &str) -> Self {
+        Self::new(Method::Get, target)
+    }
+
+    pub fn head(target: &str) -> Self {
+        Self::new(Method::Head, target)
+    }
+
+    pub fn post(target: &str, content_type: &str, body: Vec<u8>) -> Self {
+        Self::new(Method::Post, target)
+            .with_header("Content-Type", content_type)
+            .with_body(body)
+    }
+
+    // ヘッダーを追加する。同じ名前のヘッダーがあっても置き換えない
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    // ボディを設定し、Content-Lengthをその長さにする
+    pub fn with_body(mut self, body: Vec<u8>) -> Self {
+        self.headers.set("Content-Length", &body.len().to_string());
+        self.body = body;
+        self
+    }
+
+    // ボディの一部だけを要求する
+    /// https://httpwg.org/specs/rfc9110.html#field.range
+    pub fn with_range(mut self, range: ByteRange) -> Self {
+        self.headers.set("Range", &range.header_value());
+        self
+    }
+
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    pub fn target(&self) -> String {
+        self.target.clone()
+    }
+
+    pub fn headers(&self) -> HeaderMap {
+        self.headers.clone()
+    }
+
+    pub fn body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    // 指定されていなければヘッダーを追加する
+    pub fn set_default_header(&mut self, name: &str, value: &str) {
+        if !self.headers.contains(name) {
+            self.headers.append(name, value);
+        }
+    }
+
+    // 送信するバイト列にする。改行はCRLF
+    // ヘッダーの名前や値に改行などが含まれている場合はエラー
+    /// https://httpwg.org/specs/rfc9112.html#message.format
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self
+            .target
+            .bytes()
+            .any(|b| b.is_ascii_whitespace() || b.is_ascii_control())
+        {
+            return Err(Error::Network(format!(
+                "Invalid request target: {:?}",
+                self.target
+            )));
+        }
+
+        let mut request = format!("{} {} HTTP/1.1\r\n", self.method.name(), self.target);
+        for header in self.headers.iter() {
+            let name = header.name();
+            let value = header.value();
+            if !is_valid_header_name(&name) || !is_valid_header_value(&value) {
+                return Err(Error::Network(format!(
+                    "Invalid request header: {:?}: {:?}",
+                    name, value
+                )));
+            }
+            request.push_str(&name);
+            request.push_str(": ");
+            request.push_str(&value);
+            request.push_str("\r\n");
+        }
+        request.push_str("\r\n");
+
+        let mut bytes = request.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        Ok(bytes)
+    }
+}
+
+/// https://httpwg.org/specs/rfc9110.html#fields.names
+pub(crate) fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+// 改行やNULを含む値はヘッダーを分割してしまうので受け付けない
+/// https://fetch.spec.whatwg.org/#header-value
+fn is_valid_header_value(value: &str) -> bool {
+    !value.bytes().any(|b| b == b'\r' || b == b'\n' || b == 0)
+}
+
+// "http://example.com/"のような、スキームから始まるtarget（absolute-form）かどうか
+// クエリの中の"://"はスキームとして扱わない
+/// https://httpwg.org/specs/rfc9112.html#absolute-form
+fn is_absolute_form(target: &str) -> bool {
+    match target.split_once("://") {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target() {
+        assert_eq!(HttpRequest::get("index.html").target(), "/index.html");
+        assert_eq!(HttpRequest::get("/index.html").target(), "/index.html");
+        assert_eq!(HttpRequest::get("").target(), "/");
+        assert_eq!(HttpRequest::get("a?q=1#top").target(), "/a?q=1");
+
+        let url = Url::new("http://example.com/a/b?q=1#top".to_string())
+            .parse()
+            .expect("failed to parse url");
+        assert_eq!(HttpRequest::for_url(Method::Get, &url).target(), "/a/b?q=1");
+
+        // クエリの中のURLはabsolute-formではない
+        let url = Url::new("http://example.com/login?next=http://a/".to_string())
+            .parse()
+            .expect("failed to parse url");
+        assert_eq!(
+            HttpRequest::for_url(Method::Get, &url).target(),
+            "/login?next=http://a/"
+        );
+        assert_eq!(
+            HttpRequest::get("login?next=http://a/").target(),
+            "/login?next=http://a/"
+        );
+        assert_eq!(
+            HttpRequest::get("http://example.com/a").target(),
+            "http://example.com/a"
+        );
+
+        // "//"から始まるパスの先頭を削らない
+        let url = Url::new("http://example.com//b".to_string())
+            .parse()
+            .expect("failed to parse url");
+        assert_eq!(HttpRequest::for_url(Method::Get, &url).target(), "//b");
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let request = HttpRequest::get("/index.html")
+            .with_header("Host", "example.com")
+            .with_header("Accept", "text/html");
+        assert_eq!(
+            request.to_bytes().expect("failed to serialize request"),
+            b"GET /index.html HTTP/1.1\r\nHost: example.com\r\nAccept: text/html\r\n\r\n".to_vec()
+        );
+
+        let request = HttpRequest::post("/form", "text/plain", b"hello".to_vec());
+        assert_eq!(request.method(), Method::Post);
+        assert_eq!(
+            request.to_bytes().expect("failed to serialize request"),
+            b"POST /form HTTP/1.1\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello"
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_default_header() {
+        let mut request = HttpRequest::head("/").with_header("accept", "*/*");
+        request.set_default_header("Accept", "text/html");
+        request.set_default_header("Host", "example.com");
+        assert_eq!(request.headers().get_all("Accept"), ["*/*"]);
+        assert_eq!(
+            request.headers().get("host"),
+            Some("example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let request = HttpRequest::get("/").with_header("X-Test", "a\r\nInjected: 1");
+        assert!(request.to_bytes().is_err());
+        let request = HttpRequest::get("/").with_header("Bad Name", "a");
+        assert!(request.to_bytes().is_err());
+        assert!(HttpRequest::get("/a b").to_bytes().is_err());
+    }
+}
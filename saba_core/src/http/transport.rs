@@ -0,0 +1,207 @@
+//! HttpClientがサーバーと通信するための接続
+
+use crate::error::Error;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// サーバーとの間でバイト列を送受信する接続
+pub trait Connection {
+    // すべてのバイト列を送信する
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error>;
+
+    // 受信したバイト列をbufに書き込み、そのバイト数を返す。接続が閉じられた場合は0
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+}
+
+/// ホスト名を解決してサーバーに接続する方法
+/// OSごとのネットワークの実装や、テスト用のメモリ上の接続を差し替えられるようにする
+pub trait Transport {
+    type Connection: Connection;
+
+    fn connect(&self, host: &str, port: u16) -> Result<Self::Connection, Error>;
+
+    // 現在時刻（1970年1月1日からの秒数）。時計のない環境ではNone
+    fn now(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Script {
+    host: String,
+    port: u16,
+    // リクエストを1つ受け取るたびに、先頭から順に1つ返す
+    responses: VecDeque<Vec<u8>>,
+    // 合計でこのバイト数を返した後は、受信に失敗する
+    fail_after: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct ScriptState {
+    // まだ使われていない接続
+    scripts: Vec<Script>,
+    // 送信されたリクエスト（ホスト, ポート, バイト列）
+    requests: Vec<(String, u16, Vec<u8>)>,
+    connections: usize,
+    now: Option<u64>,
+}
+
+/// 接続ごとに決められたレスポンスを返す、メモリ上のTransport
+/// ネットワークを使わずにHttpClientを試すために使う
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedTransport {
+    state: Rc<RefCell<ScriptState>>,
+}
+
+impl ScriptedTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 次にhost:portへ開かれる接続を登録する
+    // 接続はリクエストを1つ受け取るたびにresponsesを順に返し、すべて返し終えると閉じられる
+    pub fn add_connection(&self, host: &str, port: u16, responses: &[&[u8]]) {
+        self.add_script(host, port, responses, None);
+    }
+
+    // 合計でfail_afterバイトを返した後に、受信に失敗する接続を登録する
+    pub fn add_broken_connection(&self, host: &str, port: u16, response: &[u8], fail_after: usize) {
+        self.add_script(host, port, &[response], Some(fail_after));
+    }
+
+    // nowが返す時刻を変える。Noneの場合は時計のない環境として振る舞う
+    pub fn set_now(&self, now: Option<u64>) {
+        self.state.borrow_mut().now = now;
+    }
+
+    // これまでに送信されたリクエスト（ホスト, ポート, バイト列）
+    pub fn requests(&self) -> Vec<(String, u16, Vec<u8>)> {
+        self.state.borrow().requests.clone()
+    }
+
+    // これまでに開かれた接続の数
+    pub fn connection_count(&self) -> usize {
+        self.state.borrow().connections
+    }
+
+    // まだ使われていない接続の数
+    pub fn remaining_connections(&self) -> usize {
+        self.state.borrow().scripts.len()
+    }
+
+    fn add_script(&self, host: &str, port: u16, responses: &[&[u8]], fail_after: Option<usize>) {
+        self.state.borrow_mut().scripts.push(Script {
+            host: host.to_string(),
+            port,
+            responses: responses.iter().map(|r| r.to_vec()).collect(),
+            fail_after,
+        });
+    }
+}
+
+impl Transport for ScriptedTransport {
+    type Connection = ScriptedConnection;
+
+    fn connect(&self, host: &str, port: u16) -> Result<ScriptedConnection, Error> {
+        let mut state = self.state.borrow_mut();
+        let index = state
+            .scripts
+            .iter()
+            .position(|s| s.host == host && s.port == port)
+            .ok_or_else(|| Error::Network(format!("Failed to connect to {}:{}", host, port)))?;
+        state.connections += 1;
+        Ok(ScriptedConnection {
+            script: state.scripts.remove(index),
+            pending: VecDeque::new(),
+            sent: 0,
+            state: self.state.clone(),
+        })
+    }
+
+    fn now(&self) -> Option<u64> {
+        self.state.borrow().now
+    }
+}
+
+/// ScriptedTransportが開いた接続
+#[derive(Debug)]
+pub struct ScriptedConnection {
+    script: Script,
+    // 受信を待っているバイト列
+    pending: VecDeque<u8>,
+    // これまでに返したバイト数
+    sent: usize,
+    state: Rc<RefCell<ScriptState>>,
+}
+
+impl Connection for ScriptedConnection {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self.state.borrow_mut().requests.push((
+            self.script.host.clone(),
+            self.script.port,
+            buf.to_vec(),
+        ));
+        if let Some(response) = self.script.responses.pop_front() {
+            self.pending.extend(response);
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut n = buf.len().min(self.pending.len());
+        if let Some(fail_after) = self.script.fail_after {
+            if self.sent >= fail_after {
+                return Err(Error::Network("Connection reset".to_string()));
+            }
+            n = n.min(fail_after - self.sent);
+        }
+        for (b, p) in buf.iter_mut().zip(self.pending.drain(..n)) {
+            *b = p;
+        }
+        self.sent += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripted_connection() {
+        let transport = ScriptedTransport::new();
+        transport.add_connection("example.com", 80, &[b"first", b"second"]);
+        transport.add_broken_connection("example.com", 80, b"partial", 4);
+        assert!(transport.connect("example.com", 8080).is_err());
+
+        let mut connection = transport
+            .connect("example.com", 80)
+            .expect("failed to connect");
+        let mut buf = [0u8; 16];
+        connection.write_all(b"1").expect("failed to write");
+        assert_eq!(connection.read(&mut buf), Ok(5));
+        assert_eq!(&buf[..5], b"first");
+        connection.write_all(b"2").expect("failed to write");
+        assert_eq!(connection.read(&mut buf), Ok(6));
+        // 返すものがなくなると閉じられる
+        assert_eq!(connection.read(&mut buf), Ok(0));
+
+        let mut connection = transport
+            .connect("example.com", 80)
+            .expect("failed to connect");
+        connection.write_all(b"3").expect("failed to write");
+        assert_eq!(connection.read(&mut buf), Ok(4));
+        assert!(connection.read(&mut buf).is_err());
+
+        assert_eq!(transport.connection_count(), 2);
+        assert_eq!(transport.remaining_connections(), 0);
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2], ("example.com".to_string(), 80, b"3".to_vec()));
+    }
+}
@@ -0,0 +1,639 @@
+//! https://www.rfc-editor.org/rfc/rfc1951 (DEFLATE)
+//! https://www.rfc-editor.org/rfc/rfc1950 (zlib)
+//! https://www.rfc-editor.org/rfc/rfc1952 (gzip)
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InflateError {
+    // データが途中で終わっている
+    UnexpectedEnd,
+    // gzipやzlibのヘッダーが不正
+    InvalidHeader,
+    // ブロックの種類が3（予約済み）
+    InvalidBlockType,
+    // 非圧縮ブロックのLENとNLENが対応していない
+    InvalidStoredLength,
+    // ハフマン符号の定義や、符号そのものが不正
+    InvalidCode,
+    // 出力済みのデータより前を参照している
+    InvalidDistance,
+    // CRC-32やAdler-32、展開後のサイズが一致しない
+    ChecksumMismatch,
+    // 展開後のサイズがMAX_OUTPUT_SIZEを超える
+    OutputTooLarge,
+}
+
+// 展開後のサイズの上限（16MiB）
+// 数KBの圧縮データが数GBに展開されてメモリを使い果たさないよう、超えた時点で失敗にする
+pub const MAX_OUTPUT_SIZE: usize = 16 * 1024 * 1024;
+
+const MAX_BITS: usize = 15;
+
+// 長さの符号（257〜285）の基準値と追加ビット数
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+// 距離の符号（0〜29）の基準値と追加ビット数
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+// 符号長の符号の長さが並ぶ順序
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+// DEFLATEのデータを展開する。zlibやgzipの枠は含まない
+pub fn inflate(input: &[u8]) -> Result<Vec<u8>, InflateError> {
+    inflate_with_length(input, MAX_OUTPUT_SIZE).map(|(output, _)| output)
+}
+
+// "Content-Encoding: deflate"のボディを展開する
+// 仕様上はzlib形式だが、DEFLATEのデータをそのまま送るサーバーもあるので、zlibのヘッダーがなければそのまま展開する
+pub fn deflate_decode(input: &[u8]) -> Result<Vec<u8>, InflateError> {
+    if has_zlib_header(input) {
+        zlib_decode(input)
+    } else {
+        inflate(input)
+    }
+}
+
+/// https://www.rfc-editor.org/rfc/rfc1950#section-2.2
+pub fn zlib_decode(input: &[u8]) -> Result<Vec<u8>, InflateError> {
+    zlib_decode_with_limit(input, MAX_OUTPUT_SIZE)
+}
+
+fn zlib_decode_with_limit(input: &[u8], max_output: usize) -> Result<Vec<u8>, InflateError> {
+    if !has_zlib_header(input) {
+        return Err(InflateError::InvalidHeader);
+    }
+    // 事前に定義された辞書は使わない
+    if input[1] & 0x20 != 0 {
+        return Err(InflateError::InvalidHeader);
+    }
+
+    let (output, consumed) = inflate_with_length(&input[2..], max_output)?;
+    let trailer = input
+        .get(2 + consumed..2 + consumed + 4)
+        .ok_or(InflateError::UnexpectedEnd)?;
+    let expected = u32::from_be_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    if adler32(&output) != expected {
+        return Err(InflateError::ChecksumMismatch);
+    }
+    Ok(output)
+}
+
+// 複数のメンバーが連結されている場合は、展開したものをつなげて返す
+/// https://www.rfc-editor.org/rfc/rfc1952#section-2.2
+pub fn gzip_decode(input: &[u8]) -> Result<Vec<u8>, InflateError> {
+    gzip_decode_with_limit(input, MAX_OUTPUT_SIZE)
+}
+
+// 上限はすべてのメンバーを合わせたサイズに対して確かめる
+fn gzip_decode_with_limit(input: &[u8], max_output: usize) -> Result<Vec<u8>, InflateError> {
+    let mut output = Vec::new();
+    let mut position = 0;
+    loop {
+        position += gzip_decode_member(&input[position..], &mut output, max_output)?;
+        // メンバーの後ろの0埋めは無視する
+        if input[position..].iter().all(|b| *b == 0) {
+            return Ok(output);
+        }
+    }
+}
+
+// gzipのメンバーを1つ展開してoutputに加え、読んだバイト数を返す
+fn gzip_decode_member(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    max_output: usize,
+) -> Result<usize, InflateError> {
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+
+    // ID1, ID2, CM, FLG, MTIME(4), XFL, OS
+    let header = input.get(..10).ok_or(InflateError::UnexpectedEnd)?;
+    if header[0] != 0x1f || header[1] != 0x8b || header[2] != 8 || header[3] & 0xe0 != 0 {
+        return Err(InflateError::InvalidHeader);
+    }
+    let flags = header[3];
+    let mut position = 10;
+
+    if flags & FEXTRA != 0 {
+        let length = input
+            .get(position..position + 2)
+            .ok_or(InflateError::UnexpectedEnd)?;
+        position += 2 + u16::from_le_bytes([length[0], length[1]]) as usize;
+    }
+    // ファイル名とコメントは0で終わる文字列
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let rest = input.get(position..).ok_or(InflateError::UnexpectedEnd)?;
+            let end = rest
+                .iter()
+                .position(|b| *b == 0)
+                .ok_or(InflateError::UnexpectedEnd)?;
+            position += end + 1;
+        }
+    }
+    if flags & FHCRC != 0 {
+        position += 2;
+    }
+
+    let data = input.get(position..).ok_or(InflateError::UnexpectedEnd)?;
+    let (member, consumed) = inflate_with_length(data, max_output - output.len())?;
+    position += consumed;
+
+    // CRC32, ISIZE
+    let trailer = input
+        .get(position..position + 8)
+        .ok_or(InflateError::UnexpectedEnd)?;
+    let crc = u32::from_le_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    let size = u32::from_le_bytes([trailer[4], trailer[5], trailer[6], trailer[7]]);
+    if crc32(&member) != crc || member.len() as u32 != size {
+        return Err(InflateError::ChecksumMismatch);
+    }
+
+    output.extend_from_slice(&member);
+    Ok(position + 8)
+}
+
+fn has_zlib_header(input: &[u8]) -> bool {
+    match input {
+        // CMが8（deflate）で、CMFとFLGを合わせた値が31の倍数
+        [cmf, flg, ..] => {
+            cmf & 0x0f == 8 && cmf >> 4 <= 7 && (*cmf as u16 * 256 + *flg as u16) % 31 == 0
+        }
+        _ => false,
+    }
+}
+
+// 展開したデータと、入力のうち読んだバイト数を返す
+// 展開後のサイズがmax_outputを超える場合は、そこで展開をやめる
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.3
+fn inflate_with_length(input: &[u8], max_output: usize) -> Result<(Vec<u8>, usize), InflateError> {
+    let mut reader = BitReader::new(input);
+    let mut output = Vec::new();
+
+    loop {
+        let is_final = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => inflate_stored(&mut reader, &mut output, max_output)?,
+            1 => {
+                let (literal, distance) = fixed_huffman();
+                inflate_codes(&mut reader, &mut output, max_output, &literal, &distance)?;
+            }
+            2 => {
+                let (literal, distance) = dynamic_huffman(&mut reader)?;
+                inflate_codes(&mut reader, &mut output, max_output, &literal, &distance)?;
+            }
+            _ => return Err(InflateError::InvalidBlockType),
+        }
+
+        if is_final {
+            return Ok((output, reader.position));
+        }
+    }
+}
+
+// 非圧縮のブロック
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.4
+fn inflate_stored(
+    reader: &mut BitReader,
+    output: &mut Vec<u8>,
+    max_output: usize,
+) -> Result<(), InflateError> {
+    // バイト境界まで読み飛ばす
+    reader.align_to_byte();
+
+    let header = reader
+        .input
+        .get(reader.position..reader.position + 4)
+        .ok_or(InflateError::UnexpectedEnd)?;
+    let length = u16::from_le_bytes([header[0], header[1]]);
+    let nlength = u16::from_le_bytes([header[2], header[3]]);
+    if length != !nlength {
+        return Err(InflateError::InvalidStoredLength);
+    }
+    reader.position += 4;
+
+    let data = reader
+        .input
+        .get(reader.position..reader.position + length as usize)
+        .ok_or(InflateError::UnexpectedEnd)?;
+    reserve_output(output, data.len(), max_output)?;
+    output.extend_from_slice(data);
+    reader.position += length as usize;
+    Ok(())
+}
+
+// 圧縮されたブロックのデータを、ブロックの終わりの符号まで展開する
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.5
+fn inflate_codes(
+    reader: &mut BitReader,
+    output: &mut Vec<u8>,
+    max_output: usize,
+    literal: &Huffman,
+    distance: &Huffman,
+) -> Result<(), InflateError> {
+    loop {
+        let symbol = reader.decode(literal)? as usize;
+        if symbol < 256 {
+            reserve_output(output, 1, max_output)?;
+            output.push(symbol as u8);
+            continue;
+        }
+        if symbol == 256 {
+            return Ok(());
+        }
+
+        // 長さと距離の組は、出力済みのデータの一部を繰り返す
+        let index = symbol - 257;
+        if index >= LENGTH_BASE.len() {
+            return Err(InflateError::InvalidCode);
+        }
+        let length =
+            LENGTH_BASE[index] as usize + reader.bits(LENGTH_EXTRA[index] as u32)? as usize;
+
+        let index = reader.decode(distance)? as usize;
+        if index >= DISTANCE_BASE.len() {
+            return Err(InflateError::InvalidCode);
+        }
+        let dist =
+            DISTANCE_BASE[index] as usize + reader.bits(DISTANCE_EXTRA[index] as u32)? as usize;
+        if dist > output.len() {
+            return Err(InflateError::InvalidDistance);
+        }
+
+        // 距離より長い場合は、コピーしたばかりのバイトをさらにコピーする
+        let start = output.len() - dist;
+        reserve_output(output, length, max_output)?;
+        for i in 0..length {
+            output.push(output[start + i]);
+        }
+    }
+}
+
+// outputにさらにlengthバイト加えても上限を超えないか確かめる
+fn reserve_output(output: &[u8], length: usize, max_output: usize) -> Result<(), InflateError> {
+    if output.len().saturating_add(length) > max_output {
+        Err(InflateError::OutputTooLarge)
+    } else {
+        Ok(())
+    }
+}
+
+// 固定ハフマン符号
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.6
+fn fixed_huffman() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    let literal = Huffman::new(&lengths).expect("fixed literal codes are valid");
+    let distance = Huffman::new(&[5u8; 30]).expect("fixed distance codes are valid");
+    (literal, distance)
+}
+
+// ブロックの先頭で定義されるハフマン符号
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.7
+fn dynamic_huffman(reader: &mut BitReader) -> Result<(Huffman, Huffman), InflateError> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return Err(InflateError::InvalidCode);
+    }
+
+    // 符号長そのものを符号化するハフマン符号
+    let mut code_lengths = [0u8; 19];
+    for &index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[index] = reader.bits(3)? as u8;
+    }
+    let code_length_huffman = Huffman::new(&code_lengths)?;
+
+    // リテラルと長さの符号、距離の符号の符号長は続けて並んでいる
+    let mut lengths = vec![0u8; literal_count + distance_count];
+    let mut index = 0;
+    while index < lengths.len() {
+        let symbol = reader.decode(&code_length_huffman)?;
+        let (value, repeat) = match symbol {
+            0..=15 => {
+                lengths[index] = symbol as u8;
+                index += 1;
+                continue;
+            }
+            // 直前の符号長を3〜6回繰り返す
+            16 => {
+                if index == 0 {
+                    return Err(InflateError::InvalidCode);
+                }
+                (lengths[index - 1], 3 + reader.bits(2)? as usize)
+            }
+            // 0を3〜10回繰り返す
+            17 => (0, 3 + reader.bits(3)? as usize),
+            // 0を11〜138回繰り返す
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if index + repeat > lengths.len() {
+            return Err(InflateError::InvalidCode);
+        }
+        lengths[index..index + repeat].fill(value);
+        index += repeat;
+    }
+
+    // ブロックの終わりの符号がなければ展開を終えられない
+    if lengths[256] == 0 {
+        return Err(InflateError::InvalidCode);
+    }
+
+    let literal = Huffman::new(&lengths[..literal_count])?;
+    let distance = Huffman::new(&lengths[literal_count..])?;
+    Ok((literal, distance))
+}
+
+/// 符号長から組み立てた正準ハフマン符号
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.2
+struct Huffman {
+    // 符号長ごとの符号の数
+    counts: [u16; MAX_BITS + 1],
+    // 符号の順に並べたシンボル
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, InflateError> {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+
+        // 符号の数が多すぎて、どの符号長でも表せない場合は不正
+        // 少ない場合（不完全な符号）は、距離の符号が1つしかないときなどに使われるので許容する
+        let mut left: i32 = 1;
+        for &count in counts.iter().skip(1) {
+            left <<= 1;
+            left -= count as i32;
+            if left < 0 {
+                return Err(InflateError::InvalidCode);
+            }
+        }
+
+        let mut offsets = [0u16; MAX_BITS + 1];
+        for length in 1..MAX_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+
+        Ok(Self { counts, symbols })
+    }
+}
+
+/// 下位のビットから順に読む
+struct BitReader<'a> {
+    input: &'a [u8],
+    // 次に読むバイトの位置
+    position: usize,
+    buffer: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            position: 0,
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    fn bits(&mut self, n: u32) -> Result<u32, InflateError> {
+        while self.count < n {
+            let byte = *self
+                .input
+                .get(self.position)
+                .ok_or(InflateError::UnexpectedEnd)?;
+            self.buffer |= (byte as u32) << self.count;
+            self.position += 1;
+            self.count += 8;
+        }
+
+        let value = self.buffer & ((1u32 << n) - 1);
+        self.buffer >>= n;
+        self.count -= n;
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.buffer = 0;
+        self.count = 0;
+    }
+
+    // ハフマン符号は上位のビットから詰められているので、1ビットずつ読んで符号長ごとに照合する
+    fn decode(&mut self, huffman: &Huffman) -> Result<u16, InflateError> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for length in 1..=MAX_BITS {
+            code |= self.bits(1)? as i32;
+            let count = huffman.counts[length] as i32;
+            if code - first < count {
+                return Ok(huffman.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+        Err(InflateError::InvalidCode)
+    }
+}
+
+/// https://www.rfc-editor.org/rfc/rfc1952#section-8
+pub fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut n = 0;
+        while n < 256 {
+            let mut c = n as u32;
+            let mut k = 0;
+            while k < 8 {
+                c = if c & 1 != 0 {
+                    0xedb88320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+                k += 1;
+            }
+            table[n] = c;
+            n += 1;
+        }
+        table
+    };
+
+    let mut crc = 0xffffffffu32;
+    for &b in data {
+        crc = TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc ^ 0xffffffff
+}
+
+/// https://www.rfc-editor.org/rfc/rfc1950#section-8.2
+pub fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let mut a = 1u32;
+    let mut b = 0u32;
+    // 合計があふれない範囲ごとに剰余を取る
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = include_bytes!("../testdata/inflate/sample.html");
+
+    #[test]
+    fn test_gzip_dynamic_huffman() {
+        let compressed = include_bytes!("../testdata/inflate/sample.html.gz");
+        assert_eq!(gzip_decode(compressed), Ok(SAMPLE.to_vec()));
+    }
+
+    #[test]
+    fn test_gzip_stored() {
+        // 圧縮レベル0では非圧縮のブロックになる。ファイル名のフィールドも含む
+        let compressed = include_bytes!("../testdata/inflate/sample.html.stored.gz");
+        assert_eq!(gzip_decode(compressed), Ok(SAMPLE.to_vec()));
+    }
+
+    #[test]
+    fn test_zlib_and_raw_deflate() {
+        let zlib = include_bytes!("../testdata/inflate/sample.html.zlib");
+        assert_eq!(zlib_decode(zlib), Ok(SAMPLE.to_vec()));
+        assert_eq!(deflate_decode(zlib), Ok(SAMPLE.to_vec()));
+
+        let raw = include_bytes!("../testdata/inflate/sample.html.deflate");
+        assert_eq!(inflate(raw), Ok(SAMPLE.to_vec()));
+        assert_eq!(deflate_decode(raw), Ok(SAMPLE.to_vec()));
+    }
+
+    #[test]
+    fn test_fixed_huffman() {
+        // zlib.compress(b"Hello, Hello, Hello!")
+        let compressed = [
+            0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0xd7, 0x51, 0xf0, 0x40, 0xa2, 0x14, 0x01,
+            0x46, 0x3e, 0x06, 0x96,
+        ];
+        assert_eq!(
+            zlib_decode(&compressed),
+            Ok(b"Hello, Hello, Hello!".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_gzip_multiple_members() {
+        let member = include_bytes!("../testdata/inflate/sample.html.gz");
+        let mut compressed = member.to_vec();
+        compressed.extend_from_slice(member);
+        let mut expected = SAMPLE.to_vec();
+        expected.extend_from_slice(SAMPLE);
+        assert_eq!(gzip_decode(&compressed), Ok(expected));
+    }
+
+    #[test]
+    fn test_errors() {
+        let compressed = include_bytes!("../testdata/inflate/sample.html.gz");
+        assert_eq!(
+            gzip_decode(&compressed[..compressed.len() / 2]),
+            Err(InflateError::UnexpectedEnd)
+        );
+
+        let mut corrupted = compressed.to_vec();
+        let crc = corrupted.len() - 8;
+        corrupted[crc] ^= 0xff;
+        assert_eq!(gzip_decode(&corrupted), Err(InflateError::ChecksumMismatch));
+
+        assert_eq!(
+            gzip_decode(b"not gzip data"),
+            Err(InflateError::InvalidHeader)
+        );
+        // BTYPE = 3
+        assert_eq!(inflate(&[0x07]), Err(InflateError::InvalidBlockType));
+        // LENとNLENが対応していない
+        assert_eq!(
+            inflate(&[0x01, 0x01, 0x00, 0x00, 0x00]),
+            Err(InflateError::InvalidStoredLength)
+        );
+    }
+
+    #[test]
+    fn test_output_limit() {
+        // 16MiBと1バイトの0を圧縮したもの
+        let bomb = include_bytes!("../testdata/inflate/zeros.gz");
+        assert_eq!(gzip_decode(bomb), Err(InflateError::OutputTooLarge));
+
+        // 上限ちょうどであれば展開できる
+        let compressed = include_bytes!("../testdata/inflate/sample.html.gz");
+        assert_eq!(
+            gzip_decode_with_limit(compressed, SAMPLE.len()),
+            Ok(SAMPLE.to_vec())
+        );
+        assert_eq!(
+            gzip_decode_with_limit(compressed, SAMPLE.len() - 1),
+            Err(InflateError::OutputTooLarge)
+        );
+        // 複数のメンバーを合わせたサイズで確かめる
+        let mut members = compressed.to_vec();
+        members.extend_from_slice(compressed);
+        assert_eq!(
+            gzip_decode_with_limit(&members, SAMPLE.len() + 1),
+            Err(InflateError::OutputTooLarge)
+        );
+        let stored = include_bytes!("../testdata/inflate/sample.html.stored.gz");
+        assert_eq!(
+            gzip_decode_with_limit(stored, 10),
+            Err(InflateError::OutputTooLarge)
+        );
+        let zlib = include_bytes!("../testdata/inflate/sample.html.zlib");
+        assert_eq!(
+            zlib_decode_with_limit(zlib, 10),
+            Err(InflateError::OutputTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+}
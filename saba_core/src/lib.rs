@@ -2,7 +2,16 @@
 
 extern crate alloc;
 
+pub mod cookie;
+pub mod data_url;
+pub mod encoding;
 pub mod error;
+pub mod form;
 pub mod http;
+pub mod inflate;
+pub mod mime;
 pub mod renderer;
 pub mod url;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
@@ -0,0 +1,248 @@
+//! https://mimesniff.spec.whatwg.org/#understanding-mime-types
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// "text/html;charset=utf-8"のようなMIMEタイプ
+/// https://mimesniff.spec.whatwg.org/#mime-type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MimeType {
+    type_: String,
+    subtype: String,
+    parameters: Vec<(String, String)>,
+}
+
+impl MimeType {
+    pub fn new(type_: &str, subtype: &str) -> Self {
+        Self {
+            type_: type_.to_ascii_lowercase(),
+            subtype: subtype.to_ascii_lowercase(),
+            parameters: Vec::new(),
+        }
+    }
+
+    // 解析できない場合はNoneを返す
+    /// https://mimesniff.spec.whatwg.org/#parse-a-mime-type
+    pub fn parse(input: &str) -> Option<Self> {
+        let input: Vec<char> = input.trim_matches(is_http_whitespace).chars().collect();
+        let mut position = 0;
+
+        let type_ = collect(&input, &mut position, |c| c != '/');
+        if type_.is_empty() || !type_.chars().all(is_http_token_code_point) {
+            return None;
+        }
+        if position >= input.len() {
+            return None;
+        }
+        // "/"を読み飛ばす
+        position += 1;
+
+        let subtype = collect(&input, &mut position, |c| c != ';');
+        let subtype = subtype.trim_end_matches(is_http_whitespace);
+        if subtype.is_empty() || !subtype.chars().all(is_http_token_code_point) {
+            return None;
+        }
+
+        let mut mime_type = MimeType::new(&type_, subtype);
+
+        while position < input.len() {
+            // ";"を読み飛ばす
+            position += 1;
+            collect(&input, &mut position, is_http_whitespace);
+
+            let name = collect(&input, &mut position, |c| c != ';' && c != '=').to_ascii_lowercase();
+            if position >= input.len() {
+                break;
+            }
+            if input[position] == ';' {
+                continue;
+            }
+            // "="を読み飛ばす
+            position += 1;
+            if position >= input.len() {
+                break;
+            }
+
+            let value = if input[position] == '"' {
+                let value = collect_quoted_string(&input, &mut position);
+                collect(&input, &mut position, |c| c != ';');
+                value
+            } else {
+                let value = collect(&input, &mut position, |c| c != ';');
+                let value = value.trim_end_matches(is_http_whitespace).to_string();
+                if value.is_empty() {
+                    continue;
+                }
+                value
+            };
+
+            // 同じ名前のパラメータは最初のものを使う
+            if !name.is_empty()
+                && name.chars().all(is_http_token_code_point)
+                && value.chars().all(is_http_quoted_string_token_code_point)
+                && mime_type.parameter(&name).is_none()
+            {
+                mime_type.parameters.push((name, value));
+            }
+        }
+
+        Some(mime_type)
+    }
+
+    pub fn type_(&self) -> String {
+        self.type_.clone()
+    }
+
+    pub fn subtype(&self) -> String {
+        self.subtype.clone()
+    }
+
+    // パラメータを除いた"type/subtype"
+    /// https://mimesniff.spec.whatwg.org/#mime-type-essence
+    pub fn essence(&self) -> String {
+        let mut essence = self.type_.clone();
+        essence.push('/');
+        essence.push_str(&self.subtype);
+        essence
+    }
+
+    pub fn parameters(&self) -> Vec<(String, String)> {
+        self.parameters.clone()
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<String> {
+        self.parameters
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.clone())
+    }
+
+    pub fn set_parameter(&mut self, name: &str, value: &str) {
+        let name = name.to_ascii_lowercase();
+        match self.parameters.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.parameters.push((name, value.to_string())),
+        }
+    }
+
+    pub fn charset(&self) -> Option<String> {
+        self.parameter("charset")
+    }
+}
+
+impl fmt::Display for MimeType {
+    /// https://mimesniff.spec.whatwg.org/#serialize-a-mime-type
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.type_, self.subtype)?;
+        for (name, value) in &self.parameters {
+            write!(f, ";{}=", name)?;
+            if !value.is_empty() && value.chars().all(is_http_token_code_point) {
+                f.write_str(value)?;
+            } else {
+                f.write_str("\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{}", c)?;
+                }
+                f.write_str("\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// https://fetch.spec.whatwg.org/#http-whitespace
+pub fn is_http_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | ' ')
+}
+
+/// https://mimesniff.spec.whatwg.org/#http-token-code-point
+pub fn is_http_token_code_point(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            '!' | '#' | '$' | '%' | '&' | '\'' | '*' | '+' | '-' | '.' | '^' | '_' | '`' | '|' | '~'
+        )
+}
+
+/// https://mimesniff.spec.whatwg.org/#http-quoted-string-token-code-point
+fn is_http_quoted_string_token_code_point(c: char) -> bool {
+    c == '\t' || (' '..='~').contains(&c) || ('\u{80}'..='\u{FF}').contains(&c)
+}
+
+// 条件を満たす間、文字を集める
+/// https://infra.spec.whatwg.org/#collect-a-sequence-of-code-points
+fn collect(input: &[char], position: &mut usize, condition: impl Fn(char) -> bool) -> String {
+    let mut result = String::new();
+    while *position < input.len() && condition(input[*position]) {
+        result.push(input[*position]);
+        *position += 1;
+    }
+    result
+}
+
+// ダブルクオートで囲まれた文字列を、エスケープを外して取り出す
+/// https://fetch.spec.whatwg.org/#collect-an-http-quoted-string
+fn collect_quoted_string(input: &[char], position: &mut usize) -> String {
+    let mut value = String::new();
+    // 最初の"を読み飛ばす
+    *position += 1;
+
+    loop {
+        value.push_str(&collect(input, position, |c| c != '"' && c != '\\'));
+        if *position >= input.len() {
+            break;
+        }
+
+        let quote_or_backslash = input[*position];
+        *position += 1;
+        if quote_or_backslash == '\\' {
+            if *position >= input.len() {
+                value.push('\\');
+                break;
+            }
+            value.push(input[*position]);
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mime = MimeType::parse("Text/HTML; Charset=\"Shift_JIS\"").expect("failed to parse mime type");
+        assert_eq!(mime.essence(), "text/html");
+        assert_eq!(mime.charset(), Some("Shift_JIS".to_string()));
+        assert_eq!(mime.to_string(), "text/html;charset=Shift_JIS");
+    }
+
+    #[test]
+    fn test_parameters() {
+        let mime = MimeType::parse("text/plain;a=1;;b;c=\"x;\\\"y\";a=2;d= ").expect("failed to parse mime type");
+        assert_eq!(
+            mime.parameters(),
+            [("a".to_string(), "1".to_string()), ("c".to_string(), "x;\"y".to_string())]
+        );
+        assert_eq!(mime.to_string(), "text/plain;a=1;c=\"x;\\\"y\"");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(MimeType::parse(""), None);
+        assert_eq!(MimeType::parse("text"), None);
+        assert_eq!(MimeType::parse("text/"), None);
+        assert_eq!(MimeType::parse("te xt/html"), None);
+        assert_eq!(MimeType::parse("/html"), None);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::renderer::dom::node::Element;
+use crate::renderer::dom::node::Node;
+use crate::renderer::dom::node::NodeKind;
+use crate::url::percent_encoding::percent_decode_str;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+/// フラグメントが指し示すドキュメントの位置
+/// https://html.spec.whatwg.org/multipage/browsing-the-web.html#the-indicated-part-of-the-document
+#[derive(Debug, Clone)]
+pub enum IndicatedPart {
+    /// https://html.spec.whatwg.org/multipage/browsing-the-web.html#top-of-the-document
+    TopOfDocument,
+    Element(Rc<RefCell<Node>>),
+}
+
+// URLのフラグメントに対応する要素を探す
+// ページを再取得せずに、見つかった位置までスクロールするために使う
+/// https://html.spec.whatwg.org/multipage/browsing-the-web.html#select-the-indicated-part
+pub fn find_indicated_part(document: &Rc<RefCell<Node>>, fragment: &str) -> Option<IndicatedPart> {
+    if fragment.is_empty() {
+        return Some(IndicatedPart::TopOfDocument);
+    }
+
+    if let Some(element) = find_potential_indicated_element(document, fragment) {
+        return Some(IndicatedPart::Element(element));
+    }
+
+    // パーセントデコードしたフラグメントでも探す（例: "#%E7%9B%AE%E6%AC%A1" -> "目次"）
+    let decoded = percent_decode_str(fragment);
+    if let Some(element) = find_potential_indicated_element(document, &decoded) {
+        return Some(IndicatedPart::Element(element));
+    }
+
+    if decoded.eq_ignore_ascii_case("top") {
+        return Some(IndicatedPart::TopOfDocument);
+    }
+
+    None
+}
+
+// idが一致する要素、または名前が一致する<a>要素をツリー順で探す
+/// https://html.spec.whatwg.org/multipage/browsing-the-web.html#find-a-potential-indicated-element
+fn find_potential_indicated_element(
+    node: &Rc<RefCell<Node>>,
+    fragment: &str,
+) -> Option<Rc<RefCell<Node>>> {
+    if let NodeKind::Element(element) = &node.borrow().kind {
+        if is_indicated_element(element, fragment) {
+            return Some(node.clone());
+        }
+    }
+
+    let mut child = node.borrow().first_child();
+    while let Some(c) = child {
+        if let Some(found) = find_potential_indicated_element(&c, fragment) {
+            return Some(found);
+        }
+        child = c.borrow().next_sibling();
+    }
+
+    None
+}
+
+fn is_indicated_element(element: &Element, fragment: &str) -> bool {
+    if element.get_attribute("id").as_deref() == Some(fragment) {
+        return true;
+    }
+    element.tag_name() == "a" && element.get_attribute("name").as_deref() == Some(fragment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::html::attribute::Attribute;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn attribute(name: &str, value: &str) -> Attribute {
+        let mut attr = Attribute::new();
+        name.chars().for_each(|c| attr.addr_char(c, true));
+        value.chars().for_each(|c| attr.addr_char(c, false));
+        attr
+    }
+
+    fn element(tag: &str, attributes: Vec<Attribute>) -> Rc<RefCell<Node>> {
+        Rc::new(RefCell::new(Node::new(NodeKind::Element(Element::new(tag, attributes)))))
+    }
+
+    fn append_child(parent: &Rc<RefCell<Node>>, child: Rc<RefCell<Node>>) {
+        child.borrow_mut().set_parent(Rc::downgrade(parent));
+        let last = parent.borrow().last_child().upgrade();
+        match last {
+            Some(last) => {
+                child.borrow_mut().set_previous_sibling(Rc::downgrade(&last));
+                last.borrow_mut().set_next_sibling(Some(child.clone()));
+            }
+            None => parent.borrow_mut().set_first_child(Some(child.clone())),
+        }
+        parent.borrow_mut().set_last_child(Rc::downgrade(&child));
+    }
+
+    fn describe(part: Option<IndicatedPart>) -> Option<(String, Option<String>)> {
+        match part {
+            Some(IndicatedPart::Element(node)) => match &node.borrow().kind {
+                NodeKind::Element(e) => Some((e.tag_name(), e.get_attribute("id"))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn document() -> Rc<RefCell<Node>> {
+        let document = Rc::new(RefCell::new(Node::new(NodeKind::Document)));
+        let body = element("body", Vec::new());
+        append_child(&body, element("h1", vec![attribute("id", "title")]));
+        append_child(&body, element("a", vec![attribute("name", "anchor")]));
+        append_child(&body, element("p", vec![attribute("id", "目次")]));
+        append_child(&document, body);
+        document
+    }
+
+    #[test]
+    fn test_find_by_id() {
+        let part = find_indicated_part(&document(), "title");
+        assert_eq!(Some(("h1".to_string(), Some("title".to_string()))), describe(part));
+    }
+
+    #[test]
+    fn test_find_by_anchor_name() {
+        let part = find_indicated_part(&document(), "anchor");
+        assert_eq!(Some(("a".to_string(), None)), describe(part));
+    }
+
+    #[test]
+    fn test_find_percent_encoded() {
+        let part = find_indicated_part(&document(), "%E7%9B%AE%E6%AC%A1");
+        assert_eq!(Some(("p".to_string(), Some("目次".to_string()))), describe(part));
+    }
+
+    #[test]
+    fn test_top_of_document() {
+        assert!(matches!(
+            find_indicated_part(&document(), ""),
+            Some(IndicatedPart::TopOfDocument)
+        ));
+        assert!(matches!(
+            find_indicated_part(&document(), "TOP"),
+            Some(IndicatedPart::TopOfDocument)
+        ));
+        assert!(find_indicated_part(&document(), "missing").is_none());
+    }
+}
@@ -1,7 +1,10 @@
+use crate::renderer::html::attribute::Attribute;
 use alloc::rc::Rc;
 use alloc::rc::Weak;
 use core::cell::RefCell;
 use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
 
 #[derive(Debug, Clone)]
 pub struct Node {
@@ -115,13 +118,33 @@ impl Window {
     }
 }
 
-
 #[derive(Debug, Clone)]
-pub struct Attribute {
-    pub name: String,
+pub struct Element {
+    tag_name: String,
+    attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
-pub struct Element {
-    
+impl Element {
+    pub fn new(tag_name: &str, attributes: Vec<Attribute>) -> Self {
+        Self {
+            tag_name: tag_name.to_string(),
+            attributes,
+        }
+    }
+
+    pub fn tag_name(&self) -> String {
+        self.tag_name.clone()
+    }
+
+    pub fn attributes(&self) -> Vec<Attribute> {
+        self.attributes.clone()
+    }
+
+    // 指定した名前の属性の値を返す
+    pub fn get_attribute(&self, name: &str) -> Option<String> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.name == name)
+            .map(|attr| attr.value())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::encoding::sniff::decode_html;
 use crate::renderer::html::attribute::Attribute;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -24,6 +25,12 @@ impl HtmlTokenizer{
         }
     }
 
+    // 受信したバイト列から文字エンコーディングを決め、デコードしてからトークンに分ける
+    // transport_charsetにはContent-Typeヘッダーのcharsetを渡す
+    pub fn from_bytes(bytes: &[u8], transport_charset: Option<&str>) -> Self {
+        Self::new(decode_html(bytes, transport_charset))
+    }
+
     fn is_eof(&self) -> bool {
         self.pos > self.input.len()
     }
@@ -652,4 +659,26 @@ mod tests {
             assert_eq!(Some(e), tokenizer.next());
         }
     }
+
+    #[test]
+    fn test_from_bytes() {
+        // "<p>日本</p>"をEUC-JPで表したもの
+        let html = b"<p>\xc6\xfc\xcb\xdc</p>";
+        let mut tokenizer = HtmlTokenizer::from_bytes(html, Some("EUC-JP"));
+        let expected = [
+            HtmlToken::StartTag {
+                tag: "p".to_string(),
+                self_closing: false,
+                attributes: Vec::new(),
+            },
+            HtmlToken::Char('日'),
+            HtmlToken::Char('本'),
+            HtmlToken::EndTag {
+                tag: "p".to_string(),
+            },
+        ];
+        for e in expected {
+            assert_eq!(Some(e), tokenizer.next());
+        }
+    }
 }
@@ -0,0 +1,40 @@
+//! 複数のモジュールのテストで使う値を作る関数
+
+use crate::http::headers::HeaderMap;
+use crate::http::HttpResponse;
+use crate::url::Url;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+pub(crate) fn url(s: &str) -> Url {
+    Url::new(s.to_string())
+        .parse()
+        .expect("failed to parse url")
+}
+
+pub(crate) fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in pairs {
+        headers.append(name, value);
+    }
+    headers
+}
+
+// ステータスコードとヘッダーだけのレスポンス。ボディは空
+pub(crate) fn response(status_code: u32, headers: &[(&str, &str)]) -> HttpResponse {
+    response_with_body(status_code, headers, b"")
+}
+
+pub(crate) fn response_with_body(
+    status_code: u32,
+    headers: &[(&str, &str)],
+    body: &[u8],
+) -> HttpResponse {
+    HttpResponse::from_parts(
+        "HTTP/1.1".to_string(),
+        status_code,
+        "".to_string(),
+        self::headers(headers),
+        Vec::from(body),
+    )
+}
@@ -0,0 +1,477 @@
+//! https://url.spec.whatwg.org/#hosts-(domains-and-ip-addresses)
+
+use crate::url::idna;
+use crate::url::percent_encoding::percent_decode;
+use crate::url::percent_encoding::utf8_percent_encode;
+use crate::url::percent_encoding::EncodeSet;
+use crate::url::public_suffix;
+use crate::url::UrlError;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// https://url.spec.whatwg.org/#concept-host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Host {
+    /// https://url.spec.whatwg.org/#concept-domain
+    Domain(String),
+    /// https://url.spec.whatwg.org/#concept-ipv4
+    Ipv4(u32),
+    /// https://url.spec.whatwg.org/#concept-ipv6
+    Ipv6([u16; 8]),
+    /// https://url.spec.whatwg.org/#opaque-host
+    Opaque(String),
+    /// https://url.spec.whatwg.org/#empty-host
+    Empty,
+}
+
+impl Host {
+    /// https://url.spec.whatwg.org/#concept-host-parser
+    pub fn parse(input: &str, is_opaque: bool) -> Result<Self, UrlError> {
+        if let Some(rest) = input.strip_prefix('[') {
+            return match rest.strip_suffix(']') {
+                Some(address) => Ok(Host::Ipv6(parse_ipv6(address)?)),
+                None => Err(UrlError::InvalidIpv6Address),
+            };
+        }
+
+        if is_opaque {
+            return parse_opaque_host(input);
+        }
+
+        // パーセントデコードしてからASCIIのドメインに変換する
+        let domain = String::from_utf8_lossy(&percent_decode(input.as_bytes())).into_owned();
+        let ascii_domain = domain_to_ascii(&domain)?;
+
+        if ends_in_a_number(&ascii_domain) {
+            return Ok(Host::Ipv4(parse_ipv4(&ascii_domain)?));
+        }
+
+        Ok(Host::Domain(ascii_domain))
+    }
+}
+
+impl Host {
+    // UIに表示するためのホスト名。国際化ドメイン名はUnicodeに戻す
+    /// https://url.spec.whatwg.org/#concept-domain-to-unicode
+    pub fn to_unicode(&self) -> String {
+        match self {
+            Host::Domain(domain) => idna::domain_to_unicode(domain),
+            _ => self.to_string(),
+        }
+    }
+
+    /// https://url.spec.whatwg.org/#host-public-suffix
+    pub fn public_suffix(&self) -> Option<String> {
+        match self {
+            Host::Domain(domain) => Some(public_suffix::public_suffix(domain)),
+            _ => None,
+        }
+    }
+
+    /// https://url.spec.whatwg.org/#host-registrable-domain
+    pub fn registrable_domain(&self) -> Option<String> {
+        match self {
+            Host::Domain(domain) => public_suffix::registrable_domain(domain),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Host {
+    /// https://url.spec.whatwg.org/#concept-host-serializer
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Host::Domain(domain) => f.write_str(domain),
+            Host::Opaque(host) => f.write_str(host),
+            Host::Empty => Ok(()),
+            Host::Ipv4(address) => {
+                let b = address.to_be_bytes();
+                write!(f, "{}.{}.{}.{}", b[0], b[1], b[2], b[3])
+            }
+            Host::Ipv6(pieces) => {
+                f.write_str("[")?;
+                write_ipv6(pieces, f)?;
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// https://url.spec.whatwg.org/#forbidden-host-code-point
+fn is_forbidden_host_code_point(c: char) -> bool {
+    matches!(
+        c,
+        '\0' | '\t'
+            | '\n'
+            | '\r'
+            | ' '
+            | '#'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+    )
+}
+
+/// https://url.spec.whatwg.org/#forbidden-domain-code-point
+fn is_forbidden_domain_code_point(c: char) -> bool {
+    is_forbidden_host_code_point(c) || c.is_ascii_control() || c == '%'
+}
+
+/// https://url.spec.whatwg.org/#concept-domain-to-ascii
+fn domain_to_ascii(domain: &str) -> Result<String, UrlError> {
+    let result = idna::domain_to_ascii(domain)?;
+
+    if result.is_empty() || result.chars().any(is_forbidden_domain_code_point) {
+        return Err(UrlError::InvalidDomain);
+    }
+
+    Ok(result)
+}
+
+/// https://url.spec.whatwg.org/#concept-opaque-host-parser
+fn parse_opaque_host(input: &str) -> Result<Host, UrlError> {
+    if input.chars().any(is_forbidden_host_code_point) {
+        return Err(UrlError::InvalidDomain);
+    }
+
+    Ok(Host::Opaque(utf8_percent_encode(input, EncodeSet::C0Control)))
+}
+
+/// https://url.spec.whatwg.org/#ends-in-a-number-checker
+fn ends_in_a_number(input: &str) -> bool {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.last() == Some(&"") {
+        if parts.len() == 1 {
+            return false;
+        }
+        parts.pop();
+    }
+
+    let last = match parts.last() {
+        Some(last) => *last,
+        None => return false,
+    };
+
+    if !last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+
+    parse_ipv4_number(last).is_ok()
+}
+
+/// https://url.spec.whatwg.org/#ipv4-number-parser
+fn parse_ipv4_number(input: &str) -> Result<u64, UrlError> {
+    if input.is_empty() {
+        return Err(UrlError::InvalidIpv4Address);
+    }
+
+    let (digits, radix) = if let Some(hex) = input.strip_prefix("0x").or(input.strip_prefix("0X")) {
+        (hex, 16)
+    } else if input.len() >= 2 && input.starts_with('0') {
+        (&input[1..], 8)
+    } else {
+        (input, 10)
+    };
+
+    let mut value: u64 = 0;
+    for c in digits.chars() {
+        let digit = match c.to_digit(radix) {
+            Some(d) => d as u64,
+            None => return Err(UrlError::InvalidIpv4Address),
+        };
+        // 桁あふれした値はどのみち範囲外なので飽和させておく
+        value = value.saturating_mul(radix as u64).saturating_add(digit);
+    }
+
+    Ok(value)
+}
+
+/// https://url.spec.whatwg.org/#concept-ipv4-parser
+fn parse_ipv4(input: &str) -> Result<u32, UrlError> {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.last() == Some(&"") && parts.len() > 1 {
+        parts.pop();
+    }
+
+    if parts.len() > 4 {
+        return Err(UrlError::InvalidIpv4Address);
+    }
+
+    let mut numbers = Vec::with_capacity(parts.len());
+    for part in parts {
+        numbers.push(parse_ipv4_number(part)?);
+    }
+
+    let (last, rest) = match numbers.split_last() {
+        Some(split) => split,
+        None => return Err(UrlError::InvalidIpv4Address),
+    };
+
+    // 最後以外の数値は1バイトに収まらなければならない
+    if rest.iter().any(|n| *n > 255) {
+        return Err(UrlError::InvalidIpv4Address);
+    }
+
+    // 最後の数値は残りのバイトすべてを埋める
+    if *last >= 1u64 << (8 * (5 - numbers.len())) {
+        return Err(UrlError::InvalidIpv4Address);
+    }
+
+    let mut ipv4 = *last;
+    for (counter, n) in rest.iter().enumerate() {
+        ipv4 += n << (8 * (3 - counter));
+    }
+
+    Ok(ipv4 as u32)
+}
+
+/// https://url.spec.whatwg.org/#concept-ipv6-parser
+fn parse_ipv6(input: &str) -> Result<[u16; 8], UrlError> {
+    let input: Vec<char> = input.chars().collect();
+    let mut address = [0u16; 8];
+    let mut piece_index = 0;
+    let mut compress: Option<usize> = None;
+    let mut pointer = 0;
+
+    let c = |p: usize| input.get(p).copied();
+
+    if c(pointer) == Some(':') {
+        if c(pointer + 1) != Some(':') {
+            return Err(UrlError::InvalidIpv6Address);
+        }
+        pointer += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+
+    while c(pointer).is_some() {
+        if piece_index == 8 {
+            return Err(UrlError::InvalidIpv6Address);
+        }
+
+        if c(pointer) == Some(':') {
+            if compress.is_some() {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+            pointer += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+
+        let mut value: u16 = 0;
+        let mut length = 0;
+        while length < 4 {
+            match c(pointer).and_then(|ch| ch.to_digit(16)) {
+                Some(d) => {
+                    value = value * 0x10 + d as u16;
+                    pointer += 1;
+                    length += 1;
+                }
+                None => break,
+            }
+        }
+
+        // IPv4アドレスが埋め込まれている場合（例: ::ffff:192.0.2.1）
+        if c(pointer) == Some('.') {
+            if length == 0 {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+            pointer -= length;
+            if piece_index > 6 {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+
+            let mut numbers_seen = 0;
+            while c(pointer).is_some() {
+                let mut ipv4_piece: Option<u16> = None;
+                if numbers_seen > 0 {
+                    if c(pointer) == Some('.') && numbers_seen < 4 {
+                        pointer += 1;
+                    } else {
+                        return Err(UrlError::InvalidIpv6Address);
+                    }
+                }
+
+                if !c(pointer).is_some_and(|ch| ch.is_ascii_digit()) {
+                    return Err(UrlError::InvalidIpv6Address);
+                }
+
+                while let Some(d) = c(pointer).and_then(|ch| ch.to_digit(10)) {
+                    ipv4_piece = match ipv4_piece {
+                        None => Some(d as u16),
+                        Some(0) => return Err(UrlError::InvalidIpv6Address),
+                        Some(v) => Some(v * 10 + d as u16),
+                    };
+                    if ipv4_piece.is_some_and(|v| v > 255) {
+                        return Err(UrlError::InvalidIpv6Address);
+                    }
+                    pointer += 1;
+                }
+
+                address[piece_index] =
+                    address[piece_index] * 0x100 + ipv4_piece.unwrap_or_default();
+                numbers_seen += 1;
+                if numbers_seen == 2 || numbers_seen == 4 {
+                    piece_index += 1;
+                }
+            }
+
+            if numbers_seen != 4 {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+            break;
+        }
+
+        if c(pointer) == Some(':') {
+            pointer += 1;
+            if c(pointer).is_none() {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+        } else if c(pointer).is_some() {
+            return Err(UrlError::InvalidIpv6Address);
+        }
+
+        address[piece_index] = value;
+        piece_index += 1;
+    }
+
+    match compress {
+        Some(compress) => {
+            // 省略された0の部分を後ろに詰める
+            let mut swaps = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None => {
+            if piece_index != 8 {
+                return Err(UrlError::InvalidIpv6Address);
+            }
+        }
+    }
+
+    Ok(address)
+}
+
+/// https://url.spec.whatwg.org/#concept-ipv6-serializer
+fn write_ipv6(pieces: &[u16; 8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // 最も長い（2個以上の）0の並びを探して"::"に圧縮する
+    let mut compress: Option<(usize, usize)> = None;
+    let mut i = 0;
+    while i < 8 {
+        if pieces[i] != 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < 8 && pieces[i] == 0 {
+            i += 1;
+        }
+        let len = i - start;
+        if len > 1 && compress.map_or(true, |(_, l)| len > l) {
+            compress = Some((start, len));
+        }
+    }
+
+    let mut ignore0 = false;
+    for (index, piece) in pieces.iter().enumerate() {
+        if ignore0 && *piece == 0 {
+            continue;
+        }
+        ignore0 = false;
+
+        if let Some((start, _)) = compress {
+            if start == index {
+                f.write_str(if index == 0 { "::" } else { ":" })?;
+                ignore0 = true;
+                continue;
+            }
+        }
+
+        write!(f, "{:x}", piece)?;
+        if index != 7 {
+            f.write_str(":")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_domain() {
+        assert_eq!(
+            Ok(Host::Domain("example.com".to_string())),
+            Host::parse("EXAMPLE.com", false)
+        );
+        assert_eq!(
+            Ok(Host::Domain("example.com".to_string())),
+            Host::parse("ex%41mple.com", false)
+        );
+        assert_eq!(Err(UrlError::InvalidDomain), Host::parse("exa mple.com", false));
+    }
+
+    #[test]
+    fn test_idn_domain() {
+        let host = Host::parse("日本語.JP", false).expect("failed to parse host");
+        assert_eq!(Host::Domain("xn--wgv71a119e.jp".to_string()), host);
+        assert_eq!("日本語.jp", host.to_unicode());
+
+        // パーセントエンコードされたUTF-8もデコードしてから変換する
+        let host = Host::parse("%E6%97%A5%E6%9C%AC%E8%AA%9E.jp", false).expect("failed to parse host");
+        assert_eq!(Host::Domain("xn--wgv71a119e.jp".to_string()), host);
+    }
+
+    #[test]
+    fn test_ipv4() {
+        assert_eq!(Ok(Host::Ipv4(0x7F000001)), Host::parse("127.0.0.1", false));
+        assert_eq!(Ok(Host::Ipv4(0x7F000001)), Host::parse("0x7f.1", false));
+        assert_eq!(Ok(Host::Ipv4(0xC0A80001)), Host::parse("0300.0250.0.1", false));
+        assert_eq!(Err(UrlError::InvalidIpv4Address), Host::parse("256.0.0.1", false));
+        assert_eq!(Err(UrlError::InvalidIpv4Address), Host::parse("1.2.3.4.5", false));
+        assert_eq!("127.0.0.1", Host::Ipv4(0x7F000001).to_string());
+    }
+
+    #[test]
+    fn test_ipv6() {
+        let loopback = Host::parse("[::1]", false).expect("failed to parse ipv6");
+        assert_eq!(Host::Ipv6([0, 0, 0, 0, 0, 0, 0, 1]), loopback);
+        assert_eq!("[::1]", loopback.to_string());
+
+        let address = Host::parse("[2001:DB8:0:0:1:0:0:1]", false).expect("failed to parse ipv6");
+        assert_eq!("[2001:db8::1:0:0:1]", address.to_string());
+
+        let mapped = Host::parse("[::ffff:192.0.2.1]", false).expect("failed to parse ipv6");
+        assert_eq!("[::ffff:c000:201]", mapped.to_string());
+
+        assert_eq!(Err(UrlError::InvalidIpv6Address), Host::parse("[::1", false));
+        assert_eq!(Err(UrlError::InvalidIpv6Address), Host::parse("[1:::2]", false));
+    }
+
+    #[test]
+    fn test_opaque() {
+        assert_eq!(
+            Ok(Host::Opaque("ex%C3%A4mple".to_string())),
+            Host::parse("exämple", true)
+        );
+        assert_eq!(Err(UrlError::InvalidDomain), Host::parse("a<b", true));
+    }
+}
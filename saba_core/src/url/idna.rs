@@ -0,0 +1,457 @@
+//! https://www.unicode.org/reports/tr46/
+//! https://datatracker.ietf.org/doc/html/rfc3492
+
+use crate::url::idna_table::Status;
+use crate::url::idna_table::CANONICAL_COMPOSITION;
+use crate::url::idna_table::CANONICAL_DECOMPOSITION;
+use crate::url::idna_table::COMBINING_CLASS;
+use crate::url::idna_table::MAPPING_TABLE;
+use crate::url::UrlError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const ACE_PREFIX: &str = "xn--";
+
+// "日本語.jp"のような国際化ドメイン名を、DNSやHostヘッダで使うASCIIの形（"xn--wgv71a119e.jp"）に変換する
+/// https://url.spec.whatwg.org/#concept-domain-to-ascii
+/// https://www.unicode.org/reports/tr46/#ToASCII
+pub fn domain_to_ascii(domain: &str) -> Result<String, UrlError> {
+    // ASCIIだけで"xn--"のラベルも含まない場合は小文字にするだけでよい
+    if domain.is_ascii() && !domain.split('.').any(has_ace_prefix) {
+        return Ok(domain.to_ascii_lowercase());
+    }
+
+    let labels = process(domain)?;
+
+    let mut result = String::new();
+    for (i, label) in labels.iter().enumerate() {
+        if i > 0 {
+            result.push('.');
+        }
+        if label.iter().all(char::is_ascii) {
+            result.extend(label.iter());
+        } else {
+            result.push_str(ACE_PREFIX);
+            result.push_str(&punycode_encode(label).ok_or(UrlError::InvalidDomain)?);
+        }
+    }
+
+    Ok(result)
+}
+
+// "xn--"で始まるラベルをUnicodeに戻す。UIでの表示に使う
+// 変換できない場合は元の文字列を返す
+/// https://www.unicode.org/reports/tr46/#ToUnicode
+pub fn domain_to_unicode(domain: &str) -> String {
+    match process(domain) {
+        Ok(labels) => {
+            let mut result = String::new();
+            for (i, label) in labels.iter().enumerate() {
+                if i > 0 {
+                    result.push('.');
+                }
+                result.extend(label.iter());
+            }
+            result
+        }
+        Err(_) => String::from(domain),
+    }
+}
+
+fn has_ace_prefix(label: &str) -> bool {
+    label
+        .get(..ACE_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(ACE_PREFIX))
+}
+
+// マッピング、正規化、ラベルの分割とデコード、検証までを行い、Unicodeのラベルのリストを返す
+/// https://www.unicode.org/reports/tr46/#Processing
+fn process(domain: &str) -> Result<Vec<Vec<char>>, UrlError> {
+    // 1. マッピング
+    let mut mapped = Vec::with_capacity(domain.len());
+    for c in domain.chars() {
+        match lookup(c) {
+            Status::Valid => mapped.push(c),
+            Status::Ignored => {}
+            Status::Mapped(to) => mapped.extend(to.chars()),
+            Status::Disallowed => return Err(UrlError::InvalidDomain),
+        }
+    }
+
+    // 2. NFCに正規化
+    let normalized = nfc(&mapped);
+
+    // 3. ラベルに分割し、"xn--"で始まるラベルはデコードする
+    let mut labels = Vec::new();
+    for label in normalized.split(|c| *c == '.') {
+        let text: String = label.iter().collect();
+        if has_ace_prefix(&text) {
+            if !text.is_ascii() {
+                return Err(UrlError::InvalidDomain);
+            }
+            let decoded = punycode_decode(&text[ACE_PREFIX.len()..]).ok_or(UrlError::InvalidDomain)?;
+            validate_label(&decoded)?;
+            labels.push(decoded);
+        } else {
+            validate_label(label)?;
+            labels.push(label.to_vec());
+        }
+    }
+
+    Ok(labels)
+}
+
+/// https://www.unicode.org/reports/tr46/#Validity_Criteria
+fn validate_label(label: &[char]) -> Result<(), UrlError> {
+    if nfc(label) != label {
+        return Err(UrlError::InvalidDomain);
+    }
+    if label.iter().any(|c| lookup(*c) != Status::Valid) {
+        return Err(UrlError::InvalidDomain);
+    }
+    Ok(())
+}
+
+fn lookup(c: char) -> Status {
+    let cp = c as u32;
+    match MAPPING_TABLE.binary_search_by(|(start, end, _)| {
+        if *end < cp {
+            core::cmp::Ordering::Less
+        } else if *start > cp {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => MAPPING_TABLE[index].2,
+        Err(_) => Status::Disallowed,
+    }
+}
+
+// ハングル音節の分解・合成に使う定数
+/// https://www.unicode.org/versions/latest/ch03.pdf (3.12 Conjoining Jamo Behavior)
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// https://unicode.org/reports/tr15/#Norm_Forms
+fn nfc(input: &[char]) -> Vec<char> {
+    // 正準分解
+    let mut decomposed = Vec::with_capacity(input.len());
+    for c in input {
+        decompose(*c as u32, &mut decomposed);
+    }
+
+    // 結合文字を結合クラスの順に並べ替える（安定ソート）
+    let mut i = 1;
+    while i < decomposed.len() {
+        let ccc = combining_class(decomposed[i]);
+        let mut j = i;
+        while ccc != 0 && j > 0 && combining_class(decomposed[j - 1]) > ccc {
+            decomposed.swap(j - 1, j);
+            j -= 1;
+        }
+        i += 1;
+    }
+
+    // 正準合成
+    let mut result: Vec<u32> = Vec::with_capacity(decomposed.len());
+    let mut starter: Option<usize> = None;
+    let mut last_ccc = 0;
+    for cp in decomposed {
+        let ccc = combining_class(cp);
+        if let Some(s) = starter {
+            // 間に挟まった文字によって合成が妨げられていないか
+            let blocked = result.len() - 1 != s && (last_ccc == 0 || last_ccc >= ccc);
+            if !blocked {
+                if let Some(composite) = compose(result[s], cp) {
+                    result[s] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if ccc == 0 {
+            starter = Some(result.len());
+        }
+        last_ccc = ccc;
+        result.push(cp);
+    }
+
+    result.into_iter().filter_map(char::from_u32).collect()
+}
+
+fn decompose(cp: u32, out: &mut Vec<u32>) {
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let s_index = cp - S_BASE;
+        out.push(L_BASE + s_index / N_COUNT);
+        out.push(V_BASE + (s_index % N_COUNT) / T_COUNT);
+        if s_index % T_COUNT != 0 {
+            out.push(T_BASE + s_index % T_COUNT);
+        }
+        return;
+    }
+
+    match CANONICAL_DECOMPOSITION.binary_search_by_key(&cp, |(c, _, _)| *c) {
+        Ok(index) => {
+            let (_, first, second) = CANONICAL_DECOMPOSITION[index];
+            decompose(first, out);
+            if second != 0 {
+                decompose(second, out);
+            }
+        }
+        Err(_) => out.push(cp),
+    }
+}
+
+fn compose(first: u32, second: u32) -> Option<u32> {
+    // L + V -> LV
+    if (L_BASE..L_BASE + L_COUNT).contains(&first) && (V_BASE..V_BASE + V_COUNT).contains(&second) {
+        return Some(S_BASE + ((first - L_BASE) * V_COUNT + (second - V_BASE)) * T_COUNT);
+    }
+    // LV + T -> LVT
+    if (S_BASE..S_BASE + S_COUNT).contains(&first)
+        && (first - S_BASE) % T_COUNT == 0
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&second)
+    {
+        return Some(first + (second - T_BASE));
+    }
+
+    CANONICAL_COMPOSITION
+        .binary_search_by_key(&(first, second), |(f, s, _)| (*f, *s))
+        .ok()
+        .map(|index| CANONICAL_COMPOSITION[index].2)
+}
+
+fn combining_class(cp: u32) -> u8 {
+    match COMBINING_CLASS.binary_search_by(|(start, end, _)| {
+        if *end < cp {
+            core::cmp::Ordering::Less
+        } else if *start > cp {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => COMBINING_CLASS[index].2,
+        Err(_) => 0,
+    }
+}
+
+// Punycodeのパラメータ
+/// https://datatracker.ietf.org/doc/html/rfc3492#section-5
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// https://datatracker.ietf.org/doc/html/rfc3492#section-6.1
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    // 0..25は"a".."z"、26..35は"0".."9"
+    if d < 26 {
+        (b'a' + d as u8) as char
+    } else {
+        (b'0' + (d - 26) as u8) as char
+    }
+}
+
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+// 桁あふれした場合はNoneを返す
+/// https://datatracker.ietf.org/doc/html/rfc3492#section-6.3
+pub fn punycode_encode(input: &[char]) -> Option<String> {
+    let mut output: String = input.iter().filter(|c| c.is_ascii()).collect();
+    let basic_count = output.len() as u32;
+    let mut handled = basic_count;
+    if basic_count > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while (handled as usize) < input.len() {
+        // まだ処理していない最小のコードポイント
+        let m = input.iter().map(|c| *c as u32).filter(|c| *c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for c in input {
+            let c = *c as u32;
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_count);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1)?;
+        n = n.checked_add(1)?;
+    }
+
+    Some(output)
+}
+
+// 不正な入力の場合はNoneを返す
+/// https://datatracker.ietf.org/doc/html/rfc3492#section-6.2
+pub fn punycode_decode(input: &str) -> Option<Vec<char>> {
+    // 最後の"-"より前は基本文字（ASCII）
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut digits = extended.chars();
+
+    while !digits.as_str().is_empty() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(digits.next()?)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+
+        // 拡張部分に基本文字が出てくるのは不正
+        if n < INITIAL_N {
+            return None;
+        }
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_punycode() {
+        // https://datatracker.ietf.org/doc/html/rfc3492#section-7.1 (J) Japanese
+        let text = "なぜみんな日本語を話してくれないのか";
+        let encoded = punycode_encode(&text.chars().collect::<Vec<char>>());
+        assert_eq!(encoded, Some("n8jok5ay5dzabd5bym9f0cm5685rrjetr6pdxa".to_string()));
+
+        let decoded = punycode_decode("n8jok5ay5dzabd5bym9f0cm5685rrjetr6pdxa").expect("failed to decode");
+        assert_eq!(decoded.iter().collect::<String>(), text);
+
+        assert_eq!(
+            punycode_encode(&"bücher".chars().collect::<Vec<char>>()),
+            Some("bcher-kva".to_string())
+        );
+        assert_eq!(punycode_decode("bcher-kva").map(|d| d.iter().collect::<String>()), Some("bücher".to_string()));
+
+        assert_eq!(punycode_decode("999999999999"), None);
+        assert_eq!(punycode_decode("abc!"), None);
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(domain_to_ascii("日本語.jp"), Ok("xn--wgv71a119e.jp".to_string()));
+        assert_eq!(domain_to_ascii("例え.テスト"), Ok("xn--r8jz45g.xn--zckzah".to_string()));
+        assert_eq!(domain_to_ascii("Bücher.DE"), Ok("xn--bcher-kva.de".to_string()));
+        // ß は非移行処理ではそのまま残る
+        assert_eq!(domain_to_ascii("faß.de"), Ok("xn--fa-hia.de".to_string()));
+    }
+
+    #[test]
+    fn test_to_ascii_mapping() {
+        // 全角英数字と全角の句点
+        assert_eq!(domain_to_ascii("Ｅｘａｍｐｌｅ。com"), Ok("example.com".to_string()));
+        // 半角カナの濁点は合成される
+        assert_eq!(domain_to_ascii("ｶﾞ.jp"), Ok("xn--mck.jp".to_string()));
+        // ソフトハイフンは無視される
+        assert_eq!(domain_to_ascii("exa\u{AD}mple.com"), Ok("example.com".to_string()));
+    }
+
+    #[test]
+    fn test_to_ascii_invalid() {
+        assert_eq!(domain_to_ascii("a\u{FFFF}b.com"), Err(UrlError::InvalidDomain));
+        assert_eq!(domain_to_ascii("xn--a.com"), Err(UrlError::InvalidDomain));
+        // 大文字の"xn--"ラベルも小文字にしてからデコードする
+        assert_eq!(domain_to_ascii("xn--BCHER-KVA.de"), Ok("xn--bcher-kva.de".to_string()));
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(domain_to_unicode("xn--wgv71a119e.jp"), "日本語.jp".to_string());
+        assert_eq!(domain_to_unicode("XN--BCHER-KVA.de"), "bücher.de".to_string());
+        assert_eq!(domain_to_unicode("example.com"), "example.com".to_string());
+        assert_eq!(domain_to_unicode("xn--a.com"), "xn--a.com".to_string());
+    }
+}
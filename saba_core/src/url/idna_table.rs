@@ -0,0 +1,11160 @@
+// This file is generated by scripts/gen_idna_table.py. Do not edit it by hand.
+// IdnaMappingTable.txt / Unicode 15.1.0
+
+/// https://www.unicode.org/reports/tr46/#IDNA_Mapping_Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Valid,
+    Ignored,
+    Mapped(&'static str),
+    Disallowed,
+}
+
+// (最初のコードポイント, 最後のコードポイント, 状態)
+pub static MAPPING_TABLE: &[(u32, u32, Status)] = &[
+    (0x0, 0x40, Status::Valid),
+    (0x41, 0x41, Status::Mapped("a")),
+    (0x42, 0x42, Status::Mapped("b")),
+    (0x43, 0x43, Status::Mapped("c")),
+    (0x44, 0x44, Status::Mapped("d")),
+    (0x45, 0x45, Status::Mapped("e")),
+    (0x46, 0x46, Status::Mapped("f")),
+    (0x47, 0x47, Status::Mapped("g")),
+    (0x48, 0x48, Status::Mapped("h")),
+    (0x49, 0x49, Status::Mapped("i")),
+    (0x4A, 0x4A, Status::Mapped("j")),
+    (0x4B, 0x4B, Status::Mapped("k")),
+    (0x4C, 0x4C, Status::Mapped("l")),
+    (0x4D, 0x4D, Status::Mapped("m")),
+    (0x4E, 0x4E, Status::Mapped("n")),
+    (0x4F, 0x4F, Status::Mapped("o")),
+    (0x50, 0x50, Status::Mapped("p")),
+    (0x51, 0x51, Status::Mapped("q")),
+    (0x52, 0x52, Status::Mapped("r")),
+    (0x53, 0x53, Status::Mapped("s")),
+    (0x54, 0x54, Status::Mapped("t")),
+    (0x55, 0x55, Status::Mapped("u")),
+    (0x56, 0x56, Status::Mapped("v")),
+    (0x57, 0x57, Status::Mapped("w")),
+    (0x58, 0x58, Status::Mapped("x")),
+    (0x59, 0x59, Status::Mapped("y")),
+    (0x5A, 0x5A, Status::Mapped("z")),
+    (0x5B, 0x7F, Status::Valid),
+    (0x80, 0x9F, Status::Disallowed),
+    (0xA0, 0xA9, Status::Valid),
+    (0xAA, 0xAA, Status::Mapped("a")),
+    (0xAB, 0xAC, Status::Valid),
+    (0xAD, 0xAD, Status::Ignored),
+    (0xAE, 0xB1, Status::Valid),
+    (0xB2, 0xB2, Status::Mapped("2")),
+    (0xB3, 0xB3, Status::Mapped("3")),
+    (0xB4, 0xB4, Status::Valid),
+    (0xB5, 0xB5, Status::Mapped("\u{3BC}")),
+    (0xB6, 0xB8, Status::Valid),
+    (0xB9, 0xB9, Status::Mapped("1")),
+    (0xBA, 0xBA, Status::Mapped("o")),
+    (0xBB, 0xBB, Status::Valid),
+    (0xBC, 0xBC, Status::Mapped("1\u{2044}4")),
+    (0xBD, 0xBD, Status::Mapped("1\u{2044}2")),
+    (0xBE, 0xBE, Status::Mapped("3\u{2044}4")),
+    (0xBF, 0xBF, Status::Valid),
+    (0xC0, 0xC0, Status::Mapped("\u{E0}")),
+    (0xC1, 0xC1, Status::Mapped("\u{E1}")),
+    (0xC2, 0xC2, Status::Mapped("\u{E2}")),
+    (0xC3, 0xC3, Status::Mapped("\u{E3}")),
+    (0xC4, 0xC4, Status::Mapped("\u{E4}")),
+    (0xC5, 0xC5, Status::Mapped("\u{E5}")),
+    (0xC6, 0xC6, Status::Mapped("\u{E6}")),
+    (0xC7, 0xC7, Status::Mapped("\u{E7}")),
+    (0xC8, 0xC8, Status::Mapped("\u{E8}")),
+    (0xC9, 0xC9, Status::Mapped("\u{E9}")),
+    (0xCA, 0xCA, Status::Mapped("\u{EA}")),
+    (0xCB, 0xCB, Status::Mapped("\u{EB}")),
+    (0xCC, 0xCC, Status::Mapped("\u{EC}")),
+    (0xCD, 0xCD, Status::Mapped("\u{ED}")),
+    (0xCE, 0xCE, Status::Mapped("\u{EE}")),
+    (0xCF, 0xCF, Status::Mapped("\u{EF}")),
+    (0xD0, 0xD0, Status::Mapped("\u{F0}")),
+    (0xD1, 0xD1, Status::Mapped("\u{F1}")),
+    (0xD2, 0xD2, Status::Mapped("\u{F2}")),
+    (0xD3, 0xD3, Status::Mapped("\u{F3}")),
+    (0xD4, 0xD4, Status::Mapped("\u{F4}")),
+    (0xD5, 0xD5, Status::Mapped("\u{F5}")),
+    (0xD6, 0xD6, Status::Mapped("\u{F6}")),
+    (0xD7, 0xD7, Status::Valid),
+    (0xD8, 0xD8, Status::Mapped("\u{F8}")),
+    (0xD9, 0xD9, Status::Mapped("\u{F9}")),
+    (0xDA, 0xDA, Status::Mapped("\u{FA}")),
+    (0xDB, 0xDB, Status::Mapped("\u{FB}")),
+    (0xDC, 0xDC, Status::Mapped("\u{FC}")),
+    (0xDD, 0xDD, Status::Mapped("\u{FD}")),
+    (0xDE, 0xDE, Status::Mapped("\u{FE}")),
+    (0xDF, 0xFF, Status::Valid),
+    (0x100, 0x100, Status::Mapped("\u{101}")),
+    (0x101, 0x101, Status::Valid),
+    (0x102, 0x102, Status::Mapped("\u{103}")),
+    (0x103, 0x103, Status::Valid),
+    (0x104, 0x104, Status::Mapped("\u{105}")),
+    (0x105, 0x105, Status::Valid),
+    (0x106, 0x106, Status::Mapped("\u{107}")),
+    (0x107, 0x107, Status::Valid),
+    (0x108, 0x108, Status::Mapped("\u{109}")),
+    (0x109, 0x109, Status::Valid),
+    (0x10A, 0x10A, Status::Mapped("\u{10B}")),
+    (0x10B, 0x10B, Status::Valid),
+    (0x10C, 0x10C, Status::Mapped("\u{10D}")),
+    (0x10D, 0x10D, Status::Valid),
+    (0x10E, 0x10E, Status::Mapped("\u{10F}")),
+    (0x10F, 0x10F, Status::Valid),
+    (0x110, 0x110, Status::Mapped("\u{111}")),
+    (0x111, 0x111, Status::Valid),
+    (0x112, 0x112, Status::Mapped("\u{113}")),
+    (0x113, 0x113, Status::Valid),
+    (0x114, 0x114, Status::Mapped("\u{115}")),
+    (0x115, 0x115, Status::Valid),
+    (0x116, 0x116, Status::Mapped("\u{117}")),
+    (0x117, 0x117, Status::Valid),
+    (0x118, 0x118, Status::Mapped("\u{119}")),
+    (0x119, 0x119, Status::Valid),
+    (0x11A, 0x11A, Status::Mapped("\u{11B}")),
+    (0x11B, 0x11B, Status::Valid),
+    (0x11C, 0x11C, Status::Mapped("\u{11D}")),
+    (0x11D, 0x11D, Status::Valid),
+    (0x11E, 0x11E, Status::Mapped("\u{11F}")),
+    (0x11F, 0x11F, Status::Valid),
+    (0x120, 0x120, Status::Mapped("\u{121}")),
+    (0x121, 0x121, Status::Valid),
+    (0x122, 0x122, Status::Mapped("\u{123}")),
+    (0x123, 0x123, Status::Valid),
+    (0x124, 0x124, Status::Mapped("\u{125}")),
+    (0x125, 0x125, Status::Valid),
+    (0x126, 0x126, Status::Mapped("\u{127}")),
+    (0x127, 0x127, Status::Valid),
+    (0x128, 0x128, Status::Mapped("\u{129}")),
+    (0x129, 0x129, Status::Valid),
+    (0x12A, 0x12A, Status::Mapped("\u{12B}")),
+    (0x12B, 0x12B, Status::Valid),
+    (0x12C, 0x12C, Status::Mapped("\u{12D}")),
+    (0x12D, 0x12D, Status::Valid),
+    (0x12E, 0x12E, Status::Mapped("\u{12F}")),
+    (0x12F, 0x12F, Status::Valid),
+    (0x130, 0x130, Status::Mapped("i\u{307}")),
+    (0x131, 0x131, Status::Valid),
+    (0x132, 0x133, Status::Mapped("ij")),
+    (0x134, 0x134, Status::Mapped("\u{135}")),
+    (0x135, 0x135, Status::Valid),
+    (0x136, 0x136, Status::Mapped("\u{137}")),
+    (0x137, 0x138, Status::Valid),
+    (0x139, 0x139, Status::Mapped("\u{13A}")),
+    (0x13A, 0x13A, Status::Valid),
+    (0x13B, 0x13B, Status::Mapped("\u{13C}")),
+    (0x13C, 0x13C, Status::Valid),
+    (0x13D, 0x13D, Status::Mapped("\u{13E}")),
+    (0x13E, 0x13E, Status::Valid),
+    (0x13F, 0x140, Status::Mapped("l\u{B7}")),
+    (0x141, 0x141, Status::Mapped("\u{142}")),
+    (0x142, 0x142, Status::Valid),
+    (0x143, 0x143, Status::Mapped("\u{144}")),
+    (0x144, 0x144, Status::Valid),
+    (0x145, 0x145, Status::Mapped("\u{146}")),
+    (0x146, 0x146, Status::Valid),
+    (0x147, 0x147, Status::Mapped("\u{148}")),
+    (0x148, 0x148, Status::Valid),
+    (0x149, 0x149, Status::Mapped("\u{2BC}n")),
+    (0x14A, 0x14A, Status::Mapped("\u{14B}")),
+    (0x14B, 0x14B, Status::Valid),
+    (0x14C, 0x14C, Status::Mapped("\u{14D}")),
+    (0x14D, 0x14D, Status::Valid),
+    (0x14E, 0x14E, Status::Mapped("\u{14F}")),
+    (0x14F, 0x14F, Status::Valid),
+    (0x150, 0x150, Status::Mapped("\u{151}")),
+    (0x151, 0x151, Status::Valid),
+    (0x152, 0x152, Status::Mapped("\u{153}")),
+    (0x153, 0x153, Status::Valid),
+    (0x154, 0x154, Status::Mapped("\u{155}")),
+    (0x155, 0x155, Status::Valid),
+    (0x156, 0x156, Status::Mapped("\u{157}")),
+    (0x157, 0x157, Status::Valid),
+    (0x158, 0x158, Status::Mapped("\u{159}")),
+    (0x159, 0x159, Status::Valid),
+    (0x15A, 0x15A, Status::Mapped("\u{15B}")),
+    (0x15B, 0x15B, Status::Valid),
+    (0x15C, 0x15C, Status::Mapped("\u{15D}")),
+    (0x15D, 0x15D, Status::Valid),
+    (0x15E, 0x15E, Status::Mapped("\u{15F}")),
+    (0x15F, 0x15F, Status::Valid),
+    (0x160, 0x160, Status::Mapped("\u{161}")),
+    (0x161, 0x161, Status::Valid),
+    (0x162, 0x162, Status::Mapped("\u{163}")),
+    (0x163, 0x163, Status::Valid),
+    (0x164, 0x164, Status::Mapped("\u{165}")),
+    (0x165, 0x165, Status::Valid),
+    (0x166, 0x166, Status::Mapped("\u{167}")),
+    (0x167, 0x167, Status::Valid),
+    (0x168, 0x168, Status::Mapped("\u{169}")),
+    (0x169, 0x169, Status::Valid),
+    (0x16A, 0x16A, Status::Mapped("\u{16B}")),
+    (0x16B, 0x16B, Status::Valid),
+    (0x16C, 0x16C, Status::Mapped("\u{16D}")),
+    (0x16D, 0x16D, Status::Valid),
+    (0x16E, 0x16E, Status::Mapped("\u{16F}")),
+    (0x16F, 0x16F, Status::Valid),
+    (0x170, 0x170, Status::Mapped("\u{171}")),
+    (0x171, 0x171, Status::Valid),
+    (0x172, 0x172, Status::Mapped("\u{173}")),
+    (0x173, 0x173, Status::Valid),
+    (0x174, 0x174, Status::Mapped("\u{175}")),
+    (0x175, 0x175, Status::Valid),
+    (0x176, 0x176, Status::Mapped("\u{177}")),
+    (0x177, 0x177, Status::Valid),
+    (0x178, 0x178, Status::Mapped("\u{FF}")),
+    (0x179, 0x179, Status::Mapped("\u{17A}")),
+    (0x17A, 0x17A, Status::Valid),
+    (0x17B, 0x17B, Status::Mapped("\u{17C}")),
+    (0x17C, 0x17C, Status::Valid),
+    (0x17D, 0x17D, Status::Mapped("\u{17E}")),
+    (0x17E, 0x17E, Status::Valid),
+    (0x17F, 0x17F, Status::Mapped("s")),
+    (0x180, 0x180, Status::Valid),
+    (0x181, 0x181, Status::Mapped("\u{253}")),
+    (0x182, 0x182, Status::Mapped("\u{183}")),
+    (0x183, 0x183, Status::Valid),
+    (0x184, 0x184, Status::Mapped("\u{185}")),
+    (0x185, 0x185, Status::Valid),
+    (0x186, 0x186, Status::Mapped("\u{254}")),
+    (0x187, 0x187, Status::Mapped("\u{188}")),
+    (0x188, 0x188, Status::Valid),
+    (0x189, 0x189, Status::Mapped("\u{256}")),
+    (0x18A, 0x18A, Status::Mapped("\u{257}")),
+    (0x18B, 0x18B, Status::Mapped("\u{18C}")),
+    (0x18C, 0x18D, Status::Valid),
+    (0x18E, 0x18E, Status::Mapped("\u{1DD}")),
+    (0x18F, 0x18F, Status::Mapped("\u{259}")),
+    (0x190, 0x190, Status::Mapped("\u{25B}")),
+    (0x191, 0x191, Status::Mapped("\u{192}")),
+    (0x192, 0x192, Status::Valid),
+    (0x193, 0x193, Status::Mapped("\u{260}")),
+    (0x194, 0x194, Status::Mapped("\u{263}")),
+    (0x195, 0x195, Status::Valid),
+    (0x196, 0x196, Status::Mapped("\u{269}")),
+    (0x197, 0x197, Status::Mapped("\u{268}")),
+    (0x198, 0x198, Status::Mapped("\u{199}")),
+    (0x199, 0x19B, Status::Valid),
+    (0x19C, 0x19C, Status::Mapped("\u{26F}")),
+    (0x19D, 0x19D, Status::Mapped("\u{272}")),
+    (0x19E, 0x19E, Status::Valid),
+    (0x19F, 0x19F, Status::Mapped("\u{275}")),
+    (0x1A0, 0x1A0, Status::Mapped("\u{1A1}")),
+    (0x1A1, 0x1A1, Status::Valid),
+    (0x1A2, 0x1A2, Status::Mapped("\u{1A3}")),
+    (0x1A3, 0x1A3, Status::Valid),
+    (0x1A4, 0x1A4, Status::Mapped("\u{1A5}")),
+    (0x1A5, 0x1A5, Status::Valid),
+    (0x1A6, 0x1A6, Status::Mapped("\u{280}")),
+    (0x1A7, 0x1A7, Status::Mapped("\u{1A8}")),
+    (0x1A8, 0x1A8, Status::Valid),
+    (0x1A9, 0x1A9, Status::Mapped("\u{283}")),
+    (0x1AA, 0x1AB, Status::Valid),
+    (0x1AC, 0x1AC, Status::Mapped("\u{1AD}")),
+    (0x1AD, 0x1AD, Status::Valid),
+    (0x1AE, 0x1AE, Status::Mapped("\u{288}")),
+    (0x1AF, 0x1AF, Status::Mapped("\u{1B0}")),
+    (0x1B0, 0x1B0, Status::Valid),
+    (0x1B1, 0x1B1, Status::Mapped("\u{28A}")),
+    (0x1B2, 0x1B2, Status::Mapped("\u{28B}")),
+    (0x1B3, 0x1B3, Status::Mapped("\u{1B4}")),
+    (0x1B4, 0x1B4, Status::Valid),
+    (0x1B5, 0x1B5, Status::Mapped("\u{1B6}")),
+    (0x1B6, 0x1B6, Status::Valid),
+    (0x1B7, 0x1B7, Status::Mapped("\u{292}")),
+    (0x1B8, 0x1B8, Status::Mapped("\u{1B9}")),
+    (0x1B9, 0x1BB, Status::Valid),
+    (0x1BC, 0x1BC, Status::Mapped("\u{1BD}")),
+    (0x1BD, 0x1C3, Status::Valid),
+    (0x1C4, 0x1C6, Status::Mapped("d\u{17E}")),
+    (0x1C7, 0x1C9, Status::Mapped("lj")),
+    (0x1CA, 0x1CC, Status::Mapped("nj")),
+    (0x1CD, 0x1CD, Status::Mapped("\u{1CE}")),
+    (0x1CE, 0x1CE, Status::Valid),
+    (0x1CF, 0x1CF, Status::Mapped("\u{1D0}")),
+    (0x1D0, 0x1D0, Status::Valid),
+    (0x1D1, 0x1D1, Status::Mapped("\u{1D2}")),
+    (0x1D2, 0x1D2, Status::Valid),
+    (0x1D3, 0x1D3, Status::Mapped("\u{1D4}")),
+    (0x1D4, 0x1D4, Status::Valid),
+    (0x1D5, 0x1D5, Status::Mapped("\u{1D6}")),
+    (0x1D6, 0x1D6, Status::Valid),
+    (0x1D7, 0x1D7, Status::Mapped("\u{1D8}")),
+    (0x1D8, 0x1D8, Status::Valid),
+    (0x1D9, 0x1D9, Status::Mapped("\u{1DA}")),
+    (0x1DA, 0x1DA, Status::Valid),
+    (0x1DB, 0x1DB, Status::Mapped("\u{1DC}")),
+    (0x1DC, 0x1DD, Status::Valid),
+    (0x1DE, 0x1DE, Status::Mapped("\u{1DF}")),
+    (0x1DF, 0x1DF, Status::Valid),
+    (0x1E0, 0x1E0, Status::Mapped("\u{1E1}")),
+    (0x1E1, 0x1E1, Status::Valid),
+    (0x1E2, 0x1E2, Status::Mapped("\u{1E3}")),
+    (0x1E3, 0x1E3, Status::Valid),
+    (0x1E4, 0x1E4, Status::Mapped("\u{1E5}")),
+    (0x1E5, 0x1E5, Status::Valid),
+    (0x1E6, 0x1E6, Status::Mapped("\u{1E7}")),
+    (0x1E7, 0x1E7, Status::Valid),
+    (0x1E8, 0x1E8, Status::Mapped("\u{1E9}")),
+    (0x1E9, 0x1E9, Status::Valid),
+    (0x1EA, 0x1EA, Status::Mapped("\u{1EB}")),
+    (0x1EB, 0x1EB, Status::Valid),
+    (0x1EC, 0x1EC, Status::Mapped("\u{1ED}")),
+    (0x1ED, 0x1ED, Status::Valid),
+    (0x1EE, 0x1EE, Status::Mapped("\u{1EF}")),
+    (0x1EF, 0x1F0, Status::Valid),
+    (0x1F1, 0x1F3, Status::Mapped("dz")),
+    (0x1F4, 0x1F4, Status::Mapped("\u{1F5}")),
+    (0x1F5, 0x1F5, Status::Valid),
+    (0x1F6, 0x1F6, Status::Mapped("\u{195}")),
+    (0x1F7, 0x1F7, Status::Mapped("\u{1BF}")),
+    (0x1F8, 0x1F8, Status::Mapped("\u{1F9}")),
+    (0x1F9, 0x1F9, Status::Valid),
+    (0x1FA, 0x1FA, Status::Mapped("\u{1FB}")),
+    (0x1FB, 0x1FB, Status::Valid),
+    (0x1FC, 0x1FC, Status::Mapped("\u{1FD}")),
+    (0x1FD, 0x1FD, Status::Valid),
+    (0x1FE, 0x1FE, Status::Mapped("\u{1FF}")),
+    (0x1FF, 0x1FF, Status::Valid),
+    (0x200, 0x200, Status::Mapped("\u{201}")),
+    (0x201, 0x201, Status::Valid),
+    (0x202, 0x202, Status::Mapped("\u{203}")),
+    (0x203, 0x203, Status::Valid),
+    (0x204, 0x204, Status::Mapped("\u{205}")),
+    (0x205, 0x205, Status::Valid),
+    (0x206, 0x206, Status::Mapped("\u{207}")),
+    (0x207, 0x207, Status::Valid),
+    (0x208, 0x208, Status::Mapped("\u{209}")),
+    (0x209, 0x209, Status::Valid),
+    (0x20A, 0x20A, Status::Mapped("\u{20B}")),
+    (0x20B, 0x20B, Status::Valid),
+    (0x20C, 0x20C, Status::Mapped("\u{20D}")),
+    (0x20D, 0x20D, Status::Valid),
+    (0x20E, 0x20E, Status::Mapped("\u{20F}")),
+    (0x20F, 0x20F, Status::Valid),
+    (0x210, 0x210, Status::Mapped("\u{211}")),
+    (0x211, 0x211, Status::Valid),
+    (0x212, 0x212, Status::Mapped("\u{213}")),
+    (0x213, 0x213, Status::Valid),
+    (0x214, 0x214, Status::Mapped("\u{215}")),
+    (0x215, 0x215, Status::Valid),
+    (0x216, 0x216, Status::Mapped("\u{217}")),
+    (0x217, 0x217, Status::Valid),
+    (0x218, 0x218, Status::Mapped("\u{219}")),
+    (0x219, 0x219, Status::Valid),
+    (0x21A, 0x21A, Status::Mapped("\u{21B}")),
+    (0x21B, 0x21B, Status::Valid),
+    (0x21C, 0x21C, Status::Mapped("\u{21D}")),
+    (0x21D, 0x21D, Status::Valid),
+    (0x21E, 0x21E, Status::Mapped("\u{21F}")),
+    (0x21F, 0x21F, Status::Valid),
+    (0x220, 0x220, Status::Mapped("\u{19E}")),
+    (0x221, 0x221, Status::Valid),
+    (0x222, 0x222, Status::Mapped("\u{223}")),
+    (0x223, 0x223, Status::Valid),
+    (0x224, 0x224, Status::Mapped("\u{225}")),
+    (0x225, 0x225, Status::Valid),
+    (0x226, 0x226, Status::Mapped("\u{227}")),
+    (0x227, 0x227, Status::Valid),
+    (0x228, 0x228, Status::Mapped("\u{229}")),
+    (0x229, 0x229, Status::Valid),
+    (0x22A, 0x22A, Status::Mapped("\u{22B}")),
+    (0x22B, 0x22B, Status::Valid),
+    (0x22C, 0x22C, Status::Mapped("\u{22D}")),
+    (0x22D, 0x22D, Status::Valid),
+    (0x22E, 0x22E, Status::Mapped("\u{22F}")),
+    (0x22F, 0x22F, Status::Valid),
+    (0x230, 0x230, Status::Mapped("\u{231}")),
+    (0x231, 0x231, Status::Valid),
+    (0x232, 0x232, Status::Mapped("\u{233}")),
+    (0x233, 0x239, Status::Valid),
+    (0x23A, 0x23A, Status::Mapped("\u{2C65}")),
+    (0x23B, 0x23B, Status::Mapped("\u{23C}")),
+    (0x23C, 0x23C, Status::Valid),
+    (0x23D, 0x23D, Status::Mapped("\u{19A}")),
+    (0x23E, 0x23E, Status::Mapped("\u{2C66}")),
+    (0x23F, 0x240, Status::Valid),
+    (0x241, 0x241, Status::Mapped("\u{242}")),
+    (0x242, 0x242, Status::Valid),
+    (0x243, 0x243, Status::Mapped("\u{180}")),
+    (0x244, 0x244, Status::Mapped("\u{289}")),
+    (0x245, 0x245, Status::Mapped("\u{28C}")),
+    (0x246, 0x246, Status::Mapped("\u{247}")),
+    (0x247, 0x247, Status::Valid),
+    (0x248, 0x248, Status::Mapped("\u{249}")),
+    (0x249, 0x249, Status::Valid),
+    (0x24A, 0x24A, Status::Mapped("\u{24B}")),
+    (0x24B, 0x24B, Status::Valid),
+    (0x24C, 0x24C, Status::Mapped("\u{24D}")),
+    (0x24D, 0x24D, Status::Valid),
+    (0x24E, 0x24E, Status::Mapped("\u{24F}")),
+    (0x24F, 0x2AF, Status::Valid),
+    (0x2B0, 0x2B0, Status::Mapped("h")),
+    (0x2B1, 0x2B1, Status::Mapped("\u{266}")),
+    (0x2B2, 0x2B2, Status::Mapped("j")),
+    (0x2B3, 0x2B3, Status::Mapped("r")),
+    (0x2B4, 0x2B4, Status::Mapped("\u{279}")),
+    (0x2B5, 0x2B5, Status::Mapped("\u{27B}")),
+    (0x2B6, 0x2B6, Status::Mapped("\u{281}")),
+    (0x2B7, 0x2B7, Status::Mapped("w")),
+    (0x2B8, 0x2B8, Status::Mapped("y")),
+    (0x2B9, 0x2DF, Status::Valid),
+    (0x2E0, 0x2E0, Status::Mapped("\u{263}")),
+    (0x2E1, 0x2E1, Status::Mapped("l")),
+    (0x2E2, 0x2E2, Status::Mapped("s")),
+    (0x2E3, 0x2E3, Status::Mapped("x")),
+    (0x2E4, 0x2E4, Status::Mapped("\u{295}")),
+    (0x2E5, 0x33F, Status::Valid),
+    (0x340, 0x340, Status::Mapped("\u{300}")),
+    (0x341, 0x341, Status::Mapped("\u{301}")),
+    (0x342, 0x342, Status::Valid),
+    (0x343, 0x343, Status::Mapped("\u{313}")),
+    (0x344, 0x344, Status::Mapped("\u{308}\u{301}")),
+    (0x345, 0x345, Status::Mapped("\u{3B9}")),
+    (0x346, 0x34E, Status::Valid),
+    (0x34F, 0x34F, Status::Ignored),
+    (0x350, 0x36F, Status::Valid),
+    (0x370, 0x370, Status::Mapped("\u{371}")),
+    (0x371, 0x371, Status::Valid),
+    (0x372, 0x372, Status::Mapped("\u{373}")),
+    (0x373, 0x373, Status::Valid),
+    (0x374, 0x374, Status::Mapped("\u{2B9}")),
+    (0x375, 0x375, Status::Valid),
+    (0x376, 0x376, Status::Mapped("\u{377}")),
+    (0x377, 0x377, Status::Valid),
+    (0x378, 0x379, Status::Disallowed),
+    (0x37A, 0x37E, Status::Valid),
+    (0x37F, 0x37F, Status::Mapped("\u{3F3}")),
+    (0x380, 0x383, Status::Disallowed),
+    (0x384, 0x385, Status::Valid),
+    (0x386, 0x386, Status::Mapped("\u{3AC}")),
+    (0x387, 0x387, Status::Mapped("\u{B7}")),
+    (0x388, 0x388, Status::Mapped("\u{3AD}")),
+    (0x389, 0x389, Status::Mapped("\u{3AE}")),
+    (0x38A, 0x38A, Status::Mapped("\u{3AF}")),
+    (0x38B, 0x38B, Status::Disallowed),
+    (0x38C, 0x38C, Status::Mapped("\u{3CC}")),
+    (0x38D, 0x38D, Status::Disallowed),
+    (0x38E, 0x38E, Status::Mapped("\u{3CD}")),
+    (0x38F, 0x38F, Status::Mapped("\u{3CE}")),
+    (0x390, 0x390, Status::Valid),
+    (0x391, 0x391, Status::Mapped("\u{3B1}")),
+    (0x392, 0x392, Status::Mapped("\u{3B2}")),
+    (0x393, 0x393, Status::Mapped("\u{3B3}")),
+    (0x394, 0x394, Status::Mapped("\u{3B4}")),
+    (0x395, 0x395, Status::Mapped("\u{3B5}")),
+    (0x396, 0x396, Status::Mapped("\u{3B6}")),
+    (0x397, 0x397, Status::Mapped("\u{3B7}")),
+    (0x398, 0x398, Status::Mapped("\u{3B8}")),
+    (0x399, 0x399, Status::Mapped("\u{3B9}")),
+    (0x39A, 0x39A, Status::Mapped("\u{3BA}")),
+    (0x39B, 0x39B, Status::Mapped("\u{3BB}")),
+    (0x39C, 0x39C, Status::Mapped("\u{3BC}")),
+    (0x39D, 0x39D, Status::Mapped("\u{3BD}")),
+    (0x39E, 0x39E, Status::Mapped("\u{3BE}")),
+    (0x39F, 0x39F, Status::Mapped("\u{3BF}")),
+    (0x3A0, 0x3A0, Status::Mapped("\u{3C0}")),
+    (0x3A1, 0x3A1, Status::Mapped("\u{3C1}")),
+    (0x3A2, 0x3A2, Status::Disallowed),
+    (0x3A3, 0x3A3, Status::Mapped("\u{3C3}")),
+    (0x3A4, 0x3A4, Status::Mapped("\u{3C4}")),
+    (0x3A5, 0x3A5, Status::Mapped("\u{3C5}")),
+    (0x3A6, 0x3A6, Status::Mapped("\u{3C6}")),
+    (0x3A7, 0x3A7, Status::Mapped("\u{3C7}")),
+    (0x3A8, 0x3A8, Status::Mapped("\u{3C8}")),
+    (0x3A9, 0x3A9, Status::Mapped("\u{3C9}")),
+    (0x3AA, 0x3AA, Status::Mapped("\u{3CA}")),
+    (0x3AB, 0x3AB, Status::Mapped("\u{3CB}")),
+    (0x3AC, 0x3CE, Status::Valid),
+    (0x3CF, 0x3CF, Status::Mapped("\u{3D7}")),
+    (0x3D0, 0x3D0, Status::Mapped("\u{3B2}")),
+    (0x3D1, 0x3D1, Status::Mapped("\u{3B8}")),
+    (0x3D2, 0x3D2, Status::Mapped("\u{3C5}")),
+    (0x3D3, 0x3D3, Status::Mapped("\u{3CD}")),
+    (0x3D4, 0x3D4, Status::Mapped("\u{3CB}")),
+    (0x3D5, 0x3D5, Status::Mapped("\u{3C6}")),
+    (0x3D6, 0x3D6, Status::Mapped("\u{3C0}")),
+    (0x3D7, 0x3D7, Status::Valid),
+    (0x3D8, 0x3D8, Status::Mapped("\u{3D9}")),
+    (0x3D9, 0x3D9, Status::Valid),
+    (0x3DA, 0x3DA, Status::Mapped("\u{3DB}")),
+    (0x3DB, 0x3DB, Status::Valid),
+    (0x3DC, 0x3DC, Status::Mapped("\u{3DD}")),
+    (0x3DD, 0x3DD, Status::Valid),
+    (0x3DE, 0x3DE, Status::Mapped("\u{3DF}")),
+    (0x3DF, 0x3DF, Status::Valid),
+    (0x3E0, 0x3E0, Status::Mapped("\u{3E1}")),
+    (0x3E1, 0x3E1, Status::Valid),
+    (0x3E2, 0x3E2, Status::Mapped("\u{3E3}")),
+    (0x3E3, 0x3E3, Status::Valid),
+    (0x3E4, 0x3E4, Status::Mapped("\u{3E5}")),
+    (0x3E5, 0x3E5, Status::Valid),
+    (0x3E6, 0x3E6, Status::Mapped("\u{3E7}")),
+    (0x3E7, 0x3E7, Status::Valid),
+    (0x3E8, 0x3E8, Status::Mapped("\u{3E9}")),
+    (0x3E9, 0x3E9, Status::Valid),
+    (0x3EA, 0x3EA, Status::Mapped("\u{3EB}")),
+    (0x3EB, 0x3EB, Status::Valid),
+    (0x3EC, 0x3EC, Status::Mapped("\u{3ED}")),
+    (0x3ED, 0x3ED, Status::Valid),
+    (0x3EE, 0x3EE, Status::Mapped("\u{3EF}")),
+    (0x3EF, 0x3EF, Status::Valid),
+    (0x3F0, 0x3F0, Status::Mapped("\u{3BA}")),
+    (0x3F1, 0x3F1, Status::Mapped("\u{3C1}")),
+    (0x3F2, 0x3F2, Status::Mapped("\u{3C3}")),
+    (0x3F3, 0x3F3, Status::Valid),
+    (0x3F4, 0x3F4, Status::Mapped("\u{3B8}")),
+    (0x3F5, 0x3F5, Status::Mapped("\u{3B5}")),
+    (0x3F6, 0x3F6, Status::Valid),
+    (0x3F7, 0x3F7, Status::Mapped("\u{3F8}")),
+    (0x3F8, 0x3F8, Status::Valid),
+    (0x3F9, 0x3F9, Status::Mapped("\u{3C3}")),
+    (0x3FA, 0x3FA, Status::Mapped("\u{3FB}")),
+    (0x3FB, 0x3FC, Status::Valid),
+    (0x3FD, 0x3FD, Status::Mapped("\u{37B}")),
+    (0x3FE, 0x3FE, Status::Mapped("\u{37C}")),
+    (0x3FF, 0x3FF, Status::Mapped("\u{37D}")),
+    (0x400, 0x400, Status::Mapped("\u{450}")),
+    (0x401, 0x401, Status::Mapped("\u{451}")),
+    (0x402, 0x402, Status::Mapped("\u{452}")),
+    (0x403, 0x403, Status::Mapped("\u{453}")),
+    (0x404, 0x404, Status::Mapped("\u{454}")),
+    (0x405, 0x405, Status::Mapped("\u{455}")),
+    (0x406, 0x406, Status::Mapped("\u{456}")),
+    (0x407, 0x407, Status::Mapped("\u{457}")),
+    (0x408, 0x408, Status::Mapped("\u{458}")),
+    (0x409, 0x409, Status::Mapped("\u{459}")),
+    (0x40A, 0x40A, Status::Mapped("\u{45A}")),
+    (0x40B, 0x40B, Status::Mapped("\u{45B}")),
+    (0x40C, 0x40C, Status::Mapped("\u{45C}")),
+    (0x40D, 0x40D, Status::Mapped("\u{45D}")),
+    (0x40E, 0x40E, Status::Mapped("\u{45E}")),
+    (0x40F, 0x40F, Status::Mapped("\u{45F}")),
+    (0x410, 0x410, Status::Mapped("\u{430}")),
+    (0x411, 0x411, Status::Mapped("\u{431}")),
+    (0x412, 0x412, Status::Mapped("\u{432}")),
+    (0x413, 0x413, Status::Mapped("\u{433}")),
+    (0x414, 0x414, Status::Mapped("\u{434}")),
+    (0x415, 0x415, Status::Mapped("\u{435}")),
+    (0x416, 0x416, Status::Mapped("\u{436}")),
+    (0x417, 0x417, Status::Mapped("\u{437}")),
+    (0x418, 0x418, Status::Mapped("\u{438}")),
+    (0x419, 0x419, Status::Mapped("\u{439}")),
+    (0x41A, 0x41A, Status::Mapped("\u{43A}")),
+    (0x41B, 0x41B, Status::Mapped("\u{43B}")),
+    (0x41C, 0x41C, Status::Mapped("\u{43C}")),
+    (0x41D, 0x41D, Status::Mapped("\u{43D}")),
+    (0x41E, 0x41E, Status::Mapped("\u{43E}")),
+    (0x41F, 0x41F, Status::Mapped("\u{43F}")),
+    (0x420, 0x420, Status::Mapped("\u{440}")),
+    (0x421, 0x421, Status::Mapped("\u{441}")),
+    (0x422, 0x422, Status::Mapped("\u{442}")),
+    (0x423, 0x423, Status::Mapped("\u{443}")),
+    (0x424, 0x424, Status::Mapped("\u{444}")),
+    (0x425, 0x425, Status::Mapped("\u{445}")),
+    (0x426, 0x426, Status::Mapped("\u{446}")),
+    (0x427, 0x427, Status::Mapped("\u{447}")),
+    (0x428, 0x428, Status::Mapped("\u{448}")),
+    (0x429, 0x429, Status::Mapped("\u{449}")),
+    (0x42A, 0x42A, Status::Mapped("\u{44A}")),
+    (0x42B, 0x42B, Status::Mapped("\u{44B}")),
+    (0x42C, 0x42C, Status::Mapped("\u{44C}")),
+    (0x42D, 0x42D, Status::Mapped("\u{44D}")),
+    (0x42E, 0x42E, Status::Mapped("\u{44E}")),
+    (0x42F, 0x42F, Status::Mapped("\u{44F}")),
+    (0x430, 0x45F, Status::Valid),
+    (0x460, 0x460, Status::Mapped("\u{461}")),
+    (0x461, 0x461, Status::Valid),
+    (0x462, 0x462, Status::Mapped("\u{463}")),
+    (0x463, 0x463, Status::Valid),
+    (0x464, 0x464, Status::Mapped("\u{465}")),
+    (0x465, 0x465, Status::Valid),
+    (0x466, 0x466, Status::Mapped("\u{467}")),
+    (0x467, 0x467, Status::Valid),
+    (0x468, 0x468, Status::Mapped("\u{469}")),
+    (0x469, 0x469, Status::Valid),
+    (0x46A, 0x46A, Status::Mapped("\u{46B}")),
+    (0x46B, 0x46B, Status::Valid),
+    (0x46C, 0x46C, Status::Mapped("\u{46D}")),
+    (0x46D, 0x46D, Status::Valid),
+    (0x46E, 0x46E, Status::Mapped("\u{46F}")),
+    (0x46F, 0x46F, Status::Valid),
+    (0x470, 0x470, Status::Mapped("\u{471}")),
+    (0x471, 0x471, Status::Valid),
+    (0x472, 0x472, Status::Mapped("\u{473}")),
+    (0x473, 0x473, Status::Valid),
+    (0x474, 0x474, Status::Mapped("\u{475}")),
+    (0x475, 0x475, Status::Valid),
+    (0x476, 0x476, Status::Mapped("\u{477}")),
+    (0x477, 0x477, Status::Valid),
+    (0x478, 0x478, Status::Mapped("\u{479}")),
+    (0x479, 0x479, Status::Valid),
+    (0x47A, 0x47A, Status::Mapped("\u{47B}")),
+    (0x47B, 0x47B, Status::Valid),
+    (0x47C, 0x47C, Status::Mapped("\u{47D}")),
+    (0x47D, 0x47D, Status::Valid),
+    (0x47E, 0x47E, Status::Mapped("\u{47F}")),
+    (0x47F, 0x47F, Status::Valid),
+    (0x480, 0x480, Status::Mapped("\u{481}")),
+    (0x481, 0x489, Status::Valid),
+    (0x48A, 0x48A, Status::Mapped("\u{48B}")),
+    (0x48B, 0x48B, Status::Valid),
+    (0x48C, 0x48C, Status::Mapped("\u{48D}")),
+    (0x48D, 0x48D, Status::Valid),
+    (0x48E, 0x48E, Status::Mapped("\u{48F}")),
+    (0x48F, 0x48F, Status::Valid),
+    (0x490, 0x490, Status::Mapped("\u{491}")),
+    (0x491, 0x491, Status::Valid),
+    (0x492, 0x492, Status::Mapped("\u{493}")),
+    (0x493, 0x493, Status::Valid),
+    (0x494, 0x494, Status::Mapped("\u{495}")),
+    (0x495, 0x495, Status::Valid),
+    (0x496, 0x496, Status::Mapped("\u{497}")),
+    (0x497, 0x497, Status::Valid),
+    (0x498, 0x498, Status::Mapped("\u{499}")),
+    (0x499, 0x499, Status::Valid),
+    (0x49A, 0x49A, Status::Mapped("\u{49B}")),
+    (0x49B, 0x49B, Status::Valid),
+    (0x49C, 0x49C, Status::Mapped("\u{49D}")),
+    (0x49D, 0x49D, Status::Valid),
+    (0x49E, 0x49E, Status::Mapped("\u{49F}")),
+    (0x49F, 0x49F, Status::Valid),
+    (0x4A0, 0x4A0, Status::Mapped("\u{4A1}")),
+    (0x4A1, 0x4A1, Status::Valid),
+    (0x4A2, 0x4A2, Status::Mapped("\u{4A3}")),
+    (0x4A3, 0x4A3, Status::Valid),
+    (0x4A4, 0x4A4, Status::Mapped("\u{4A5}")),
+    (0x4A5, 0x4A5, Status::Valid),
+    (0x4A6, 0x4A6, Status::Mapped("\u{4A7}")),
+    (0x4A7, 0x4A7, Status::Valid),
+    (0x4A8, 0x4A8, Status::Mapped("\u{4A9}")),
+    (0x4A9, 0x4A9, Status::Valid),
+    (0x4AA, 0x4AA, Status::Mapped("\u{4AB}")),
+    (0x4AB, 0x4AB, Status::Valid),
+    (0x4AC, 0x4AC, Status::Mapped("\u{4AD}")),
+    (0x4AD, 0x4AD, Status::Valid),
+    (0x4AE, 0x4AE, Status::Mapped("\u{4AF}")),
+    (0x4AF, 0x4AF, Status::Valid),
+    (0x4B0, 0x4B0, Status::Mapped("\u{4B1}")),
+    (0x4B1, 0x4B1, Status::Valid),
+    (0x4B2, 0x4B2, Status::Mapped("\u{4B3}")),
+    (0x4B3, 0x4B3, Status::Valid),
+    (0x4B4, 0x4B4, Status::Mapped("\u{4B5}")),
+    (0x4B5, 0x4B5, Status::Valid),
+    (0x4B6, 0x4B6, Status::Mapped("\u{4B7}")),
+    (0x4B7, 0x4B7, Status::Valid),
+    (0x4B8, 0x4B8, Status::Mapped("\u{4B9}")),
+    (0x4B9, 0x4B9, Status::Valid),
+    (0x4BA, 0x4BA, Status::Mapped("\u{4BB}")),
+    (0x4BB, 0x4BB, Status::Valid),
+    (0x4BC, 0x4BC, Status::Mapped("\u{4BD}")),
+    (0x4BD, 0x4BD, Status::Valid),
+    (0x4BE, 0x4BE, Status::Mapped("\u{4BF}")),
+    (0x4BF, 0x4BF, Status::Valid),
+    (0x4C0, 0x4C0, Status::Disallowed),
+    (0x4C1, 0x4C1, Status::Mapped("\u{4C2}")),
+    (0x4C2, 0x4C2, Status::Valid),
+    (0x4C3, 0x4C3, Status::Mapped("\u{4C4}")),
+    (0x4C4, 0x4C4, Status::Valid),
+    (0x4C5, 0x4C5, Status::Mapped("\u{4C6}")),
+    (0x4C6, 0x4C6, Status::Valid),
+    (0x4C7, 0x4C7, Status::Mapped("\u{4C8}")),
+    (0x4C8, 0x4C8, Status::Valid),
+    (0x4C9, 0x4C9, Status::Mapped("\u{4CA}")),
+    (0x4CA, 0x4CA, Status::Valid),
+    (0x4CB, 0x4CB, Status::Mapped("\u{4CC}")),
+    (0x4CC, 0x4CC, Status::Valid),
+    (0x4CD, 0x4CD, Status::Mapped("\u{4CE}")),
+    (0x4CE, 0x4CF, Status::Valid),
+    (0x4D0, 0x4D0, Status::Mapped("\u{4D1}")),
+    (0x4D1, 0x4D1, Status::Valid),
+    (0x4D2, 0x4D2, Status::Mapped("\u{4D3}")),
+    (0x4D3, 0x4D3, Status::Valid),
+    (0x4D4, 0x4D4, Status::Mapped("\u{4D5}")),
+    (0x4D5, 0x4D5, Status::Valid),
+    (0x4D6, 0x4D6, Status::Mapped("\u{4D7}")),
+    (0x4D7, 0x4D7, Status::Valid),
+    (0x4D8, 0x4D8, Status::Mapped("\u{4D9}")),
+    (0x4D9, 0x4D9, Status::Valid),
+    (0x4DA, 0x4DA, Status::Mapped("\u{4DB}")),
+    (0x4DB, 0x4DB, Status::Valid),
+    (0x4DC, 0x4DC, Status::Mapped("\u{4DD}")),
+    (0x4DD, 0x4DD, Status::Valid),
+    (0x4DE, 0x4DE, Status::Mapped("\u{4DF}")),
+    (0x4DF, 0x4DF, Status::Valid),
+    (0x4E0, 0x4E0, Status::Mapped("\u{4E1}")),
+    (0x4E1, 0x4E1, Status::Valid),
+    (0x4E2, 0x4E2, Status::Mapped("\u{4E3}")),
+    (0x4E3, 0x4E3, Status::Valid),
+    (0x4E4, 0x4E4, Status::Mapped("\u{4E5}")),
+    (0x4E5, 0x4E5, Status::Valid),
+    (0x4E6, 0x4E6, Status::Mapped("\u{4E7}")),
+    (0x4E7, 0x4E7, Status::Valid),
+    (0x4E8, 0x4E8, Status::Mapped("\u{4E9}")),
+    (0x4E9, 0x4E9, Status::Valid),
+    (0x4EA, 0x4EA, Status::Mapped("\u{4EB}")),
+    (0x4EB, 0x4EB, Status::Valid),
+    (0x4EC, 0x4EC, Status::Mapped("\u{4ED}")),
+    (0x4ED, 0x4ED, Status::Valid),
+    (0x4EE, 0x4EE, Status::Mapped("\u{4EF}")),
+    (0x4EF, 0x4EF, Status::Valid),
+    (0x4F0, 0x4F0, Status::Mapped("\u{4F1}")),
+    (0x4F1, 0x4F1, Status::Valid),
+    (0x4F2, 0x4F2, Status::Mapped("\u{4F3}")),
+    (0x4F3, 0x4F3, Status::Valid),
+    (0x4F4, 0x4F4, Status::Mapped("\u{4F5}")),
+    (0x4F5, 0x4F5, Status::Valid),
+    (0x4F6, 0x4F6, Status::Mapped("\u{4F7}")),
+    (0x4F7, 0x4F7, Status::Valid),
+    (0x4F8, 0x4F8, Status::Mapped("\u{4F9}")),
+    (0x4F9, 0x4F9, Status::Valid),
+    (0x4FA, 0x4FA, Status::Mapped("\u{4FB}")),
+    (0x4FB, 0x4FB, Status::Valid),
+    (0x4FC, 0x4FC, Status::Mapped("\u{4FD}")),
+    (0x4FD, 0x4FD, Status::Valid),
+    (0x4FE, 0x4FE, Status::Mapped("\u{4FF}")),
+    (0x4FF, 0x4FF, Status::Valid),
+    (0x500, 0x500, Status::Mapped("\u{501}")),
+    (0x501, 0x501, Status::Valid),
+    (0x502, 0x502, Status::Mapped("\u{503}")),
+    (0x503, 0x503, Status::Valid),
+    (0x504, 0x504, Status::Mapped("\u{505}")),
+    (0x505, 0x505, Status::Valid),
+    (0x506, 0x506, Status::Mapped("\u{507}")),
+    (0x507, 0x507, Status::Valid),
+    (0x508, 0x508, Status::Mapped("\u{509}")),
+    (0x509, 0x509, Status::Valid),
+    (0x50A, 0x50A, Status::Mapped("\u{50B}")),
+    (0x50B, 0x50B, Status::Valid),
+    (0x50C, 0x50C, Status::Mapped("\u{50D}")),
+    (0x50D, 0x50D, Status::Valid),
+    (0x50E, 0x50E, Status::Mapped("\u{50F}")),
+    (0x50F, 0x50F, Status::Valid),
+    (0x510, 0x510, Status::Mapped("\u{511}")),
+    (0x511, 0x511, Status::Valid),
+    (0x512, 0x512, Status::Mapped("\u{513}")),
+    (0x513, 0x513, Status::Valid),
+    (0x514, 0x514, Status::Mapped("\u{515}")),
+    (0x515, 0x515, Status::Valid),
+    (0x516, 0x516, Status::Mapped("\u{517}")),
+    (0x517, 0x517, Status::Valid),
+    (0x518, 0x518, Status::Mapped("\u{519}")),
+    (0x519, 0x519, Status::Valid),
+    (0x51A, 0x51A, Status::Mapped("\u{51B}")),
+    (0x51B, 0x51B, Status::Valid),
+    (0x51C, 0x51C, Status::Mapped("\u{51D}")),
+    (0x51D, 0x51D, Status::Valid),
+    (0x51E, 0x51E, Status::Mapped("\u{51F}")),
+    (0x51F, 0x51F, Status::Valid),
+    (0x520, 0x520, Status::Mapped("\u{521}")),
+    (0x521, 0x521, Status::Valid),
+    (0x522, 0x522, Status::Mapped("\u{523}")),
+    (0x523, 0x523, Status::Valid),
+    (0x524, 0x524, Status::Mapped("\u{525}")),
+    (0x525, 0x525, Status::Valid),
+    (0x526, 0x526, Status::Mapped("\u{527}")),
+    (0x527, 0x527, Status::Valid),
+    (0x528, 0x528, Status::Mapped("\u{529}")),
+    (0x529, 0x529, Status::Valid),
+    (0x52A, 0x52A, Status::Mapped("\u{52B}")),
+    (0x52B, 0x52B, Status::Valid),
+    (0x52C, 0x52C, Status::Mapped("\u{52D}")),
+    (0x52D, 0x52D, Status::Valid),
+    (0x52E, 0x52E, Status::Mapped("\u{52F}")),
+    (0x52F, 0x52F, Status::Valid),
+    (0x530, 0x530, Status::Disallowed),
+    (0x531, 0x531, Status::Mapped("\u{561}")),
+    (0x532, 0x532, Status::Mapped("\u{562}")),
+    (0x533, 0x533, Status::Mapped("\u{563}")),
+    (0x534, 0x534, Status::Mapped("\u{564}")),
+    (0x535, 0x535, Status::Mapped("\u{565}")),
+    (0x536, 0x536, Status::Mapped("\u{566}")),
+    (0x537, 0x537, Status::Mapped("\u{567}")),
+    (0x538, 0x538, Status::Mapped("\u{568}")),
+    (0x539, 0x539, Status::Mapped("\u{569}")),
+    (0x53A, 0x53A, Status::Mapped("\u{56A}")),
+    (0x53B, 0x53B, Status::Mapped("\u{56B}")),
+    (0x53C, 0x53C, Status::Mapped("\u{56C}")),
+    (0x53D, 0x53D, Status::Mapped("\u{56D}")),
+    (0x53E, 0x53E, Status::Mapped("\u{56E}")),
+    (0x53F, 0x53F, Status::Mapped("\u{56F}")),
+    (0x540, 0x540, Status::Mapped("\u{570}")),
+    (0x541, 0x541, Status::Mapped("\u{571}")),
+    (0x542, 0x542, Status::Mapped("\u{572}")),
+    (0x543, 0x543, Status::Mapped("\u{573}")),
+    (0x544, 0x544, Status::Mapped("\u{574}")),
+    (0x545, 0x545, Status::Mapped("\u{575}")),
+    (0x546, 0x546, Status::Mapped("\u{576}")),
+    (0x547, 0x547, Status::Mapped("\u{577}")),
+    (0x548, 0x548, Status::Mapped("\u{578}")),
+    (0x549, 0x549, Status::Mapped("\u{579}")),
+    (0x54A, 0x54A, Status::Mapped("\u{57A}")),
+    (0x54B, 0x54B, Status::Mapped("\u{57B}")),
+    (0x54C, 0x54C, Status::Mapped("\u{57C}")),
+    (0x54D, 0x54D, Status::Mapped("\u{57D}")),
+    (0x54E, 0x54E, Status::Mapped("\u{57E}")),
+    (0x54F, 0x54F, Status::Mapped("\u{57F}")),
+    (0x550, 0x550, Status::Mapped("\u{580}")),
+    (0x551, 0x551, Status::Mapped("\u{581}")),
+    (0x552, 0x552, Status::Mapped("\u{582}")),
+    (0x553, 0x553, Status::Mapped("\u{583}")),
+    (0x554, 0x554, Status::Mapped("\u{584}")),
+    (0x555, 0x555, Status::Mapped("\u{585}")),
+    (0x556, 0x556, Status::Mapped("\u{586}")),
+    (0x557, 0x558, Status::Disallowed),
+    (0x559, 0x586, Status::Valid),
+    (0x587, 0x587, Status::Mapped("\u{565}\u{582}")),
+    (0x588, 0x58A, Status::Valid),
+    (0x58B, 0x58C, Status::Disallowed),
+    (0x58D, 0x58F, Status::Valid),
+    (0x590, 0x590, Status::Disallowed),
+    (0x591, 0x5C7, Status::Valid),
+    (0x5C8, 0x5CF, Status::Disallowed),
+    (0x5D0, 0x5EA, Status::Valid),
+    (0x5EB, 0x5EE, Status::Disallowed),
+    (0x5EF, 0x5F4, Status::Valid),
+    (0x5F5, 0x605, Status::Disallowed),
+    (0x606, 0x61B, Status::Valid),
+    (0x61C, 0x61C, Status::Disallowed),
+    (0x61D, 0x674, Status::Valid),
+    (0x675, 0x675, Status::Mapped("\u{627}\u{674}")),
+    (0x676, 0x676, Status::Mapped("\u{648}\u{674}")),
+    (0x677, 0x677, Status::Mapped("\u{6C7}\u{674}")),
+    (0x678, 0x678, Status::Mapped("\u{64A}\u{674}")),
+    (0x679, 0x6DC, Status::Valid),
+    (0x6DD, 0x6DD, Status::Disallowed),
+    (0x6DE, 0x70D, Status::Valid),
+    (0x70E, 0x70F, Status::Disallowed),
+    (0x710, 0x74A, Status::Valid),
+    (0x74B, 0x74C, Status::Disallowed),
+    (0x74D, 0x7B1, Status::Valid),
+    (0x7B2, 0x7BF, Status::Disallowed),
+    (0x7C0, 0x7FA, Status::Valid),
+    (0x7FB, 0x7FC, Status::Disallowed),
+    (0x7FD, 0x82D, Status::Valid),
+    (0x82E, 0x82F, Status::Disallowed),
+    (0x830, 0x83E, Status::Valid),
+    (0x83F, 0x83F, Status::Disallowed),
+    (0x840, 0x85B, Status::Valid),
+    (0x85C, 0x85D, Status::Disallowed),
+    (0x85E, 0x85E, Status::Valid),
+    (0x85F, 0x85F, Status::Disallowed),
+    (0x860, 0x86A, Status::Valid),
+    (0x86B, 0x86F, Status::Disallowed),
+    (0x870, 0x88E, Status::Valid),
+    (0x88F, 0x897, Status::Disallowed),
+    (0x898, 0x8E1, Status::Valid),
+    (0x8E2, 0x8E2, Status::Disallowed),
+    (0x8E3, 0x957, Status::Valid),
+    (0x958, 0x958, Status::Mapped("\u{915}\u{93C}")),
+    (0x959, 0x959, Status::Mapped("\u{916}\u{93C}")),
+    (0x95A, 0x95A, Status::Mapped("\u{917}\u{93C}")),
+    (0x95B, 0x95B, Status::Mapped("\u{91C}\u{93C}")),
+    (0x95C, 0x95C, Status::Mapped("\u{921}\u{93C}")),
+    (0x95D, 0x95D, Status::Mapped("\u{922}\u{93C}")),
+    (0x95E, 0x95E, Status::Mapped("\u{92B}\u{93C}")),
+    (0x95F, 0x95F, Status::Mapped("\u{92F}\u{93C}")),
+    (0x960, 0x983, Status::Valid),
+    (0x984, 0x984, Status::Disallowed),
+    (0x985, 0x98C, Status::Valid),
+    (0x98D, 0x98E, Status::Disallowed),
+    (0x98F, 0x990, Status::Valid),
+    (0x991, 0x992, Status::Disallowed),
+    (0x993, 0x9A8, Status::Valid),
+    (0x9A9, 0x9A9, Status::Disallowed),
+    (0x9AA, 0x9B0, Status::Valid),
+    (0x9B1, 0x9B1, Status::Disallowed),
+    (0x9B2, 0x9B2, Status::Valid),
+    (0x9B3, 0x9B5, Status::Disallowed),
+    (0x9B6, 0x9B9, Status::Valid),
+    (0x9BA, 0x9BB, Status::Disallowed),
+    (0x9BC, 0x9C4, Status::Valid),
+    (0x9C5, 0x9C6, Status::Disallowed),
+    (0x9C7, 0x9C8, Status::Valid),
+    (0x9C9, 0x9CA, Status::Disallowed),
+    (0x9CB, 0x9CE, Status::Valid),
+    (0x9CF, 0x9D6, Status::Disallowed),
+    (0x9D7, 0x9D7, Status::Valid),
+    (0x9D8, 0x9DB, Status::Disallowed),
+    (0x9DC, 0x9DC, Status::Mapped("\u{9A1}\u{9BC}")),
+    (0x9DD, 0x9DD, Status::Mapped("\u{9A2}\u{9BC}")),
+    (0x9DE, 0x9DE, Status::Disallowed),
+    (0x9DF, 0x9DF, Status::Mapped("\u{9AF}\u{9BC}")),
+    (0x9E0, 0x9E3, Status::Valid),
+    (0x9E4, 0x9E5, Status::Disallowed),
+    (0x9E6, 0x9FE, Status::Valid),
+    (0x9FF, 0xA00, Status::Disallowed),
+    (0xA01, 0xA03, Status::Valid),
+    (0xA04, 0xA04, Status::Disallowed),
+    (0xA05, 0xA0A, Status::Valid),
+    (0xA0B, 0xA0E, Status::Disallowed),
+    (0xA0F, 0xA10, Status::Valid),
+    (0xA11, 0xA12, Status::Disallowed),
+    (0xA13, 0xA28, Status::Valid),
+    (0xA29, 0xA29, Status::Disallowed),
+    (0xA2A, 0xA30, Status::Valid),
+    (0xA31, 0xA31, Status::Disallowed),
+    (0xA32, 0xA32, Status::Valid),
+    (0xA33, 0xA33, Status::Mapped("\u{A32}\u{A3C}")),
+    (0xA34, 0xA34, Status::Disallowed),
+    (0xA35, 0xA35, Status::Valid),
+    (0xA36, 0xA36, Status::Mapped("\u{A38}\u{A3C}")),
+    (0xA37, 0xA37, Status::Disallowed),
+    (0xA38, 0xA39, Status::Valid),
+    (0xA3A, 0xA3B, Status::Disallowed),
+    (0xA3C, 0xA3C, Status::Valid),
+    (0xA3D, 0xA3D, Status::Disallowed),
+    (0xA3E, 0xA42, Status::Valid),
+    (0xA43, 0xA46, Status::Disallowed),
+    (0xA47, 0xA48, Status::Valid),
+    (0xA49, 0xA4A, Status::Disallowed),
+    (0xA4B, 0xA4D, Status::Valid),
+    (0xA4E, 0xA50, Status::Disallowed),
+    (0xA51, 0xA51, Status::Valid),
+    (0xA52, 0xA58, Status::Disallowed),
+    (0xA59, 0xA59, Status::Mapped("\u{A16}\u{A3C}")),
+    (0xA5A, 0xA5A, Status::Mapped("\u{A17}\u{A3C}")),
+    (0xA5B, 0xA5B, Status::Mapped("\u{A1C}\u{A3C}")),
+    (0xA5C, 0xA5C, Status::Valid),
+    (0xA5D, 0xA5D, Status::Disallowed),
+    (0xA5E, 0xA5E, Status::Mapped("\u{A2B}\u{A3C}")),
+    (0xA5F, 0xA65, Status::Disallowed),
+    (0xA66, 0xA76, Status::Valid),
+    (0xA77, 0xA80, Status::Disallowed),
+    (0xA81, 0xA83, Status::Valid),
+    (0xA84, 0xA84, Status::Disallowed),
+    (0xA85, 0xA8D, Status::Valid),
+    (0xA8E, 0xA8E, Status::Disallowed),
+    (0xA8F, 0xA91, Status::Valid),
+    (0xA92, 0xA92, Status::Disallowed),
+    (0xA93, 0xAA8, Status::Valid),
+    (0xAA9, 0xAA9, Status::Disallowed),
+    (0xAAA, 0xAB0, Status::Valid),
+    (0xAB1, 0xAB1, Status::Disallowed),
+    (0xAB2, 0xAB3, Status::Valid),
+    (0xAB4, 0xAB4, Status::Disallowed),
+    (0xAB5, 0xAB9, Status::Valid),
+    (0xABA, 0xABB, Status::Disallowed),
+    (0xABC, 0xAC5, Status::Valid),
+    (0xAC6, 0xAC6, Status::Disallowed),
+    (0xAC7, 0xAC9, Status::Valid),
+    (0xACA, 0xACA, Status::Disallowed),
+    (0xACB, 0xACD, Status::Valid),
+    (0xACE, 0xACF, Status::Disallowed),
+    (0xAD0, 0xAD0, Status::Valid),
+    (0xAD1, 0xADF, Status::Disallowed),
+    (0xAE0, 0xAE3, Status::Valid),
+    (0xAE4, 0xAE5, Status::Disallowed),
+    (0xAE6, 0xAF1, Status::Valid),
+    (0xAF2, 0xAF8, Status::Disallowed),
+    (0xAF9, 0xAFF, Status::Valid),
+    (0xB00, 0xB00, Status::Disallowed),
+    (0xB01, 0xB03, Status::Valid),
+    (0xB04, 0xB04, Status::Disallowed),
+    (0xB05, 0xB0C, Status::Valid),
+    (0xB0D, 0xB0E, Status::Disallowed),
+    (0xB0F, 0xB10, Status::Valid),
+    (0xB11, 0xB12, Status::Disallowed),
+    (0xB13, 0xB28, Status::Valid),
+    (0xB29, 0xB29, Status::Disallowed),
+    (0xB2A, 0xB30, Status::Valid),
+    (0xB31, 0xB31, Status::Disallowed),
+    (0xB32, 0xB33, Status::Valid),
+    (0xB34, 0xB34, Status::Disallowed),
+    (0xB35, 0xB39, Status::Valid),
+    (0xB3A, 0xB3B, Status::Disallowed),
+    (0xB3C, 0xB44, Status::Valid),
+    (0xB45, 0xB46, Status::Disallowed),
+    (0xB47, 0xB48, Status::Valid),
+    (0xB49, 0xB4A, Status::Disallowed),
+    (0xB4B, 0xB4D, Status::Valid),
+    (0xB4E, 0xB54, Status::Disallowed),
+    (0xB55, 0xB57, Status::Valid),
+    (0xB58, 0xB5B, Status::Disallowed),
+    (0xB5C, 0xB5C, Status::Mapped("\u{B21}\u{B3C}")),
+    (0xB5D, 0xB5D, Status::Mapped("\u{B22}\u{B3C}")),
+    (0xB5E, 0xB5E, Status::Disallowed),
+    (0xB5F, 0xB63, Status::Valid),
+    (0xB64, 0xB65, Status::Disallowed),
+    (0xB66, 0xB77, Status::Valid),
+    (0xB78, 0xB81, Status::Disallowed),
+    (0xB82, 0xB83, Status::Valid),
+    (0xB84, 0xB84, Status::Disallowed),
+    (0xB85, 0xB8A, Status::Valid),
+    (0xB8B, 0xB8D, Status::Disallowed),
+    (0xB8E, 0xB90, Status::Valid),
+    (0xB91, 0xB91, Status::Disallowed),
+    (0xB92, 0xB95, Status::Valid),
+    (0xB96, 0xB98, Status::Disallowed),
+    (0xB99, 0xB9A, Status::Valid),
+    (0xB9B, 0xB9B, Status::Disallowed),
+    (0xB9C, 0xB9C, Status::Valid),
+    (0xB9D, 0xB9D, Status::Disallowed),
+    (0xB9E, 0xB9F, Status::Valid),
+    (0xBA0, 0xBA2, Status::Disallowed),
+    (0xBA3, 0xBA4, Status::Valid),
+    (0xBA5, 0xBA7, Status::Disallowed),
+    (0xBA8, 0xBAA, Status::Valid),
+    (0xBAB, 0xBAD, Status::Disallowed),
+    (0xBAE, 0xBB9, Status::Valid),
+    (0xBBA, 0xBBD, Status::Disallowed),
+    (0xBBE, 0xBC2, Status::Valid),
+    (0xBC3, 0xBC5, Status::Disallowed),
+    (0xBC6, 0xBC8, Status::Valid),
+    (0xBC9, 0xBC9, Status::Disallowed),
+    (0xBCA, 0xBCD, Status::Valid),
+    (0xBCE, 0xBCF, Status::Disallowed),
+    (0xBD0, 0xBD0, Status::Valid),
+    (0xBD1, 0xBD6, Status::Disallowed),
+    (0xBD7, 0xBD7, Status::Valid),
+    (0xBD8, 0xBE5, Status::Disallowed),
+    (0xBE6, 0xBFA, Status::Valid),
+    (0xBFB, 0xBFF, Status::Disallowed),
+    (0xC00, 0xC0C, Status::Valid),
+    (0xC0D, 0xC0D, Status::Disallowed),
+    (0xC0E, 0xC10, Status::Valid),
+    (0xC11, 0xC11, Status::Disallowed),
+    (0xC12, 0xC28, Status::Valid),
+    (0xC29, 0xC29, Status::Disallowed),
+    (0xC2A, 0xC39, Status::Valid),
+    (0xC3A, 0xC3B, Status::Disallowed),
+    (0xC3C, 0xC44, Status::Valid),
+    (0xC45, 0xC45, Status::Disallowed),
+    (0xC46, 0xC48, Status::Valid),
+    (0xC49, 0xC49, Status::Disallowed),
+    (0xC4A, 0xC4D, Status::Valid),
+    (0xC4E, 0xC54, Status::Disallowed),
+    (0xC55, 0xC56, Status::Valid),
+    (0xC57, 0xC57, Status::Disallowed),
+    (0xC58, 0xC5A, Status::Valid),
+    (0xC5B, 0xC5C, Status::Disallowed),
+    (0xC5D, 0xC5D, Status::Valid),
+    (0xC5E, 0xC5F, Status::Disallowed),
+    (0xC60, 0xC63, Status::Valid),
+    (0xC64, 0xC65, Status::Disallowed),
+    (0xC66, 0xC6F, Status::Valid),
+    (0xC70, 0xC76, Status::Disallowed),
+    (0xC77, 0xC8C, Status::Valid),
+    (0xC8D, 0xC8D, Status::Disallowed),
+    (0xC8E, 0xC90, Status::Valid),
+    (0xC91, 0xC91, Status::Disallowed),
+    (0xC92, 0xCA8, Status::Valid),
+    (0xCA9, 0xCA9, Status::Disallowed),
+    (0xCAA, 0xCB3, Status::Valid),
+    (0xCB4, 0xCB4, Status::Disallowed),
+    (0xCB5, 0xCB9, Status::Valid),
+    (0xCBA, 0xCBB, Status::Disallowed),
+    (0xCBC, 0xCC4, Status::Valid),
+    (0xCC5, 0xCC5, Status::Disallowed),
+    (0xCC6, 0xCC8, Status::Valid),
+    (0xCC9, 0xCC9, Status::Disallowed),
+    (0xCCA, 0xCCD, Status::Valid),
+    (0xCCE, 0xCD4, Status::Disallowed),
+    (0xCD5, 0xCD6, Status::Valid),
+    (0xCD7, 0xCDC, Status::Disallowed),
+    (0xCDD, 0xCDE, Status::Valid),
+    (0xCDF, 0xCDF, Status::Disallowed),
+    (0xCE0, 0xCE3, Status::Valid),
+    (0xCE4, 0xCE5, Status::Disallowed),
+    (0xCE6, 0xCEF, Status::Valid),
+    (0xCF0, 0xCF0, Status::Disallowed),
+    (0xCF1, 0xCF3, Status::Valid),
+    (0xCF4, 0xCFF, Status::Disallowed),
+    (0xD00, 0xD0C, Status::Valid),
+    (0xD0D, 0xD0D, Status::Disallowed),
+    (0xD0E, 0xD10, Status::Valid),
+    (0xD11, 0xD11, Status::Disallowed),
+    (0xD12, 0xD44, Status::Valid),
+    (0xD45, 0xD45, Status::Disallowed),
+    (0xD46, 0xD48, Status::Valid),
+    (0xD49, 0xD49, Status::Disallowed),
+    (0xD4A, 0xD4F, Status::Valid),
+    (0xD50, 0xD53, Status::Disallowed),
+    (0xD54, 0xD63, Status::Valid),
+    (0xD64, 0xD65, Status::Disallowed),
+    (0xD66, 0xD7F, Status::Valid),
+    (0xD80, 0xD80, Status::Disallowed),
+    (0xD81, 0xD83, Status::Valid),
+    (0xD84, 0xD84, Status::Disallowed),
+    (0xD85, 0xD96, Status::Valid),
+    (0xD97, 0xD99, Status::Disallowed),
+    (0xD9A, 0xDB1, Status::Valid),
+    (0xDB2, 0xDB2, Status::Disallowed),
+    (0xDB3, 0xDBB, Status::Valid),
+    (0xDBC, 0xDBC, Status::Disallowed),
+    (0xDBD, 0xDBD, Status::Valid),
+    (0xDBE, 0xDBF, Status::Disallowed),
+    (0xDC0, 0xDC6, Status::Valid),
+    (0xDC7, 0xDC9, Status::Disallowed),
+    (0xDCA, 0xDCA, Status::Valid),
+    (0xDCB, 0xDCE, Status::Disallowed),
+    (0xDCF, 0xDD4, Status::Valid),
+    (0xDD5, 0xDD5, Status::Disallowed),
+    (0xDD6, 0xDD6, Status::Valid),
+    (0xDD7, 0xDD7, Status::Disallowed),
+    (0xDD8, 0xDDF, Status::Valid),
+    (0xDE0, 0xDE5, Status::Disallowed),
+    (0xDE6, 0xDEF, Status::Valid),
+    (0xDF0, 0xDF1, Status::Disallowed),
+    (0xDF2, 0xDF4, Status::Valid),
+    (0xDF5, 0xE00, Status::Disallowed),
+    (0xE01, 0xE32, Status::Valid),
+    (0xE33, 0xE33, Status::Mapped("\u{E4D}\u{E32}")),
+    (0xE34, 0xE3A, Status::Valid),
+    (0xE3B, 0xE3E, Status::Disallowed),
+    (0xE3F, 0xE5B, Status::Valid),
+    (0xE5C, 0xE80, Status::Disallowed),
+    (0xE81, 0xE82, Status::Valid),
+    (0xE83, 0xE83, Status::Disallowed),
+    (0xE84, 0xE84, Status::Valid),
+    (0xE85, 0xE85, Status::Disallowed),
+    (0xE86, 0xE8A, Status::Valid),
+    (0xE8B, 0xE8B, Status::Disallowed),
+    (0xE8C, 0xEA3, Status::Valid),
+    (0xEA4, 0xEA4, Status::Disallowed),
+    (0xEA5, 0xEA5, Status::Valid),
+    (0xEA6, 0xEA6, Status::Disallowed),
+    (0xEA7, 0xEB2, Status::Valid),
+    (0xEB3, 0xEB3, Status::Mapped("\u{ECD}\u{EB2}")),
+    (0xEB4, 0xEBD, Status::Valid),
+    (0xEBE, 0xEBF, Status::Disallowed),
+    (0xEC0, 0xEC4, Status::Valid),
+    (0xEC5, 0xEC5, Status::Disallowed),
+    (0xEC6, 0xEC6, Status::Valid),
+    (0xEC7, 0xEC7, Status::Disallowed),
+    (0xEC8, 0xECE, Status::Valid),
+    (0xECF, 0xECF, Status::Disallowed),
+    (0xED0, 0xED9, Status::Valid),
+    (0xEDA, 0xEDB, Status::Disallowed),
+    (0xEDC, 0xEDC, Status::Mapped("\u{EAB}\u{E99}")),
+    (0xEDD, 0xEDD, Status::Mapped("\u{EAB}\u{EA1}")),
+    (0xEDE, 0xEDF, Status::Valid),
+    (0xEE0, 0xEFF, Status::Disallowed),
+    (0xF00, 0xF0B, Status::Valid),
+    (0xF0C, 0xF0C, Status::Mapped("\u{F0B}")),
+    (0xF0D, 0xF42, Status::Valid),
+    (0xF43, 0xF43, Status::Mapped("\u{F42}\u{FB7}")),
+    (0xF44, 0xF47, Status::Valid),
+    (0xF48, 0xF48, Status::Disallowed),
+    (0xF49, 0xF4C, Status::Valid),
+    (0xF4D, 0xF4D, Status::Mapped("\u{F4C}\u{FB7}")),
+    (0xF4E, 0xF51, Status::Valid),
+    (0xF52, 0xF52, Status::Mapped("\u{F51}\u{FB7}")),
+    (0xF53, 0xF56, Status::Valid),
+    (0xF57, 0xF57, Status::Mapped("\u{F56}\u{FB7}")),
+    (0xF58, 0xF5B, Status::Valid),
+    (0xF5C, 0xF5C, Status::Mapped("\u{F5B}\u{FB7}")),
+    (0xF5D, 0xF68, Status::Valid),
+    (0xF69, 0xF69, Status::Mapped("\u{F40}\u{FB5}")),
+    (0xF6A, 0xF6C, Status::Valid),
+    (0xF6D, 0xF70, Status::Disallowed),
+    (0xF71, 0xF72, Status::Valid),
+    (0xF73, 0xF73, Status::Mapped("\u{F71}\u{F72}")),
+    (0xF74, 0xF74, Status::Valid),
+    (0xF75, 0xF75, Status::Mapped("\u{F71}\u{F74}")),
+    (0xF76, 0xF76, Status::Mapped("\u{FB2}\u{F80}")),
+    (0xF77, 0xF77, Status::Mapped("\u{FB2}\u{F71}\u{F80}")),
+    (0xF78, 0xF78, Status::Mapped("\u{FB3}\u{F80}")),
+    (0xF79, 0xF79, Status::Mapped("\u{FB3}\u{F71}\u{F80}")),
+    (0xF7A, 0xF80, Status::Valid),
+    (0xF81, 0xF81, Status::Mapped("\u{F71}\u{F80}")),
+    (0xF82, 0xF92, Status::Valid),
+    (0xF93, 0xF93, Status::Mapped("\u{F92}\u{FB7}")),
+    (0xF94, 0xF97, Status::Valid),
+    (0xF98, 0xF98, Status::Disallowed),
+    (0xF99, 0xF9C, Status::Valid),
+    (0xF9D, 0xF9D, Status::Mapped("\u{F9C}\u{FB7}")),
+    (0xF9E, 0xFA1, Status::Valid),
+    (0xFA2, 0xFA2, Status::Mapped("\u{FA1}\u{FB7}")),
+    (0xFA3, 0xFA6, Status::Valid),
+    (0xFA7, 0xFA7, Status::Mapped("\u{FA6}\u{FB7}")),
+    (0xFA8, 0xFAB, Status::Valid),
+    (0xFAC, 0xFAC, Status::Mapped("\u{FAB}\u{FB7}")),
+    (0xFAD, 0xFB8, Status::Valid),
+    (0xFB9, 0xFB9, Status::Mapped("\u{F90}\u{FB5}")),
+    (0xFBA, 0xFBC, Status::Valid),
+    (0xFBD, 0xFBD, Status::Disallowed),
+    (0xFBE, 0xFCC, Status::Valid),
+    (0xFCD, 0xFCD, Status::Disallowed),
+    (0xFCE, 0xFDA, Status::Valid),
+    (0xFDB, 0xFFF, Status::Disallowed),
+    (0x1000, 0x109F, Status::Valid),
+    (0x10A0, 0x10C6, Status::Disallowed),
+    (0x10C7, 0x10C7, Status::Mapped("\u{2D27}")),
+    (0x10C8, 0x10CC, Status::Disallowed),
+    (0x10CD, 0x10CD, Status::Mapped("\u{2D2D}")),
+    (0x10CE, 0x10CF, Status::Disallowed),
+    (0x10D0, 0x10FB, Status::Valid),
+    (0x10FC, 0x10FC, Status::Mapped("\u{10DC}")),
+    (0x10FD, 0x115E, Status::Valid),
+    (0x115F, 0x1160, Status::Disallowed),
+    (0x1161, 0x1248, Status::Valid),
+    (0x1249, 0x1249, Status::Disallowed),
+    (0x124A, 0x124D, Status::Valid),
+    (0x124E, 0x124F, Status::Disallowed),
+    (0x1250, 0x1256, Status::Valid),
+    (0x1257, 0x1257, Status::Disallowed),
+    (0x1258, 0x1258, Status::Valid),
+    (0x1259, 0x1259, Status::Disallowed),
+    (0x125A, 0x125D, Status::Valid),
+    (0x125E, 0x125F, Status::Disallowed),
+    (0x1260, 0x1288, Status::Valid),
+    (0x1289, 0x1289, Status::Disallowed),
+    (0x128A, 0x128D, Status::Valid),
+    (0x128E, 0x128F, Status::Disallowed),
+    (0x1290, 0x12B0, Status::Valid),
+    (0x12B1, 0x12B1, Status::Disallowed),
+    (0x12B2, 0x12B5, Status::Valid),
+    (0x12B6, 0x12B7, Status::Disallowed),
+    (0x12B8, 0x12BE, Status::Valid),
+    (0x12BF, 0x12BF, Status::Disallowed),
+    (0x12C0, 0x12C0, Status::Valid),
+    (0x12C1, 0x12C1, Status::Disallowed),
+    (0x12C2, 0x12C5, Status::Valid),
+    (0x12C6, 0x12C7, Status::Disallowed),
+    (0x12C8, 0x12D6, Status::Valid),
+    (0x12D7, 0x12D7, Status::Disallowed),
+    (0x12D8, 0x1310, Status::Valid),
+    (0x1311, 0x1311, Status::Disallowed),
+    (0x1312, 0x1315, Status::Valid),
+    (0x1316, 0x1317, Status::Disallowed),
+    (0x1318, 0x135A, Status::Valid),
+    (0x135B, 0x135C, Status::Disallowed),
+    (0x135D, 0x137C, Status::Valid),
+    (0x137D, 0x137F, Status::Disallowed),
+    (0x1380, 0x1399, Status::Valid),
+    (0x139A, 0x139F, Status::Disallowed),
+    (0x13A0, 0x13F5, Status::Valid),
+    (0x13F6, 0x13F7, Status::Disallowed),
+    (0x13F8, 0x13F8, Status::Mapped("\u{13F0}")),
+    (0x13F9, 0x13F9, Status::Mapped("\u{13F1}")),
+    (0x13FA, 0x13FA, Status::Mapped("\u{13F2}")),
+    (0x13FB, 0x13FB, Status::Mapped("\u{13F3}")),
+    (0x13FC, 0x13FC, Status::Mapped("\u{13F4}")),
+    (0x13FD, 0x13FD, Status::Mapped("\u{13F5}")),
+    (0x13FE, 0x13FF, Status::Disallowed),
+    (0x1400, 0x167F, Status::Valid),
+    (0x1680, 0x1680, Status::Disallowed),
+    (0x1681, 0x169C, Status::Valid),
+    (0x169D, 0x169F, Status::Disallowed),
+    (0x16A0, 0x16F8, Status::Valid),
+    (0x16F9, 0x16FF, Status::Disallowed),
+    (0x1700, 0x1715, Status::Valid),
+    (0x1716, 0x171E, Status::Disallowed),
+    (0x171F, 0x1736, Status::Valid),
+    (0x1737, 0x173F, Status::Disallowed),
+    (0x1740, 0x1753, Status::Valid),
+    (0x1754, 0x175F, Status::Disallowed),
+    (0x1760, 0x176C, Status::Valid),
+    (0x176D, 0x176D, Status::Disallowed),
+    (0x176E, 0x1770, Status::Valid),
+    (0x1771, 0x1771, Status::Disallowed),
+    (0x1772, 0x1773, Status::Valid),
+    (0x1774, 0x177F, Status::Disallowed),
+    (0x1780, 0x17B3, Status::Valid),
+    (0x17B4, 0x17B5, Status::Disallowed),
+    (0x17B6, 0x17DD, Status::Valid),
+    (0x17DE, 0x17DF, Status::Disallowed),
+    (0x17E0, 0x17E9, Status::Valid),
+    (0x17EA, 0x17EF, Status::Disallowed),
+    (0x17F0, 0x17F9, Status::Valid),
+    (0x17FA, 0x17FF, Status::Disallowed),
+    (0x1800, 0x1805, Status::Valid),
+    (0x1806, 0x1806, Status::Disallowed),
+    (0x1807, 0x180A, Status::Valid),
+    (0x180B, 0x180D, Status::Ignored),
+    (0x180E, 0x180E, Status::Disallowed),
+    (0x180F, 0x180F, Status::Ignored),
+    (0x1810, 0x1819, Status::Valid),
+    (0x181A, 0x181F, Status::Disallowed),
+    (0x1820, 0x1878, Status::Valid),
+    (0x1879, 0x187F, Status::Disallowed),
+    (0x1880, 0x18AA, Status::Valid),
+    (0x18AB, 0x18AF, Status::Disallowed),
+    (0x18B0, 0x18F5, Status::Valid),
+    (0x18F6, 0x18FF, Status::Disallowed),
+    (0x1900, 0x191E, Status::Valid),
+    (0x191F, 0x191F, Status::Disallowed),
+    (0x1920, 0x192B, Status::Valid),
+    (0x192C, 0x192F, Status::Disallowed),
+    (0x1930, 0x193B, Status::Valid),
+    (0x193C, 0x193F, Status::Disallowed),
+    (0x1940, 0x1940, Status::Valid),
+    (0x1941, 0x1943, Status::Disallowed),
+    (0x1944, 0x196D, Status::Valid),
+    (0x196E, 0x196F, Status::Disallowed),
+    (0x1970, 0x1974, Status::Valid),
+    (0x1975, 0x197F, Status::Disallowed),
+    (0x1980, 0x19AB, Status::Valid),
+    (0x19AC, 0x19AF, Status::Disallowed),
+    (0x19B0, 0x19C9, Status::Valid),
+    (0x19CA, 0x19CF, Status::Disallowed),
+    (0x19D0, 0x19DA, Status::Valid),
+    (0x19DB, 0x19DD, Status::Disallowed),
+    (0x19DE, 0x1A1B, Status::Valid),
+    (0x1A1C, 0x1A1D, Status::Disallowed),
+    (0x1A1E, 0x1A5E, Status::Valid),
+    (0x1A5F, 0x1A5F, Status::Disallowed),
+    (0x1A60, 0x1A7C, Status::Valid),
+    (0x1A7D, 0x1A7E, Status::Disallowed),
+    (0x1A7F, 0x1A89, Status::Valid),
+    (0x1A8A, 0x1A8F, Status::Disallowed),
+    (0x1A90, 0x1A99, Status::Valid),
+    (0x1A9A, 0x1A9F, Status::Disallowed),
+    (0x1AA0, 0x1AAD, Status::Valid),
+    (0x1AAE, 0x1AAF, Status::Disallowed),
+    (0x1AB0, 0x1ACE, Status::Valid),
+    (0x1ACF, 0x1AFF, Status::Disallowed),
+    (0x1B00, 0x1B4C, Status::Valid),
+    (0x1B4D, 0x1B4F, Status::Disallowed),
+    (0x1B50, 0x1B7E, Status::Valid),
+    (0x1B7F, 0x1B7F, Status::Disallowed),
+    (0x1B80, 0x1BF3, Status::Valid),
+    (0x1BF4, 0x1BFB, Status::Disallowed),
+    (0x1BFC, 0x1C37, Status::Valid),
+    (0x1C38, 0x1C3A, Status::Disallowed),
+    (0x1C3B, 0x1C49, Status::Valid),
+    (0x1C4A, 0x1C4C, Status::Disallowed),
+    (0x1C4D, 0x1C7F, Status::Valid),
+    (0x1C80, 0x1C80, Status::Mapped("\u{432}")),
+    (0x1C81, 0x1C81, Status::Mapped("\u{434}")),
+    (0x1C82, 0x1C82, Status::Mapped("\u{43E}")),
+    (0x1C83, 0x1C83, Status::Mapped("\u{441}")),
+    (0x1C84, 0x1C85, Status::Mapped("\u{442}")),
+    (0x1C86, 0x1C86, Status::Mapped("\u{44A}")),
+    (0x1C87, 0x1C87, Status::Mapped("\u{463}")),
+    (0x1C88, 0x1C88, Status::Mapped("\u{A64B}")),
+    (0x1C89, 0x1C8F, Status::Disallowed),
+    (0x1C90, 0x1C90, Status::Mapped("\u{10D0}")),
+    (0x1C91, 0x1C91, Status::Mapped("\u{10D1}")),
+    (0x1C92, 0x1C92, Status::Mapped("\u{10D2}")),
+    (0x1C93, 0x1C93, Status::Mapped("\u{10D3}")),
+    (0x1C94, 0x1C94, Status::Mapped("\u{10D4}")),
+    (0x1C95, 0x1C95, Status::Mapped("\u{10D5}")),
+    (0x1C96, 0x1C96, Status::Mapped("\u{10D6}")),
+    (0x1C97, 0x1C97, Status::Mapped("\u{10D7}")),
+    (0x1C98, 0x1C98, Status::Mapped("\u{10D8}")),
+    (0x1C99, 0x1C99, Status::Mapped("\u{10D9}")),
+    (0x1C9A, 0x1C9A, Status::Mapped("\u{10DA}")),
+    (0x1C9B, 0x1C9B, Status::Mapped("\u{10DB}")),
+    (0x1C9C, 0x1C9C, Status::Mapped("\u{10DC}")),
+    (0x1C9D, 0x1C9D, Status::Mapped("\u{10DD}")),
+    (0x1C9E, 0x1C9E, Status::Mapped("\u{10DE}")),
+    (0x1C9F, 0x1C9F, Status::Mapped("\u{10DF}")),
+    (0x1CA0, 0x1CA0, Status::Mapped("\u{10E0}")),
+    (0x1CA1, 0x1CA1, Status::Mapped("\u{10E1}")),
+    (0x1CA2, 0x1CA2, Status::Mapped("\u{10E2}")),
+    (0x1CA3, 0x1CA3, Status::Mapped("\u{10E3}")),
+    (0x1CA4, 0x1CA4, Status::Mapped("\u{10E4}")),
+    (0x1CA5, 0x1CA5, Status::Mapped("\u{10E5}")),
+    (0x1CA6, 0x1CA6, Status::Mapped("\u{10E6}")),
+    (0x1CA7, 0x1CA7, Status::Mapped("\u{10E7}")),
+    (0x1CA8, 0x1CA8, Status::Mapped("\u{10E8}")),
+    (0x1CA9, 0x1CA9, Status::Mapped("\u{10E9}")),
+    (0x1CAA, 0x1CAA, Status::Mapped("\u{10EA}")),
+    (0x1CAB, 0x1CAB, Status::Mapped("\u{10EB}")),
+    (0x1CAC, 0x1CAC, Status::Mapped("\u{10EC}")),
+    (0x1CAD, 0x1CAD, Status::Mapped("\u{10ED}")),
+    (0x1CAE, 0x1CAE, Status::Mapped("\u{10EE}")),
+    (0x1CAF, 0x1CAF, Status::Mapped("\u{10EF}")),
+    (0x1CB0, 0x1CB0, Status::Mapped("\u{10F0}")),
+    (0x1CB1, 0x1CB1, Status::Mapped("\u{10F1}")),
+    (0x1CB2, 0x1CB2, Status::Mapped("\u{10F2}")),
+    (0x1CB3, 0x1CB3, Status::Mapped("\u{10F3}")),
+    (0x1CB4, 0x1CB4, Status::Mapped("\u{10F4}")),
+    (0x1CB5, 0x1CB5, Status::Mapped("\u{10F5}")),
+    (0x1CB6, 0x1CB6, Status::Mapped("\u{10F6}")),
+    (0x1CB7, 0x1CB7, Status::Mapped("\u{10F7}")),
+    (0x1CB8, 0x1CB8, Status::Mapped("\u{10F8}")),
+    (0x1CB9, 0x1CB9, Status::Mapped("\u{10F9}")),
+    (0x1CBA, 0x1CBA, Status::Mapped("\u{10FA}")),
+    (0x1CBB, 0x1CBC, Status::Disallowed),
+    (0x1CBD, 0x1CBD, Status::Mapped("\u{10FD}")),
+    (0x1CBE, 0x1CBE, Status::Mapped("\u{10FE}")),
+    (0x1CBF, 0x1CBF, Status::Mapped("\u{10FF}")),
+    (0x1CC0, 0x1CC7, Status::Valid),
+    (0x1CC8, 0x1CCF, Status::Disallowed),
+    (0x1CD0, 0x1CFA, Status::Valid),
+    (0x1CFB, 0x1CFF, Status::Disallowed),
+    (0x1D00, 0x1D2B, Status::Valid),
+    (0x1D2C, 0x1D2C, Status::Mapped("a")),
+    (0x1D2D, 0x1D2D, Status::Mapped("\u{E6}")),
+    (0x1D2E, 0x1D2E, Status::Mapped("b")),
+    (0x1D2F, 0x1D2F, Status::Valid),
+    (0x1D30, 0x1D30, Status::Mapped("d")),
+    (0x1D31, 0x1D31, Status::Mapped("e")),
+    (0x1D32, 0x1D32, Status::Mapped("\u{1DD}")),
+    (0x1D33, 0x1D33, Status::Mapped("g")),
+    (0x1D34, 0x1D34, Status::Mapped("h")),
+    (0x1D35, 0x1D35, Status::Mapped("i")),
+    (0x1D36, 0x1D36, Status::Mapped("j")),
+    (0x1D37, 0x1D37, Status::Mapped("k")),
+    (0x1D38, 0x1D38, Status::Mapped("l")),
+    (0x1D39, 0x1D39, Status::Mapped("m")),
+    (0x1D3A, 0x1D3A, Status::Mapped("n")),
+    (0x1D3B, 0x1D3B, Status::Valid),
+    (0x1D3C, 0x1D3C, Status::Mapped("o")),
+    (0x1D3D, 0x1D3D, Status::Mapped("\u{223}")),
+    (0x1D3E, 0x1D3E, Status::Mapped("p")),
+    (0x1D3F, 0x1D3F, Status::Mapped("r")),
+    (0x1D40, 0x1D40, Status::Mapped("t")),
+    (0x1D41, 0x1D41, Status::Mapped("u")),
+    (0x1D42, 0x1D42, Status::Mapped("w")),
+    (0x1D43, 0x1D43, Status::Mapped("a")),
+    (0x1D44, 0x1D44, Status::Mapped("\u{250}")),
+    (0x1D45, 0x1D45, Status::Mapped("\u{251}")),
+    (0x1D46, 0x1D46, Status::Mapped("\u{1D02}")),
+    (0x1D47, 0x1D47, Status::Mapped("b")),
+    (0x1D48, 0x1D48, Status::Mapped("d")),
+    (0x1D49, 0x1D49, Status::Mapped("e")),
+    (0x1D4A, 0x1D4A, Status::Mapped("\u{259}")),
+    (0x1D4B, 0x1D4B, Status::Mapped("\u{25B}")),
+    (0x1D4C, 0x1D4C, Status::Mapped("\u{25C}")),
+    (0x1D4D, 0x1D4D, Status::Mapped("g")),
+    (0x1D4E, 0x1D4E, Status::Valid),
+    (0x1D4F, 0x1D4F, Status::Mapped("k")),
+    (0x1D50, 0x1D50, Status::Mapped("m")),
+    (0x1D51, 0x1D51, Status::Mapped("\u{14B}")),
+    (0x1D52, 0x1D52, Status::Mapped("o")),
+    (0x1D53, 0x1D53, Status::Mapped("\u{254}")),
+    (0x1D54, 0x1D54, Status::Mapped("\u{1D16}")),
+    (0x1D55, 0x1D55, Status::Mapped("\u{1D17}")),
+    (0x1D56, 0x1D56, Status::Mapped("p")),
+    (0x1D57, 0x1D57, Status::Mapped("t")),
+    (0x1D58, 0x1D58, Status::Mapped("u")),
+    (0x1D59, 0x1D59, Status::Mapped("\u{1D1D}")),
+    (0x1D5A, 0x1D5A, Status::Mapped("\u{26F}")),
+    (0x1D5B, 0x1D5B, Status::Mapped("v")),
+    (0x1D5C, 0x1D5C, Status::Mapped("\u{1D25}")),
+    (0x1D5D, 0x1D5D, Status::Mapped("\u{3B2}")),
+    (0x1D5E, 0x1D5E, Status::Mapped("\u{3B3}")),
+    (0x1D5F, 0x1D5F, Status::Mapped("\u{3B4}")),
+    (0x1D60, 0x1D60, Status::Mapped("\u{3C6}")),
+    (0x1D61, 0x1D61, Status::Mapped("\u{3C7}")),
+    (0x1D62, 0x1D62, Status::Mapped("i")),
+    (0x1D63, 0x1D63, Status::Mapped("r")),
+    (0x1D64, 0x1D64, Status::Mapped("u")),
+    (0x1D65, 0x1D65, Status::Mapped("v")),
+    (0x1D66, 0x1D66, Status::Mapped("\u{3B2}")),
+    (0x1D67, 0x1D67, Status::Mapped("\u{3B3}")),
+    (0x1D68, 0x1D68, Status::Mapped("\u{3C1}")),
+    (0x1D69, 0x1D69, Status::Mapped("\u{3C6}")),
+    (0x1D6A, 0x1D6A, Status::Mapped("\u{3C7}")),
+    (0x1D6B, 0x1D77, Status::Valid),
+    (0x1D78, 0x1D78, Status::Mapped("\u{43D}")),
+    (0x1D79, 0x1D9A, Status::Valid),
+    (0x1D9B, 0x1D9B, Status::Mapped("\u{252}")),
+    (0x1D9C, 0x1D9C, Status::Mapped("c")),
+    (0x1D9D, 0x1D9D, Status::Mapped("\u{255}")),
+    (0x1D9E, 0x1D9E, Status::Mapped("\u{F0}")),
+    (0x1D9F, 0x1D9F, Status::Mapped("\u{25C}")),
+    (0x1DA0, 0x1DA0, Status::Mapped("f")),
+    (0x1DA1, 0x1DA1, Status::Mapped("\u{25F}")),
+    (0x1DA2, 0x1DA2, Status::Mapped("\u{261}")),
+    (0x1DA3, 0x1DA3, Status::Mapped("\u{265}")),
+    (0x1DA4, 0x1DA4, Status::Mapped("\u{268}")),
+    (0x1DA5, 0x1DA5, Status::Mapped("\u{269}")),
+    (0x1DA6, 0x1DA6, Status::Mapped("\u{26A}")),
+    (0x1DA7, 0x1DA7, Status::Mapped("\u{1D7B}")),
+    (0x1DA8, 0x1DA8, Status::Mapped("\u{29D}")),
+    (0x1DA9, 0x1DA9, Status::Mapped("\u{26D}")),
+    (0x1DAA, 0x1DAA, Status::Mapped("\u{1D85}")),
+    (0x1DAB, 0x1DAB, Status::Mapped("\u{29F}")),
+    (0x1DAC, 0x1DAC, Status::Mapped("\u{271}")),
+    (0x1DAD, 0x1DAD, Status::Mapped("\u{270}")),
+    (0x1DAE, 0x1DAE, Status::Mapped("\u{272}")),
+    (0x1DAF, 0x1DAF, Status::Mapped("\u{273}")),
+    (0x1DB0, 0x1DB0, Status::Mapped("\u{274}")),
+    (0x1DB1, 0x1DB1, Status::Mapped("\u{275}")),
+    (0x1DB2, 0x1DB2, Status::Mapped("\u{278}")),
+    (0x1DB3, 0x1DB3, Status::Mapped("\u{282}")),
+    (0x1DB4, 0x1DB4, Status::Mapped("\u{283}")),
+    (0x1DB5, 0x1DB5, Status::Mapped("\u{1AB}")),
+    (0x1DB6, 0x1DB6, Status::Mapped("\u{289}")),
+    (0x1DB7, 0x1DB7, Status::Mapped("\u{28A}")),
+    (0x1DB8, 0x1DB8, Status::Mapped("\u{1D1C}")),
+    (0x1DB9, 0x1DB9, Status::Mapped("\u{28B}")),
+    (0x1DBA, 0x1DBA, Status::Mapped("\u{28C}")),
+    (0x1DBB, 0x1DBB, Status::Mapped("z")),
+    (0x1DBC, 0x1DBC, Status::Mapped("\u{290}")),
+    (0x1DBD, 0x1DBD, Status::Mapped("\u{291}")),
+    (0x1DBE, 0x1DBE, Status::Mapped("\u{292}")),
+    (0x1DBF, 0x1DBF, Status::Mapped("\u{3B8}")),
+    (0x1DC0, 0x1DFF, Status::Valid),
+    (0x1E00, 0x1E00, Status::Mapped("\u{1E01}")),
+    (0x1E01, 0x1E01, Status::Valid),
+    (0x1E02, 0x1E02, Status::Mapped("\u{1E03}")),
+    (0x1E03, 0x1E03, Status::Valid),
+    (0x1E04, 0x1E04, Status::Mapped("\u{1E05}")),
+    (0x1E05, 0x1E05, Status::Valid),
+    (0x1E06, 0x1E06, Status::Mapped("\u{1E07}")),
+    (0x1E07, 0x1E07, Status::Valid),
+    (0x1E08, 0x1E08, Status::Mapped("\u{1E09}")),
+    (0x1E09, 0x1E09, Status::Valid),
+    (0x1E0A, 0x1E0A, Status::Mapped("\u{1E0B}")),
+    (0x1E0B, 0x1E0B, Status::Valid),
+    (0x1E0C, 0x1E0C, Status::Mapped("\u{1E0D}")),
+    (0x1E0D, 0x1E0D, Status::Valid),
+    (0x1E0E, 0x1E0E, Status::Mapped("\u{1E0F}")),
+    (0x1E0F, 0x1E0F, Status::Valid),
+    (0x1E10, 0x1E10, Status::Mapped("\u{1E11}")),
+    (0x1E11, 0x1E11, Status::Valid),
+    (0x1E12, 0x1E12, Status::Mapped("\u{1E13}")),
+    (0x1E13, 0x1E13, Status::Valid),
+    (0x1E14, 0x1E14, Status::Mapped("\u{1E15}")),
+    (0x1E15, 0x1E15, Status::Valid),
+    (0x1E16, 0x1E16, Status::Mapped("\u{1E17}")),
+    (0x1E17, 0x1E17, Status::Valid),
+    (0x1E18, 0x1E18, Status::Mapped("\u{1E19}")),
+    (0x1E19, 0x1E19, Status::Valid),
+    (0x1E1A, 0x1E1A, Status::Mapped("\u{1E1B}")),
+    (0x1E1B, 0x1E1B, Status::Valid),
+    (0x1E1C, 0x1E1C, Status::Mapped("\u{1E1D}")),
+    (0x1E1D, 0x1E1D, Status::Valid),
+    (0x1E1E, 0x1E1E, Status::Mapped("\u{1E1F}")),
+    (0x1E1F, 0x1E1F, Status::Valid),
+    (0x1E20, 0x1E20, Status::Mapped("\u{1E21}")),
+    (0x1E21, 0x1E21, Status::Valid),
+    (0x1E22, 0x1E22, Status::Mapped("\u{1E23}")),
+    (0x1E23, 0x1E23, Status::Valid),
+    (0x1E24, 0x1E24, Status::Mapped("\u{1E25}")),
+    (0x1E25, 0x1E25, Status::Valid),
+    (0x1E26, 0x1E26, Status::Mapped("\u{1E27}")),
+    (0x1E27, 0x1E27, Status::Valid),
+    (0x1E28, 0x1E28, Status::Mapped("\u{1E29}")),
+    (0x1E29, 0x1E29, Status::Valid),
+    (0x1E2A, 0x1E2A, Status::Mapped("\u{1E2B}")),
+    (0x1E2B, 0x1E2B, Status::Valid),
+    (0x1E2C, 0x1E2C, Status::Mapped("\u{1E2D}")),
+    (0x1E2D, 0x1E2D, Status::Valid),
+    (0x1E2E, 0x1E2E, Status::Mapped("\u{1E2F}")),
+    (0x1E2F, 0x1E2F, Status::Valid),
+    (0x1E30, 0x1E30, Status::Mapped("\u{1E31}")),
+    (0x1E31, 0x1E31, Status::Valid),
+    (0x1E32, 0x1E32, Status::Mapped("\u{1E33}")),
+    (0x1E33, 0x1E33, Status::Valid),
+    (0x1E34, 0x1E34, Status::Mapped("\u{1E35}")),
+    (0x1E35, 0x1E35, Status::Valid),
+    (0x1E36, 0x1E36, Status::Mapped("\u{1E37}")),
+    (0x1E37, 0x1E37, Status::Valid),
+    (0x1E38, 0x1E38, Status::Mapped("\u{1E39}")),
+    (0x1E39, 0x1E39, Status::Valid),
+    (0x1E3A, 0x1E3A, Status::Mapped("\u{1E3B}")),
+    (0x1E3B, 0x1E3B, Status::Valid),
+    (0x1E3C, 0x1E3C, Status::Mapped("\u{1E3D}")),
+    (0x1E3D, 0x1E3D, Status::Valid),
+    (0x1E3E, 0x1E3E, Status::Mapped("\u{1E3F}")),
+    (0x1E3F, 0x1E3F, Status::Valid),
+    (0x1E40, 0x1E40, Status::Mapped("\u{1E41}")),
+    (0x1E41, 0x1E41, Status::Valid),
+    (0x1E42, 0x1E42, Status::Mapped("\u{1E43}")),
+    (0x1E43, 0x1E43, Status::Valid),
+    (0x1E44, 0x1E44, Status::Mapped("\u{1E45}")),
+    (0x1E45, 0x1E45, Status::Valid),
+    (0x1E46, 0x1E46, Status::Mapped("\u{1E47}")),
+    (0x1E47, 0x1E47, Status::Valid),
+    (0x1E48, 0x1E48, Status::Mapped("\u{1E49}")),
+    (0x1E49, 0x1E49, Status::Valid),
+    (0x1E4A, 0x1E4A, Status::Mapped("\u{1E4B}")),
+    (0x1E4B, 0x1E4B, Status::Valid),
+    (0x1E4C, 0x1E4C, Status::Mapped("\u{1E4D}")),
+    (0x1E4D, 0x1E4D, Status::Valid),
+    (0x1E4E, 0x1E4E, Status::Mapped("\u{1E4F}")),
+    (0x1E4F, 0x1E4F, Status::Valid),
+    (0x1E50, 0x1E50, Status::Mapped("\u{1E51}")),
+    (0x1E51, 0x1E51, Status::Valid),
+    (0x1E52, 0x1E52, Status::Mapped("\u{1E53}")),
+    (0x1E53, 0x1E53, Status::Valid),
+    (0x1E54, 0x1E54, Status::Mapped("\u{1E55}")),
+    (0x1E55, 0x1E55, Status::Valid),
+    (0x1E56, 0x1E56, Status::Mapped("\u{1E57}")),
+    (0x1E57, 0x1E57, Status::Valid),
+    (0x1E58, 0x1E58, Status::Mapped("\u{1E59}")),
+    (0x1E59, 0x1E59, Status::Valid),
+    (0x1E5A, 0x1E5A, Status::Mapped("\u{1E5B}")),
+    (0x1E5B, 0x1E5B, Status::Valid),
+    (0x1E5C, 0x1E5C, Status::Mapped("\u{1E5D}")),
+    (0x1E5D, 0x1E5D, Status::Valid),
+    (0x1E5E, 0x1E5E, Status::Mapped("\u{1E5F}")),
+    (0x1E5F, 0x1E5F, Status::Valid),
+    (0x1E60, 0x1E60, Status::Mapped("\u{1E61}")),
+    (0x1E61, 0x1E61, Status::Valid),
+    (0x1E62, 0x1E62, Status::Mapped("\u{1E63}")),
+    (0x1E63, 0x1E63, Status::Valid),
+    (0x1E64, 0x1E64, Status::Mapped("\u{1E65}")),
+    (0x1E65, 0x1E65, Status::Valid),
+    (0x1E66, 0x1E66, Status::Mapped("\u{1E67}")),
+    (0x1E67, 0x1E67, Status::Valid),
+    (0x1E68, 0x1E68, Status::Mapped("\u{1E69}")),
+    (0x1E69, 0x1E69, Status::Valid),
+    (0x1E6A, 0x1E6A, Status::Mapped("\u{1E6B}")),
+    (0x1E6B, 0x1E6B, Status::Valid),
+    (0x1E6C, 0x1E6C, Status::Mapped("\u{1E6D}")),
+    (0x1E6D, 0x1E6D, Status::Valid),
+    (0x1E6E, 0x1E6E, Status::Mapped("\u{1E6F}")),
+    (0x1E6F, 0x1E6F, Status::Valid),
+    (0x1E70, 0x1E70, Status::Mapped("\u{1E71}")),
+    (0x1E71, 0x1E71, Status::Valid),
+    (0x1E72, 0x1E72, Status::Mapped("\u{1E73}")),
+    (0x1E73, 0x1E73, Status::Valid),
+    (0x1E74, 0x1E74, Status::Mapped("\u{1E75}")),
+    (0x1E75, 0x1E75, Status::Valid),
+    (0x1E76, 0x1E76, Status::Mapped("\u{1E77}")),
+    (0x1E77, 0x1E77, Status::Valid),
+    (0x1E78, 0x1E78, Status::Mapped("\u{1E79}")),
+    (0x1E79, 0x1E79, Status::Valid),
+    (0x1E7A, 0x1E7A, Status::Mapped("\u{1E7B}")),
+    (0x1E7B, 0x1E7B, Status::Valid),
+    (0x1E7C, 0x1E7C, Status::Mapped("\u{1E7D}")),
+    (0x1E7D, 0x1E7D, Status::Valid),
+    (0x1E7E, 0x1E7E, Status::Mapped("\u{1E7F}")),
+    (0x1E7F, 0x1E7F, Status::Valid),
+    (0x1E80, 0x1E80, Status::Mapped("\u{1E81}")),
+    (0x1E81, 0x1E81, Status::Valid),
+    (0x1E82, 0x1E82, Status::Mapped("\u{1E83}")),
+    (0x1E83, 0x1E83, Status::Valid),
+    (0x1E84, 0x1E84, Status::Mapped("\u{1E85}")),
+    (0x1E85, 0x1E85, Status::Valid),
+    (0x1E86, 0x1E86, Status::Mapped("\u{1E87}")),
+    (0x1E87, 0x1E87, Status::Valid),
+    (0x1E88, 0x1E88, Status::Mapped("\u{1E89}")),
+    (0x1E89, 0x1E89, Status::Valid),
+    (0x1E8A, 0x1E8A, Status::Mapped("\u{1E8B}")),
+    (0x1E8B, 0x1E8B, Status::Valid),
+    (0x1E8C, 0x1E8C, Status::Mapped("\u{1E8D}")),
+    (0x1E8D, 0x1E8D, Status::Valid),
+    (0x1E8E, 0x1E8E, Status::Mapped("\u{1E8F}")),
+    (0x1E8F, 0x1E8F, Status::Valid),
+    (0x1E90, 0x1E90, Status::Mapped("\u{1E91}")),
+    (0x1E91, 0x1E91, Status::Valid),
+    (0x1E92, 0x1E92, Status::Mapped("\u{1E93}")),
+    (0x1E93, 0x1E93, Status::Valid),
+    (0x1E94, 0x1E94, Status::Mapped("\u{1E95}")),
+    (0x1E95, 0x1E99, Status::Valid),
+    (0x1E9A, 0x1E9A, Status::Mapped("a\u{2BE}")),
+    (0x1E9B, 0x1E9B, Status::Mapped("\u{1E61}")),
+    (0x1E9C, 0x1E9D, Status::Valid),
+    (0x1E9E, 0x1E9E, Status::Mapped("\u{DF}")),
+    (0x1E9F, 0x1E9F, Status::Valid),
+    (0x1EA0, 0x1EA0, Status::Mapped("\u{1EA1}")),
+    (0x1EA1, 0x1EA1, Status::Valid),
+    (0x1EA2, 0x1EA2, Status::Mapped("\u{1EA3}")),
+    (0x1EA3, 0x1EA3, Status::Valid),
+    (0x1EA4, 0x1EA4, Status::Mapped("\u{1EA5}")),
+    (0x1EA5, 0x1EA5, Status::Valid),
+    (0x1EA6, 0x1EA6, Status::Mapped("\u{1EA7}")),
+    (0x1EA7, 0x1EA7, Status::Valid),
+    (0x1EA8, 0x1EA8, Status::Mapped("\u{1EA9}")),
+    (0x1EA9, 0x1EA9, Status::Valid),
+    (0x1EAA, 0x1EAA, Status::Mapped("\u{1EAB}")),
+    (0x1EAB, 0x1EAB, Status::Valid),
+    (0x1EAC, 0x1EAC, Status::Mapped("\u{1EAD}")),
+    (0x1EAD, 0x1EAD, Status::Valid),
+    (0x1EAE, 0x1EAE, Status::Mapped("\u{1EAF}")),
+    (0x1EAF, 0x1EAF, Status::Valid),
+    (0x1EB0, 0x1EB0, Status::Mapped("\u{1EB1}")),
+    (0x1EB1, 0x1EB1, Status::Valid),
+    (0x1EB2, 0x1EB2, Status::Mapped("\u{1EB3}")),
+    (0x1EB3, 0x1EB3, Status::Valid),
+    (0x1EB4, 0x1EB4, Status::Mapped("\u{1EB5}")),
+    (0x1EB5, 0x1EB5, Status::Valid),
+    (0x1EB6, 0x1EB6, Status::Mapped("\u{1EB7}")),
+    (0x1EB7, 0x1EB7, Status::Valid),
+    (0x1EB8, 0x1EB8, Status::Mapped("\u{1EB9}")),
+    (0x1EB9, 0x1EB9, Status::Valid),
+    (0x1EBA, 0x1EBA, Status::Mapped("\u{1EBB}")),
+    (0x1EBB, 0x1EBB, Status::Valid),
+    (0x1EBC, 0x1EBC, Status::Mapped("\u{1EBD}")),
+    (0x1EBD, 0x1EBD, Status::Valid),
+    (0x1EBE, 0x1EBE, Status::Mapped("\u{1EBF}")),
+    (0x1EBF, 0x1EBF, Status::Valid),
+    (0x1EC0, 0x1EC0, Status::Mapped("\u{1EC1}")),
+    (0x1EC1, 0x1EC1, Status::Valid),
+    (0x1EC2, 0x1EC2, Status::Mapped("\u{1EC3}")),
+    (0x1EC3, 0x1EC3, Status::Valid),
+    (0x1EC4, 0x1EC4, Status::Mapped("\u{1EC5}")),
+    (0x1EC5, 0x1EC5, Status::Valid),
+    (0x1EC6, 0x1EC6, Status::Mapped("\u{1EC7}")),
+    (0x1EC7, 0x1EC7, Status::Valid),
+    (0x1EC8, 0x1EC8, Status::Mapped("\u{1EC9}")),
+    (0x1EC9, 0x1EC9, Status::Valid),
+    (0x1ECA, 0x1ECA, Status::Mapped("\u{1ECB}")),
+    (0x1ECB, 0x1ECB, Status::Valid),
+    (0x1ECC, 0x1ECC, Status::Mapped("\u{1ECD}")),
+    (0x1ECD, 0x1ECD, Status::Valid),
+    (0x1ECE, 0x1ECE, Status::Mapped("\u{1ECF}")),
+    (0x1ECF, 0x1ECF, Status::Valid),
+    (0x1ED0, 0x1ED0, Status::Mapped("\u{1ED1}")),
+    (0x1ED1, 0x1ED1, Status::Valid),
+    (0x1ED2, 0x1ED2, Status::Mapped("\u{1ED3}")),
+    (0x1ED3, 0x1ED3, Status::Valid),
+    (0x1ED4, 0x1ED4, Status::Mapped("\u{1ED5}")),
+    (0x1ED5, 0x1ED5, Status::Valid),
+    (0x1ED6, 0x1ED6, Status::Mapped("\u{1ED7}")),
+    (0x1ED7, 0x1ED7, Status::Valid),
+    (0x1ED8, 0x1ED8, Status::Mapped("\u{1ED9}")),
+    (0x1ED9, 0x1ED9, Status::Valid),
+    (0x1EDA, 0x1EDA, Status::Mapped("\u{1EDB}")),
+    (0x1EDB, 0x1EDB, Status::Valid),
+    (0x1EDC, 0x1EDC, Status::Mapped("\u{1EDD}")),
+    (0x1EDD, 0x1EDD, Status::Valid),
+    (0x1EDE, 0x1EDE, Status::Mapped("\u{1EDF}")),
+    (0x1EDF, 0x1EDF, Status::Valid),
+    (0x1EE0, 0x1EE0, Status::Mapped("\u{1EE1}")),
+    (0x1EE1, 0x1EE1, Status::Valid),
+    (0x1EE2, 0x1EE2, Status::Mapped("\u{1EE3}")),
+    (0x1EE3, 0x1EE3, Status::Valid),
+    (0x1EE4, 0x1EE4, Status::Mapped("\u{1EE5}")),
+    (0x1EE5, 0x1EE5, Status::Valid),
+    (0x1EE6, 0x1EE6, Status::Mapped("\u{1EE7}")),
+    (0x1EE7, 0x1EE7, Status::Valid),
+    (0x1EE8, 0x1EE8, Status::Mapped("\u{1EE9}")),
+    (0x1EE9, 0x1EE9, Status::Valid),
+    (0x1EEA, 0x1EEA, Status::Mapped("\u{1EEB}")),
+    (0x1EEB, 0x1EEB, Status::Valid),
+    (0x1EEC, 0x1EEC, Status::Mapped("\u{1EED}")),
+    (0x1EED, 0x1EED, Status::Valid),
+    (0x1EEE, 0x1EEE, Status::Mapped("\u{1EEF}")),
+    (0x1EEF, 0x1EEF, Status::Valid),
+    (0x1EF0, 0x1EF0, Status::Mapped("\u{1EF1}")),
+    (0x1EF1, 0x1EF1, Status::Valid),
+    (0x1EF2, 0x1EF2, Status::Mapped("\u{1EF3}")),
+    (0x1EF3, 0x1EF3, Status::Valid),
+    (0x1EF4, 0x1EF4, Status::Mapped("\u{1EF5}")),
+    (0x1EF5, 0x1EF5, Status::Valid),
+    (0x1EF6, 0x1EF6, Status::Mapped("\u{1EF7}")),
+    (0x1EF7, 0x1EF7, Status::Valid),
+    (0x1EF8, 0x1EF8, Status::Mapped("\u{1EF9}")),
+    (0x1EF9, 0x1EF9, Status::Valid),
+    (0x1EFA, 0x1EFA, Status::Mapped("\u{1EFB}")),
+    (0x1EFB, 0x1EFB, Status::Valid),
+    (0x1EFC, 0x1EFC, Status::Mapped("\u{1EFD}")),
+    (0x1EFD, 0x1EFD, Status::Valid),
+    (0x1EFE, 0x1EFE, Status::Mapped("\u{1EFF}")),
+    (0x1EFF, 0x1F07, Status::Valid),
+    (0x1F08, 0x1F08, Status::Mapped("\u{1F00}")),
+    (0x1F09, 0x1F09, Status::Mapped("\u{1F01}")),
+    (0x1F0A, 0x1F0A, Status::Mapped("\u{1F02}")),
+    (0x1F0B, 0x1F0B, Status::Mapped("\u{1F03}")),
+    (0x1F0C, 0x1F0C, Status::Mapped("\u{1F04}")),
+    (0x1F0D, 0x1F0D, Status::Mapped("\u{1F05}")),
+    (0x1F0E, 0x1F0E, Status::Mapped("\u{1F06}")),
+    (0x1F0F, 0x1F0F, Status::Mapped("\u{1F07}")),
+    (0x1F10, 0x1F15, Status::Valid),
+    (0x1F16, 0x1F17, Status::Disallowed),
+    (0x1F18, 0x1F18, Status::Mapped("\u{1F10}")),
+    (0x1F19, 0x1F19, Status::Mapped("\u{1F11}")),
+    (0x1F1A, 0x1F1A, Status::Mapped("\u{1F12}")),
+    (0x1F1B, 0x1F1B, Status::Mapped("\u{1F13}")),
+    (0x1F1C, 0x1F1C, Status::Mapped("\u{1F14}")),
+    (0x1F1D, 0x1F1D, Status::Mapped("\u{1F15}")),
+    (0x1F1E, 0x1F1F, Status::Disallowed),
+    (0x1F20, 0x1F27, Status::Valid),
+    (0x1F28, 0x1F28, Status::Mapped("\u{1F20}")),
+    (0x1F29, 0x1F29, Status::Mapped("\u{1F21}")),
+    (0x1F2A, 0x1F2A, Status::Mapped("\u{1F22}")),
+    (0x1F2B, 0x1F2B, Status::Mapped("\u{1F23}")),
+    (0x1F2C, 0x1F2C, Status::Mapped("\u{1F24}")),
+    (0x1F2D, 0x1F2D, Status::Mapped("\u{1F25}")),
+    (0x1F2E, 0x1F2E, Status::Mapped("\u{1F26}")),
+    (0x1F2F, 0x1F2F, Status::Mapped("\u{1F27}")),
+    (0x1F30, 0x1F37, Status::Valid),
+    (0x1F38, 0x1F38, Status::Mapped("\u{1F30}")),
+    (0x1F39, 0x1F39, Status::Mapped("\u{1F31}")),
+    (0x1F3A, 0x1F3A, Status::Mapped("\u{1F32}")),
+    (0x1F3B, 0x1F3B, Status::Mapped("\u{1F33}")),
+    (0x1F3C, 0x1F3C, Status::Mapped("\u{1F34}")),
+    (0x1F3D, 0x1F3D, Status::Mapped("\u{1F35}")),
+    (0x1F3E, 0x1F3E, Status::Mapped("\u{1F36}")),
+    (0x1F3F, 0x1F3F, Status::Mapped("\u{1F37}")),
+    (0x1F40, 0x1F45, Status::Valid),
+    (0x1F46, 0x1F47, Status::Disallowed),
+    (0x1F48, 0x1F48, Status::Mapped("\u{1F40}")),
+    (0x1F49, 0x1F49, Status::Mapped("\u{1F41}")),
+    (0x1F4A, 0x1F4A, Status::Mapped("\u{1F42}")),
+    (0x1F4B, 0x1F4B, Status::Mapped("\u{1F43}")),
+    (0x1F4C, 0x1F4C, Status::Mapped("\u{1F44}")),
+    (0x1F4D, 0x1F4D, Status::Mapped("\u{1F45}")),
+    (0x1F4E, 0x1F4F, Status::Disallowed),
+    (0x1F50, 0x1F57, Status::Valid),
+    (0x1F58, 0x1F58, Status::Disallowed),
+    (0x1F59, 0x1F59, Status::Mapped("\u{1F51}")),
+    (0x1F5A, 0x1F5A, Status::Disallowed),
+    (0x1F5B, 0x1F5B, Status::Mapped("\u{1F53}")),
+    (0x1F5C, 0x1F5C, Status::Disallowed),
+    (0x1F5D, 0x1F5D, Status::Mapped("\u{1F55}")),
+    (0x1F5E, 0x1F5E, Status::Disallowed),
+    (0x1F5F, 0x1F5F, Status::Mapped("\u{1F57}")),
+    (0x1F60, 0x1F67, Status::Valid),
+    (0x1F68, 0x1F68, Status::Mapped("\u{1F60}")),
+    (0x1F69, 0x1F69, Status::Mapped("\u{1F61}")),
+    (0x1F6A, 0x1F6A, Status::Mapped("\u{1F62}")),
+    (0x1F6B, 0x1F6B, Status::Mapped("\u{1F63}")),
+    (0x1F6C, 0x1F6C, Status::Mapped("\u{1F64}")),
+    (0x1F6D, 0x1F6D, Status::Mapped("\u{1F65}")),
+    (0x1F6E, 0x1F6E, Status::Mapped("\u{1F66}")),
+    (0x1F6F, 0x1F6F, Status::Mapped("\u{1F67}")),
+    (0x1F70, 0x1F70, Status::Valid),
+    (0x1F71, 0x1F71, Status::Mapped("\u{3AC}")),
+    (0x1F72, 0x1F72, Status::Valid),
+    (0x1F73, 0x1F73, Status::Mapped("\u{3AD}")),
+    (0x1F74, 0x1F74, Status::Valid),
+    (0x1F75, 0x1F75, Status::Mapped("\u{3AE}")),
+    (0x1F76, 0x1F76, Status::Valid),
+    (0x1F77, 0x1F77, Status::Mapped("\u{3AF}")),
+    (0x1F78, 0x1F78, Status::Valid),
+    (0x1F79, 0x1F79, Status::Mapped("\u{3CC}")),
+    (0x1F7A, 0x1F7A, Status::Valid),
+    (0x1F7B, 0x1F7B, Status::Mapped("\u{3CD}")),
+    (0x1F7C, 0x1F7C, Status::Valid),
+    (0x1F7D, 0x1F7D, Status::Mapped("\u{3CE}")),
+    (0x1F7E, 0x1F7F, Status::Disallowed),
+    (0x1F80, 0x1F80, Status::Mapped("\u{1F00}\u{3B9}")),
+    (0x1F81, 0x1F81, Status::Mapped("\u{1F01}\u{3B9}")),
+    (0x1F82, 0x1F82, Status::Mapped("\u{1F02}\u{3B9}")),
+    (0x1F83, 0x1F83, Status::Mapped("\u{1F03}\u{3B9}")),
+    (0x1F84, 0x1F84, Status::Mapped("\u{1F04}\u{3B9}")),
+    (0x1F85, 0x1F85, Status::Mapped("\u{1F05}\u{3B9}")),
+    (0x1F86, 0x1F86, Status::Mapped("\u{1F06}\u{3B9}")),
+    (0x1F87, 0x1F87, Status::Mapped("\u{1F07}\u{3B9}")),
+    (0x1F88, 0x1F88, Status::Mapped("\u{1F00}\u{3B9}")),
+    (0x1F89, 0x1F89, Status::Mapped("\u{1F01}\u{3B9}")),
+    (0x1F8A, 0x1F8A, Status::Mapped("\u{1F02}\u{3B9}")),
+    (0x1F8B, 0x1F8B, Status::Mapped("\u{1F03}\u{3B9}")),
+    (0x1F8C, 0x1F8C, Status::Mapped("\u{1F04}\u{3B9}")),
+    (0x1F8D, 0x1F8D, Status::Mapped("\u{1F05}\u{3B9}")),
+    (0x1F8E, 0x1F8E, Status::Mapped("\u{1F06}\u{3B9}")),
+    (0x1F8F, 0x1F8F, Status::Mapped("\u{1F07}\u{3B9}")),
+    (0x1F90, 0x1F90, Status::Mapped("\u{1F20}\u{3B9}")),
+    (0x1F91, 0x1F91, Status::Mapped("\u{1F21}\u{3B9}")),
+    (0x1F92, 0x1F92, Status::Mapped("\u{1F22}\u{3B9}")),
+    (0x1F93, 0x1F93, Status::Mapped("\u{1F23}\u{3B9}")),
+    (0x1F94, 0x1F94, Status::Mapped("\u{1F24}\u{3B9}")),
+    (0x1F95, 0x1F95, Status::Mapped("\u{1F25}\u{3B9}")),
+    (0x1F96, 0x1F96, Status::Mapped("\u{1F26}\u{3B9}")),
+    (0x1F97, 0x1F97, Status::Mapped("\u{1F27}\u{3B9}")),
+    (0x1F98, 0x1F98, Status::Mapped("\u{1F20}\u{3B9}")),
+    (0x1F99, 0x1F99, Status::Mapped("\u{1F21}\u{3B9}")),
+    (0x1F9A, 0x1F9A, Status::Mapped("\u{1F22}\u{3B9}")),
+    (0x1F9B, 0x1F9B, Status::Mapped("\u{1F23}\u{3B9}")),
+    (0x1F9C, 0x1F9C, Status::Mapped("\u{1F24}\u{3B9}")),
+    (0x1F9D, 0x1F9D, Status::Mapped("\u{1F25}\u{3B9}")),
+    (0x1F9E, 0x1F9E, Status::Mapped("\u{1F26}\u{3B9}")),
+    (0x1F9F, 0x1F9F, Status::Mapped("\u{1F27}\u{3B9}")),
+    (0x1FA0, 0x1FA0, Status::Mapped("\u{1F60}\u{3B9}")),
+    (0x1FA1, 0x1FA1, Status::Mapped("\u{1F61}\u{3B9}")),
+    (0x1FA2, 0x1FA2, Status::Mapped("\u{1F62}\u{3B9}")),
+    (0x1FA3, 0x1FA3, Status::Mapped("\u{1F63}\u{3B9}")),
+    (0x1FA4, 0x1FA4, Status::Mapped("\u{1F64}\u{3B9}")),
+    (0x1FA5, 0x1FA5, Status::Mapped("\u{1F65}\u{3B9}")),
+    (0x1FA6, 0x1FA6, Status::Mapped("\u{1F66}\u{3B9}")),
+    (0x1FA7, 0x1FA7, Status::Mapped("\u{1F67}\u{3B9}")),
+    (0x1FA8, 0x1FA8, Status::Mapped("\u{1F60}\u{3B9}")),
+    (0x1FA9, 0x1FA9, Status::Mapped("\u{1F61}\u{3B9}")),
+    (0x1FAA, 0x1FAA, Status::Mapped("\u{1F62}\u{3B9}")),
+    (0x1FAB, 0x1FAB, Status::Mapped("\u{1F63}\u{3B9}")),
+    (0x1FAC, 0x1FAC, Status::Mapped("\u{1F64}\u{3B9}")),
+    (0x1FAD, 0x1FAD, Status::Mapped("\u{1F65}\u{3B9}")),
+    (0x1FAE, 0x1FAE, Status::Mapped("\u{1F66}\u{3B9}")),
+    (0x1FAF, 0x1FAF, Status::Mapped("\u{1F67}\u{3B9}")),
+    (0x1FB0, 0x1FB1, Status::Valid),
+    (0x1FB2, 0x1FB2, Status::Mapped("\u{1F70}\u{3B9}")),
+    (0x1FB3, 0x1FB3, Status::Mapped("\u{3B1}\u{3B9}")),
+    (0x1FB4, 0x1FB4, Status::Mapped("\u{3AC}\u{3B9}")),
+    (0x1FB5, 0x1FB5, Status::Disallowed),
+    (0x1FB6, 0x1FB6, Status::Valid),
+    (0x1FB7, 0x1FB7, Status::Mapped("\u{1FB6}\u{3B9}")),
+    (0x1FB8, 0x1FB8, Status::Mapped("\u{1FB0}")),
+    (0x1FB9, 0x1FB9, Status::Mapped("\u{1FB1}")),
+    (0x1FBA, 0x1FBA, Status::Mapped("\u{1F70}")),
+    (0x1FBB, 0x1FBB, Status::Mapped("\u{3AC}")),
+    (0x1FBC, 0x1FBC, Status::Mapped("\u{3B1}\u{3B9}")),
+    (0x1FBD, 0x1FBD, Status::Valid),
+    (0x1FBE, 0x1FBE, Status::Mapped("\u{3B9}")),
+    (0x1FBF, 0x1FC1, Status::Valid),
+    (0x1FC2, 0x1FC2, Status::Mapped("\u{1F74}\u{3B9}")),
+    (0x1FC3, 0x1FC3, Status::Mapped("\u{3B7}\u{3B9}")),
+    (0x1FC4, 0x1FC4, Status::Mapped("\u{3AE}\u{3B9}")),
+    (0x1FC5, 0x1FC5, Status::Disallowed),
+    (0x1FC6, 0x1FC6, Status::Valid),
+    (0x1FC7, 0x1FC7, Status::Mapped("\u{1FC6}\u{3B9}")),
+    (0x1FC8, 0x1FC8, Status::Mapped("\u{1F72}")),
+    (0x1FC9, 0x1FC9, Status::Mapped("\u{3AD}")),
+    (0x1FCA, 0x1FCA, Status::Mapped("\u{1F74}")),
+    (0x1FCB, 0x1FCB, Status::Mapped("\u{3AE}")),
+    (0x1FCC, 0x1FCC, Status::Mapped("\u{3B7}\u{3B9}")),
+    (0x1FCD, 0x1FD2, Status::Valid),
+    (0x1FD3, 0x1FD3, Status::Mapped("\u{390}")),
+    (0x1FD4, 0x1FD5, Status::Disallowed),
+    (0x1FD6, 0x1FD7, Status::Valid),
+    (0x1FD8, 0x1FD8, Status::Mapped("\u{1FD0}")),
+    (0x1FD9, 0x1FD9, Status::Mapped("\u{1FD1}")),
+    (0x1FDA, 0x1FDA, Status::Mapped("\u{1F76}")),
+    (0x1FDB, 0x1FDB, Status::Mapped("\u{3AF}")),
+    (0x1FDC, 0x1FDC, Status::Disallowed),
+    (0x1FDD, 0x1FE2, Status::Valid),
+    (0x1FE3, 0x1FE3, Status::Mapped("\u{3B0}")),
+    (0x1FE4, 0x1FE7, Status::Valid),
+    (0x1FE8, 0x1FE8, Status::Mapped("\u{1FE0}")),
+    (0x1FE9, 0x1FE9, Status::Mapped("\u{1FE1}")),
+    (0x1FEA, 0x1FEA, Status::Mapped("\u{1F7A}")),
+    (0x1FEB, 0x1FEB, Status::Mapped("\u{3CD}")),
+    (0x1FEC, 0x1FEC, Status::Mapped("\u{1FE5}")),
+    (0x1FED, 0x1FEF, Status::Valid),
+    (0x1FF0, 0x1FF1, Status::Disallowed),
+    (0x1FF2, 0x1FF2, Status::Mapped("\u{1F7C}\u{3B9}")),
+    (0x1FF3, 0x1FF3, Status::Mapped("\u{3C9}\u{3B9}")),
+    (0x1FF4, 0x1FF4, Status::Mapped("\u{3CE}\u{3B9}")),
+    (0x1FF5, 0x1FF5, Status::Disallowed),
+    (0x1FF6, 0x1FF6, Status::Valid),
+    (0x1FF7, 0x1FF7, Status::Mapped("\u{1FF6}\u{3B9}")),
+    (0x1FF8, 0x1FF8, Status::Mapped("\u{1F78}")),
+    (0x1FF9, 0x1FF9, Status::Mapped("\u{3CC}")),
+    (0x1FFA, 0x1FFA, Status::Mapped("\u{1F7C}")),
+    (0x1FFB, 0x1FFB, Status::Mapped("\u{3CE}")),
+    (0x1FFC, 0x1FFC, Status::Mapped("\u{3C9}\u{3B9}")),
+    (0x1FFD, 0x1FFE, Status::Valid),
+    (0x1FFF, 0x1FFF, Status::Disallowed),
+    (0x2000, 0x200A, Status::Valid),
+    (0x200B, 0x200B, Status::Ignored),
+    (0x200C, 0x200D, Status::Valid),
+    (0x200E, 0x200F, Status::Disallowed),
+    (0x2010, 0x2010, Status::Valid),
+    (0x2011, 0x2011, Status::Mapped("\u{2010}")),
+    (0x2012, 0x2023, Status::Valid),
+    (0x2024, 0x2026, Status::Disallowed),
+    (0x2027, 0x2027, Status::Valid),
+    (0x2028, 0x202E, Status::Disallowed),
+    (0x202F, 0x2032, Status::Valid),
+    (0x2033, 0x2033, Status::Mapped("\u{2032}\u{2032}")),
+    (0x2034, 0x2034, Status::Mapped("\u{2032}\u{2032}\u{2032}")),
+    (0x2035, 0x2035, Status::Valid),
+    (0x2036, 0x2036, Status::Mapped("\u{2035}\u{2035}")),
+    (0x2037, 0x2037, Status::Mapped("\u{2035}\u{2035}\u{2035}")),
+    (0x2038, 0x2056, Status::Valid),
+    (0x2057, 0x2057, Status::Mapped("\u{2032}\u{2032}\u{2032}\u{2032}")),
+    (0x2058, 0x205F, Status::Valid),
+    (0x2060, 0x2060, Status::Ignored),
+    (0x2061, 0x2063, Status::Disallowed),
+    (0x2064, 0x2064, Status::Ignored),
+    (0x2065, 0x206F, Status::Disallowed),
+    (0x2070, 0x2070, Status::Mapped("0")),
+    (0x2071, 0x2071, Status::Mapped("i")),
+    (0x2072, 0x2073, Status::Disallowed),
+    (0x2074, 0x2074, Status::Mapped("4")),
+    (0x2075, 0x2075, Status::Mapped("5")),
+    (0x2076, 0x2076, Status::Mapped("6")),
+    (0x2077, 0x2077, Status::Mapped("7")),
+    (0x2078, 0x2078, Status::Mapped("8")),
+    (0x2079, 0x2079, Status::Mapped("9")),
+    (0x207A, 0x207A, Status::Valid),
+    (0x207B, 0x207B, Status::Mapped("\u{2212}")),
+    (0x207C, 0x207E, Status::Valid),
+    (0x207F, 0x207F, Status::Mapped("n")),
+    (0x2080, 0x2080, Status::Mapped("0")),
+    (0x2081, 0x2081, Status::Mapped("1")),
+    (0x2082, 0x2082, Status::Mapped("2")),
+    (0x2083, 0x2083, Status::Mapped("3")),
+    (0x2084, 0x2084, Status::Mapped("4")),
+    (0x2085, 0x2085, Status::Mapped("5")),
+    (0x2086, 0x2086, Status::Mapped("6")),
+    (0x2087, 0x2087, Status::Mapped("7")),
+    (0x2088, 0x2088, Status::Mapped("8")),
+    (0x2089, 0x2089, Status::Mapped("9")),
+    (0x208A, 0x208A, Status::Valid),
+    (0x208B, 0x208B, Status::Mapped("\u{2212}")),
+    (0x208C, 0x208E, Status::Valid),
+    (0x208F, 0x208F, Status::Disallowed),
+    (0x2090, 0x2090, Status::Mapped("a")),
+    (0x2091, 0x2091, Status::Mapped("e")),
+    (0x2092, 0x2092, Status::Mapped("o")),
+    (0x2093, 0x2093, Status::Mapped("x")),
+    (0x2094, 0x2094, Status::Mapped("\u{259}")),
+    (0x2095, 0x2095, Status::Mapped("h")),
+    (0x2096, 0x2096, Status::Mapped("k")),
+    (0x2097, 0x2097, Status::Mapped("l")),
+    (0x2098, 0x2098, Status::Mapped("m")),
+    (0x2099, 0x2099, Status::Mapped("n")),
+    (0x209A, 0x209A, Status::Mapped("p")),
+    (0x209B, 0x209B, Status::Mapped("s")),
+    (0x209C, 0x209C, Status::Mapped("t")),
+    (0x209D, 0x209F, Status::Disallowed),
+    (0x20A0, 0x20A7, Status::Valid),
+    (0x20A8, 0x20A8, Status::Mapped("rs")),
+    (0x20A9, 0x20C0, Status::Valid),
+    (0x20C1, 0x20CF, Status::Disallowed),
+    (0x20D0, 0x20F0, Status::Valid),
+    (0x20F1, 0x20FF, Status::Disallowed),
+    (0x2100, 0x2101, Status::Valid),
+    (0x2102, 0x2102, Status::Mapped("c")),
+    (0x2103, 0x2103, Status::Mapped("\u{B0}c")),
+    (0x2104, 0x2106, Status::Valid),
+    (0x2107, 0x2107, Status::Mapped("\u{25B}")),
+    (0x2108, 0x2108, Status::Valid),
+    (0x2109, 0x2109, Status::Mapped("\u{B0}f")),
+    (0x210A, 0x210A, Status::Mapped("g")),
+    (0x210B, 0x210E, Status::Mapped("h")),
+    (0x210F, 0x210F, Status::Mapped("\u{127}")),
+    (0x2110, 0x2111, Status::Mapped("i")),
+    (0x2112, 0x2113, Status::Mapped("l")),
+    (0x2114, 0x2114, Status::Valid),
+    (0x2115, 0x2115, Status::Mapped("n")),
+    (0x2116, 0x2116, Status::Mapped("no")),
+    (0x2117, 0x2118, Status::Valid),
+    (0x2119, 0x2119, Status::Mapped("p")),
+    (0x211A, 0x211A, Status::Mapped("q")),
+    (0x211B, 0x211D, Status::Mapped("r")),
+    (0x211E, 0x211F, Status::Valid),
+    (0x2120, 0x2120, Status::Mapped("sm")),
+    (0x2121, 0x2121, Status::Mapped("tel")),
+    (0x2122, 0x2122, Status::Mapped("tm")),
+    (0x2123, 0x2123, Status::Valid),
+    (0x2124, 0x2124, Status::Mapped("z")),
+    (0x2125, 0x2125, Status::Valid),
+    (0x2126, 0x2126, Status::Mapped("\u{3C9}")),
+    (0x2127, 0x2127, Status::Valid),
+    (0x2128, 0x2128, Status::Mapped("z")),
+    (0x2129, 0x2129, Status::Valid),
+    (0x212A, 0x212A, Status::Mapped("k")),
+    (0x212B, 0x212B, Status::Mapped("\u{E5}")),
+    (0x212C, 0x212C, Status::Mapped("b")),
+    (0x212D, 0x212D, Status::Mapped("c")),
+    (0x212E, 0x212E, Status::Valid),
+    (0x212F, 0x2130, Status::Mapped("e")),
+    (0x2131, 0x2131, Status::Mapped("f")),
+    (0x2132, 0x2132, Status::Disallowed),
+    (0x2133, 0x2133, Status::Mapped("m")),
+    (0x2134, 0x2134, Status::Mapped("o")),
+    (0x2135, 0x2135, Status::Mapped("\u{5D0}")),
+    (0x2136, 0x2136, Status::Mapped("\u{5D1}")),
+    (0x2137, 0x2137, Status::Mapped("\u{5D2}")),
+    (0x2138, 0x2138, Status::Mapped("\u{5D3}")),
+    (0x2139, 0x2139, Status::Mapped("i")),
+    (0x213A, 0x213A, Status::Valid),
+    (0x213B, 0x213B, Status::Mapped("fax")),
+    (0x213C, 0x213C, Status::Mapped("\u{3C0}")),
+    (0x213D, 0x213E, Status::Mapped("\u{3B3}")),
+    (0x213F, 0x213F, Status::Mapped("\u{3C0}")),
+    (0x2140, 0x2140, Status::Mapped("\u{2211}")),
+    (0x2141, 0x2144, Status::Valid),
+    (0x2145, 0x2146, Status::Mapped("d")),
+    (0x2147, 0x2147, Status::Mapped("e")),
+    (0x2148, 0x2148, Status::Mapped("i")),
+    (0x2149, 0x2149, Status::Mapped("j")),
+    (0x214A, 0x214F, Status::Valid),
+    (0x2150, 0x2150, Status::Mapped("1\u{2044}7")),
+    (0x2151, 0x2151, Status::Mapped("1\u{2044}9")),
+    (0x2152, 0x2152, Status::Mapped("1\u{2044}10")),
+    (0x2153, 0x2153, Status::Mapped("1\u{2044}3")),
+    (0x2154, 0x2154, Status::Mapped("2\u{2044}3")),
+    (0x2155, 0x2155, Status::Mapped("1\u{2044}5")),
+    (0x2156, 0x2156, Status::Mapped("2\u{2044}5")),
+    (0x2157, 0x2157, Status::Mapped("3\u{2044}5")),
+    (0x2158, 0x2158, Status::Mapped("4\u{2044}5")),
+    (0x2159, 0x2159, Status::Mapped("1\u{2044}6")),
+    (0x215A, 0x215A, Status::Mapped("5\u{2044}6")),
+    (0x215B, 0x215B, Status::Mapped("1\u{2044}8")),
+    (0x215C, 0x215C, Status::Mapped("3\u{2044}8")),
+    (0x215D, 0x215D, Status::Mapped("5\u{2044}8")),
+    (0x215E, 0x215E, Status::Mapped("7\u{2044}8")),
+    (0x215F, 0x215F, Status::Mapped("1\u{2044}")),
+    (0x2160, 0x2160, Status::Mapped("i")),
+    (0x2161, 0x2161, Status::Mapped("ii")),
+    (0x2162, 0x2162, Status::Mapped("iii")),
+    (0x2163, 0x2163, Status::Mapped("iv")),
+    (0x2164, 0x2164, Status::Mapped("v")),
+    (0x2165, 0x2165, Status::Mapped("vi")),
+    (0x2166, 0x2166, Status::Mapped("vii")),
+    (0x2167, 0x2167, Status::Mapped("viii")),
+    (0x2168, 0x2168, Status::Mapped("ix")),
+    (0x2169, 0x2169, Status::Mapped("x")),
+    (0x216A, 0x216A, Status::Mapped("xi")),
+    (0x216B, 0x216B, Status::Mapped("xii")),
+    (0x216C, 0x216C, Status::Mapped("l")),
+    (0x216D, 0x216D, Status::Mapped("c")),
+    (0x216E, 0x216E, Status::Mapped("d")),
+    (0x216F, 0x216F, Status::Mapped("m")),
+    (0x2170, 0x2170, Status::Mapped("i")),
+    (0x2171, 0x2171, Status::Mapped("ii")),
+    (0x2172, 0x2172, Status::Mapped("iii")),
+    (0x2173, 0x2173, Status::Mapped("iv")),
+    (0x2174, 0x2174, Status::Mapped("v")),
+    (0x2175, 0x2175, Status::Mapped("vi")),
+    (0x2176, 0x2176, Status::Mapped("vii")),
+    (0x2177, 0x2177, Status::Mapped("viii")),
+    (0x2178, 0x2178, Status::Mapped("ix")),
+    (0x2179, 0x2179, Status::Mapped("x")),
+    (0x217A, 0x217A, Status::Mapped("xi")),
+    (0x217B, 0x217B, Status::Mapped("xii")),
+    (0x217C, 0x217C, Status::Mapped("l")),
+    (0x217D, 0x217D, Status::Mapped("c")),
+    (0x217E, 0x217E, Status::Mapped("d")),
+    (0x217F, 0x217F, Status::Mapped("m")),
+    (0x2180, 0x2182, Status::Valid),
+    (0x2183, 0x2183, Status::Disallowed),
+    (0x2184, 0x2188, Status::Valid),
+    (0x2189, 0x2189, Status::Mapped("0\u{2044}3")),
+    (0x218A, 0x218B, Status::Valid),
+    (0x218C, 0x218F, Status::Disallowed),
+    (0x2190, 0x222B, Status::Valid),
+    (0x222C, 0x222C, Status::Mapped("\u{222B}\u{222B}")),
+    (0x222D, 0x222D, Status::Mapped("\u{222B}\u{222B}\u{222B}")),
+    (0x222E, 0x222E, Status::Valid),
+    (0x222F, 0x222F, Status::Mapped("\u{222E}\u{222E}")),
+    (0x2230, 0x2230, Status::Mapped("\u{222E}\u{222E}\u{222E}")),
+    (0x2231, 0x2328, Status::Valid),
+    (0x2329, 0x2329, Status::Mapped("\u{3008}")),
+    (0x232A, 0x232A, Status::Mapped("\u{3009}")),
+    (0x232B, 0x2426, Status::Valid),
+    (0x2427, 0x243F, Status::Disallowed),
+    (0x2440, 0x244A, Status::Valid),
+    (0x244B, 0x245F, Status::Disallowed),
+    (0x2460, 0x2460, Status::Mapped("1")),
+    (0x2461, 0x2461, Status::Mapped("2")),
+    (0x2462, 0x2462, Status::Mapped("3")),
+    (0x2463, 0x2463, Status::Mapped("4")),
+    (0x2464, 0x2464, Status::Mapped("5")),
+    (0x2465, 0x2465, Status::Mapped("6")),
+    (0x2466, 0x2466, Status::Mapped("7")),
+    (0x2467, 0x2467, Status::Mapped("8")),
+    (0x2468, 0x2468, Status::Mapped("9")),
+    (0x2469, 0x2469, Status::Mapped("10")),
+    (0x246A, 0x246A, Status::Mapped("11")),
+    (0x246B, 0x246B, Status::Mapped("12")),
+    (0x246C, 0x246C, Status::Mapped("13")),
+    (0x246D, 0x246D, Status::Mapped("14")),
+    (0x246E, 0x246E, Status::Mapped("15")),
+    (0x246F, 0x246F, Status::Mapped("16")),
+    (0x2470, 0x2470, Status::Mapped("17")),
+    (0x2471, 0x2471, Status::Mapped("18")),
+    (0x2472, 0x2472, Status::Mapped("19")),
+    (0x2473, 0x2473, Status::Mapped("20")),
+    (0x2474, 0x2487, Status::Valid),
+    (0x2488, 0x249B, Status::Disallowed),
+    (0x249C, 0x24B5, Status::Valid),
+    (0x24B6, 0x24B6, Status::Mapped("a")),
+    (0x24B7, 0x24B7, Status::Mapped("b")),
+    (0x24B8, 0x24B8, Status::Mapped("c")),
+    (0x24B9, 0x24B9, Status::Mapped("d")),
+    (0x24BA, 0x24BA, Status::Mapped("e")),
+    (0x24BB, 0x24BB, Status::Mapped("f")),
+    (0x24BC, 0x24BC, Status::Mapped("g")),
+    (0x24BD, 0x24BD, Status::Mapped("h")),
+    (0x24BE, 0x24BE, Status::Mapped("i")),
+    (0x24BF, 0x24BF, Status::Mapped("j")),
+    (0x24C0, 0x24C0, Status::Mapped("k")),
+    (0x24C1, 0x24C1, Status::Mapped("l")),
+    (0x24C2, 0x24C2, Status::Mapped("m")),
+    (0x24C3, 0x24C3, Status::Mapped("n")),
+    (0x24C4, 0x24C4, Status::Mapped("o")),
+    (0x24C5, 0x24C5, Status::Mapped("p")),
+    (0x24C6, 0x24C6, Status::Mapped("q")),
+    (0x24C7, 0x24C7, Status::Mapped("r")),
+    (0x24C8, 0x24C8, Status::Mapped("s")),
+    (0x24C9, 0x24C9, Status::Mapped("t")),
+    (0x24CA, 0x24CA, Status::Mapped("u")),
+    (0x24CB, 0x24CB, Status::Mapped("v")),
+    (0x24CC, 0x24CC, Status::Mapped("w")),
+    (0x24CD, 0x24CD, Status::Mapped("x")),
+    (0x24CE, 0x24CE, Status::Mapped("y")),
+    (0x24CF, 0x24CF, Status::Mapped("z")),
+    (0x24D0, 0x24D0, Status::Mapped("a")),
+    (0x24D1, 0x24D1, Status::Mapped("b")),
+    (0x24D2, 0x24D2, Status::Mapped("c")),
+    (0x24D3, 0x24D3, Status::Mapped("d")),
+    (0x24D4, 0x24D4, Status::Mapped("e")),
+    (0x24D5, 0x24D5, Status::Mapped("f")),
+    (0x24D6, 0x24D6, Status::Mapped("g")),
+    (0x24D7, 0x24D7, Status::Mapped("h")),
+    (0x24D8, 0x24D8, Status::Mapped("i")),
+    (0x24D9, 0x24D9, Status::Mapped("j")),
+    (0x24DA, 0x24DA, Status::Mapped("k")),
+    (0x24DB, 0x24DB, Status::Mapped("l")),
+    (0x24DC, 0x24DC, Status::Mapped("m")),
+    (0x24DD, 0x24DD, Status::Mapped("n")),
+    (0x24DE, 0x24DE, Status::Mapped("o")),
+    (0x24DF, 0x24DF, Status::Mapped("p")),
+    (0x24E0, 0x24E0, Status::Mapped("q")),
+    (0x24E1, 0x24E1, Status::Mapped("r")),
+    (0x24E2, 0x24E2, Status::Mapped("s")),
+    (0x24E3, 0x24E3, Status::Mapped("t")),
+    (0x24E4, 0x24E4, Status::Mapped("u")),
+    (0x24E5, 0x24E5, Status::Mapped("v")),
+    (0x24E6, 0x24E6, Status::Mapped("w")),
+    (0x24E7, 0x24E7, Status::Mapped("x")),
+    (0x24E8, 0x24E8, Status::Mapped("y")),
+    (0x24E9, 0x24E9, Status::Mapped("z")),
+    (0x24EA, 0x24EA, Status::Mapped("0")),
+    (0x24EB, 0x2A0B, Status::Valid),
+    (0x2A0C, 0x2A0C, Status::Mapped("\u{222B}\u{222B}\u{222B}\u{222B}")),
+    (0x2A0D, 0x2ADB, Status::Valid),
+    (0x2ADC, 0x2ADC, Status::Mapped("\u{2ADD}\u{338}")),
+    (0x2ADD, 0x2B73, Status::Valid),
+    (0x2B74, 0x2B75, Status::Disallowed),
+    (0x2B76, 0x2B95, Status::Valid),
+    (0x2B96, 0x2B96, Status::Disallowed),
+    (0x2B97, 0x2BFF, Status::Valid),
+    (0x2C00, 0x2C00, Status::Mapped("\u{2C30}")),
+    (0x2C01, 0x2C01, Status::Mapped("\u{2C31}")),
+    (0x2C02, 0x2C02, Status::Mapped("\u{2C32}")),
+    (0x2C03, 0x2C03, Status::Mapped("\u{2C33}")),
+    (0x2C04, 0x2C04, Status::Mapped("\u{2C34}")),
+    (0x2C05, 0x2C05, Status::Mapped("\u{2C35}")),
+    (0x2C06, 0x2C06, Status::Mapped("\u{2C36}")),
+    (0x2C07, 0x2C07, Status::Mapped("\u{2C37}")),
+    (0x2C08, 0x2C08, Status::Mapped("\u{2C38}")),
+    (0x2C09, 0x2C09, Status::Mapped("\u{2C39}")),
+    (0x2C0A, 0x2C0A, Status::Mapped("\u{2C3A}")),
+    (0x2C0B, 0x2C0B, Status::Mapped("\u{2C3B}")),
+    (0x2C0C, 0x2C0C, Status::Mapped("\u{2C3C}")),
+    (0x2C0D, 0x2C0D, Status::Mapped("\u{2C3D}")),
+    (0x2C0E, 0x2C0E, Status::Mapped("\u{2C3E}")),
+    (0x2C0F, 0x2C0F, Status::Mapped("\u{2C3F}")),
+    (0x2C10, 0x2C10, Status::Mapped("\u{2C40}")),
+    (0x2C11, 0x2C11, Status::Mapped("\u{2C41}")),
+    (0x2C12, 0x2C12, Status::Mapped("\u{2C42}")),
+    (0x2C13, 0x2C13, Status::Mapped("\u{2C43}")),
+    (0x2C14, 0x2C14, Status::Mapped("\u{2C44}")),
+    (0x2C15, 0x2C15, Status::Mapped("\u{2C45}")),
+    (0x2C16, 0x2C16, Status::Mapped("\u{2C46}")),
+    (0x2C17, 0x2C17, Status::Mapped("\u{2C47}")),
+    (0x2C18, 0x2C18, Status::Mapped("\u{2C48}")),
+    (0x2C19, 0x2C19, Status::Mapped("\u{2C49}")),
+    (0x2C1A, 0x2C1A, Status::Mapped("\u{2C4A}")),
+    (0x2C1B, 0x2C1B, Status::Mapped("\u{2C4B}")),
+    (0x2C1C, 0x2C1C, Status::Mapped("\u{2C4C}")),
+    (0x2C1D, 0x2C1D, Status::Mapped("\u{2C4D}")),
+    (0x2C1E, 0x2C1E, Status::Mapped("\u{2C4E}")),
+    (0x2C1F, 0x2C1F, Status::Mapped("\u{2C4F}")),
+    (0x2C20, 0x2C20, Status::Mapped("\u{2C50}")),
+    (0x2C21, 0x2C21, Status::Mapped("\u{2C51}")),
+    (0x2C22, 0x2C22, Status::Mapped("\u{2C52}")),
+    (0x2C23, 0x2C23, Status::Mapped("\u{2C53}")),
+    (0x2C24, 0x2C24, Status::Mapped("\u{2C54}")),
+    (0x2C25, 0x2C25, Status::Mapped("\u{2C55}")),
+    (0x2C26, 0x2C26, Status::Mapped("\u{2C56}")),
+    (0x2C27, 0x2C27, Status::Mapped("\u{2C57}")),
+    (0x2C28, 0x2C28, Status::Mapped("\u{2C58}")),
+    (0x2C29, 0x2C29, Status::Mapped("\u{2C59}")),
+    (0x2C2A, 0x2C2A, Status::Mapped("\u{2C5A}")),
+    (0x2C2B, 0x2C2B, Status::Mapped("\u{2C5B}")),
+    (0x2C2C, 0x2C2C, Status::Mapped("\u{2C5C}")),
+    (0x2C2D, 0x2C2D, Status::Mapped("\u{2C5D}")),
+    (0x2C2E, 0x2C2E, Status::Mapped("\u{2C5E}")),
+    (0x2C2F, 0x2C2F, Status::Mapped("\u{2C5F}")),
+    (0x2C30, 0x2C5F, Status::Valid),
+    (0x2C60, 0x2C60, Status::Mapped("\u{2C61}")),
+    (0x2C61, 0x2C61, Status::Valid),
+    (0x2C62, 0x2C62, Status::Mapped("\u{26B}")),
+    (0x2C63, 0x2C63, Status::Mapped("\u{1D7D}")),
+    (0x2C64, 0x2C64, Status::Mapped("\u{27D}")),
+    (0x2C65, 0x2C66, Status::Valid),
+    (0x2C67, 0x2C67, Status::Mapped("\u{2C68}")),
+    (0x2C68, 0x2C68, Status::Valid),
+    (0x2C69, 0x2C69, Status::Mapped("\u{2C6A}")),
+    (0x2C6A, 0x2C6A, Status::Valid),
+    (0x2C6B, 0x2C6B, Status::Mapped("\u{2C6C}")),
+    (0x2C6C, 0x2C6C, Status::Valid),
+    (0x2C6D, 0x2C6D, Status::Mapped("\u{251}")),
+    (0x2C6E, 0x2C6E, Status::Mapped("\u{271}")),
+    (0x2C6F, 0x2C6F, Status::Mapped("\u{250}")),
+    (0x2C70, 0x2C70, Status::Mapped("\u{252}")),
+    (0x2C71, 0x2C71, Status::Valid),
+    (0x2C72, 0x2C72, Status::Mapped("\u{2C73}")),
+    (0x2C73, 0x2C74, Status::Valid),
+    (0x2C75, 0x2C75, Status::Mapped("\u{2C76}")),
+    (0x2C76, 0x2C7B, Status::Valid),
+    (0x2C7C, 0x2C7C, Status::Mapped("j")),
+    (0x2C7D, 0x2C7D, Status::Mapped("v")),
+    (0x2C7E, 0x2C7E, Status::Mapped("\u{23F}")),
+    (0x2C7F, 0x2C7F, Status::Mapped("\u{240}")),
+    (0x2C80, 0x2C80, Status::Mapped("\u{2C81}")),
+    (0x2C81, 0x2C81, Status::Valid),
+    (0x2C82, 0x2C82, Status::Mapped("\u{2C83}")),
+    (0x2C83, 0x2C83, Status::Valid),
+    (0x2C84, 0x2C84, Status::Mapped("\u{2C85}")),
+    (0x2C85, 0x2C85, Status::Valid),
+    (0x2C86, 0x2C86, Status::Mapped("\u{2C87}")),
+    (0x2C87, 0x2C87, Status::Valid),
+    (0x2C88, 0x2C88, Status::Mapped("\u{2C89}")),
+    (0x2C89, 0x2C89, Status::Valid),
+    (0x2C8A, 0x2C8A, Status::Mapped("\u{2C8B}")),
+    (0x2C8B, 0x2C8B, Status::Valid),
+    (0x2C8C, 0x2C8C, Status::Mapped("\u{2C8D}")),
+    (0x2C8D, 0x2C8D, Status::Valid),
+    (0x2C8E, 0x2C8E, Status::Mapped("\u{2C8F}")),
+    (0x2C8F, 0x2C8F, Status::Valid),
+    (0x2C90, 0x2C90, Status::Mapped("\u{2C91}")),
+    (0x2C91, 0x2C91, Status::Valid),
+    (0x2C92, 0x2C92, Status::Mapped("\u{2C93}")),
+    (0x2C93, 0x2C93, Status::Valid),
+    (0x2C94, 0x2C94, Status::Mapped("\u{2C95}")),
+    (0x2C95, 0x2C95, Status::Valid),
+    (0x2C96, 0x2C96, Status::Mapped("\u{2C97}")),
+    (0x2C97, 0x2C97, Status::Valid),
+    (0x2C98, 0x2C98, Status::Mapped("\u{2C99}")),
+    (0x2C99, 0x2C99, Status::Valid),
+    (0x2C9A, 0x2C9A, Status::Mapped("\u{2C9B}")),
+    (0x2C9B, 0x2C9B, Status::Valid),
+    (0x2C9C, 0x2C9C, Status::Mapped("\u{2C9D}")),
+    (0x2C9D, 0x2C9D, Status::Valid),
+    (0x2C9E, 0x2C9E, Status::Mapped("\u{2C9F}")),
+    (0x2C9F, 0x2C9F, Status::Valid),
+    (0x2CA0, 0x2CA0, Status::Mapped("\u{2CA1}")),
+    (0x2CA1, 0x2CA1, Status::Valid),
+    (0x2CA2, 0x2CA2, Status::Mapped("\u{2CA3}")),
+    (0x2CA3, 0x2CA3, Status::Valid),
+    (0x2CA4, 0x2CA4, Status::Mapped("\u{2CA5}")),
+    (0x2CA5, 0x2CA5, Status::Valid),
+    (0x2CA6, 0x2CA6, Status::Mapped("\u{2CA7}")),
+    (0x2CA7, 0x2CA7, Status::Valid),
+    (0x2CA8, 0x2CA8, Status::Mapped("\u{2CA9}")),
+    (0x2CA9, 0x2CA9, Status::Valid),
+    (0x2CAA, 0x2CAA, Status::Mapped("\u{2CAB}")),
+    (0x2CAB, 0x2CAB, Status::Valid),
+    (0x2CAC, 0x2CAC, Status::Mapped("\u{2CAD}")),
+    (0x2CAD, 0x2CAD, Status::Valid),
+    (0x2CAE, 0x2CAE, Status::Mapped("\u{2CAF}")),
+    (0x2CAF, 0x2CAF, Status::Valid),
+    (0x2CB0, 0x2CB0, Status::Mapped("\u{2CB1}")),
+    (0x2CB1, 0x2CB1, Status::Valid),
+    (0x2CB2, 0x2CB2, Status::Mapped("\u{2CB3}")),
+    (0x2CB3, 0x2CB3, Status::Valid),
+    (0x2CB4, 0x2CB4, Status::Mapped("\u{2CB5}")),
+    (0x2CB5, 0x2CB5, Status::Valid),
+    (0x2CB6, 0x2CB6, Status::Mapped("\u{2CB7}")),
+    (0x2CB7, 0x2CB7, Status::Valid),
+    (0x2CB8, 0x2CB8, Status::Mapped("\u{2CB9}")),
+    (0x2CB9, 0x2CB9, Status::Valid),
+    (0x2CBA, 0x2CBA, Status::Mapped("\u{2CBB}")),
+    (0x2CBB, 0x2CBB, Status::Valid),
+    (0x2CBC, 0x2CBC, Status::Mapped("\u{2CBD}")),
+    (0x2CBD, 0x2CBD, Status::Valid),
+    (0x2CBE, 0x2CBE, Status::Mapped("\u{2CBF}")),
+    (0x2CBF, 0x2CBF, Status::Valid),
+    (0x2CC0, 0x2CC0, Status::Mapped("\u{2CC1}")),
+    (0x2CC1, 0x2CC1, Status::Valid),
+    (0x2CC2, 0x2CC2, Status::Mapped("\u{2CC3}")),
+    (0x2CC3, 0x2CC3, Status::Valid),
+    (0x2CC4, 0x2CC4, Status::Mapped("\u{2CC5}")),
+    (0x2CC5, 0x2CC5, Status::Valid),
+    (0x2CC6, 0x2CC6, Status::Mapped("\u{2CC7}")),
+    (0x2CC7, 0x2CC7, Status::Valid),
+    (0x2CC8, 0x2CC8, Status::Mapped("\u{2CC9}")),
+    (0x2CC9, 0x2CC9, Status::Valid),
+    (0x2CCA, 0x2CCA, Status::Mapped("\u{2CCB}")),
+    (0x2CCB, 0x2CCB, Status::Valid),
+    (0x2CCC, 0x2CCC, Status::Mapped("\u{2CCD}")),
+    (0x2CCD, 0x2CCD, Status::Valid),
+    (0x2CCE, 0x2CCE, Status::Mapped("\u{2CCF}")),
+    (0x2CCF, 0x2CCF, Status::Valid),
+    (0x2CD0, 0x2CD0, Status::Mapped("\u{2CD1}")),
+    (0x2CD1, 0x2CD1, Status::Valid),
+    (0x2CD2, 0x2CD2, Status::Mapped("\u{2CD3}")),
+    (0x2CD3, 0x2CD3, Status::Valid),
+    (0x2CD4, 0x2CD4, Status::Mapped("\u{2CD5}")),
+    (0x2CD5, 0x2CD5, Status::Valid),
+    (0x2CD6, 0x2CD6, Status::Mapped("\u{2CD7}")),
+    (0x2CD7, 0x2CD7, Status::Valid),
+    (0x2CD8, 0x2CD8, Status::Mapped("\u{2CD9}")),
+    (0x2CD9, 0x2CD9, Status::Valid),
+    (0x2CDA, 0x2CDA, Status::Mapped("\u{2CDB}")),
+    (0x2CDB, 0x2CDB, Status::Valid),
+    (0x2CDC, 0x2CDC, Status::Mapped("\u{2CDD}")),
+    (0x2CDD, 0x2CDD, Status::Valid),
+    (0x2CDE, 0x2CDE, Status::Mapped("\u{2CDF}")),
+    (0x2CDF, 0x2CDF, Status::Valid),
+    (0x2CE0, 0x2CE0, Status::Mapped("\u{2CE1}")),
+    (0x2CE1, 0x2CE1, Status::Valid),
+    (0x2CE2, 0x2CE2, Status::Mapped("\u{2CE3}")),
+    (0x2CE3, 0x2CEA, Status::Valid),
+    (0x2CEB, 0x2CEB, Status::Mapped("\u{2CEC}")),
+    (0x2CEC, 0x2CEC, Status::Valid),
+    (0x2CED, 0x2CED, Status::Mapped("\u{2CEE}")),
+    (0x2CEE, 0x2CF1, Status::Valid),
+    (0x2CF2, 0x2CF2, Status::Mapped("\u{2CF3}")),
+    (0x2CF3, 0x2CF3, Status::Valid),
+    (0x2CF4, 0x2CF8, Status::Disallowed),
+    (0x2CF9, 0x2D25, Status::Valid),
+    (0x2D26, 0x2D26, Status::Disallowed),
+    (0x2D27, 0x2D27, Status::Valid),
+    (0x2D28, 0x2D2C, Status::Disallowed),
+    (0x2D2D, 0x2D2D, Status::Valid),
+    (0x2D2E, 0x2D2F, Status::Disallowed),
+    (0x2D30, 0x2D67, Status::Valid),
+    (0x2D68, 0x2D6E, Status::Disallowed),
+    (0x2D6F, 0x2D6F, Status::Mapped("\u{2D61}")),
+    (0x2D70, 0x2D70, Status::Valid),
+    (0x2D71, 0x2D7E, Status::Disallowed),
+    (0x2D7F, 0x2D96, Status::Valid),
+    (0x2D97, 0x2D9F, Status::Disallowed),
+    (0x2DA0, 0x2DA6, Status::Valid),
+    (0x2DA7, 0x2DA7, Status::Disallowed),
+    (0x2DA8, 0x2DAE, Status::Valid),
+    (0x2DAF, 0x2DAF, Status::Disallowed),
+    (0x2DB0, 0x2DB6, Status::Valid),
+    (0x2DB7, 0x2DB7, Status::Disallowed),
+    (0x2DB8, 0x2DBE, Status::Valid),
+    (0x2DBF, 0x2DBF, Status::Disallowed),
+    (0x2DC0, 0x2DC6, Status::Valid),
+    (0x2DC7, 0x2DC7, Status::Disallowed),
+    (0x2DC8, 0x2DCE, Status::Valid),
+    (0x2DCF, 0x2DCF, Status::Disallowed),
+    (0x2DD0, 0x2DD6, Status::Valid),
+    (0x2DD7, 0x2DD7, Status::Disallowed),
+    (0x2DD8, 0x2DDE, Status::Valid),
+    (0x2DDF, 0x2DDF, Status::Disallowed),
+    (0x2DE0, 0x2E5D, Status::Valid),
+    (0x2E5E, 0x2E7F, Status::Disallowed),
+    (0x2E80, 0x2E99, Status::Valid),
+    (0x2E9A, 0x2E9A, Status::Disallowed),
+    (0x2E9B, 0x2E9E, Status::Valid),
+    (0x2E9F, 0x2E9F, Status::Mapped("\u{6BCD}")),
+    (0x2EA0, 0x2EF2, Status::Valid),
+    (0x2EF3, 0x2EF3, Status::Mapped("\u{9F9F}")),
+    (0x2EF4, 0x2EFF, Status::Disallowed),
+    (0x2F00, 0x2F00, Status::Mapped("\u{4E00}")),
+    (0x2F01, 0x2F01, Status::Mapped("\u{4E28}")),
+    (0x2F02, 0x2F02, Status::Mapped("\u{4E36}")),
+    (0x2F03, 0x2F03, Status::Mapped("\u{4E3F}")),
+    (0x2F04, 0x2F04, Status::Mapped("\u{4E59}")),
+    (0x2F05, 0x2F05, Status::Mapped("\u{4E85}")),
+    (0x2F06, 0x2F06, Status::Mapped("\u{4E8C}")),
+    (0x2F07, 0x2F07, Status::Mapped("\u{4EA0}")),
+    (0x2F08, 0x2F08, Status::Mapped("\u{4EBA}")),
+    (0x2F09, 0x2F09, Status::Mapped("\u{513F}")),
+    (0x2F0A, 0x2F0A, Status::Mapped("\u{5165}")),
+    (0x2F0B, 0x2F0B, Status::Mapped("\u{516B}")),
+    (0x2F0C, 0x2F0C, Status::Mapped("\u{5182}")),
+    (0x2F0D, 0x2F0D, Status::Mapped("\u{5196}")),
+    (0x2F0E, 0x2F0E, Status::Mapped("\u{51AB}")),
+    (0x2F0F, 0x2F0F, Status::Mapped("\u{51E0}")),
+    (0x2F10, 0x2F10, Status::Mapped("\u{51F5}")),
+    (0x2F11, 0x2F11, Status::Mapped("\u{5200}")),
+    (0x2F12, 0x2F12, Status::Mapped("\u{529B}")),
+    (0x2F13, 0x2F13, Status::Mapped("\u{52F9}")),
+    (0x2F14, 0x2F14, Status::Mapped("\u{5315}")),
+    (0x2F15, 0x2F15, Status::Mapped("\u{531A}")),
+    (0x2F16, 0x2F16, Status::Mapped("\u{5338}")),
+    (0x2F17, 0x2F17, Status::Mapped("\u{5341}")),
+    (0x2F18, 0x2F18, Status::Mapped("\u{535C}")),
+    (0x2F19, 0x2F19, Status::Mapped("\u{5369}")),
+    (0x2F1A, 0x2F1A, Status::Mapped("\u{5382}")),
+    (0x2F1B, 0x2F1B, Status::Mapped("\u{53B6}")),
+    (0x2F1C, 0x2F1C, Status::Mapped("\u{53C8}")),
+    (0x2F1D, 0x2F1D, Status::Mapped("\u{53E3}")),
+    (0x2F1E, 0x2F1E, Status::Mapped("\u{56D7}")),
+    (0x2F1F, 0x2F1F, Status::Mapped("\u{571F}")),
+    (0x2F20, 0x2F20, Status::Mapped("\u{58EB}")),
+    (0x2F21, 0x2F21, Status::Mapped("\u{5902}")),
+    (0x2F22, 0x2F22, Status::Mapped("\u{590A}")),
+    (0x2F23, 0x2F23, Status::Mapped("\u{5915}")),
+    (0x2F24, 0x2F24, Status::Mapped("\u{5927}")),
+    (0x2F25, 0x2F25, Status::Mapped("\u{5973}")),
+    (0x2F26, 0x2F26, Status::Mapped("\u{5B50}")),
+    (0x2F27, 0x2F27, Status::Mapped("\u{5B80}")),
+    (0x2F28, 0x2F28, Status::Mapped("\u{5BF8}")),
+    (0x2F29, 0x2F29, Status::Mapped("\u{5C0F}")),
+    (0x2F2A, 0x2F2A, Status::Mapped("\u{5C22}")),
+    (0x2F2B, 0x2F2B, Status::Mapped("\u{5C38}")),
+    (0x2F2C, 0x2F2C, Status::Mapped("\u{5C6E}")),
+    (0x2F2D, 0x2F2D, Status::Mapped("\u{5C71}")),
+    (0x2F2E, 0x2F2E, Status::Mapped("\u{5DDB}")),
+    (0x2F2F, 0x2F2F, Status::Mapped("\u{5DE5}")),
+    (0x2F30, 0x2F30, Status::Mapped("\u{5DF1}")),
+    (0x2F31, 0x2F31, Status::Mapped("\u{5DFE}")),
+    (0x2F32, 0x2F32, Status::Mapped("\u{5E72}")),
+    (0x2F33, 0x2F33, Status::Mapped("\u{5E7A}")),
+    (0x2F34, 0x2F34, Status::Mapped("\u{5E7F}")),
+    (0x2F35, 0x2F35, Status::Mapped("\u{5EF4}")),
+    (0x2F36, 0x2F36, Status::Mapped("\u{5EFE}")),
+    (0x2F37, 0x2F37, Status::Mapped("\u{5F0B}")),
+    (0x2F38, 0x2F38, Status::Mapped("\u{5F13}")),
+    (0x2F39, 0x2F39, Status::Mapped("\u{5F50}")),
+    (0x2F3A, 0x2F3A, Status::Mapped("\u{5F61}")),
+    (0x2F3B, 0x2F3B, Status::Mapped("\u{5F73}")),
+    (0x2F3C, 0x2F3C, Status::Mapped("\u{5FC3}")),
+    (0x2F3D, 0x2F3D, Status::Mapped("\u{6208}")),
+    (0x2F3E, 0x2F3E, Status::Mapped("\u{6236}")),
+    (0x2F3F, 0x2F3F, Status::Mapped("\u{624B}")),
+    (0x2F40, 0x2F40, Status::Mapped("\u{652F}")),
+    (0x2F41, 0x2F41, Status::Mapped("\u{6534}")),
+    (0x2F42, 0x2F42, Status::Mapped("\u{6587}")),
+    (0x2F43, 0x2F43, Status::Mapped("\u{6597}")),
+    (0x2F44, 0x2F44, Status::Mapped("\u{65A4}")),
+    (0x2F45, 0x2F45, Status::Mapped("\u{65B9}")),
+    (0x2F46, 0x2F46, Status::Mapped("\u{65E0}")),
+    (0x2F47, 0x2F47, Status::Mapped("\u{65E5}")),
+    (0x2F48, 0x2F48, Status::Mapped("\u{66F0}")),
+    (0x2F49, 0x2F49, Status::Mapped("\u{6708}")),
+    (0x2F4A, 0x2F4A, Status::Mapped("\u{6728}")),
+    (0x2F4B, 0x2F4B, Status::Mapped("\u{6B20}")),
+    (0x2F4C, 0x2F4C, Status::Mapped("\u{6B62}")),
+    (0x2F4D, 0x2F4D, Status::Mapped("\u{6B79}")),
+    (0x2F4E, 0x2F4E, Status::Mapped("\u{6BB3}")),
+    (0x2F4F, 0x2F4F, Status::Mapped("\u{6BCB}")),
+    (0x2F50, 0x2F50, Status::Mapped("\u{6BD4}")),
+    (0x2F51, 0x2F51, Status::Mapped("\u{6BDB}")),
+    (0x2F52, 0x2F52, Status::Mapped("\u{6C0F}")),
+    (0x2F53, 0x2F53, Status::Mapped("\u{6C14}")),
+    (0x2F54, 0x2F54, Status::Mapped("\u{6C34}")),
+    (0x2F55, 0x2F55, Status::Mapped("\u{706B}")),
+    (0x2F56, 0x2F56, Status::Mapped("\u{722A}")),
+    (0x2F57, 0x2F57, Status::Mapped("\u{7236}")),
+    (0x2F58, 0x2F58, Status::Mapped("\u{723B}")),
+    (0x2F59, 0x2F59, Status::Mapped("\u{723F}")),
+    (0x2F5A, 0x2F5A, Status::Mapped("\u{7247}")),
+    (0x2F5B, 0x2F5B, Status::Mapped("\u{7259}")),
+    (0x2F5C, 0x2F5C, Status::Mapped("\u{725B}")),
+    (0x2F5D, 0x2F5D, Status::Mapped("\u{72AC}")),
+    (0x2F5E, 0x2F5E, Status::Mapped("\u{7384}")),
+    (0x2F5F, 0x2F5F, Status::Mapped("\u{7389}")),
+    (0x2F60, 0x2F60, Status::Mapped("\u{74DC}")),
+    (0x2F61, 0x2F61, Status::Mapped("\u{74E6}")),
+    (0x2F62, 0x2F62, Status::Mapped("\u{7518}")),
+    (0x2F63, 0x2F63, Status::Mapped("\u{751F}")),
+    (0x2F64, 0x2F64, Status::Mapped("\u{7528}")),
+    (0x2F65, 0x2F65, Status::Mapped("\u{7530}")),
+    (0x2F66, 0x2F66, Status::Mapped("\u{758B}")),
+    (0x2F67, 0x2F67, Status::Mapped("\u{7592}")),
+    (0x2F68, 0x2F68, Status::Mapped("\u{7676}")),
+    (0x2F69, 0x2F69, Status::Mapped("\u{767D}")),
+    (0x2F6A, 0x2F6A, Status::Mapped("\u{76AE}")),
+    (0x2F6B, 0x2F6B, Status::Mapped("\u{76BF}")),
+    (0x2F6C, 0x2F6C, Status::Mapped("\u{76EE}")),
+    (0x2F6D, 0x2F6D, Status::Mapped("\u{77DB}")),
+    (0x2F6E, 0x2F6E, Status::Mapped("\u{77E2}")),
+    (0x2F6F, 0x2F6F, Status::Mapped("\u{77F3}")),
+    (0x2F70, 0x2F70, Status::Mapped("\u{793A}")),
+    (0x2F71, 0x2F71, Status::Mapped("\u{79B8}")),
+    (0x2F72, 0x2F72, Status::Mapped("\u{79BE}")),
+    (0x2F73, 0x2F73, Status::Mapped("\u{7A74}")),
+    (0x2F74, 0x2F74, Status::Mapped("\u{7ACB}")),
+    (0x2F75, 0x2F75, Status::Mapped("\u{7AF9}")),
+    (0x2F76, 0x2F76, Status::Mapped("\u{7C73}")),
+    (0x2F77, 0x2F77, Status::Mapped("\u{7CF8}")),
+    (0x2F78, 0x2F78, Status::Mapped("\u{7F36}")),
+    (0x2F79, 0x2F79, Status::Mapped("\u{7F51}")),
+    (0x2F7A, 0x2F7A, Status::Mapped("\u{7F8A}")),
+    (0x2F7B, 0x2F7B, Status::Mapped("\u{7FBD}")),
+    (0x2F7C, 0x2F7C, Status::Mapped("\u{8001}")),
+    (0x2F7D, 0x2F7D, Status::Mapped("\u{800C}")),
+    (0x2F7E, 0x2F7E, Status::Mapped("\u{8012}")),
+    (0x2F7F, 0x2F7F, Status::Mapped("\u{8033}")),
+    (0x2F80, 0x2F80, Status::Mapped("\u{807F}")),
+    (0x2F81, 0x2F81, Status::Mapped("\u{8089}")),
+    (0x2F82, 0x2F82, Status::Mapped("\u{81E3}")),
+    (0x2F83, 0x2F83, Status::Mapped("\u{81EA}")),
+    (0x2F84, 0x2F84, Status::Mapped("\u{81F3}")),
+    (0x2F85, 0x2F85, Status::Mapped("\u{81FC}")),
+    (0x2F86, 0x2F86, Status::Mapped("\u{820C}")),
+    (0x2F87, 0x2F87, Status::Mapped("\u{821B}")),
+    (0x2F88, 0x2F88, Status::Mapped("\u{821F}")),
+    (0x2F89, 0x2F89, Status::Mapped("\u{826E}")),
+    (0x2F8A, 0x2F8A, Status::Mapped("\u{8272}")),
+    (0x2F8B, 0x2F8B, Status::Mapped("\u{8278}")),
+    (0x2F8C, 0x2F8C, Status::Mapped("\u{864D}")),
+    (0x2F8D, 0x2F8D, Status::Mapped("\u{866B}")),
+    (0x2F8E, 0x2F8E, Status::Mapped("\u{8840}")),
+    (0x2F8F, 0x2F8F, Status::Mapped("\u{884C}")),
+    (0x2F90, 0x2F90, Status::Mapped("\u{8863}")),
+    (0x2F91, 0x2F91, Status::Mapped("\u{897E}")),
+    (0x2F92, 0x2F92, Status::Mapped("\u{898B}")),
+    (0x2F93, 0x2F93, Status::Mapped("\u{89D2}")),
+    (0x2F94, 0x2F94, Status::Mapped("\u{8A00}")),
+    (0x2F95, 0x2F95, Status::Mapped("\u{8C37}")),
+    (0x2F96, 0x2F96, Status::Mapped("\u{8C46}")),
+    (0x2F97, 0x2F97, Status::Mapped("\u{8C55}")),
+    (0x2F98, 0x2F98, Status::Mapped("\u{8C78}")),
+    (0x2F99, 0x2F99, Status::Mapped("\u{8C9D}")),
+    (0x2F9A, 0x2F9A, Status::Mapped("\u{8D64}")),
+    (0x2F9B, 0x2F9B, Status::Mapped("\u{8D70}")),
+    (0x2F9C, 0x2F9C, Status::Mapped("\u{8DB3}")),
+    (0x2F9D, 0x2F9D, Status::Mapped("\u{8EAB}")),
+    (0x2F9E, 0x2F9E, Status::Mapped("\u{8ECA}")),
+    (0x2F9F, 0x2F9F, Status::Mapped("\u{8F9B}")),
+    (0x2FA0, 0x2FA0, Status::Mapped("\u{8FB0}")),
+    (0x2FA1, 0x2FA1, Status::Mapped("\u{8FB5}")),
+    (0x2FA2, 0x2FA2, Status::Mapped("\u{9091}")),
+    (0x2FA3, 0x2FA3, Status::Mapped("\u{9149}")),
+    (0x2FA4, 0x2FA4, Status::Mapped("\u{91C6}")),
+    (0x2FA5, 0x2FA5, Status::Mapped("\u{91CC}")),
+    (0x2FA6, 0x2FA6, Status::Mapped("\u{91D1}")),
+    (0x2FA7, 0x2FA7, Status::Mapped("\u{9577}")),
+    (0x2FA8, 0x2FA8, Status::Mapped("\u{9580}")),
+    (0x2FA9, 0x2FA9, Status::Mapped("\u{961C}")),
+    (0x2FAA, 0x2FAA, Status::Mapped("\u{96B6}")),
+    (0x2FAB, 0x2FAB, Status::Mapped("\u{96B9}")),
+    (0x2FAC, 0x2FAC, Status::Mapped("\u{96E8}")),
+    (0x2FAD, 0x2FAD, Status::Mapped("\u{9751}")),
+    (0x2FAE, 0x2FAE, Status::Mapped("\u{975E}")),
+    (0x2FAF, 0x2FAF, Status::Mapped("\u{9762}")),
+    (0x2FB0, 0x2FB0, Status::Mapped("\u{9769}")),
+    (0x2FB1, 0x2FB1, Status::Mapped("\u{97CB}")),
+    (0x2FB2, 0x2FB2, Status::Mapped("\u{97ED}")),
+    (0x2FB3, 0x2FB3, Status::Mapped("\u{97F3}")),
+    (0x2FB4, 0x2FB4, Status::Mapped("\u{9801}")),
+    (0x2FB5, 0x2FB5, Status::Mapped("\u{98A8}")),
+    (0x2FB6, 0x2FB6, Status::Mapped("\u{98DB}")),
+    (0x2FB7, 0x2FB7, Status::Mapped("\u{98DF}")),
+    (0x2FB8, 0x2FB8, Status::Mapped("\u{9996}")),
+    (0x2FB9, 0x2FB9, Status::Mapped("\u{9999}")),
+    (0x2FBA, 0x2FBA, Status::Mapped("\u{99AC}")),
+    (0x2FBB, 0x2FBB, Status::Mapped("\u{9AA8}")),
+    (0x2FBC, 0x2FBC, Status::Mapped("\u{9AD8}")),
+    (0x2FBD, 0x2FBD, Status::Mapped("\u{9ADF}")),
+    (0x2FBE, 0x2FBE, Status::Mapped("\u{9B25}")),
+    (0x2FBF, 0x2FBF, Status::Mapped("\u{9B2F}")),
+    (0x2FC0, 0x2FC0, Status::Mapped("\u{9B32}")),
+    (0x2FC1, 0x2FC1, Status::Mapped("\u{9B3C}")),
+    (0x2FC2, 0x2FC2, Status::Mapped("\u{9B5A}")),
+    (0x2FC3, 0x2FC3, Status::Mapped("\u{9CE5}")),
+    (0x2FC4, 0x2FC4, Status::Mapped("\u{9E75}")),
+    (0x2FC5, 0x2FC5, Status::Mapped("\u{9E7F}")),
+    (0x2FC6, 0x2FC6, Status::Mapped("\u{9EA5}")),
+    (0x2FC7, 0x2FC7, Status::Mapped("\u{9EBB}")),
+    (0x2FC8, 0x2FC8, Status::Mapped("\u{9EC3}")),
+    (0x2FC9, 0x2FC9, Status::Mapped("\u{9ECD}")),
+    (0x2FCA, 0x2FCA, Status::Mapped("\u{9ED1}")),
+    (0x2FCB, 0x2FCB, Status::Mapped("\u{9EF9}")),
+    (0x2FCC, 0x2FCC, Status::Mapped("\u{9EFD}")),
+    (0x2FCD, 0x2FCD, Status::Mapped("\u{9F0E}")),
+    (0x2FCE, 0x2FCE, Status::Mapped("\u{9F13}")),
+    (0x2FCF, 0x2FCF, Status::Mapped("\u{9F20}")),
+    (0x2FD0, 0x2FD0, Status::Mapped("\u{9F3B}")),
+    (0x2FD1, 0x2FD1, Status::Mapped("\u{9F4A}")),
+    (0x2FD2, 0x2FD2, Status::Mapped("\u{9F52}")),
+    (0x2FD3, 0x2FD3, Status::Mapped("\u{9F8D}")),
+    (0x2FD4, 0x2FD4, Status::Mapped("\u{9F9C}")),
+    (0x2FD5, 0x2FD5, Status::Mapped("\u{9FA0}")),
+    (0x2FD6, 0x2FFF, Status::Disallowed),
+    (0x3000, 0x3001, Status::Valid),
+    (0x3002, 0x3002, Status::Mapped(".")),
+    (0x3003, 0x3035, Status::Valid),
+    (0x3036, 0x3036, Status::Mapped("\u{3012}")),
+    (0x3037, 0x3037, Status::Valid),
+    (0x3038, 0x3038, Status::Mapped("\u{5341}")),
+    (0x3039, 0x3039, Status::Mapped("\u{5344}")),
+    (0x303A, 0x303A, Status::Mapped("\u{5345}")),
+    (0x303B, 0x303F, Status::Valid),
+    (0x3040, 0x3040, Status::Disallowed),
+    (0x3041, 0x3096, Status::Valid),
+    (0x3097, 0x3098, Status::Disallowed),
+    (0x3099, 0x309E, Status::Valid),
+    (0x309F, 0x309F, Status::Mapped("\u{3088}\u{308A}")),
+    (0x30A0, 0x30FE, Status::Valid),
+    (0x30FF, 0x30FF, Status::Mapped("\u{30B3}\u{30C8}")),
+    (0x3100, 0x3104, Status::Disallowed),
+    (0x3105, 0x312F, Status::Valid),
+    (0x3130, 0x3130, Status::Disallowed),
+    (0x3131, 0x3131, Status::Mapped("\u{1100}")),
+    (0x3132, 0x3132, Status::Mapped("\u{1101}")),
+    (0x3133, 0x3133, Status::Mapped("\u{11AA}")),
+    (0x3134, 0x3134, Status::Mapped("\u{1102}")),
+    (0x3135, 0x3135, Status::Mapped("\u{11AC}")),
+    (0x3136, 0x3136, Status::Mapped("\u{11AD}")),
+    (0x3137, 0x3137, Status::Mapped("\u{1103}")),
+    (0x3138, 0x3138, Status::Mapped("\u{1104}")),
+    (0x3139, 0x3139, Status::Mapped("\u{1105}")),
+    (0x313A, 0x313A, Status::Mapped("\u{11B0}")),
+    (0x313B, 0x313B, Status::Mapped("\u{11B1}")),
+    (0x313C, 0x313C, Status::Mapped("\u{11B2}")),
+    (0x313D, 0x313D, Status::Mapped("\u{11B3}")),
+    (0x313E, 0x313E, Status::Mapped("\u{11B4}")),
+    (0x313F, 0x313F, Status::Mapped("\u{11B5}")),
+    (0x3140, 0x3140, Status::Mapped("\u{111A}")),
+    (0x3141, 0x3141, Status::Mapped("\u{1106}")),
+    (0x3142, 0x3142, Status::Mapped("\u{1107}")),
+    (0x3143, 0x3143, Status::Mapped("\u{1108}")),
+    (0x3144, 0x3144, Status::Mapped("\u{1121}")),
+    (0x3145, 0x3145, Status::Mapped("\u{1109}")),
+    (0x3146, 0x3146, Status::Mapped("\u{110A}")),
+    (0x3147, 0x3147, Status::Mapped("\u{110B}")),
+    (0x3148, 0x3148, Status::Mapped("\u{110C}")),
+    (0x3149, 0x3149, Status::Mapped("\u{110D}")),
+    (0x314A, 0x314A, Status::Mapped("\u{110E}")),
+    (0x314B, 0x314B, Status::Mapped("\u{110F}")),
+    (0x314C, 0x314C, Status::Mapped("\u{1110}")),
+    (0x314D, 0x314D, Status::Mapped("\u{1111}")),
+    (0x314E, 0x314E, Status::Mapped("\u{1112}")),
+    (0x314F, 0x314F, Status::Mapped("\u{1161}")),
+    (0x3150, 0x3150, Status::Mapped("\u{1162}")),
+    (0x3151, 0x3151, Status::Mapped("\u{1163}")),
+    (0x3152, 0x3152, Status::Mapped("\u{1164}")),
+    (0x3153, 0x3153, Status::Mapped("\u{1165}")),
+    (0x3154, 0x3154, Status::Mapped("\u{1166}")),
+    (0x3155, 0x3155, Status::Mapped("\u{1167}")),
+    (0x3156, 0x3156, Status::Mapped("\u{1168}")),
+    (0x3157, 0x3157, Status::Mapped("\u{1169}")),
+    (0x3158, 0x3158, Status::Mapped("\u{116A}")),
+    (0x3159, 0x3159, Status::Mapped("\u{116B}")),
+    (0x315A, 0x315A, Status::Mapped("\u{116C}")),
+    (0x315B, 0x315B, Status::Mapped("\u{116D}")),
+    (0x315C, 0x315C, Status::Mapped("\u{116E}")),
+    (0x315D, 0x315D, Status::Mapped("\u{116F}")),
+    (0x315E, 0x315E, Status::Mapped("\u{1170}")),
+    (0x315F, 0x315F, Status::Mapped("\u{1171}")),
+    (0x3160, 0x3160, Status::Mapped("\u{1172}")),
+    (0x3161, 0x3161, Status::Mapped("\u{1173}")),
+    (0x3162, 0x3162, Status::Mapped("\u{1174}")),
+    (0x3163, 0x3163, Status::Mapped("\u{1175}")),
+    (0x3164, 0x3164, Status::Disallowed),
+    (0x3165, 0x3165, Status::Mapped("\u{1114}")),
+    (0x3166, 0x3166, Status::Mapped("\u{1115}")),
+    (0x3167, 0x3167, Status::Mapped("\u{11C7}")),
+    (0x3168, 0x3168, Status::Mapped("\u{11C8}")),
+    (0x3169, 0x3169, Status::Mapped("\u{11CC}")),
+    (0x316A, 0x316A, Status::Mapped("\u{11CE}")),
+    (0x316B, 0x316B, Status::Mapped("\u{11D3}")),
+    (0x316C, 0x316C, Status::Mapped("\u{11D7}")),
+    (0x316D, 0x316D, Status::Mapped("\u{11D9}")),
+    (0x316E, 0x316E, Status::Mapped("\u{111C}")),
+    (0x316F, 0x316F, Status::Mapped("\u{11DD}")),
+    (0x3170, 0x3170, Status::Mapped("\u{11DF}")),
+    (0x3171, 0x3171, Status::Mapped("\u{111D}")),
+    (0x3172, 0x3172, Status::Mapped("\u{111E}")),
+    (0x3173, 0x3173, Status::Mapped("\u{1120}")),
+    (0x3174, 0x3174, Status::Mapped("\u{1122}")),
+    (0x3175, 0x3175, Status::Mapped("\u{1123}")),
+    (0x3176, 0x3176, Status::Mapped("\u{1127}")),
+    (0x3177, 0x3177, Status::Mapped("\u{1129}")),
+    (0x3178, 0x3178, Status::Mapped("\u{112B}")),
+    (0x3179, 0x3179, Status::Mapped("\u{112C}")),
+    (0x317A, 0x317A, Status::Mapped("\u{112D}")),
+    (0x317B, 0x317B, Status::Mapped("\u{112E}")),
+    (0x317C, 0x317C, Status::Mapped("\u{112F}")),
+    (0x317D, 0x317D, Status::Mapped("\u{1132}")),
+    (0x317E, 0x317E, Status::Mapped("\u{1136}")),
+    (0x317F, 0x317F, Status::Mapped("\u{1140}")),
+    (0x3180, 0x3180, Status::Mapped("\u{1147}")),
+    (0x3181, 0x3181, Status::Mapped("\u{114C}")),
+    (0x3182, 0x3182, Status::Mapped("\u{11F1}")),
+    (0x3183, 0x3183, Status::Mapped("\u{11F2}")),
+    (0x3184, 0x3184, Status::Mapped("\u{1157}")),
+    (0x3185, 0x3185, Status::Mapped("\u{1158}")),
+    (0x3186, 0x3186, Status::Mapped("\u{1159}")),
+    (0x3187, 0x3187, Status::Mapped("\u{1184}")),
+    (0x3188, 0x3188, Status::Mapped("\u{1185}")),
+    (0x3189, 0x3189, Status::Mapped("\u{1188}")),
+    (0x318A, 0x318A, Status::Mapped("\u{1191}")),
+    (0x318B, 0x318B, Status::Mapped("\u{1192}")),
+    (0x318C, 0x318C, Status::Mapped("\u{1194}")),
+    (0x318D, 0x318D, Status::Mapped("\u{119E}")),
+    (0x318E, 0x318E, Status::Mapped("\u{11A1}")),
+    (0x318F, 0x318F, Status::Disallowed),
+    (0x3190, 0x3191, Status::Valid),
+    (0x3192, 0x3192, Status::Mapped("\u{4E00}")),
+    (0x3193, 0x3193, Status::Mapped("\u{4E8C}")),
+    (0x3194, 0x3194, Status::Mapped("\u{4E09}")),
+    (0x3195, 0x3195, Status::Mapped("\u{56DB}")),
+    (0x3196, 0x3196, Status::Mapped("\u{4E0A}")),
+    (0x3197, 0x3197, Status::Mapped("\u{4E2D}")),
+    (0x3198, 0x3198, Status::Mapped("\u{4E0B}")),
+    (0x3199, 0x3199, Status::Mapped("\u{7532}")),
+    (0x319A, 0x319A, Status::Mapped("\u{4E59}")),
+    (0x319B, 0x319B, Status::Mapped("\u{4E19}")),
+    (0x319C, 0x319C, Status::Mapped("\u{4E01}")),
+    (0x319D, 0x319D, Status::Mapped("\u{5929}")),
+    (0x319E, 0x319E, Status::Mapped("\u{5730}")),
+    (0x319F, 0x319F, Status::Mapped("\u{4EBA}")),
+    (0x31A0, 0x31E3, Status::Valid),
+    (0x31E4, 0x31EF, Status::Disallowed),
+    (0x31F0, 0x321E, Status::Valid),
+    (0x321F, 0x321F, Status::Disallowed),
+    (0x3220, 0x3243, Status::Valid),
+    (0x3244, 0x3244, Status::Mapped("\u{554F}")),
+    (0x3245, 0x3245, Status::Mapped("\u{5E7C}")),
+    (0x3246, 0x3246, Status::Mapped("\u{6587}")),
+    (0x3247, 0x3247, Status::Mapped("\u{7B8F}")),
+    (0x3248, 0x324F, Status::Valid),
+    (0x3250, 0x3250, Status::Mapped("pte")),
+    (0x3251, 0x3251, Status::Mapped("21")),
+    (0x3252, 0x3252, Status::Mapped("22")),
+    (0x3253, 0x3253, Status::Mapped("23")),
+    (0x3254, 0x3254, Status::Mapped("24")),
+    (0x3255, 0x3255, Status::Mapped("25")),
+    (0x3256, 0x3256, Status::Mapped("26")),
+    (0x3257, 0x3257, Status::Mapped("27")),
+    (0x3258, 0x3258, Status::Mapped("28")),
+    (0x3259, 0x3259, Status::Mapped("29")),
+    (0x325A, 0x325A, Status::Mapped("30")),
+    (0x325B, 0x325B, Status::Mapped("31")),
+    (0x325C, 0x325C, Status::Mapped("32")),
+    (0x325D, 0x325D, Status::Mapped("33")),
+    (0x325E, 0x325E, Status::Mapped("34")),
+    (0x325F, 0x325F, Status::Mapped("35")),
+    (0x3260, 0x3260, Status::Mapped("\u{1100}")),
+    (0x3261, 0x3261, Status::Mapped("\u{1102}")),
+    (0x3262, 0x3262, Status::Mapped("\u{1103}")),
+    (0x3263, 0x3263, Status::Mapped("\u{1105}")),
+    (0x3264, 0x3264, Status::Mapped("\u{1106}")),
+    (0x3265, 0x3265, Status::Mapped("\u{1107}")),
+    (0x3266, 0x3266, Status::Mapped("\u{1109}")),
+    (0x3267, 0x3267, Status::Mapped("\u{110B}")),
+    (0x3268, 0x3268, Status::Mapped("\u{110C}")),
+    (0x3269, 0x3269, Status::Mapped("\u{110E}")),
+    (0x326A, 0x326A, Status::Mapped("\u{110F}")),
+    (0x326B, 0x326B, Status::Mapped("\u{1110}")),
+    (0x326C, 0x326C, Status::Mapped("\u{1111}")),
+    (0x326D, 0x326D, Status::Mapped("\u{1112}")),
+    (0x326E, 0x326E, Status::Mapped("\u{AC00}")),
+    (0x326F, 0x326F, Status::Mapped("\u{B098}")),
+    (0x3270, 0x3270, Status::Mapped("\u{B2E4}")),
+    (0x3271, 0x3271, Status::Mapped("\u{B77C}")),
+    (0x3272, 0x3272, Status::Mapped("\u{B9C8}")),
+    (0x3273, 0x3273, Status::Mapped("\u{BC14}")),
+    (0x3274, 0x3274, Status::Mapped("\u{C0AC}")),
+    (0x3275, 0x3275, Status::Mapped("\u{C544}")),
+    (0x3276, 0x3276, Status::Mapped("\u{C790}")),
+    (0x3277, 0x3277, Status::Mapped("\u{CC28}")),
+    (0x3278, 0x3278, Status::Mapped("\u{CE74}")),
+    (0x3279, 0x3279, Status::Mapped("\u{D0C0}")),
+    (0x327A, 0x327A, Status::Mapped("\u{D30C}")),
+    (0x327B, 0x327B, Status::Mapped("\u{D558}")),
+    (0x327C, 0x327C, Status::Mapped("\u{CC38}\u{ACE0}")),
+    (0x327D, 0x327D, Status::Mapped("\u{C8FC}\u{C758}")),
+    (0x327E, 0x327E, Status::Mapped("\u{C6B0}")),
+    (0x327F, 0x327F, Status::Valid),
+    (0x3280, 0x3280, Status::Mapped("\u{4E00}")),
+    (0x3281, 0x3281, Status::Mapped("\u{4E8C}")),
+    (0x3282, 0x3282, Status::Mapped("\u{4E09}")),
+    (0x3283, 0x3283, Status::Mapped("\u{56DB}")),
+    (0x3284, 0x3284, Status::Mapped("\u{4E94}")),
+    (0x3285, 0x3285, Status::Mapped("\u{516D}")),
+    (0x3286, 0x3286, Status::Mapped("\u{4E03}")),
+    (0x3287, 0x3287, Status::Mapped("\u{516B}")),
+    (0x3288, 0x3288, Status::Mapped("\u{4E5D}")),
+    (0x3289, 0x3289, Status::Mapped("\u{5341}")),
+    (0x328A, 0x328A, Status::Mapped("\u{6708}")),
+    (0x328B, 0x328B, Status::Mapped("\u{706B}")),
+    (0x328C, 0x328C, Status::Mapped("\u{6C34}")),
+    (0x328D, 0x328D, Status::Mapped("\u{6728}")),
+    (0x328E, 0x328E, Status::Mapped("\u{91D1}")),
+    (0x328F, 0x328F, Status::Mapped("\u{571F}")),
+    (0x3290, 0x3290, Status::Mapped("\u{65E5}")),
+    (0x3291, 0x3291, Status::Mapped("\u{682A}")),
+    (0x3292, 0x3292, Status::Mapped("\u{6709}")),
+    (0x3293, 0x3293, Status::Mapped("\u{793E}")),
+    (0x3294, 0x3294, Status::Mapped("\u{540D}")),
+    (0x3295, 0x3295, Status::Mapped("\u{7279}")),
+    (0x3296, 0x3296, Status::Mapped("\u{8CA1}")),
+    (0x3297, 0x3297, Status::Mapped("\u{795D}")),
+    (0x3298, 0x3298, Status::Mapped("\u{52B4}")),
+    (0x3299, 0x3299, Status::Mapped("\u{79D8}")),
+    (0x329A, 0x329A, Status::Mapped("\u{7537}")),
+    (0x329B, 0x329B, Status::Mapped("\u{5973}")),
+    (0x329C, 0x329C, Status::Mapped("\u{9069}")),
+    (0x329D, 0x329D, Status::Mapped("\u{512A}")),
+    (0x329E, 0x329E, Status::Mapped("\u{5370}")),
+    (0x329F, 0x329F, Status::Mapped("\u{6CE8}")),
+    (0x32A0, 0x32A0, Status::Mapped("\u{9805}")),
+    (0x32A1, 0x32A1, Status::Mapped("\u{4F11}")),
+    (0x32A2, 0x32A2, Status::Mapped("\u{5199}")),
+    (0x32A3, 0x32A3, Status::Mapped("\u{6B63}")),
+    (0x32A4, 0x32A4, Status::Mapped("\u{4E0A}")),
+    (0x32A5, 0x32A5, Status::Mapped("\u{4E2D}")),
+    (0x32A6, 0x32A6, Status::Mapped("\u{4E0B}")),
+    (0x32A7, 0x32A7, Status::Mapped("\u{5DE6}")),
+    (0x32A8, 0x32A8, Status::Mapped("\u{53F3}")),
+    (0x32A9, 0x32A9, Status::Mapped("\u{533B}")),
+    (0x32AA, 0x32AA, Status::Mapped("\u{5B97}")),
+    (0x32AB, 0x32AB, Status::Mapped("\u{5B66}")),
+    (0x32AC, 0x32AC, Status::Mapped("\u{76E3}")),
+    (0x32AD, 0x32AD, Status::Mapped("\u{4F01}")),
+    (0x32AE, 0x32AE, Status::Mapped("\u{8CC7}")),
+    (0x32AF, 0x32AF, Status::Mapped("\u{5354}")),
+    (0x32B0, 0x32B0, Status::Mapped("\u{591C}")),
+    (0x32B1, 0x32B1, Status::Mapped("36")),
+    (0x32B2, 0x32B2, Status::Mapped("37")),
+    (0x32B3, 0x32B3, Status::Mapped("38")),
+    (0x32B4, 0x32B4, Status::Mapped("39")),
+    (0x32B5, 0x32B5, Status::Mapped("40")),
+    (0x32B6, 0x32B6, Status::Mapped("41")),
+    (0x32B7, 0x32B7, Status::Mapped("42")),
+    (0x32B8, 0x32B8, Status::Mapped("43")),
+    (0x32B9, 0x32B9, Status::Mapped("44")),
+    (0x32BA, 0x32BA, Status::Mapped("45")),
+    (0x32BB, 0x32BB, Status::Mapped("46")),
+    (0x32BC, 0x32BC, Status::Mapped("47")),
+    (0x32BD, 0x32BD, Status::Mapped("48")),
+    (0x32BE, 0x32BE, Status::Mapped("49")),
+    (0x32BF, 0x32BF, Status::Mapped("50")),
+    (0x32C0, 0x32C0, Status::Mapped("1\u{6708}")),
+    (0x32C1, 0x32C1, Status::Mapped("2\u{6708}")),
+    (0x32C2, 0x32C2, Status::Mapped("3\u{6708}")),
+    (0x32C3, 0x32C3, Status::Mapped("4\u{6708}")),
+    (0x32C4, 0x32C4, Status::Mapped("5\u{6708}")),
+    (0x32C5, 0x32C5, Status::Mapped("6\u{6708}")),
+    (0x32C6, 0x32C6, Status::Mapped("7\u{6708}")),
+    (0x32C7, 0x32C7, Status::Mapped("8\u{6708}")),
+    (0x32C8, 0x32C8, Status::Mapped("9\u{6708}")),
+    (0x32C9, 0x32C9, Status::Mapped("10\u{6708}")),
+    (0x32CA, 0x32CA, Status::Mapped("11\u{6708}")),
+    (0x32CB, 0x32CB, Status::Mapped("12\u{6708}")),
+    (0x32CC, 0x32CC, Status::Mapped("hg")),
+    (0x32CD, 0x32CD, Status::Mapped("erg")),
+    (0x32CE, 0x32CE, Status::Mapped("ev")),
+    (0x32CF, 0x32CF, Status::Mapped("ltd")),
+    (0x32D0, 0x32D0, Status::Mapped("\u{30A2}")),
+    (0x32D1, 0x32D1, Status::Mapped("\u{30A4}")),
+    (0x32D2, 0x32D2, Status::Mapped("\u{30A6}")),
+    (0x32D3, 0x32D3, Status::Mapped("\u{30A8}")),
+    (0x32D4, 0x32D4, Status::Mapped("\u{30AA}")),
+    (0x32D5, 0x32D5, Status::Mapped("\u{30AB}")),
+    (0x32D6, 0x32D6, Status::Mapped("\u{30AD}")),
+    (0x32D7, 0x32D7, Status::Mapped("\u{30AF}")),
+    (0x32D8, 0x32D8, Status::Mapped("\u{30B1}")),
+    (0x32D9, 0x32D9, Status::Mapped("\u{30B3}")),
+    (0x32DA, 0x32DA, Status::Mapped("\u{30B5}")),
+    (0x32DB, 0x32DB, Status::Mapped("\u{30B7}")),
+    (0x32DC, 0x32DC, Status::Mapped("\u{30B9}")),
+    (0x32DD, 0x32DD, Status::Mapped("\u{30BB}")),
+    (0x32DE, 0x32DE, Status::Mapped("\u{30BD}")),
+    (0x32DF, 0x32DF, Status::Mapped("\u{30BF}")),
+    (0x32E0, 0x32E0, Status::Mapped("\u{30C1}")),
+    (0x32E1, 0x32E1, Status::Mapped("\u{30C4}")),
+    (0x32E2, 0x32E2, Status::Mapped("\u{30C6}")),
+    (0x32E3, 0x32E3, Status::Mapped("\u{30C8}")),
+    (0x32E4, 0x32E4, Status::Mapped("\u{30CA}")),
+    (0x32E5, 0x32E5, Status::Mapped("\u{30CB}")),
+    (0x32E6, 0x32E6, Status::Mapped("\u{30CC}")),
+    (0x32E7, 0x32E7, Status::Mapped("\u{30CD}")),
+    (0x32E8, 0x32E8, Status::Mapped("\u{30CE}")),
+    (0x32E9, 0x32E9, Status::Mapped("\u{30CF}")),
+    (0x32EA, 0x32EA, Status::Mapped("\u{30D2}")),
+    (0x32EB, 0x32EB, Status::Mapped("\u{30D5}")),
+    (0x32EC, 0x32EC, Status::Mapped("\u{30D8}")),
+    (0x32ED, 0x32ED, Status::Mapped("\u{30DB}")),
+    (0x32EE, 0x32EE, Status::Mapped("\u{30DE}")),
+    (0x32EF, 0x32EF, Status::Mapped("\u{30DF}")),
+    (0x32F0, 0x32F0, Status::Mapped("\u{30E0}")),
+    (0x32F1, 0x32F1, Status::Mapped("\u{30E1}")),
+    (0x32F2, 0x32F2, Status::Mapped("\u{30E2}")),
+    (0x32F3, 0x32F3, Status::Mapped("\u{30E4}")),
+    (0x32F4, 0x32F4, Status::Mapped("\u{30E6}")),
+    (0x32F5, 0x32F5, Status::Mapped("\u{30E8}")),
+    (0x32F6, 0x32F6, Status::Mapped("\u{30E9}")),
+    (0x32F7, 0x32F7, Status::Mapped("\u{30EA}")),
+    (0x32F8, 0x32F8, Status::Mapped("\u{30EB}")),
+    (0x32F9, 0x32F9, Status::Mapped("\u{30EC}")),
+    (0x32FA, 0x32FA, Status::Mapped("\u{30ED}")),
+    (0x32FB, 0x32FB, Status::Mapped("\u{30EF}")),
+    (0x32FC, 0x32FC, Status::Mapped("\u{30F0}")),
+    (0x32FD, 0x32FD, Status::Mapped("\u{30F1}")),
+    (0x32FE, 0x32FE, Status::Mapped("\u{30F2}")),
+    (0x32FF, 0x32FF, Status::Mapped("\u{4EE4}\u{548C}")),
+    (0x3300, 0x3300, Status::Mapped("\u{30A2}\u{30D1}\u{30FC}\u{30C8}")),
+    (0x3301, 0x3301, Status::Mapped("\u{30A2}\u{30EB}\u{30D5}\u{30A1}")),
+    (0x3302, 0x3302, Status::Mapped("\u{30A2}\u{30F3}\u{30DA}\u{30A2}")),
+    (0x3303, 0x3303, Status::Mapped("\u{30A2}\u{30FC}\u{30EB}")),
+    (0x3304, 0x3304, Status::Mapped("\u{30A4}\u{30CB}\u{30F3}\u{30B0}")),
+    (0x3305, 0x3305, Status::Mapped("\u{30A4}\u{30F3}\u{30C1}")),
+    (0x3306, 0x3306, Status::Mapped("\u{30A6}\u{30A9}\u{30F3}")),
+    (0x3307, 0x3307, Status::Mapped("\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}")),
+    (0x3308, 0x3308, Status::Mapped("\u{30A8}\u{30FC}\u{30AB}\u{30FC}")),
+    (0x3309, 0x3309, Status::Mapped("\u{30AA}\u{30F3}\u{30B9}")),
+    (0x330A, 0x330A, Status::Mapped("\u{30AA}\u{30FC}\u{30E0}")),
+    (0x330B, 0x330B, Status::Mapped("\u{30AB}\u{30A4}\u{30EA}")),
+    (0x330C, 0x330C, Status::Mapped("\u{30AB}\u{30E9}\u{30C3}\u{30C8}")),
+    (0x330D, 0x330D, Status::Mapped("\u{30AB}\u{30ED}\u{30EA}\u{30FC}")),
+    (0x330E, 0x330E, Status::Mapped("\u{30AC}\u{30ED}\u{30F3}")),
+    (0x330F, 0x330F, Status::Mapped("\u{30AC}\u{30F3}\u{30DE}")),
+    (0x3310, 0x3310, Status::Mapped("\u{30AE}\u{30AC}")),
+    (0x3311, 0x3311, Status::Mapped("\u{30AE}\u{30CB}\u{30FC}")),
+    (0x3312, 0x3312, Status::Mapped("\u{30AD}\u{30E5}\u{30EA}\u{30FC}")),
+    (0x3313, 0x3313, Status::Mapped("\u{30AE}\u{30EB}\u{30C0}\u{30FC}")),
+    (0x3314, 0x3314, Status::Mapped("\u{30AD}\u{30ED}")),
+    (0x3315, 0x3315, Status::Mapped("\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}")),
+    (0x3316, 0x3316, Status::Mapped("\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}")),
+    (0x3317, 0x3317, Status::Mapped("\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}")),
+    (0x3318, 0x3318, Status::Mapped("\u{30B0}\u{30E9}\u{30E0}")),
+    (0x3319, 0x3319, Status::Mapped("\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}")),
+    (0x331A, 0x331A, Status::Mapped("\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}")),
+    (0x331B, 0x331B, Status::Mapped("\u{30AF}\u{30ED}\u{30FC}\u{30CD}")),
+    (0x331C, 0x331C, Status::Mapped("\u{30B1}\u{30FC}\u{30B9}")),
+    (0x331D, 0x331D, Status::Mapped("\u{30B3}\u{30EB}\u{30CA}")),
+    (0x331E, 0x331E, Status::Mapped("\u{30B3}\u{30FC}\u{30DD}")),
+    (0x331F, 0x331F, Status::Mapped("\u{30B5}\u{30A4}\u{30AF}\u{30EB}")),
+    (0x3320, 0x3320, Status::Mapped("\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}")),
+    (0x3321, 0x3321, Status::Mapped("\u{30B7}\u{30EA}\u{30F3}\u{30B0}")),
+    (0x3322, 0x3322, Status::Mapped("\u{30BB}\u{30F3}\u{30C1}")),
+    (0x3323, 0x3323, Status::Mapped("\u{30BB}\u{30F3}\u{30C8}")),
+    (0x3324, 0x3324, Status::Mapped("\u{30C0}\u{30FC}\u{30B9}")),
+    (0x3325, 0x3325, Status::Mapped("\u{30C7}\u{30B7}")),
+    (0x3326, 0x3326, Status::Mapped("\u{30C9}\u{30EB}")),
+    (0x3327, 0x3327, Status::Mapped("\u{30C8}\u{30F3}")),
+    (0x3328, 0x3328, Status::Mapped("\u{30CA}\u{30CE}")),
+    (0x3329, 0x3329, Status::Mapped("\u{30CE}\u{30C3}\u{30C8}")),
+    (0x332A, 0x332A, Status::Mapped("\u{30CF}\u{30A4}\u{30C4}")),
+    (0x332B, 0x332B, Status::Mapped("\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}")),
+    (0x332C, 0x332C, Status::Mapped("\u{30D1}\u{30FC}\u{30C4}")),
+    (0x332D, 0x332D, Status::Mapped("\u{30D0}\u{30FC}\u{30EC}\u{30EB}")),
+    (0x332E, 0x332E, Status::Mapped("\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}")),
+    (0x332F, 0x332F, Status::Mapped("\u{30D4}\u{30AF}\u{30EB}")),
+    (0x3330, 0x3330, Status::Mapped("\u{30D4}\u{30B3}")),
+    (0x3331, 0x3331, Status::Mapped("\u{30D3}\u{30EB}")),
+    (0x3332, 0x3332, Status::Mapped("\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}")),
+    (0x3333, 0x3333, Status::Mapped("\u{30D5}\u{30A3}\u{30FC}\u{30C8}")),
+    (0x3334, 0x3334, Status::Mapped("\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}")),
+    (0x3335, 0x3335, Status::Mapped("\u{30D5}\u{30E9}\u{30F3}")),
+    (0x3336, 0x3336, Status::Mapped("\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}")),
+    (0x3337, 0x3337, Status::Mapped("\u{30DA}\u{30BD}")),
+    (0x3338, 0x3338, Status::Mapped("\u{30DA}\u{30CB}\u{30D2}")),
+    (0x3339, 0x3339, Status::Mapped("\u{30D8}\u{30EB}\u{30C4}")),
+    (0x333A, 0x333A, Status::Mapped("\u{30DA}\u{30F3}\u{30B9}")),
+    (0x333B, 0x333B, Status::Mapped("\u{30DA}\u{30FC}\u{30B8}")),
+    (0x333C, 0x333C, Status::Mapped("\u{30D9}\u{30FC}\u{30BF}")),
+    (0x333D, 0x333D, Status::Mapped("\u{30DD}\u{30A4}\u{30F3}\u{30C8}")),
+    (0x333E, 0x333E, Status::Mapped("\u{30DC}\u{30EB}\u{30C8}")),
+    (0x333F, 0x333F, Status::Mapped("\u{30DB}\u{30F3}")),
+    (0x3340, 0x3340, Status::Mapped("\u{30DD}\u{30F3}\u{30C9}")),
+    (0x3341, 0x3341, Status::Mapped("\u{30DB}\u{30FC}\u{30EB}")),
+    (0x3342, 0x3342, Status::Mapped("\u{30DB}\u{30FC}\u{30F3}")),
+    (0x3343, 0x3343, Status::Mapped("\u{30DE}\u{30A4}\u{30AF}\u{30ED}")),
+    (0x3344, 0x3344, Status::Mapped("\u{30DE}\u{30A4}\u{30EB}")),
+    (0x3345, 0x3345, Status::Mapped("\u{30DE}\u{30C3}\u{30CF}")),
+    (0x3346, 0x3346, Status::Mapped("\u{30DE}\u{30EB}\u{30AF}")),
+    (0x3347, 0x3347, Status::Mapped("\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}")),
+    (0x3348, 0x3348, Status::Mapped("\u{30DF}\u{30AF}\u{30ED}\u{30F3}")),
+    (0x3349, 0x3349, Status::Mapped("\u{30DF}\u{30EA}")),
+    (0x334A, 0x334A, Status::Mapped("\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}")),
+    (0x334B, 0x334B, Status::Mapped("\u{30E1}\u{30AC}")),
+    (0x334C, 0x334C, Status::Mapped("\u{30E1}\u{30AC}\u{30C8}\u{30F3}")),
+    (0x334D, 0x334D, Status::Mapped("\u{30E1}\u{30FC}\u{30C8}\u{30EB}")),
+    (0x334E, 0x334E, Status::Mapped("\u{30E4}\u{30FC}\u{30C9}")),
+    (0x334F, 0x334F, Status::Mapped("\u{30E4}\u{30FC}\u{30EB}")),
+    (0x3350, 0x3350, Status::Mapped("\u{30E6}\u{30A2}\u{30F3}")),
+    (0x3351, 0x3351, Status::Mapped("\u{30EA}\u{30C3}\u{30C8}\u{30EB}")),
+    (0x3352, 0x3352, Status::Mapped("\u{30EA}\u{30E9}")),
+    (0x3353, 0x3353, Status::Mapped("\u{30EB}\u{30D4}\u{30FC}")),
+    (0x3354, 0x3354, Status::Mapped("\u{30EB}\u{30FC}\u{30D6}\u{30EB}")),
+    (0x3355, 0x3355, Status::Mapped("\u{30EC}\u{30E0}")),
+    (0x3356, 0x3356, Status::Mapped("\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}")),
+    (0x3357, 0x3357, Status::Mapped("\u{30EF}\u{30C3}\u{30C8}")),
+    (0x3358, 0x3358, Status::Mapped("0\u{70B9}")),
+    (0x3359, 0x3359, Status::Mapped("1\u{70B9}")),
+    (0x335A, 0x335A, Status::Mapped("2\u{70B9}")),
+    (0x335B, 0x335B, Status::Mapped("3\u{70B9}")),
+    (0x335C, 0x335C, Status::Mapped("4\u{70B9}")),
+    (0x335D, 0x335D, Status::Mapped("5\u{70B9}")),
+    (0x335E, 0x335E, Status::Mapped("6\u{70B9}")),
+    (0x335F, 0x335F, Status::Mapped("7\u{70B9}")),
+    (0x3360, 0x3360, Status::Mapped("8\u{70B9}")),
+    (0x3361, 0x3361, Status::Mapped("9\u{70B9}")),
+    (0x3362, 0x3362, Status::Mapped("10\u{70B9}")),
+    (0x3363, 0x3363, Status::Mapped("11\u{70B9}")),
+    (0x3364, 0x3364, Status::Mapped("12\u{70B9}")),
+    (0x3365, 0x3365, Status::Mapped("13\u{70B9}")),
+    (0x3366, 0x3366, Status::Mapped("14\u{70B9}")),
+    (0x3367, 0x3367, Status::Mapped("15\u{70B9}")),
+    (0x3368, 0x3368, Status::Mapped("16\u{70B9}")),
+    (0x3369, 0x3369, Status::Mapped("17\u{70B9}")),
+    (0x336A, 0x336A, Status::Mapped("18\u{70B9}")),
+    (0x336B, 0x336B, Status::Mapped("19\u{70B9}")),
+    (0x336C, 0x336C, Status::Mapped("20\u{70B9}")),
+    (0x336D, 0x336D, Status::Mapped("21\u{70B9}")),
+    (0x336E, 0x336E, Status::Mapped("22\u{70B9}")),
+    (0x336F, 0x336F, Status::Mapped("23\u{70B9}")),
+    (0x3370, 0x3370, Status::Mapped("24\u{70B9}")),
+    (0x3371, 0x3371, Status::Mapped("hpa")),
+    (0x3372, 0x3372, Status::Mapped("da")),
+    (0x3373, 0x3373, Status::Mapped("au")),
+    (0x3374, 0x3374, Status::Mapped("bar")),
+    (0x3375, 0x3375, Status::Mapped("ov")),
+    (0x3376, 0x3376, Status::Mapped("pc")),
+    (0x3377, 0x3377, Status::Mapped("dm")),
+    (0x3378, 0x3378, Status::Mapped("dm2")),
+    (0x3379, 0x3379, Status::Mapped("dm3")),
+    (0x337A, 0x337A, Status::Mapped("iu")),
+    (0x337B, 0x337B, Status::Mapped("\u{5E73}\u{6210}")),
+    (0x337C, 0x337C, Status::Mapped("\u{662D}\u{548C}")),
+    (0x337D, 0x337D, Status::Mapped("\u{5927}\u{6B63}")),
+    (0x337E, 0x337E, Status::Mapped("\u{660E}\u{6CBB}")),
+    (0x337F, 0x337F, Status::Mapped("\u{682A}\u{5F0F}\u{4F1A}\u{793E}")),
+    (0x3380, 0x3380, Status::Mapped("pa")),
+    (0x3381, 0x3381, Status::Mapped("na")),
+    (0x3382, 0x3382, Status::Mapped("\u{3BC}a")),
+    (0x3383, 0x3383, Status::Mapped("ma")),
+    (0x3384, 0x3384, Status::Mapped("ka")),
+    (0x3385, 0x3385, Status::Mapped("kb")),
+    (0x3386, 0x3386, Status::Mapped("mb")),
+    (0x3387, 0x3387, Status::Mapped("gb")),
+    (0x3388, 0x3388, Status::Mapped("cal")),
+    (0x3389, 0x3389, Status::Mapped("kcal")),
+    (0x338A, 0x338A, Status::Mapped("pf")),
+    (0x338B, 0x338B, Status::Mapped("nf")),
+    (0x338C, 0x338C, Status::Mapped("\u{3BC}f")),
+    (0x338D, 0x338D, Status::Mapped("\u{3BC}g")),
+    (0x338E, 0x338E, Status::Mapped("mg")),
+    (0x338F, 0x338F, Status::Mapped("kg")),
+    (0x3390, 0x3390, Status::Mapped("hz")),
+    (0x3391, 0x3391, Status::Mapped("khz")),
+    (0x3392, 0x3392, Status::Mapped("mhz")),
+    (0x3393, 0x3393, Status::Mapped("ghz")),
+    (0x3394, 0x3394, Status::Mapped("thz")),
+    (0x3395, 0x3395, Status::Mapped("\u{3BC}l")),
+    (0x3396, 0x3396, Status::Mapped("ml")),
+    (0x3397, 0x3397, Status::Mapped("dl")),
+    (0x3398, 0x3398, Status::Mapped("kl")),
+    (0x3399, 0x3399, Status::Mapped("fm")),
+    (0x339A, 0x339A, Status::Mapped("nm")),
+    (0x339B, 0x339B, Status::Mapped("\u{3BC}m")),
+    (0x339C, 0x339C, Status::Mapped("mm")),
+    (0x339D, 0x339D, Status::Mapped("cm")),
+    (0x339E, 0x339E, Status::Mapped("km")),
+    (0x339F, 0x339F, Status::Mapped("mm2")),
+    (0x33A0, 0x33A0, Status::Mapped("cm2")),
+    (0x33A1, 0x33A1, Status::Mapped("m2")),
+    (0x33A2, 0x33A2, Status::Mapped("km2")),
+    (0x33A3, 0x33A3, Status::Mapped("mm3")),
+    (0x33A4, 0x33A4, Status::Mapped("cm3")),
+    (0x33A5, 0x33A5, Status::Mapped("m3")),
+    (0x33A6, 0x33A6, Status::Mapped("km3")),
+    (0x33A7, 0x33A7, Status::Mapped("m\u{2215}s")),
+    (0x33A8, 0x33A8, Status::Mapped("m\u{2215}s2")),
+    (0x33A9, 0x33A9, Status::Mapped("pa")),
+    (0x33AA, 0x33AA, Status::Mapped("kpa")),
+    (0x33AB, 0x33AB, Status::Mapped("mpa")),
+    (0x33AC, 0x33AC, Status::Mapped("gpa")),
+    (0x33AD, 0x33AD, Status::Mapped("rad")),
+    (0x33AE, 0x33AE, Status::Mapped("rad\u{2215}s")),
+    (0x33AF, 0x33AF, Status::Mapped("rad\u{2215}s2")),
+    (0x33B0, 0x33B0, Status::Mapped("ps")),
+    (0x33B1, 0x33B1, Status::Mapped("ns")),
+    (0x33B2, 0x33B2, Status::Mapped("\u{3BC}s")),
+    (0x33B3, 0x33B3, Status::Mapped("ms")),
+    (0x33B4, 0x33B4, Status::Mapped("pv")),
+    (0x33B5, 0x33B5, Status::Mapped("nv")),
+    (0x33B6, 0x33B6, Status::Mapped("\u{3BC}v")),
+    (0x33B7, 0x33B7, Status::Mapped("mv")),
+    (0x33B8, 0x33B8, Status::Mapped("kv")),
+    (0x33B9, 0x33B9, Status::Mapped("mv")),
+    (0x33BA, 0x33BA, Status::Mapped("pw")),
+    (0x33BB, 0x33BB, Status::Mapped("nw")),
+    (0x33BC, 0x33BC, Status::Mapped("\u{3BC}w")),
+    (0x33BD, 0x33BD, Status::Mapped("mw")),
+    (0x33BE, 0x33BE, Status::Mapped("kw")),
+    (0x33BF, 0x33BF, Status::Mapped("mw")),
+    (0x33C0, 0x33C0, Status::Mapped("k\u{3C9}")),
+    (0x33C1, 0x33C1, Status::Mapped("m\u{3C9}")),
+    (0x33C2, 0x33C2, Status::Disallowed),
+    (0x33C3, 0x33C3, Status::Mapped("bq")),
+    (0x33C4, 0x33C4, Status::Mapped("cc")),
+    (0x33C5, 0x33C5, Status::Mapped("cd")),
+    (0x33C6, 0x33C6, Status::Mapped("c\u{2215}kg")),
+    (0x33C7, 0x33C7, Status::Disallowed),
+    (0x33C8, 0x33C8, Status::Mapped("db")),
+    (0x33C9, 0x33C9, Status::Mapped("gy")),
+    (0x33CA, 0x33CA, Status::Mapped("ha")),
+    (0x33CB, 0x33CB, Status::Mapped("hp")),
+    (0x33CC, 0x33CC, Status::Mapped("in")),
+    (0x33CD, 0x33CD, Status::Mapped("kk")),
+    (0x33CE, 0x33CE, Status::Mapped("km")),
+    (0x33CF, 0x33CF, Status::Mapped("kt")),
+    (0x33D0, 0x33D0, Status::Mapped("lm")),
+    (0x33D1, 0x33D1, Status::Mapped("ln")),
+    (0x33D2, 0x33D2, Status::Mapped("log")),
+    (0x33D3, 0x33D3, Status::Mapped("lx")),
+    (0x33D4, 0x33D4, Status::Mapped("mb")),
+    (0x33D5, 0x33D5, Status::Mapped("mil")),
+    (0x33D6, 0x33D6, Status::Mapped("mol")),
+    (0x33D7, 0x33D7, Status::Mapped("ph")),
+    (0x33D8, 0x33D8, Status::Disallowed),
+    (0x33D9, 0x33D9, Status::Mapped("ppm")),
+    (0x33DA, 0x33DA, Status::Mapped("pr")),
+    (0x33DB, 0x33DB, Status::Mapped("sr")),
+    (0x33DC, 0x33DC, Status::Mapped("sv")),
+    (0x33DD, 0x33DD, Status::Mapped("wb")),
+    (0x33DE, 0x33DE, Status::Mapped("v\u{2215}m")),
+    (0x33DF, 0x33DF, Status::Mapped("a\u{2215}m")),
+    (0x33E0, 0x33E0, Status::Mapped("1\u{65E5}")),
+    (0x33E1, 0x33E1, Status::Mapped("2\u{65E5}")),
+    (0x33E2, 0x33E2, Status::Mapped("3\u{65E5}")),
+    (0x33E3, 0x33E3, Status::Mapped("4\u{65E5}")),
+    (0x33E4, 0x33E4, Status::Mapped("5\u{65E5}")),
+    (0x33E5, 0x33E5, Status::Mapped("6\u{65E5}")),
+    (0x33E6, 0x33E6, Status::Mapped("7\u{65E5}")),
+    (0x33E7, 0x33E7, Status::Mapped("8\u{65E5}")),
+    (0x33E8, 0x33E8, Status::Mapped("9\u{65E5}")),
+    (0x33E9, 0x33E9, Status::Mapped("10\u{65E5}")),
+    (0x33EA, 0x33EA, Status::Mapped("11\u{65E5}")),
+    (0x33EB, 0x33EB, Status::Mapped("12\u{65E5}")),
+    (0x33EC, 0x33EC, Status::Mapped("13\u{65E5}")),
+    (0x33ED, 0x33ED, Status::Mapped("14\u{65E5}")),
+    (0x33EE, 0x33EE, Status::Mapped("15\u{65E5}")),
+    (0x33EF, 0x33EF, Status::Mapped("16\u{65E5}")),
+    (0x33F0, 0x33F0, Status::Mapped("17\u{65E5}")),
+    (0x33F1, 0x33F1, Status::Mapped("18\u{65E5}")),
+    (0x33F2, 0x33F2, Status::Mapped("19\u{65E5}")),
+    (0x33F3, 0x33F3, Status::Mapped("20\u{65E5}")),
+    (0x33F4, 0x33F4, Status::Mapped("21\u{65E5}")),
+    (0x33F5, 0x33F5, Status::Mapped("22\u{65E5}")),
+    (0x33F6, 0x33F6, Status::Mapped("23\u{65E5}")),
+    (0x33F7, 0x33F7, Status::Mapped("24\u{65E5}")),
+    (0x33F8, 0x33F8, Status::Mapped("25\u{65E5}")),
+    (0x33F9, 0x33F9, Status::Mapped("26\u{65E5}")),
+    (0x33FA, 0x33FA, Status::Mapped("27\u{65E5}")),
+    (0x33FB, 0x33FB, Status::Mapped("28\u{65E5}")),
+    (0x33FC, 0x33FC, Status::Mapped("29\u{65E5}")),
+    (0x33FD, 0x33FD, Status::Mapped("30\u{65E5}")),
+    (0x33FE, 0x33FE, Status::Mapped("31\u{65E5}")),
+    (0x33FF, 0x33FF, Status::Mapped("gal")),
+    (0x3400, 0xA48C, Status::Valid),
+    (0xA48D, 0xA48F, Status::Disallowed),
+    (0xA490, 0xA4C6, Status::Valid),
+    (0xA4C7, 0xA4CF, Status::Disallowed),
+    (0xA4D0, 0xA62B, Status::Valid),
+    (0xA62C, 0xA63F, Status::Disallowed),
+    (0xA640, 0xA640, Status::Mapped("\u{A641}")),
+    (0xA641, 0xA641, Status::Valid),
+    (0xA642, 0xA642, Status::Mapped("\u{A643}")),
+    (0xA643, 0xA643, Status::Valid),
+    (0xA644, 0xA644, Status::Mapped("\u{A645}")),
+    (0xA645, 0xA645, Status::Valid),
+    (0xA646, 0xA646, Status::Mapped("\u{A647}")),
+    (0xA647, 0xA647, Status::Valid),
+    (0xA648, 0xA648, Status::Mapped("\u{A649}")),
+    (0xA649, 0xA649, Status::Valid),
+    (0xA64A, 0xA64A, Status::Mapped("\u{A64B}")),
+    (0xA64B, 0xA64B, Status::Valid),
+    (0xA64C, 0xA64C, Status::Mapped("\u{A64D}")),
+    (0xA64D, 0xA64D, Status::Valid),
+    (0xA64E, 0xA64E, Status::Mapped("\u{A64F}")),
+    (0xA64F, 0xA64F, Status::Valid),
+    (0xA650, 0xA650, Status::Mapped("\u{A651}")),
+    (0xA651, 0xA651, Status::Valid),
+    (0xA652, 0xA652, Status::Mapped("\u{A653}")),
+    (0xA653, 0xA653, Status::Valid),
+    (0xA654, 0xA654, Status::Mapped("\u{A655}")),
+    (0xA655, 0xA655, Status::Valid),
+    (0xA656, 0xA656, Status::Mapped("\u{A657}")),
+    (0xA657, 0xA657, Status::Valid),
+    (0xA658, 0xA658, Status::Mapped("\u{A659}")),
+    (0xA659, 0xA659, Status::Valid),
+    (0xA65A, 0xA65A, Status::Mapped("\u{A65B}")),
+    (0xA65B, 0xA65B, Status::Valid),
+    (0xA65C, 0xA65C, Status::Mapped("\u{A65D}")),
+    (0xA65D, 0xA65D, Status::Valid),
+    (0xA65E, 0xA65E, Status::Mapped("\u{A65F}")),
+    (0xA65F, 0xA65F, Status::Valid),
+    (0xA660, 0xA660, Status::Mapped("\u{A661}")),
+    (0xA661, 0xA661, Status::Valid),
+    (0xA662, 0xA662, Status::Mapped("\u{A663}")),
+    (0xA663, 0xA663, Status::Valid),
+    (0xA664, 0xA664, Status::Mapped("\u{A665}")),
+    (0xA665, 0xA665, Status::Valid),
+    (0xA666, 0xA666, Status::Mapped("\u{A667}")),
+    (0xA667, 0xA667, Status::Valid),
+    (0xA668, 0xA668, Status::Mapped("\u{A669}")),
+    (0xA669, 0xA669, Status::Valid),
+    (0xA66A, 0xA66A, Status::Mapped("\u{A66B}")),
+    (0xA66B, 0xA66B, Status::Valid),
+    (0xA66C, 0xA66C, Status::Mapped("\u{A66D}")),
+    (0xA66D, 0xA67F, Status::Valid),
+    (0xA680, 0xA680, Status::Mapped("\u{A681}")),
+    (0xA681, 0xA681, Status::Valid),
+    (0xA682, 0xA682, Status::Mapped("\u{A683}")),
+    (0xA683, 0xA683, Status::Valid),
+    (0xA684, 0xA684, Status::Mapped("\u{A685}")),
+    (0xA685, 0xA685, Status::Valid),
+    (0xA686, 0xA686, Status::Mapped("\u{A687}")),
+    (0xA687, 0xA687, Status::Valid),
+    (0xA688, 0xA688, Status::Mapped("\u{A689}")),
+    (0xA689, 0xA689, Status::Valid),
+    (0xA68A, 0xA68A, Status::Mapped("\u{A68B}")),
+    (0xA68B, 0xA68B, Status::Valid),
+    (0xA68C, 0xA68C, Status::Mapped("\u{A68D}")),
+    (0xA68D, 0xA68D, Status::Valid),
+    (0xA68E, 0xA68E, Status::Mapped("\u{A68F}")),
+    (0xA68F, 0xA68F, Status::Valid),
+    (0xA690, 0xA690, Status::Mapped("\u{A691}")),
+    (0xA691, 0xA691, Status::Valid),
+    (0xA692, 0xA692, Status::Mapped("\u{A693}")),
+    (0xA693, 0xA693, Status::Valid),
+    (0xA694, 0xA694, Status::Mapped("\u{A695}")),
+    (0xA695, 0xA695, Status::Valid),
+    (0xA696, 0xA696, Status::Mapped("\u{A697}")),
+    (0xA697, 0xA697, Status::Valid),
+    (0xA698, 0xA698, Status::Mapped("\u{A699}")),
+    (0xA699, 0xA699, Status::Valid),
+    (0xA69A, 0xA69A, Status::Mapped("\u{A69B}")),
+    (0xA69B, 0xA69B, Status::Valid),
+    (0xA69C, 0xA69C, Status::Mapped("\u{44A}")),
+    (0xA69D, 0xA69D, Status::Mapped("\u{44C}")),
+    (0xA69E, 0xA6F7, Status::Valid),
+    (0xA6F8, 0xA6FF, Status::Disallowed),
+    (0xA700, 0xA721, Status::Valid),
+    (0xA722, 0xA722, Status::Mapped("\u{A723}")),
+    (0xA723, 0xA723, Status::Valid),
+    (0xA724, 0xA724, Status::Mapped("\u{A725}")),
+    (0xA725, 0xA725, Status::Valid),
+    (0xA726, 0xA726, Status::Mapped("\u{A727}")),
+    (0xA727, 0xA727, Status::Valid),
+    (0xA728, 0xA728, Status::Mapped("\u{A729}")),
+    (0xA729, 0xA729, Status::Valid),
+    (0xA72A, 0xA72A, Status::Mapped("\u{A72B}")),
+    (0xA72B, 0xA72B, Status::Valid),
+    (0xA72C, 0xA72C, Status::Mapped("\u{A72D}")),
+    (0xA72D, 0xA72D, Status::Valid),
+    (0xA72E, 0xA72E, Status::Mapped("\u{A72F}")),
+    (0xA72F, 0xA731, Status::Valid),
+    (0xA732, 0xA732, Status::Mapped("\u{A733}")),
+    (0xA733, 0xA733, Status::Valid),
+    (0xA734, 0xA734, Status::Mapped("\u{A735}")),
+    (0xA735, 0xA735, Status::Valid),
+    (0xA736, 0xA736, Status::Mapped("\u{A737}")),
+    (0xA737, 0xA737, Status::Valid),
+    (0xA738, 0xA738, Status::Mapped("\u{A739}")),
+    (0xA739, 0xA739, Status::Valid),
+    (0xA73A, 0xA73A, Status::Mapped("\u{A73B}")),
+    (0xA73B, 0xA73B, Status::Valid),
+    (0xA73C, 0xA73C, Status::Mapped("\u{A73D}")),
+    (0xA73D, 0xA73D, Status::Valid),
+    (0xA73E, 0xA73E, Status::Mapped("\u{A73F}")),
+    (0xA73F, 0xA73F, Status::Valid),
+    (0xA740, 0xA740, Status::Mapped("\u{A741}")),
+    (0xA741, 0xA741, Status::Valid),
+    (0xA742, 0xA742, Status::Mapped("\u{A743}")),
+    (0xA743, 0xA743, Status::Valid),
+    (0xA744, 0xA744, Status::Mapped("\u{A745}")),
+    (0xA745, 0xA745, Status::Valid),
+    (0xA746, 0xA746, Status::Mapped("\u{A747}")),
+    (0xA747, 0xA747, Status::Valid),
+    (0xA748, 0xA748, Status::Mapped("\u{A749}")),
+    (0xA749, 0xA749, Status::Valid),
+    (0xA74A, 0xA74A, Status::Mapped("\u{A74B}")),
+    (0xA74B, 0xA74B, Status::Valid),
+    (0xA74C, 0xA74C, Status::Mapped("\u{A74D}")),
+    (0xA74D, 0xA74D, Status::Valid),
+    (0xA74E, 0xA74E, Status::Mapped("\u{A74F}")),
+    (0xA74F, 0xA74F, Status::Valid),
+    (0xA750, 0xA750, Status::Mapped("\u{A751}")),
+    (0xA751, 0xA751, Status::Valid),
+    (0xA752, 0xA752, Status::Mapped("\u{A753}")),
+    (0xA753, 0xA753, Status::Valid),
+    (0xA754, 0xA754, Status::Mapped("\u{A755}")),
+    (0xA755, 0xA755, Status::Valid),
+    (0xA756, 0xA756, Status::Mapped("\u{A757}")),
+    (0xA757, 0xA757, Status::Valid),
+    (0xA758, 0xA758, Status::Mapped("\u{A759}")),
+    (0xA759, 0xA759, Status::Valid),
+    (0xA75A, 0xA75A, Status::Mapped("\u{A75B}")),
+    (0xA75B, 0xA75B, Status::Valid),
+    (0xA75C, 0xA75C, Status::Mapped("\u{A75D}")),
+    (0xA75D, 0xA75D, Status::Valid),
+    (0xA75E, 0xA75E, Status::Mapped("\u{A75F}")),
+    (0xA75F, 0xA75F, Status::Valid),
+    (0xA760, 0xA760, Status::Mapped("\u{A761}")),
+    (0xA761, 0xA761, Status::Valid),
+    (0xA762, 0xA762, Status::Mapped("\u{A763}")),
+    (0xA763, 0xA763, Status::Valid),
+    (0xA764, 0xA764, Status::Mapped("\u{A765}")),
+    (0xA765, 0xA765, Status::Valid),
+    (0xA766, 0xA766, Status::Mapped("\u{A767}")),
+    (0xA767, 0xA767, Status::Valid),
+    (0xA768, 0xA768, Status::Mapped("\u{A769}")),
+    (0xA769, 0xA769, Status::Valid),
+    (0xA76A, 0xA76A, Status::Mapped("\u{A76B}")),
+    (0xA76B, 0xA76B, Status::Valid),
+    (0xA76C, 0xA76C, Status::Mapped("\u{A76D}")),
+    (0xA76D, 0xA76D, Status::Valid),
+    (0xA76E, 0xA76E, Status::Mapped("\u{A76F}")),
+    (0xA76F, 0xA76F, Status::Valid),
+    (0xA770, 0xA770, Status::Mapped("\u{A76F}")),
+    (0xA771, 0xA778, Status::Valid),
+    (0xA779, 0xA779, Status::Mapped("\u{A77A}")),
+    (0xA77A, 0xA77A, Status::Valid),
+    (0xA77B, 0xA77B, Status::Mapped("\u{A77C}")),
+    (0xA77C, 0xA77C, Status::Valid),
+    (0xA77D, 0xA77D, Status::Mapped("\u{1D79}")),
+    (0xA77E, 0xA77E, Status::Mapped("\u{A77F}")),
+    (0xA77F, 0xA77F, Status::Valid),
+    (0xA780, 0xA780, Status::Mapped("\u{A781}")),
+    (0xA781, 0xA781, Status::Valid),
+    (0xA782, 0xA782, Status::Mapped("\u{A783}")),
+    (0xA783, 0xA783, Status::Valid),
+    (0xA784, 0xA784, Status::Mapped("\u{A785}")),
+    (0xA785, 0xA785, Status::Valid),
+    (0xA786, 0xA786, Status::Mapped("\u{A787}")),
+    (0xA787, 0xA78A, Status::Valid),
+    (0xA78B, 0xA78B, Status::Mapped("\u{A78C}")),
+    (0xA78C, 0xA78C, Status::Valid),
+    (0xA78D, 0xA78D, Status::Mapped("\u{265}")),
+    (0xA78E, 0xA78F, Status::Valid),
+    (0xA790, 0xA790, Status::Mapped("\u{A791}")),
+    (0xA791, 0xA791, Status::Valid),
+    (0xA792, 0xA792, Status::Mapped("\u{A793}")),
+    (0xA793, 0xA795, Status::Valid),
+    (0xA796, 0xA796, Status::Mapped("\u{A797}")),
+    (0xA797, 0xA797, Status::Valid),
+    (0xA798, 0xA798, Status::Mapped("\u{A799}")),
+    (0xA799, 0xA799, Status::Valid),
+    (0xA79A, 0xA79A, Status::Mapped("\u{A79B}")),
+    (0xA79B, 0xA79B, Status::Valid),
+    (0xA79C, 0xA79C, Status::Mapped("\u{A79D}")),
+    (0xA79D, 0xA79D, Status::Valid),
+    (0xA79E, 0xA79E, Status::Mapped("\u{A79F}")),
+    (0xA79F, 0xA79F, Status::Valid),
+    (0xA7A0, 0xA7A0, Status::Mapped("\u{A7A1}")),
+    (0xA7A1, 0xA7A1, Status::Valid),
+    (0xA7A2, 0xA7A2, Status::Mapped("\u{A7A3}")),
+    (0xA7A3, 0xA7A3, Status::Valid),
+    (0xA7A4, 0xA7A4, Status::Mapped("\u{A7A5}")),
+    (0xA7A5, 0xA7A5, Status::Valid),
+    (0xA7A6, 0xA7A6, Status::Mapped("\u{A7A7}")),
+    (0xA7A7, 0xA7A7, Status::Valid),
+    (0xA7A8, 0xA7A8, Status::Mapped("\u{A7A9}")),
+    (0xA7A9, 0xA7A9, Status::Valid),
+    (0xA7AA, 0xA7AA, Status::Mapped("\u{266}")),
+    (0xA7AB, 0xA7AB, Status::Mapped("\u{25C}")),
+    (0xA7AC, 0xA7AC, Status::Mapped("\u{261}")),
+    (0xA7AD, 0xA7AD, Status::Mapped("\u{26C}")),
+    (0xA7AE, 0xA7AE, Status::Mapped("\u{26A}")),
+    (0xA7AF, 0xA7AF, Status::Valid),
+    (0xA7B0, 0xA7B0, Status::Mapped("\u{29E}")),
+    (0xA7B1, 0xA7B1, Status::Mapped("\u{287}")),
+    (0xA7B2, 0xA7B2, Status::Mapped("\u{29D}")),
+    (0xA7B3, 0xA7B3, Status::Mapped("\u{AB53}")),
+    (0xA7B4, 0xA7B4, Status::Mapped("\u{A7B5}")),
+    (0xA7B5, 0xA7B5, Status::Valid),
+    (0xA7B6, 0xA7B6, Status::Mapped("\u{A7B7}")),
+    (0xA7B7, 0xA7B7, Status::Valid),
+    (0xA7B8, 0xA7B8, Status::Mapped("\u{A7B9}")),
+    (0xA7B9, 0xA7B9, Status::Valid),
+    (0xA7BA, 0xA7BA, Status::Mapped("\u{A7BB}")),
+    (0xA7BB, 0xA7BB, Status::Valid),
+    (0xA7BC, 0xA7BC, Status::Mapped("\u{A7BD}")),
+    (0xA7BD, 0xA7BD, Status::Valid),
+    (0xA7BE, 0xA7BE, Status::Mapped("\u{A7BF}")),
+    (0xA7BF, 0xA7BF, Status::Valid),
+    (0xA7C0, 0xA7C0, Status::Mapped("\u{A7C1}")),
+    (0xA7C1, 0xA7C1, Status::Valid),
+    (0xA7C2, 0xA7C2, Status::Mapped("\u{A7C3}")),
+    (0xA7C3, 0xA7C3, Status::Valid),
+    (0xA7C4, 0xA7C4, Status::Mapped("\u{A794}")),
+    (0xA7C5, 0xA7C5, Status::Mapped("\u{282}")),
+    (0xA7C6, 0xA7C6, Status::Mapped("\u{1D8E}")),
+    (0xA7C7, 0xA7C7, Status::Mapped("\u{A7C8}")),
+    (0xA7C8, 0xA7C8, Status::Valid),
+    (0xA7C9, 0xA7C9, Status::Mapped("\u{A7CA}")),
+    (0xA7CA, 0xA7CA, Status::Valid),
+    (0xA7CB, 0xA7CF, Status::Disallowed),
+    (0xA7D0, 0xA7D0, Status::Mapped("\u{A7D1}")),
+    (0xA7D1, 0xA7D1, Status::Valid),
+    (0xA7D2, 0xA7D2, Status::Disallowed),
+    (0xA7D3, 0xA7D3, Status::Valid),
+    (0xA7D4, 0xA7D4, Status::Disallowed),
+    (0xA7D5, 0xA7D5, Status::Valid),
+    (0xA7D6, 0xA7D6, Status::Mapped("\u{A7D7}")),
+    (0xA7D7, 0xA7D7, Status::Valid),
+    (0xA7D8, 0xA7D8, Status::Mapped("\u{A7D9}")),
+    (0xA7D9, 0xA7D9, Status::Valid),
+    (0xA7DA, 0xA7F1, Status::Disallowed),
+    (0xA7F2, 0xA7F2, Status::Mapped("c")),
+    (0xA7F3, 0xA7F3, Status::Mapped("f")),
+    (0xA7F4, 0xA7F4, Status::Mapped("q")),
+    (0xA7F5, 0xA7F5, Status::Mapped("\u{A7F6}")),
+    (0xA7F6, 0xA7F7, Status::Valid),
+    (0xA7F8, 0xA7F8, Status::Mapped("\u{127}")),
+    (0xA7F9, 0xA7F9, Status::Mapped("\u{153}")),
+    (0xA7FA, 0xA82C, Status::Valid),
+    (0xA82D, 0xA82F, Status::Disallowed),
+    (0xA830, 0xA839, Status::Valid),
+    (0xA83A, 0xA83F, Status::Disallowed),
+    (0xA840, 0xA877, Status::Valid),
+    (0xA878, 0xA87F, Status::Disallowed),
+    (0xA880, 0xA8C5, Status::Valid),
+    (0xA8C6, 0xA8CD, Status::Disallowed),
+    (0xA8CE, 0xA8D9, Status::Valid),
+    (0xA8DA, 0xA8DF, Status::Disallowed),
+    (0xA8E0, 0xA953, Status::Valid),
+    (0xA954, 0xA95E, Status::Disallowed),
+    (0xA95F, 0xA97C, Status::Valid),
+    (0xA97D, 0xA97F, Status::Disallowed),
+    (0xA980, 0xA9CD, Status::Valid),
+    (0xA9CE, 0xA9CE, Status::Disallowed),
+    (0xA9CF, 0xA9D9, Status::Valid),
+    (0xA9DA, 0xA9DD, Status::Disallowed),
+    (0xA9DE, 0xA9FE, Status::Valid),
+    (0xA9FF, 0xA9FF, Status::Disallowed),
+    (0xAA00, 0xAA36, Status::Valid),
+    (0xAA37, 0xAA3F, Status::Disallowed),
+    (0xAA40, 0xAA4D, Status::Valid),
+    (0xAA4E, 0xAA4F, Status::Disallowed),
+    (0xAA50, 0xAA59, Status::Valid),
+    (0xAA5A, 0xAA5B, Status::Disallowed),
+    (0xAA5C, 0xAAC2, Status::Valid),
+    (0xAAC3, 0xAADA, Status::Disallowed),
+    (0xAADB, 0xAAF6, Status::Valid),
+    (0xAAF7, 0xAB00, Status::Disallowed),
+    (0xAB01, 0xAB06, Status::Valid),
+    (0xAB07, 0xAB08, Status::Disallowed),
+    (0xAB09, 0xAB0E, Status::Valid),
+    (0xAB0F, 0xAB10, Status::Disallowed),
+    (0xAB11, 0xAB16, Status::Valid),
+    (0xAB17, 0xAB1F, Status::Disallowed),
+    (0xAB20, 0xAB26, Status::Valid),
+    (0xAB27, 0xAB27, Status::Disallowed),
+    (0xAB28, 0xAB2E, Status::Valid),
+    (0xAB2F, 0xAB2F, Status::Disallowed),
+    (0xAB30, 0xAB5B, Status::Valid),
+    (0xAB5C, 0xAB5C, Status::Mapped("\u{A727}")),
+    (0xAB5D, 0xAB5D, Status::Mapped("\u{AB37}")),
+    (0xAB5E, 0xAB5E, Status::Mapped("\u{26B}")),
+    (0xAB5F, 0xAB5F, Status::Mapped("\u{AB52}")),
+    (0xAB60, 0xAB68, Status::Valid),
+    (0xAB69, 0xAB69, Status::Mapped("\u{28D}")),
+    (0xAB6A, 0xAB6B, Status::Valid),
+    (0xAB6C, 0xAB6F, Status::Disallowed),
+    (0xAB70, 0xAB70, Status::Mapped("\u{13A0}")),
+    (0xAB71, 0xAB71, Status::Mapped("\u{13A1}")),
+    (0xAB72, 0xAB72, Status::Mapped("\u{13A2}")),
+    (0xAB73, 0xAB73, Status::Mapped("\u{13A3}")),
+    (0xAB74, 0xAB74, Status::Mapped("\u{13A4}")),
+    (0xAB75, 0xAB75, Status::Mapped("\u{13A5}")),
+    (0xAB76, 0xAB76, Status::Mapped("\u{13A6}")),
+    (0xAB77, 0xAB77, Status::Mapped("\u{13A7}")),
+    (0xAB78, 0xAB78, Status::Mapped("\u{13A8}")),
+    (0xAB79, 0xAB79, Status::Mapped("\u{13A9}")),
+    (0xAB7A, 0xAB7A, Status::Mapped("\u{13AA}")),
+    (0xAB7B, 0xAB7B, Status::Mapped("\u{13AB}")),
+    (0xAB7C, 0xAB7C, Status::Mapped("\u{13AC}")),
+    (0xAB7D, 0xAB7D, Status::Mapped("\u{13AD}")),
+    (0xAB7E, 0xAB7E, Status::Mapped("\u{13AE}")),
+    (0xAB7F, 0xAB7F, Status::Mapped("\u{13AF}")),
+    (0xAB80, 0xAB80, Status::Mapped("\u{13B0}")),
+    (0xAB81, 0xAB81, Status::Mapped("\u{13B1}")),
+    (0xAB82, 0xAB82, Status::Mapped("\u{13B2}")),
+    (0xAB83, 0xAB83, Status::Mapped("\u{13B3}")),
+    (0xAB84, 0xAB84, Status::Mapped("\u{13B4}")),
+    (0xAB85, 0xAB85, Status::Mapped("\u{13B5}")),
+    (0xAB86, 0xAB86, Status::Mapped("\u{13B6}")),
+    (0xAB87, 0xAB87, Status::Mapped("\u{13B7}")),
+    (0xAB88, 0xAB88, Status::Mapped("\u{13B8}")),
+    (0xAB89, 0xAB89, Status::Mapped("\u{13B9}")),
+    (0xAB8A, 0xAB8A, Status::Mapped("\u{13BA}")),
+    (0xAB8B, 0xAB8B, Status::Mapped("\u{13BB}")),
+    (0xAB8C, 0xAB8C, Status::Mapped("\u{13BC}")),
+    (0xAB8D, 0xAB8D, Status::Mapped("\u{13BD}")),
+    (0xAB8E, 0xAB8E, Status::Mapped("\u{13BE}")),
+    (0xAB8F, 0xAB8F, Status::Mapped("\u{13BF}")),
+    (0xAB90, 0xAB90, Status::Mapped("\u{13C0}")),
+    (0xAB91, 0xAB91, Status::Mapped("\u{13C1}")),
+    (0xAB92, 0xAB92, Status::Mapped("\u{13C2}")),
+    (0xAB93, 0xAB93, Status::Mapped("\u{13C3}")),
+    (0xAB94, 0xAB94, Status::Mapped("\u{13C4}")),
+    (0xAB95, 0xAB95, Status::Mapped("\u{13C5}")),
+    (0xAB96, 0xAB96, Status::Mapped("\u{13C6}")),
+    (0xAB97, 0xAB97, Status::Mapped("\u{13C7}")),
+    (0xAB98, 0xAB98, Status::Mapped("\u{13C8}")),
+    (0xAB99, 0xAB99, Status::Mapped("\u{13C9}")),
+    (0xAB9A, 0xAB9A, Status::Mapped("\u{13CA}")),
+    (0xAB9B, 0xAB9B, Status::Mapped("\u{13CB}")),
+    (0xAB9C, 0xAB9C, Status::Mapped("\u{13CC}")),
+    (0xAB9D, 0xAB9D, Status::Mapped("\u{13CD}")),
+    (0xAB9E, 0xAB9E, Status::Mapped("\u{13CE}")),
+    (0xAB9F, 0xAB9F, Status::Mapped("\u{13CF}")),
+    (0xABA0, 0xABA0, Status::Mapped("\u{13D0}")),
+    (0xABA1, 0xABA1, Status::Mapped("\u{13D1}")),
+    (0xABA2, 0xABA2, Status::Mapped("\u{13D2}")),
+    (0xABA3, 0xABA3, Status::Mapped("\u{13D3}")),
+    (0xABA4, 0xABA4, Status::Mapped("\u{13D4}")),
+    (0xABA5, 0xABA5, Status::Mapped("\u{13D5}")),
+    (0xABA6, 0xABA6, Status::Mapped("\u{13D6}")),
+    (0xABA7, 0xABA7, Status::Mapped("\u{13D7}")),
+    (0xABA8, 0xABA8, Status::Mapped("\u{13D8}")),
+    (0xABA9, 0xABA9, Status::Mapped("\u{13D9}")),
+    (0xABAA, 0xABAA, Status::Mapped("\u{13DA}")),
+    (0xABAB, 0xABAB, Status::Mapped("\u{13DB}")),
+    (0xABAC, 0xABAC, Status::Mapped("\u{13DC}")),
+    (0xABAD, 0xABAD, Status::Mapped("\u{13DD}")),
+    (0xABAE, 0xABAE, Status::Mapped("\u{13DE}")),
+    (0xABAF, 0xABAF, Status::Mapped("\u{13DF}")),
+    (0xABB0, 0xABB0, Status::Mapped("\u{13E0}")),
+    (0xABB1, 0xABB1, Status::Mapped("\u{13E1}")),
+    (0xABB2, 0xABB2, Status::Mapped("\u{13E2}")),
+    (0xABB3, 0xABB3, Status::Mapped("\u{13E3}")),
+    (0xABB4, 0xABB4, Status::Mapped("\u{13E4}")),
+    (0xABB5, 0xABB5, Status::Mapped("\u{13E5}")),
+    (0xABB6, 0xABB6, Status::Mapped("\u{13E6}")),
+    (0xABB7, 0xABB7, Status::Mapped("\u{13E7}")),
+    (0xABB8, 0xABB8, Status::Mapped("\u{13E8}")),
+    (0xABB9, 0xABB9, Status::Mapped("\u{13E9}")),
+    (0xABBA, 0xABBA, Status::Mapped("\u{13EA}")),
+    (0xABBB, 0xABBB, Status::Mapped("\u{13EB}")),
+    (0xABBC, 0xABBC, Status::Mapped("\u{13EC}")),
+    (0xABBD, 0xABBD, Status::Mapped("\u{13ED}")),
+    (0xABBE, 0xABBE, Status::Mapped("\u{13EE}")),
+    (0xABBF, 0xABBF, Status::Mapped("\u{13EF}")),
+    (0xABC0, 0xABED, Status::Valid),
+    (0xABEE, 0xABEF, Status::Disallowed),
+    (0xABF0, 0xABF9, Status::Valid),
+    (0xABFA, 0xABFF, Status::Disallowed),
+    (0xAC00, 0xD7A3, Status::Valid),
+    (0xD7A4, 0xD7AF, Status::Disallowed),
+    (0xD7B0, 0xD7C6, Status::Valid),
+    (0xD7C7, 0xD7CA, Status::Disallowed),
+    (0xD7CB, 0xD7FB, Status::Valid),
+    (0xD7FC, 0xF8FF, Status::Disallowed),
+    (0xF900, 0xF900, Status::Mapped("\u{8C48}")),
+    (0xF901, 0xF901, Status::Mapped("\u{66F4}")),
+    (0xF902, 0xF902, Status::Mapped("\u{8ECA}")),
+    (0xF903, 0xF903, Status::Mapped("\u{8CC8}")),
+    (0xF904, 0xF904, Status::Mapped("\u{6ED1}")),
+    (0xF905, 0xF905, Status::Mapped("\u{4E32}")),
+    (0xF906, 0xF906, Status::Mapped("\u{53E5}")),
+    (0xF907, 0xF908, Status::Mapped("\u{9F9C}")),
+    (0xF909, 0xF909, Status::Mapped("\u{5951}")),
+    (0xF90A, 0xF90A, Status::Mapped("\u{91D1}")),
+    (0xF90B, 0xF90B, Status::Mapped("\u{5587}")),
+    (0xF90C, 0xF90C, Status::Mapped("\u{5948}")),
+    (0xF90D, 0xF90D, Status::Mapped("\u{61F6}")),
+    (0xF90E, 0xF90E, Status::Mapped("\u{7669}")),
+    (0xF90F, 0xF90F, Status::Mapped("\u{7F85}")),
+    (0xF910, 0xF910, Status::Mapped("\u{863F}")),
+    (0xF911, 0xF911, Status::Mapped("\u{87BA}")),
+    (0xF912, 0xF912, Status::Mapped("\u{88F8}")),
+    (0xF913, 0xF913, Status::Mapped("\u{908F}")),
+    (0xF914, 0xF914, Status::Mapped("\u{6A02}")),
+    (0xF915, 0xF915, Status::Mapped("\u{6D1B}")),
+    (0xF916, 0xF916, Status::Mapped("\u{70D9}")),
+    (0xF917, 0xF917, Status::Mapped("\u{73DE}")),
+    (0xF918, 0xF918, Status::Mapped("\u{843D}")),
+    (0xF919, 0xF919, Status::Mapped("\u{916A}")),
+    (0xF91A, 0xF91A, Status::Mapped("\u{99F1}")),
+    (0xF91B, 0xF91B, Status::Mapped("\u{4E82}")),
+    (0xF91C, 0xF91C, Status::Mapped("\u{5375}")),
+    (0xF91D, 0xF91D, Status::Mapped("\u{6B04}")),
+    (0xF91E, 0xF91E, Status::Mapped("\u{721B}")),
+    (0xF91F, 0xF91F, Status::Mapped("\u{862D}")),
+    (0xF920, 0xF920, Status::Mapped("\u{9E1E}")),
+    (0xF921, 0xF921, Status::Mapped("\u{5D50}")),
+    (0xF922, 0xF922, Status::Mapped("\u{6FEB}")),
+    (0xF923, 0xF923, Status::Mapped("\u{85CD}")),
+    (0xF924, 0xF924, Status::Mapped("\u{8964}")),
+    (0xF925, 0xF925, Status::Mapped("\u{62C9}")),
+    (0xF926, 0xF926, Status::Mapped("\u{81D8}")),
+    (0xF927, 0xF927, Status::Mapped("\u{881F}")),
+    (0xF928, 0xF928, Status::Mapped("\u{5ECA}")),
+    (0xF929, 0xF929, Status::Mapped("\u{6717}")),
+    (0xF92A, 0xF92A, Status::Mapped("\u{6D6A}")),
+    (0xF92B, 0xF92B, Status::Mapped("\u{72FC}")),
+    (0xF92C, 0xF92C, Status::Mapped("\u{90CE}")),
+    (0xF92D, 0xF92D, Status::Mapped("\u{4F86}")),
+    (0xF92E, 0xF92E, Status::Mapped("\u{51B7}")),
+    (0xF92F, 0xF92F, Status::Mapped("\u{52DE}")),
+    (0xF930, 0xF930, Status::Mapped("\u{64C4}")),
+    (0xF931, 0xF931, Status::Mapped("\u{6AD3}")),
+    (0xF932, 0xF932, Status::Mapped("\u{7210}")),
+    (0xF933, 0xF933, Status::Mapped("\u{76E7}")),
+    (0xF934, 0xF934, Status::Mapped("\u{8001}")),
+    (0xF935, 0xF935, Status::Mapped("\u{8606}")),
+    (0xF936, 0xF936, Status::Mapped("\u{865C}")),
+    (0xF937, 0xF937, Status::Mapped("\u{8DEF}")),
+    (0xF938, 0xF938, Status::Mapped("\u{9732}")),
+    (0xF939, 0xF939, Status::Mapped("\u{9B6F}")),
+    (0xF93A, 0xF93A, Status::Mapped("\u{9DFA}")),
+    (0xF93B, 0xF93B, Status::Mapped("\u{788C}")),
+    (0xF93C, 0xF93C, Status::Mapped("\u{797F}")),
+    (0xF93D, 0xF93D, Status::Mapped("\u{7DA0}")),
+    (0xF93E, 0xF93E, Status::Mapped("\u{83C9}")),
+    (0xF93F, 0xF93F, Status::Mapped("\u{9304}")),
+    (0xF940, 0xF940, Status::Mapped("\u{9E7F}")),
+    (0xF941, 0xF941, Status::Mapped("\u{8AD6}")),
+    (0xF942, 0xF942, Status::Mapped("\u{58DF}")),
+    (0xF943, 0xF943, Status::Mapped("\u{5F04}")),
+    (0xF944, 0xF944, Status::Mapped("\u{7C60}")),
+    (0xF945, 0xF945, Status::Mapped("\u{807E}")),
+    (0xF946, 0xF946, Status::Mapped("\u{7262}")),
+    (0xF947, 0xF947, Status::Mapped("\u{78CA}")),
+    (0xF948, 0xF948, Status::Mapped("\u{8CC2}")),
+    (0xF949, 0xF949, Status::Mapped("\u{96F7}")),
+    (0xF94A, 0xF94A, Status::Mapped("\u{58D8}")),
+    (0xF94B, 0xF94B, Status::Mapped("\u{5C62}")),
+    (0xF94C, 0xF94C, Status::Mapped("\u{6A13}")),
+    (0xF94D, 0xF94D, Status::Mapped("\u{6DDA}")),
+    (0xF94E, 0xF94E, Status::Mapped("\u{6F0F}")),
+    (0xF94F, 0xF94F, Status::Mapped("\u{7D2F}")),
+    (0xF950, 0xF950, Status::Mapped("\u{7E37}")),
+    (0xF951, 0xF951, Status::Mapped("\u{964B}")),
+    (0xF952, 0xF952, Status::Mapped("\u{52D2}")),
+    (0xF953, 0xF953, Status::Mapped("\u{808B}")),
+    (0xF954, 0xF954, Status::Mapped("\u{51DC}")),
+    (0xF955, 0xF955, Status::Mapped("\u{51CC}")),
+    (0xF956, 0xF956, Status::Mapped("\u{7A1C}")),
+    (0xF957, 0xF957, Status::Mapped("\u{7DBE}")),
+    (0xF958, 0xF958, Status::Mapped("\u{83F1}")),
+    (0xF959, 0xF959, Status::Mapped("\u{9675}")),
+    (0xF95A, 0xF95A, Status::Mapped("\u{8B80}")),
+    (0xF95B, 0xF95B, Status::Mapped("\u{62CF}")),
+    (0xF95C, 0xF95C, Status::Mapped("\u{6A02}")),
+    (0xF95D, 0xF95D, Status::Mapped("\u{8AFE}")),
+    (0xF95E, 0xF95E, Status::Mapped("\u{4E39}")),
+    (0xF95F, 0xF95F, Status::Mapped("\u{5BE7}")),
+    (0xF960, 0xF960, Status::Mapped("\u{6012}")),
+    (0xF961, 0xF961, Status::Mapped("\u{7387}")),
+    (0xF962, 0xF962, Status::Mapped("\u{7570}")),
+    (0xF963, 0xF963, Status::Mapped("\u{5317}")),
+    (0xF964, 0xF964, Status::Mapped("\u{78FB}")),
+    (0xF965, 0xF965, Status::Mapped("\u{4FBF}")),
+    (0xF966, 0xF966, Status::Mapped("\u{5FA9}")),
+    (0xF967, 0xF967, Status::Mapped("\u{4E0D}")),
+    (0xF968, 0xF968, Status::Mapped("\u{6CCC}")),
+    (0xF969, 0xF969, Status::Mapped("\u{6578}")),
+    (0xF96A, 0xF96A, Status::Mapped("\u{7D22}")),
+    (0xF96B, 0xF96B, Status::Mapped("\u{53C3}")),
+    (0xF96C, 0xF96C, Status::Mapped("\u{585E}")),
+    (0xF96D, 0xF96D, Status::Mapped("\u{7701}")),
+    (0xF96E, 0xF96E, Status::Mapped("\u{8449}")),
+    (0xF96F, 0xF96F, Status::Mapped("\u{8AAA}")),
+    (0xF970, 0xF970, Status::Mapped("\u{6BBA}")),
+    (0xF971, 0xF971, Status::Mapped("\u{8FB0}")),
+    (0xF972, 0xF972, Status::Mapped("\u{6C88}")),
+    (0xF973, 0xF973, Status::Mapped("\u{62FE}")),
+    (0xF974, 0xF974, Status::Mapped("\u{82E5}")),
+    (0xF975, 0xF975, Status::Mapped("\u{63A0}")),
+    (0xF976, 0xF976, Status::Mapped("\u{7565}")),
+    (0xF977, 0xF977, Status::Mapped("\u{4EAE}")),
+    (0xF978, 0xF978, Status::Mapped("\u{5169}")),
+    (0xF979, 0xF979, Status::Mapped("\u{51C9}")),
+    (0xF97A, 0xF97A, Status::Mapped("\u{6881}")),
+    (0xF97B, 0xF97B, Status::Mapped("\u{7CE7}")),
+    (0xF97C, 0xF97C, Status::Mapped("\u{826F}")),
+    (0xF97D, 0xF97D, Status::Mapped("\u{8AD2}")),
+    (0xF97E, 0xF97E, Status::Mapped("\u{91CF}")),
+    (0xF97F, 0xF97F, Status::Mapped("\u{52F5}")),
+    (0xF980, 0xF980, Status::Mapped("\u{5442}")),
+    (0xF981, 0xF981, Status::Mapped("\u{5973}")),
+    (0xF982, 0xF982, Status::Mapped("\u{5EEC}")),
+    (0xF983, 0xF983, Status::Mapped("\u{65C5}")),
+    (0xF984, 0xF984, Status::Mapped("\u{6FFE}")),
+    (0xF985, 0xF985, Status::Mapped("\u{792A}")),
+    (0xF986, 0xF986, Status::Mapped("\u{95AD}")),
+    (0xF987, 0xF987, Status::Mapped("\u{9A6A}")),
+    (0xF988, 0xF988, Status::Mapped("\u{9E97}")),
+    (0xF989, 0xF989, Status::Mapped("\u{9ECE}")),
+    (0xF98A, 0xF98A, Status::Mapped("\u{529B}")),
+    (0xF98B, 0xF98B, Status::Mapped("\u{66C6}")),
+    (0xF98C, 0xF98C, Status::Mapped("\u{6B77}")),
+    (0xF98D, 0xF98D, Status::Mapped("\u{8F62}")),
+    (0xF98E, 0xF98E, Status::Mapped("\u{5E74}")),
+    (0xF98F, 0xF98F, Status::Mapped("\u{6190}")),
+    (0xF990, 0xF990, Status::Mapped("\u{6200}")),
+    (0xF991, 0xF991, Status::Mapped("\u{649A}")),
+    (0xF992, 0xF992, Status::Mapped("\u{6F23}")),
+    (0xF993, 0xF993, Status::Mapped("\u{7149}")),
+    (0xF994, 0xF994, Status::Mapped("\u{7489}")),
+    (0xF995, 0xF995, Status::Mapped("\u{79CA}")),
+    (0xF996, 0xF996, Status::Mapped("\u{7DF4}")),
+    (0xF997, 0xF997, Status::Mapped("\u{806F}")),
+    (0xF998, 0xF998, Status::Mapped("\u{8F26}")),
+    (0xF999, 0xF999, Status::Mapped("\u{84EE}")),
+    (0xF99A, 0xF99A, Status::Mapped("\u{9023}")),
+    (0xF99B, 0xF99B, Status::Mapped("\u{934A}")),
+    (0xF99C, 0xF99C, Status::Mapped("\u{5217}")),
+    (0xF99D, 0xF99D, Status::Mapped("\u{52A3}")),
+    (0xF99E, 0xF99E, Status::Mapped("\u{54BD}")),
+    (0xF99F, 0xF99F, Status::Mapped("\u{70C8}")),
+    (0xF9A0, 0xF9A0, Status::Mapped("\u{88C2}")),
+    (0xF9A1, 0xF9A1, Status::Mapped("\u{8AAA}")),
+    (0xF9A2, 0xF9A2, Status::Mapped("\u{5EC9}")),
+    (0xF9A3, 0xF9A3, Status::Mapped("\u{5FF5}")),
+    (0xF9A4, 0xF9A4, Status::Mapped("\u{637B}")),
+    (0xF9A5, 0xF9A5, Status::Mapped("\u{6BAE}")),
+    (0xF9A6, 0xF9A6, Status::Mapped("\u{7C3E}")),
+    (0xF9A7, 0xF9A7, Status::Mapped("\u{7375}")),
+    (0xF9A8, 0xF9A8, Status::Mapped("\u{4EE4}")),
+    (0xF9A9, 0xF9A9, Status::Mapped("\u{56F9}")),
+    (0xF9AA, 0xF9AA, Status::Mapped("\u{5BE7}")),
+    (0xF9AB, 0xF9AB, Status::Mapped("\u{5DBA}")),
+    (0xF9AC, 0xF9AC, Status::Mapped("\u{601C}")),
+    (0xF9AD, 0xF9AD, Status::Mapped("\u{73B2}")),
+    (0xF9AE, 0xF9AE, Status::Mapped("\u{7469}")),
+    (0xF9AF, 0xF9AF, Status::Mapped("\u{7F9A}")),
+    (0xF9B0, 0xF9B0, Status::Mapped("\u{8046}")),
+    (0xF9B1, 0xF9B1, Status::Mapped("\u{9234}")),
+    (0xF9B2, 0xF9B2, Status::Mapped("\u{96F6}")),
+    (0xF9B3, 0xF9B3, Status::Mapped("\u{9748}")),
+    (0xF9B4, 0xF9B4, Status::Mapped("\u{9818}")),
+    (0xF9B5, 0xF9B5, Status::Mapped("\u{4F8B}")),
+    (0xF9B6, 0xF9B6, Status::Mapped("\u{79AE}")),
+    (0xF9B7, 0xF9B7, Status::Mapped("\u{91B4}")),
+    (0xF9B8, 0xF9B8, Status::Mapped("\u{96B8}")),
+    (0xF9B9, 0xF9B9, Status::Mapped("\u{60E1}")),
+    (0xF9BA, 0xF9BA, Status::Mapped("\u{4E86}")),
+    (0xF9BB, 0xF9BB, Status::Mapped("\u{50DA}")),
+    (0xF9BC, 0xF9BC, Status::Mapped("\u{5BEE}")),
+    (0xF9BD, 0xF9BD, Status::Mapped("\u{5C3F}")),
+    (0xF9BE, 0xF9BE, Status::Mapped("\u{6599}")),
+    (0xF9BF, 0xF9BF, Status::Mapped("\u{6A02}")),
+    (0xF9C0, 0xF9C0, Status::Mapped("\u{71CE}")),
+    (0xF9C1, 0xF9C1, Status::Mapped("\u{7642}")),
+    (0xF9C2, 0xF9C2, Status::Mapped("\u{84FC}")),
+    (0xF9C3, 0xF9C3, Status::Mapped("\u{907C}")),
+    (0xF9C4, 0xF9C4, Status::Mapped("\u{9F8D}")),
+    (0xF9C5, 0xF9C5, Status::Mapped("\u{6688}")),
+    (0xF9C6, 0xF9C6, Status::Mapped("\u{962E}")),
+    (0xF9C7, 0xF9C7, Status::Mapped("\u{5289}")),
+    (0xF9C8, 0xF9C8, Status::Mapped("\u{677B}")),
+    (0xF9C9, 0xF9C9, Status::Mapped("\u{67F3}")),
+    (0xF9CA, 0xF9CA, Status::Mapped("\u{6D41}")),
+    (0xF9CB, 0xF9CB, Status::Mapped("\u{6E9C}")),
+    (0xF9CC, 0xF9CC, Status::Mapped("\u{7409}")),
+    (0xF9CD, 0xF9CD, Status::Mapped("\u{7559}")),
+    (0xF9CE, 0xF9CE, Status::Mapped("\u{786B}")),
+    (0xF9CF, 0xF9CF, Status::Mapped("\u{7D10}")),
+    (0xF9D0, 0xF9D0, Status::Mapped("\u{985E}")),
+    (0xF9D1, 0xF9D1, Status::Mapped("\u{516D}")),
+    (0xF9D2, 0xF9D2, Status::Mapped("\u{622E}")),
+    (0xF9D3, 0xF9D3, Status::Mapped("\u{9678}")),
+    (0xF9D4, 0xF9D4, Status::Mapped("\u{502B}")),
+    (0xF9D5, 0xF9D5, Status::Mapped("\u{5D19}")),
+    (0xF9D6, 0xF9D6, Status::Mapped("\u{6DEA}")),
+    (0xF9D7, 0xF9D7, Status::Mapped("\u{8F2A}")),
+    (0xF9D8, 0xF9D8, Status::Mapped("\u{5F8B}")),
+    (0xF9D9, 0xF9D9, Status::Mapped("\u{6144}")),
+    (0xF9DA, 0xF9DA, Status::Mapped("\u{6817}")),
+    (0xF9DB, 0xF9DB, Status::Mapped("\u{7387}")),
+    (0xF9DC, 0xF9DC, Status::Mapped("\u{9686}")),
+    (0xF9DD, 0xF9DD, Status::Mapped("\u{5229}")),
+    (0xF9DE, 0xF9DE, Status::Mapped("\u{540F}")),
+    (0xF9DF, 0xF9DF, Status::Mapped("\u{5C65}")),
+    (0xF9E0, 0xF9E0, Status::Mapped("\u{6613}")),
+    (0xF9E1, 0xF9E1, Status::Mapped("\u{674E}")),
+    (0xF9E2, 0xF9E2, Status::Mapped("\u{68A8}")),
+    (0xF9E3, 0xF9E3, Status::Mapped("\u{6CE5}")),
+    (0xF9E4, 0xF9E4, Status::Mapped("\u{7406}")),
+    (0xF9E5, 0xF9E5, Status::Mapped("\u{75E2}")),
+    (0xF9E6, 0xF9E6, Status::Mapped("\u{7F79}")),
+    (0xF9E7, 0xF9E7, Status::Mapped("\u{88CF}")),
+    (0xF9E8, 0xF9E8, Status::Mapped("\u{88E1}")),
+    (0xF9E9, 0xF9E9, Status::Mapped("\u{91CC}")),
+    (0xF9EA, 0xF9EA, Status::Mapped("\u{96E2}")),
+    (0xF9EB, 0xF9EB, Status::Mapped("\u{533F}")),
+    (0xF9EC, 0xF9EC, Status::Mapped("\u{6EBA}")),
+    (0xF9ED, 0xF9ED, Status::Mapped("\u{541D}")),
+    (0xF9EE, 0xF9EE, Status::Mapped("\u{71D0}")),
+    (0xF9EF, 0xF9EF, Status::Mapped("\u{7498}")),
+    (0xF9F0, 0xF9F0, Status::Mapped("\u{85FA}")),
+    (0xF9F1, 0xF9F1, Status::Mapped("\u{96A3}")),
+    (0xF9F2, 0xF9F2, Status::Mapped("\u{9C57}")),
+    (0xF9F3, 0xF9F3, Status::Mapped("\u{9E9F}")),
+    (0xF9F4, 0xF9F4, Status::Mapped("\u{6797}")),
+    (0xF9F5, 0xF9F5, Status::Mapped("\u{6DCB}")),
+    (0xF9F6, 0xF9F6, Status::Mapped("\u{81E8}")),
+    (0xF9F7, 0xF9F7, Status::Mapped("\u{7ACB}")),
+    (0xF9F8, 0xF9F8, Status::Mapped("\u{7B20}")),
+    (0xF9F9, 0xF9F9, Status::Mapped("\u{7C92}")),
+    (0xF9FA, 0xF9FA, Status::Mapped("\u{72C0}")),
+    (0xF9FB, 0xF9FB, Status::Mapped("\u{7099}")),
+    (0xF9FC, 0xF9FC, Status::Mapped("\u{8B58}")),
+    (0xF9FD, 0xF9FD, Status::Mapped("\u{4EC0}")),
+    (0xF9FE, 0xF9FE, Status::Mapped("\u{8336}")),
+    (0xF9FF, 0xF9FF, Status::Mapped("\u{523A}")),
+    (0xFA00, 0xFA00, Status::Mapped("\u{5207}")),
+    (0xFA01, 0xFA01, Status::Mapped("\u{5EA6}")),
+    (0xFA02, 0xFA02, Status::Mapped("\u{62D3}")),
+    (0xFA03, 0xFA03, Status::Mapped("\u{7CD6}")),
+    (0xFA04, 0xFA04, Status::Mapped("\u{5B85}")),
+    (0xFA05, 0xFA05, Status::Mapped("\u{6D1E}")),
+    (0xFA06, 0xFA06, Status::Mapped("\u{66B4}")),
+    (0xFA07, 0xFA07, Status::Mapped("\u{8F3B}")),
+    (0xFA08, 0xFA08, Status::Mapped("\u{884C}")),
+    (0xFA09, 0xFA09, Status::Mapped("\u{964D}")),
+    (0xFA0A, 0xFA0A, Status::Mapped("\u{898B}")),
+    (0xFA0B, 0xFA0B, Status::Mapped("\u{5ED3}")),
+    (0xFA0C, 0xFA0C, Status::Mapped("\u{5140}")),
+    (0xFA0D, 0xFA0D, Status::Mapped("\u{55C0}")),
+    (0xFA0E, 0xFA0F, Status::Valid),
+    (0xFA10, 0xFA10, Status::Mapped("\u{585A}")),
+    (0xFA11, 0xFA11, Status::Valid),
+    (0xFA12, 0xFA12, Status::Mapped("\u{6674}")),
+    (0xFA13, 0xFA14, Status::Valid),
+    (0xFA15, 0xFA15, Status::Mapped("\u{51DE}")),
+    (0xFA16, 0xFA16, Status::Mapped("\u{732A}")),
+    (0xFA17, 0xFA17, Status::Mapped("\u{76CA}")),
+    (0xFA18, 0xFA18, Status::Mapped("\u{793C}")),
+    (0xFA19, 0xFA19, Status::Mapped("\u{795E}")),
+    (0xFA1A, 0xFA1A, Status::Mapped("\u{7965}")),
+    (0xFA1B, 0xFA1B, Status::Mapped("\u{798F}")),
+    (0xFA1C, 0xFA1C, Status::Mapped("\u{9756}")),
+    (0xFA1D, 0xFA1D, Status::Mapped("\u{7CBE}")),
+    (0xFA1E, 0xFA1E, Status::Mapped("\u{7FBD}")),
+    (0xFA1F, 0xFA1F, Status::Valid),
+    (0xFA20, 0xFA20, Status::Mapped("\u{8612}")),
+    (0xFA21, 0xFA21, Status::Valid),
+    (0xFA22, 0xFA22, Status::Mapped("\u{8AF8}")),
+    (0xFA23, 0xFA24, Status::Valid),
+    (0xFA25, 0xFA25, Status::Mapped("\u{9038}")),
+    (0xFA26, 0xFA26, Status::Mapped("\u{90FD}")),
+    (0xFA27, 0xFA29, Status::Valid),
+    (0xFA2A, 0xFA2A, Status::Mapped("\u{98EF}")),
+    (0xFA2B, 0xFA2B, Status::Mapped("\u{98FC}")),
+    (0xFA2C, 0xFA2C, Status::Mapped("\u{9928}")),
+    (0xFA2D, 0xFA2D, Status::Mapped("\u{9DB4}")),
+    (0xFA2E, 0xFA2E, Status::Mapped("\u{90DE}")),
+    (0xFA2F, 0xFA2F, Status::Mapped("\u{96B7}")),
+    (0xFA30, 0xFA30, Status::Mapped("\u{4FAE}")),
+    (0xFA31, 0xFA31, Status::Mapped("\u{50E7}")),
+    (0xFA32, 0xFA32, Status::Mapped("\u{514D}")),
+    (0xFA33, 0xFA33, Status::Mapped("\u{52C9}")),
+    (0xFA34, 0xFA34, Status::Mapped("\u{52E4}")),
+    (0xFA35, 0xFA35, Status::Mapped("\u{5351}")),
+    (0xFA36, 0xFA36, Status::Mapped("\u{559D}")),
+    (0xFA37, 0xFA37, Status::Mapped("\u{5606}")),
+    (0xFA38, 0xFA38, Status::Mapped("\u{5668}")),
+    (0xFA39, 0xFA39, Status::Mapped("\u{5840}")),
+    (0xFA3A, 0xFA3A, Status::Mapped("\u{58A8}")),
+    (0xFA3B, 0xFA3B, Status::Mapped("\u{5C64}")),
+    (0xFA3C, 0xFA3C, Status::Mapped("\u{5C6E}")),
+    (0xFA3D, 0xFA3D, Status::Mapped("\u{6094}")),
+    (0xFA3E, 0xFA3E, Status::Mapped("\u{6168}")),
+    (0xFA3F, 0xFA3F, Status::Mapped("\u{618E}")),
+    (0xFA40, 0xFA40, Status::Mapped("\u{61F2}")),
+    (0xFA41, 0xFA41, Status::Mapped("\u{654F}")),
+    (0xFA42, 0xFA42, Status::Mapped("\u{65E2}")),
+    (0xFA43, 0xFA43, Status::Mapped("\u{6691}")),
+    (0xFA44, 0xFA44, Status::Mapped("\u{6885}")),
+    (0xFA45, 0xFA45, Status::Mapped("\u{6D77}")),
+    (0xFA46, 0xFA46, Status::Mapped("\u{6E1A}")),
+    (0xFA47, 0xFA47, Status::Mapped("\u{6F22}")),
+    (0xFA48, 0xFA48, Status::Mapped("\u{716E}")),
+    (0xFA49, 0xFA49, Status::Mapped("\u{722B}")),
+    (0xFA4A, 0xFA4A, Status::Mapped("\u{7422}")),
+    (0xFA4B, 0xFA4B, Status::Mapped("\u{7891}")),
+    (0xFA4C, 0xFA4C, Status::Mapped("\u{793E}")),
+    (0xFA4D, 0xFA4D, Status::Mapped("\u{7949}")),
+    (0xFA4E, 0xFA4E, Status::Mapped("\u{7948}")),
+    (0xFA4F, 0xFA4F, Status::Mapped("\u{7950}")),
+    (0xFA50, 0xFA50, Status::Mapped("\u{7956}")),
+    (0xFA51, 0xFA51, Status::Mapped("\u{795D}")),
+    (0xFA52, 0xFA52, Status::Mapped("\u{798D}")),
+    (0xFA53, 0xFA53, Status::Mapped("\u{798E}")),
+    (0xFA54, 0xFA54, Status::Mapped("\u{7A40}")),
+    (0xFA55, 0xFA55, Status::Mapped("\u{7A81}")),
+    (0xFA56, 0xFA56, Status::Mapped("\u{7BC0}")),
+    (0xFA57, 0xFA57, Status::Mapped("\u{7DF4}")),
+    (0xFA58, 0xFA58, Status::Mapped("\u{7E09}")),
+    (0xFA59, 0xFA59, Status::Mapped("\u{7E41}")),
+    (0xFA5A, 0xFA5A, Status::Mapped("\u{7F72}")),
+    (0xFA5B, 0xFA5B, Status::Mapped("\u{8005}")),
+    (0xFA5C, 0xFA5C, Status::Mapped("\u{81ED}")),
+    (0xFA5D, 0xFA5E, Status::Mapped("\u{8279}")),
+    (0xFA5F, 0xFA5F, Status::Mapped("\u{8457}")),
+    (0xFA60, 0xFA60, Status::Mapped("\u{8910}")),
+    (0xFA61, 0xFA61, Status::Mapped("\u{8996}")),
+    (0xFA62, 0xFA62, Status::Mapped("\u{8B01}")),
+    (0xFA63, 0xFA63, Status::Mapped("\u{8B39}")),
+    (0xFA64, 0xFA64, Status::Mapped("\u{8CD3}")),
+    (0xFA65, 0xFA65, Status::Mapped("\u{8D08}")),
+    (0xFA66, 0xFA66, Status::Mapped("\u{8FB6}")),
+    (0xFA67, 0xFA67, Status::Mapped("\u{9038}")),
+    (0xFA68, 0xFA68, Status::Mapped("\u{96E3}")),
+    (0xFA69, 0xFA69, Status::Mapped("\u{97FF}")),
+    (0xFA6A, 0xFA6A, Status::Mapped("\u{983B}")),
+    (0xFA6B, 0xFA6B, Status::Mapped("\u{6075}")),
+    (0xFA6C, 0xFA6C, Status::Mapped("\u{242EE}")),
+    (0xFA6D, 0xFA6D, Status::Mapped("\u{8218}")),
+    (0xFA6E, 0xFA6F, Status::Disallowed),
+    (0xFA70, 0xFA70, Status::Mapped("\u{4E26}")),
+    (0xFA71, 0xFA71, Status::Mapped("\u{51B5}")),
+    (0xFA72, 0xFA72, Status::Mapped("\u{5168}")),
+    (0xFA73, 0xFA73, Status::Mapped("\u{4F80}")),
+    (0xFA74, 0xFA74, Status::Mapped("\u{5145}")),
+    (0xFA75, 0xFA75, Status::Mapped("\u{5180}")),
+    (0xFA76, 0xFA76, Status::Mapped("\u{52C7}")),
+    (0xFA77, 0xFA77, Status::Mapped("\u{52FA}")),
+    (0xFA78, 0xFA78, Status::Mapped("\u{559D}")),
+    (0xFA79, 0xFA79, Status::Mapped("\u{5555}")),
+    (0xFA7A, 0xFA7A, Status::Mapped("\u{5599}")),
+    (0xFA7B, 0xFA7B, Status::Mapped("\u{55E2}")),
+    (0xFA7C, 0xFA7C, Status::Mapped("\u{585A}")),
+    (0xFA7D, 0xFA7D, Status::Mapped("\u{58B3}")),
+    (0xFA7E, 0xFA7E, Status::Mapped("\u{5944}")),
+    (0xFA7F, 0xFA7F, Status::Mapped("\u{5954}")),
+    (0xFA80, 0xFA80, Status::Mapped("\u{5A62}")),
+    (0xFA81, 0xFA81, Status::Mapped("\u{5B28}")),
+    (0xFA82, 0xFA82, Status::Mapped("\u{5ED2}")),
+    (0xFA83, 0xFA83, Status::Mapped("\u{5ED9}")),
+    (0xFA84, 0xFA84, Status::Mapped("\u{5F69}")),
+    (0xFA85, 0xFA85, Status::Mapped("\u{5FAD}")),
+    (0xFA86, 0xFA86, Status::Mapped("\u{60D8}")),
+    (0xFA87, 0xFA87, Status::Mapped("\u{614E}")),
+    (0xFA88, 0xFA88, Status::Mapped("\u{6108}")),
+    (0xFA89, 0xFA89, Status::Mapped("\u{618E}")),
+    (0xFA8A, 0xFA8A, Status::Mapped("\u{6160}")),
+    (0xFA8B, 0xFA8B, Status::Mapped("\u{61F2}")),
+    (0xFA8C, 0xFA8C, Status::Mapped("\u{6234}")),
+    (0xFA8D, 0xFA8D, Status::Mapped("\u{63C4}")),
+    (0xFA8E, 0xFA8E, Status::Mapped("\u{641C}")),
+    (0xFA8F, 0xFA8F, Status::Mapped("\u{6452}")),
+    (0xFA90, 0xFA90, Status::Mapped("\u{6556}")),
+    (0xFA91, 0xFA91, Status::Mapped("\u{6674}")),
+    (0xFA92, 0xFA92, Status::Mapped("\u{6717}")),
+    (0xFA93, 0xFA93, Status::Mapped("\u{671B}")),
+    (0xFA94, 0xFA94, Status::Mapped("\u{6756}")),
+    (0xFA95, 0xFA95, Status::Mapped("\u{6B79}")),
+    (0xFA96, 0xFA96, Status::Mapped("\u{6BBA}")),
+    (0xFA97, 0xFA97, Status::Mapped("\u{6D41}")),
+    (0xFA98, 0xFA98, Status::Mapped("\u{6EDB}")),
+    (0xFA99, 0xFA99, Status::Mapped("\u{6ECB}")),
+    (0xFA9A, 0xFA9A, Status::Mapped("\u{6F22}")),
+    (0xFA9B, 0xFA9B, Status::Mapped("\u{701E}")),
+    (0xFA9C, 0xFA9C, Status::Mapped("\u{716E}")),
+    (0xFA9D, 0xFA9D, Status::Mapped("\u{77A7}")),
+    (0xFA9E, 0xFA9E, Status::Mapped("\u{7235}")),
+    (0xFA9F, 0xFA9F, Status::Mapped("\u{72AF}")),
+    (0xFAA0, 0xFAA0, Status::Mapped("\u{732A}")),
+    (0xFAA1, 0xFAA1, Status::Mapped("\u{7471}")),
+    (0xFAA2, 0xFAA2, Status::Mapped("\u{7506}")),
+    (0xFAA3, 0xFAA3, Status::Mapped("\u{753B}")),
+    (0xFAA4, 0xFAA4, Status::Mapped("\u{761D}")),
+    (0xFAA5, 0xFAA5, Status::Mapped("\u{761F}")),
+    (0xFAA6, 0xFAA6, Status::Mapped("\u{76CA}")),
+    (0xFAA7, 0xFAA7, Status::Mapped("\u{76DB}")),
+    (0xFAA8, 0xFAA8, Status::Mapped("\u{76F4}")),
+    (0xFAA9, 0xFAA9, Status::Mapped("\u{774A}")),
+    (0xFAAA, 0xFAAA, Status::Mapped("\u{7740}")),
+    (0xFAAB, 0xFAAB, Status::Mapped("\u{78CC}")),
+    (0xFAAC, 0xFAAC, Status::Mapped("\u{7AB1}")),
+    (0xFAAD, 0xFAAD, Status::Mapped("\u{7BC0}")),
+    (0xFAAE, 0xFAAE, Status::Mapped("\u{7C7B}")),
+    (0xFAAF, 0xFAAF, Status::Mapped("\u{7D5B}")),
+    (0xFAB0, 0xFAB0, Status::Mapped("\u{7DF4}")),
+    (0xFAB1, 0xFAB1, Status::Mapped("\u{7F3E}")),
+    (0xFAB2, 0xFAB2, Status::Mapped("\u{8005}")),
+    (0xFAB3, 0xFAB3, Status::Mapped("\u{8352}")),
+    (0xFAB4, 0xFAB4, Status::Mapped("\u{83EF}")),
+    (0xFAB5, 0xFAB5, Status::Mapped("\u{8779}")),
+    (0xFAB6, 0xFAB6, Status::Mapped("\u{8941}")),
+    (0xFAB7, 0xFAB7, Status::Mapped("\u{8986}")),
+    (0xFAB8, 0xFAB8, Status::Mapped("\u{8996}")),
+    (0xFAB9, 0xFAB9, Status::Mapped("\u{8ABF}")),
+    (0xFABA, 0xFABA, Status::Mapped("\u{8AF8}")),
+    (0xFABB, 0xFABB, Status::Mapped("\u{8ACB}")),
+    (0xFABC, 0xFABC, Status::Mapped("\u{8B01}")),
+    (0xFABD, 0xFABD, Status::Mapped("\u{8AFE}")),
+    (0xFABE, 0xFABE, Status::Mapped("\u{8AED}")),
+    (0xFABF, 0xFABF, Status::Mapped("\u{8B39}")),
+    (0xFAC0, 0xFAC0, Status::Mapped("\u{8B8A}")),
+    (0xFAC1, 0xFAC1, Status::Mapped("\u{8D08}")),
+    (0xFAC2, 0xFAC2, Status::Mapped("\u{8F38}")),
+    (0xFAC3, 0xFAC3, Status::Mapped("\u{9072}")),
+    (0xFAC4, 0xFAC4, Status::Mapped("\u{9199}")),
+    (0xFAC5, 0xFAC5, Status::Mapped("\u{9276}")),
+    (0xFAC6, 0xFAC6, Status::Mapped("\u{967C}")),
+    (0xFAC7, 0xFAC7, Status::Mapped("\u{96E3}")),
+    (0xFAC8, 0xFAC8, Status::Mapped("\u{9756}")),
+    (0xFAC9, 0xFAC9, Status::Mapped("\u{97DB}")),
+    (0xFACA, 0xFACA, Status::Mapped("\u{97FF}")),
+    (0xFACB, 0xFACB, Status::Mapped("\u{980B}")),
+    (0xFACC, 0xFACC, Status::Mapped("\u{983B}")),
+    (0xFACD, 0xFACD, Status::Mapped("\u{9B12}")),
+    (0xFACE, 0xFACE, Status::Mapped("\u{9F9C}")),
+    (0xFACF, 0xFACF, Status::Mapped("\u{2284A}")),
+    (0xFAD0, 0xFAD0, Status::Mapped("\u{22844}")),
+    (0xFAD1, 0xFAD1, Status::Mapped("\u{233D5}")),
+    (0xFAD2, 0xFAD2, Status::Mapped("\u{3B9D}")),
+    (0xFAD3, 0xFAD3, Status::Mapped("\u{4018}")),
+    (0xFAD4, 0xFAD4, Status::Mapped("\u{4039}")),
+    (0xFAD5, 0xFAD5, Status::Mapped("\u{25249}")),
+    (0xFAD6, 0xFAD6, Status::Mapped("\u{25CD0}")),
+    (0xFAD7, 0xFAD7, Status::Mapped("\u{27ED3}")),
+    (0xFAD8, 0xFAD8, Status::Mapped("\u{9F43}")),
+    (0xFAD9, 0xFAD9, Status::Mapped("\u{9F8E}")),
+    (0xFADA, 0xFAFF, Status::Disallowed),
+    (0xFB00, 0xFB00, Status::Mapped("ff")),
+    (0xFB01, 0xFB01, Status::Mapped("fi")),
+    (0xFB02, 0xFB02, Status::Mapped("fl")),
+    (0xFB03, 0xFB03, Status::Mapped("ffi")),
+    (0xFB04, 0xFB04, Status::Mapped("ffl")),
+    (0xFB05, 0xFB06, Status::Mapped("st")),
+    (0xFB07, 0xFB12, Status::Disallowed),
+    (0xFB13, 0xFB13, Status::Mapped("\u{574}\u{576}")),
+    (0xFB14, 0xFB14, Status::Mapped("\u{574}\u{565}")),
+    (0xFB15, 0xFB15, Status::Mapped("\u{574}\u{56B}")),
+    (0xFB16, 0xFB16, Status::Mapped("\u{57E}\u{576}")),
+    (0xFB17, 0xFB17, Status::Mapped("\u{574}\u{56D}")),
+    (0xFB18, 0xFB1C, Status::Disallowed),
+    (0xFB1D, 0xFB1D, Status::Mapped("\u{5D9}\u{5B4}")),
+    (0xFB1E, 0xFB1E, Status::Valid),
+    (0xFB1F, 0xFB1F, Status::Mapped("\u{5F2}\u{5B7}")),
+    (0xFB20, 0xFB20, Status::Mapped("\u{5E2}")),
+    (0xFB21, 0xFB21, Status::Mapped("\u{5D0}")),
+    (0xFB22, 0xFB22, Status::Mapped("\u{5D3}")),
+    (0xFB23, 0xFB23, Status::Mapped("\u{5D4}")),
+    (0xFB24, 0xFB24, Status::Mapped("\u{5DB}")),
+    (0xFB25, 0xFB25, Status::Mapped("\u{5DC}")),
+    (0xFB26, 0xFB26, Status::Mapped("\u{5DD}")),
+    (0xFB27, 0xFB27, Status::Mapped("\u{5E8}")),
+    (0xFB28, 0xFB28, Status::Mapped("\u{5EA}")),
+    (0xFB29, 0xFB29, Status::Valid),
+    (0xFB2A, 0xFB2A, Status::Mapped("\u{5E9}\u{5C1}")),
+    (0xFB2B, 0xFB2B, Status::Mapped("\u{5E9}\u{5C2}")),
+    (0xFB2C, 0xFB2C, Status::Mapped("\u{5E9}\u{5BC}\u{5C1}")),
+    (0xFB2D, 0xFB2D, Status::Mapped("\u{5E9}\u{5BC}\u{5C2}")),
+    (0xFB2E, 0xFB2E, Status::Mapped("\u{5D0}\u{5B7}")),
+    (0xFB2F, 0xFB2F, Status::Mapped("\u{5D0}\u{5B8}")),
+    (0xFB30, 0xFB30, Status::Mapped("\u{5D0}\u{5BC}")),
+    (0xFB31, 0xFB31, Status::Mapped("\u{5D1}\u{5BC}")),
+    (0xFB32, 0xFB32, Status::Mapped("\u{5D2}\u{5BC}")),
+    (0xFB33, 0xFB33, Status::Mapped("\u{5D3}\u{5BC}")),
+    (0xFB34, 0xFB34, Status::Mapped("\u{5D4}\u{5BC}")),
+    (0xFB35, 0xFB35, Status::Mapped("\u{5D5}\u{5BC}")),
+    (0xFB36, 0xFB36, Status::Mapped("\u{5D6}\u{5BC}")),
+    (0xFB37, 0xFB37, Status::Disallowed),
+    (0xFB38, 0xFB38, Status::Mapped("\u{5D8}\u{5BC}")),
+    (0xFB39, 0xFB39, Status::Mapped("\u{5D9}\u{5BC}")),
+    (0xFB3A, 0xFB3A, Status::Mapped("\u{5DA}\u{5BC}")),
+    (0xFB3B, 0xFB3B, Status::Mapped("\u{5DB}\u{5BC}")),
+    (0xFB3C, 0xFB3C, Status::Mapped("\u{5DC}\u{5BC}")),
+    (0xFB3D, 0xFB3D, Status::Disallowed),
+    (0xFB3E, 0xFB3E, Status::Mapped("\u{5DE}\u{5BC}")),
+    (0xFB3F, 0xFB3F, Status::Disallowed),
+    (0xFB40, 0xFB40, Status::Mapped("\u{5E0}\u{5BC}")),
+    (0xFB41, 0xFB41, Status::Mapped("\u{5E1}\u{5BC}")),
+    (0xFB42, 0xFB42, Status::Disallowed),
+    (0xFB43, 0xFB43, Status::Mapped("\u{5E3}\u{5BC}")),
+    (0xFB44, 0xFB44, Status::Mapped("\u{5E4}\u{5BC}")),
+    (0xFB45, 0xFB45, Status::Disallowed),
+    (0xFB46, 0xFB46, Status::Mapped("\u{5E6}\u{5BC}")),
+    (0xFB47, 0xFB47, Status::Mapped("\u{5E7}\u{5BC}")),
+    (0xFB48, 0xFB48, Status::Mapped("\u{5E8}\u{5BC}")),
+    (0xFB49, 0xFB49, Status::Mapped("\u{5E9}\u{5BC}")),
+    (0xFB4A, 0xFB4A, Status::Mapped("\u{5EA}\u{5BC}")),
+    (0xFB4B, 0xFB4B, Status::Mapped("\u{5D5}\u{5B9}")),
+    (0xFB4C, 0xFB4C, Status::Mapped("\u{5D1}\u{5BF}")),
+    (0xFB4D, 0xFB4D, Status::Mapped("\u{5DB}\u{5BF}")),
+    (0xFB4E, 0xFB4E, Status::Mapped("\u{5E4}\u{5BF}")),
+    (0xFB4F, 0xFB4F, Status::Mapped("\u{5D0}\u{5DC}")),
+    (0xFB50, 0xFB51, Status::Mapped("\u{671}")),
+    (0xFB52, 0xFB55, Status::Mapped("\u{67B}")),
+    (0xFB56, 0xFB59, Status::Mapped("\u{67E}")),
+    (0xFB5A, 0xFB5D, Status::Mapped("\u{680}")),
+    (0xFB5E, 0xFB61, Status::Mapped("\u{67A}")),
+    (0xFB62, 0xFB65, Status::Mapped("\u{67F}")),
+    (0xFB66, 0xFB69, Status::Mapped("\u{679}")),
+    (0xFB6A, 0xFB6D, Status::Mapped("\u{6A4}")),
+    (0xFB6E, 0xFB71, Status::Mapped("\u{6A6}")),
+    (0xFB72, 0xFB75, Status::Mapped("\u{684}")),
+    (0xFB76, 0xFB79, Status::Mapped("\u{683}")),
+    (0xFB7A, 0xFB7D, Status::Mapped("\u{686}")),
+    (0xFB7E, 0xFB81, Status::Mapped("\u{687}")),
+    (0xFB82, 0xFB83, Status::Mapped("\u{68D}")),
+    (0xFB84, 0xFB85, Status::Mapped("\u{68C}")),
+    (0xFB86, 0xFB87, Status::Mapped("\u{68E}")),
+    (0xFB88, 0xFB89, Status::Mapped("\u{688}")),
+    (0xFB8A, 0xFB8B, Status::Mapped("\u{698}")),
+    (0xFB8C, 0xFB8D, Status::Mapped("\u{691}")),
+    (0xFB8E, 0xFB91, Status::Mapped("\u{6A9}")),
+    (0xFB92, 0xFB95, Status::Mapped("\u{6AF}")),
+    (0xFB96, 0xFB99, Status::Mapped("\u{6B3}")),
+    (0xFB9A, 0xFB9D, Status::Mapped("\u{6B1}")),
+    (0xFB9E, 0xFB9F, Status::Mapped("\u{6BA}")),
+    (0xFBA0, 0xFBA3, Status::Mapped("\u{6BB}")),
+    (0xFBA4, 0xFBA5, Status::Mapped("\u{6C0}")),
+    (0xFBA6, 0xFBA9, Status::Mapped("\u{6C1}")),
+    (0xFBAA, 0xFBAD, Status::Mapped("\u{6BE}")),
+    (0xFBAE, 0xFBAF, Status::Mapped("\u{6D2}")),
+    (0xFBB0, 0xFBB1, Status::Mapped("\u{6D3}")),
+    (0xFBB2, 0xFBC2, Status::Valid),
+    (0xFBC3, 0xFBD2, Status::Disallowed),
+    (0xFBD3, 0xFBD6, Status::Mapped("\u{6AD}")),
+    (0xFBD7, 0xFBD8, Status::Mapped("\u{6C7}")),
+    (0xFBD9, 0xFBDA, Status::Mapped("\u{6C6}")),
+    (0xFBDB, 0xFBDC, Status::Mapped("\u{6C8}")),
+    (0xFBDD, 0xFBDD, Status::Mapped("\u{6C7}\u{674}")),
+    (0xFBDE, 0xFBDF, Status::Mapped("\u{6CB}")),
+    (0xFBE0, 0xFBE1, Status::Mapped("\u{6C5}")),
+    (0xFBE2, 0xFBE3, Status::Mapped("\u{6C9}")),
+    (0xFBE4, 0xFBE7, Status::Mapped("\u{6D0}")),
+    (0xFBE8, 0xFBE9, Status::Mapped("\u{649}")),
+    (0xFBEA, 0xFBEB, Status::Mapped("\u{626}\u{627}")),
+    (0xFBEC, 0xFBED, Status::Mapped("\u{626}\u{6D5}")),
+    (0xFBEE, 0xFBEF, Status::Mapped("\u{626}\u{648}")),
+    (0xFBF0, 0xFBF1, Status::Mapped("\u{626}\u{6C7}")),
+    (0xFBF2, 0xFBF3, Status::Mapped("\u{626}\u{6C6}")),
+    (0xFBF4, 0xFBF5, Status::Mapped("\u{626}\u{6C8}")),
+    (0xFBF6, 0xFBF8, Status::Mapped("\u{626}\u{6D0}")),
+    (0xFBF9, 0xFBFB, Status::Mapped("\u{626}\u{649}")),
+    (0xFBFC, 0xFBFF, Status::Mapped("\u{6CC}")),
+    (0xFC00, 0xFC00, Status::Mapped("\u{626}\u{62C}")),
+    (0xFC01, 0xFC01, Status::Mapped("\u{626}\u{62D}")),
+    (0xFC02, 0xFC02, Status::Mapped("\u{626}\u{645}")),
+    (0xFC03, 0xFC03, Status::Mapped("\u{626}\u{649}")),
+    (0xFC04, 0xFC04, Status::Mapped("\u{626}\u{64A}")),
+    (0xFC05, 0xFC05, Status::Mapped("\u{628}\u{62C}")),
+    (0xFC06, 0xFC06, Status::Mapped("\u{628}\u{62D}")),
+    (0xFC07, 0xFC07, Status::Mapped("\u{628}\u{62E}")),
+    (0xFC08, 0xFC08, Status::Mapped("\u{628}\u{645}")),
+    (0xFC09, 0xFC09, Status::Mapped("\u{628}\u{649}")),
+    (0xFC0A, 0xFC0A, Status::Mapped("\u{628}\u{64A}")),
+    (0xFC0B, 0xFC0B, Status::Mapped("\u{62A}\u{62C}")),
+    (0xFC0C, 0xFC0C, Status::Mapped("\u{62A}\u{62D}")),
+    (0xFC0D, 0xFC0D, Status::Mapped("\u{62A}\u{62E}")),
+    (0xFC0E, 0xFC0E, Status::Mapped("\u{62A}\u{645}")),
+    (0xFC0F, 0xFC0F, Status::Mapped("\u{62A}\u{649}")),
+    (0xFC10, 0xFC10, Status::Mapped("\u{62A}\u{64A}")),
+    (0xFC11, 0xFC11, Status::Mapped("\u{62B}\u{62C}")),
+    (0xFC12, 0xFC12, Status::Mapped("\u{62B}\u{645}")),
+    (0xFC13, 0xFC13, Status::Mapped("\u{62B}\u{649}")),
+    (0xFC14, 0xFC14, Status::Mapped("\u{62B}\u{64A}")),
+    (0xFC15, 0xFC15, Status::Mapped("\u{62C}\u{62D}")),
+    (0xFC16, 0xFC16, Status::Mapped("\u{62C}\u{645}")),
+    (0xFC17, 0xFC17, Status::Mapped("\u{62D}\u{62C}")),
+    (0xFC18, 0xFC18, Status::Mapped("\u{62D}\u{645}")),
+    (0xFC19, 0xFC19, Status::Mapped("\u{62E}\u{62C}")),
+    (0xFC1A, 0xFC1A, Status::Mapped("\u{62E}\u{62D}")),
+    (0xFC1B, 0xFC1B, Status::Mapped("\u{62E}\u{645}")),
+    (0xFC1C, 0xFC1C, Status::Mapped("\u{633}\u{62C}")),
+    (0xFC1D, 0xFC1D, Status::Mapped("\u{633}\u{62D}")),
+    (0xFC1E, 0xFC1E, Status::Mapped("\u{633}\u{62E}")),
+    (0xFC1F, 0xFC1F, Status::Mapped("\u{633}\u{645}")),
+    (0xFC20, 0xFC20, Status::Mapped("\u{635}\u{62D}")),
+    (0xFC21, 0xFC21, Status::Mapped("\u{635}\u{645}")),
+    (0xFC22, 0xFC22, Status::Mapped("\u{636}\u{62C}")),
+    (0xFC23, 0xFC23, Status::Mapped("\u{636}\u{62D}")),
+    (0xFC24, 0xFC24, Status::Mapped("\u{636}\u{62E}")),
+    (0xFC25, 0xFC25, Status::Mapped("\u{636}\u{645}")),
+    (0xFC26, 0xFC26, Status::Mapped("\u{637}\u{62D}")),
+    (0xFC27, 0xFC27, Status::Mapped("\u{637}\u{645}")),
+    (0xFC28, 0xFC28, Status::Mapped("\u{638}\u{645}")),
+    (0xFC29, 0xFC29, Status::Mapped("\u{639}\u{62C}")),
+    (0xFC2A, 0xFC2A, Status::Mapped("\u{639}\u{645}")),
+    (0xFC2B, 0xFC2B, Status::Mapped("\u{63A}\u{62C}")),
+    (0xFC2C, 0xFC2C, Status::Mapped("\u{63A}\u{645}")),
+    (0xFC2D, 0xFC2D, Status::Mapped("\u{641}\u{62C}")),
+    (0xFC2E, 0xFC2E, Status::Mapped("\u{641}\u{62D}")),
+    (0xFC2F, 0xFC2F, Status::Mapped("\u{641}\u{62E}")),
+    (0xFC30, 0xFC30, Status::Mapped("\u{641}\u{645}")),
+    (0xFC31, 0xFC31, Status::Mapped("\u{641}\u{649}")),
+    (0xFC32, 0xFC32, Status::Mapped("\u{641}\u{64A}")),
+    (0xFC33, 0xFC33, Status::Mapped("\u{642}\u{62D}")),
+    (0xFC34, 0xFC34, Status::Mapped("\u{642}\u{645}")),
+    (0xFC35, 0xFC35, Status::Mapped("\u{642}\u{649}")),
+    (0xFC36, 0xFC36, Status::Mapped("\u{642}\u{64A}")),
+    (0xFC37, 0xFC37, Status::Mapped("\u{643}\u{627}")),
+    (0xFC38, 0xFC38, Status::Mapped("\u{643}\u{62C}")),
+    (0xFC39, 0xFC39, Status::Mapped("\u{643}\u{62D}")),
+    (0xFC3A, 0xFC3A, Status::Mapped("\u{643}\u{62E}")),
+    (0xFC3B, 0xFC3B, Status::Mapped("\u{643}\u{644}")),
+    (0xFC3C, 0xFC3C, Status::Mapped("\u{643}\u{645}")),
+    (0xFC3D, 0xFC3D, Status::Mapped("\u{643}\u{649}")),
+    (0xFC3E, 0xFC3E, Status::Mapped("\u{643}\u{64A}")),
+    (0xFC3F, 0xFC3F, Status::Mapped("\u{644}\u{62C}")),
+    (0xFC40, 0xFC40, Status::Mapped("\u{644}\u{62D}")),
+    (0xFC41, 0xFC41, Status::Mapped("\u{644}\u{62E}")),
+    (0xFC42, 0xFC42, Status::Mapped("\u{644}\u{645}")),
+    (0xFC43, 0xFC43, Status::Mapped("\u{644}\u{649}")),
+    (0xFC44, 0xFC44, Status::Mapped("\u{644}\u{64A}")),
+    (0xFC45, 0xFC45, Status::Mapped("\u{645}\u{62C}")),
+    (0xFC46, 0xFC46, Status::Mapped("\u{645}\u{62D}")),
+    (0xFC47, 0xFC47, Status::Mapped("\u{645}\u{62E}")),
+    (0xFC48, 0xFC48, Status::Mapped("\u{645}\u{645}")),
+    (0xFC49, 0xFC49, Status::Mapped("\u{645}\u{649}")),
+    (0xFC4A, 0xFC4A, Status::Mapped("\u{645}\u{64A}")),
+    (0xFC4B, 0xFC4B, Status::Mapped("\u{646}\u{62C}")),
+    (0xFC4C, 0xFC4C, Status::Mapped("\u{646}\u{62D}")),
+    (0xFC4D, 0xFC4D, Status::Mapped("\u{646}\u{62E}")),
+    (0xFC4E, 0xFC4E, Status::Mapped("\u{646}\u{645}")),
+    (0xFC4F, 0xFC4F, Status::Mapped("\u{646}\u{649}")),
+    (0xFC50, 0xFC50, Status::Mapped("\u{646}\u{64A}")),
+    (0xFC51, 0xFC51, Status::Mapped("\u{647}\u{62C}")),
+    (0xFC52, 0xFC52, Status::Mapped("\u{647}\u{645}")),
+    (0xFC53, 0xFC53, Status::Mapped("\u{647}\u{649}")),
+    (0xFC54, 0xFC54, Status::Mapped("\u{647}\u{64A}")),
+    (0xFC55, 0xFC55, Status::Mapped("\u{64A}\u{62C}")),
+    (0xFC56, 0xFC56, Status::Mapped("\u{64A}\u{62D}")),
+    (0xFC57, 0xFC57, Status::Mapped("\u{64A}\u{62E}")),
+    (0xFC58, 0xFC58, Status::Mapped("\u{64A}\u{645}")),
+    (0xFC59, 0xFC59, Status::Mapped("\u{64A}\u{649}")),
+    (0xFC5A, 0xFC5A, Status::Mapped("\u{64A}\u{64A}")),
+    (0xFC5B, 0xFC5B, Status::Mapped("\u{630}\u{670}")),
+    (0xFC5C, 0xFC5C, Status::Mapped("\u{631}\u{670}")),
+    (0xFC5D, 0xFC5D, Status::Mapped("\u{649}\u{670}")),
+    (0xFC5E, 0xFC63, Status::Valid),
+    (0xFC64, 0xFC64, Status::Mapped("\u{626}\u{631}")),
+    (0xFC65, 0xFC65, Status::Mapped("\u{626}\u{632}")),
+    (0xFC66, 0xFC66, Status::Mapped("\u{626}\u{645}")),
+    (0xFC67, 0xFC67, Status::Mapped("\u{626}\u{646}")),
+    (0xFC68, 0xFC68, Status::Mapped("\u{626}\u{649}")),
+    (0xFC69, 0xFC69, Status::Mapped("\u{626}\u{64A}")),
+    (0xFC6A, 0xFC6A, Status::Mapped("\u{628}\u{631}")),
+    (0xFC6B, 0xFC6B, Status::Mapped("\u{628}\u{632}")),
+    (0xFC6C, 0xFC6C, Status::Mapped("\u{628}\u{645}")),
+    (0xFC6D, 0xFC6D, Status::Mapped("\u{628}\u{646}")),
+    (0xFC6E, 0xFC6E, Status::Mapped("\u{628}\u{649}")),
+    (0xFC6F, 0xFC6F, Status::Mapped("\u{628}\u{64A}")),
+    (0xFC70, 0xFC70, Status::Mapped("\u{62A}\u{631}")),
+    (0xFC71, 0xFC71, Status::Mapped("\u{62A}\u{632}")),
+    (0xFC72, 0xFC72, Status::Mapped("\u{62A}\u{645}")),
+    (0xFC73, 0xFC73, Status::Mapped("\u{62A}\u{646}")),
+    (0xFC74, 0xFC74, Status::Mapped("\u{62A}\u{649}")),
+    (0xFC75, 0xFC75, Status::Mapped("\u{62A}\u{64A}")),
+    (0xFC76, 0xFC76, Status::Mapped("\u{62B}\u{631}")),
+    (0xFC77, 0xFC77, Status::Mapped("\u{62B}\u{632}")),
+    (0xFC78, 0xFC78, Status::Mapped("\u{62B}\u{645}")),
+    (0xFC79, 0xFC79, Status::Mapped("\u{62B}\u{646}")),
+    (0xFC7A, 0xFC7A, Status::Mapped("\u{62B}\u{649}")),
+    (0xFC7B, 0xFC7B, Status::Mapped("\u{62B}\u{64A}")),
+    (0xFC7C, 0xFC7C, Status::Mapped("\u{641}\u{649}")),
+    (0xFC7D, 0xFC7D, Status::Mapped("\u{641}\u{64A}")),
+    (0xFC7E, 0xFC7E, Status::Mapped("\u{642}\u{649}")),
+    (0xFC7F, 0xFC7F, Status::Mapped("\u{642}\u{64A}")),
+    (0xFC80, 0xFC80, Status::Mapped("\u{643}\u{627}")),
+    (0xFC81, 0xFC81, Status::Mapped("\u{643}\u{644}")),
+    (0xFC82, 0xFC82, Status::Mapped("\u{643}\u{645}")),
+    (0xFC83, 0xFC83, Status::Mapped("\u{643}\u{649}")),
+    (0xFC84, 0xFC84, Status::Mapped("\u{643}\u{64A}")),
+    (0xFC85, 0xFC85, Status::Mapped("\u{644}\u{645}")),
+    (0xFC86, 0xFC86, Status::Mapped("\u{644}\u{649}")),
+    (0xFC87, 0xFC87, Status::Mapped("\u{644}\u{64A}")),
+    (0xFC88, 0xFC88, Status::Mapped("\u{645}\u{627}")),
+    (0xFC89, 0xFC89, Status::Mapped("\u{645}\u{645}")),
+    (0xFC8A, 0xFC8A, Status::Mapped("\u{646}\u{631}")),
+    (0xFC8B, 0xFC8B, Status::Mapped("\u{646}\u{632}")),
+    (0xFC8C, 0xFC8C, Status::Mapped("\u{646}\u{645}")),
+    (0xFC8D, 0xFC8D, Status::Mapped("\u{646}\u{646}")),
+    (0xFC8E, 0xFC8E, Status::Mapped("\u{646}\u{649}")),
+    (0xFC8F, 0xFC8F, Status::Mapped("\u{646}\u{64A}")),
+    (0xFC90, 0xFC90, Status::Mapped("\u{649}\u{670}")),
+    (0xFC91, 0xFC91, Status::Mapped("\u{64A}\u{631}")),
+    (0xFC92, 0xFC92, Status::Mapped("\u{64A}\u{632}")),
+    (0xFC93, 0xFC93, Status::Mapped("\u{64A}\u{645}")),
+    (0xFC94, 0xFC94, Status::Mapped("\u{64A}\u{646}")),
+    (0xFC95, 0xFC95, Status::Mapped("\u{64A}\u{649}")),
+    (0xFC96, 0xFC96, Status::Mapped("\u{64A}\u{64A}")),
+    (0xFC97, 0xFC97, Status::Mapped("\u{626}\u{62C}")),
+    (0xFC98, 0xFC98, Status::Mapped("\u{626}\u{62D}")),
+    (0xFC99, 0xFC99, Status::Mapped("\u{626}\u{62E}")),
+    (0xFC9A, 0xFC9A, Status::Mapped("\u{626}\u{645}")),
+    (0xFC9B, 0xFC9B, Status::Mapped("\u{626}\u{647}")),
+    (0xFC9C, 0xFC9C, Status::Mapped("\u{628}\u{62C}")),
+    (0xFC9D, 0xFC9D, Status::Mapped("\u{628}\u{62D}")),
+    (0xFC9E, 0xFC9E, Status::Mapped("\u{628}\u{62E}")),
+    (0xFC9F, 0xFC9F, Status::Mapped("\u{628}\u{645}")),
+    (0xFCA0, 0xFCA0, Status::Mapped("\u{628}\u{647}")),
+    (0xFCA1, 0xFCA1, Status::Mapped("\u{62A}\u{62C}")),
+    (0xFCA2, 0xFCA2, Status::Mapped("\u{62A}\u{62D}")),
+    (0xFCA3, 0xFCA3, Status::Mapped("\u{62A}\u{62E}")),
+    (0xFCA4, 0xFCA4, Status::Mapped("\u{62A}\u{645}")),
+    (0xFCA5, 0xFCA5, Status::Mapped("\u{62A}\u{647}")),
+    (0xFCA6, 0xFCA6, Status::Mapped("\u{62B}\u{645}")),
+    (0xFCA7, 0xFCA7, Status::Mapped("\u{62C}\u{62D}")),
+    (0xFCA8, 0xFCA8, Status::Mapped("\u{62C}\u{645}")),
+    (0xFCA9, 0xFCA9, Status::Mapped("\u{62D}\u{62C}")),
+    (0xFCAA, 0xFCAA, Status::Mapped("\u{62D}\u{645}")),
+    (0xFCAB, 0xFCAB, Status::Mapped("\u{62E}\u{62C}")),
+    (0xFCAC, 0xFCAC, Status::Mapped("\u{62E}\u{645}")),
+    (0xFCAD, 0xFCAD, Status::Mapped("\u{633}\u{62C}")),
+    (0xFCAE, 0xFCAE, Status::Mapped("\u{633}\u{62D}")),
+    (0xFCAF, 0xFCAF, Status::Mapped("\u{633}\u{62E}")),
+    (0xFCB0, 0xFCB0, Status::Mapped("\u{633}\u{645}")),
+    (0xFCB1, 0xFCB1, Status::Mapped("\u{635}\u{62D}")),
+    (0xFCB2, 0xFCB2, Status::Mapped("\u{635}\u{62E}")),
+    (0xFCB3, 0xFCB3, Status::Mapped("\u{635}\u{645}")),
+    (0xFCB4, 0xFCB4, Status::Mapped("\u{636}\u{62C}")),
+    (0xFCB5, 0xFCB5, Status::Mapped("\u{636}\u{62D}")),
+    (0xFCB6, 0xFCB6, Status::Mapped("\u{636}\u{62E}")),
+    (0xFCB7, 0xFCB7, Status::Mapped("\u{636}\u{645}")),
+    (0xFCB8, 0xFCB8, Status::Mapped("\u{637}\u{62D}")),
+    (0xFCB9, 0xFCB9, Status::Mapped("\u{638}\u{645}")),
+    (0xFCBA, 0xFCBA, Status::Mapped("\u{639}\u{62C}")),
+    (0xFCBB, 0xFCBB, Status::Mapped("\u{639}\u{645}")),
+    (0xFCBC, 0xFCBC, Status::Mapped("\u{63A}\u{62C}")),
+    (0xFCBD, 0xFCBD, Status::Mapped("\u{63A}\u{645}")),
+    (0xFCBE, 0xFCBE, Status::Mapped("\u{641}\u{62C}")),
+    (0xFCBF, 0xFCBF, Status::Mapped("\u{641}\u{62D}")),
+    (0xFCC0, 0xFCC0, Status::Mapped("\u{641}\u{62E}")),
+    (0xFCC1, 0xFCC1, Status::Mapped("\u{641}\u{645}")),
+    (0xFCC2, 0xFCC2, Status::Mapped("\u{642}\u{62D}")),
+    (0xFCC3, 0xFCC3, Status::Mapped("\u{642}\u{645}")),
+    (0xFCC4, 0xFCC4, Status::Mapped("\u{643}\u{62C}")),
+    (0xFCC5, 0xFCC5, Status::Mapped("\u{643}\u{62D}")),
+    (0xFCC6, 0xFCC6, Status::Mapped("\u{643}\u{62E}")),
+    (0xFCC7, 0xFCC7, Status::Mapped("\u{643}\u{644}")),
+    (0xFCC8, 0xFCC8, Status::Mapped("\u{643}\u{645}")),
+    (0xFCC9, 0xFCC9, Status::Mapped("\u{644}\u{62C}")),
+    (0xFCCA, 0xFCCA, Status::Mapped("\u{644}\u{62D}")),
+    (0xFCCB, 0xFCCB, Status::Mapped("\u{644}\u{62E}")),
+    (0xFCCC, 0xFCCC, Status::Mapped("\u{644}\u{645}")),
+    (0xFCCD, 0xFCCD, Status::Mapped("\u{644}\u{647}")),
+    (0xFCCE, 0xFCCE, Status::Mapped("\u{645}\u{62C}")),
+    (0xFCCF, 0xFCCF, Status::Mapped("\u{645}\u{62D}")),
+    (0xFCD0, 0xFCD0, Status::Mapped("\u{645}\u{62E}")),
+    (0xFCD1, 0xFCD1, Status::Mapped("\u{645}\u{645}")),
+    (0xFCD2, 0xFCD2, Status::Mapped("\u{646}\u{62C}")),
+    (0xFCD3, 0xFCD3, Status::Mapped("\u{646}\u{62D}")),
+    (0xFCD4, 0xFCD4, Status::Mapped("\u{646}\u{62E}")),
+    (0xFCD5, 0xFCD5, Status::Mapped("\u{646}\u{645}")),
+    (0xFCD6, 0xFCD6, Status::Mapped("\u{646}\u{647}")),
+    (0xFCD7, 0xFCD7, Status::Mapped("\u{647}\u{62C}")),
+    (0xFCD8, 0xFCD8, Status::Mapped("\u{647}\u{645}")),
+    (0xFCD9, 0xFCD9, Status::Mapped("\u{647}\u{670}")),
+    (0xFCDA, 0xFCDA, Status::Mapped("\u{64A}\u{62C}")),
+    (0xFCDB, 0xFCDB, Status::Mapped("\u{64A}\u{62D}")),
+    (0xFCDC, 0xFCDC, Status::Mapped("\u{64A}\u{62E}")),
+    (0xFCDD, 0xFCDD, Status::Mapped("\u{64A}\u{645}")),
+    (0xFCDE, 0xFCDE, Status::Mapped("\u{64A}\u{647}")),
+    (0xFCDF, 0xFCDF, Status::Mapped("\u{626}\u{645}")),
+    (0xFCE0, 0xFCE0, Status::Mapped("\u{626}\u{647}")),
+    (0xFCE1, 0xFCE1, Status::Mapped("\u{628}\u{645}")),
+    (0xFCE2, 0xFCE2, Status::Mapped("\u{628}\u{647}")),
+    (0xFCE3, 0xFCE3, Status::Mapped("\u{62A}\u{645}")),
+    (0xFCE4, 0xFCE4, Status::Mapped("\u{62A}\u{647}")),
+    (0xFCE5, 0xFCE5, Status::Mapped("\u{62B}\u{645}")),
+    (0xFCE6, 0xFCE6, Status::Mapped("\u{62B}\u{647}")),
+    (0xFCE7, 0xFCE7, Status::Mapped("\u{633}\u{645}")),
+    (0xFCE8, 0xFCE8, Status::Mapped("\u{633}\u{647}")),
+    (0xFCE9, 0xFCE9, Status::Mapped("\u{634}\u{645}")),
+    (0xFCEA, 0xFCEA, Status::Mapped("\u{634}\u{647}")),
+    (0xFCEB, 0xFCEB, Status::Mapped("\u{643}\u{644}")),
+    (0xFCEC, 0xFCEC, Status::Mapped("\u{643}\u{645}")),
+    (0xFCED, 0xFCED, Status::Mapped("\u{644}\u{645}")),
+    (0xFCEE, 0xFCEE, Status::Mapped("\u{646}\u{645}")),
+    (0xFCEF, 0xFCEF, Status::Mapped("\u{646}\u{647}")),
+    (0xFCF0, 0xFCF0, Status::Mapped("\u{64A}\u{645}")),
+    (0xFCF1, 0xFCF1, Status::Mapped("\u{64A}\u{647}")),
+    (0xFCF2, 0xFCF2, Status::Mapped("\u{640}\u{64E}\u{651}")),
+    (0xFCF3, 0xFCF3, Status::Mapped("\u{640}\u{64F}\u{651}")),
+    (0xFCF4, 0xFCF4, Status::Mapped("\u{640}\u{650}\u{651}")),
+    (0xFCF5, 0xFCF5, Status::Mapped("\u{637}\u{649}")),
+    (0xFCF6, 0xFCF6, Status::Mapped("\u{637}\u{64A}")),
+    (0xFCF7, 0xFCF7, Status::Mapped("\u{639}\u{649}")),
+    (0xFCF8, 0xFCF8, Status::Mapped("\u{639}\u{64A}")),
+    (0xFCF9, 0xFCF9, Status::Mapped("\u{63A}\u{649}")),
+    (0xFCFA, 0xFCFA, Status::Mapped("\u{63A}\u{64A}")),
+    (0xFCFB, 0xFCFB, Status::Mapped("\u{633}\u{649}")),
+    (0xFCFC, 0xFCFC, Status::Mapped("\u{633}\u{64A}")),
+    (0xFCFD, 0xFCFD, Status::Mapped("\u{634}\u{649}")),
+    (0xFCFE, 0xFCFE, Status::Mapped("\u{634}\u{64A}")),
+    (0xFCFF, 0xFCFF, Status::Mapped("\u{62D}\u{649}")),
+    (0xFD00, 0xFD00, Status::Mapped("\u{62D}\u{64A}")),
+    (0xFD01, 0xFD01, Status::Mapped("\u{62C}\u{649}")),
+    (0xFD02, 0xFD02, Status::Mapped("\u{62C}\u{64A}")),
+    (0xFD03, 0xFD03, Status::Mapped("\u{62E}\u{649}")),
+    (0xFD04, 0xFD04, Status::Mapped("\u{62E}\u{64A}")),
+    (0xFD05, 0xFD05, Status::Mapped("\u{635}\u{649}")),
+    (0xFD06, 0xFD06, Status::Mapped("\u{635}\u{64A}")),
+    (0xFD07, 0xFD07, Status::Mapped("\u{636}\u{649}")),
+    (0xFD08, 0xFD08, Status::Mapped("\u{636}\u{64A}")),
+    (0xFD09, 0xFD09, Status::Mapped("\u{634}\u{62C}")),
+    (0xFD0A, 0xFD0A, Status::Mapped("\u{634}\u{62D}")),
+    (0xFD0B, 0xFD0B, Status::Mapped("\u{634}\u{62E}")),
+    (0xFD0C, 0xFD0C, Status::Mapped("\u{634}\u{645}")),
+    (0xFD0D, 0xFD0D, Status::Mapped("\u{634}\u{631}")),
+    (0xFD0E, 0xFD0E, Status::Mapped("\u{633}\u{631}")),
+    (0xFD0F, 0xFD0F, Status::Mapped("\u{635}\u{631}")),
+    (0xFD10, 0xFD10, Status::Mapped("\u{636}\u{631}")),
+    (0xFD11, 0xFD11, Status::Mapped("\u{637}\u{649}")),
+    (0xFD12, 0xFD12, Status::Mapped("\u{637}\u{64A}")),
+    (0xFD13, 0xFD13, Status::Mapped("\u{639}\u{649}")),
+    (0xFD14, 0xFD14, Status::Mapped("\u{639}\u{64A}")),
+    (0xFD15, 0xFD15, Status::Mapped("\u{63A}\u{649}")),
+    (0xFD16, 0xFD16, Status::Mapped("\u{63A}\u{64A}")),
+    (0xFD17, 0xFD17, Status::Mapped("\u{633}\u{649}")),
+    (0xFD18, 0xFD18, Status::Mapped("\u{633}\u{64A}")),
+    (0xFD19, 0xFD19, Status::Mapped("\u{634}\u{649}")),
+    (0xFD1A, 0xFD1A, Status::Mapped("\u{634}\u{64A}")),
+    (0xFD1B, 0xFD1B, Status::Mapped("\u{62D}\u{649}")),
+    (0xFD1C, 0xFD1C, Status::Mapped("\u{62D}\u{64A}")),
+    (0xFD1D, 0xFD1D, Status::Mapped("\u{62C}\u{649}")),
+    (0xFD1E, 0xFD1E, Status::Mapped("\u{62C}\u{64A}")),
+    (0xFD1F, 0xFD1F, Status::Mapped("\u{62E}\u{649}")),
+    (0xFD20, 0xFD20, Status::Mapped("\u{62E}\u{64A}")),
+    (0xFD21, 0xFD21, Status::Mapped("\u{635}\u{649}")),
+    (0xFD22, 0xFD22, Status::Mapped("\u{635}\u{64A}")),
+    (0xFD23, 0xFD23, Status::Mapped("\u{636}\u{649}")),
+    (0xFD24, 0xFD24, Status::Mapped("\u{636}\u{64A}")),
+    (0xFD25, 0xFD25, Status::Mapped("\u{634}\u{62C}")),
+    (0xFD26, 0xFD26, Status::Mapped("\u{634}\u{62D}")),
+    (0xFD27, 0xFD27, Status::Mapped("\u{634}\u{62E}")),
+    (0xFD28, 0xFD28, Status::Mapped("\u{634}\u{645}")),
+    (0xFD29, 0xFD29, Status::Mapped("\u{634}\u{631}")),
+    (0xFD2A, 0xFD2A, Status::Mapped("\u{633}\u{631}")),
+    (0xFD2B, 0xFD2B, Status::Mapped("\u{635}\u{631}")),
+    (0xFD2C, 0xFD2C, Status::Mapped("\u{636}\u{631}")),
+    (0xFD2D, 0xFD2D, Status::Mapped("\u{634}\u{62C}")),
+    (0xFD2E, 0xFD2E, Status::Mapped("\u{634}\u{62D}")),
+    (0xFD2F, 0xFD2F, Status::Mapped("\u{634}\u{62E}")),
+    (0xFD30, 0xFD30, Status::Mapped("\u{634}\u{645}")),
+    (0xFD31, 0xFD31, Status::Mapped("\u{633}\u{647}")),
+    (0xFD32, 0xFD32, Status::Mapped("\u{634}\u{647}")),
+    (0xFD33, 0xFD33, Status::Mapped("\u{637}\u{645}")),
+    (0xFD34, 0xFD34, Status::Mapped("\u{633}\u{62C}")),
+    (0xFD35, 0xFD35, Status::Mapped("\u{633}\u{62D}")),
+    (0xFD36, 0xFD36, Status::Mapped("\u{633}\u{62E}")),
+    (0xFD37, 0xFD37, Status::Mapped("\u{634}\u{62C}")),
+    (0xFD38, 0xFD38, Status::Mapped("\u{634}\u{62D}")),
+    (0xFD39, 0xFD39, Status::Mapped("\u{634}\u{62E}")),
+    (0xFD3A, 0xFD3A, Status::Mapped("\u{637}\u{645}")),
+    (0xFD3B, 0xFD3B, Status::Mapped("\u{638}\u{645}")),
+    (0xFD3C, 0xFD3D, Status::Mapped("\u{627}\u{64B}")),
+    (0xFD3E, 0xFD4F, Status::Valid),
+    (0xFD50, 0xFD50, Status::Mapped("\u{62A}\u{62C}\u{645}")),
+    (0xFD51, 0xFD52, Status::Mapped("\u{62A}\u{62D}\u{62C}")),
+    (0xFD53, 0xFD53, Status::Mapped("\u{62A}\u{62D}\u{645}")),
+    (0xFD54, 0xFD54, Status::Mapped("\u{62A}\u{62E}\u{645}")),
+    (0xFD55, 0xFD55, Status::Mapped("\u{62A}\u{645}\u{62C}")),
+    (0xFD56, 0xFD56, Status::Mapped("\u{62A}\u{645}\u{62D}")),
+    (0xFD57, 0xFD57, Status::Mapped("\u{62A}\u{645}\u{62E}")),
+    (0xFD58, 0xFD59, Status::Mapped("\u{62C}\u{645}\u{62D}")),
+    (0xFD5A, 0xFD5A, Status::Mapped("\u{62D}\u{645}\u{64A}")),
+    (0xFD5B, 0xFD5B, Status::Mapped("\u{62D}\u{645}\u{649}")),
+    (0xFD5C, 0xFD5C, Status::Mapped("\u{633}\u{62D}\u{62C}")),
+    (0xFD5D, 0xFD5D, Status::Mapped("\u{633}\u{62C}\u{62D}")),
+    (0xFD5E, 0xFD5E, Status::Mapped("\u{633}\u{62C}\u{649}")),
+    (0xFD5F, 0xFD60, Status::Mapped("\u{633}\u{645}\u{62D}")),
+    (0xFD61, 0xFD61, Status::Mapped("\u{633}\u{645}\u{62C}")),
+    (0xFD62, 0xFD63, Status::Mapped("\u{633}\u{645}\u{645}")),
+    (0xFD64, 0xFD65, Status::Mapped("\u{635}\u{62D}\u{62D}")),
+    (0xFD66, 0xFD66, Status::Mapped("\u{635}\u{645}\u{645}")),
+    (0xFD67, 0xFD68, Status::Mapped("\u{634}\u{62D}\u{645}")),
+    (0xFD69, 0xFD69, Status::Mapped("\u{634}\u{62C}\u{64A}")),
+    (0xFD6A, 0xFD6B, Status::Mapped("\u{634}\u{645}\u{62E}")),
+    (0xFD6C, 0xFD6D, Status::Mapped("\u{634}\u{645}\u{645}")),
+    (0xFD6E, 0xFD6E, Status::Mapped("\u{636}\u{62D}\u{649}")),
+    (0xFD6F, 0xFD70, Status::Mapped("\u{636}\u{62E}\u{645}")),
+    (0xFD71, 0xFD72, Status::Mapped("\u{637}\u{645}\u{62D}")),
+    (0xFD73, 0xFD73, Status::Mapped("\u{637}\u{645}\u{645}")),
+    (0xFD74, 0xFD74, Status::Mapped("\u{637}\u{645}\u{64A}")),
+    (0xFD75, 0xFD75, Status::Mapped("\u{639}\u{62C}\u{645}")),
+    (0xFD76, 0xFD77, Status::Mapped("\u{639}\u{645}\u{645}")),
+    (0xFD78, 0xFD78, Status::Mapped("\u{639}\u{645}\u{649}")),
+    (0xFD79, 0xFD79, Status::Mapped("\u{63A}\u{645}\u{645}")),
+    (0xFD7A, 0xFD7A, Status::Mapped("\u{63A}\u{645}\u{64A}")),
+    (0xFD7B, 0xFD7B, Status::Mapped("\u{63A}\u{645}\u{649}")),
+    (0xFD7C, 0xFD7D, Status::Mapped("\u{641}\u{62E}\u{645}")),
+    (0xFD7E, 0xFD7E, Status::Mapped("\u{642}\u{645}\u{62D}")),
+    (0xFD7F, 0xFD7F, Status::Mapped("\u{642}\u{645}\u{645}")),
+    (0xFD80, 0xFD80, Status::Mapped("\u{644}\u{62D}\u{645}")),
+    (0xFD81, 0xFD81, Status::Mapped("\u{644}\u{62D}\u{64A}")),
+    (0xFD82, 0xFD82, Status::Mapped("\u{644}\u{62D}\u{649}")),
+    (0xFD83, 0xFD84, Status::Mapped("\u{644}\u{62C}\u{62C}")),
+    (0xFD85, 0xFD86, Status::Mapped("\u{644}\u{62E}\u{645}")),
+    (0xFD87, 0xFD88, Status::Mapped("\u{644}\u{645}\u{62D}")),
+    (0xFD89, 0xFD89, Status::Mapped("\u{645}\u{62D}\u{62C}")),
+    (0xFD8A, 0xFD8A, Status::Mapped("\u{645}\u{62D}\u{645}")),
+    (0xFD8B, 0xFD8B, Status::Mapped("\u{645}\u{62D}\u{64A}")),
+    (0xFD8C, 0xFD8C, Status::Mapped("\u{645}\u{62C}\u{62D}")),
+    (0xFD8D, 0xFD8D, Status::Mapped("\u{645}\u{62C}\u{645}")),
+    (0xFD8E, 0xFD8E, Status::Mapped("\u{645}\u{62E}\u{62C}")),
+    (0xFD8F, 0xFD8F, Status::Mapped("\u{645}\u{62E}\u{645}")),
+    (0xFD90, 0xFD91, Status::Disallowed),
+    (0xFD92, 0xFD92, Status::Mapped("\u{645}\u{62C}\u{62E}")),
+    (0xFD93, 0xFD93, Status::Mapped("\u{647}\u{645}\u{62C}")),
+    (0xFD94, 0xFD94, Status::Mapped("\u{647}\u{645}\u{645}")),
+    (0xFD95, 0xFD95, Status::Mapped("\u{646}\u{62D}\u{645}")),
+    (0xFD96, 0xFD96, Status::Mapped("\u{646}\u{62D}\u{649}")),
+    (0xFD97, 0xFD98, Status::Mapped("\u{646}\u{62C}\u{645}")),
+    (0xFD99, 0xFD99, Status::Mapped("\u{646}\u{62C}\u{649}")),
+    (0xFD9A, 0xFD9A, Status::Mapped("\u{646}\u{645}\u{64A}")),
+    (0xFD9B, 0xFD9B, Status::Mapped("\u{646}\u{645}\u{649}")),
+    (0xFD9C, 0xFD9D, Status::Mapped("\u{64A}\u{645}\u{645}")),
+    (0xFD9E, 0xFD9E, Status::Mapped("\u{628}\u{62E}\u{64A}")),
+    (0xFD9F, 0xFD9F, Status::Mapped("\u{62A}\u{62C}\u{64A}")),
+    (0xFDA0, 0xFDA0, Status::Mapped("\u{62A}\u{62C}\u{649}")),
+    (0xFDA1, 0xFDA1, Status::Mapped("\u{62A}\u{62E}\u{64A}")),
+    (0xFDA2, 0xFDA2, Status::Mapped("\u{62A}\u{62E}\u{649}")),
+    (0xFDA3, 0xFDA3, Status::Mapped("\u{62A}\u{645}\u{64A}")),
+    (0xFDA4, 0xFDA4, Status::Mapped("\u{62A}\u{645}\u{649}")),
+    (0xFDA5, 0xFDA5, Status::Mapped("\u{62C}\u{645}\u{64A}")),
+    (0xFDA6, 0xFDA6, Status::Mapped("\u{62C}\u{62D}\u{649}")),
+    (0xFDA7, 0xFDA7, Status::Mapped("\u{62C}\u{645}\u{649}")),
+    (0xFDA8, 0xFDA8, Status::Mapped("\u{633}\u{62E}\u{649}")),
+    (0xFDA9, 0xFDA9, Status::Mapped("\u{635}\u{62D}\u{64A}")),
+    (0xFDAA, 0xFDAA, Status::Mapped("\u{634}\u{62D}\u{64A}")),
+    (0xFDAB, 0xFDAB, Status::Mapped("\u{636}\u{62D}\u{64A}")),
+    (0xFDAC, 0xFDAC, Status::Mapped("\u{644}\u{62C}\u{64A}")),
+    (0xFDAD, 0xFDAD, Status::Mapped("\u{644}\u{645}\u{64A}")),
+    (0xFDAE, 0xFDAE, Status::Mapped("\u{64A}\u{62D}\u{64A}")),
+    (0xFDAF, 0xFDAF, Status::Mapped("\u{64A}\u{62C}\u{64A}")),
+    (0xFDB0, 0xFDB0, Status::Mapped("\u{64A}\u{645}\u{64A}")),
+    (0xFDB1, 0xFDB1, Status::Mapped("\u{645}\u{645}\u{64A}")),
+    (0xFDB2, 0xFDB2, Status::Mapped("\u{642}\u{645}\u{64A}")),
+    (0xFDB3, 0xFDB3, Status::Mapped("\u{646}\u{62D}\u{64A}")),
+    (0xFDB4, 0xFDB4, Status::Mapped("\u{642}\u{645}\u{62D}")),
+    (0xFDB5, 0xFDB5, Status::Mapped("\u{644}\u{62D}\u{645}")),
+    (0xFDB6, 0xFDB6, Status::Mapped("\u{639}\u{645}\u{64A}")),
+    (0xFDB7, 0xFDB7, Status::Mapped("\u{643}\u{645}\u{64A}")),
+    (0xFDB8, 0xFDB8, Status::Mapped("\u{646}\u{62C}\u{62D}")),
+    (0xFDB9, 0xFDB9, Status::Mapped("\u{645}\u{62E}\u{64A}")),
+    (0xFDBA, 0xFDBA, Status::Mapped("\u{644}\u{62C}\u{645}")),
+    (0xFDBB, 0xFDBB, Status::Mapped("\u{643}\u{645}\u{645}")),
+    (0xFDBC, 0xFDBC, Status::Mapped("\u{644}\u{62C}\u{645}")),
+    (0xFDBD, 0xFDBD, Status::Mapped("\u{646}\u{62C}\u{62D}")),
+    (0xFDBE, 0xFDBE, Status::Mapped("\u{62C}\u{62D}\u{64A}")),
+    (0xFDBF, 0xFDBF, Status::Mapped("\u{62D}\u{62C}\u{64A}")),
+    (0xFDC0, 0xFDC0, Status::Mapped("\u{645}\u{62C}\u{64A}")),
+    (0xFDC1, 0xFDC1, Status::Mapped("\u{641}\u{645}\u{64A}")),
+    (0xFDC2, 0xFDC2, Status::Mapped("\u{628}\u{62D}\u{64A}")),
+    (0xFDC3, 0xFDC3, Status::Mapped("\u{643}\u{645}\u{645}")),
+    (0xFDC4, 0xFDC4, Status::Mapped("\u{639}\u{62C}\u{645}")),
+    (0xFDC5, 0xFDC5, Status::Mapped("\u{635}\u{645}\u{645}")),
+    (0xFDC6, 0xFDC6, Status::Mapped("\u{633}\u{62E}\u{64A}")),
+    (0xFDC7, 0xFDC7, Status::Mapped("\u{646}\u{62C}\u{64A}")),
+    (0xFDC8, 0xFDCE, Status::Disallowed),
+    (0xFDCF, 0xFDCF, Status::Valid),
+    (0xFDD0, 0xFDEF, Status::Disallowed),
+    (0xFDF0, 0xFDF0, Status::Mapped("\u{635}\u{644}\u{6D2}")),
+    (0xFDF1, 0xFDF1, Status::Mapped("\u{642}\u{644}\u{6D2}")),
+    (0xFDF2, 0xFDF2, Status::Mapped("\u{627}\u{644}\u{644}\u{647}")),
+    (0xFDF3, 0xFDF3, Status::Mapped("\u{627}\u{643}\u{628}\u{631}")),
+    (0xFDF4, 0xFDF4, Status::Mapped("\u{645}\u{62D}\u{645}\u{62F}")),
+    (0xFDF5, 0xFDF5, Status::Mapped("\u{635}\u{644}\u{639}\u{645}")),
+    (0xFDF6, 0xFDF6, Status::Mapped("\u{631}\u{633}\u{648}\u{644}")),
+    (0xFDF7, 0xFDF7, Status::Mapped("\u{639}\u{644}\u{64A}\u{647}")),
+    (0xFDF8, 0xFDF8, Status::Mapped("\u{648}\u{633}\u{644}\u{645}")),
+    (0xFDF9, 0xFDF9, Status::Mapped("\u{635}\u{644}\u{649}")),
+    (0xFDFA, 0xFDFB, Status::Valid),
+    (0xFDFC, 0xFDFC, Status::Mapped("\u{631}\u{6CC}\u{627}\u{644}")),
+    (0xFDFD, 0xFDFF, Status::Valid),
+    (0xFE00, 0xFE0F, Status::Ignored),
+    (0xFE10, 0xFE10, Status::Valid),
+    (0xFE11, 0xFE11, Status::Mapped("\u{3001}")),
+    (0xFE12, 0xFE12, Status::Disallowed),
+    (0xFE13, 0xFE16, Status::Valid),
+    (0xFE17, 0xFE17, Status::Mapped("\u{3016}")),
+    (0xFE18, 0xFE18, Status::Mapped("\u{3017}")),
+    (0xFE19, 0xFE1F, Status::Disallowed),
+    (0xFE20, 0xFE2F, Status::Valid),
+    (0xFE30, 0xFE30, Status::Disallowed),
+    (0xFE31, 0xFE31, Status::Mapped("\u{2014}")),
+    (0xFE32, 0xFE32, Status::Mapped("\u{2013}")),
+    (0xFE33, 0xFE38, Status::Valid),
+    (0xFE39, 0xFE39, Status::Mapped("\u{3014}")),
+    (0xFE3A, 0xFE3A, Status::Mapped("\u{3015}")),
+    (0xFE3B, 0xFE3B, Status::Mapped("\u{3010}")),
+    (0xFE3C, 0xFE3C, Status::Mapped("\u{3011}")),
+    (0xFE3D, 0xFE3D, Status::Mapped("\u{300A}")),
+    (0xFE3E, 0xFE3E, Status::Mapped("\u{300B}")),
+    (0xFE3F, 0xFE3F, Status::Mapped("\u{3008}")),
+    (0xFE40, 0xFE40, Status::Mapped("\u{3009}")),
+    (0xFE41, 0xFE41, Status::Mapped("\u{300C}")),
+    (0xFE42, 0xFE42, Status::Mapped("\u{300D}")),
+    (0xFE43, 0xFE43, Status::Mapped("\u{300E}")),
+    (0xFE44, 0xFE44, Status::Mapped("\u{300F}")),
+    (0xFE45, 0xFE50, Status::Valid),
+    (0xFE51, 0xFE51, Status::Mapped("\u{3001}")),
+    (0xFE52, 0xFE53, Status::Disallowed),
+    (0xFE54, 0xFE57, Status::Valid),
+    (0xFE58, 0xFE58, Status::Mapped("\u{2014}")),
+    (0xFE59, 0xFE5C, Status::Valid),
+    (0xFE5D, 0xFE5D, Status::Mapped("\u{3014}")),
+    (0xFE5E, 0xFE5E, Status::Mapped("\u{3015}")),
+    (0xFE5F, 0xFE62, Status::Valid),
+    (0xFE63, 0xFE63, Status::Mapped("-")),
+    (0xFE64, 0xFE66, Status::Valid),
+    (0xFE67, 0xFE67, Status::Disallowed),
+    (0xFE68, 0xFE6B, Status::Valid),
+    (0xFE6C, 0xFE6F, Status::Disallowed),
+    (0xFE70, 0xFE70, Status::Valid),
+    (0xFE71, 0xFE71, Status::Mapped("\u{640}\u{64B}")),
+    (0xFE72, 0xFE74, Status::Valid),
+    (0xFE75, 0xFE75, Status::Disallowed),
+    (0xFE76, 0xFE76, Status::Valid),
+    (0xFE77, 0xFE77, Status::Mapped("\u{640}\u{64E}")),
+    (0xFE78, 0xFE78, Status::Valid),
+    (0xFE79, 0xFE79, Status::Mapped("\u{640}\u{64F}")),
+    (0xFE7A, 0xFE7A, Status::Valid),
+    (0xFE7B, 0xFE7B, Status::Mapped("\u{640}\u{650}")),
+    (0xFE7C, 0xFE7C, Status::Valid),
+    (0xFE7D, 0xFE7D, Status::Mapped("\u{640}\u{651}")),
+    (0xFE7E, 0xFE7E, Status::Valid),
+    (0xFE7F, 0xFE7F, Status::Mapped("\u{640}\u{652}")),
+    (0xFE80, 0xFE80, Status::Mapped("\u{621}")),
+    (0xFE81, 0xFE82, Status::Mapped("\u{622}")),
+    (0xFE83, 0xFE84, Status::Mapped("\u{623}")),
+    (0xFE85, 0xFE86, Status::Mapped("\u{624}")),
+    (0xFE87, 0xFE88, Status::Mapped("\u{625}")),
+    (0xFE89, 0xFE8C, Status::Mapped("\u{626}")),
+    (0xFE8D, 0xFE8E, Status::Mapped("\u{627}")),
+    (0xFE8F, 0xFE92, Status::Mapped("\u{628}")),
+    (0xFE93, 0xFE94, Status::Mapped("\u{629}")),
+    (0xFE95, 0xFE98, Status::Mapped("\u{62A}")),
+    (0xFE99, 0xFE9C, Status::Mapped("\u{62B}")),
+    (0xFE9D, 0xFEA0, Status::Mapped("\u{62C}")),
+    (0xFEA1, 0xFEA4, Status::Mapped("\u{62D}")),
+    (0xFEA5, 0xFEA8, Status::Mapped("\u{62E}")),
+    (0xFEA9, 0xFEAA, Status::Mapped("\u{62F}")),
+    (0xFEAB, 0xFEAC, Status::Mapped("\u{630}")),
+    (0xFEAD, 0xFEAE, Status::Mapped("\u{631}")),
+    (0xFEAF, 0xFEB0, Status::Mapped("\u{632}")),
+    (0xFEB1, 0xFEB4, Status::Mapped("\u{633}")),
+    (0xFEB5, 0xFEB8, Status::Mapped("\u{634}")),
+    (0xFEB9, 0xFEBC, Status::Mapped("\u{635}")),
+    (0xFEBD, 0xFEC0, Status::Mapped("\u{636}")),
+    (0xFEC1, 0xFEC4, Status::Mapped("\u{637}")),
+    (0xFEC5, 0xFEC8, Status::Mapped("\u{638}")),
+    (0xFEC9, 0xFECC, Status::Mapped("\u{639}")),
+    (0xFECD, 0xFED0, Status::Mapped("\u{63A}")),
+    (0xFED1, 0xFED4, Status::Mapped("\u{641}")),
+    (0xFED5, 0xFED8, Status::Mapped("\u{642}")),
+    (0xFED9, 0xFEDC, Status::Mapped("\u{643}")),
+    (0xFEDD, 0xFEE0, Status::Mapped("\u{644}")),
+    (0xFEE1, 0xFEE4, Status::Mapped("\u{645}")),
+    (0xFEE5, 0xFEE8, Status::Mapped("\u{646}")),
+    (0xFEE9, 0xFEEC, Status::Mapped("\u{647}")),
+    (0xFEED, 0xFEEE, Status::Mapped("\u{648}")),
+    (0xFEEF, 0xFEF0, Status::Mapped("\u{649}")),
+    (0xFEF1, 0xFEF4, Status::Mapped("\u{64A}")),
+    (0xFEF5, 0xFEF6, Status::Mapped("\u{644}\u{622}")),
+    (0xFEF7, 0xFEF8, Status::Mapped("\u{644}\u{623}")),
+    (0xFEF9, 0xFEFA, Status::Mapped("\u{644}\u{625}")),
+    (0xFEFB, 0xFEFC, Status::Mapped("\u{644}\u{627}")),
+    (0xFEFD, 0xFEFE, Status::Disallowed),
+    (0xFEFF, 0xFEFF, Status::Ignored),
+    (0xFF00, 0xFF00, Status::Disallowed),
+    (0xFF01, 0xFF0C, Status::Valid),
+    (0xFF0D, 0xFF0D, Status::Mapped("-")),
+    (0xFF0E, 0xFF0E, Status::Mapped(".")),
+    (0xFF0F, 0xFF0F, Status::Valid),
+    (0xFF10, 0xFF10, Status::Mapped("0")),
+    (0xFF11, 0xFF11, Status::Mapped("1")),
+    (0xFF12, 0xFF12, Status::Mapped("2")),
+    (0xFF13, 0xFF13, Status::Mapped("3")),
+    (0xFF14, 0xFF14, Status::Mapped("4")),
+    (0xFF15, 0xFF15, Status::Mapped("5")),
+    (0xFF16, 0xFF16, Status::Mapped("6")),
+    (0xFF17, 0xFF17, Status::Mapped("7")),
+    (0xFF18, 0xFF18, Status::Mapped("8")),
+    (0xFF19, 0xFF19, Status::Mapped("9")),
+    (0xFF1A, 0xFF20, Status::Valid),
+    (0xFF21, 0xFF21, Status::Mapped("a")),
+    (0xFF22, 0xFF22, Status::Mapped("b")),
+    (0xFF23, 0xFF23, Status::Mapped("c")),
+    (0xFF24, 0xFF24, Status::Mapped("d")),
+    (0xFF25, 0xFF25, Status::Mapped("e")),
+    (0xFF26, 0xFF26, Status::Mapped("f")),
+    (0xFF27, 0xFF27, Status::Mapped("g")),
+    (0xFF28, 0xFF28, Status::Mapped("h")),
+    (0xFF29, 0xFF29, Status::Mapped("i")),
+    (0xFF2A, 0xFF2A, Status::Mapped("j")),
+    (0xFF2B, 0xFF2B, Status::Mapped("k")),
+    (0xFF2C, 0xFF2C, Status::Mapped("l")),
+    (0xFF2D, 0xFF2D, Status::Mapped("m")),
+    (0xFF2E, 0xFF2E, Status::Mapped("n")),
+    (0xFF2F, 0xFF2F, Status::Mapped("o")),
+    (0xFF30, 0xFF30, Status::Mapped("p")),
+    (0xFF31, 0xFF31, Status::Mapped("q")),
+    (0xFF32, 0xFF32, Status::Mapped("r")),
+    (0xFF33, 0xFF33, Status::Mapped("s")),
+    (0xFF34, 0xFF34, Status::Mapped("t")),
+    (0xFF35, 0xFF35, Status::Mapped("u")),
+    (0xFF36, 0xFF36, Status::Mapped("v")),
+    (0xFF37, 0xFF37, Status::Mapped("w")),
+    (0xFF38, 0xFF38, Status::Mapped("x")),
+    (0xFF39, 0xFF39, Status::Mapped("y")),
+    (0xFF3A, 0xFF3A, Status::Mapped("z")),
+    (0xFF3B, 0xFF40, Status::Valid),
+    (0xFF41, 0xFF41, Status::Mapped("a")),
+    (0xFF42, 0xFF42, Status::Mapped("b")),
+    (0xFF43, 0xFF43, Status::Mapped("c")),
+    (0xFF44, 0xFF44, Status::Mapped("d")),
+    (0xFF45, 0xFF45, Status::Mapped("e")),
+    (0xFF46, 0xFF46, Status::Mapped("f")),
+    (0xFF47, 0xFF47, Status::Mapped("g")),
+    (0xFF48, 0xFF48, Status::Mapped("h")),
+    (0xFF49, 0xFF49, Status::Mapped("i")),
+    (0xFF4A, 0xFF4A, Status::Mapped("j")),
+    (0xFF4B, 0xFF4B, Status::Mapped("k")),
+    (0xFF4C, 0xFF4C, Status::Mapped("l")),
+    (0xFF4D, 0xFF4D, Status::Mapped("m")),
+    (0xFF4E, 0xFF4E, Status::Mapped("n")),
+    (0xFF4F, 0xFF4F, Status::Mapped("o")),
+    (0xFF50, 0xFF50, Status::Mapped("p")),
+    (0xFF51, 0xFF51, Status::Mapped("q")),
+    (0xFF52, 0xFF52, Status::Mapped("r")),
+    (0xFF53, 0xFF53, Status::Mapped("s")),
+    (0xFF54, 0xFF54, Status::Mapped("t")),
+    (0xFF55, 0xFF55, Status::Mapped("u")),
+    (0xFF56, 0xFF56, Status::Mapped("v")),
+    (0xFF57, 0xFF57, Status::Mapped("w")),
+    (0xFF58, 0xFF58, Status::Mapped("x")),
+    (0xFF59, 0xFF59, Status::Mapped("y")),
+    (0xFF5A, 0xFF5A, Status::Mapped("z")),
+    (0xFF5B, 0xFF5E, Status::Valid),
+    (0xFF5F, 0xFF5F, Status::Mapped("\u{2985}")),
+    (0xFF60, 0xFF60, Status::Mapped("\u{2986}")),
+    (0xFF61, 0xFF61, Status::Mapped(".")),
+    (0xFF62, 0xFF62, Status::Mapped("\u{300C}")),
+    (0xFF63, 0xFF63, Status::Mapped("\u{300D}")),
+    (0xFF64, 0xFF64, Status::Mapped("\u{3001}")),
+    (0xFF65, 0xFF65, Status::Mapped("\u{30FB}")),
+    (0xFF66, 0xFF66, Status::Mapped("\u{30F2}")),
+    (0xFF67, 0xFF67, Status::Mapped("\u{30A1}")),
+    (0xFF68, 0xFF68, Status::Mapped("\u{30A3}")),
+    (0xFF69, 0xFF69, Status::Mapped("\u{30A5}")),
+    (0xFF6A, 0xFF6A, Status::Mapped("\u{30A7}")),
+    (0xFF6B, 0xFF6B, Status::Mapped("\u{30A9}")),
+    (0xFF6C, 0xFF6C, Status::Mapped("\u{30E3}")),
+    (0xFF6D, 0xFF6D, Status::Mapped("\u{30E5}")),
+    (0xFF6E, 0xFF6E, Status::Mapped("\u{30E7}")),
+    (0xFF6F, 0xFF6F, Status::Mapped("\u{30C3}")),
+    (0xFF70, 0xFF70, Status::Mapped("\u{30FC}")),
+    (0xFF71, 0xFF71, Status::Mapped("\u{30A2}")),
+    (0xFF72, 0xFF72, Status::Mapped("\u{30A4}")),
+    (0xFF73, 0xFF73, Status::Mapped("\u{30A6}")),
+    (0xFF74, 0xFF74, Status::Mapped("\u{30A8}")),
+    (0xFF75, 0xFF75, Status::Mapped("\u{30AA}")),
+    (0xFF76, 0xFF76, Status::Mapped("\u{30AB}")),
+    (0xFF77, 0xFF77, Status::Mapped("\u{30AD}")),
+    (0xFF78, 0xFF78, Status::Mapped("\u{30AF}")),
+    (0xFF79, 0xFF79, Status::Mapped("\u{30B1}")),
+    (0xFF7A, 0xFF7A, Status::Mapped("\u{30B3}")),
+    (0xFF7B, 0xFF7B, Status::Mapped("\u{30B5}")),
+    (0xFF7C, 0xFF7C, Status::Mapped("\u{30B7}")),
+    (0xFF7D, 0xFF7D, Status::Mapped("\u{30B9}")),
+    (0xFF7E, 0xFF7E, Status::Mapped("\u{30BB}")),
+    (0xFF7F, 0xFF7F, Status::Mapped("\u{30BD}")),
+    (0xFF80, 0xFF80, Status::Mapped("\u{30BF}")),
+    (0xFF81, 0xFF81, Status::Mapped("\u{30C1}")),
+    (0xFF82, 0xFF82, Status::Mapped("\u{30C4}")),
+    (0xFF83, 0xFF83, Status::Mapped("\u{30C6}")),
+    (0xFF84, 0xFF84, Status::Mapped("\u{30C8}")),
+    (0xFF85, 0xFF85, Status::Mapped("\u{30CA}")),
+    (0xFF86, 0xFF86, Status::Mapped("\u{30CB}")),
+    (0xFF87, 0xFF87, Status::Mapped("\u{30CC}")),
+    (0xFF88, 0xFF88, Status::Mapped("\u{30CD}")),
+    (0xFF89, 0xFF89, Status::Mapped("\u{30CE}")),
+    (0xFF8A, 0xFF8A, Status::Mapped("\u{30CF}")),
+    (0xFF8B, 0xFF8B, Status::Mapped("\u{30D2}")),
+    (0xFF8C, 0xFF8C, Status::Mapped("\u{30D5}")),
+    (0xFF8D, 0xFF8D, Status::Mapped("\u{30D8}")),
+    (0xFF8E, 0xFF8E, Status::Mapped("\u{30DB}")),
+    (0xFF8F, 0xFF8F, Status::Mapped("\u{30DE}")),
+    (0xFF90, 0xFF90, Status::Mapped("\u{30DF}")),
+    (0xFF91, 0xFF91, Status::Mapped("\u{30E0}")),
+    (0xFF92, 0xFF92, Status::Mapped("\u{30E1}")),
+    (0xFF93, 0xFF93, Status::Mapped("\u{30E2}")),
+    (0xFF94, 0xFF94, Status::Mapped("\u{30E4}")),
+    (0xFF95, 0xFF95, Status::Mapped("\u{30E6}")),
+    (0xFF96, 0xFF96, Status::Mapped("\u{30E8}")),
+    (0xFF97, 0xFF97, Status::Mapped("\u{30E9}")),
+    (0xFF98, 0xFF98, Status::Mapped("\u{30EA}")),
+    (0xFF99, 0xFF99, Status::Mapped("\u{30EB}")),
+    (0xFF9A, 0xFF9A, Status::Mapped("\u{30EC}")),
+    (0xFF9B, 0xFF9B, Status::Mapped("\u{30ED}")),
+    (0xFF9C, 0xFF9C, Status::Mapped("\u{30EF}")),
+    (0xFF9D, 0xFF9D, Status::Mapped("\u{30F3}")),
+    (0xFF9E, 0xFF9E, Status::Mapped("\u{3099}")),
+    (0xFF9F, 0xFF9F, Status::Mapped("\u{309A}")),
+    (0xFFA0, 0xFFA0, Status::Disallowed),
+    (0xFFA1, 0xFFA1, Status::Mapped("\u{1100}")),
+    (0xFFA2, 0xFFA2, Status::Mapped("\u{1101}")),
+    (0xFFA3, 0xFFA3, Status::Mapped("\u{11AA}")),
+    (0xFFA4, 0xFFA4, Status::Mapped("\u{1102}")),
+    (0xFFA5, 0xFFA5, Status::Mapped("\u{11AC}")),
+    (0xFFA6, 0xFFA6, Status::Mapped("\u{11AD}")),
+    (0xFFA7, 0xFFA7, Status::Mapped("\u{1103}")),
+    (0xFFA8, 0xFFA8, Status::Mapped("\u{1104}")),
+    (0xFFA9, 0xFFA9, Status::Mapped("\u{1105}")),
+    (0xFFAA, 0xFFAA, Status::Mapped("\u{11B0}")),
+    (0xFFAB, 0xFFAB, Status::Mapped("\u{11B1}")),
+    (0xFFAC, 0xFFAC, Status::Mapped("\u{11B2}")),
+    (0xFFAD, 0xFFAD, Status::Mapped("\u{11B3}")),
+    (0xFFAE, 0xFFAE, Status::Mapped("\u{11B4}")),
+    (0xFFAF, 0xFFAF, Status::Mapped("\u{11B5}")),
+    (0xFFB0, 0xFFB0, Status::Mapped("\u{111A}")),
+    (0xFFB1, 0xFFB1, Status::Mapped("\u{1106}")),
+    (0xFFB2, 0xFFB2, Status::Mapped("\u{1107}")),
+    (0xFFB3, 0xFFB3, Status::Mapped("\u{1108}")),
+    (0xFFB4, 0xFFB4, Status::Mapped("\u{1121}")),
+    (0xFFB5, 0xFFB5, Status::Mapped("\u{1109}")),
+    (0xFFB6, 0xFFB6, Status::Mapped("\u{110A}")),
+    (0xFFB7, 0xFFB7, Status::Mapped("\u{110B}")),
+    (0xFFB8, 0xFFB8, Status::Mapped("\u{110C}")),
+    (0xFFB9, 0xFFB9, Status::Mapped("\u{110D}")),
+    (0xFFBA, 0xFFBA, Status::Mapped("\u{110E}")),
+    (0xFFBB, 0xFFBB, Status::Mapped("\u{110F}")),
+    (0xFFBC, 0xFFBC, Status::Mapped("\u{1110}")),
+    (0xFFBD, 0xFFBD, Status::Mapped("\u{1111}")),
+    (0xFFBE, 0xFFBE, Status::Mapped("\u{1112}")),
+    (0xFFBF, 0xFFC1, Status::Disallowed),
+    (0xFFC2, 0xFFC2, Status::Mapped("\u{1161}")),
+    (0xFFC3, 0xFFC3, Status::Mapped("\u{1162}")),
+    (0xFFC4, 0xFFC4, Status::Mapped("\u{1163}")),
+    (0xFFC5, 0xFFC5, Status::Mapped("\u{1164}")),
+    (0xFFC6, 0xFFC6, Status::Mapped("\u{1165}")),
+    (0xFFC7, 0xFFC7, Status::Mapped("\u{1166}")),
+    (0xFFC8, 0xFFC9, Status::Disallowed),
+    (0xFFCA, 0xFFCA, Status::Mapped("\u{1167}")),
+    (0xFFCB, 0xFFCB, Status::Mapped("\u{1168}")),
+    (0xFFCC, 0xFFCC, Status::Mapped("\u{1169}")),
+    (0xFFCD, 0xFFCD, Status::Mapped("\u{116A}")),
+    (0xFFCE, 0xFFCE, Status::Mapped("\u{116B}")),
+    (0xFFCF, 0xFFCF, Status::Mapped("\u{116C}")),
+    (0xFFD0, 0xFFD1, Status::Disallowed),
+    (0xFFD2, 0xFFD2, Status::Mapped("\u{116D}")),
+    (0xFFD3, 0xFFD3, Status::Mapped("\u{116E}")),
+    (0xFFD4, 0xFFD4, Status::Mapped("\u{116F}")),
+    (0xFFD5, 0xFFD5, Status::Mapped("\u{1170}")),
+    (0xFFD6, 0xFFD6, Status::Mapped("\u{1171}")),
+    (0xFFD7, 0xFFD7, Status::Mapped("\u{1172}")),
+    (0xFFD8, 0xFFD9, Status::Disallowed),
+    (0xFFDA, 0xFFDA, Status::Mapped("\u{1173}")),
+    (0xFFDB, 0xFFDB, Status::Mapped("\u{1174}")),
+    (0xFFDC, 0xFFDC, Status::Mapped("\u{1175}")),
+    (0xFFDD, 0xFFDF, Status::Disallowed),
+    (0xFFE0, 0xFFE0, Status::Mapped("\u{A2}")),
+    (0xFFE1, 0xFFE1, Status::Mapped("\u{A3}")),
+    (0xFFE2, 0xFFE2, Status::Mapped("\u{AC}")),
+    (0xFFE3, 0xFFE3, Status::Valid),
+    (0xFFE4, 0xFFE4, Status::Mapped("\u{A6}")),
+    (0xFFE5, 0xFFE5, Status::Mapped("\u{A5}")),
+    (0xFFE6, 0xFFE6, Status::Mapped("\u{20A9}")),
+    (0xFFE7, 0xFFE7, Status::Disallowed),
+    (0xFFE8, 0xFFE8, Status::Mapped("\u{2502}")),
+    (0xFFE9, 0xFFE9, Status::Mapped("\u{2190}")),
+    (0xFFEA, 0xFFEA, Status::Mapped("\u{2191}")),
+    (0xFFEB, 0xFFEB, Status::Mapped("\u{2192}")),
+    (0xFFEC, 0xFFEC, Status::Mapped("\u{2193}")),
+    (0xFFED, 0xFFED, Status::Mapped("\u{25A0}")),
+    (0xFFEE, 0xFFEE, Status::Mapped("\u{25CB}")),
+    (0xFFEF, 0xFFFF, Status::Disallowed),
+    (0x10000, 0x1000B, Status::Valid),
+    (0x1000C, 0x1000C, Status::Disallowed),
+    (0x1000D, 0x10026, Status::Valid),
+    (0x10027, 0x10027, Status::Disallowed),
+    (0x10028, 0x1003A, Status::Valid),
+    (0x1003B, 0x1003B, Status::Disallowed),
+    (0x1003C, 0x1003D, Status::Valid),
+    (0x1003E, 0x1003E, Status::Disallowed),
+    (0x1003F, 0x1004D, Status::Valid),
+    (0x1004E, 0x1004F, Status::Disallowed),
+    (0x10050, 0x1005D, Status::Valid),
+    (0x1005E, 0x1007F, Status::Disallowed),
+    (0x10080, 0x100FA, Status::Valid),
+    (0x100FB, 0x100FF, Status::Disallowed),
+    (0x10100, 0x10102, Status::Valid),
+    (0x10103, 0x10106, Status::Disallowed),
+    (0x10107, 0x10133, Status::Valid),
+    (0x10134, 0x10136, Status::Disallowed),
+    (0x10137, 0x1018E, Status::Valid),
+    (0x1018F, 0x1018F, Status::Disallowed),
+    (0x10190, 0x1019C, Status::Valid),
+    (0x1019D, 0x1019F, Status::Disallowed),
+    (0x101A0, 0x101A0, Status::Valid),
+    (0x101A1, 0x101CF, Status::Disallowed),
+    (0x101D0, 0x101FD, Status::Valid),
+    (0x101FE, 0x1027F, Status::Disallowed),
+    (0x10280, 0x1029C, Status::Valid),
+    (0x1029D, 0x1029F, Status::Disallowed),
+    (0x102A0, 0x102D0, Status::Valid),
+    (0x102D1, 0x102DF, Status::Disallowed),
+    (0x102E0, 0x102FB, Status::Valid),
+    (0x102FC, 0x102FF, Status::Disallowed),
+    (0x10300, 0x10323, Status::Valid),
+    (0x10324, 0x1032C, Status::Disallowed),
+    (0x1032D, 0x1034A, Status::Valid),
+    (0x1034B, 0x1034F, Status::Disallowed),
+    (0x10350, 0x1037A, Status::Valid),
+    (0x1037B, 0x1037F, Status::Disallowed),
+    (0x10380, 0x1039D, Status::Valid),
+    (0x1039E, 0x1039E, Status::Disallowed),
+    (0x1039F, 0x103C3, Status::Valid),
+    (0x103C4, 0x103C7, Status::Disallowed),
+    (0x103C8, 0x103D5, Status::Valid),
+    (0x103D6, 0x103FF, Status::Disallowed),
+    (0x10400, 0x10400, Status::Mapped("\u{10428}")),
+    (0x10401, 0x10401, Status::Mapped("\u{10429}")),
+    (0x10402, 0x10402, Status::Mapped("\u{1042A}")),
+    (0x10403, 0x10403, Status::Mapped("\u{1042B}")),
+    (0x10404, 0x10404, Status::Mapped("\u{1042C}")),
+    (0x10405, 0x10405, Status::Mapped("\u{1042D}")),
+    (0x10406, 0x10406, Status::Mapped("\u{1042E}")),
+    (0x10407, 0x10407, Status::Mapped("\u{1042F}")),
+    (0x10408, 0x10408, Status::Mapped("\u{10430}")),
+    (0x10409, 0x10409, Status::Mapped("\u{10431}")),
+    (0x1040A, 0x1040A, Status::Mapped("\u{10432}")),
+    (0x1040B, 0x1040B, Status::Mapped("\u{10433}")),
+    (0x1040C, 0x1040C, Status::Mapped("\u{10434}")),
+    (0x1040D, 0x1040D, Status::Mapped("\u{10435}")),
+    (0x1040E, 0x1040E, Status::Mapped("\u{10436}")),
+    (0x1040F, 0x1040F, Status::Mapped("\u{10437}")),
+    (0x10410, 0x10410, Status::Mapped("\u{10438}")),
+    (0x10411, 0x10411, Status::Mapped("\u{10439}")),
+    (0x10412, 0x10412, Status::Mapped("\u{1043A}")),
+    (0x10413, 0x10413, Status::Mapped("\u{1043B}")),
+    (0x10414, 0x10414, Status::Mapped("\u{1043C}")),
+    (0x10415, 0x10415, Status::Mapped("\u{1043D}")),
+    (0x10416, 0x10416, Status::Mapped("\u{1043E}")),
+    (0x10417, 0x10417, Status::Mapped("\u{1043F}")),
+    (0x10418, 0x10418, Status::Mapped("\u{10440}")),
+    (0x10419, 0x10419, Status::Mapped("\u{10441}")),
+    (0x1041A, 0x1041A, Status::Mapped("\u{10442}")),
+    (0x1041B, 0x1041B, Status::Mapped("\u{10443}")),
+    (0x1041C, 0x1041C, Status::Mapped("\u{10444}")),
+    (0x1041D, 0x1041D, Status::Mapped("\u{10445}")),
+    (0x1041E, 0x1041E, Status::Mapped("\u{10446}")),
+    (0x1041F, 0x1041F, Status::Mapped("\u{10447}")),
+    (0x10420, 0x10420, Status::Mapped("\u{10448}")),
+    (0x10421, 0x10421, Status::Mapped("\u{10449}")),
+    (0x10422, 0x10422, Status::Mapped("\u{1044A}")),
+    (0x10423, 0x10423, Status::Mapped("\u{1044B}")),
+    (0x10424, 0x10424, Status::Mapped("\u{1044C}")),
+    (0x10425, 0x10425, Status::Mapped("\u{1044D}")),
+    (0x10426, 0x10426, Status::Mapped("\u{1044E}")),
+    (0x10427, 0x10427, Status::Mapped("\u{1044F}")),
+    (0x10428, 0x1049D, Status::Valid),
+    (0x1049E, 0x1049F, Status::Disallowed),
+    (0x104A0, 0x104A9, Status::Valid),
+    (0x104AA, 0x104AF, Status::Disallowed),
+    (0x104B0, 0x104B0, Status::Mapped("\u{104D8}")),
+    (0x104B1, 0x104B1, Status::Mapped("\u{104D9}")),
+    (0x104B2, 0x104B2, Status::Mapped("\u{104DA}")),
+    (0x104B3, 0x104B3, Status::Mapped("\u{104DB}")),
+    (0x104B4, 0x104B4, Status::Mapped("\u{104DC}")),
+    (0x104B5, 0x104B5, Status::Mapped("\u{104DD}")),
+    (0x104B6, 0x104B6, Status::Mapped("\u{104DE}")),
+    (0x104B7, 0x104B7, Status::Mapped("\u{104DF}")),
+    (0x104B8, 0x104B8, Status::Mapped("\u{104E0}")),
+    (0x104B9, 0x104B9, Status::Mapped("\u{104E1}")),
+    (0x104BA, 0x104BA, Status::Mapped("\u{104E2}")),
+    (0x104BB, 0x104BB, Status::Mapped("\u{104E3}")),
+    (0x104BC, 0x104BC, Status::Mapped("\u{104E4}")),
+    (0x104BD, 0x104BD, Status::Mapped("\u{104E5}")),
+    (0x104BE, 0x104BE, Status::Mapped("\u{104E6}")),
+    (0x104BF, 0x104BF, Status::Mapped("\u{104E7}")),
+    (0x104C0, 0x104C0, Status::Mapped("\u{104E8}")),
+    (0x104C1, 0x104C1, Status::Mapped("\u{104E9}")),
+    (0x104C2, 0x104C2, Status::Mapped("\u{104EA}")),
+    (0x104C3, 0x104C3, Status::Mapped("\u{104EB}")),
+    (0x104C4, 0x104C4, Status::Mapped("\u{104EC}")),
+    (0x104C5, 0x104C5, Status::Mapped("\u{104ED}")),
+    (0x104C6, 0x104C6, Status::Mapped("\u{104EE}")),
+    (0x104C7, 0x104C7, Status::Mapped("\u{104EF}")),
+    (0x104C8, 0x104C8, Status::Mapped("\u{104F0}")),
+    (0x104C9, 0x104C9, Status::Mapped("\u{104F1}")),
+    (0x104CA, 0x104CA, Status::Mapped("\u{104F2}")),
+    (0x104CB, 0x104CB, Status::Mapped("\u{104F3}")),
+    (0x104CC, 0x104CC, Status::Mapped("\u{104F4}")),
+    (0x104CD, 0x104CD, Status::Mapped("\u{104F5}")),
+    (0x104CE, 0x104CE, Status::Mapped("\u{104F6}")),
+    (0x104CF, 0x104CF, Status::Mapped("\u{104F7}")),
+    (0x104D0, 0x104D0, Status::Mapped("\u{104F8}")),
+    (0x104D1, 0x104D1, Status::Mapped("\u{104F9}")),
+    (0x104D2, 0x104D2, Status::Mapped("\u{104FA}")),
+    (0x104D3, 0x104D3, Status::Mapped("\u{104FB}")),
+    (0x104D4, 0x104D7, Status::Disallowed),
+    (0x104D8, 0x104FB, Status::Valid),
+    (0x104FC, 0x104FF, Status::Disallowed),
+    (0x10500, 0x10527, Status::Valid),
+    (0x10528, 0x1052F, Status::Disallowed),
+    (0x10530, 0x10563, Status::Valid),
+    (0x10564, 0x1056E, Status::Disallowed),
+    (0x1056F, 0x1056F, Status::Valid),
+    (0x10570, 0x10570, Status::Mapped("\u{10597}")),
+    (0x10571, 0x10571, Status::Mapped("\u{10598}")),
+    (0x10572, 0x10572, Status::Mapped("\u{10599}")),
+    (0x10573, 0x10573, Status::Mapped("\u{1059A}")),
+    (0x10574, 0x10574, Status::Mapped("\u{1059B}")),
+    (0x10575, 0x10575, Status::Mapped("\u{1059C}")),
+    (0x10576, 0x10576, Status::Mapped("\u{1059D}")),
+    (0x10577, 0x10577, Status::Mapped("\u{1059E}")),
+    (0x10578, 0x10578, Status::Mapped("\u{1059F}")),
+    (0x10579, 0x10579, Status::Mapped("\u{105A0}")),
+    (0x1057A, 0x1057A, Status::Mapped("\u{105A1}")),
+    (0x1057B, 0x1057B, Status::Disallowed),
+    (0x1057C, 0x1057C, Status::Mapped("\u{105A3}")),
+    (0x1057D, 0x1057D, Status::Mapped("\u{105A4}")),
+    (0x1057E, 0x1057E, Status::Mapped("\u{105A5}")),
+    (0x1057F, 0x1057F, Status::Mapped("\u{105A6}")),
+    (0x10580, 0x10580, Status::Mapped("\u{105A7}")),
+    (0x10581, 0x10581, Status::Mapped("\u{105A8}")),
+    (0x10582, 0x10582, Status::Mapped("\u{105A9}")),
+    (0x10583, 0x10583, Status::Mapped("\u{105AA}")),
+    (0x10584, 0x10584, Status::Mapped("\u{105AB}")),
+    (0x10585, 0x10585, Status::Mapped("\u{105AC}")),
+    (0x10586, 0x10586, Status::Mapped("\u{105AD}")),
+    (0x10587, 0x10587, Status::Mapped("\u{105AE}")),
+    (0x10588, 0x10588, Status::Mapped("\u{105AF}")),
+    (0x10589, 0x10589, Status::Mapped("\u{105B0}")),
+    (0x1058A, 0x1058A, Status::Mapped("\u{105B1}")),
+    (0x1058B, 0x1058B, Status::Disallowed),
+    (0x1058C, 0x1058C, Status::Mapped("\u{105B3}")),
+    (0x1058D, 0x1058D, Status::Mapped("\u{105B4}")),
+    (0x1058E, 0x1058E, Status::Mapped("\u{105B5}")),
+    (0x1058F, 0x1058F, Status::Mapped("\u{105B6}")),
+    (0x10590, 0x10590, Status::Mapped("\u{105B7}")),
+    (0x10591, 0x10591, Status::Mapped("\u{105B8}")),
+    (0x10592, 0x10592, Status::Mapped("\u{105B9}")),
+    (0x10593, 0x10593, Status::Disallowed),
+    (0x10594, 0x10594, Status::Mapped("\u{105BB}")),
+    (0x10595, 0x10595, Status::Mapped("\u{105BC}")),
+    (0x10596, 0x10596, Status::Disallowed),
+    (0x10597, 0x105A1, Status::Valid),
+    (0x105A2, 0x105A2, Status::Disallowed),
+    (0x105A3, 0x105B1, Status::Valid),
+    (0x105B2, 0x105B2, Status::Disallowed),
+    (0x105B3, 0x105B9, Status::Valid),
+    (0x105BA, 0x105BA, Status::Disallowed),
+    (0x105BB, 0x105BC, Status::Valid),
+    (0x105BD, 0x105FF, Status::Disallowed),
+    (0x10600, 0x10736, Status::Valid),
+    (0x10737, 0x1073F, Status::Disallowed),
+    (0x10740, 0x10755, Status::Valid),
+    (0x10756, 0x1075F, Status::Disallowed),
+    (0x10760, 0x10767, Status::Valid),
+    (0x10768, 0x1077F, Status::Disallowed),
+    (0x10780, 0x10780, Status::Valid),
+    (0x10781, 0x10781, Status::Mapped("\u{2D0}")),
+    (0x10782, 0x10782, Status::Mapped("\u{2D1}")),
+    (0x10783, 0x10783, Status::Mapped("\u{E6}")),
+    (0x10784, 0x10784, Status::Mapped("\u{299}")),
+    (0x10785, 0x10785, Status::Mapped("\u{253}")),
+    (0x10786, 0x10786, Status::Disallowed),
+    (0x10787, 0x10787, Status::Mapped("\u{2A3}")),
+    (0x10788, 0x10788, Status::Mapped("\u{AB66}")),
+    (0x10789, 0x10789, Status::Mapped("\u{2A5}")),
+    (0x1078A, 0x1078A, Status::Mapped("\u{2A4}")),
+    (0x1078B, 0x1078B, Status::Mapped("\u{256}")),
+    (0x1078C, 0x1078C, Status::Mapped("\u{257}")),
+    (0x1078D, 0x1078D, Status::Mapped("\u{1D91}")),
+    (0x1078E, 0x1078E, Status::Mapped("\u{258}")),
+    (0x1078F, 0x1078F, Status::Mapped("\u{25E}")),
+    (0x10790, 0x10790, Status::Mapped("\u{2A9}")),
+    (0x10791, 0x10791, Status::Mapped("\u{264}")),
+    (0x10792, 0x10792, Status::Mapped("\u{262}")),
+    (0x10793, 0x10793, Status::Mapped("\u{260}")),
+    (0x10794, 0x10794, Status::Mapped("\u{29B}")),
+    (0x10795, 0x10795, Status::Mapped("\u{127}")),
+    (0x10796, 0x10796, Status::Mapped("\u{29C}")),
+    (0x10797, 0x10797, Status::Mapped("\u{267}")),
+    (0x10798, 0x10798, Status::Mapped("\u{284}")),
+    (0x10799, 0x10799, Status::Mapped("\u{2AA}")),
+    (0x1079A, 0x1079A, Status::Mapped("\u{2AB}")),
+    (0x1079B, 0x1079B, Status::Mapped("\u{26C}")),
+    (0x1079C, 0x1079C, Status::Mapped("\u{1DF04}")),
+    (0x1079D, 0x1079D, Status::Mapped("\u{A78E}")),
+    (0x1079E, 0x1079E, Status::Mapped("\u{26E}")),
+    (0x1079F, 0x1079F, Status::Mapped("\u{1DF05}")),
+    (0x107A0, 0x107A0, Status::Mapped("\u{28E}")),
+    (0x107A1, 0x107A1, Status::Mapped("\u{1DF06}")),
+    (0x107A2, 0x107A2, Status::Mapped("\u{F8}")),
+    (0x107A3, 0x107A3, Status::Mapped("\u{276}")),
+    (0x107A4, 0x107A4, Status::Mapped("\u{277}")),
+    (0x107A5, 0x107A5, Status::Mapped("q")),
+    (0x107A6, 0x107A6, Status::Mapped("\u{27A}")),
+    (0x107A7, 0x107A7, Status::Mapped("\u{1DF08}")),
+    (0x107A8, 0x107A8, Status::Mapped("\u{27D}")),
+    (0x107A9, 0x107A9, Status::Mapped("\u{27E}")),
+    (0x107AA, 0x107AA, Status::Mapped("\u{280}")),
+    (0x107AB, 0x107AB, Status::Mapped("\u{2A8}")),
+    (0x107AC, 0x107AC, Status::Mapped("\u{2A6}")),
+    (0x107AD, 0x107AD, Status::Mapped("\u{AB67}")),
+    (0x107AE, 0x107AE, Status::Mapped("\u{2A7}")),
+    (0x107AF, 0x107AF, Status::Mapped("\u{288}")),
+    (0x107B0, 0x107B0, Status::Mapped("\u{2C71}")),
+    (0x107B1, 0x107B1, Status::Disallowed),
+    (0x107B2, 0x107B2, Status::Mapped("\u{28F}")),
+    (0x107B3, 0x107B3, Status::Mapped("\u{2A1}")),
+    (0x107B4, 0x107B4, Status::Mapped("\u{2A2}")),
+    (0x107B5, 0x107B5, Status::Mapped("\u{298}")),
+    (0x107B6, 0x107B6, Status::Mapped("\u{1C0}")),
+    (0x107B7, 0x107B7, Status::Mapped("\u{1C1}")),
+    (0x107B8, 0x107B8, Status::Mapped("\u{1C2}")),
+    (0x107B9, 0x107B9, Status::Mapped("\u{1DF0A}")),
+    (0x107BA, 0x107BA, Status::Mapped("\u{1DF1E}")),
+    (0x107BB, 0x107FF, Status::Disallowed),
+    (0x10800, 0x10805, Status::Valid),
+    (0x10806, 0x10807, Status::Disallowed),
+    (0x10808, 0x10808, Status::Valid),
+    (0x10809, 0x10809, Status::Disallowed),
+    (0x1080A, 0x10835, Status::Valid),
+    (0x10836, 0x10836, Status::Disallowed),
+    (0x10837, 0x10838, Status::Valid),
+    (0x10839, 0x1083B, Status::Disallowed),
+    (0x1083C, 0x1083C, Status::Valid),
+    (0x1083D, 0x1083E, Status::Disallowed),
+    (0x1083F, 0x10855, Status::Valid),
+    (0x10856, 0x10856, Status::Disallowed),
+    (0x10857, 0x1089E, Status::Valid),
+    (0x1089F, 0x108A6, Status::Disallowed),
+    (0x108A7, 0x108AF, Status::Valid),
+    (0x108B0, 0x108DF, Status::Disallowed),
+    (0x108E0, 0x108F2, Status::Valid),
+    (0x108F3, 0x108F3, Status::Disallowed),
+    (0x108F4, 0x108F5, Status::Valid),
+    (0x108F6, 0x108FA, Status::Disallowed),
+    (0x108FB, 0x1091B, Status::Valid),
+    (0x1091C, 0x1091E, Status::Disallowed),
+    (0x1091F, 0x10939, Status::Valid),
+    (0x1093A, 0x1093E, Status::Disallowed),
+    (0x1093F, 0x1093F, Status::Valid),
+    (0x10940, 0x1097F, Status::Disallowed),
+    (0x10980, 0x109B7, Status::Valid),
+    (0x109B8, 0x109BB, Status::Disallowed),
+    (0x109BC, 0x109CF, Status::Valid),
+    (0x109D0, 0x109D1, Status::Disallowed),
+    (0x109D2, 0x10A03, Status::Valid),
+    (0x10A04, 0x10A04, Status::Disallowed),
+    (0x10A05, 0x10A06, Status::Valid),
+    (0x10A07, 0x10A0B, Status::Disallowed),
+    (0x10A0C, 0x10A13, Status::Valid),
+    (0x10A14, 0x10A14, Status::Disallowed),
+    (0x10A15, 0x10A17, Status::Valid),
+    (0x10A18, 0x10A18, Status::Disallowed),
+    (0x10A19, 0x10A35, Status::Valid),
+    (0x10A36, 0x10A37, Status::Disallowed),
+    (0x10A38, 0x10A3A, Status::Valid),
+    (0x10A3B, 0x10A3E, Status::Disallowed),
+    (0x10A3F, 0x10A48, Status::Valid),
+    (0x10A49, 0x10A4F, Status::Disallowed),
+    (0x10A50, 0x10A58, Status::Valid),
+    (0x10A59, 0x10A5F, Status::Disallowed),
+    (0x10A60, 0x10A9F, Status::Valid),
+    (0x10AA0, 0x10ABF, Status::Disallowed),
+    (0x10AC0, 0x10AE6, Status::Valid),
+    (0x10AE7, 0x10AEA, Status::Disallowed),
+    (0x10AEB, 0x10AF6, Status::Valid),
+    (0x10AF7, 0x10AFF, Status::Disallowed),
+    (0x10B00, 0x10B35, Status::Valid),
+    (0x10B36, 0x10B38, Status::Disallowed),
+    (0x10B39, 0x10B55, Status::Valid),
+    (0x10B56, 0x10B57, Status::Disallowed),
+    (0x10B58, 0x10B72, Status::Valid),
+    (0x10B73, 0x10B77, Status::Disallowed),
+    (0x10B78, 0x10B91, Status::Valid),
+    (0x10B92, 0x10B98, Status::Disallowed),
+    (0x10B99, 0x10B9C, Status::Valid),
+    (0x10B9D, 0x10BA8, Status::Disallowed),
+    (0x10BA9, 0x10BAF, Status::Valid),
+    (0x10BB0, 0x10BFF, Status::Disallowed),
+    (0x10C00, 0x10C48, Status::Valid),
+    (0x10C49, 0x10C7F, Status::Disallowed),
+    (0x10C80, 0x10C80, Status::Mapped("\u{10CC0}")),
+    (0x10C81, 0x10C81, Status::Mapped("\u{10CC1}")),
+    (0x10C82, 0x10C82, Status::Mapped("\u{10CC2}")),
+    (0x10C83, 0x10C83, Status::Mapped("\u{10CC3}")),
+    (0x10C84, 0x10C84, Status::Mapped("\u{10CC4}")),
+    (0x10C85, 0x10C85, Status::Mapped("\u{10CC5}")),
+    (0x10C86, 0x10C86, Status::Mapped("\u{10CC6}")),
+    (0x10C87, 0x10C87, Status::Mapped("\u{10CC7}")),
+    (0x10C88, 0x10C88, Status::Mapped("\u{10CC8}")),
+    (0x10C89, 0x10C89, Status::Mapped("\u{10CC9}")),
+    (0x10C8A, 0x10C8A, Status::Mapped("\u{10CCA}")),
+    (0x10C8B, 0x10C8B, Status::Mapped("\u{10CCB}")),
+    (0x10C8C, 0x10C8C, Status::Mapped("\u{10CCC}")),
+    (0x10C8D, 0x10C8D, Status::Mapped("\u{10CCD}")),
+    (0x10C8E, 0x10C8E, Status::Mapped("\u{10CCE}")),
+    (0x10C8F, 0x10C8F, Status::Mapped("\u{10CCF}")),
+    (0x10C90, 0x10C90, Status::Mapped("\u{10CD0}")),
+    (0x10C91, 0x10C91, Status::Mapped("\u{10CD1}")),
+    (0x10C92, 0x10C92, Status::Mapped("\u{10CD2}")),
+    (0x10C93, 0x10C93, Status::Mapped("\u{10CD3}")),
+    (0x10C94, 0x10C94, Status::Mapped("\u{10CD4}")),
+    (0x10C95, 0x10C95, Status::Mapped("\u{10CD5}")),
+    (0x10C96, 0x10C96, Status::Mapped("\u{10CD6}")),
+    (0x10C97, 0x10C97, Status::Mapped("\u{10CD7}")),
+    (0x10C98, 0x10C98, Status::Mapped("\u{10CD8}")),
+    (0x10C99, 0x10C99, Status::Mapped("\u{10CD9}")),
+    (0x10C9A, 0x10C9A, Status::Mapped("\u{10CDA}")),
+    (0x10C9B, 0x10C9B, Status::Mapped("\u{10CDB}")),
+    (0x10C9C, 0x10C9C, Status::Mapped("\u{10CDC}")),
+    (0x10C9D, 0x10C9D, Status::Mapped("\u{10CDD}")),
+    (0x10C9E, 0x10C9E, Status::Mapped("\u{10CDE}")),
+    (0x10C9F, 0x10C9F, Status::Mapped("\u{10CDF}")),
+    (0x10CA0, 0x10CA0, Status::Mapped("\u{10CE0}")),
+    (0x10CA1, 0x10CA1, Status::Mapped("\u{10CE1}")),
+    (0x10CA2, 0x10CA2, Status::Mapped("\u{10CE2}")),
+    (0x10CA3, 0x10CA3, Status::Mapped("\u{10CE3}")),
+    (0x10CA4, 0x10CA4, Status::Mapped("\u{10CE4}")),
+    (0x10CA5, 0x10CA5, Status::Mapped("\u{10CE5}")),
+    (0x10CA6, 0x10CA6, Status::Mapped("\u{10CE6}")),
+    (0x10CA7, 0x10CA7, Status::Mapped("\u{10CE7}")),
+    (0x10CA8, 0x10CA8, Status::Mapped("\u{10CE8}")),
+    (0x10CA9, 0x10CA9, Status::Mapped("\u{10CE9}")),
+    (0x10CAA, 0x10CAA, Status::Mapped("\u{10CEA}")),
+    (0x10CAB, 0x10CAB, Status::Mapped("\u{10CEB}")),
+    (0x10CAC, 0x10CAC, Status::Mapped("\u{10CEC}")),
+    (0x10CAD, 0x10CAD, Status::Mapped("\u{10CED}")),
+    (0x10CAE, 0x10CAE, Status::Mapped("\u{10CEE}")),
+    (0x10CAF, 0x10CAF, Status::Mapped("\u{10CEF}")),
+    (0x10CB0, 0x10CB0, Status::Mapped("\u{10CF0}")),
+    (0x10CB1, 0x10CB1, Status::Mapped("\u{10CF1}")),
+    (0x10CB2, 0x10CB2, Status::Mapped("\u{10CF2}")),
+    (0x10CB3, 0x10CBF, Status::Disallowed),
+    (0x10CC0, 0x10CF2, Status::Valid),
+    (0x10CF3, 0x10CF9, Status::Disallowed),
+    (0x10CFA, 0x10D27, Status::Valid),
+    (0x10D28, 0x10D2F, Status::Disallowed),
+    (0x10D30, 0x10D39, Status::Valid),
+    (0x10D3A, 0x10E5F, Status::Disallowed),
+    (0x10E60, 0x10E7E, Status::Valid),
+    (0x10E7F, 0x10E7F, Status::Disallowed),
+    (0x10E80, 0x10EA9, Status::Valid),
+    (0x10EAA, 0x10EAA, Status::Disallowed),
+    (0x10EAB, 0x10EAD, Status::Valid),
+    (0x10EAE, 0x10EAF, Status::Disallowed),
+    (0x10EB0, 0x10EB1, Status::Valid),
+    (0x10EB2, 0x10EFC, Status::Disallowed),
+    (0x10EFD, 0x10F27, Status::Valid),
+    (0x10F28, 0x10F2F, Status::Disallowed),
+    (0x10F30, 0x10F59, Status::Valid),
+    (0x10F5A, 0x10F6F, Status::Disallowed),
+    (0x10F70, 0x10F89, Status::Valid),
+    (0x10F8A, 0x10FAF, Status::Disallowed),
+    (0x10FB0, 0x10FCB, Status::Valid),
+    (0x10FCC, 0x10FDF, Status::Disallowed),
+    (0x10FE0, 0x10FF6, Status::Valid),
+    (0x10FF7, 0x10FFF, Status::Disallowed),
+    (0x11000, 0x1104D, Status::Valid),
+    (0x1104E, 0x11051, Status::Disallowed),
+    (0x11052, 0x11075, Status::Valid),
+    (0x11076, 0x1107E, Status::Disallowed),
+    (0x1107F, 0x110BC, Status::Valid),
+    (0x110BD, 0x110BD, Status::Disallowed),
+    (0x110BE, 0x110C2, Status::Valid),
+    (0x110C3, 0x110CF, Status::Disallowed),
+    (0x110D0, 0x110E8, Status::Valid),
+    (0x110E9, 0x110EF, Status::Disallowed),
+    (0x110F0, 0x110F9, Status::Valid),
+    (0x110FA, 0x110FF, Status::Disallowed),
+    (0x11100, 0x11134, Status::Valid),
+    (0x11135, 0x11135, Status::Disallowed),
+    (0x11136, 0x11147, Status::Valid),
+    (0x11148, 0x1114F, Status::Disallowed),
+    (0x11150, 0x11176, Status::Valid),
+    (0x11177, 0x1117F, Status::Disallowed),
+    (0x11180, 0x111DF, Status::Valid),
+    (0x111E0, 0x111E0, Status::Disallowed),
+    (0x111E1, 0x111F4, Status::Valid),
+    (0x111F5, 0x111FF, Status::Disallowed),
+    (0x11200, 0x11211, Status::Valid),
+    (0x11212, 0x11212, Status::Disallowed),
+    (0x11213, 0x11241, Status::Valid),
+    (0x11242, 0x1127F, Status::Disallowed),
+    (0x11280, 0x11286, Status::Valid),
+    (0x11287, 0x11287, Status::Disallowed),
+    (0x11288, 0x11288, Status::Valid),
+    (0x11289, 0x11289, Status::Disallowed),
+    (0x1128A, 0x1128D, Status::Valid),
+    (0x1128E, 0x1128E, Status::Disallowed),
+    (0x1128F, 0x1129D, Status::Valid),
+    (0x1129E, 0x1129E, Status::Disallowed),
+    (0x1129F, 0x112A9, Status::Valid),
+    (0x112AA, 0x112AF, Status::Disallowed),
+    (0x112B0, 0x112EA, Status::Valid),
+    (0x112EB, 0x112EF, Status::Disallowed),
+    (0x112F0, 0x112F9, Status::Valid),
+    (0x112FA, 0x112FF, Status::Disallowed),
+    (0x11300, 0x11303, Status::Valid),
+    (0x11304, 0x11304, Status::Disallowed),
+    (0x11305, 0x1130C, Status::Valid),
+    (0x1130D, 0x1130E, Status::Disallowed),
+    (0x1130F, 0x11310, Status::Valid),
+    (0x11311, 0x11312, Status::Disallowed),
+    (0x11313, 0x11328, Status::Valid),
+    (0x11329, 0x11329, Status::Disallowed),
+    (0x1132A, 0x11330, Status::Valid),
+    (0x11331, 0x11331, Status::Disallowed),
+    (0x11332, 0x11333, Status::Valid),
+    (0x11334, 0x11334, Status::Disallowed),
+    (0x11335, 0x11339, Status::Valid),
+    (0x1133A, 0x1133A, Status::Disallowed),
+    (0x1133B, 0x11344, Status::Valid),
+    (0x11345, 0x11346, Status::Disallowed),
+    (0x11347, 0x11348, Status::Valid),
+    (0x11349, 0x1134A, Status::Disallowed),
+    (0x1134B, 0x1134D, Status::Valid),
+    (0x1134E, 0x1134F, Status::Disallowed),
+    (0x11350, 0x11350, Status::Valid),
+    (0x11351, 0x11356, Status::Disallowed),
+    (0x11357, 0x11357, Status::Valid),
+    (0x11358, 0x1135C, Status::Disallowed),
+    (0x1135D, 0x11363, Status::Valid),
+    (0x11364, 0x11365, Status::Disallowed),
+    (0x11366, 0x1136C, Status::Valid),
+    (0x1136D, 0x1136F, Status::Disallowed),
+    (0x11370, 0x11374, Status::Valid),
+    (0x11375, 0x113FF, Status::Disallowed),
+    (0x11400, 0x1145B, Status::Valid),
+    (0x1145C, 0x1145C, Status::Disallowed),
+    (0x1145D, 0x11461, Status::Valid),
+    (0x11462, 0x1147F, Status::Disallowed),
+    (0x11480, 0x114C7, Status::Valid),
+    (0x114C8, 0x114CF, Status::Disallowed),
+    (0x114D0, 0x114D9, Status::Valid),
+    (0x114DA, 0x1157F, Status::Disallowed),
+    (0x11580, 0x115B5, Status::Valid),
+    (0x115B6, 0x115B7, Status::Disallowed),
+    (0x115B8, 0x115DD, Status::Valid),
+    (0x115DE, 0x115FF, Status::Disallowed),
+    (0x11600, 0x11644, Status::Valid),
+    (0x11645, 0x1164F, Status::Disallowed),
+    (0x11650, 0x11659, Status::Valid),
+    (0x1165A, 0x1165F, Status::Disallowed),
+    (0x11660, 0x1166C, Status::Valid),
+    (0x1166D, 0x1167F, Status::Disallowed),
+    (0x11680, 0x116B9, Status::Valid),
+    (0x116BA, 0x116BF, Status::Disallowed),
+    (0x116C0, 0x116C9, Status::Valid),
+    (0x116CA, 0x116FF, Status::Disallowed),
+    (0x11700, 0x1171A, Status::Valid),
+    (0x1171B, 0x1171C, Status::Disallowed),
+    (0x1171D, 0x1172B, Status::Valid),
+    (0x1172C, 0x1172F, Status::Disallowed),
+    (0x11730, 0x11746, Status::Valid),
+    (0x11747, 0x117FF, Status::Disallowed),
+    (0x11800, 0x1183B, Status::Valid),
+    (0x1183C, 0x1189F, Status::Disallowed),
+    (0x118A0, 0x118A0, Status::Mapped("\u{118C0}")),
+    (0x118A1, 0x118A1, Status::Mapped("\u{118C1}")),
+    (0x118A2, 0x118A2, Status::Mapped("\u{118C2}")),
+    (0x118A3, 0x118A3, Status::Mapped("\u{118C3}")),
+    (0x118A4, 0x118A4, Status::Mapped("\u{118C4}")),
+    (0x118A5, 0x118A5, Status::Mapped("\u{118C5}")),
+    (0x118A6, 0x118A6, Status::Mapped("\u{118C6}")),
+    (0x118A7, 0x118A7, Status::Mapped("\u{118C7}")),
+    (0x118A8, 0x118A8, Status::Mapped("\u{118C8}")),
+    (0x118A9, 0x118A9, Status::Mapped("\u{118C9}")),
+    (0x118AA, 0x118AA, Status::Mapped("\u{118CA}")),
+    (0x118AB, 0x118AB, Status::Mapped("\u{118CB}")),
+    (0x118AC, 0x118AC, Status::Mapped("\u{118CC}")),
+    (0x118AD, 0x118AD, Status::Mapped("\u{118CD}")),
+    (0x118AE, 0x118AE, Status::Mapped("\u{118CE}")),
+    (0x118AF, 0x118AF, Status::Mapped("\u{118CF}")),
+    (0x118B0, 0x118B0, Status::Mapped("\u{118D0}")),
+    (0x118B1, 0x118B1, Status::Mapped("\u{118D1}")),
+    (0x118B2, 0x118B2, Status::Mapped("\u{118D2}")),
+    (0x118B3, 0x118B3, Status::Mapped("\u{118D3}")),
+    (0x118B4, 0x118B4, Status::Mapped("\u{118D4}")),
+    (0x118B5, 0x118B5, Status::Mapped("\u{118D5}")),
+    (0x118B6, 0x118B6, Status::Mapped("\u{118D6}")),
+    (0x118B7, 0x118B7, Status::Mapped("\u{118D7}")),
+    (0x118B8, 0x118B8, Status::Mapped("\u{118D8}")),
+    (0x118B9, 0x118B9, Status::Mapped("\u{118D9}")),
+    (0x118BA, 0x118BA, Status::Mapped("\u{118DA}")),
+    (0x118BB, 0x118BB, Status::Mapped("\u{118DB}")),
+    (0x118BC, 0x118BC, Status::Mapped("\u{118DC}")),
+    (0x118BD, 0x118BD, Status::Mapped("\u{118DD}")),
+    (0x118BE, 0x118BE, Status::Mapped("\u{118DE}")),
+    (0x118BF, 0x118BF, Status::Mapped("\u{118DF}")),
+    (0x118C0, 0x118F2, Status::Valid),
+    (0x118F3, 0x118FE, Status::Disallowed),
+    (0x118FF, 0x11906, Status::Valid),
+    (0x11907, 0x11908, Status::Disallowed),
+    (0x11909, 0x11909, Status::Valid),
+    (0x1190A, 0x1190B, Status::Disallowed),
+    (0x1190C, 0x11913, Status::Valid),
+    (0x11914, 0x11914, Status::Disallowed),
+    (0x11915, 0x11916, Status::Valid),
+    (0x11917, 0x11917, Status::Disallowed),
+    (0x11918, 0x11935, Status::Valid),
+    (0x11936, 0x11936, Status::Disallowed),
+    (0x11937, 0x11938, Status::Valid),
+    (0x11939, 0x1193A, Status::Disallowed),
+    (0x1193B, 0x11946, Status::Valid),
+    (0x11947, 0x1194F, Status::Disallowed),
+    (0x11950, 0x11959, Status::Valid),
+    (0x1195A, 0x1199F, Status::Disallowed),
+    (0x119A0, 0x119A7, Status::Valid),
+    (0x119A8, 0x119A9, Status::Disallowed),
+    (0x119AA, 0x119D7, Status::Valid),
+    (0x119D8, 0x119D9, Status::Disallowed),
+    (0x119DA, 0x119E4, Status::Valid),
+    (0x119E5, 0x119FF, Status::Disallowed),
+    (0x11A00, 0x11A47, Status::Valid),
+    (0x11A48, 0x11A4F, Status::Disallowed),
+    (0x11A50, 0x11AA2, Status::Valid),
+    (0x11AA3, 0x11AAF, Status::Disallowed),
+    (0x11AB0, 0x11AF8, Status::Valid),
+    (0x11AF9, 0x11AFF, Status::Disallowed),
+    (0x11B00, 0x11B09, Status::Valid),
+    (0x11B0A, 0x11BFF, Status::Disallowed),
+    (0x11C00, 0x11C08, Status::Valid),
+    (0x11C09, 0x11C09, Status::Disallowed),
+    (0x11C0A, 0x11C36, Status::Valid),
+    (0x11C37, 0x11C37, Status::Disallowed),
+    (0x11C38, 0x11C45, Status::Valid),
+    (0x11C46, 0x11C4F, Status::Disallowed),
+    (0x11C50, 0x11C6C, Status::Valid),
+    (0x11C6D, 0x11C6F, Status::Disallowed),
+    (0x11C70, 0x11C8F, Status::Valid),
+    (0x11C90, 0x11C91, Status::Disallowed),
+    (0x11C92, 0x11CA7, Status::Valid),
+    (0x11CA8, 0x11CA8, Status::Disallowed),
+    (0x11CA9, 0x11CB6, Status::Valid),
+    (0x11CB7, 0x11CFF, Status::Disallowed),
+    (0x11D00, 0x11D06, Status::Valid),
+    (0x11D07, 0x11D07, Status::Disallowed),
+    (0x11D08, 0x11D09, Status::Valid),
+    (0x11D0A, 0x11D0A, Status::Disallowed),
+    (0x11D0B, 0x11D36, Status::Valid),
+    (0x11D37, 0x11D39, Status::Disallowed),
+    (0x11D3A, 0x11D3A, Status::Valid),
+    (0x11D3B, 0x11D3B, Status::Disallowed),
+    (0x11D3C, 0x11D3D, Status::Valid),
+    (0x11D3E, 0x11D3E, Status::Disallowed),
+    (0x11D3F, 0x11D47, Status::Valid),
+    (0x11D48, 0x11D4F, Status::Disallowed),
+    (0x11D50, 0x11D59, Status::Valid),
+    (0x11D5A, 0x11D5F, Status::Disallowed),
+    (0x11D60, 0x11D65, Status::Valid),
+    (0x11D66, 0x11D66, Status::Disallowed),
+    (0x11D67, 0x11D68, Status::Valid),
+    (0x11D69, 0x11D69, Status::Disallowed),
+    (0x11D6A, 0x11D8E, Status::Valid),
+    (0x11D8F, 0x11D8F, Status::Disallowed),
+    (0x11D90, 0x11D91, Status::Valid),
+    (0x11D92, 0x11D92, Status::Disallowed),
+    (0x11D93, 0x11D98, Status::Valid),
+    (0x11D99, 0x11D9F, Status::Disallowed),
+    (0x11DA0, 0x11DA9, Status::Valid),
+    (0x11DAA, 0x11EDF, Status::Disallowed),
+    (0x11EE0, 0x11EF8, Status::Valid),
+    (0x11EF9, 0x11EFF, Status::Disallowed),
+    (0x11F00, 0x11F10, Status::Valid),
+    (0x11F11, 0x11F11, Status::Disallowed),
+    (0x11F12, 0x11F3A, Status::Valid),
+    (0x11F3B, 0x11F3D, Status::Disallowed),
+    (0x11F3E, 0x11F59, Status::Valid),
+    (0x11F5A, 0x11FAF, Status::Disallowed),
+    (0x11FB0, 0x11FB0, Status::Valid),
+    (0x11FB1, 0x11FBF, Status::Disallowed),
+    (0x11FC0, 0x11FF1, Status::Valid),
+    (0x11FF2, 0x11FFE, Status::Disallowed),
+    (0x11FFF, 0x12399, Status::Valid),
+    (0x1239A, 0x123FF, Status::Disallowed),
+    (0x12400, 0x1246E, Status::Valid),
+    (0x1246F, 0x1246F, Status::Disallowed),
+    (0x12470, 0x12474, Status::Valid),
+    (0x12475, 0x1247F, Status::Disallowed),
+    (0x12480, 0x12543, Status::Valid),
+    (0x12544, 0x12F8F, Status::Disallowed),
+    (0x12F90, 0x12FF2, Status::Valid),
+    (0x12FF3, 0x12FFF, Status::Disallowed),
+    (0x13000, 0x1342F, Status::Valid),
+    (0x13430, 0x1343F, Status::Disallowed),
+    (0x13440, 0x13455, Status::Valid),
+    (0x13456, 0x143FF, Status::Disallowed),
+    (0x14400, 0x14646, Status::Valid),
+    (0x14647, 0x167FF, Status::Disallowed),
+    (0x16800, 0x16A38, Status::Valid),
+    (0x16A39, 0x16A3F, Status::Disallowed),
+    (0x16A40, 0x16A5E, Status::Valid),
+    (0x16A5F, 0x16A5F, Status::Disallowed),
+    (0x16A60, 0x16A69, Status::Valid),
+    (0x16A6A, 0x16A6D, Status::Disallowed),
+    (0x16A6E, 0x16ABE, Status::Valid),
+    (0x16ABF, 0x16ABF, Status::Disallowed),
+    (0x16AC0, 0x16AC9, Status::Valid),
+    (0x16ACA, 0x16ACF, Status::Disallowed),
+    (0x16AD0, 0x16AED, Status::Valid),
+    (0x16AEE, 0x16AEF, Status::Disallowed),
+    (0x16AF0, 0x16AF5, Status::Valid),
+    (0x16AF6, 0x16AFF, Status::Disallowed),
+    (0x16B00, 0x16B45, Status::Valid),
+    (0x16B46, 0x16B4F, Status::Disallowed),
+    (0x16B50, 0x16B59, Status::Valid),
+    (0x16B5A, 0x16B5A, Status::Disallowed),
+    (0x16B5B, 0x16B61, Status::Valid),
+    (0x16B62, 0x16B62, Status::Disallowed),
+    (0x16B63, 0x16B77, Status::Valid),
+    (0x16B78, 0x16B7C, Status::Disallowed),
+    (0x16B7D, 0x16B8F, Status::Valid),
+    (0x16B90, 0x16E3F, Status::Disallowed),
+    (0x16E40, 0x16E40, Status::Mapped("\u{16E60}")),
+    (0x16E41, 0x16E41, Status::Mapped("\u{16E61}")),
+    (0x16E42, 0x16E42, Status::Mapped("\u{16E62}")),
+    (0x16E43, 0x16E43, Status::Mapped("\u{16E63}")),
+    (0x16E44, 0x16E44, Status::Mapped("\u{16E64}")),
+    (0x16E45, 0x16E45, Status::Mapped("\u{16E65}")),
+    (0x16E46, 0x16E46, Status::Mapped("\u{16E66}")),
+    (0x16E47, 0x16E47, Status::Mapped("\u{16E67}")),
+    (0x16E48, 0x16E48, Status::Mapped("\u{16E68}")),
+    (0x16E49, 0x16E49, Status::Mapped("\u{16E69}")),
+    (0x16E4A, 0x16E4A, Status::Mapped("\u{16E6A}")),
+    (0x16E4B, 0x16E4B, Status::Mapped("\u{16E6B}")),
+    (0x16E4C, 0x16E4C, Status::Mapped("\u{16E6C}")),
+    (0x16E4D, 0x16E4D, Status::Mapped("\u{16E6D}")),
+    (0x16E4E, 0x16E4E, Status::Mapped("\u{16E6E}")),
+    (0x16E4F, 0x16E4F, Status::Mapped("\u{16E6F}")),
+    (0x16E50, 0x16E50, Status::Mapped("\u{16E70}")),
+    (0x16E51, 0x16E51, Status::Mapped("\u{16E71}")),
+    (0x16E52, 0x16E52, Status::Mapped("\u{16E72}")),
+    (0x16E53, 0x16E53, Status::Mapped("\u{16E73}")),
+    (0x16E54, 0x16E54, Status::Mapped("\u{16E74}")),
+    (0x16E55, 0x16E55, Status::Mapped("\u{16E75}")),
+    (0x16E56, 0x16E56, Status::Mapped("\u{16E76}")),
+    (0x16E57, 0x16E57, Status::Mapped("\u{16E77}")),
+    (0x16E58, 0x16E58, Status::Mapped("\u{16E78}")),
+    (0x16E59, 0x16E59, Status::Mapped("\u{16E79}")),
+    (0x16E5A, 0x16E5A, Status::Mapped("\u{16E7A}")),
+    (0x16E5B, 0x16E5B, Status::Mapped("\u{16E7B}")),
+    (0x16E5C, 0x16E5C, Status::Mapped("\u{16E7C}")),
+    (0x16E5D, 0x16E5D, Status::Mapped("\u{16E7D}")),
+    (0x16E5E, 0x16E5E, Status::Mapped("\u{16E7E}")),
+    (0x16E5F, 0x16E5F, Status::Mapped("\u{16E7F}")),
+    (0x16E60, 0x16E9A, Status::Valid),
+    (0x16E9B, 0x16EFF, Status::Disallowed),
+    (0x16F00, 0x16F4A, Status::Valid),
+    (0x16F4B, 0x16F4E, Status::Disallowed),
+    (0x16F4F, 0x16F87, Status::Valid),
+    (0x16F88, 0x16F8E, Status::Disallowed),
+    (0x16F8F, 0x16F9F, Status::Valid),
+    (0x16FA0, 0x16FDF, Status::Disallowed),
+    (0x16FE0, 0x16FE4, Status::Valid),
+    (0x16FE5, 0x16FEF, Status::Disallowed),
+    (0x16FF0, 0x16FF1, Status::Valid),
+    (0x16FF2, 0x16FFF, Status::Disallowed),
+    (0x17000, 0x187F7, Status::Valid),
+    (0x187F8, 0x187FF, Status::Disallowed),
+    (0x18800, 0x18CD5, Status::Valid),
+    (0x18CD6, 0x18CFF, Status::Disallowed),
+    (0x18D00, 0x18D08, Status::Valid),
+    (0x18D09, 0x1AFEF, Status::Disallowed),
+    (0x1AFF0, 0x1AFF3, Status::Valid),
+    (0x1AFF4, 0x1AFF4, Status::Disallowed),
+    (0x1AFF5, 0x1AFFB, Status::Valid),
+    (0x1AFFC, 0x1AFFC, Status::Disallowed),
+    (0x1AFFD, 0x1AFFE, Status::Valid),
+    (0x1AFFF, 0x1AFFF, Status::Disallowed),
+    (0x1B000, 0x1B122, Status::Valid),
+    (0x1B123, 0x1B131, Status::Disallowed),
+    (0x1B132, 0x1B132, Status::Valid),
+    (0x1B133, 0x1B14F, Status::Disallowed),
+    (0x1B150, 0x1B152, Status::Valid),
+    (0x1B153, 0x1B154, Status::Disallowed),
+    (0x1B155, 0x1B155, Status::Valid),
+    (0x1B156, 0x1B163, Status::Disallowed),
+    (0x1B164, 0x1B167, Status::Valid),
+    (0x1B168, 0x1B16F, Status::Disallowed),
+    (0x1B170, 0x1B2FB, Status::Valid),
+    (0x1B2FC, 0x1BBFF, Status::Disallowed),
+    (0x1BC00, 0x1BC6A, Status::Valid),
+    (0x1BC6B, 0x1BC6F, Status::Disallowed),
+    (0x1BC70, 0x1BC7C, Status::Valid),
+    (0x1BC7D, 0x1BC7F, Status::Disallowed),
+    (0x1BC80, 0x1BC88, Status::Valid),
+    (0x1BC89, 0x1BC8F, Status::Disallowed),
+    (0x1BC90, 0x1BC99, Status::Valid),
+    (0x1BC9A, 0x1BC9B, Status::Disallowed),
+    (0x1BC9C, 0x1BC9F, Status::Valid),
+    (0x1BCA0, 0x1BCA3, Status::Ignored),
+    (0x1BCA4, 0x1CEFF, Status::Disallowed),
+    (0x1CF00, 0x1CF2D, Status::Valid),
+    (0x1CF2E, 0x1CF2F, Status::Disallowed),
+    (0x1CF30, 0x1CF46, Status::Valid),
+    (0x1CF47, 0x1CF4F, Status::Disallowed),
+    (0x1CF50, 0x1CFC3, Status::Valid),
+    (0x1CFC4, 0x1CFFF, Status::Disallowed),
+    (0x1D000, 0x1D0F5, Status::Valid),
+    (0x1D0F6, 0x1D0FF, Status::Disallowed),
+    (0x1D100, 0x1D126, Status::Valid),
+    (0x1D127, 0x1D128, Status::Disallowed),
+    (0x1D129, 0x1D15D, Status::Valid),
+    (0x1D15E, 0x1D15E, Status::Mapped("\u{1D157}\u{1D165}")),
+    (0x1D15F, 0x1D15F, Status::Mapped("\u{1D158}\u{1D165}")),
+    (0x1D160, 0x1D160, Status::Mapped("\u{1D158}\u{1D165}\u{1D16E}")),
+    (0x1D161, 0x1D161, Status::Mapped("\u{1D158}\u{1D165}\u{1D16F}")),
+    (0x1D162, 0x1D162, Status::Mapped("\u{1D158}\u{1D165}\u{1D170}")),
+    (0x1D163, 0x1D163, Status::Mapped("\u{1D158}\u{1D165}\u{1D171}")),
+    (0x1D164, 0x1D164, Status::Mapped("\u{1D158}\u{1D165}\u{1D172}")),
+    (0x1D165, 0x1D172, Status::Valid),
+    (0x1D173, 0x1D17A, Status::Disallowed),
+    (0x1D17B, 0x1D1BA, Status::Valid),
+    (0x1D1BB, 0x1D1BB, Status::Mapped("\u{1D1B9}\u{1D165}")),
+    (0x1D1BC, 0x1D1BC, Status::Mapped("\u{1D1BA}\u{1D165}")),
+    (0x1D1BD, 0x1D1BD, Status::Mapped("\u{1D1B9}\u{1D165}\u{1D16E}")),
+    (0x1D1BE, 0x1D1BE, Status::Mapped("\u{1D1BA}\u{1D165}\u{1D16E}")),
+    (0x1D1BF, 0x1D1BF, Status::Mapped("\u{1D1B9}\u{1D165}\u{1D16F}")),
+    (0x1D1C0, 0x1D1C0, Status::Mapped("\u{1D1BA}\u{1D165}\u{1D16F}")),
+    (0x1D1C1, 0x1D1EA, Status::Valid),
+    (0x1D1EB, 0x1D1FF, Status::Disallowed),
+    (0x1D200, 0x1D245, Status::Valid),
+    (0x1D246, 0x1D2BF, Status::Disallowed),
+    (0x1D2C0, 0x1D2D3, Status::Valid),
+    (0x1D2D4, 0x1D2DF, Status::Disallowed),
+    (0x1D2E0, 0x1D2F3, Status::Valid),
+    (0x1D2F4, 0x1D2FF, Status::Disallowed),
+    (0x1D300, 0x1D356, Status::Valid),
+    (0x1D357, 0x1D35F, Status::Disallowed),
+    (0x1D360, 0x1D378, Status::Valid),
+    (0x1D379, 0x1D3FF, Status::Disallowed),
+    (0x1D400, 0x1D400, Status::Mapped("a")),
+    (0x1D401, 0x1D401, Status::Mapped("b")),
+    (0x1D402, 0x1D402, Status::Mapped("c")),
+    (0x1D403, 0x1D403, Status::Mapped("d")),
+    (0x1D404, 0x1D404, Status::Mapped("e")),
+    (0x1D405, 0x1D405, Status::Mapped("f")),
+    (0x1D406, 0x1D406, Status::Mapped("g")),
+    (0x1D407, 0x1D407, Status::Mapped("h")),
+    (0x1D408, 0x1D408, Status::Mapped("i")),
+    (0x1D409, 0x1D409, Status::Mapped("j")),
+    (0x1D40A, 0x1D40A, Status::Mapped("k")),
+    (0x1D40B, 0x1D40B, Status::Mapped("l")),
+    (0x1D40C, 0x1D40C, Status::Mapped("m")),
+    (0x1D40D, 0x1D40D, Status::Mapped("n")),
+    (0x1D40E, 0x1D40E, Status::Mapped("o")),
+    (0x1D40F, 0x1D40F, Status::Mapped("p")),
+    (0x1D410, 0x1D410, Status::Mapped("q")),
+    (0x1D411, 0x1D411, Status::Mapped("r")),
+    (0x1D412, 0x1D412, Status::Mapped("s")),
+    (0x1D413, 0x1D413, Status::Mapped("t")),
+    (0x1D414, 0x1D414, Status::Mapped("u")),
+    (0x1D415, 0x1D415, Status::Mapped("v")),
+    (0x1D416, 0x1D416, Status::Mapped("w")),
+    (0x1D417, 0x1D417, Status::Mapped("x")),
+    (0x1D418, 0x1D418, Status::Mapped("y")),
+    (0x1D419, 0x1D419, Status::Mapped("z")),
+    (0x1D41A, 0x1D41A, Status::Mapped("a")),
+    (0x1D41B, 0x1D41B, Status::Mapped("b")),
+    (0x1D41C, 0x1D41C, Status::Mapped("c")),
+    (0x1D41D, 0x1D41D, Status::Mapped("d")),
+    (0x1D41E, 0x1D41E, Status::Mapped("e")),
+    (0x1D41F, 0x1D41F, Status::Mapped("f")),
+    (0x1D420, 0x1D420, Status::Mapped("g")),
+    (0x1D421, 0x1D421, Status::Mapped("h")),
+    (0x1D422, 0x1D422, Status::Mapped("i")),
+    (0x1D423, 0x1D423, Status::Mapped("j")),
+    (0x1D424, 0x1D424, Status::Mapped("k")),
+    (0x1D425, 0x1D425, Status::Mapped("l")),
+    (0x1D426, 0x1D426, Status::Mapped("m")),
+    (0x1D427, 0x1D427, Status::Mapped("n")),
+    (0x1D428, 0x1D428, Status::Mapped("o")),
+    (0x1D429, 0x1D429, Status::Mapped("p")),
+    (0x1D42A, 0x1D42A, Status::Mapped("q")),
+    (0x1D42B, 0x1D42B, Status::Mapped("r")),
+    (0x1D42C, 0x1D42C, Status::Mapped("s")),
+    (0x1D42D, 0x1D42D, Status::Mapped("t")),
+    (0x1D42E, 0x1D42E, Status::Mapped("u")),
+    (0x1D42F, 0x1D42F, Status::Mapped("v")),
+    (0x1D430, 0x1D430, Status::Mapped("w")),
+    (0x1D431, 0x1D431, Status::Mapped("x")),
+    (0x1D432, 0x1D432, Status::Mapped("y")),
+    (0x1D433, 0x1D433, Status::Mapped("z")),
+    (0x1D434, 0x1D434, Status::Mapped("a")),
+    (0x1D435, 0x1D435, Status::Mapped("b")),
+    (0x1D436, 0x1D436, Status::Mapped("c")),
+    (0x1D437, 0x1D437, Status::Mapped("d")),
+    (0x1D438, 0x1D438, Status::Mapped("e")),
+    (0x1D439, 0x1D439, Status::Mapped("f")),
+    (0x1D43A, 0x1D43A, Status::Mapped("g")),
+    (0x1D43B, 0x1D43B, Status::Mapped("h")),
+    (0x1D43C, 0x1D43C, Status::Mapped("i")),
+    (0x1D43D, 0x1D43D, Status::Mapped("j")),
+    (0x1D43E, 0x1D43E, Status::Mapped("k")),
+    (0x1D43F, 0x1D43F, Status::Mapped("l")),
+    (0x1D440, 0x1D440, Status::Mapped("m")),
+    (0x1D441, 0x1D441, Status::Mapped("n")),
+    (0x1D442, 0x1D442, Status::Mapped("o")),
+    (0x1D443, 0x1D443, Status::Mapped("p")),
+    (0x1D444, 0x1D444, Status::Mapped("q")),
+    (0x1D445, 0x1D445, Status::Mapped("r")),
+    (0x1D446, 0x1D446, Status::Mapped("s")),
+    (0x1D447, 0x1D447, Status::Mapped("t")),
+    (0x1D448, 0x1D448, Status::Mapped("u")),
+    (0x1D449, 0x1D449, Status::Mapped("v")),
+    (0x1D44A, 0x1D44A, Status::Mapped("w")),
+    (0x1D44B, 0x1D44B, Status::Mapped("x")),
+    (0x1D44C, 0x1D44C, Status::Mapped("y")),
+    (0x1D44D, 0x1D44D, Status::Mapped("z")),
+    (0x1D44E, 0x1D44E, Status::Mapped("a")),
+    (0x1D44F, 0x1D44F, Status::Mapped("b")),
+    (0x1D450, 0x1D450, Status::Mapped("c")),
+    (0x1D451, 0x1D451, Status::Mapped("d")),
+    (0x1D452, 0x1D452, Status::Mapped("e")),
+    (0x1D453, 0x1D453, Status::Mapped("f")),
+    (0x1D454, 0x1D454, Status::Mapped("g")),
+    (0x1D455, 0x1D455, Status::Disallowed),
+    (0x1D456, 0x1D456, Status::Mapped("i")),
+    (0x1D457, 0x1D457, Status::Mapped("j")),
+    (0x1D458, 0x1D458, Status::Mapped("k")),
+    (0x1D459, 0x1D459, Status::Mapped("l")),
+    (0x1D45A, 0x1D45A, Status::Mapped("m")),
+    (0x1D45B, 0x1D45B, Status::Mapped("n")),
+    (0x1D45C, 0x1D45C, Status::Mapped("o")),
+    (0x1D45D, 0x1D45D, Status::Mapped("p")),
+    (0x1D45E, 0x1D45E, Status::Mapped("q")),
+    (0x1D45F, 0x1D45F, Status::Mapped("r")),
+    (0x1D460, 0x1D460, Status::Mapped("s")),
+    (0x1D461, 0x1D461, Status::Mapped("t")),
+    (0x1D462, 0x1D462, Status::Mapped("u")),
+    (0x1D463, 0x1D463, Status::Mapped("v")),
+    (0x1D464, 0x1D464, Status::Mapped("w")),
+    (0x1D465, 0x1D465, Status::Mapped("x")),
+    (0x1D466, 0x1D466, Status::Mapped("y")),
+    (0x1D467, 0x1D467, Status::Mapped("z")),
+    (0x1D468, 0x1D468, Status::Mapped("a")),
+    (0x1D469, 0x1D469, Status::Mapped("b")),
+    (0x1D46A, 0x1D46A, Status::Mapped("c")),
+    (0x1D46B, 0x1D46B, Status::Mapped("d")),
+    (0x1D46C, 0x1D46C, Status::Mapped("e")),
+    (0x1D46D, 0x1D46D, Status::Mapped("f")),
+    (0x1D46E, 0x1D46E, Status::Mapped("g")),
+    (0x1D46F, 0x1D46F, Status::Mapped("h")),
+    (0x1D470, 0x1D470, Status::Mapped("i")),
+    (0x1D471, 0x1D471, Status::Mapped("j")),
+    (0x1D472, 0x1D472, Status::Mapped("k")),
+    (0x1D473, 0x1D473, Status::Mapped("l")),
+    (0x1D474, 0x1D474, Status::Mapped("m")),
+    (0x1D475, 0x1D475, Status::Mapped("n")),
+    (0x1D476, 0x1D476, Status::Mapped("o")),
+    (0x1D477, 0x1D477, Status::Mapped("p")),
+    (0x1D478, 0x1D478, Status::Mapped("q")),
+    (0x1D479, 0x1D479, Status::Mapped("r")),
+    (0x1D47A, 0x1D47A, Status::Mapped("s")),
+    (0x1D47B, 0x1D47B, Status::Mapped("t")),
+    (0x1D47C, 0x1D47C, Status::Mapped("u")),
+    (0x1D47D, 0x1D47D, Status::Mapped("v")),
+    (0x1D47E, 0x1D47E, Status::Mapped("w")),
+    (0x1D47F, 0x1D47F, Status::Mapped("x")),
+    (0x1D480, 0x1D480, Status::Mapped("y")),
+    (0x1D481, 0x1D481, Status::Mapped("z")),
+    (0x1D482, 0x1D482, Status::Mapped("a")),
+    (0x1D483, 0x1D483, Status::Mapped("b")),
+    (0x1D484, 0x1D484, Status::Mapped("c")),
+    (0x1D485, 0x1D485, Status::Mapped("d")),
+    (0x1D486, 0x1D486, Status::Mapped("e")),
+    (0x1D487, 0x1D487, Status::Mapped("f")),
+    (0x1D488, 0x1D488, Status::Mapped("g")),
+    (0x1D489, 0x1D489, Status::Mapped("h")),
+    (0x1D48A, 0x1D48A, Status::Mapped("i")),
+    (0x1D48B, 0x1D48B, Status::Mapped("j")),
+    (0x1D48C, 0x1D48C, Status::Mapped("k")),
+    (0x1D48D, 0x1D48D, Status::Mapped("l")),
+    (0x1D48E, 0x1D48E, Status::Mapped("m")),
+    (0x1D48F, 0x1D48F, Status::Mapped("n")),
+    (0x1D490, 0x1D490, Status::Mapped("o")),
+    (0x1D491, 0x1D491, Status::Mapped("p")),
+    (0x1D492, 0x1D492, Status::Mapped("q")),
+    (0x1D493, 0x1D493, Status::Mapped("r")),
+    (0x1D494, 0x1D494, Status::Mapped("s")),
+    (0x1D495, 0x1D495, Status::Mapped("t")),
+    (0x1D496, 0x1D496, Status::Mapped("u")),
+    (0x1D497, 0x1D497, Status::Mapped("v")),
+    (0x1D498, 0x1D498, Status::Mapped("w")),
+    (0x1D499, 0x1D499, Status::Mapped("x")),
+    (0x1D49A, 0x1D49A, Status::Mapped("y")),
+    (0x1D49B, 0x1D49B, Status::Mapped("z")),
+    (0x1D49C, 0x1D49C, Status::Mapped("a")),
+    (0x1D49D, 0x1D49D, Status::Disallowed),
+    (0x1D49E, 0x1D49E, Status::Mapped("c")),
+    (0x1D49F, 0x1D49F, Status::Mapped("d")),
+    (0x1D4A0, 0x1D4A1, Status::Disallowed),
+    (0x1D4A2, 0x1D4A2, Status::Mapped("g")),
+    (0x1D4A3, 0x1D4A4, Status::Disallowed),
+    (0x1D4A5, 0x1D4A5, Status::Mapped("j")),
+    (0x1D4A6, 0x1D4A6, Status::Mapped("k")),
+    (0x1D4A7, 0x1D4A8, Status::Disallowed),
+    (0x1D4A9, 0x1D4A9, Status::Mapped("n")),
+    (0x1D4AA, 0x1D4AA, Status::Mapped("o")),
+    (0x1D4AB, 0x1D4AB, Status::Mapped("p")),
+    (0x1D4AC, 0x1D4AC, Status::Mapped("q")),
+    (0x1D4AD, 0x1D4AD, Status::Disallowed),
+    (0x1D4AE, 0x1D4AE, Status::Mapped("s")),
+    (0x1D4AF, 0x1D4AF, Status::Mapped("t")),
+    (0x1D4B0, 0x1D4B0, Status::Mapped("u")),
+    (0x1D4B1, 0x1D4B1, Status::Mapped("v")),
+    (0x1D4B2, 0x1D4B2, Status::Mapped("w")),
+    (0x1D4B3, 0x1D4B3, Status::Mapped("x")),
+    (0x1D4B4, 0x1D4B4, Status::Mapped("y")),
+    (0x1D4B5, 0x1D4B5, Status::Mapped("z")),
+    (0x1D4B6, 0x1D4B6, Status::Mapped("a")),
+    (0x1D4B7, 0x1D4B7, Status::Mapped("b")),
+    (0x1D4B8, 0x1D4B8, Status::Mapped("c")),
+    (0x1D4B9, 0x1D4B9, Status::Mapped("d")),
+    (0x1D4BA, 0x1D4BA, Status::Disallowed),
+    (0x1D4BB, 0x1D4BB, Status::Mapped("f")),
+    (0x1D4BC, 0x1D4BC, Status::Disallowed),
+    (0x1D4BD, 0x1D4BD, Status::Mapped("h")),
+    (0x1D4BE, 0x1D4BE, Status::Mapped("i")),
+    (0x1D4BF, 0x1D4BF, Status::Mapped("j")),
+    (0x1D4C0, 0x1D4C0, Status::Mapped("k")),
+    (0x1D4C1, 0x1D4C1, Status::Mapped("l")),
+    (0x1D4C2, 0x1D4C2, Status::Mapped("m")),
+    (0x1D4C3, 0x1D4C3, Status::Mapped("n")),
+    (0x1D4C4, 0x1D4C4, Status::Disallowed),
+    (0x1D4C5, 0x1D4C5, Status::Mapped("p")),
+    (0x1D4C6, 0x1D4C6, Status::Mapped("q")),
+    (0x1D4C7, 0x1D4C7, Status::Mapped("r")),
+    (0x1D4C8, 0x1D4C8, Status::Mapped("s")),
+    (0x1D4C9, 0x1D4C9, Status::Mapped("t")),
+    (0x1D4CA, 0x1D4CA, Status::Mapped("u")),
+    (0x1D4CB, 0x1D4CB, Status::Mapped("v")),
+    (0x1D4CC, 0x1D4CC, Status::Mapped("w")),
+    (0x1D4CD, 0x1D4CD, Status::Mapped("x")),
+    (0x1D4CE, 0x1D4CE, Status::Mapped("y")),
+    (0x1D4CF, 0x1D4CF, Status::Mapped("z")),
+    (0x1D4D0, 0x1D4D0, Status::Mapped("a")),
+    (0x1D4D1, 0x1D4D1, Status::Mapped("b")),
+    (0x1D4D2, 0x1D4D2, Status::Mapped("c")),
+    (0x1D4D3, 0x1D4D3, Status::Mapped("d")),
+    (0x1D4D4, 0x1D4D4, Status::Mapped("e")),
+    (0x1D4D5, 0x1D4D5, Status::Mapped("f")),
+    (0x1D4D6, 0x1D4D6, Status::Mapped("g")),
+    (0x1D4D7, 0x1D4D7, Status::Mapped("h")),
+    (0x1D4D8, 0x1D4D8, Status::Mapped("i")),
+    (0x1D4D9, 0x1D4D9, Status::Mapped("j")),
+    (0x1D4DA, 0x1D4DA, Status::Mapped("k")),
+    (0x1D4DB, 0x1D4DB, Status::Mapped("l")),
+    (0x1D4DC, 0x1D4DC, Status::Mapped("m")),
+    (0x1D4DD, 0x1D4DD, Status::Mapped("n")),
+    (0x1D4DE, 0x1D4DE, Status::Mapped("o")),
+    (0x1D4DF, 0x1D4DF, Status::Mapped("p")),
+    (0x1D4E0, 0x1D4E0, Status::Mapped("q")),
+    (0x1D4E1, 0x1D4E1, Status::Mapped("r")),
+    (0x1D4E2, 0x1D4E2, Status::Mapped("s")),
+    (0x1D4E3, 0x1D4E3, Status::Mapped("t")),
+    (0x1D4E4, 0x1D4E4, Status::Mapped("u")),
+    (0x1D4E5, 0x1D4E5, Status::Mapped("v")),
+    (0x1D4E6, 0x1D4E6, Status::Mapped("w")),
+    (0x1D4E7, 0x1D4E7, Status::Mapped("x")),
+    (0x1D4E8, 0x1D4E8, Status::Mapped("y")),
+    (0x1D4E9, 0x1D4E9, Status::Mapped("z")),
+    (0x1D4EA, 0x1D4EA, Status::Mapped("a")),
+    (0x1D4EB, 0x1D4EB, Status::Mapped("b")),
+    (0x1D4EC, 0x1D4EC, Status::Mapped("c")),
+    (0x1D4ED, 0x1D4ED, Status::Mapped("d")),
+    (0x1D4EE, 0x1D4EE, Status::Mapped("e")),
+    (0x1D4EF, 0x1D4EF, Status::Mapped("f")),
+    (0x1D4F0, 0x1D4F0, Status::Mapped("g")),
+    (0x1D4F1, 0x1D4F1, Status::Mapped("h")),
+    (0x1D4F2, 0x1D4F2, Status::Mapped("i")),
+    (0x1D4F3, 0x1D4F3, Status::Mapped("j")),
+    (0x1D4F4, 0x1D4F4, Status::Mapped("k")),
+    (0x1D4F5, 0x1D4F5, Status::Mapped("l")),
+    (0x1D4F6, 0x1D4F6, Status::Mapped("m")),
+    (0x1D4F7, 0x1D4F7, Status::Mapped("n")),
+    (0x1D4F8, 0x1D4F8, Status::Mapped("o")),
+    (0x1D4F9, 0x1D4F9, Status::Mapped("p")),
+    (0x1D4FA, 0x1D4FA, Status::Mapped("q")),
+    (0x1D4FB, 0x1D4FB, Status::Mapped("r")),
+    (0x1D4FC, 0x1D4FC, Status::Mapped("s")),
+    (0x1D4FD, 0x1D4FD, Status::Mapped("t")),
+    (0x1D4FE, 0x1D4FE, Status::Mapped("u")),
+    (0x1D4FF, 0x1D4FF, Status::Mapped("v")),
+    (0x1D500, 0x1D500, Status::Mapped("w")),
+    (0x1D501, 0x1D501, Status::Mapped("x")),
+    (0x1D502, 0x1D502, Status::Mapped("y")),
+    (0x1D503, 0x1D503, Status::Mapped("z")),
+    (0x1D504, 0x1D504, Status::Mapped("a")),
+    (0x1D505, 0x1D505, Status::Mapped("b")),
+    (0x1D506, 0x1D506, Status::Disallowed),
+    (0x1D507, 0x1D507, Status::Mapped("d")),
+    (0x1D508, 0x1D508, Status::Mapped("e")),
+    (0x1D509, 0x1D509, Status::Mapped("f")),
+    (0x1D50A, 0x1D50A, Status::Mapped("g")),
+    (0x1D50B, 0x1D50C, Status::Disallowed),
+    (0x1D50D, 0x1D50D, Status::Mapped("j")),
+    (0x1D50E, 0x1D50E, Status::Mapped("k")),
+    (0x1D50F, 0x1D50F, Status::Mapped("l")),
+    (0x1D510, 0x1D510, Status::Mapped("m")),
+    (0x1D511, 0x1D511, Status::Mapped("n")),
+    (0x1D512, 0x1D512, Status::Mapped("o")),
+    (0x1D513, 0x1D513, Status::Mapped("p")),
+    (0x1D514, 0x1D514, Status::Mapped("q")),
+    (0x1D515, 0x1D515, Status::Disallowed),
+    (0x1D516, 0x1D516, Status::Mapped("s")),
+    (0x1D517, 0x1D517, Status::Mapped("t")),
+    (0x1D518, 0x1D518, Status::Mapped("u")),
+    (0x1D519, 0x1D519, Status::Mapped("v")),
+    (0x1D51A, 0x1D51A, Status::Mapped("w")),
+    (0x1D51B, 0x1D51B, Status::Mapped("x")),
+    (0x1D51C, 0x1D51C, Status::Mapped("y")),
+    (0x1D51D, 0x1D51D, Status::Disallowed),
+    (0x1D51E, 0x1D51E, Status::Mapped("a")),
+    (0x1D51F, 0x1D51F, Status::Mapped("b")),
+    (0x1D520, 0x1D520, Status::Mapped("c")),
+    (0x1D521, 0x1D521, Status::Mapped("d")),
+    (0x1D522, 0x1D522, Status::Mapped("e")),
+    (0x1D523, 0x1D523, Status::Mapped("f")),
+    (0x1D524, 0x1D524, Status::Mapped("g")),
+    (0x1D525, 0x1D525, Status::Mapped("h")),
+    (0x1D526, 0x1D526, Status::Mapped("i")),
+    (0x1D527, 0x1D527, Status::Mapped("j")),
+    (0x1D528, 0x1D528, Status::Mapped("k")),
+    (0x1D529, 0x1D529, Status::Mapped("l")),
+    (0x1D52A, 0x1D52A, Status::Mapped("m")),
+    (0x1D52B, 0x1D52B, Status::Mapped("n")),
+    (0x1D52C, 0x1D52C, Status::Mapped("o")),
+    (0x1D52D, 0x1D52D, Status::Mapped("p")),
+    (0x1D52E, 0x1D52E, Status::Mapped("q")),
+    (0x1D52F, 0x1D52F, Status::Mapped("r")),
+    (0x1D530, 0x1D530, Status::Mapped("s")),
+    (0x1D531, 0x1D531, Status::Mapped("t")),
+    (0x1D532, 0x1D532, Status::Mapped("u")),
+    (0x1D533, 0x1D533, Status::Mapped("v")),
+    (0x1D534, 0x1D534, Status::Mapped("w")),
+    (0x1D535, 0x1D535, Status::Mapped("x")),
+    (0x1D536, 0x1D536, Status::Mapped("y")),
+    (0x1D537, 0x1D537, Status::Mapped("z")),
+    (0x1D538, 0x1D538, Status::Mapped("a")),
+    (0x1D539, 0x1D539, Status::Mapped("b")),
+    (0x1D53A, 0x1D53A, Status::Disallowed),
+    (0x1D53B, 0x1D53B, Status::Mapped("d")),
+    (0x1D53C, 0x1D53C, Status::Mapped("e")),
+    (0x1D53D, 0x1D53D, Status::Mapped("f")),
+    (0x1D53E, 0x1D53E, Status::Mapped("g")),
+    (0x1D53F, 0x1D53F, Status::Disallowed),
+    (0x1D540, 0x1D540, Status::Mapped("i")),
+    (0x1D541, 0x1D541, Status::Mapped("j")),
+    (0x1D542, 0x1D542, Status::Mapped("k")),
+    (0x1D543, 0x1D543, Status::Mapped("l")),
+    (0x1D544, 0x1D544, Status::Mapped("m")),
+    (0x1D545, 0x1D545, Status::Disallowed),
+    (0x1D546, 0x1D546, Status::Mapped("o")),
+    (0x1D547, 0x1D549, Status::Disallowed),
+    (0x1D54A, 0x1D54A, Status::Mapped("s")),
+    (0x1D54B, 0x1D54B, Status::Mapped("t")),
+    (0x1D54C, 0x1D54C, Status::Mapped("u")),
+    (0x1D54D, 0x1D54D, Status::Mapped("v")),
+    (0x1D54E, 0x1D54E, Status::Mapped("w")),
+    (0x1D54F, 0x1D54F, Status::Mapped("x")),
+    (0x1D550, 0x1D550, Status::Mapped("y")),
+    (0x1D551, 0x1D551, Status::Disallowed),
+    (0x1D552, 0x1D552, Status::Mapped("a")),
+    (0x1D553, 0x1D553, Status::Mapped("b")),
+    (0x1D554, 0x1D554, Status::Mapped("c")),
+    (0x1D555, 0x1D555, Status::Mapped("d")),
+    (0x1D556, 0x1D556, Status::Mapped("e")),
+    (0x1D557, 0x1D557, Status::Mapped("f")),
+    (0x1D558, 0x1D558, Status::Mapped("g")),
+    (0x1D559, 0x1D559, Status::Mapped("h")),
+    (0x1D55A, 0x1D55A, Status::Mapped("i")),
+    (0x1D55B, 0x1D55B, Status::Mapped("j")),
+    (0x1D55C, 0x1D55C, Status::Mapped("k")),
+    (0x1D55D, 0x1D55D, Status::Mapped("l")),
+    (0x1D55E, 0x1D55E, Status::Mapped("m")),
+    (0x1D55F, 0x1D55F, Status::Mapped("n")),
+    (0x1D560, 0x1D560, Status::Mapped("o")),
+    (0x1D561, 0x1D561, Status::Mapped("p")),
+    (0x1D562, 0x1D562, Status::Mapped("q")),
+    (0x1D563, 0x1D563, Status::Mapped("r")),
+    (0x1D564, 0x1D564, Status::Mapped("s")),
+    (0x1D565, 0x1D565, Status::Mapped("t")),
+    (0x1D566, 0x1D566, Status::Mapped("u")),
+    (0x1D567, 0x1D567, Status::Mapped("v")),
+    (0x1D568, 0x1D568, Status::Mapped("w")),
+    (0x1D569, 0x1D569, Status::Mapped("x")),
+    (0x1D56A, 0x1D56A, Status::Mapped("y")),
+    (0x1D56B, 0x1D56B, Status::Mapped("z")),
+    (0x1D56C, 0x1D56C, Status::Mapped("a")),
+    (0x1D56D, 0x1D56D, Status::Mapped("b")),
+    (0x1D56E, 0x1D56E, Status::Mapped("c")),
+    (0x1D56F, 0x1D56F, Status::Mapped("d")),
+    (0x1D570, 0x1D570, Status::Mapped("e")),
+    (0x1D571, 0x1D571, Status::Mapped("f")),
+    (0x1D572, 0x1D572, Status::Mapped("g")),
+    (0x1D573, 0x1D573, Status::Mapped("h")),
+    (0x1D574, 0x1D574, Status::Mapped("i")),
+    (0x1D575, 0x1D575, Status::Mapped("j")),
+    (0x1D576, 0x1D576, Status::Mapped("k")),
+    (0x1D577, 0x1D577, Status::Mapped("l")),
+    (0x1D578, 0x1D578, Status::Mapped("m")),
+    (0x1D579, 0x1D579, Status::Mapped("n")),
+    (0x1D57A, 0x1D57A, Status::Mapped("o")),
+    (0x1D57B, 0x1D57B, Status::Mapped("p")),
+    (0x1D57C, 0x1D57C, Status::Mapped("q")),
+    (0x1D57D, 0x1D57D, Status::Mapped("r")),
+    (0x1D57E, 0x1D57E, Status::Mapped("s")),
+    (0x1D57F, 0x1D57F, Status::Mapped("t")),
+    (0x1D580, 0x1D580, Status::Mapped("u")),
+    (0x1D581, 0x1D581, Status::Mapped("v")),
+    (0x1D582, 0x1D582, Status::Mapped("w")),
+    (0x1D583, 0x1D583, Status::Mapped("x")),
+    (0x1D584, 0x1D584, Status::Mapped("y")),
+    (0x1D585, 0x1D585, Status::Mapped("z")),
+    (0x1D586, 0x1D586, Status::Mapped("a")),
+    (0x1D587, 0x1D587, Status::Mapped("b")),
+    (0x1D588, 0x1D588, Status::Mapped("c")),
+    (0x1D589, 0x1D589, Status::Mapped("d")),
+    (0x1D58A, 0x1D58A, Status::Mapped("e")),
+    (0x1D58B, 0x1D58B, Status::Mapped("f")),
+    (0x1D58C, 0x1D58C, Status::Mapped("g")),
+    (0x1D58D, 0x1D58D, Status::Mapped("h")),
+    (0x1D58E, 0x1D58E, Status::Mapped("i")),
+    (0x1D58F, 0x1D58F, Status::Mapped("j")),
+    (0x1D590, 0x1D590, Status::Mapped("k")),
+    (0x1D591, 0x1D591, Status::Mapped("l")),
+    (0x1D592, 0x1D592, Status::Mapped("m")),
+    (0x1D593, 0x1D593, Status::Mapped("n")),
+    (0x1D594, 0x1D594, Status::Mapped("o")),
+    (0x1D595, 0x1D595, Status::Mapped("p")),
+    (0x1D596, 0x1D596, Status::Mapped("q")),
+    (0x1D597, 0x1D597, Status::Mapped("r")),
+    (0x1D598, 0x1D598, Status::Mapped("s")),
+    (0x1D599, 0x1D599, Status::Mapped("t")),
+    (0x1D59A, 0x1D59A, Status::Mapped("u")),
+    (0x1D59B, 0x1D59B, Status::Mapped("v")),
+    (0x1D59C, 0x1D59C, Status::Mapped("w")),
+    (0x1D59D, 0x1D59D, Status::Mapped("x")),
+    (0x1D59E, 0x1D59E, Status::Mapped("y")),
+    (0x1D59F, 0x1D59F, Status::Mapped("z")),
+    (0x1D5A0, 0x1D5A0, Status::Mapped("a")),
+    (0x1D5A1, 0x1D5A1, Status::Mapped("b")),
+    (0x1D5A2, 0x1D5A2, Status::Mapped("c")),
+    (0x1D5A3, 0x1D5A3, Status::Mapped("d")),
+    (0x1D5A4, 0x1D5A4, Status::Mapped("e")),
+    (0x1D5A5, 0x1D5A5, Status::Mapped("f")),
+    (0x1D5A6, 0x1D5A6, Status::Mapped("g")),
+    (0x1D5A7, 0x1D5A7, Status::Mapped("h")),
+    (0x1D5A8, 0x1D5A8, Status::Mapped("i")),
+    (0x1D5A9, 0x1D5A9, Status::Mapped("j")),
+    (0x1D5AA, 0x1D5AA, Status::Mapped("k")),
+    (0x1D5AB, 0x1D5AB, Status::Mapped("l")),
+    (0x1D5AC, 0x1D5AC, Status::Mapped("m")),
+    (0x1D5AD, 0x1D5AD, Status::Mapped("n")),
+    (0x1D5AE, 0x1D5AE, Status::Mapped("o")),
+    (0x1D5AF, 0x1D5AF, Status::Mapped("p")),
+    (0x1D5B0, 0x1D5B0, Status::Mapped("q")),
+    (0x1D5B1, 0x1D5B1, Status::Mapped("r")),
+    (0x1D5B2, 0x1D5B2, Status::Mapped("s")),
+    (0x1D5B3, 0x1D5B3, Status::Mapped("t")),
+    (0x1D5B4, 0x1D5B4, Status::Mapped("u")),
+    (0x1D5B5, 0x1D5B5, Status::Mapped("v")),
+    (0x1D5B6, 0x1D5B6, Status::Mapped("w")),
+    (0x1D5B7, 0x1D5B7, Status::Mapped("x")),
+    (0x1D5B8, 0x1D5B8, Status::Mapped("y")),
+    (0x1D5B9, 0x1D5B9, Status::Mapped("z")),
+    (0x1D5BA, 0x1D5BA, Status::Mapped("a")),
+    (0x1D5BB, 0x1D5BB, Status::Mapped("b")),
+    (0x1D5BC, 0x1D5BC, Status::Mapped("c")),
+    (0x1D5BD, 0x1D5BD, Status::Mapped("d")),
+    (0x1D5BE, 0x1D5BE, Status::Mapped("e")),
+    (0x1D5BF, 0x1D5BF, Status::Mapped("f")),
+    (0x1D5C0, 0x1D5C0, Status::Mapped("g")),
+    (0x1D5C1, 0x1D5C1, Status::Mapped("h")),
+    (0x1D5C2, 0x1D5C2, Status::Mapped("i")),
+    (0x1D5C3, 0x1D5C3, Status::Mapped("j")),
+    (0x1D5C4, 0x1D5C4, Status::Mapped("k")),
+    (0x1D5C5, 0x1D5C5, Status::Mapped("l")),
+    (0x1D5C6, 0x1D5C6, Status::Mapped("m")),
+    (0x1D5C7, 0x1D5C7, Status::Mapped("n")),
+    (0x1D5C8, 0x1D5C8, Status::Mapped("o")),
+    (0x1D5C9, 0x1D5C9, Status::Mapped("p")),
+    (0x1D5CA, 0x1D5CA, Status::Mapped("q")),
+    (0x1D5CB, 0x1D5CB, Status::Mapped("r")),
+    (0x1D5CC, 0x1D5CC, Status::Mapped("s")),
+    (0x1D5CD, 0x1D5CD, Status::Mapped("t")),
+    (0x1D5CE, 0x1D5CE, Status::Mapped("u")),
+    (0x1D5CF, 0x1D5CF, Status::Mapped("v")),
+    (0x1D5D0, 0x1D5D0, Status::Mapped("w")),
+    (0x1D5D1, 0x1D5D1, Status::Mapped("x")),
+    (0x1D5D2, 0x1D5D2, Status::Mapped("y")),
+    (0x1D5D3, 0x1D5D3, Status::Mapped("z")),
+    (0x1D5D4, 0x1D5D4, Status::Mapped("a")),
+    (0x1D5D5, 0x1D5D5, Status::Mapped("b")),
+    (0x1D5D6, 0x1D5D6, Status::Mapped("c")),
+    (0x1D5D7, 0x1D5D7, Status::Mapped("d")),
+    (0x1D5D8, 0x1D5D8, Status::Mapped("e")),
+    (0x1D5D9, 0x1D5D9, Status::Mapped("f")),
+    (0x1D5DA, 0x1D5DA, Status::Mapped("g")),
+    (0x1D5DB, 0x1D5DB, Status::Mapped("h")),
+    (0x1D5DC, 0x1D5DC, Status::Mapped("i")),
+    (0x1D5DD, 0x1D5DD, Status::Mapped("j")),
+    (0x1D5DE, 0x1D5DE, Status::Mapped("k")),
+    (0x1D5DF, 0x1D5DF, Status::Mapped("l")),
+    (0x1D5E0, 0x1D5E0, Status::Mapped("m")),
+    (0x1D5E1, 0x1D5E1, Status::Mapped("n")),
+    (0x1D5E2, 0x1D5E2, Status::Mapped("o")),
+    (0x1D5E3, 0x1D5E3, Status::Mapped("p")),
+    (0x1D5E4, 0x1D5E4, Status::Mapped("q")),
+    (0x1D5E5, 0x1D5E5, Status::Mapped("r")),
+    (0x1D5E6, 0x1D5E6, Status::Mapped("s")),
+    (0x1D5E7, 0x1D5E7, Status::Mapped("t")),
+    (0x1D5E8, 0x1D5E8, Status::Mapped("u")),
+    (0x1D5E9, 0x1D5E9, Status::Mapped("v")),
+    (0x1D5EA, 0x1D5EA, Status::Mapped("w")),
+    (0x1D5EB, 0x1D5EB, Status::Mapped("x")),
+    (0x1D5EC, 0x1D5EC, Status::Mapped("y")),
+    (0x1D5ED, 0x1D5ED, Status::Mapped("z")),
+    (0x1D5EE, 0x1D5EE, Status::Mapped("a")),
+    (0x1D5EF, 0x1D5EF, Status::Mapped("b")),
+    (0x1D5F0, 0x1D5F0, Status::Mapped("c")),
+    (0x1D5F1, 0x1D5F1, Status::Mapped("d")),
+    (0x1D5F2, 0x1D5F2, Status::Mapped("e")),
+    (0x1D5F3, 0x1D5F3, Status::Mapped("f")),
+    (0x1D5F4, 0x1D5F4, Status::Mapped("g")),
+    (0x1D5F5, 0x1D5F5, Status::Mapped("h")),
+    (0x1D5F6, 0x1D5F6, Status::Mapped("i")),
+    (0x1D5F7, 0x1D5F7, Status::Mapped("j")),
+    (0x1D5F8, 0x1D5F8, Status::Mapped("k")),
+    (0x1D5F9, 0x1D5F9, Status::Mapped("l")),
+    (0x1D5FA, 0x1D5FA, Status::Mapped("m")),
+    (0x1D5FB, 0x1D5FB, Status::Mapped("n")),
+    (0x1D5FC, 0x1D5FC, Status::Mapped("o")),
+    (0x1D5FD, 0x1D5FD, Status::Mapped("p")),
+    (0x1D5FE, 0x1D5FE, Status::Mapped("q")),
+    (0x1D5FF, 0x1D5FF, Status::Mapped("r")),
+    (0x1D600, 0x1D600, Status::Mapped("s")),
+    (0x1D601, 0x1D601, Status::Mapped("t")),
+    (0x1D602, 0x1D602, Status::Mapped("u")),
+    (0x1D603, 0x1D603, Status::Mapped("v")),
+    (0x1D604, 0x1D604, Status::Mapped("w")),
+    (0x1D605, 0x1D605, Status::Mapped("x")),
+    (0x1D606, 0x1D606, Status::Mapped("y")),
+    (0x1D607, 0x1D607, Status::Mapped("z")),
+    (0x1D608, 0x1D608, Status::Mapped("a")),
+    (0x1D609, 0x1D609, Status::Mapped("b")),
+    (0x1D60A, 0x1D60A, Status::Mapped("c")),
+    (0x1D60B, 0x1D60B, Status::Mapped("d")),
+    (0x1D60C, 0x1D60C, Status::Mapped("e")),
+    (0x1D60D, 0x1D60D, Status::Mapped("f")),
+    (0x1D60E, 0x1D60E, Status::Mapped("g")),
+    (0x1D60F, 0x1D60F, Status::Mapped("h")),
+    (0x1D610, 0x1D610, Status::Mapped("i")),
+    (0x1D611, 0x1D611, Status::Mapped("j")),
+    (0x1D612, 0x1D612, Status::Mapped("k")),
+    (0x1D613, 0x1D613, Status::Mapped("l")),
+    (0x1D614, 0x1D614, Status::Mapped("m")),
+    (0x1D615, 0x1D615, Status::Mapped("n")),
+    (0x1D616, 0x1D616, Status::Mapped("o")),
+    (0x1D617, 0x1D617, Status::Mapped("p")),
+    (0x1D618, 0x1D618, Status::Mapped("q")),
+    (0x1D619, 0x1D619, Status::Mapped("r")),
+    (0x1D61A, 0x1D61A, Status::Mapped("s")),
+    (0x1D61B, 0x1D61B, Status::Mapped("t")),
+    (0x1D61C, 0x1D61C, Status::Mapped("u")),
+    (0x1D61D, 0x1D61D, Status::Mapped("v")),
+    (0x1D61E, 0x1D61E, Status::Mapped("w")),
+    (0x1D61F, 0x1D61F, Status::Mapped("x")),
+    (0x1D620, 0x1D620, Status::Mapped("y")),
+    (0x1D621, 0x1D621, Status::Mapped("z")),
+    (0x1D622, 0x1D622, Status::Mapped("a")),
+    (0x1D623, 0x1D623, Status::Mapped("b")),
+    (0x1D624, 0x1D624, Status::Mapped("c")),
+    (0x1D625, 0x1D625, Status::Mapped("d")),
+    (0x1D626, 0x1D626, Status::Mapped("e")),
+    (0x1D627, 0x1D627, Status::Mapped("f")),
+    (0x1D628, 0x1D628, Status::Mapped("g")),
+    (0x1D629, 0x1D629, Status::Mapped("h")),
+    (0x1D62A, 0x1D62A, Status::Mapped("i")),
+    (0x1D62B, 0x1D62B, Status::Mapped("j")),
+    (0x1D62C, 0x1D62C, Status::Mapped("k")),
+    (0x1D62D, 0x1D62D, Status::Mapped("l")),
+    (0x1D62E, 0x1D62E, Status::Mapped("m")),
+    (0x1D62F, 0x1D62F, Status::Mapped("n")),
+    (0x1D630, 0x1D630, Status::Mapped("o")),
+    (0x1D631, 0x1D631, Status::Mapped("p")),
+    (0x1D632, 0x1D632, Status::Mapped("q")),
+    (0x1D633, 0x1D633, Status::Mapped("r")),
+    (0x1D634, 0x1D634, Status::Mapped("s")),
+    (0x1D635, 0x1D635, Status::Mapped("t")),
+    (0x1D636, 0x1D636, Status::Mapped("u")),
+    (0x1D637, 0x1D637, Status::Mapped("v")),
+    (0x1D638, 0x1D638, Status::Mapped("w")),
+    (0x1D639, 0x1D639, Status::Mapped("x")),
+    (0x1D63A, 0x1D63A, Status::Mapped("y")),
+    (0x1D63B, 0x1D63B, Status::Mapped("z")),
+    (0x1D63C, 0x1D63C, Status::Mapped("a")),
+    (0x1D63D, 0x1D63D, Status::Mapped("b")),
+    (0x1D63E, 0x1D63E, Status::Mapped("c")),
+    (0x1D63F, 0x1D63F, Status::Mapped("d")),
+    (0x1D640, 0x1D640, Status::Mapped("e")),
+    (0x1D641, 0x1D641, Status::Mapped("f")),
+    (0x1D642, 0x1D642, Status::Mapped("g")),
+    (0x1D643, 0x1D643, Status::Mapped("h")),
+    (0x1D644, 0x1D644, Status::Mapped("i")),
+    (0x1D645, 0x1D645, Status::Mapped("j")),
+    (0x1D646, 0x1D646, Status::Mapped("k")),
+    (0x1D647, 0x1D647, Status::Mapped("l")),
+    (0x1D648, 0x1D648, Status::Mapped("m")),
+    (0x1D649, 0x1D649, Status::Mapped("n")),
+    (0x1D64A, 0x1D64A, Status::Mapped("o")),
+    (0x1D64B, 0x1D64B, Status::Mapped("p")),
+    (0x1D64C, 0x1D64C, Status::Mapped("q")),
+    (0x1D64D, 0x1D64D, Status::Mapped("r")),
+    (0x1D64E, 0x1D64E, Status::Mapped("s")),
+    (0x1D64F, 0x1D64F, Status::Mapped("t")),
+    (0x1D650, 0x1D650, Status::Mapped("u")),
+    (0x1D651, 0x1D651, Status::Mapped("v")),
+    (0x1D652, 0x1D652, Status::Mapped("w")),
+    (0x1D653, 0x1D653, Status::Mapped("x")),
+    (0x1D654, 0x1D654, Status::Mapped("y")),
+    (0x1D655, 0x1D655, Status::Mapped("z")),
+    (0x1D656, 0x1D656, Status::Mapped("a")),
+    (0x1D657, 0x1D657, Status::Mapped("b")),
+    (0x1D658, 0x1D658, Status::Mapped("c")),
+    (0x1D659, 0x1D659, Status::Mapped("d")),
+    (0x1D65A, 0x1D65A, Status::Mapped("e")),
+    (0x1D65B, 0x1D65B, Status::Mapped("f")),
+    (0x1D65C, 0x1D65C, Status::Mapped("g")),
+    (0x1D65D, 0x1D65D, Status::Mapped("h")),
+    (0x1D65E, 0x1D65E, Status::Mapped("i")),
+    (0x1D65F, 0x1D65F, Status::Mapped("j")),
+    (0x1D660, 0x1D660, Status::Mapped("k")),
+    (0x1D661, 0x1D661, Status::Mapped("l")),
+    (0x1D662, 0x1D662, Status::Mapped("m")),
+    (0x1D663, 0x1D663, Status::Mapped("n")),
+    (0x1D664, 0x1D664, Status::Mapped("o")),
+    (0x1D665, 0x1D665, Status::Mapped("p")),
+    (0x1D666, 0x1D666, Status::Mapped("q")),
+    (0x1D667, 0x1D667, Status::Mapped("r")),
+    (0x1D668, 0x1D668, Status::Mapped("s")),
+    (0x1D669, 0x1D669, Status::Mapped("t")),
+    (0x1D66A, 0x1D66A, Status::Mapped("u")),
+    (0x1D66B, 0x1D66B, Status::Mapped("v")),
+    (0x1D66C, 0x1D66C, Status::Mapped("w")),
+    (0x1D66D, 0x1D66D, Status::Mapped("x")),
+    (0x1D66E, 0x1D66E, Status::Mapped("y")),
+    (0x1D66F, 0x1D66F, Status::Mapped("z")),
+    (0x1D670, 0x1D670, Status::Mapped("a")),
+    (0x1D671, 0x1D671, Status::Mapped("b")),
+    (0x1D672, 0x1D672, Status::Mapped("c")),
+    (0x1D673, 0x1D673, Status::Mapped("d")),
+    (0x1D674, 0x1D674, Status::Mapped("e")),
+    (0x1D675, 0x1D675, Status::Mapped("f")),
+    (0x1D676, 0x1D676, Status::Mapped("g")),
+    (0x1D677, 0x1D677, Status::Mapped("h")),
+    (0x1D678, 0x1D678, Status::Mapped("i")),
+    (0x1D679, 0x1D679, Status::Mapped("j")),
+    (0x1D67A, 0x1D67A, Status::Mapped("k")),
+    (0x1D67B, 0x1D67B, Status::Mapped("l")),
+    (0x1D67C, 0x1D67C, Status::Mapped("m")),
+    (0x1D67D, 0x1D67D, Status::Mapped("n")),
+    (0x1D67E, 0x1D67E, Status::Mapped("o")),
+    (0x1D67F, 0x1D67F, Status::Mapped("p")),
+    (0x1D680, 0x1D680, Status::Mapped("q")),
+    (0x1D681, 0x1D681, Status::Mapped("r")),
+    (0x1D682, 0x1D682, Status::Mapped("s")),
+    (0x1D683, 0x1D683, Status::Mapped("t")),
+    (0x1D684, 0x1D684, Status::Mapped("u")),
+    (0x1D685, 0x1D685, Status::Mapped("v")),
+    (0x1D686, 0x1D686, Status::Mapped("w")),
+    (0x1D687, 0x1D687, Status::Mapped("x")),
+    (0x1D688, 0x1D688, Status::Mapped("y")),
+    (0x1D689, 0x1D689, Status::Mapped("z")),
+    (0x1D68A, 0x1D68A, Status::Mapped("a")),
+    (0x1D68B, 0x1D68B, Status::Mapped("b")),
+    (0x1D68C, 0x1D68C, Status::Mapped("c")),
+    (0x1D68D, 0x1D68D, Status::Mapped("d")),
+    (0x1D68E, 0x1D68E, Status::Mapped("e")),
+    (0x1D68F, 0x1D68F, Status::Mapped("f")),
+    (0x1D690, 0x1D690, Status::Mapped("g")),
+    (0x1D691, 0x1D691, Status::Mapped("h")),
+    (0x1D692, 0x1D692, Status::Mapped("i")),
+    (0x1D693, 0x1D693, Status::Mapped("j")),
+    (0x1D694, 0x1D694, Status::Mapped("k")),
+    (0x1D695, 0x1D695, Status::Mapped("l")),
+    (0x1D696, 0x1D696, Status::Mapped("m")),
+    (0x1D697, 0x1D697, Status::Mapped("n")),
+    (0x1D698, 0x1D698, Status::Mapped("o")),
+    (0x1D699, 0x1D699, Status::Mapped("p")),
+    (0x1D69A, 0x1D69A, Status::Mapped("q")),
+    (0x1D69B, 0x1D69B, Status::Mapped("r")),
+    (0x1D69C, 0x1D69C, Status::Mapped("s")),
+    (0x1D69D, 0x1D69D, Status::Mapped("t")),
+    (0x1D69E, 0x1D69E, Status::Mapped("u")),
+    (0x1D69F, 0x1D69F, Status::Mapped("v")),
+    (0x1D6A0, 0x1D6A0, Status::Mapped("w")),
+    (0x1D6A1, 0x1D6A1, Status::Mapped("x")),
+    (0x1D6A2, 0x1D6A2, Status::Mapped("y")),
+    (0x1D6A3, 0x1D6A3, Status::Mapped("z")),
+    (0x1D6A4, 0x1D6A4, Status::Mapped("\u{131}")),
+    (0x1D6A5, 0x1D6A5, Status::Mapped("\u{237}")),
+    (0x1D6A6, 0x1D6A7, Status::Disallowed),
+    (0x1D6A8, 0x1D6A8, Status::Mapped("\u{3B1}")),
+    (0x1D6A9, 0x1D6A9, Status::Mapped("\u{3B2}")),
+    (0x1D6AA, 0x1D6AA, Status::Mapped("\u{3B3}")),
+    (0x1D6AB, 0x1D6AB, Status::Mapped("\u{3B4}")),
+    (0x1D6AC, 0x1D6AC, Status::Mapped("\u{3B5}")),
+    (0x1D6AD, 0x1D6AD, Status::Mapped("\u{3B6}")),
+    (0x1D6AE, 0x1D6AE, Status::Mapped("\u{3B7}")),
+    (0x1D6AF, 0x1D6AF, Status::Mapped("\u{3B8}")),
+    (0x1D6B0, 0x1D6B0, Status::Mapped("\u{3B9}")),
+    (0x1D6B1, 0x1D6B1, Status::Mapped("\u{3BA}")),
+    (0x1D6B2, 0x1D6B2, Status::Mapped("\u{3BB}")),
+    (0x1D6B3, 0x1D6B3, Status::Mapped("\u{3BC}")),
+    (0x1D6B4, 0x1D6B4, Status::Mapped("\u{3BD}")),
+    (0x1D6B5, 0x1D6B5, Status::Mapped("\u{3BE}")),
+    (0x1D6B6, 0x1D6B6, Status::Mapped("\u{3BF}")),
+    (0x1D6B7, 0x1D6B7, Status::Mapped("\u{3C0}")),
+    (0x1D6B8, 0x1D6B8, Status::Mapped("\u{3C1}")),
+    (0x1D6B9, 0x1D6B9, Status::Mapped("\u{3B8}")),
+    (0x1D6BA, 0x1D6BA, Status::Mapped("\u{3C3}")),
+    (0x1D6BB, 0x1D6BB, Status::Mapped("\u{3C4}")),
+    (0x1D6BC, 0x1D6BC, Status::Mapped("\u{3C5}")),
+    (0x1D6BD, 0x1D6BD, Status::Mapped("\u{3C6}")),
+    (0x1D6BE, 0x1D6BE, Status::Mapped("\u{3C7}")),
+    (0x1D6BF, 0x1D6BF, Status::Mapped("\u{3C8}")),
+    (0x1D6C0, 0x1D6C0, Status::Mapped("\u{3C9}")),
+    (0x1D6C1, 0x1D6C1, Status::Mapped("\u{2207}")),
+    (0x1D6C2, 0x1D6C2, Status::Mapped("\u{3B1}")),
+    (0x1D6C3, 0x1D6C3, Status::Mapped("\u{3B2}")),
+    (0x1D6C4, 0x1D6C4, Status::Mapped("\u{3B3}")),
+    (0x1D6C5, 0x1D6C5, Status::Mapped("\u{3B4}")),
+    (0x1D6C6, 0x1D6C6, Status::Mapped("\u{3B5}")),
+    (0x1D6C7, 0x1D6C7, Status::Mapped("\u{3B6}")),
+    (0x1D6C8, 0x1D6C8, Status::Mapped("\u{3B7}")),
+    (0x1D6C9, 0x1D6C9, Status::Mapped("\u{3B8}")),
+    (0x1D6CA, 0x1D6CA, Status::Mapped("\u{3B9}")),
+    (0x1D6CB, 0x1D6CB, Status::Mapped("\u{3BA}")),
+    (0x1D6CC, 0x1D6CC, Status::Mapped("\u{3BB}")),
+    (0x1D6CD, 0x1D6CD, Status::Mapped("\u{3BC}")),
+    (0x1D6CE, 0x1D6CE, Status::Mapped("\u{3BD}")),
+    (0x1D6CF, 0x1D6CF, Status::Mapped("\u{3BE}")),
+    (0x1D6D0, 0x1D6D0, Status::Mapped("\u{3BF}")),
+    (0x1D6D1, 0x1D6D1, Status::Mapped("\u{3C0}")),
+    (0x1D6D2, 0x1D6D2, Status::Mapped("\u{3C1}")),
+    (0x1D6D3, 0x1D6D4, Status::Mapped("\u{3C3}")),
+    (0x1D6D5, 0x1D6D5, Status::Mapped("\u{3C4}")),
+    (0x1D6D6, 0x1D6D6, Status::Mapped("\u{3C5}")),
+    (0x1D6D7, 0x1D6D7, Status::Mapped("\u{3C6}")),
+    (0x1D6D8, 0x1D6D8, Status::Mapped("\u{3C7}")),
+    (0x1D6D9, 0x1D6D9, Status::Mapped("\u{3C8}")),
+    (0x1D6DA, 0x1D6DA, Status::Mapped("\u{3C9}")),
+    (0x1D6DB, 0x1D6DB, Status::Mapped("\u{2202}")),
+    (0x1D6DC, 0x1D6DC, Status::Mapped("\u{3B5}")),
+    (0x1D6DD, 0x1D6DD, Status::Mapped("\u{3B8}")),
+    (0x1D6DE, 0x1D6DE, Status::Mapped("\u{3BA}")),
+    (0x1D6DF, 0x1D6DF, Status::Mapped("\u{3C6}")),
+    (0x1D6E0, 0x1D6E0, Status::Mapped("\u{3C1}")),
+    (0x1D6E1, 0x1D6E1, Status::Mapped("\u{3C0}")),
+    (0x1D6E2, 0x1D6E2, Status::Mapped("\u{3B1}")),
+    (0x1D6E3, 0x1D6E3, Status::Mapped("\u{3B2}")),
+    (0x1D6E4, 0x1D6E4, Status::Mapped("\u{3B3}")),
+    (0x1D6E5, 0x1D6E5, Status::Mapped("\u{3B4}")),
+    (0x1D6E6, 0x1D6E6, Status::Mapped("\u{3B5}")),
+    (0x1D6E7, 0x1D6E7, Status::Mapped("\u{3B6}")),
+    (0x1D6E8, 0x1D6E8, Status::Mapped("\u{3B7}")),
+    (0x1D6E9, 0x1D6E9, Status::Mapped("\u{3B8}")),
+    (0x1D6EA, 0x1D6EA, Status::Mapped("\u{3B9}")),
+    (0x1D6EB, 0x1D6EB, Status::Mapped("\u{3BA}")),
+    (0x1D6EC, 0x1D6EC, Status::Mapped("\u{3BB}")),
+    (0x1D6ED, 0x1D6ED, Status::Mapped("\u{3BC}")),
+    (0x1D6EE, 0x1D6EE, Status::Mapped("\u{3BD}")),
+    (0x1D6EF, 0x1D6EF, Status::Mapped("\u{3BE}")),
+    (0x1D6F0, 0x1D6F0, Status::Mapped("\u{3BF}")),
+    (0x1D6F1, 0x1D6F1, Status::Mapped("\u{3C0}")),
+    (0x1D6F2, 0x1D6F2, Status::Mapped("\u{3C1}")),
+    (0x1D6F3, 0x1D6F3, Status::Mapped("\u{3B8}")),
+    (0x1D6F4, 0x1D6F4, Status::Mapped("\u{3C3}")),
+    (0x1D6F5, 0x1D6F5, Status::Mapped("\u{3C4}")),
+    (0x1D6F6, 0x1D6F6, Status::Mapped("\u{3C5}")),
+    (0x1D6F7, 0x1D6F7, Status::Mapped("\u{3C6}")),
+    (0x1D6F8, 0x1D6F8, Status::Mapped("\u{3C7}")),
+    (0x1D6F9, 0x1D6F9, Status::Mapped("\u{3C8}")),
+    (0x1D6FA, 0x1D6FA, Status::Mapped("\u{3C9}")),
+    (0x1D6FB, 0x1D6FB, Status::Mapped("\u{2207}")),
+    (0x1D6FC, 0x1D6FC, Status::Mapped("\u{3B1}")),
+    (0x1D6FD, 0x1D6FD, Status::Mapped("\u{3B2}")),
+    (0x1D6FE, 0x1D6FE, Status::Mapped("\u{3B3}")),
+    (0x1D6FF, 0x1D6FF, Status::Mapped("\u{3B4}")),
+    (0x1D700, 0x1D700, Status::Mapped("\u{3B5}")),
+    (0x1D701, 0x1D701, Status::Mapped("\u{3B6}")),
+    (0x1D702, 0x1D702, Status::Mapped("\u{3B7}")),
+    (0x1D703, 0x1D703, Status::Mapped("\u{3B8}")),
+    (0x1D704, 0x1D704, Status::Mapped("\u{3B9}")),
+    (0x1D705, 0x1D705, Status::Mapped("\u{3BA}")),
+    (0x1D706, 0x1D706, Status::Mapped("\u{3BB}")),
+    (0x1D707, 0x1D707, Status::Mapped("\u{3BC}")),
+    (0x1D708, 0x1D708, Status::Mapped("\u{3BD}")),
+    (0x1D709, 0x1D709, Status::Mapped("\u{3BE}")),
+    (0x1D70A, 0x1D70A, Status::Mapped("\u{3BF}")),
+    (0x1D70B, 0x1D70B, Status::Mapped("\u{3C0}")),
+    (0x1D70C, 0x1D70C, Status::Mapped("\u{3C1}")),
+    (0x1D70D, 0x1D70E, Status::Mapped("\u{3C3}")),
+    (0x1D70F, 0x1D70F, Status::Mapped("\u{3C4}")),
+    (0x1D710, 0x1D710, Status::Mapped("\u{3C5}")),
+    (0x1D711, 0x1D711, Status::Mapped("\u{3C6}")),
+    (0x1D712, 0x1D712, Status::Mapped("\u{3C7}")),
+    (0x1D713, 0x1D713, Status::Mapped("\u{3C8}")),
+    (0x1D714, 0x1D714, Status::Mapped("\u{3C9}")),
+    (0x1D715, 0x1D715, Status::Mapped("\u{2202}")),
+    (0x1D716, 0x1D716, Status::Mapped("\u{3B5}")),
+    (0x1D717, 0x1D717, Status::Mapped("\u{3B8}")),
+    (0x1D718, 0x1D718, Status::Mapped("\u{3BA}")),
+    (0x1D719, 0x1D719, Status::Mapped("\u{3C6}")),
+    (0x1D71A, 0x1D71A, Status::Mapped("\u{3C1}")),
+    (0x1D71B, 0x1D71B, Status::Mapped("\u{3C0}")),
+    (0x1D71C, 0x1D71C, Status::Mapped("\u{3B1}")),
+    (0x1D71D, 0x1D71D, Status::Mapped("\u{3B2}")),
+    (0x1D71E, 0x1D71E, Status::Mapped("\u{3B3}")),
+    (0x1D71F, 0x1D71F, Status::Mapped("\u{3B4}")),
+    (0x1D720, 0x1D720, Status::Mapped("\u{3B5}")),
+    (0x1D721, 0x1D721, Status::Mapped("\u{3B6}")),
+    (0x1D722, 0x1D722, Status::Mapped("\u{3B7}")),
+    (0x1D723, 0x1D723, Status::Mapped("\u{3B8}")),
+    (0x1D724, 0x1D724, Status::Mapped("\u{3B9}")),
+    (0x1D725, 0x1D725, Status::Mapped("\u{3BA}")),
+    (0x1D726, 0x1D726, Status::Mapped("\u{3BB}")),
+    (0x1D727, 0x1D727, Status::Mapped("\u{3BC}")),
+    (0x1D728, 0x1D728, Status::Mapped("\u{3BD}")),
+    (0x1D729, 0x1D729, Status::Mapped("\u{3BE}")),
+    (0x1D72A, 0x1D72A, Status::Mapped("\u{3BF}")),
+    (0x1D72B, 0x1D72B, Status::Mapped("\u{3C0}")),
+    (0x1D72C, 0x1D72C, Status::Mapped("\u{3C1}")),
+    (0x1D72D, 0x1D72D, Status::Mapped("\u{3B8}")),
+    (0x1D72E, 0x1D72E, Status::Mapped("\u{3C3}")),
+    (0x1D72F, 0x1D72F, Status::Mapped("\u{3C4}")),
+    (0x1D730, 0x1D730, Status::Mapped("\u{3C5}")),
+    (0x1D731, 0x1D731, Status::Mapped("\u{3C6}")),
+    (0x1D732, 0x1D732, Status::Mapped("\u{3C7}")),
+    (0x1D733, 0x1D733, Status::Mapped("\u{3C8}")),
+    (0x1D734, 0x1D734, Status::Mapped("\u{3C9}")),
+    (0x1D735, 0x1D735, Status::Mapped("\u{2207}")),
+    (0x1D736, 0x1D736, Status::Mapped("\u{3B1}")),
+    (0x1D737, 0x1D737, Status::Mapped("\u{3B2}")),
+    (0x1D738, 0x1D738, Status::Mapped("\u{3B3}")),
+    (0x1D739, 0x1D739, Status::Mapped("\u{3B4}")),
+    (0x1D73A, 0x1D73A, Status::Mapped("\u{3B5}")),
+    (0x1D73B, 0x1D73B, Status::Mapped("\u{3B6}")),
+    (0x1D73C, 0x1D73C, Status::Mapped("\u{3B7}")),
+    (0x1D73D, 0x1D73D, Status::Mapped("\u{3B8}")),
+    (0x1D73E, 0x1D73E, Status::Mapped("\u{3B9}")),
+    (0x1D73F, 0x1D73F, Status::Mapped("\u{3BA}")),
+    (0x1D740, 0x1D740, Status::Mapped("\u{3BB}")),
+    (0x1D741, 0x1D741, Status::Mapped("\u{3BC}")),
+    (0x1D742, 0x1D742, Status::Mapped("\u{3BD}")),
+    (0x1D743, 0x1D743, Status::Mapped("\u{3BE}")),
+    (0x1D744, 0x1D744, Status::Mapped("\u{3BF}")),
+    (0x1D745, 0x1D745, Status::Mapped("\u{3C0}")),
+    (0x1D746, 0x1D746, Status::Mapped("\u{3C1}")),
+    (0x1D747, 0x1D748, Status::Mapped("\u{3C3}")),
+    (0x1D749, 0x1D749, Status::Mapped("\u{3C4}")),
+    (0x1D74A, 0x1D74A, Status::Mapped("\u{3C5}")),
+    (0x1D74B, 0x1D74B, Status::Mapped("\u{3C6}")),
+    (0x1D74C, 0x1D74C, Status::Mapped("\u{3C7}")),
+    (0x1D74D, 0x1D74D, Status::Mapped("\u{3C8}")),
+    (0x1D74E, 0x1D74E, Status::Mapped("\u{3C9}")),
+    (0x1D74F, 0x1D74F, Status::Mapped("\u{2202}")),
+    (0x1D750, 0x1D750, Status::Mapped("\u{3B5}")),
+    (0x1D751, 0x1D751, Status::Mapped("\u{3B8}")),
+    (0x1D752, 0x1D752, Status::Mapped("\u{3BA}")),
+    (0x1D753, 0x1D753, Status::Mapped("\u{3C6}")),
+    (0x1D754, 0x1D754, Status::Mapped("\u{3C1}")),
+    (0x1D755, 0x1D755, Status::Mapped("\u{3C0}")),
+    (0x1D756, 0x1D756, Status::Mapped("\u{3B1}")),
+    (0x1D757, 0x1D757, Status::Mapped("\u{3B2}")),
+    (0x1D758, 0x1D758, Status::Mapped("\u{3B3}")),
+    (0x1D759, 0x1D759, Status::Mapped("\u{3B4}")),
+    (0x1D75A, 0x1D75A, Status::Mapped("\u{3B5}")),
+    (0x1D75B, 0x1D75B, Status::Mapped("\u{3B6}")),
+    (0x1D75C, 0x1D75C, Status::Mapped("\u{3B7}")),
+    (0x1D75D, 0x1D75D, Status::Mapped("\u{3B8}")),
+    (0x1D75E, 0x1D75E, Status::Mapped("\u{3B9}")),
+    (0x1D75F, 0x1D75F, Status::Mapped("\u{3BA}")),
+    (0x1D760, 0x1D760, Status::Mapped("\u{3BB}")),
+    (0x1D761, 0x1D761, Status::Mapped("\u{3BC}")),
+    (0x1D762, 0x1D762, Status::Mapped("\u{3BD}")),
+    (0x1D763, 0x1D763, Status::Mapped("\u{3BE}")),
+    (0x1D764, 0x1D764, Status::Mapped("\u{3BF}")),
+    (0x1D765, 0x1D765, Status::Mapped("\u{3C0}")),
+    (0x1D766, 0x1D766, Status::Mapped("\u{3C1}")),
+    (0x1D767, 0x1D767, Status::Mapped("\u{3B8}")),
+    (0x1D768, 0x1D768, Status::Mapped("\u{3C3}")),
+    (0x1D769, 0x1D769, Status::Mapped("\u{3C4}")),
+    (0x1D76A, 0x1D76A, Status::Mapped("\u{3C5}")),
+    (0x1D76B, 0x1D76B, Status::Mapped("\u{3C6}")),
+    (0x1D76C, 0x1D76C, Status::Mapped("\u{3C7}")),
+    (0x1D76D, 0x1D76D, Status::Mapped("\u{3C8}")),
+    (0x1D76E, 0x1D76E, Status::Mapped("\u{3C9}")),
+    (0x1D76F, 0x1D76F, Status::Mapped("\u{2207}")),
+    (0x1D770, 0x1D770, Status::Mapped("\u{3B1}")),
+    (0x1D771, 0x1D771, Status::Mapped("\u{3B2}")),
+    (0x1D772, 0x1D772, Status::Mapped("\u{3B3}")),
+    (0x1D773, 0x1D773, Status::Mapped("\u{3B4}")),
+    (0x1D774, 0x1D774, Status::Mapped("\u{3B5}")),
+    (0x1D775, 0x1D775, Status::Mapped("\u{3B6}")),
+    (0x1D776, 0x1D776, Status::Mapped("\u{3B7}")),
+    (0x1D777, 0x1D777, Status::Mapped("\u{3B8}")),
+    (0x1D778, 0x1D778, Status::Mapped("\u{3B9}")),
+    (0x1D779, 0x1D779, Status::Mapped("\u{3BA}")),
+    (0x1D77A, 0x1D77A, Status::Mapped("\u{3BB}")),
+    (0x1D77B, 0x1D77B, Status::Mapped("\u{3BC}")),
+    (0x1D77C, 0x1D77C, Status::Mapped("\u{3BD}")),
+    (0x1D77D, 0x1D77D, Status::Mapped("\u{3BE}")),
+    (0x1D77E, 0x1D77E, Status::Mapped("\u{3BF}")),
+    (0x1D77F, 0x1D77F, Status::Mapped("\u{3C0}")),
+    (0x1D780, 0x1D780, Status::Mapped("\u{3C1}")),
+    (0x1D781, 0x1D782, Status::Mapped("\u{3C3}")),
+    (0x1D783, 0x1D783, Status::Mapped("\u{3C4}")),
+    (0x1D784, 0x1D784, Status::Mapped("\u{3C5}")),
+    (0x1D785, 0x1D785, Status::Mapped("\u{3C6}")),
+    (0x1D786, 0x1D786, Status::Mapped("\u{3C7}")),
+    (0x1D787, 0x1D787, Status::Mapped("\u{3C8}")),
+    (0x1D788, 0x1D788, Status::Mapped("\u{3C9}")),
+    (0x1D789, 0x1D789, Status::Mapped("\u{2202}")),
+    (0x1D78A, 0x1D78A, Status::Mapped("\u{3B5}")),
+    (0x1D78B, 0x1D78B, Status::Mapped("\u{3B8}")),
+    (0x1D78C, 0x1D78C, Status::Mapped("\u{3BA}")),
+    (0x1D78D, 0x1D78D, Status::Mapped("\u{3C6}")),
+    (0x1D78E, 0x1D78E, Status::Mapped("\u{3C1}")),
+    (0x1D78F, 0x1D78F, Status::Mapped("\u{3C0}")),
+    (0x1D790, 0x1D790, Status::Mapped("\u{3B1}")),
+    (0x1D791, 0x1D791, Status::Mapped("\u{3B2}")),
+    (0x1D792, 0x1D792, Status::Mapped("\u{3B3}")),
+    (0x1D793, 0x1D793, Status::Mapped("\u{3B4}")),
+    (0x1D794, 0x1D794, Status::Mapped("\u{3B5}")),
+    (0x1D795, 0x1D795, Status::Mapped("\u{3B6}")),
+    (0x1D796, 0x1D796, Status::Mapped("\u{3B7}")),
+    (0x1D797, 0x1D797, Status::Mapped("\u{3B8}")),
+    (0x1D798, 0x1D798, Status::Mapped("\u{3B9}")),
+    (0x1D799, 0x1D799, Status::Mapped("\u{3BA}")),
+    (0x1D79A, 0x1D79A, Status::Mapped("\u{3BB}")),
+    (0x1D79B, 0x1D79B, Status::Mapped("\u{3BC}")),
+    (0x1D79C, 0x1D79C, Status::Mapped("\u{3BD}")),
+    (0x1D79D, 0x1D79D, Status::Mapped("\u{3BE}")),
+    (0x1D79E, 0x1D79E, Status::Mapped("\u{3BF}")),
+    (0x1D79F, 0x1D79F, Status::Mapped("\u{3C0}")),
+    (0x1D7A0, 0x1D7A0, Status::Mapped("\u{3C1}")),
+    (0x1D7A1, 0x1D7A1, Status::Mapped("\u{3B8}")),
+    (0x1D7A2, 0x1D7A2, Status::Mapped("\u{3C3}")),
+    (0x1D7A3, 0x1D7A3, Status::Mapped("\u{3C4}")),
+    (0x1D7A4, 0x1D7A4, Status::Mapped("\u{3C5}")),
+    (0x1D7A5, 0x1D7A5, Status::Mapped("\u{3C6}")),
+    (0x1D7A6, 0x1D7A6, Status::Mapped("\u{3C7}")),
+    (0x1D7A7, 0x1D7A7, Status::Mapped("\u{3C8}")),
+    (0x1D7A8, 0x1D7A8, Status::Mapped("\u{3C9}")),
+    (0x1D7A9, 0x1D7A9, Status::Mapped("\u{2207}")),
+    (0x1D7AA, 0x1D7AA, Status::Mapped("\u{3B1}")),
+    (0x1D7AB, 0x1D7AB, Status::Mapped("\u{3B2}")),
+    (0x1D7AC, 0x1D7AC, Status::Mapped("\u{3B3}")),
+    (0x1D7AD, 0x1D7AD, Status::Mapped("\u{3B4}")),
+    (0x1D7AE, 0x1D7AE, Status::Mapped("\u{3B5}")),
+    (0x1D7AF, 0x1D7AF, Status::Mapped("\u{3B6}")),
+    (0x1D7B0, 0x1D7B0, Status::Mapped("\u{3B7}")),
+    (0x1D7B1, 0x1D7B1, Status::Mapped("\u{3B8}")),
+    (0x1D7B2, 0x1D7B2, Status::Mapped("\u{3B9}")),
+    (0x1D7B3, 0x1D7B3, Status::Mapped("\u{3BA}")),
+    (0x1D7B4, 0x1D7B4, Status::Mapped("\u{3BB}")),
+    (0x1D7B5, 0x1D7B5, Status::Mapped("\u{3BC}")),
+    (0x1D7B6, 0x1D7B6, Status::Mapped("\u{3BD}")),
+    (0x1D7B7, 0x1D7B7, Status::Mapped("\u{3BE}")),
+    (0x1D7B8, 0x1D7B8, Status::Mapped("\u{3BF}")),
+    (0x1D7B9, 0x1D7B9, Status::Mapped("\u{3C0}")),
+    (0x1D7BA, 0x1D7BA, Status::Mapped("\u{3C1}")),
+    (0x1D7BB, 0x1D7BC, Status::Mapped("\u{3C3}")),
+    (0x1D7BD, 0x1D7BD, Status::Mapped("\u{3C4}")),
+    (0x1D7BE, 0x1D7BE, Status::Mapped("\u{3C5}")),
+    (0x1D7BF, 0x1D7BF, Status::Mapped("\u{3C6}")),
+    (0x1D7C0, 0x1D7C0, Status::Mapped("\u{3C7}")),
+    (0x1D7C1, 0x1D7C1, Status::Mapped("\u{3C8}")),
+    (0x1D7C2, 0x1D7C2, Status::Mapped("\u{3C9}")),
+    (0x1D7C3, 0x1D7C3, Status::Mapped("\u{2202}")),
+    (0x1D7C4, 0x1D7C4, Status::Mapped("\u{3B5}")),
+    (0x1D7C5, 0x1D7C5, Status::Mapped("\u{3B8}")),
+    (0x1D7C6, 0x1D7C6, Status::Mapped("\u{3BA}")),
+    (0x1D7C7, 0x1D7C7, Status::Mapped("\u{3C6}")),
+    (0x1D7C8, 0x1D7C8, Status::Mapped("\u{3C1}")),
+    (0x1D7C9, 0x1D7C9, Status::Mapped("\u{3C0}")),
+    (0x1D7CA, 0x1D7CB, Status::Mapped("\u{3DD}")),
+    (0x1D7CC, 0x1D7CD, Status::Disallowed),
+    (0x1D7CE, 0x1D7CE, Status::Mapped("0")),
+    (0x1D7CF, 0x1D7CF, Status::Mapped("1")),
+    (0x1D7D0, 0x1D7D0, Status::Mapped("2")),
+    (0x1D7D1, 0x1D7D1, Status::Mapped("3")),
+    (0x1D7D2, 0x1D7D2, Status::Mapped("4")),
+    (0x1D7D3, 0x1D7D3, Status::Mapped("5")),
+    (0x1D7D4, 0x1D7D4, Status::Mapped("6")),
+    (0x1D7D5, 0x1D7D5, Status::Mapped("7")),
+    (0x1D7D6, 0x1D7D6, Status::Mapped("8")),
+    (0x1D7D7, 0x1D7D7, Status::Mapped("9")),
+    (0x1D7D8, 0x1D7D8, Status::Mapped("0")),
+    (0x1D7D9, 0x1D7D9, Status::Mapped("1")),
+    (0x1D7DA, 0x1D7DA, Status::Mapped("2")),
+    (0x1D7DB, 0x1D7DB, Status::Mapped("3")),
+    (0x1D7DC, 0x1D7DC, Status::Mapped("4")),
+    (0x1D7DD, 0x1D7DD, Status::Mapped("5")),
+    (0x1D7DE, 0x1D7DE, Status::Mapped("6")),
+    (0x1D7DF, 0x1D7DF, Status::Mapped("7")),
+    (0x1D7E0, 0x1D7E0, Status::Mapped("8")),
+    (0x1D7E1, 0x1D7E1, Status::Mapped("9")),
+    (0x1D7E2, 0x1D7E2, Status::Mapped("0")),
+    (0x1D7E3, 0x1D7E3, Status::Mapped("1")),
+    (0x1D7E4, 0x1D7E4, Status::Mapped("2")),
+    (0x1D7E5, 0x1D7E5, Status::Mapped("3")),
+    (0x1D7E6, 0x1D7E6, Status::Mapped("4")),
+    (0x1D7E7, 0x1D7E7, Status::Mapped("5")),
+    (0x1D7E8, 0x1D7E8, Status::Mapped("6")),
+    (0x1D7E9, 0x1D7E9, Status::Mapped("7")),
+    (0x1D7EA, 0x1D7EA, Status::Mapped("8")),
+    (0x1D7EB, 0x1D7EB, Status::Mapped("9")),
+    (0x1D7EC, 0x1D7EC, Status::Mapped("0")),
+    (0x1D7ED, 0x1D7ED, Status::Mapped("1")),
+    (0x1D7EE, 0x1D7EE, Status::Mapped("2")),
+    (0x1D7EF, 0x1D7EF, Status::Mapped("3")),
+    (0x1D7F0, 0x1D7F0, Status::Mapped("4")),
+    (0x1D7F1, 0x1D7F1, Status::Mapped("5")),
+    (0x1D7F2, 0x1D7F2, Status::Mapped("6")),
+    (0x1D7F3, 0x1D7F3, Status::Mapped("7")),
+    (0x1D7F4, 0x1D7F4, Status::Mapped("8")),
+    (0x1D7F5, 0x1D7F5, Status::Mapped("9")),
+    (0x1D7F6, 0x1D7F6, Status::Mapped("0")),
+    (0x1D7F7, 0x1D7F7, Status::Mapped("1")),
+    (0x1D7F8, 0x1D7F8, Status::Mapped("2")),
+    (0x1D7F9, 0x1D7F9, Status::Mapped("3")),
+    (0x1D7FA, 0x1D7FA, Status::Mapped("4")),
+    (0x1D7FB, 0x1D7FB, Status::Mapped("5")),
+    (0x1D7FC, 0x1D7FC, Status::Mapped("6")),
+    (0x1D7FD, 0x1D7FD, Status::Mapped("7")),
+    (0x1D7FE, 0x1D7FE, Status::Mapped("8")),
+    (0x1D7FF, 0x1D7FF, Status::Mapped("9")),
+    (0x1D800, 0x1DA8B, Status::Valid),
+    (0x1DA8C, 0x1DA9A, Status::Disallowed),
+    (0x1DA9B, 0x1DA9F, Status::Valid),
+    (0x1DAA0, 0x1DAA0, Status::Disallowed),
+    (0x1DAA1, 0x1DAAF, Status::Valid),
+    (0x1DAB0, 0x1DEFF, Status::Disallowed),
+    (0x1DF00, 0x1DF1E, Status::Valid),
+    (0x1DF1F, 0x1DF24, Status::Disallowed),
+    (0x1DF25, 0x1DF2A, Status::Valid),
+    (0x1DF2B, 0x1DFFF, Status::Disallowed),
+    (0x1E000, 0x1E006, Status::Valid),
+    (0x1E007, 0x1E007, Status::Disallowed),
+    (0x1E008, 0x1E018, Status::Valid),
+    (0x1E019, 0x1E01A, Status::Disallowed),
+    (0x1E01B, 0x1E021, Status::Valid),
+    (0x1E022, 0x1E022, Status::Disallowed),
+    (0x1E023, 0x1E024, Status::Valid),
+    (0x1E025, 0x1E025, Status::Disallowed),
+    (0x1E026, 0x1E02A, Status::Valid),
+    (0x1E02B, 0x1E02F, Status::Disallowed),
+    (0x1E030, 0x1E030, Status::Mapped("\u{430}")),
+    (0x1E031, 0x1E031, Status::Mapped("\u{431}")),
+    (0x1E032, 0x1E032, Status::Mapped("\u{432}")),
+    (0x1E033, 0x1E033, Status::Mapped("\u{433}")),
+    (0x1E034, 0x1E034, Status::Mapped("\u{434}")),
+    (0x1E035, 0x1E035, Status::Mapped("\u{435}")),
+    (0x1E036, 0x1E036, Status::Mapped("\u{436}")),
+    (0x1E037, 0x1E037, Status::Mapped("\u{437}")),
+    (0x1E038, 0x1E038, Status::Mapped("\u{438}")),
+    (0x1E039, 0x1E039, Status::Mapped("\u{43A}")),
+    (0x1E03A, 0x1E03A, Status::Mapped("\u{43B}")),
+    (0x1E03B, 0x1E03B, Status::Mapped("\u{43C}")),
+    (0x1E03C, 0x1E03C, Status::Mapped("\u{43E}")),
+    (0x1E03D, 0x1E03D, Status::Mapped("\u{43F}")),
+    (0x1E03E, 0x1E03E, Status::Mapped("\u{440}")),
+    (0x1E03F, 0x1E03F, Status::Mapped("\u{441}")),
+    (0x1E040, 0x1E040, Status::Mapped("\u{442}")),
+    (0x1E041, 0x1E041, Status::Mapped("\u{443}")),
+    (0x1E042, 0x1E042, Status::Mapped("\u{444}")),
+    (0x1E043, 0x1E043, Status::Mapped("\u{445}")),
+    (0x1E044, 0x1E044, Status::Mapped("\u{446}")),
+    (0x1E045, 0x1E045, Status::Mapped("\u{447}")),
+    (0x1E046, 0x1E046, Status::Mapped("\u{448}")),
+    (0x1E047, 0x1E047, Status::Mapped("\u{44B}")),
+    (0x1E048, 0x1E048, Status::Mapped("\u{44D}")),
+    (0x1E049, 0x1E049, Status::Mapped("\u{44E}")),
+    (0x1E04A, 0x1E04A, Status::Mapped("\u{A689}")),
+    (0x1E04B, 0x1E04B, Status::Mapped("\u{4D9}")),
+    (0x1E04C, 0x1E04C, Status::Mapped("\u{456}")),
+    (0x1E04D, 0x1E04D, Status::Mapped("\u{458}")),
+    (0x1E04E, 0x1E04E, Status::Mapped("\u{4E9}")),
+    (0x1E04F, 0x1E04F, Status::Mapped("\u{4AF}")),
+    (0x1E050, 0x1E050, Status::Mapped("\u{4CF}")),
+    (0x1E051, 0x1E051, Status::Mapped("\u{430}")),
+    (0x1E052, 0x1E052, Status::Mapped("\u{431}")),
+    (0x1E053, 0x1E053, Status::Mapped("\u{432}")),
+    (0x1E054, 0x1E054, Status::Mapped("\u{433}")),
+    (0x1E055, 0x1E055, Status::Mapped("\u{434}")),
+    (0x1E056, 0x1E056, Status::Mapped("\u{435}")),
+    (0x1E057, 0x1E057, Status::Mapped("\u{436}")),
+    (0x1E058, 0x1E058, Status::Mapped("\u{437}")),
+    (0x1E059, 0x1E059, Status::Mapped("\u{438}")),
+    (0x1E05A, 0x1E05A, Status::Mapped("\u{43A}")),
+    (0x1E05B, 0x1E05B, Status::Mapped("\u{43B}")),
+    (0x1E05C, 0x1E05C, Status::Mapped("\u{43E}")),
+    (0x1E05D, 0x1E05D, Status::Mapped("\u{43F}")),
+    (0x1E05E, 0x1E05E, Status::Mapped("\u{441}")),
+    (0x1E05F, 0x1E05F, Status::Mapped("\u{443}")),
+    (0x1E060, 0x1E060, Status::Mapped("\u{444}")),
+    (0x1E061, 0x1E061, Status::Mapped("\u{445}")),
+    (0x1E062, 0x1E062, Status::Mapped("\u{446}")),
+    (0x1E063, 0x1E063, Status::Mapped("\u{447}")),
+    (0x1E064, 0x1E064, Status::Mapped("\u{448}")),
+    (0x1E065, 0x1E065, Status::Mapped("\u{44A}")),
+    (0x1E066, 0x1E066, Status::Mapped("\u{44B}")),
+    (0x1E067, 0x1E067, Status::Mapped("\u{491}")),
+    (0x1E068, 0x1E068, Status::Mapped("\u{456}")),
+    (0x1E069, 0x1E069, Status::Mapped("\u{455}")),
+    (0x1E06A, 0x1E06A, Status::Mapped("\u{45F}")),
+    (0x1E06B, 0x1E06B, Status::Mapped("\u{4AB}")),
+    (0x1E06C, 0x1E06C, Status::Mapped("\u{A651}")),
+    (0x1E06D, 0x1E06D, Status::Mapped("\u{4B1}")),
+    (0x1E06E, 0x1E08E, Status::Disallowed),
+    (0x1E08F, 0x1E08F, Status::Valid),
+    (0x1E090, 0x1E0FF, Status::Disallowed),
+    (0x1E100, 0x1E12C, Status::Valid),
+    (0x1E12D, 0x1E12F, Status::Disallowed),
+    (0x1E130, 0x1E13D, Status::Valid),
+    (0x1E13E, 0x1E13F, Status::Disallowed),
+    (0x1E140, 0x1E149, Status::Valid),
+    (0x1E14A, 0x1E14D, Status::Disallowed),
+    (0x1E14E, 0x1E14F, Status::Valid),
+    (0x1E150, 0x1E28F, Status::Disallowed),
+    (0x1E290, 0x1E2AE, Status::Valid),
+    (0x1E2AF, 0x1E2BF, Status::Disallowed),
+    (0x1E2C0, 0x1E2F9, Status::Valid),
+    (0x1E2FA, 0x1E2FE, Status::Disallowed),
+    (0x1E2FF, 0x1E2FF, Status::Valid),
+    (0x1E300, 0x1E4CF, Status::Disallowed),
+    (0x1E4D0, 0x1E4F9, Status::Valid),
+    (0x1E4FA, 0x1E7DF, Status::Disallowed),
+    (0x1E7E0, 0x1E7E6, Status::Valid),
+    (0x1E7E7, 0x1E7E7, Status::Disallowed),
+    (0x1E7E8, 0x1E7EB, Status::Valid),
+    (0x1E7EC, 0x1E7EC, Status::Disallowed),
+    (0x1E7ED, 0x1E7EE, Status::Valid),
+    (0x1E7EF, 0x1E7EF, Status::Disallowed),
+    (0x1E7F0, 0x1E7FE, Status::Valid),
+    (0x1E7FF, 0x1E7FF, Status::Disallowed),
+    (0x1E800, 0x1E8C4, Status::Valid),
+    (0x1E8C5, 0x1E8C6, Status::Disallowed),
+    (0x1E8C7, 0x1E8D6, Status::Valid),
+    (0x1E8D7, 0x1E8FF, Status::Disallowed),
+    (0x1E900, 0x1E900, Status::Mapped("\u{1E922}")),
+    (0x1E901, 0x1E901, Status::Mapped("\u{1E923}")),
+    (0x1E902, 0x1E902, Status::Mapped("\u{1E924}")),
+    (0x1E903, 0x1E903, Status::Mapped("\u{1E925}")),
+    (0x1E904, 0x1E904, Status::Mapped("\u{1E926}")),
+    (0x1E905, 0x1E905, Status::Mapped("\u{1E927}")),
+    (0x1E906, 0x1E906, Status::Mapped("\u{1E928}")),
+    (0x1E907, 0x1E907, Status::Mapped("\u{1E929}")),
+    (0x1E908, 0x1E908, Status::Mapped("\u{1E92A}")),
+    (0x1E909, 0x1E909, Status::Mapped("\u{1E92B}")),
+    (0x1E90A, 0x1E90A, Status::Mapped("\u{1E92C}")),
+    (0x1E90B, 0x1E90B, Status::Mapped("\u{1E92D}")),
+    (0x1E90C, 0x1E90C, Status::Mapped("\u{1E92E}")),
+    (0x1E90D, 0x1E90D, Status::Mapped("\u{1E92F}")),
+    (0x1E90E, 0x1E90E, Status::Mapped("\u{1E930}")),
+    (0x1E90F, 0x1E90F, Status::Mapped("\u{1E931}")),
+    (0x1E910, 0x1E910, Status::Mapped("\u{1E932}")),
+    (0x1E911, 0x1E911, Status::Mapped("\u{1E933}")),
+    (0x1E912, 0x1E912, Status::Mapped("\u{1E934}")),
+    (0x1E913, 0x1E913, Status::Mapped("\u{1E935}")),
+    (0x1E914, 0x1E914, Status::Mapped("\u{1E936}")),
+    (0x1E915, 0x1E915, Status::Mapped("\u{1E937}")),
+    (0x1E916, 0x1E916, Status::Mapped("\u{1E938}")),
+    (0x1E917, 0x1E917, Status::Mapped("\u{1E939}")),
+    (0x1E918, 0x1E918, Status::Mapped("\u{1E93A}")),
+    (0x1E919, 0x1E919, Status::Mapped("\u{1E93B}")),
+    (0x1E91A, 0x1E91A, Status::Mapped("\u{1E93C}")),
+    (0x1E91B, 0x1E91B, Status::Mapped("\u{1E93D}")),
+    (0x1E91C, 0x1E91C, Status::Mapped("\u{1E93E}")),
+    (0x1E91D, 0x1E91D, Status::Mapped("\u{1E93F}")),
+    (0x1E91E, 0x1E91E, Status::Mapped("\u{1E940}")),
+    (0x1E91F, 0x1E91F, Status::Mapped("\u{1E941}")),
+    (0x1E920, 0x1E920, Status::Mapped("\u{1E942}")),
+    (0x1E921, 0x1E921, Status::Mapped("\u{1E943}")),
+    (0x1E922, 0x1E94B, Status::Valid),
+    (0x1E94C, 0x1E94F, Status::Disallowed),
+    (0x1E950, 0x1E959, Status::Valid),
+    (0x1E95A, 0x1E95D, Status::Disallowed),
+    (0x1E95E, 0x1E95F, Status::Valid),
+    (0x1E960, 0x1EC70, Status::Disallowed),
+    (0x1EC71, 0x1ECB4, Status::Valid),
+    (0x1ECB5, 0x1ED00, Status::Disallowed),
+    (0x1ED01, 0x1ED3D, Status::Valid),
+    (0x1ED3E, 0x1EDFF, Status::Disallowed),
+    (0x1EE00, 0x1EE00, Status::Mapped("\u{627}")),
+    (0x1EE01, 0x1EE01, Status::Mapped("\u{628}")),
+    (0x1EE02, 0x1EE02, Status::Mapped("\u{62C}")),
+    (0x1EE03, 0x1EE03, Status::Mapped("\u{62F}")),
+    (0x1EE04, 0x1EE04, Status::Disallowed),
+    (0x1EE05, 0x1EE05, Status::Mapped("\u{648}")),
+    (0x1EE06, 0x1EE06, Status::Mapped("\u{632}")),
+    (0x1EE07, 0x1EE07, Status::Mapped("\u{62D}")),
+    (0x1EE08, 0x1EE08, Status::Mapped("\u{637}")),
+    (0x1EE09, 0x1EE09, Status::Mapped("\u{64A}")),
+    (0x1EE0A, 0x1EE0A, Status::Mapped("\u{643}")),
+    (0x1EE0B, 0x1EE0B, Status::Mapped("\u{644}")),
+    (0x1EE0C, 0x1EE0C, Status::Mapped("\u{645}")),
+    (0x1EE0D, 0x1EE0D, Status::Mapped("\u{646}")),
+    (0x1EE0E, 0x1EE0E, Status::Mapped("\u{633}")),
+    (0x1EE0F, 0x1EE0F, Status::Mapped("\u{639}")),
+    (0x1EE10, 0x1EE10, Status::Mapped("\u{641}")),
+    (0x1EE11, 0x1EE11, Status::Mapped("\u{635}")),
+    (0x1EE12, 0x1EE12, Status::Mapped("\u{642}")),
+    (0x1EE13, 0x1EE13, Status::Mapped("\u{631}")),
+    (0x1EE14, 0x1EE14, Status::Mapped("\u{634}")),
+    (0x1EE15, 0x1EE15, Status::Mapped("\u{62A}")),
+    (0x1EE16, 0x1EE16, Status::Mapped("\u{62B}")),
+    (0x1EE17, 0x1EE17, Status::Mapped("\u{62E}")),
+    (0x1EE18, 0x1EE18, Status::Mapped("\u{630}")),
+    (0x1EE19, 0x1EE19, Status::Mapped("\u{636}")),
+    (0x1EE1A, 0x1EE1A, Status::Mapped("\u{638}")),
+    (0x1EE1B, 0x1EE1B, Status::Mapped("\u{63A}")),
+    (0x1EE1C, 0x1EE1C, Status::Mapped("\u{66E}")),
+    (0x1EE1D, 0x1EE1D, Status::Mapped("\u{6BA}")),
+    (0x1EE1E, 0x1EE1E, Status::Mapped("\u{6A1}")),
+    (0x1EE1F, 0x1EE1F, Status::Mapped("\u{66F}")),
+    (0x1EE20, 0x1EE20, Status::Disallowed),
+    (0x1EE21, 0x1EE21, Status::Mapped("\u{628}")),
+    (0x1EE22, 0x1EE22, Status::Mapped("\u{62C}")),
+    (0x1EE23, 0x1EE23, Status::Disallowed),
+    (0x1EE24, 0x1EE24, Status::Mapped("\u{647}")),
+    (0x1EE25, 0x1EE26, Status::Disallowed),
+    (0x1EE27, 0x1EE27, Status::Mapped("\u{62D}")),
+    (0x1EE28, 0x1EE28, Status::Disallowed),
+    (0x1EE29, 0x1EE29, Status::Mapped("\u{64A}")),
+    (0x1EE2A, 0x1EE2A, Status::Mapped("\u{643}")),
+    (0x1EE2B, 0x1EE2B, Status::Mapped("\u{644}")),
+    (0x1EE2C, 0x1EE2C, Status::Mapped("\u{645}")),
+    (0x1EE2D, 0x1EE2D, Status::Mapped("\u{646}")),
+    (0x1EE2E, 0x1EE2E, Status::Mapped("\u{633}")),
+    (0x1EE2F, 0x1EE2F, Status::Mapped("\u{639}")),
+    (0x1EE30, 0x1EE30, Status::Mapped("\u{641}")),
+    (0x1EE31, 0x1EE31, Status::Mapped("\u{635}")),
+    (0x1EE32, 0x1EE32, Status::Mapped("\u{642}")),
+    (0x1EE33, 0x1EE33, Status::Disallowed),
+    (0x1EE34, 0x1EE34, Status::Mapped("\u{634}")),
+    (0x1EE35, 0x1EE35, Status::Mapped("\u{62A}")),
+    (0x1EE36, 0x1EE36, Status::Mapped("\u{62B}")),
+    (0x1EE37, 0x1EE37, Status::Mapped("\u{62E}")),
+    (0x1EE38, 0x1EE38, Status::Disallowed),
+    (0x1EE39, 0x1EE39, Status::Mapped("\u{636}")),
+    (0x1EE3A, 0x1EE3A, Status::Disallowed),
+    (0x1EE3B, 0x1EE3B, Status::Mapped("\u{63A}")),
+    (0x1EE3C, 0x1EE41, Status::Disallowed),
+    (0x1EE42, 0x1EE42, Status::Mapped("\u{62C}")),
+    (0x1EE43, 0x1EE46, Status::Disallowed),
+    (0x1EE47, 0x1EE47, Status::Mapped("\u{62D}")),
+    (0x1EE48, 0x1EE48, Status::Disallowed),
+    (0x1EE49, 0x1EE49, Status::Mapped("\u{64A}")),
+    (0x1EE4A, 0x1EE4A, Status::Disallowed),
+    (0x1EE4B, 0x1EE4B, Status::Mapped("\u{644}")),
+    (0x1EE4C, 0x1EE4C, Status::Disallowed),
+    (0x1EE4D, 0x1EE4D, Status::Mapped("\u{646}")),
+    (0x1EE4E, 0x1EE4E, Status::Mapped("\u{633}")),
+    (0x1EE4F, 0x1EE4F, Status::Mapped("\u{639}")),
+    (0x1EE50, 0x1EE50, Status::Disallowed),
+    (0x1EE51, 0x1EE51, Status::Mapped("\u{635}")),
+    (0x1EE52, 0x1EE52, Status::Mapped("\u{642}")),
+    (0x1EE53, 0x1EE53, Status::Disallowed),
+    (0x1EE54, 0x1EE54, Status::Mapped("\u{634}")),
+    (0x1EE55, 0x1EE56, Status::Disallowed),
+    (0x1EE57, 0x1EE57, Status::Mapped("\u{62E}")),
+    (0x1EE58, 0x1EE58, Status::Disallowed),
+    (0x1EE59, 0x1EE59, Status::Mapped("\u{636}")),
+    (0x1EE5A, 0x1EE5A, Status::Disallowed),
+    (0x1EE5B, 0x1EE5B, Status::Mapped("\u{63A}")),
+    (0x1EE5C, 0x1EE5C, Status::Disallowed),
+    (0x1EE5D, 0x1EE5D, Status::Mapped("\u{6BA}")),
+    (0x1EE5E, 0x1EE5E, Status::Disallowed),
+    (0x1EE5F, 0x1EE5F, Status::Mapped("\u{66F}")),
+    (0x1EE60, 0x1EE60, Status::Disallowed),
+    (0x1EE61, 0x1EE61, Status::Mapped("\u{628}")),
+    (0x1EE62, 0x1EE62, Status::Mapped("\u{62C}")),
+    (0x1EE63, 0x1EE63, Status::Disallowed),
+    (0x1EE64, 0x1EE64, Status::Mapped("\u{647}")),
+    (0x1EE65, 0x1EE66, Status::Disallowed),
+    (0x1EE67, 0x1EE67, Status::Mapped("\u{62D}")),
+    (0x1EE68, 0x1EE68, Status::Mapped("\u{637}")),
+    (0x1EE69, 0x1EE69, Status::Mapped("\u{64A}")),
+    (0x1EE6A, 0x1EE6A, Status::Mapped("\u{643}")),
+    (0x1EE6B, 0x1EE6B, Status::Disallowed),
+    (0x1EE6C, 0x1EE6C, Status::Mapped("\u{645}")),
+    (0x1EE6D, 0x1EE6D, Status::Mapped("\u{646}")),
+    (0x1EE6E, 0x1EE6E, Status::Mapped("\u{633}")),
+    (0x1EE6F, 0x1EE6F, Status::Mapped("\u{639}")),
+    (0x1EE70, 0x1EE70, Status::Mapped("\u{641}")),
+    (0x1EE71, 0x1EE71, Status::Mapped("\u{635}")),
+    (0x1EE72, 0x1EE72, Status::Mapped("\u{642}")),
+    (0x1EE73, 0x1EE73, Status::Disallowed),
+    (0x1EE74, 0x1EE74, Status::Mapped("\u{634}")),
+    (0x1EE75, 0x1EE75, Status::Mapped("\u{62A}")),
+    (0x1EE76, 0x1EE76, Status::Mapped("\u{62B}")),
+    (0x1EE77, 0x1EE77, Status::Mapped("\u{62E}")),
+    (0x1EE78, 0x1EE78, Status::Disallowed),
+    (0x1EE79, 0x1EE79, Status::Mapped("\u{636}")),
+    (0x1EE7A, 0x1EE7A, Status::Mapped("\u{638}")),
+    (0x1EE7B, 0x1EE7B, Status::Mapped("\u{63A}")),
+    (0x1EE7C, 0x1EE7C, Status::Mapped("\u{66E}")),
+    (0x1EE7D, 0x1EE7D, Status::Disallowed),
+    (0x1EE7E, 0x1EE7E, Status::Mapped("\u{6A1}")),
+    (0x1EE7F, 0x1EE7F, Status::Disallowed),
+    (0x1EE80, 0x1EE80, Status::Mapped("\u{627}")),
+    (0x1EE81, 0x1EE81, Status::Mapped("\u{628}")),
+    (0x1EE82, 0x1EE82, Status::Mapped("\u{62C}")),
+    (0x1EE83, 0x1EE83, Status::Mapped("\u{62F}")),
+    (0x1EE84, 0x1EE84, Status::Mapped("\u{647}")),
+    (0x1EE85, 0x1EE85, Status::Mapped("\u{648}")),
+    (0x1EE86, 0x1EE86, Status::Mapped("\u{632}")),
+    (0x1EE87, 0x1EE87, Status::Mapped("\u{62D}")),
+    (0x1EE88, 0x1EE88, Status::Mapped("\u{637}")),
+    (0x1EE89, 0x1EE89, Status::Mapped("\u{64A}")),
+    (0x1EE8A, 0x1EE8A, Status::Disallowed),
+    (0x1EE8B, 0x1EE8B, Status::Mapped("\u{644}")),
+    (0x1EE8C, 0x1EE8C, Status::Mapped("\u{645}")),
+    (0x1EE8D, 0x1EE8D, Status::Mapped("\u{646}")),
+    (0x1EE8E, 0x1EE8E, Status::Mapped("\u{633}")),
+    (0x1EE8F, 0x1EE8F, Status::Mapped("\u{639}")),
+    (0x1EE90, 0x1EE90, Status::Mapped("\u{641}")),
+    (0x1EE91, 0x1EE91, Status::Mapped("\u{635}")),
+    (0x1EE92, 0x1EE92, Status::Mapped("\u{642}")),
+    (0x1EE93, 0x1EE93, Status::Mapped("\u{631}")),
+    (0x1EE94, 0x1EE94, Status::Mapped("\u{634}")),
+    (0x1EE95, 0x1EE95, Status::Mapped("\u{62A}")),
+    (0x1EE96, 0x1EE96, Status::Mapped("\u{62B}")),
+    (0x1EE97, 0x1EE97, Status::Mapped("\u{62E}")),
+    (0x1EE98, 0x1EE98, Status::Mapped("\u{630}")),
+    (0x1EE99, 0x1EE99, Status::Mapped("\u{636}")),
+    (0x1EE9A, 0x1EE9A, Status::Mapped("\u{638}")),
+    (0x1EE9B, 0x1EE9B, Status::Mapped("\u{63A}")),
+    (0x1EE9C, 0x1EEA0, Status::Disallowed),
+    (0x1EEA1, 0x1EEA1, Status::Mapped("\u{628}")),
+    (0x1EEA2, 0x1EEA2, Status::Mapped("\u{62C}")),
+    (0x1EEA3, 0x1EEA3, Status::Mapped("\u{62F}")),
+    (0x1EEA4, 0x1EEA4, Status::Disallowed),
+    (0x1EEA5, 0x1EEA5, Status::Mapped("\u{648}")),
+    (0x1EEA6, 0x1EEA6, Status::Mapped("\u{632}")),
+    (0x1EEA7, 0x1EEA7, Status::Mapped("\u{62D}")),
+    (0x1EEA8, 0x1EEA8, Status::Mapped("\u{637}")),
+    (0x1EEA9, 0x1EEA9, Status::Mapped("\u{64A}")),
+    (0x1EEAA, 0x1EEAA, Status::Disallowed),
+    (0x1EEAB, 0x1EEAB, Status::Mapped("\u{644}")),
+    (0x1EEAC, 0x1EEAC, Status::Mapped("\u{645}")),
+    (0x1EEAD, 0x1EEAD, Status::Mapped("\u{646}")),
+    (0x1EEAE, 0x1EEAE, Status::Mapped("\u{633}")),
+    (0x1EEAF, 0x1EEAF, Status::Mapped("\u{639}")),
+    (0x1EEB0, 0x1EEB0, Status::Mapped("\u{641}")),
+    (0x1EEB1, 0x1EEB1, Status::Mapped("\u{635}")),
+    (0x1EEB2, 0x1EEB2, Status::Mapped("\u{642}")),
+    (0x1EEB3, 0x1EEB3, Status::Mapped("\u{631}")),
+    (0x1EEB4, 0x1EEB4, Status::Mapped("\u{634}")),
+    (0x1EEB5, 0x1EEB5, Status::Mapped("\u{62A}")),
+    (0x1EEB6, 0x1EEB6, Status::Mapped("\u{62B}")),
+    (0x1EEB7, 0x1EEB7, Status::Mapped("\u{62E}")),
+    (0x1EEB8, 0x1EEB8, Status::Mapped("\u{630}")),
+    (0x1EEB9, 0x1EEB9, Status::Mapped("\u{636}")),
+    (0x1EEBA, 0x1EEBA, Status::Mapped("\u{638}")),
+    (0x1EEBB, 0x1EEBB, Status::Mapped("\u{63A}")),
+    (0x1EEBC, 0x1EEEF, Status::Disallowed),
+    (0x1EEF0, 0x1EEF1, Status::Valid),
+    (0x1EEF2, 0x1EFFF, Status::Disallowed),
+    (0x1F000, 0x1F02B, Status::Valid),
+    (0x1F02C, 0x1F02F, Status::Disallowed),
+    (0x1F030, 0x1F093, Status::Valid),
+    (0x1F094, 0x1F09F, Status::Disallowed),
+    (0x1F0A0, 0x1F0AE, Status::Valid),
+    (0x1F0AF, 0x1F0B0, Status::Disallowed),
+    (0x1F0B1, 0x1F0BF, Status::Valid),
+    (0x1F0C0, 0x1F0C0, Status::Disallowed),
+    (0x1F0C1, 0x1F0CF, Status::Valid),
+    (0x1F0D0, 0x1F0D0, Status::Disallowed),
+    (0x1F0D1, 0x1F0F5, Status::Valid),
+    (0x1F0F6, 0x1F100, Status::Disallowed),
+    (0x1F101, 0x1F129, Status::Valid),
+    (0x1F12A, 0x1F12A, Status::Mapped("\u{3014}s\u{3015}")),
+    (0x1F12B, 0x1F12B, Status::Mapped("c")),
+    (0x1F12C, 0x1F12C, Status::Mapped("r")),
+    (0x1F12D, 0x1F12D, Status::Mapped("cd")),
+    (0x1F12E, 0x1F12E, Status::Mapped("wz")),
+    (0x1F12F, 0x1F12F, Status::Valid),
+    (0x1F130, 0x1F130, Status::Mapped("a")),
+    (0x1F131, 0x1F131, Status::Mapped("b")),
+    (0x1F132, 0x1F132, Status::Mapped("c")),
+    (0x1F133, 0x1F133, Status::Mapped("d")),
+    (0x1F134, 0x1F134, Status::Mapped("e")),
+    (0x1F135, 0x1F135, Status::Mapped("f")),
+    (0x1F136, 0x1F136, Status::Mapped("g")),
+    (0x1F137, 0x1F137, Status::Mapped("h")),
+    (0x1F138, 0x1F138, Status::Mapped("i")),
+    (0x1F139, 0x1F139, Status::Mapped("j")),
+    (0x1F13A, 0x1F13A, Status::Mapped("k")),
+    (0x1F13B, 0x1F13B, Status::Mapped("l")),
+    (0x1F13C, 0x1F13C, Status::Mapped("m")),
+    (0x1F13D, 0x1F13D, Status::Mapped("n")),
+    (0x1F13E, 0x1F13E, Status::Mapped("o")),
+    (0x1F13F, 0x1F13F, Status::Mapped("p")),
+    (0x1F140, 0x1F140, Status::Mapped("q")),
+    (0x1F141, 0x1F141, Status::Mapped("r")),
+    (0x1F142, 0x1F142, Status::Mapped("s")),
+    (0x1F143, 0x1F143, Status::Mapped("t")),
+    (0x1F144, 0x1F144, Status::Mapped("u")),
+    (0x1F145, 0x1F145, Status::Mapped("v")),
+    (0x1F146, 0x1F146, Status::Mapped("w")),
+    (0x1F147, 0x1F147, Status::Mapped("x")),
+    (0x1F148, 0x1F148, Status::Mapped("y")),
+    (0x1F149, 0x1F149, Status::Mapped("z")),
+    (0x1F14A, 0x1F14A, Status::Mapped("hv")),
+    (0x1F14B, 0x1F14B, Status::Mapped("mv")),
+    (0x1F14C, 0x1F14C, Status::Mapped("sd")),
+    (0x1F14D, 0x1F14D, Status::Mapped("ss")),
+    (0x1F14E, 0x1F14E, Status::Mapped("ppv")),
+    (0x1F14F, 0x1F14F, Status::Mapped("wc")),
+    (0x1F150, 0x1F169, Status::Valid),
+    (0x1F16A, 0x1F16A, Status::Mapped("mc")),
+    (0x1F16B, 0x1F16B, Status::Mapped("md")),
+    (0x1F16C, 0x1F16C, Status::Mapped("mr")),
+    (0x1F16D, 0x1F18F, Status::Valid),
+    (0x1F190, 0x1F190, Status::Mapped("dj")),
+    (0x1F191, 0x1F1AD, Status::Valid),
+    (0x1F1AE, 0x1F1E5, Status::Disallowed),
+    (0x1F1E6, 0x1F1FF, Status::Valid),
+    (0x1F200, 0x1F200, Status::Mapped("\u{307B}\u{304B}")),
+    (0x1F201, 0x1F201, Status::Mapped("\u{30B3}\u{30B3}")),
+    (0x1F202, 0x1F202, Status::Mapped("\u{30B5}")),
+    (0x1F203, 0x1F20F, Status::Disallowed),
+    (0x1F210, 0x1F210, Status::Mapped("\u{624B}")),
+    (0x1F211, 0x1F211, Status::Mapped("\u{5B57}")),
+    (0x1F212, 0x1F212, Status::Mapped("\u{53CC}")),
+    (0x1F213, 0x1F213, Status::Mapped("\u{30C7}")),
+    (0x1F214, 0x1F214, Status::Mapped("\u{4E8C}")),
+    (0x1F215, 0x1F215, Status::Mapped("\u{591A}")),
+    (0x1F216, 0x1F216, Status::Mapped("\u{89E3}")),
+    (0x1F217, 0x1F217, Status::Mapped("\u{5929}")),
+    (0x1F218, 0x1F218, Status::Mapped("\u{4EA4}")),
+    (0x1F219, 0x1F219, Status::Mapped("\u{6620}")),
+    (0x1F21A, 0x1F21A, Status::Mapped("\u{7121}")),
+    (0x1F21B, 0x1F21B, Status::Mapped("\u{6599}")),
+    (0x1F21C, 0x1F21C, Status::Mapped("\u{524D}")),
+    (0x1F21D, 0x1F21D, Status::Mapped("\u{5F8C}")),
+    (0x1F21E, 0x1F21E, Status::Mapped("\u{518D}")),
+    (0x1F21F, 0x1F21F, Status::Mapped("\u{65B0}")),
+    (0x1F220, 0x1F220, Status::Mapped("\u{521D}")),
+    (0x1F221, 0x1F221, Status::Mapped("\u{7D42}")),
+    (0x1F222, 0x1F222, Status::Mapped("\u{751F}")),
+    (0x1F223, 0x1F223, Status::Mapped("\u{8CA9}")),
+    (0x1F224, 0x1F224, Status::Mapped("\u{58F0}")),
+    (0x1F225, 0x1F225, Status::Mapped("\u{5439}")),
+    (0x1F226, 0x1F226, Status::Mapped("\u{6F14}")),
+    (0x1F227, 0x1F227, Status::Mapped("\u{6295}")),
+    (0x1F228, 0x1F228, Status::Mapped("\u{6355}")),
+    (0x1F229, 0x1F229, Status::Mapped("\u{4E00}")),
+    (0x1F22A, 0x1F22A, Status::Mapped("\u{4E09}")),
+    (0x1F22B, 0x1F22B, Status::Mapped("\u{904A}")),
+    (0x1F22C, 0x1F22C, Status::Mapped("\u{5DE6}")),
+    (0x1F22D, 0x1F22D, Status::Mapped("\u{4E2D}")),
+    (0x1F22E, 0x1F22E, Status::Mapped("\u{53F3}")),
+    (0x1F22F, 0x1F22F, Status::Mapped("\u{6307}")),
+    (0x1F230, 0x1F230, Status::Mapped("\u{8D70}")),
+    (0x1F231, 0x1F231, Status::Mapped("\u{6253}")),
+    (0x1F232, 0x1F232, Status::Mapped("\u{7981}")),
+    (0x1F233, 0x1F233, Status::Mapped("\u{7A7A}")),
+    (0x1F234, 0x1F234, Status::Mapped("\u{5408}")),
+    (0x1F235, 0x1F235, Status::Mapped("\u{6E80}")),
+    (0x1F236, 0x1F236, Status::Mapped("\u{6709}")),
+    (0x1F237, 0x1F237, Status::Mapped("\u{6708}")),
+    (0x1F238, 0x1F238, Status::Mapped("\u{7533}")),
+    (0x1F239, 0x1F239, Status::Mapped("\u{5272}")),
+    (0x1F23A, 0x1F23A, Status::Mapped("\u{55B6}")),
+    (0x1F23B, 0x1F23B, Status::Mapped("\u{914D}")),
+    (0x1F23C, 0x1F23F, Status::Disallowed),
+    (0x1F240, 0x1F240, Status::Mapped("\u{3014}\u{672C}\u{3015}")),
+    (0x1F241, 0x1F241, Status::Mapped("\u{3014}\u{4E09}\u{3015}")),
+    (0x1F242, 0x1F242, Status::Mapped("\u{3014}\u{4E8C}\u{3015}")),
+    (0x1F243, 0x1F243, Status::Mapped("\u{3014}\u{5B89}\u{3015}")),
+    (0x1F244, 0x1F244, Status::Mapped("\u{3014}\u{70B9}\u{3015}")),
+    (0x1F245, 0x1F245, Status::Mapped("\u{3014}\u{6253}\u{3015}")),
+    (0x1F246, 0x1F246, Status::Mapped("\u{3014}\u{76D7}\u{3015}")),
+    (0x1F247, 0x1F247, Status::Mapped("\u{3014}\u{52DD}\u{3015}")),
+    (0x1F248, 0x1F248, Status::Mapped("\u{3014}\u{6557}\u{3015}")),
+    (0x1F249, 0x1F24F, Status::Disallowed),
+    (0x1F250, 0x1F250, Status::Mapped("\u{5F97}")),
+    (0x1F251, 0x1F251, Status::Mapped("\u{53EF}")),
+    (0x1F252, 0x1F25F, Status::Disallowed),
+    (0x1F260, 0x1F265, Status::Valid),
+    (0x1F266, 0x1F2FF, Status::Disallowed),
+    (0x1F300, 0x1F6D7, Status::Valid),
+    (0x1F6D8, 0x1F6DB, Status::Disallowed),
+    (0x1F6DC, 0x1F6EC, Status::Valid),
+    (0x1F6ED, 0x1F6EF, Status::Disallowed),
+    (0x1F6F0, 0x1F6FC, Status::Valid),
+    (0x1F6FD, 0x1F6FF, Status::Disallowed),
+    (0x1F700, 0x1F776, Status::Valid),
+    (0x1F777, 0x1F77A, Status::Disallowed),
+    (0x1F77B, 0x1F7D9, Status::Valid),
+    (0x1F7DA, 0x1F7DF, Status::Disallowed),
+    (0x1F7E0, 0x1F7EB, Status::Valid),
+    (0x1F7EC, 0x1F7EF, Status::Disallowed),
+    (0x1F7F0, 0x1F7F0, Status::Valid),
+    (0x1F7F1, 0x1F7FF, Status::Disallowed),
+    (0x1F800, 0x1F80B, Status::Valid),
+    (0x1F80C, 0x1F80F, Status::Disallowed),
+    (0x1F810, 0x1F847, Status::Valid),
+    (0x1F848, 0x1F84F, Status::Disallowed),
+    (0x1F850, 0x1F859, Status::Valid),
+    (0x1F85A, 0x1F85F, Status::Disallowed),
+    (0x1F860, 0x1F887, Status::Valid),
+    (0x1F888, 0x1F88F, Status::Disallowed),
+    (0x1F890, 0x1F8AD, Status::Valid),
+    (0x1F8AE, 0x1F8AF, Status::Disallowed),
+    (0x1F8B0, 0x1F8B1, Status::Valid),
+    (0x1F8B2, 0x1F8FF, Status::Disallowed),
+    (0x1F900, 0x1FA53, Status::Valid),
+    (0x1FA54, 0x1FA5F, Status::Disallowed),
+    (0x1FA60, 0x1FA6D, Status::Valid),
+    (0x1FA6E, 0x1FA6F, Status::Disallowed),
+    (0x1FA70, 0x1FA7C, Status::Valid),
+    (0x1FA7D, 0x1FA7F, Status::Disallowed),
+    (0x1FA80, 0x1FA88, Status::Valid),
+    (0x1FA89, 0x1FA8F, Status::Disallowed),
+    (0x1FA90, 0x1FABD, Status::Valid),
+    (0x1FABE, 0x1FABE, Status::Disallowed),
+    (0x1FABF, 0x1FAC5, Status::Valid),
+    (0x1FAC6, 0x1FACD, Status::Disallowed),
+    (0x1FACE, 0x1FADB, Status::Valid),
+    (0x1FADC, 0x1FADF, Status::Disallowed),
+    (0x1FAE0, 0x1FAE8, Status::Valid),
+    (0x1FAE9, 0x1FAEF, Status::Disallowed),
+    (0x1FAF0, 0x1FAF8, Status::Valid),
+    (0x1FAF9, 0x1FAFF, Status::Disallowed),
+    (0x1FB00, 0x1FB92, Status::Valid),
+    (0x1FB93, 0x1FB93, Status::Disallowed),
+    (0x1FB94, 0x1FBCA, Status::Valid),
+    (0x1FBCB, 0x1FBEF, Status::Disallowed),
+    (0x1FBF0, 0x1FBF0, Status::Mapped("0")),
+    (0x1FBF1, 0x1FBF1, Status::Mapped("1")),
+    (0x1FBF2, 0x1FBF2, Status::Mapped("2")),
+    (0x1FBF3, 0x1FBF3, Status::Mapped("3")),
+    (0x1FBF4, 0x1FBF4, Status::Mapped("4")),
+    (0x1FBF5, 0x1FBF5, Status::Mapped("5")),
+    (0x1FBF6, 0x1FBF6, Status::Mapped("6")),
+    (0x1FBF7, 0x1FBF7, Status::Mapped("7")),
+    (0x1FBF8, 0x1FBF8, Status::Mapped("8")),
+    (0x1FBF9, 0x1FBF9, Status::Mapped("9")),
+    (0x1FBFA, 0x1FFFF, Status::Disallowed),
+    (0x20000, 0x2A6DF, Status::Valid),
+    (0x2A6E0, 0x2A6FF, Status::Disallowed),
+    (0x2A700, 0x2B739, Status::Valid),
+    (0x2B73A, 0x2B73F, Status::Disallowed),
+    (0x2B740, 0x2B81D, Status::Valid),
+    (0x2B81E, 0x2B81F, Status::Disallowed),
+    (0x2B820, 0x2CEA1, Status::Valid),
+    (0x2CEA2, 0x2CEAF, Status::Disallowed),
+    (0x2CEB0, 0x2EBE0, Status::Valid),
+    (0x2EBE1, 0x2EBEF, Status::Disallowed),
+    (0x2EBF0, 0x2EE5D, Status::Valid),
+    (0x2EE5E, 0x2F7FF, Status::Disallowed),
+    (0x2F800, 0x2F800, Status::Mapped("\u{4E3D}")),
+    (0x2F801, 0x2F801, Status::Mapped("\u{4E38}")),
+    (0x2F802, 0x2F802, Status::Mapped("\u{4E41}")),
+    (0x2F803, 0x2F803, Status::Mapped("\u{20122}")),
+    (0x2F804, 0x2F804, Status::Mapped("\u{4F60}")),
+    (0x2F805, 0x2F805, Status::Mapped("\u{4FAE}")),
+    (0x2F806, 0x2F806, Status::Mapped("\u{4FBB}")),
+    (0x2F807, 0x2F807, Status::Mapped("\u{5002}")),
+    (0x2F808, 0x2F808, Status::Mapped("\u{507A}")),
+    (0x2F809, 0x2F809, Status::Mapped("\u{5099}")),
+    (0x2F80A, 0x2F80A, Status::Mapped("\u{50E7}")),
+    (0x2F80B, 0x2F80B, Status::Mapped("\u{50CF}")),
+    (0x2F80C, 0x2F80C, Status::Mapped("\u{349E}")),
+    (0x2F80D, 0x2F80D, Status::Mapped("\u{2063A}")),
+    (0x2F80E, 0x2F80E, Status::Mapped("\u{514D}")),
+    (0x2F80F, 0x2F80F, Status::Mapped("\u{5154}")),
+    (0x2F810, 0x2F810, Status::Mapped("\u{5164}")),
+    (0x2F811, 0x2F811, Status::Mapped("\u{5177}")),
+    (0x2F812, 0x2F812, Status::Mapped("\u{2051C}")),
+    (0x2F813, 0x2F813, Status::Mapped("\u{34B9}")),
+    (0x2F814, 0x2F814, Status::Mapped("\u{5167}")),
+    (0x2F815, 0x2F815, Status::Mapped("\u{518D}")),
+    (0x2F816, 0x2F816, Status::Mapped("\u{2054B}")),
+    (0x2F817, 0x2F817, Status::Mapped("\u{5197}")),
+    (0x2F818, 0x2F818, Status::Mapped("\u{51A4}")),
+    (0x2F819, 0x2F819, Status::Mapped("\u{4ECC}")),
+    (0x2F81A, 0x2F81A, Status::Mapped("\u{51AC}")),
+    (0x2F81B, 0x2F81B, Status::Mapped("\u{51B5}")),
+    (0x2F81C, 0x2F81C, Status::Mapped("\u{291DF}")),
+    (0x2F81D, 0x2F81D, Status::Mapped("\u{51F5}")),
+    (0x2F81E, 0x2F81E, Status::Mapped("\u{5203}")),
+    (0x2F81F, 0x2F81F, Status::Mapped("\u{34DF}")),
+    (0x2F820, 0x2F820, Status::Mapped("\u{523B}")),
+    (0x2F821, 0x2F821, Status::Mapped("\u{5246}")),
+    (0x2F822, 0x2F822, Status::Mapped("\u{5272}")),
+    (0x2F823, 0x2F823, Status::Mapped("\u{5277}")),
+    (0x2F824, 0x2F824, Status::Mapped("\u{3515}")),
+    (0x2F825, 0x2F825, Status::Mapped("\u{52C7}")),
+    (0x2F826, 0x2F826, Status::Mapped("\u{52C9}")),
+    (0x2F827, 0x2F827, Status::Mapped("\u{52E4}")),
+    (0x2F828, 0x2F828, Status::Mapped("\u{52FA}")),
+    (0x2F829, 0x2F829, Status::Mapped("\u{5305}")),
+    (0x2F82A, 0x2F82A, Status::Mapped("\u{5306}")),
+    (0x2F82B, 0x2F82B, Status::Mapped("\u{5317}")),
+    (0x2F82C, 0x2F82C, Status::Mapped("\u{5349}")),
+    (0x2F82D, 0x2F82D, Status::Mapped("\u{5351}")),
+    (0x2F82E, 0x2F82E, Status::Mapped("\u{535A}")),
+    (0x2F82F, 0x2F82F, Status::Mapped("\u{5373}")),
+    (0x2F830, 0x2F830, Status::Mapped("\u{537D}")),
+    (0x2F831, 0x2F833, Status::Mapped("\u{537F}")),
+    (0x2F834, 0x2F834, Status::Mapped("\u{20A2C}")),
+    (0x2F835, 0x2F835, Status::Mapped("\u{7070}")),
+    (0x2F836, 0x2F836, Status::Mapped("\u{53CA}")),
+    (0x2F837, 0x2F837, Status::Mapped("\u{53DF}")),
+    (0x2F838, 0x2F838, Status::Mapped("\u{20B63}")),
+    (0x2F839, 0x2F839, Status::Mapped("\u{53EB}")),
+    (0x2F83A, 0x2F83A, Status::Mapped("\u{53F1}")),
+    (0x2F83B, 0x2F83B, Status::Mapped("\u{5406}")),
+    (0x2F83C, 0x2F83C, Status::Mapped("\u{549E}")),
+    (0x2F83D, 0x2F83D, Status::Mapped("\u{5438}")),
+    (0x2F83E, 0x2F83E, Status::Mapped("\u{5448}")),
+    (0x2F83F, 0x2F83F, Status::Mapped("\u{5468}")),
+    (0x2F840, 0x2F840, Status::Mapped("\u{54A2}")),
+    (0x2F841, 0x2F841, Status::Mapped("\u{54F6}")),
+    (0x2F842, 0x2F842, Status::Mapped("\u{5510}")),
+    (0x2F843, 0x2F843, Status::Mapped("\u{5553}")),
+    (0x2F844, 0x2F844, Status::Mapped("\u{5563}")),
+    (0x2F845, 0x2F846, Status::Mapped("\u{5584}")),
+    (0x2F847, 0x2F847, Status::Mapped("\u{5599}")),
+    (0x2F848, 0x2F848, Status::Mapped("\u{55AB}")),
+    (0x2F849, 0x2F849, Status::Mapped("\u{55B3}")),
+    (0x2F84A, 0x2F84A, Status::Mapped("\u{55C2}")),
+    (0x2F84B, 0x2F84B, Status::Mapped("\u{5716}")),
+    (0x2F84C, 0x2F84C, Status::Mapped("\u{5606}")),
+    (0x2F84D, 0x2F84D, Status::Mapped("\u{5717}")),
+    (0x2F84E, 0x2F84E, Status::Mapped("\u{5651}")),
+    (0x2F84F, 0x2F84F, Status::Mapped("\u{5674}")),
+    (0x2F850, 0x2F850, Status::Mapped("\u{5207}")),
+    (0x2F851, 0x2F851, Status::Mapped("\u{58EE}")),
+    (0x2F852, 0x2F852, Status::Mapped("\u{57CE}")),
+    (0x2F853, 0x2F853, Status::Mapped("\u{57F4}")),
+    (0x2F854, 0x2F854, Status::Mapped("\u{580D}")),
+    (0x2F855, 0x2F855, Status::Mapped("\u{578B}")),
+    (0x2F856, 0x2F856, Status::Mapped("\u{5832}")),
+    (0x2F857, 0x2F857, Status::Mapped("\u{5831}")),
+    (0x2F858, 0x2F858, Status::Mapped("\u{58AC}")),
+    (0x2F859, 0x2F859, Status::Mapped("\u{214E4}")),
+    (0x2F85A, 0x2F85A, Status::Mapped("\u{58F2}")),
+    (0x2F85B, 0x2F85B, Status::Mapped("\u{58F7}")),
+    (0x2F85C, 0x2F85C, Status::Mapped("\u{5906}")),
+    (0x2F85D, 0x2F85D, Status::Mapped("\u{591A}")),
+    (0x2F85E, 0x2F85E, Status::Mapped("\u{5922}")),
+    (0x2F85F, 0x2F85F, Status::Mapped("\u{5962}")),
+    (0x2F860, 0x2F860, Status::Mapped("\u{216A8}")),
+    (0x2F861, 0x2F861, Status::Mapped("\u{216EA}")),
+    (0x2F862, 0x2F862, Status::Mapped("\u{59EC}")),
+    (0x2F863, 0x2F863, Status::Mapped("\u{5A1B}")),
+    (0x2F864, 0x2F864, Status::Mapped("\u{5A27}")),
+    (0x2F865, 0x2F865, Status::Mapped("\u{59D8}")),
+    (0x2F866, 0x2F866, Status::Mapped("\u{5A66}")),
+    (0x2F867, 0x2F867, Status::Mapped("\u{36EE}")),
+    (0x2F868, 0x2F868, Status::Disallowed),
+    (0x2F869, 0x2F869, Status::Mapped("\u{5B08}")),
+    (0x2F86A, 0x2F86B, Status::Mapped("\u{5B3E}")),
+    (0x2F86C, 0x2F86C, Status::Mapped("\u{219C8}")),
+    (0x2F86D, 0x2F86D, Status::Mapped("\u{5BC3}")),
+    (0x2F86E, 0x2F86E, Status::Mapped("\u{5BD8}")),
+    (0x2F86F, 0x2F86F, Status::Mapped("\u{5BE7}")),
+    (0x2F870, 0x2F870, Status::Mapped("\u{5BF3}")),
+    (0x2F871, 0x2F871, Status::Mapped("\u{21B18}")),
+    (0x2F872, 0x2F872, Status::Mapped("\u{5BFF}")),
+    (0x2F873, 0x2F873, Status::Mapped("\u{5C06}")),
+    (0x2F874, 0x2F874, Status::Disallowed),
+    (0x2F875, 0x2F875, Status::Mapped("\u{5C22}")),
+    (0x2F876, 0x2F876, Status::Mapped("\u{3781}")),
+    (0x2F877, 0x2F877, Status::Mapped("\u{5C60}")),
+    (0x2F878, 0x2F878, Status::Mapped("\u{5C6E}")),
+    (0x2F879, 0x2F879, Status::Mapped("\u{5CC0}")),
+    (0x2F87A, 0x2F87A, Status::Mapped("\u{5C8D}")),
+    (0x2F87B, 0x2F87B, Status::Mapped("\u{21DE4}")),
+    (0x2F87C, 0x2F87C, Status::Mapped("\u{5D43}")),
+    (0x2F87D, 0x2F87D, Status::Mapped("\u{21DE6}")),
+    (0x2F87E, 0x2F87E, Status::Mapped("\u{5D6E}")),
+    (0x2F87F, 0x2F87F, Status::Mapped("\u{5D6B}")),
+    (0x2F880, 0x2F880, Status::Mapped("\u{5D7C}")),
+    (0x2F881, 0x2F881, Status::Mapped("\u{5DE1}")),
+    (0x2F882, 0x2F882, Status::Mapped("\u{5DE2}")),
+    (0x2F883, 0x2F883, Status::Mapped("\u{382F}")),
+    (0x2F884, 0x2F884, Status::Mapped("\u{5DFD}")),
+    (0x2F885, 0x2F885, Status::Mapped("\u{5E28}")),
+    (0x2F886, 0x2F886, Status::Mapped("\u{5E3D}")),
+    (0x2F887, 0x2F887, Status::Mapped("\u{5E69}")),
+    (0x2F888, 0x2F888, Status::Mapped("\u{3862}")),
+    (0x2F889, 0x2F889, Status::Mapped("\u{22183}")),
+    (0x2F88A, 0x2F88A, Status::Mapped("\u{387C}")),
+    (0x2F88B, 0x2F88B, Status::Mapped("\u{5EB0}")),
+    (0x2F88C, 0x2F88C, Status::Mapped("\u{5EB3}")),
+    (0x2F88D, 0x2F88D, Status::Mapped("\u{5EB6}")),
+    (0x2F88E, 0x2F88E, Status::Mapped("\u{5ECA}")),
+    (0x2F88F, 0x2F88F, Status::Mapped("\u{2A392}")),
+    (0x2F890, 0x2F890, Status::Mapped("\u{5EFE}")),
+    (0x2F891, 0x2F892, Status::Mapped("\u{22331}")),
+    (0x2F893, 0x2F893, Status::Mapped("\u{8201}")),
+    (0x2F894, 0x2F895, Status::Mapped("\u{5F22}")),
+    (0x2F896, 0x2F896, Status::Mapped("\u{38C7}")),
+    (0x2F897, 0x2F897, Status::Mapped("\u{232B8}")),
+    (0x2F898, 0x2F898, Status::Mapped("\u{261DA}")),
+    (0x2F899, 0x2F899, Status::Mapped("\u{5F62}")),
+    (0x2F89A, 0x2F89A, Status::Mapped("\u{5F6B}")),
+    (0x2F89B, 0x2F89B, Status::Mapped("\u{38E3}")),
+    (0x2F89C, 0x2F89C, Status::Mapped("\u{5F9A}")),
+    (0x2F89D, 0x2F89D, Status::Mapped("\u{5FCD}")),
+    (0x2F89E, 0x2F89E, Status::Mapped("\u{5FD7}")),
+    (0x2F89F, 0x2F89F, Status::Mapped("\u{5FF9}")),
+    (0x2F8A0, 0x2F8A0, Status::Mapped("\u{6081}")),
+    (0x2F8A1, 0x2F8A1, Status::Mapped("\u{393A}")),
+    (0x2F8A2, 0x2F8A2, Status::Mapped("\u{391C}")),
+    (0x2F8A3, 0x2F8A3, Status::Mapped("\u{6094}")),
+    (0x2F8A4, 0x2F8A4, Status::Mapped("\u{226D4}")),
+    (0x2F8A5, 0x2F8A5, Status::Mapped("\u{60C7}")),
+    (0x2F8A6, 0x2F8A6, Status::Mapped("\u{6148}")),
+    (0x2F8A7, 0x2F8A7, Status::Mapped("\u{614C}")),
+    (0x2F8A8, 0x2F8A8, Status::Mapped("\u{614E}")),
+    (0x2F8A9, 0x2F8A9, Status::Mapped("\u{614C}")),
+    (0x2F8AA, 0x2F8AA, Status::Mapped("\u{617A}")),
+    (0x2F8AB, 0x2F8AB, Status::Mapped("\u{618E}")),
+    (0x2F8AC, 0x2F8AC, Status::Mapped("\u{61B2}")),
+    (0x2F8AD, 0x2F8AD, Status::Mapped("\u{61A4}")),
+    (0x2F8AE, 0x2F8AE, Status::Mapped("\u{61AF}")),
+    (0x2F8AF, 0x2F8AF, Status::Mapped("\u{61DE}")),
+    (0x2F8B0, 0x2F8B0, Status::Mapped("\u{61F2}")),
+    (0x2F8B1, 0x2F8B1, Status::Mapped("\u{61F6}")),
+    (0x2F8B2, 0x2F8B2, Status::Mapped("\u{6210}")),
+    (0x2F8B3, 0x2F8B3, Status::Mapped("\u{621B}")),
+    (0x2F8B4, 0x2F8B4, Status::Mapped("\u{625D}")),
+    (0x2F8B5, 0x2F8B5, Status::Mapped("\u{62B1}")),
+    (0x2F8B6, 0x2F8B6, Status::Mapped("\u{62D4}")),
+    (0x2F8B7, 0x2F8B7, Status::Mapped("\u{6350}")),
+    (0x2F8B8, 0x2F8B8, Status::Mapped("\u{22B0C}")),
+    (0x2F8B9, 0x2F8B9, Status::Mapped("\u{633D}")),
+    (0x2F8BA, 0x2F8BA, Status::Mapped("\u{62FC}")),
+    (0x2F8BB, 0x2F8BB, Status::Mapped("\u{6368}")),
+    (0x2F8BC, 0x2F8BC, Status::Mapped("\u{6383}")),
+    (0x2F8BD, 0x2F8BD, Status::Mapped("\u{63E4}")),
+    (0x2F8BE, 0x2F8BE, Status::Mapped("\u{22BF1}")),
+    (0x2F8BF, 0x2F8BF, Status::Mapped("\u{6422}")),
+    (0x2F8C0, 0x2F8C0, Status::Mapped("\u{63C5}")),
+    (0x2F8C1, 0x2F8C1, Status::Mapped("\u{63A9}")),
+    (0x2F8C2, 0x2F8C2, Status::Mapped("\u{3A2E}")),
+    (0x2F8C3, 0x2F8C3, Status::Mapped("\u{6469}")),
+    (0x2F8C4, 0x2F8C4, Status::Mapped("\u{647E}")),
+    (0x2F8C5, 0x2F8C5, Status::Mapped("\u{649D}")),
+    (0x2F8C6, 0x2F8C6, Status::Mapped("\u{6477}")),
+    (0x2F8C7, 0x2F8C7, Status::Mapped("\u{3A6C}")),
+    (0x2F8C8, 0x2F8C8, Status::Mapped("\u{654F}")),
+    (0x2F8C9, 0x2F8C9, Status::Mapped("\u{656C}")),
+    (0x2F8CA, 0x2F8CA, Status::Mapped("\u{2300A}")),
+    (0x2F8CB, 0x2F8CB, Status::Mapped("\u{65E3}")),
+    (0x2F8CC, 0x2F8CC, Status::Mapped("\u{66F8}")),
+    (0x2F8CD, 0x2F8CD, Status::Mapped("\u{6649}")),
+    (0x2F8CE, 0x2F8CE, Status::Mapped("\u{3B19}")),
+    (0x2F8CF, 0x2F8CF, Status::Mapped("\u{6691}")),
+    (0x2F8D0, 0x2F8D0, Status::Mapped("\u{3B08}")),
+    (0x2F8D1, 0x2F8D1, Status::Mapped("\u{3AE4}")),
+    (0x2F8D2, 0x2F8D2, Status::Mapped("\u{5192}")),
+    (0x2F8D3, 0x2F8D3, Status::Mapped("\u{5195}")),
+    (0x2F8D4, 0x2F8D4, Status::Mapped("\u{6700}")),
+    (0x2F8D5, 0x2F8D5, Status::Mapped("\u{669C}")),
+    (0x2F8D6, 0x2F8D6, Status::Mapped("\u{80AD}")),
+    (0x2F8D7, 0x2F8D7, Status::Mapped("\u{43D9}")),
+    (0x2F8D8, 0x2F8D8, Status::Mapped("\u{6717}")),
+    (0x2F8D9, 0x2F8D9, Status::Mapped("\u{671B}")),
+    (0x2F8DA, 0x2F8DA, Status::Mapped("\u{6721}")),
+    (0x2F8DB, 0x2F8DB, Status::Mapped("\u{675E}")),
+    (0x2F8DC, 0x2F8DC, Status::Mapped("\u{6753}")),
+    (0x2F8DD, 0x2F8DD, Status::Mapped("\u{233C3}")),
+    (0x2F8DE, 0x2F8DE, Status::Mapped("\u{3B49}")),
+    (0x2F8DF, 0x2F8DF, Status::Mapped("\u{67FA}")),
+    (0x2F8E0, 0x2F8E0, Status::Mapped("\u{6785}")),
+    (0x2F8E1, 0x2F8E1, Status::Mapped("\u{6852}")),
+    (0x2F8E2, 0x2F8E2, Status::Mapped("\u{6885}")),
+    (0x2F8E3, 0x2F8E3, Status::Mapped("\u{2346D}")),
+    (0x2F8E4, 0x2F8E4, Status::Mapped("\u{688E}")),
+    (0x2F8E5, 0x2F8E5, Status::Mapped("\u{681F}")),
+    (0x2F8E6, 0x2F8E6, Status::Mapped("\u{6914}")),
+    (0x2F8E7, 0x2F8E7, Status::Mapped("\u{3B9D}")),
+    (0x2F8E8, 0x2F8E8, Status::Mapped("\u{6942}")),
+    (0x2F8E9, 0x2F8E9, Status::Mapped("\u{69A3}")),
+    (0x2F8EA, 0x2F8EA, Status::Mapped("\u{69EA}")),
+    (0x2F8EB, 0x2F8EB, Status::Mapped("\u{6AA8}")),
+    (0x2F8EC, 0x2F8EC, Status::Mapped("\u{236A3}")),
+    (0x2F8ED, 0x2F8ED, Status::Mapped("\u{6ADB}")),
+    (0x2F8EE, 0x2F8EE, Status::Mapped("\u{3C18}")),
+    (0x2F8EF, 0x2F8EF, Status::Mapped("\u{6B21}")),
+    (0x2F8F0, 0x2F8F0, Status::Mapped("\u{238A7}")),
+    (0x2F8F1, 0x2F8F1, Status::Mapped("\u{6B54}")),
+    (0x2F8F2, 0x2F8F2, Status::Mapped("\u{3C4E}")),
+    (0x2F8F3, 0x2F8F3, Status::Mapped("\u{6B72}")),
+    (0x2F8F4, 0x2F8F4, Status::Mapped("\u{6B9F}")),
+    (0x2F8F5, 0x2F8F5, Status::Mapped("\u{6BBA}")),
+    (0x2F8F6, 0x2F8F6, Status::Mapped("\u{6BBB}")),
+    (0x2F8F7, 0x2F8F7, Status::Mapped("\u{23A8D}")),
+    (0x2F8F8, 0x2F8F8, Status::Mapped("\u{21D0B}")),
+    (0x2F8F9, 0x2F8F9, Status::Mapped("\u{23AFA}")),
+    (0x2F8FA, 0x2F8FA, Status::Mapped("\u{6C4E}")),
+    (0x2F8FB, 0x2F8FB, Status::Mapped("\u{23CBC}")),
+    (0x2F8FC, 0x2F8FC, Status::Mapped("\u{6CBF}")),
+    (0x2F8FD, 0x2F8FD, Status::Mapped("\u{6CCD}")),
+    (0x2F8FE, 0x2F8FE, Status::Mapped("\u{6C67}")),
+    (0x2F8FF, 0x2F8FF, Status::Mapped("\u{6D16}")),
+    (0x2F900, 0x2F900, Status::Mapped("\u{6D3E}")),
+    (0x2F901, 0x2F901, Status::Mapped("\u{6D77}")),
+    (0x2F902, 0x2F902, Status::Mapped("\u{6D41}")),
+    (0x2F903, 0x2F903, Status::Mapped("\u{6D69}")),
+    (0x2F904, 0x2F904, Status::Mapped("\u{6D78}")),
+    (0x2F905, 0x2F905, Status::Mapped("\u{6D85}")),
+    (0x2F906, 0x2F906, Status::Mapped("\u{23D1E}")),
+    (0x2F907, 0x2F907, Status::Mapped("\u{6D34}")),
+    (0x2F908, 0x2F908, Status::Mapped("\u{6E2F}")),
+    (0x2F909, 0x2F909, Status::Mapped("\u{6E6E}")),
+    (0x2F90A, 0x2F90A, Status::Mapped("\u{3D33}")),
+    (0x2F90B, 0x2F90B, Status::Mapped("\u{6ECB}")),
+    (0x2F90C, 0x2F90C, Status::Mapped("\u{6EC7}")),
+    (0x2F90D, 0x2F90D, Status::Mapped("\u{23ED1}")),
+    (0x2F90E, 0x2F90E, Status::Mapped("\u{6DF9}")),
+    (0x2F90F, 0x2F90F, Status::Mapped("\u{6F6E}")),
+    (0x2F910, 0x2F910, Status::Mapped("\u{23F5E}")),
+    (0x2F911, 0x2F911, Status::Mapped("\u{23F8E}")),
+    (0x2F912, 0x2F912, Status::Mapped("\u{6FC6}")),
+    (0x2F913, 0x2F913, Status::Mapped("\u{7039}")),
+    (0x2F914, 0x2F914, Status::Mapped("\u{701E}")),
+    (0x2F915, 0x2F915, Status::Mapped("\u{701B}")),
+    (0x2F916, 0x2F916, Status::Mapped("\u{3D96}")),
+    (0x2F917, 0x2F917, Status::Mapped("\u{704A}")),
+    (0x2F918, 0x2F918, Status::Mapped("\u{707D}")),
+    (0x2F919, 0x2F919, Status::Mapped("\u{7077}")),
+    (0x2F91A, 0x2F91A, Status::Mapped("\u{70AD}")),
+    (0x2F91B, 0x2F91B, Status::Mapped("\u{20525}")),
+    (0x2F91C, 0x2F91C, Status::Mapped("\u{7145}")),
+    (0x2F91D, 0x2F91D, Status::Mapped("\u{24263}")),
+    (0x2F91E, 0x2F91E, Status::Mapped("\u{719C}")),
+    (0x2F91F, 0x2F91F, Status::Disallowed),
+    (0x2F920, 0x2F920, Status::Mapped("\u{7228}")),
+    (0x2F921, 0x2F921, Status::Mapped("\u{7235}")),
+    (0x2F922, 0x2F922, Status::Mapped("\u{7250}")),
+    (0x2F923, 0x2F923, Status::Mapped("\u{24608}")),
+    (0x2F924, 0x2F924, Status::Mapped("\u{7280}")),
+    (0x2F925, 0x2F925, Status::Mapped("\u{7295}")),
+    (0x2F926, 0x2F926, Status::Mapped("\u{24735}")),
+    (0x2F927, 0x2F927, Status::Mapped("\u{24814}")),
+    (0x2F928, 0x2F928, Status::Mapped("\u{737A}")),
+    (0x2F929, 0x2F929, Status::Mapped("\u{738B}")),
+    (0x2F92A, 0x2F92A, Status::Mapped("\u{3EAC}")),
+    (0x2F92B, 0x2F92B, Status::Mapped("\u{73A5}")),
+    (0x2F92C, 0x2F92D, Status::Mapped("\u{3EB8}")),
+    (0x2F92E, 0x2F92E, Status::Mapped("\u{7447}")),
+    (0x2F92F, 0x2F92F, Status::Mapped("\u{745C}")),
+    (0x2F930, 0x2F930, Status::Mapped("\u{7471}")),
+    (0x2F931, 0x2F931, Status::Mapped("\u{7485}")),
+    (0x2F932, 0x2F932, Status::Mapped("\u{74CA}")),
+    (0x2F933, 0x2F933, Status::Mapped("\u{3F1B}")),
+    (0x2F934, 0x2F934, Status::Mapped("\u{7524}")),
+    (0x2F935, 0x2F935, Status::Mapped("\u{24C36}")),
+    (0x2F936, 0x2F936, Status::Mapped("\u{753E}")),
+    (0x2F937, 0x2F937, Status::Mapped("\u{24C92}")),
+    (0x2F938, 0x2F938, Status::Mapped("\u{7570}")),
+    (0x2F939, 0x2F939, Status::Mapped("\u{2219F}")),
+    (0x2F93A, 0x2F93A, Status::Mapped("\u{7610}")),
+    (0x2F93B, 0x2F93B, Status::Mapped("\u{24FA1}")),
+    (0x2F93C, 0x2F93C, Status::Mapped("\u{24FB8}")),
+    (0x2F93D, 0x2F93D, Status::Mapped("\u{25044}")),
+    (0x2F93E, 0x2F93E, Status::Mapped("\u{3FFC}")),
+    (0x2F93F, 0x2F93F, Status::Mapped("\u{4008}")),
+    (0x2F940, 0x2F940, Status::Mapped("\u{76F4}")),
+    (0x2F941, 0x2F941, Status::Mapped("\u{250F3}")),
+    (0x2F942, 0x2F942, Status::Mapped("\u{250F2}")),
+    (0x2F943, 0x2F943, Status::Mapped("\u{25119}")),
+    (0x2F944, 0x2F944, Status::Mapped("\u{25133}")),
+    (0x2F945, 0x2F945, Status::Mapped("\u{771E}")),
+    (0x2F946, 0x2F947, Status::Mapped("\u{771F}")),
+    (0x2F948, 0x2F948, Status::Mapped("\u{774A}")),
+    (0x2F949, 0x2F949, Status::Mapped("\u{4039}")),
+    (0x2F94A, 0x2F94A, Status::Mapped("\u{778B}")),
+    (0x2F94B, 0x2F94B, Status::Mapped("\u{4046}")),
+    (0x2F94C, 0x2F94C, Status::Mapped("\u{4096}")),
+    (0x2F94D, 0x2F94D, Status::Mapped("\u{2541D}")),
+    (0x2F94E, 0x2F94E, Status::Mapped("\u{784E}")),
+    (0x2F94F, 0x2F94F, Status::Mapped("\u{788C}")),
+    (0x2F950, 0x2F950, Status::Mapped("\u{78CC}")),
+    (0x2F951, 0x2F951, Status::Mapped("\u{40E3}")),
+    (0x2F952, 0x2F952, Status::Mapped("\u{25626}")),
+    (0x2F953, 0x2F953, Status::Mapped("\u{7956}")),
+    (0x2F954, 0x2F954, Status::Mapped("\u{2569A}")),
+    (0x2F955, 0x2F955, Status::Mapped("\u{256C5}")),
+    (0x2F956, 0x2F956, Status::Mapped("\u{798F}")),
+    (0x2F957, 0x2F957, Status::Mapped("\u{79EB}")),
+    (0x2F958, 0x2F958, Status::Mapped("\u{412F}")),
+    (0x2F959, 0x2F959, Status::Mapped("\u{7A40}")),
+    (0x2F95A, 0x2F95A, Status::Mapped("\u{7A4A}")),
+    (0x2F95B, 0x2F95B, Status::Mapped("\u{7A4F}")),
+    (0x2F95C, 0x2F95C, Status::Mapped("\u{2597C}")),
+    (0x2F95D, 0x2F95E, Status::Mapped("\u{25AA7}")),
+    (0x2F95F, 0x2F95F, Status::Disallowed),
+    (0x2F960, 0x2F960, Status::Mapped("\u{4202}")),
+    (0x2F961, 0x2F961, Status::Mapped("\u{25BAB}")),
+    (0x2F962, 0x2F962, Status::Mapped("\u{7BC6}")),
+    (0x2F963, 0x2F963, Status::Mapped("\u{7BC9}")),
+    (0x2F964, 0x2F964, Status::Mapped("\u{4227}")),
+    (0x2F965, 0x2F965, Status::Mapped("\u{25C80}")),
+    (0x2F966, 0x2F966, Status::Mapped("\u{7CD2}")),
+    (0x2F967, 0x2F967, Status::Mapped("\u{42A0}")),
+    (0x2F968, 0x2F968, Status::Mapped("\u{7CE8}")),
+    (0x2F969, 0x2F969, Status::Mapped("\u{7CE3}")),
+    (0x2F96A, 0x2F96A, Status::Mapped("\u{7D00}")),
+    (0x2F96B, 0x2F96B, Status::Mapped("\u{25F86}")),
+    (0x2F96C, 0x2F96C, Status::Mapped("\u{7D63}")),
+    (0x2F96D, 0x2F96D, Status::Mapped("\u{4301}")),
+    (0x2F96E, 0x2F96E, Status::Mapped("\u{7DC7}")),
+    (0x2F96F, 0x2F96F, Status::Mapped("\u{7E02}")),
+    (0x2F970, 0x2F970, Status::Mapped("\u{7E45}")),
+    (0x2F971, 0x2F971, Status::Mapped("\u{4334}")),
+    (0x2F972, 0x2F972, Status::Mapped("\u{26228}")),
+    (0x2F973, 0x2F973, Status::Mapped("\u{26247}")),
+    (0x2F974, 0x2F974, Status::Mapped("\u{4359}")),
+    (0x2F975, 0x2F975, Status::Mapped("\u{262D9}")),
+    (0x2F976, 0x2F976, Status::Mapped("\u{7F7A}")),
+    (0x2F977, 0x2F977, Status::Mapped("\u{2633E}")),
+    (0x2F978, 0x2F978, Status::Mapped("\u{7F95}")),
+    (0x2F979, 0x2F979, Status::Mapped("\u{7FFA}")),
+    (0x2F97A, 0x2F97A, Status::Mapped("\u{8005}")),
+    (0x2F97B, 0x2F97B, Status::Mapped("\u{264DA}")),
+    (0x2F97C, 0x2F97C, Status::Mapped("\u{26523}")),
+    (0x2F97D, 0x2F97D, Status::Mapped("\u{8060}")),
+    (0x2F97E, 0x2F97E, Status::Mapped("\u{265A8}")),
+    (0x2F97F, 0x2F97F, Status::Mapped("\u{8070}")),
+    (0x2F980, 0x2F980, Status::Mapped("\u{2335F}")),
+    (0x2F981, 0x2F981, Status::Mapped("\u{43D5}")),
+    (0x2F982, 0x2F982, Status::Mapped("\u{80B2}")),
+    (0x2F983, 0x2F983, Status::Mapped("\u{8103}")),
+    (0x2F984, 0x2F984, Status::Mapped("\u{440B}")),
+    (0x2F985, 0x2F985, Status::Mapped("\u{813E}")),
+    (0x2F986, 0x2F986, Status::Mapped("\u{5AB5}")),
+    (0x2F987, 0x2F987, Status::Mapped("\u{267A7}")),
+    (0x2F988, 0x2F988, Status::Mapped("\u{267B5}")),
+    (0x2F989, 0x2F989, Status::Mapped("\u{23393}")),
+    (0x2F98A, 0x2F98A, Status::Mapped("\u{2339C}")),
+    (0x2F98B, 0x2F98B, Status::Mapped("\u{8201}")),
+    (0x2F98C, 0x2F98C, Status::Mapped("\u{8204}")),
+    (0x2F98D, 0x2F98D, Status::Mapped("\u{8F9E}")),
+    (0x2F98E, 0x2F98E, Status::Mapped("\u{446B}")),
+    (0x2F98F, 0x2F98F, Status::Mapped("\u{8291}")),
+    (0x2F990, 0x2F990, Status::Mapped("\u{828B}")),
+    (0x2F991, 0x2F991, Status::Mapped("\u{829D}")),
+    (0x2F992, 0x2F992, Status::Mapped("\u{52B3}")),
+    (0x2F993, 0x2F993, Status::Mapped("\u{82B1}")),
+    (0x2F994, 0x2F994, Status::Mapped("\u{82B3}")),
+    (0x2F995, 0x2F995, Status::Mapped("\u{82BD}")),
+    (0x2F996, 0x2F996, Status::Mapped("\u{82E6}")),
+    (0x2F997, 0x2F997, Status::Mapped("\u{26B3C}")),
+    (0x2F998, 0x2F998, Status::Mapped("\u{82E5}")),
+    (0x2F999, 0x2F999, Status::Mapped("\u{831D}")),
+    (0x2F99A, 0x2F99A, Status::Mapped("\u{8363}")),
+    (0x2F99B, 0x2F99B, Status::Mapped("\u{83AD}")),
+    (0x2F99C, 0x2F99C, Status::Mapped("\u{8323}")),
+    (0x2F99D, 0x2F99D, Status::Mapped("\u{83BD}")),
+    (0x2F99E, 0x2F99E, Status::Mapped("\u{83E7}")),
+    (0x2F99F, 0x2F99F, Status::Mapped("\u{8457}")),
+    (0x2F9A0, 0x2F9A0, Status::Mapped("\u{8353}")),
+    (0x2F9A1, 0x2F9A1, Status::Mapped("\u{83CA}")),
+    (0x2F9A2, 0x2F9A2, Status::Mapped("\u{83CC}")),
+    (0x2F9A3, 0x2F9A3, Status::Mapped("\u{83DC}")),
+    (0x2F9A4, 0x2F9A4, Status::Mapped("\u{26C36}")),
+    (0x2F9A5, 0x2F9A5, Status::Mapped("\u{26D6B}")),
+    (0x2F9A6, 0x2F9A6, Status::Mapped("\u{26CD5}")),
+    (0x2F9A7, 0x2F9A7, Status::Mapped("\u{452B}")),
+    (0x2F9A8, 0x2F9A8, Status::Mapped("\u{84F1}")),
+    (0x2F9A9, 0x2F9A9, Status::Mapped("\u{84F3}")),
+    (0x2F9AA, 0x2F9AA, Status::Mapped("\u{8516}")),
+    (0x2F9AB, 0x2F9AB, Status::Mapped("\u{273CA}")),
+    (0x2F9AC, 0x2F9AC, Status::Mapped("\u{8564}")),
+    (0x2F9AD, 0x2F9AD, Status::Mapped("\u{26F2C}")),
+    (0x2F9AE, 0x2F9AE, Status::Mapped("\u{455D}")),
+    (0x2F9AF, 0x2F9AF, Status::Mapped("\u{4561}")),
+    (0x2F9B0, 0x2F9B0, Status::Mapped("\u{26FB1}")),
+    (0x2F9B1, 0x2F9B1, Status::Mapped("\u{270D2}")),
+    (0x2F9B2, 0x2F9B2, Status::Mapped("\u{456B}")),
+    (0x2F9B3, 0x2F9B3, Status::Mapped("\u{8650}")),
+    (0x2F9B4, 0x2F9B4, Status::Mapped("\u{865C}")),
+    (0x2F9B5, 0x2F9B5, Status::Mapped("\u{8667}")),
+    (0x2F9B6, 0x2F9B6, Status::Mapped("\u{8669}")),
+    (0x2F9B7, 0x2F9B7, Status::Mapped("\u{86A9}")),
+    (0x2F9B8, 0x2F9B8, Status::Mapped("\u{8688}")),
+    (0x2F9B9, 0x2F9B9, Status::Mapped("\u{870E}")),
+    (0x2F9BA, 0x2F9BA, Status::Mapped("\u{86E2}")),
+    (0x2F9BB, 0x2F9BB, Status::Mapped("\u{8779}")),
+    (0x2F9BC, 0x2F9BC, Status::Mapped("\u{8728}")),
+    (0x2F9BD, 0x2F9BD, Status::Mapped("\u{876B}")),
+    (0x2F9BE, 0x2F9BE, Status::Mapped("\u{8786}")),
+    (0x2F9BF, 0x2F9BF, Status::Disallowed),
+    (0x2F9C0, 0x2F9C0, Status::Mapped("\u{87E1}")),
+    (0x2F9C1, 0x2F9C1, Status::Mapped("\u{8801}")),
+    (0x2F9C2, 0x2F9C2, Status::Mapped("\u{45F9}")),
+    (0x2F9C3, 0x2F9C3, Status::Mapped("\u{8860}")),
+    (0x2F9C4, 0x2F9C4, Status::Mapped("\u{8863}")),
+    (0x2F9C5, 0x2F9C5, Status::Mapped("\u{27667}")),
+    (0x2F9C6, 0x2F9C6, Status::Mapped("\u{88D7}")),
+    (0x2F9C7, 0x2F9C7, Status::Mapped("\u{88DE}")),
+    (0x2F9C8, 0x2F9C8, Status::Mapped("\u{4635}")),
+    (0x2F9C9, 0x2F9C9, Status::Mapped("\u{88FA}")),
+    (0x2F9CA, 0x2F9CA, Status::Mapped("\u{34BB}")),
+    (0x2F9CB, 0x2F9CB, Status::Mapped("\u{278AE}")),
+    (0x2F9CC, 0x2F9CC, Status::Mapped("\u{27966}")),
+    (0x2F9CD, 0x2F9CD, Status::Mapped("\u{46BE}")),
+    (0x2F9CE, 0x2F9CE, Status::Mapped("\u{46C7}")),
+    (0x2F9CF, 0x2F9CF, Status::Mapped("\u{8AA0}")),
+    (0x2F9D0, 0x2F9D0, Status::Mapped("\u{8AED}")),
+    (0x2F9D1, 0x2F9D1, Status::Mapped("\u{8B8A}")),
+    (0x2F9D2, 0x2F9D2, Status::Mapped("\u{8C55}")),
+    (0x2F9D3, 0x2F9D3, Status::Mapped("\u{27CA8}")),
+    (0x2F9D4, 0x2F9D4, Status::Mapped("\u{8CAB}")),
+    (0x2F9D5, 0x2F9D5, Status::Mapped("\u{8CC1}")),
+    (0x2F9D6, 0x2F9D6, Status::Mapped("\u{8D1B}")),
+    (0x2F9D7, 0x2F9D7, Status::Mapped("\u{8D77}")),
+    (0x2F9D8, 0x2F9D8, Status::Mapped("\u{27F2F}")),
+    (0x2F9D9, 0x2F9D9, Status::Mapped("\u{20804}")),
+    (0x2F9DA, 0x2F9DA, Status::Mapped("\u{8DCB}")),
+    (0x2F9DB, 0x2F9DB, Status::Mapped("\u{8DBC}")),
+    (0x2F9DC, 0x2F9DC, Status::Mapped("\u{8DF0}")),
+    (0x2F9DD, 0x2F9DD, Status::Mapped("\u{208DE}")),
+    (0x2F9DE, 0x2F9DE, Status::Mapped("\u{8ED4}")),
+    (0x2F9DF, 0x2F9DF, Status::Mapped("\u{8F38}")),
+    (0x2F9E0, 0x2F9E0, Status::Mapped("\u{285D2}")),
+    (0x2F9E1, 0x2F9E1, Status::Mapped("\u{285ED}")),
+    (0x2F9E2, 0x2F9E2, Status::Mapped("\u{9094}")),
+    (0x2F9E3, 0x2F9E3, Status::Mapped("\u{90F1}")),
+    (0x2F9E4, 0x2F9E4, Status::Mapped("\u{9111}")),
+    (0x2F9E5, 0x2F9E5, Status::Mapped("\u{2872E}")),
+    (0x2F9E6, 0x2F9E6, Status::Mapped("\u{911B}")),
+    (0x2F9E7, 0x2F9E7, Status::Mapped("\u{9238}")),
+    (0x2F9E8, 0x2F9E8, Status::Mapped("\u{92D7}")),
+    (0x2F9E9, 0x2F9E9, Status::Mapped("\u{92D8}")),
+    (0x2F9EA, 0x2F9EA, Status::Mapped("\u{927C}")),
+    (0x2F9EB, 0x2F9EB, Status::Mapped("\u{93F9}")),
+    (0x2F9EC, 0x2F9EC, Status::Mapped("\u{9415}")),
+    (0x2F9ED, 0x2F9ED, Status::Mapped("\u{28BFA}")),
+    (0x2F9EE, 0x2F9EE, Status::Mapped("\u{958B}")),
+    (0x2F9EF, 0x2F9EF, Status::Mapped("\u{4995}")),
+    (0x2F9F0, 0x2F9F0, Status::Mapped("\u{95B7}")),
+    (0x2F9F1, 0x2F9F1, Status::Mapped("\u{28D77}")),
+    (0x2F9F2, 0x2F9F2, Status::Mapped("\u{49E6}")),
+    (0x2F9F3, 0x2F9F3, Status::Mapped("\u{96C3}")),
+    (0x2F9F4, 0x2F9F4, Status::Mapped("\u{5DB2}")),
+    (0x2F9F5, 0x2F9F5, Status::Mapped("\u{9723}")),
+    (0x2F9F6, 0x2F9F6, Status::Mapped("\u{29145}")),
+    (0x2F9F7, 0x2F9F7, Status::Mapped("\u{2921A}")),
+    (0x2F9F8, 0x2F9F8, Status::Mapped("\u{4A6E}")),
+    (0x2F9F9, 0x2F9F9, Status::Mapped("\u{4A76}")),
+    (0x2F9FA, 0x2F9FA, Status::Mapped("\u{97E0}")),
+    (0x2F9FB, 0x2F9FB, Status::Mapped("\u{2940A}")),
+    (0x2F9FC, 0x2F9FC, Status::Mapped("\u{4AB2}")),
+    (0x2F9FD, 0x2F9FD, Status::Mapped("\u{29496}")),
+    (0x2F9FE, 0x2F9FF, Status::Mapped("\u{980B}")),
+    (0x2FA00, 0x2FA00, Status::Mapped("\u{9829}")),
+    (0x2FA01, 0x2FA01, Status::Mapped("\u{295B6}")),
+    (0x2FA02, 0x2FA02, Status::Mapped("\u{98E2}")),
+    (0x2FA03, 0x2FA03, Status::Mapped("\u{4B33}")),
+    (0x2FA04, 0x2FA04, Status::Mapped("\u{9929}")),
+    (0x2FA05, 0x2FA05, Status::Mapped("\u{99A7}")),
+    (0x2FA06, 0x2FA06, Status::Mapped("\u{99C2}")),
+    (0x2FA07, 0x2FA07, Status::Mapped("\u{99FE}")),
+    (0x2FA08, 0x2FA08, Status::Mapped("\u{4BCE}")),
+    (0x2FA09, 0x2FA09, Status::Mapped("\u{29B30}")),
+    (0x2FA0A, 0x2FA0A, Status::Mapped("\u{9B12}")),
+    (0x2FA0B, 0x2FA0B, Status::Mapped("\u{9C40}")),
+    (0x2FA0C, 0x2FA0C, Status::Mapped("\u{9CFD}")),
+    (0x2FA0D, 0x2FA0D, Status::Mapped("\u{4CCE}")),
+    (0x2FA0E, 0x2FA0E, Status::Mapped("\u{4CED}")),
+    (0x2FA0F, 0x2FA0F, Status::Mapped("\u{9D67}")),
+    (0x2FA10, 0x2FA10, Status::Mapped("\u{2A0CE}")),
+    (0x2FA11, 0x2FA11, Status::Mapped("\u{4CF8}")),
+    (0x2FA12, 0x2FA12, Status::Mapped("\u{2A105}")),
+    (0x2FA13, 0x2FA13, Status::Mapped("\u{2A20E}")),
+    (0x2FA14, 0x2FA14, Status::Mapped("\u{2A291}")),
+    (0x2FA15, 0x2FA15, Status::Mapped("\u{9EBB}")),
+    (0x2FA16, 0x2FA16, Status::Mapped("\u{4D56}")),
+    (0x2FA17, 0x2FA17, Status::Mapped("\u{9EF9}")),
+    (0x2FA18, 0x2FA18, Status::Mapped("\u{9EFE}")),
+    (0x2FA19, 0x2FA19, Status::Mapped("\u{9F05}")),
+    (0x2FA1A, 0x2FA1A, Status::Mapped("\u{9F0F}")),
+    (0x2FA1B, 0x2FA1B, Status::Mapped("\u{9F16}")),
+    (0x2FA1C, 0x2FA1C, Status::Mapped("\u{9F3B}")),
+    (0x2FA1D, 0x2FA1D, Status::Mapped("\u{2A600}")),
+    (0x2FA1E, 0x2FFFF, Status::Disallowed),
+    (0x30000, 0x3134A, Status::Valid),
+    (0x3134B, 0x3134F, Status::Disallowed),
+    (0x31350, 0x323AF, Status::Valid),
+    (0x323B0, 0xE00FF, Status::Disallowed),
+    (0xE0100, 0xE01EF, Status::Ignored),
+    (0xE01F0, 0x10FFFF, Status::Disallowed),
+];
+
+// (最初のコードポイント, 最後のコードポイント, 結合クラス)
+pub static COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230),
+    (0x315, 0x315, 232),
+    (0x316, 0x319, 220),
+    (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216),
+    (0x31C, 0x320, 220),
+    (0x321, 0x322, 202),
+    (0x323, 0x326, 220),
+    (0x327, 0x328, 202),
+    (0x329, 0x333, 220),
+    (0x334, 0x338, 1),
+    (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230),
+    (0x345, 0x345, 240),
+    (0x346, 0x346, 230),
+    (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230),
+    (0x34D, 0x34E, 220),
+    (0x350, 0x352, 230),
+    (0x353, 0x356, 220),
+    (0x357, 0x357, 230),
+    (0x358, 0x358, 232),
+    (0x359, 0x35A, 220),
+    (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233),
+    (0x35D, 0x35E, 234),
+    (0x35F, 0x35F, 233),
+    (0x360, 0x361, 234),
+    (0x362, 0x362, 233),
+    (0x363, 0x36F, 230),
+    (0x483, 0x487, 230),
+    (0x591, 0x591, 220),
+    (0x592, 0x595, 230),
+    (0x596, 0x596, 220),
+    (0x597, 0x599, 230),
+    (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220),
+    (0x59C, 0x5A1, 230),
+    (0x5A2, 0x5A7, 220),
+    (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220),
+    (0x5AB, 0x5AC, 230),
+    (0x5AD, 0x5AD, 222),
+    (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230),
+    (0x5B0, 0x5B0, 10),
+    (0x5B1, 0x5B1, 11),
+    (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13),
+    (0x5B4, 0x5B4, 14),
+    (0x5B5, 0x5B5, 15),
+    (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17),
+    (0x5B8, 0x5B8, 18),
+    (0x5B9, 0x5BA, 19),
+    (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21),
+    (0x5BD, 0x5BD, 22),
+    (0x5BF, 0x5BF, 23),
+    (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25),
+    (0x5C4, 0x5C4, 230),
+    (0x5C5, 0x5C5, 220),
+    (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230),
+    (0x618, 0x618, 30),
+    (0x619, 0x619, 31),
+    (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27),
+    (0x64C, 0x64C, 28),
+    (0x64D, 0x64D, 29),
+    (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31),
+    (0x650, 0x650, 32),
+    (0x651, 0x651, 33),
+    (0x652, 0x652, 34),
+    (0x653, 0x654, 230),
+    (0x655, 0x656, 220),
+    (0x657, 0x65B, 230),
+    (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230),
+    (0x65F, 0x65F, 220),
+    (0x670, 0x670, 35),
+    (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230),
+    (0x6E3, 0x6E3, 220),
+    (0x6E4, 0x6E4, 230),
+    (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220),
+    (0x6EB, 0x6EC, 230),
+    (0x6ED, 0x6ED, 220),
+    (0x711, 0x711, 36),
+    (0x730, 0x730, 230),
+    (0x731, 0x731, 220),
+    (0x732, 0x733, 230),
+    (0x734, 0x734, 220),
+    (0x735, 0x736, 230),
+    (0x737, 0x739, 220),
+    (0x73A, 0x73A, 230),
+    (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230),
+    (0x73E, 0x73E, 220),
+    (0x73F, 0x741, 230),
+    (0x742, 0x742, 220),
+    (0x743, 0x743, 230),
+    (0x744, 0x744, 220),
+    (0x745, 0x745, 230),
+    (0x746, 0x746, 220),
+    (0x747, 0x747, 230),
+    (0x748, 0x748, 220),
+    (0x749, 0x74A, 230),
+    (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220),
+    (0x7F3, 0x7F3, 230),
+    (0x7FD, 0x7FD, 220),
+    (0x816, 0x819, 230),
+    (0x81B, 0x823, 230),
+    (0x825, 0x827, 230),
+    (0x829, 0x82D, 230),
+    (0x859, 0x85B, 220),
+    (0x898, 0x898, 230),
+    (0x899, 0x89B, 220),
+    (0x89C, 0x89F, 230),
+    (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220),
+    (0x8D4, 0x8E1, 230),
+    (0x8E3, 0x8E3, 220),
+    (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220),
+    (0x8E7, 0x8E8, 230),
+    (0x8E9, 0x8E9, 220),
+    (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220),
+    (0x8F0, 0x8F0, 27),
+    (0x8F1, 0x8F1, 28),
+    (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230),
+    (0x8F6, 0x8F6, 220),
+    (0x8F7, 0x8F8, 230),
+    (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230),
+    (0x93C, 0x93C, 7),
+    (0x94D, 0x94D, 9),
+    (0x951, 0x951, 230),
+    (0x952, 0x952, 220),
+    (0x953, 0x954, 230),
+    (0x9BC, 0x9BC, 7),
+    (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230),
+    (0xA3C, 0xA3C, 7),
+    (0xA4D, 0xA4D, 9),
+    (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9),
+    (0xB3C, 0xB3C, 7),
+    (0xB4D, 0xB4D, 9),
+    (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7),
+    (0xC4D, 0xC4D, 9),
+    (0xC55, 0xC55, 84),
+    (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7),
+    (0xCCD, 0xCCD, 9),
+    (0xD3B, 0xD3C, 9),
+    (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9),
+    (0xE38, 0xE39, 103),
+    (0xE3A, 0xE3A, 9),
+    (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118),
+    (0xEBA, 0xEBA, 9),
+    (0xEC8, 0xECB, 122),
+    (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220),
+    (0xF37, 0xF37, 220),
+    (0xF39, 0xF39, 216),
+    (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130),
+    (0xF74, 0xF74, 132),
+    (0xF7A, 0xF7D, 130),
+    (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230),
+    (0xF84, 0xF84, 9),
+    (0xF86, 0xF87, 230),
+    (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230),
+    (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10EFD, 0x10EFF, 220),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x11F41, 0x11F42, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E08F, 0x1E08F, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E4EC, 0x1E4ED, 232),
+    (0x1E4EE, 0x1E4EE, 220),
+    (0x1E4EF, 0x1E4EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+// (コードポイント, 分解後の1文字目, 分解後の2文字目または0)
+pub static CANONICAL_DECOMPOSITION: &[(u32, u32, u32)] = &[
+    (0xC0, 0x41, 0x300),
+    (0xC1, 0x41, 0x301),
+    (0xC2, 0x41, 0x302),
+    (0xC3, 0x41, 0x303),
+    (0xC4, 0x41, 0x308),
+    (0xC5, 0x41, 0x30A),
+    (0xC7, 0x43, 0x327),
+    (0xC8, 0x45, 0x300),
+    (0xC9, 0x45, 0x301),
+    (0xCA, 0x45, 0x302),
+    (0xCB, 0x45, 0x308),
+    (0xCC, 0x49, 0x300),
+    (0xCD, 0x49, 0x301),
+    (0xCE, 0x49, 0x302),
+    (0xCF, 0x49, 0x308),
+    (0xD1, 0x4E, 0x303),
+    (0xD2, 0x4F, 0x300),
+    (0xD3, 0x4F, 0x301),
+    (0xD4, 0x4F, 0x302),
+    (0xD5, 0x4F, 0x303),
+    (0xD6, 0x4F, 0x308),
+    (0xD9, 0x55, 0x300),
+    (0xDA, 0x55, 0x301),
+    (0xDB, 0x55, 0x302),
+    (0xDC, 0x55, 0x308),
+    (0xDD, 0x59, 0x301),
+    (0xE0, 0x61, 0x300),
+    (0xE1, 0x61, 0x301),
+    (0xE2, 0x61, 0x302),
+    (0xE3, 0x61, 0x303),
+    (0xE4, 0x61, 0x308),
+    (0xE5, 0x61, 0x30A),
+    (0xE7, 0x63, 0x327),
+    (0xE8, 0x65, 0x300),
+    (0xE9, 0x65, 0x301),
+    (0xEA, 0x65, 0x302),
+    (0xEB, 0x65, 0x308),
+    (0xEC, 0x69, 0x300),
+    (0xED, 0x69, 0x301),
+    (0xEE, 0x69, 0x302),
+    (0xEF, 0x69, 0x308),
+    (0xF1, 0x6E, 0x303),
+    (0xF2, 0x6F, 0x300),
+    (0xF3, 0x6F, 0x301),
+    (0xF4, 0x6F, 0x302),
+    (0xF5, 0x6F, 0x303),
+    (0xF6, 0x6F, 0x308),
+    (0xF9, 0x75, 0x300),
+    (0xFA, 0x75, 0x301),
+    (0xFB, 0x75, 0x302),
+    (0xFC, 0x75, 0x308),
+    (0xFD, 0x79, 0x301),
+    (0xFF, 0x79, 0x308),
+    (0x100, 0x41, 0x304),
+    (0x101, 0x61, 0x304),
+    (0x102, 0x41, 0x306),
+    (0x103, 0x61, 0x306),
+    (0x104, 0x41, 0x328),
+    (0x105, 0x61, 0x328),
+    (0x106, 0x43, 0x301),
+    (0x107, 0x63, 0x301),
+    (0x108, 0x43, 0x302),
+    (0x109, 0x63, 0x302),
+    (0x10A, 0x43, 0x307),
+    (0x10B, 0x63, 0x307),
+    (0x10C, 0x43, 0x30C),
+    (0x10D, 0x63, 0x30C),
+    (0x10E, 0x44, 0x30C),
+    (0x10F, 0x64, 0x30C),
+    (0x112, 0x45, 0x304),
+    (0x113, 0x65, 0x304),
+    (0x114, 0x45, 0x306),
+    (0x115, 0x65, 0x306),
+    (0x116, 0x45, 0x307),
+    (0x117, 0x65, 0x307),
+    (0x118, 0x45, 0x328),
+    (0x119, 0x65, 0x328),
+    (0x11A, 0x45, 0x30C),
+    (0x11B, 0x65, 0x30C),
+    (0x11C, 0x47, 0x302),
+    (0x11D, 0x67, 0x302),
+    (0x11E, 0x47, 0x306),
+    (0x11F, 0x67, 0x306),
+    (0x120, 0x47, 0x307),
+    (0x121, 0x67, 0x307),
+    (0x122, 0x47, 0x327),
+    (0x123, 0x67, 0x327),
+    (0x124, 0x48, 0x302),
+    (0x125, 0x68, 0x302),
+    (0x128, 0x49, 0x303),
+    (0x129, 0x69, 0x303),
+    (0x12A, 0x49, 0x304),
+    (0x12B, 0x69, 0x304),
+    (0x12C, 0x49, 0x306),
+    (0x12D, 0x69, 0x306),
+    (0x12E, 0x49, 0x328),
+    (0x12F, 0x69, 0x328),
+    (0x130, 0x49, 0x307),
+    (0x134, 0x4A, 0x302),
+    (0x135, 0x6A, 0x302),
+    (0x136, 0x4B, 0x327),
+    (0x137, 0x6B, 0x327),
+    (0x139, 0x4C, 0x301),
+    (0x13A, 0x6C, 0x301),
+    (0x13B, 0x4C, 0x327),
+    (0x13C, 0x6C, 0x327),
+    (0x13D, 0x4C, 0x30C),
+    (0x13E, 0x6C, 0x30C),
+    (0x143, 0x4E, 0x301),
+    (0x144, 0x6E, 0x301),
+    (0x145, 0x4E, 0x327),
+    (0x146, 0x6E, 0x327),
+    (0x147, 0x4E, 0x30C),
+    (0x148, 0x6E, 0x30C),
+    (0x14C, 0x4F, 0x304),
+    (0x14D, 0x6F, 0x304),
+    (0x14E, 0x4F, 0x306),
+    (0x14F, 0x6F, 0x306),
+    (0x150, 0x4F, 0x30B),
+    (0x151, 0x6F, 0x30B),
+    (0x154, 0x52, 0x301),
+    (0x155, 0x72, 0x301),
+    (0x156, 0x52, 0x327),
+    (0x157, 0x72, 0x327),
+    (0x158, 0x52, 0x30C),
+    (0x159, 0x72, 0x30C),
+    (0x15A, 0x53, 0x301),
+    (0x15B, 0x73, 0x301),
+    (0x15C, 0x53, 0x302),
+    (0x15D, 0x73, 0x302),
+    (0x15E, 0x53, 0x327),
+    (0x15F, 0x73, 0x327),
+    (0x160, 0x53, 0x30C),
+    (0x161, 0x73, 0x30C),
+    (0x162, 0x54, 0x327),
+    (0x163, 0x74, 0x327),
+    (0x164, 0x54, 0x30C),
+    (0x165, 0x74, 0x30C),
+    (0x168, 0x55, 0x303),
+    (0x169, 0x75, 0x303),
+    (0x16A, 0x55, 0x304),
+    (0x16B, 0x75, 0x304),
+    (0x16C, 0x55, 0x306),
+    (0x16D, 0x75, 0x306),
+    (0x16E, 0x55, 0x30A),
+    (0x16F, 0x75, 0x30A),
+    (0x170, 0x55, 0x30B),
+    (0x171, 0x75, 0x30B),
+    (0x172, 0x55, 0x328),
+    (0x173, 0x75, 0x328),
+    (0x174, 0x57, 0x302),
+    (0x175, 0x77, 0x302),
+    (0x176, 0x59, 0x302),
+    (0x177, 0x79, 0x302),
+    (0x178, 0x59, 0x308),
+    (0x179, 0x5A, 0x301),
+    (0x17A, 0x7A, 0x301),
+    (0x17B, 0x5A, 0x307),
+    (0x17C, 0x7A, 0x307),
+    (0x17D, 0x5A, 0x30C),
+    (0x17E, 0x7A, 0x30C),
+    (0x1A0, 0x4F, 0x31B),
+    (0x1A1, 0x6F, 0x31B),
+    (0x1AF, 0x55, 0x31B),
+    (0x1B0, 0x75, 0x31B),
+    (0x1CD, 0x41, 0x30C),
+    (0x1CE, 0x61, 0x30C),
+    (0x1CF, 0x49, 0x30C),
+    (0x1D0, 0x69, 0x30C),
+    (0x1D1, 0x4F, 0x30C),
+    (0x1D2, 0x6F, 0x30C),
+    (0x1D3, 0x55, 0x30C),
+    (0x1D4, 0x75, 0x30C),
+    (0x1D5, 0xDC, 0x304),
+    (0x1D6, 0xFC, 0x304),
+    (0x1D7, 0xDC, 0x301),
+    (0x1D8, 0xFC, 0x301),
+    (0x1D9, 0xDC, 0x30C),
+    (0x1DA, 0xFC, 0x30C),
+    (0x1DB, 0xDC, 0x300),
+    (0x1DC, 0xFC, 0x300),
+    (0x1DE, 0xC4, 0x304),
+    (0x1DF, 0xE4, 0x304),
+    (0x1E0, 0x226, 0x304),
+    (0x1E1, 0x227, 0x304),
+    (0x1E2, 0xC6, 0x304),
+    (0x1E3, 0xE6, 0x304),
+    (0x1E6, 0x47, 0x30C),
+    (0x1E7, 0x67, 0x30C),
+    (0x1E8, 0x4B, 0x30C),
+    (0x1E9, 0x6B, 0x30C),
+    (0x1EA, 0x4F, 0x328),
+    (0x1EB, 0x6F, 0x328),
+    (0x1EC, 0x1EA, 0x304),
+    (0x1ED, 0x1EB, 0x304),
+    (0x1EE, 0x1B7, 0x30C),
+    (0x1EF, 0x292, 0x30C),
+    (0x1F0, 0x6A, 0x30C),
+    (0x1F4, 0x47, 0x301),
+    (0x1F5, 0x67, 0x301),
+    (0x1F8, 0x4E, 0x300),
+    (0x1F9, 0x6E, 0x300),
+    (0x1FA, 0xC5, 0x301),
+    (0x1FB, 0xE5, 0x301),
+    (0x1FC, 0xC6, 0x301),
+    (0x1FD, 0xE6, 0x301),
+    (0x1FE, 0xD8, 0x301),
+    (0x1FF, 0xF8, 0x301),
+    (0x200, 0x41, 0x30F),
+    (0x201, 0x61, 0x30F),
+    (0x202, 0x41, 0x311),
+    (0x203, 0x61, 0x311),
+    (0x204, 0x45, 0x30F),
+    (0x205, 0x65, 0x30F),
+    (0x206, 0x45, 0x311),
+    (0x207, 0x65, 0x311),
+    (0x208, 0x49, 0x30F),
+    (0x209, 0x69, 0x30F),
+    (0x20A, 0x49, 0x311),
+    (0x20B, 0x69, 0x311),
+    (0x20C, 0x4F, 0x30F),
+    (0x20D, 0x6F, 0x30F),
+    (0x20E, 0x4F, 0x311),
+    (0x20F, 0x6F, 0x311),
+    (0x210, 0x52, 0x30F),
+    (0x211, 0x72, 0x30F),
+    (0x212, 0x52, 0x311),
+    (0x213, 0x72, 0x311),
+    (0x214, 0x55, 0x30F),
+    (0x215, 0x75, 0x30F),
+    (0x216, 0x55, 0x311),
+    (0x217, 0x75, 0x311),
+    (0x218, 0x53, 0x326),
+    (0x219, 0x73, 0x326),
+    (0x21A, 0x54, 0x326),
+    (0x21B, 0x74, 0x326),
+    (0x21E, 0x48, 0x30C),
+    (0x21F, 0x68, 0x30C),
+    (0x226, 0x41, 0x307),
+    (0x227, 0x61, 0x307),
+    (0x228, 0x45, 0x327),
+    (0x229, 0x65, 0x327),
+    (0x22A, 0xD6, 0x304),
+    (0x22B, 0xF6, 0x304),
+    (0x22C, 0xD5, 0x304),
+    (0x22D, 0xF5, 0x304),
+    (0x22E, 0x4F, 0x307),
+    (0x22F, 0x6F, 0x307),
+    (0x230, 0x22E, 0x304),
+    (0x231, 0x22F, 0x304),
+    (0x232, 0x59, 0x304),
+    (0x233, 0x79, 0x304),
+    (0x340, 0x300, 0x0),
+    (0x341, 0x301, 0x0),
+    (0x343, 0x313, 0x0),
+    (0x344, 0x308, 0x301),
+    (0x374, 0x2B9, 0x0),
+    (0x37E, 0x3B, 0x0),
+    (0x385, 0xA8, 0x301),
+    (0x386, 0x391, 0x301),
+    (0x387, 0xB7, 0x0),
+    (0x388, 0x395, 0x301),
+    (0x389, 0x397, 0x301),
+    (0x38A, 0x399, 0x301),
+    (0x38C, 0x39F, 0x301),
+    (0x38E, 0x3A5, 0x301),
+    (0x38F, 0x3A9, 0x301),
+    (0x390, 0x3CA, 0x301),
+    (0x3AA, 0x399, 0x308),
+    (0x3AB, 0x3A5, 0x308),
+    (0x3AC, 0x3B1, 0x301),
+    (0x3AD, 0x3B5, 0x301),
+    (0x3AE, 0x3B7, 0x301),
+    (0x3AF, 0x3B9, 0x301),
+    (0x3B0, 0x3CB, 0x301),
+    (0x3CA, 0x3B9, 0x308),
+    (0x3CB, 0x3C5, 0x308),
+    (0x3CC, 0x3BF, 0x301),
+    (0x3CD, 0x3C5, 0x301),
+    (0x3CE, 0x3C9, 0x301),
+    (0x3D3, 0x3D2, 0x301),
+    (0x3D4, 0x3D2, 0x308),
+    (0x400, 0x415, 0x300),
+    (0x401, 0x415, 0x308),
+    (0x403, 0x413, 0x301),
+    (0x407, 0x406, 0x308),
+    (0x40C, 0x41A, 0x301),
+    (0x40D, 0x418, 0x300),
+    (0x40E, 0x423, 0x306),
+    (0x419, 0x418, 0x306),
+    (0x439, 0x438, 0x306),
+    (0x450, 0x435, 0x300),
+    (0x451, 0x435, 0x308),
+    (0x453, 0x433, 0x301),
+    (0x457, 0x456, 0x308),
+    (0x45C, 0x43A, 0x301),
+    (0x45D, 0x438, 0x300),
+    (0x45E, 0x443, 0x306),
+    (0x476, 0x474, 0x30F),
+    (0x477, 0x475, 0x30F),
+    (0x4C1, 0x416, 0x306),
+    (0x4C2, 0x436, 0x306),
+    (0x4D0, 0x410, 0x306),
+    (0x4D1, 0x430, 0x306),
+    (0x4D2, 0x410, 0x308),
+    (0x4D3, 0x430, 0x308),
+    (0x4D6, 0x415, 0x306),
+    (0x4D7, 0x435, 0x306),
+    (0x4DA, 0x4D8, 0x308),
+    (0x4DB, 0x4D9, 0x308),
+    (0x4DC, 0x416, 0x308),
+    (0x4DD, 0x436, 0x308),
+    (0x4DE, 0x417, 0x308),
+    (0x4DF, 0x437, 0x308),
+    (0x4E2, 0x418, 0x304),
+    (0x4E3, 0x438, 0x304),
+    (0x4E4, 0x418, 0x308),
+    (0x4E5, 0x438, 0x308),
+    (0x4E6, 0x41E, 0x308),
+    (0x4E7, 0x43E, 0x308),
+    (0x4EA, 0x4E8, 0x308),
+    (0x4EB, 0x4E9, 0x308),
+    (0x4EC, 0x42D, 0x308),
+    (0x4ED, 0x44D, 0x308),
+    (0x4EE, 0x423, 0x304),
+    (0x4EF, 0x443, 0x304),
+    (0x4F0, 0x423, 0x308),
+    (0x4F1, 0x443, 0x308),
+    (0x4F2, 0x423, 0x30B),
+    (0x4F3, 0x443, 0x30B),
+    (0x4F4, 0x427, 0x308),
+    (0x4F5, 0x447, 0x308),
+    (0x4F8, 0x42B, 0x308),
+    (0x4F9, 0x44B, 0x308),
+    (0x622, 0x627, 0x653),
+    (0x623, 0x627, 0x654),
+    (0x624, 0x648, 0x654),
+    (0x625, 0x627, 0x655),
+    (0x626, 0x64A, 0x654),
+    (0x6C0, 0x6D5, 0x654),
+    (0x6C2, 0x6C1, 0x654),
+    (0x6D3, 0x6D2, 0x654),
+    (0x929, 0x928, 0x93C),
+    (0x931, 0x930, 0x93C),
+    (0x934, 0x933, 0x93C),
+    (0x958, 0x915, 0x93C),
+    (0x959, 0x916, 0x93C),
+    (0x95A, 0x917, 0x93C),
+    (0x95B, 0x91C, 0x93C),
+    (0x95C, 0x921, 0x93C),
+    (0x95D, 0x922, 0x93C),
+    (0x95E, 0x92B, 0x93C),
+    (0x95F, 0x92F, 0x93C),
+    (0x9CB, 0x9C7, 0x9BE),
+    (0x9CC, 0x9C7, 0x9D7),
+    (0x9DC, 0x9A1, 0x9BC),
+    (0x9DD, 0x9A2, 0x9BC),
+    (0x9DF, 0x9AF, 0x9BC),
+    (0xA33, 0xA32, 0xA3C),
+    (0xA36, 0xA38, 0xA3C),
+    (0xA59, 0xA16, 0xA3C),
+    (0xA5A, 0xA17, 0xA3C),
+    (0xA5B, 0xA1C, 0xA3C),
+    (0xA5E, 0xA2B, 0xA3C),
+    (0xB48, 0xB47, 0xB56),
+    (0xB4B, 0xB47, 0xB3E),
+    (0xB4C, 0xB47, 0xB57),
+    (0xB5C, 0xB21, 0xB3C),
+    (0xB5D, 0xB22, 0xB3C),
+    (0xB94, 0xB92, 0xBD7),
+    (0xBCA, 0xBC6, 0xBBE),
+    (0xBCB, 0xBC7, 0xBBE),
+    (0xBCC, 0xBC6, 0xBD7),
+    (0xC48, 0xC46, 0xC56),
+    (0xCC0, 0xCBF, 0xCD5),
+    (0xCC7, 0xCC6, 0xCD5),
+    (0xCC8, 0xCC6, 0xCD6),
+    (0xCCA, 0xCC6, 0xCC2),
+    (0xCCB, 0xCCA, 0xCD5),
+    (0xD4A, 0xD46, 0xD3E),
+    (0xD4B, 0xD47, 0xD3E),
+    (0xD4C, 0xD46, 0xD57),
+    (0xDDA, 0xDD9, 0xDCA),
+    (0xDDC, 0xDD9, 0xDCF),
+    (0xDDD, 0xDDC, 0xDCA),
+    (0xDDE, 0xDD9, 0xDDF),
+    (0xF43, 0xF42, 0xFB7),
+    (0xF4D, 0xF4C, 0xFB7),
+    (0xF52, 0xF51, 0xFB7),
+    (0xF57, 0xF56, 0xFB7),
+    (0xF5C, 0xF5B, 0xFB7),
+    (0xF69, 0xF40, 0xFB5),
+    (0xF73, 0xF71, 0xF72),
+    (0xF75, 0xF71, 0xF74),
+    (0xF76, 0xFB2, 0xF80),
+    (0xF78, 0xFB3, 0xF80),
+    (0xF81, 0xF71, 0xF80),
+    (0xF93, 0xF92, 0xFB7),
+    (0xF9D, 0xF9C, 0xFB7),
+    (0xFA2, 0xFA1, 0xFB7),
+    (0xFA7, 0xFA6, 0xFB7),
+    (0xFAC, 0xFAB, 0xFB7),
+    (0xFB9, 0xF90, 0xFB5),
+    (0x1026, 0x1025, 0x102E),
+    (0x1B06, 0x1B05, 0x1B35),
+    (0x1B08, 0x1B07, 0x1B35),
+    (0x1B0A, 0x1B09, 0x1B35),
+    (0x1B0C, 0x1B0B, 0x1B35),
+    (0x1B0E, 0x1B0D, 0x1B35),
+    (0x1B12, 0x1B11, 0x1B35),
+    (0x1B3B, 0x1B3A, 0x1B35),
+    (0x1B3D, 0x1B3C, 0x1B35),
+    (0x1B40, 0x1B3E, 0x1B35),
+    (0x1B41, 0x1B3F, 0x1B35),
+    (0x1B43, 0x1B42, 0x1B35),
+    (0x1E00, 0x41, 0x325),
+    (0x1E01, 0x61, 0x325),
+    (0x1E02, 0x42, 0x307),
+    (0x1E03, 0x62, 0x307),
+    (0x1E04, 0x42, 0x323),
+    (0x1E05, 0x62, 0x323),
+    (0x1E06, 0x42, 0x331),
+    (0x1E07, 0x62, 0x331),
+    (0x1E08, 0xC7, 0x301),
+    (0x1E09, 0xE7, 0x301),
+    (0x1E0A, 0x44, 0x307),
+    (0x1E0B, 0x64, 0x307),
+    (0x1E0C, 0x44, 0x323),
+    (0x1E0D, 0x64, 0x323),
+    (0x1E0E, 0x44, 0x331),
+    (0x1E0F, 0x64, 0x331),
+    (0x1E10, 0x44, 0x327),
+    (0x1E11, 0x64, 0x327),
+    (0x1E12, 0x44, 0x32D),
+    (0x1E13, 0x64, 0x32D),
+    (0x1E14, 0x112, 0x300),
+    (0x1E15, 0x113, 0x300),
+    (0x1E16, 0x112, 0x301),
+    (0x1E17, 0x113, 0x301),
+    (0x1E18, 0x45, 0x32D),
+    (0x1E19, 0x65, 0x32D),
+    (0x1E1A, 0x45, 0x330),
+    (0x1E1B, 0x65, 0x330),
+    (0x1E1C, 0x228, 0x306),
+    (0x1E1D, 0x229, 0x306),
+    (0x1E1E, 0x46, 0x307),
+    (0x1E1F, 0x66, 0x307),
+    (0x1E20, 0x47, 0x304),
+    (0x1E21, 0x67, 0x304),
+    (0x1E22, 0x48, 0x307),
+    (0x1E23, 0x68, 0x307),
+    (0x1E24, 0x48, 0x323),
+    (0x1E25, 0x68, 0x323),
+    (0x1E26, 0x48, 0x308),
+    (0x1E27, 0x68, 0x308),
+    (0x1E28, 0x48, 0x327),
+    (0x1E29, 0x68, 0x327),
+    (0x1E2A, 0x48, 0x32E),
+    (0x1E2B, 0x68, 0x32E),
+    (0x1E2C, 0x49, 0x330),
+    (0x1E2D, 0x69, 0x330),
+    (0x1E2E, 0xCF, 0x301),
+    (0x1E2F, 0xEF, 0x301),
+    (0x1E30, 0x4B, 0x301),
+    (0x1E31, 0x6B, 0x301),
+    (0x1E32, 0x4B, 0x323),
+    (0x1E33, 0x6B, 0x323),
+    (0x1E34, 0x4B, 0x331),
+    (0x1E35, 0x6B, 0x331),
+    (0x1E36, 0x4C, 0x323),
+    (0x1E37, 0x6C, 0x323),
+    (0x1E38, 0x1E36, 0x304),
+    (0x1E39, 0x1E37, 0x304),
+    (0x1E3A, 0x4C, 0x331),
+    (0x1E3B, 0x6C, 0x331),
+    (0x1E3C, 0x4C, 0x32D),
+    (0x1E3D, 0x6C, 0x32D),
+    (0x1E3E, 0x4D, 0x301),
+    (0x1E3F, 0x6D, 0x301),
+    (0x1E40, 0x4D, 0x307),
+    (0x1E41, 0x6D, 0x307),
+    (0x1E42, 0x4D, 0x323),
+    (0x1E43, 0x6D, 0x323),
+    (0x1E44, 0x4E, 0x307),
+    (0x1E45, 0x6E, 0x307),
+    (0x1E46, 0x4E, 0x323),
+    (0x1E47, 0x6E, 0x323),
+    (0x1E48, 0x4E, 0x331),
+    (0x1E49, 0x6E, 0x331),
+    (0x1E4A, 0x4E, 0x32D),
+    (0x1E4B, 0x6E, 0x32D),
+    (0x1E4C, 0xD5, 0x301),
+    (0x1E4D, 0xF5, 0x301),
+    (0x1E4E, 0xD5, 0x308),
+    (0x1E4F, 0xF5, 0x308),
+    (0x1E50, 0x14C, 0x300),
+    (0x1E51, 0x14D, 0x300),
+    (0x1E52, 0x14C, 0x301),
+    (0x1E53, 0x14D, 0x301),
+    (0x1E54, 0x50, 0x301),
+    (0x1E55, 0x70, 0x301),
+    (0x1E56, 0x50, 0x307),
+    (0x1E57, 0x70, 0x307),
+    (0x1E58, 0x52, 0x307),
+    (0x1E59, 0x72, 0x307),
+    (0x1E5A, 0x52, 0x323),
+    (0x1E5B, 0x72, 0x323),
+    (0x1E5C, 0x1E5A, 0x304),
+    (0x1E5D, 0x1E5B, 0x304),
+    (0x1E5E, 0x52, 0x331),
+    (0x1E5F, 0x72, 0x331),
+    (0x1E60, 0x53, 0x307),
+    (0x1E61, 0x73, 0x307),
+    (0x1E62, 0x53, 0x323),
+    (0x1E63, 0x73, 0x323),
+    (0x1E64, 0x15A, 0x307),
+    (0x1E65, 0x15B, 0x307),
+    (0x1E66, 0x160, 0x307),
+    (0x1E67, 0x161, 0x307),
+    (0x1E68, 0x1E62, 0x307),
+    (0x1E69, 0x1E63, 0x307),
+    (0x1E6A, 0x54, 0x307),
+    (0x1E6B, 0x74, 0x307),
+    (0x1E6C, 0x54, 0x323),
+    (0x1E6D, 0x74, 0x323),
+    (0x1E6E, 0x54, 0x331),
+    (0x1E6F, 0x74, 0x331),
+    (0x1E70, 0x54, 0x32D),
+    (0x1E71, 0x74, 0x32D),
+    (0x1E72, 0x55, 0x324),
+    (0x1E73, 0x75, 0x324),
+    (0x1E74, 0x55, 0x330),
+    (0x1E75, 0x75, 0x330),
+    (0x1E76, 0x55, 0x32D),
+    (0x1E77, 0x75, 0x32D),
+    (0x1E78, 0x168, 0x301),
+    (0x1E79, 0x169, 0x301),
+    (0x1E7A, 0x16A, 0x308),
+    (0x1E7B, 0x16B, 0x308),
+    (0x1E7C, 0x56, 0x303),
+    (0x1E7D, 0x76, 0x303),
+    (0x1E7E, 0x56, 0x323),
+    (0x1E7F, 0x76, 0x323),
+    (0x1E80, 0x57, 0x300),
+    (0x1E81, 0x77, 0x300),
+    (0x1E82, 0x57, 0x301),
+    (0x1E83, 0x77, 0x301),
+    (0x1E84, 0x57, 0x308),
+    (0x1E85, 0x77, 0x308),
+    (0x1E86, 0x57, 0x307),
+    (0x1E87, 0x77, 0x307),
+    (0x1E88, 0x57, 0x323),
+    (0x1E89, 0x77, 0x323),
+    (0x1E8A, 0x58, 0x307),
+    (0x1E8B, 0x78, 0x307),
+    (0x1E8C, 0x58, 0x308),
+    (0x1E8D, 0x78, 0x308),
+    (0x1E8E, 0x59, 0x307),
+    (0x1E8F, 0x79, 0x307),
+    (0x1E90, 0x5A, 0x302),
+    (0x1E91, 0x7A, 0x302),
+    (0x1E92, 0x5A, 0x323),
+    (0x1E93, 0x7A, 0x323),
+    (0x1E94, 0x5A, 0x331),
+    (0x1E95, 0x7A, 0x331),
+    (0x1E96, 0x68, 0x331),
+    (0x1E97, 0x74, 0x308),
+    (0x1E98, 0x77, 0x30A),
+    (0x1E99, 0x79, 0x30A),
+    (0x1E9B, 0x17F, 0x307),
+    (0x1EA0, 0x41, 0x323),
+    (0x1EA1, 0x61, 0x323),
+    (0x1EA2, 0x41, 0x309),
+    (0x1EA3, 0x61, 0x309),
+    (0x1EA4, 0xC2, 0x301),
+    (0x1EA5, 0xE2, 0x301),
+    (0x1EA6, 0xC2, 0x300),
+    (0x1EA7, 0xE2, 0x300),
+    (0x1EA8, 0xC2, 0x309),
+    (0x1EA9, 0xE2, 0x309),
+    (0x1EAA, 0xC2, 0x303),
+    (0x1EAB, 0xE2, 0x303),
+    (0x1EAC, 0x1EA0, 0x302),
+    (0x1EAD, 0x1EA1, 0x302),
+    (0x1EAE, 0x102, 0x301),
+    (0x1EAF, 0x103, 0x301),
+    (0x1EB0, 0x102, 0x300),
+    (0x1EB1, 0x103, 0x300),
+    (0x1EB2, 0x102, 0x309),
+    (0x1EB3, 0x103, 0x309),
+    (0x1EB4, 0x102, 0x303),
+    (0x1EB5, 0x103, 0x303),
+    (0x1EB6, 0x1EA0, 0x306),
+    (0x1EB7, 0x1EA1, 0x306),
+    (0x1EB8, 0x45, 0x323),
+    (0x1EB9, 0x65, 0x323),
+    (0x1EBA, 0x45, 0x309),
+    (0x1EBB, 0x65, 0x309),
+    (0x1EBC, 0x45, 0x303),
+    (0x1EBD, 0x65, 0x303),
+    (0x1EBE, 0xCA, 0x301),
+    (0x1EBF, 0xEA, 0x301),
+    (0x1EC0, 0xCA, 0x300),
+    (0x1EC1, 0xEA, 0x300),
+    (0x1EC2, 0xCA, 0x309),
+    (0x1EC3, 0xEA, 0x309),
+    (0x1EC4, 0xCA, 0x303),
+    (0x1EC5, 0xEA, 0x303),
+    (0x1EC6, 0x1EB8, 0x302),
+    (0x1EC7, 0x1EB9, 0x302),
+    (0x1EC8, 0x49, 0x309),
+    (0x1EC9, 0x69, 0x309),
+    (0x1ECA, 0x49, 0x323),
+    (0x1ECB, 0x69, 0x323),
+    (0x1ECC, 0x4F, 0x323),
+    (0x1ECD, 0x6F, 0x323),
+    (0x1ECE, 0x4F, 0x309),
+    (0x1ECF, 0x6F, 0x309),
+    (0x1ED0, 0xD4, 0x301),
+    (0x1ED1, 0xF4, 0x301),
+    (0x1ED2, 0xD4, 0x300),
+    (0x1ED3, 0xF4, 0x300),
+    (0x1ED4, 0xD4, 0x309),
+    (0x1ED5, 0xF4, 0x309),
+    (0x1ED6, 0xD4, 0x303),
+    (0x1ED7, 0xF4, 0x303),
+    (0x1ED8, 0x1ECC, 0x302),
+    (0x1ED9, 0x1ECD, 0x302),
+    (0x1EDA, 0x1A0, 0x301),
+    (0x1EDB, 0x1A1, 0x301),
+    (0x1EDC, 0x1A0, 0x300),
+    (0x1EDD, 0x1A1, 0x300),
+    (0x1EDE, 0x1A0, 0x309),
+    (0x1EDF, 0x1A1, 0x309),
+    (0x1EE0, 0x1A0, 0x303),
+    (0x1EE1, 0x1A1, 0x303),
+    (0x1EE2, 0x1A0, 0x323),
+    (0x1EE3, 0x1A1, 0x323),
+    (0x1EE4, 0x55, 0x323),
+    (0x1EE5, 0x75, 0x323),
+    (0x1EE6, 0x55, 0x309),
+    (0x1EE7, 0x75, 0x309),
+    (0x1EE8, 0x1AF, 0x301),
+    (0x1EE9, 0x1B0, 0x301),
+    (0x1EEA, 0x1AF, 0x300),
+    (0x1EEB, 0x1B0, 0x300),
+    (0x1EEC, 0x1AF, 0x309),
+    (0x1EED, 0x1B0, 0x309),
+    (0x1EEE, 0x1AF, 0x303),
+    (0x1EEF, 0x1B0, 0x303),
+    (0x1EF0, 0x1AF, 0x323),
+    (0x1EF1, 0x1B0, 0x323),
+    (0x1EF2, 0x59, 0x300),
+    (0x1EF3, 0x79, 0x300),
+    (0x1EF4, 0x59, 0x323),
+    (0x1EF5, 0x79, 0x323),
+    (0x1EF6, 0x59, 0x309),
+    (0x1EF7, 0x79, 0x309),
+    (0x1EF8, 0x59, 0x303),
+    (0x1EF9, 0x79, 0x303),
+    (0x1F00, 0x3B1, 0x313),
+    (0x1F01, 0x3B1, 0x314),
+    (0x1F02, 0x1F00, 0x300),
+    (0x1F03, 0x1F01, 0x300),
+    (0x1F04, 0x1F00, 0x301),
+    (0x1F05, 0x1F01, 0x301),
+    (0x1F06, 0x1F00, 0x342),
+    (0x1F07, 0x1F01, 0x342),
+    (0x1F08, 0x391, 0x313),
+    (0x1F09, 0x391, 0x314),
+    (0x1F0A, 0x1F08, 0x300),
+    (0x1F0B, 0x1F09, 0x300),
+    (0x1F0C, 0x1F08, 0x301),
+    (0x1F0D, 0x1F09, 0x301),
+    (0x1F0E, 0x1F08, 0x342),
+    (0x1F0F, 0x1F09, 0x342),
+    (0x1F10, 0x3B5, 0x313),
+    (0x1F11, 0x3B5, 0x314),
+    (0x1F12, 0x1F10, 0x300),
+    (0x1F13, 0x1F11, 0x300),
+    (0x1F14, 0x1F10, 0x301),
+    (0x1F15, 0x1F11, 0x301),
+    (0x1F18, 0x395, 0x313),
+    (0x1F19, 0x395, 0x314),
+    (0x1F1A, 0x1F18, 0x300),
+    (0x1F1B, 0x1F19, 0x300),
+    (0x1F1C, 0x1F18, 0x301),
+    (0x1F1D, 0x1F19, 0x301),
+    (0x1F20, 0x3B7, 0x313),
+    (0x1F21, 0x3B7, 0x314),
+    (0x1F22, 0x1F20, 0x300),
+    (0x1F23, 0x1F21, 0x300),
+    (0x1F24, 0x1F20, 0x301),
+    (0x1F25, 0x1F21, 0x301),
+    (0x1F26, 0x1F20, 0x342),
+    (0x1F27, 0x1F21, 0x342),
+    (0x1F28, 0x397, 0x313),
+    (0x1F29, 0x397, 0x314),
+    (0x1F2A, 0x1F28, 0x300),
+    (0x1F2B, 0x1F29, 0x300),
+    (0x1F2C, 0x1F28, 0x301),
+    (0x1F2D, 0x1F29, 0x301),
+    (0x1F2E, 0x1F28, 0x342),
+    (0x1F2F, 0x1F29, 0x342),
+    (0x1F30, 0x3B9, 0x313),
+    (0x1F31, 0x3B9, 0x314),
+    (0x1F32, 0x1F30, 0x300),
+    (0x1F33, 0x1F31, 0x300),
+    (0x1F34, 0x1F30, 0x301),
+    (0x1F35, 0x1F31, 0x301),
+    (0x1F36, 0x1F30, 0x342),
+    (0x1F37, 0x1F31, 0x342),
+    (0x1F38, 0x399, 0x313),
+    (0x1F39, 0x399, 0x314),
+    (0x1F3A, 0x1F38, 0x300),
+    (0x1F3B, 0x1F39, 0x300),
+    (0x1F3C, 0x1F38, 0x301),
+    (0x1F3D, 0x1F39, 0x301),
+    (0x1F3E, 0x1F38, 0x342),
+    (0x1F3F, 0x1F39, 0x342),
+    (0x1F40, 0x3BF, 0x313),
+    (0x1F41, 0x3BF, 0x314),
+    (0x1F42, 0x1F40, 0x300),
+    (0x1F43, 0x1F41, 0x300),
+    (0x1F44, 0x1F40, 0x301),
+    (0x1F45, 0x1F41, 0x301),
+    (0x1F48, 0x39F, 0x313),
+    (0x1F49, 0x39F, 0x314),
+    (0x1F4A, 0x1F48, 0x300),
+    (0x1F4B, 0x1F49, 0x300),
+    (0x1F4C, 0x1F48, 0x301),
+    (0x1F4D, 0x1F49, 0x301),
+    (0x1F50, 0x3C5, 0x313),
+    (0x1F51, 0x3C5, 0x314),
+    (0x1F52, 0x1F50, 0x300),
+    (0x1F53, 0x1F51, 0x300),
+    (0x1F54, 0x1F50, 0x301),
+    (0x1F55, 0x1F51, 0x301),
+    (0x1F56, 0x1F50, 0x342),
+    (0x1F57, 0x1F51, 0x342),
+    (0x1F59, 0x3A5, 0x314),
+    (0x1F5B, 0x1F59, 0x300),
+    (0x1F5D, 0x1F59, 0x301),
+    (0x1F5F, 0x1F59, 0x342),
+    (0x1F60, 0x3C9, 0x313),
+    (0x1F61, 0x3C9, 0x314),
+    (0x1F62, 0x1F60, 0x300),
+    (0x1F63, 0x1F61, 0x300),
+    (0x1F64, 0x1F60, 0x301),
+    (0x1F65, 0x1F61, 0x301),
+    (0x1F66, 0x1F60, 0x342),
+    (0x1F67, 0x1F61, 0x342),
+    (0x1F68, 0x3A9, 0x313),
+    (0x1F69, 0x3A9, 0x314),
+    (0x1F6A, 0x1F68, 0x300),
+    (0x1F6B, 0x1F69, 0x300),
+    (0x1F6C, 0x1F68, 0x301),
+    (0x1F6D, 0x1F69, 0x301),
+    (0x1F6E, 0x1F68, 0x342),
+    (0x1F6F, 0x1F69, 0x342),
+    (0x1F70, 0x3B1, 0x300),
+    (0x1F71, 0x3AC, 0x0),
+    (0x1F72, 0x3B5, 0x300),
+    (0x1F73, 0x3AD, 0x0),
+    (0x1F74, 0x3B7, 0x300),
+    (0x1F75, 0x3AE, 0x0),
+    (0x1F76, 0x3B9, 0x300),
+    (0x1F77, 0x3AF, 0x0),
+    (0x1F78, 0x3BF, 0x300),
+    (0x1F79, 0x3CC, 0x0),
+    (0x1F7A, 0x3C5, 0x300),
+    (0x1F7B, 0x3CD, 0x0),
+    (0x1F7C, 0x3C9, 0x300),
+    (0x1F7D, 0x3CE, 0x0),
+    (0x1F80, 0x1F00, 0x345),
+    (0x1F81, 0x1F01, 0x345),
+    (0x1F82, 0x1F02, 0x345),
+    (0x1F83, 0x1F03, 0x345),
+    (0x1F84, 0x1F04, 0x345),
+    (0x1F85, 0x1F05, 0x345),
+    (0x1F86, 0x1F06, 0x345),
+    (0x1F87, 0x1F07, 0x345),
+    (0x1F88, 0x1F08, 0x345),
+    (0x1F89, 0x1F09, 0x345),
+    (0x1F8A, 0x1F0A, 0x345),
+    (0x1F8B, 0x1F0B, 0x345),
+    (0x1F8C, 0x1F0C, 0x345),
+    (0x1F8D, 0x1F0D, 0x345),
+    (0x1F8E, 0x1F0E, 0x345),
+    (0x1F8F, 0x1F0F, 0x345),
+    (0x1F90, 0x1F20, 0x345),
+    (0x1F91, 0x1F21, 0x345),
+    (0x1F92, 0x1F22, 0x345),
+    (0x1F93, 0x1F23, 0x345),
+    (0x1F94, 0x1F24, 0x345),
+    (0x1F95, 0x1F25, 0x345),
+    (0x1F96, 0x1F26, 0x345),
+    (0x1F97, 0x1F27, 0x345),
+    (0x1F98, 0x1F28, 0x345),
+    (0x1F99, 0x1F29, 0x345),
+    (0x1F9A, 0x1F2A, 0x345),
+    (0x1F9B, 0x1F2B, 0x345),
+    (0x1F9C, 0x1F2C, 0x345),
+    (0x1F9D, 0x1F2D, 0x345),
+    (0x1F9E, 0x1F2E, 0x345),
+    (0x1F9F, 0x1F2F, 0x345),
+    (0x1FA0, 0x1F60, 0x345),
+    (0x1FA1, 0x1F61, 0x345),
+    (0x1FA2, 0x1F62, 0x345),
+    (0x1FA3, 0x1F63, 0x345),
+    (0x1FA4, 0x1F64, 0x345),
+    (0x1FA5, 0x1F65, 0x345),
+    (0x1FA6, 0x1F66, 0x345),
+    (0x1FA7, 0x1F67, 0x345),
+    (0x1FA8, 0x1F68, 0x345),
+    (0x1FA9, 0x1F69, 0x345),
+    (0x1FAA, 0x1F6A, 0x345),
+    (0x1FAB, 0x1F6B, 0x345),
+    (0x1FAC, 0x1F6C, 0x345),
+    (0x1FAD, 0x1F6D, 0x345),
+    (0x1FAE, 0x1F6E, 0x345),
+    (0x1FAF, 0x1F6F, 0x345),
+    (0x1FB0, 0x3B1, 0x306),
+    (0x1FB1, 0x3B1, 0x304),
+    (0x1FB2, 0x1F70, 0x345),
+    (0x1FB3, 0x3B1, 0x345),
+    (0x1FB4, 0x3AC, 0x345),
+    (0x1FB6, 0x3B1, 0x342),
+    (0x1FB7, 0x1FB6, 0x345),
+    (0x1FB8, 0x391, 0x306),
+    (0x1FB9, 0x391, 0x304),
+    (0x1FBA, 0x391, 0x300),
+    (0x1FBB, 0x386, 0x0),
+    (0x1FBC, 0x391, 0x345),
+    (0x1FBE, 0x3B9, 0x0),
+    (0x1FC1, 0xA8, 0x342),
+    (0x1FC2, 0x1F74, 0x345),
+    (0x1FC3, 0x3B7, 0x345),
+    (0x1FC4, 0x3AE, 0x345),
+    (0x1FC6, 0x3B7, 0x342),
+    (0x1FC7, 0x1FC6, 0x345),
+    (0x1FC8, 0x395, 0x300),
+    (0x1FC9, 0x388, 0x0),
+    (0x1FCA, 0x397, 0x300),
+    (0x1FCB, 0x389, 0x0),
+    (0x1FCC, 0x397, 0x345),
+    (0x1FCD, 0x1FBF, 0x300),
+    (0x1FCE, 0x1FBF, 0x301),
+    (0x1FCF, 0x1FBF, 0x342),
+    (0x1FD0, 0x3B9, 0x306),
+    (0x1FD1, 0x3B9, 0x304),
+    (0x1FD2, 0x3CA, 0x300),
+    (0x1FD3, 0x390, 0x0),
+    (0x1FD6, 0x3B9, 0x342),
+    (0x1FD7, 0x3CA, 0x342),
+    (0x1FD8, 0x399, 0x306),
+    (0x1FD9, 0x399, 0x304),
+    (0x1FDA, 0x399, 0x300),
+    (0x1FDB, 0x38A, 0x0),
+    (0x1FDD, 0x1FFE, 0x300),
+    (0x1FDE, 0x1FFE, 0x301),
+    (0x1FDF, 0x1FFE, 0x342),
+    (0x1FE0, 0x3C5, 0x306),
+    (0x1FE1, 0x3C5, 0x304),
+    (0x1FE2, 0x3CB, 0x300),
+    (0x1FE3, 0x3B0, 0x0),
+    (0x1FE4, 0x3C1, 0x313),
+    (0x1FE5, 0x3C1, 0x314),
+    (0x1FE6, 0x3C5, 0x342),
+    (0x1FE7, 0x3CB, 0x342),
+    (0x1FE8, 0x3A5, 0x306),
+    (0x1FE9, 0x3A5, 0x304),
+    (0x1FEA, 0x3A5, 0x300),
+    (0x1FEB, 0x38E, 0x0),
+    (0x1FEC, 0x3A1, 0x314),
+    (0x1FED, 0xA8, 0x300),
+    (0x1FEE, 0x385, 0x0),
+    (0x1FEF, 0x60, 0x0),
+    (0x1FF2, 0x1F7C, 0x345),
+    (0x1FF3, 0x3C9, 0x345),
+    (0x1FF4, 0x3CE, 0x345),
+    (0x1FF6, 0x3C9, 0x342),
+    (0x1FF7, 0x1FF6, 0x345),
+    (0x1FF8, 0x39F, 0x300),
+    (0x1FF9, 0x38C, 0x0),
+    (0x1FFA, 0x3A9, 0x300),
+    (0x1FFB, 0x38F, 0x0),
+    (0x1FFC, 0x3A9, 0x345),
+    (0x1FFD, 0xB4, 0x0),
+    (0x2000, 0x2002, 0x0),
+    (0x2001, 0x2003, 0x0),
+    (0x2126, 0x3A9, 0x0),
+    (0x212A, 0x4B, 0x0),
+    (0x212B, 0xC5, 0x0),
+    (0x219A, 0x2190, 0x338),
+    (0x219B, 0x2192, 0x338),
+    (0x21AE, 0x2194, 0x338),
+    (0x21CD, 0x21D0, 0x338),
+    (0x21CE, 0x21D4, 0x338),
+    (0x21CF, 0x21D2, 0x338),
+    (0x2204, 0x2203, 0x338),
+    (0x2209, 0x2208, 0x338),
+    (0x220C, 0x220B, 0x338),
+    (0x2224, 0x2223, 0x338),
+    (0x2226, 0x2225, 0x338),
+    (0x2241, 0x223C, 0x338),
+    (0x2244, 0x2243, 0x338),
+    (0x2247, 0x2245, 0x338),
+    (0x2249, 0x2248, 0x338),
+    (0x2260, 0x3D, 0x338),
+    (0x2262, 0x2261, 0x338),
+    (0x226D, 0x224D, 0x338),
+    (0x226E, 0x3C, 0x338),
+    (0x226F, 0x3E, 0x338),
+    (0x2270, 0x2264, 0x338),
+    (0x2271, 0x2265, 0x338),
+    (0x2274, 0x2272, 0x338),
+    (0x2275, 0x2273, 0x338),
+    (0x2278, 0x2276, 0x338),
+    (0x2279, 0x2277, 0x338),
+    (0x2280, 0x227A, 0x338),
+    (0x2281, 0x227B, 0x338),
+    (0x2284, 0x2282, 0x338),
+    (0x2285, 0x2283, 0x338),
+    (0x2288, 0x2286, 0x338),
+    (0x2289, 0x2287, 0x338),
+    (0x22AC, 0x22A2, 0x338),
+    (0x22AD, 0x22A8, 0x338),
+    (0x22AE, 0x22A9, 0x338),
+    (0x22AF, 0x22AB, 0x338),
+    (0x22E0, 0x227C, 0x338),
+    (0x22E1, 0x227D, 0x338),
+    (0x22E2, 0x2291, 0x338),
+    (0x22E3, 0x2292, 0x338),
+    (0x22EA, 0x22B2, 0x338),
+    (0x22EB, 0x22B3, 0x338),
+    (0x22EC, 0x22B4, 0x338),
+    (0x22ED, 0x22B5, 0x338),
+    (0x2329, 0x3008, 0x0),
+    (0x232A, 0x3009, 0x0),
+    (0x2ADC, 0x2ADD, 0x338),
+    (0x304C, 0x304B, 0x3099),
+    (0x304E, 0x304D, 0x3099),
+    (0x3050, 0x304F, 0x3099),
+    (0x3052, 0x3051, 0x3099),
+    (0x3054, 0x3053, 0x3099),
+    (0x3056, 0x3055, 0x3099),
+    (0x3058, 0x3057, 0x3099),
+    (0x305A, 0x3059, 0x3099),
+    (0x305C, 0x305B, 0x3099),
+    (0x305E, 0x305D, 0x3099),
+    (0x3060, 0x305F, 0x3099),
+    (0x3062, 0x3061, 0x3099),
+    (0x3065, 0x3064, 0x3099),
+    (0x3067, 0x3066, 0x3099),
+    (0x3069, 0x3068, 0x3099),
+    (0x3070, 0x306F, 0x3099),
+    (0x3071, 0x306F, 0x309A),
+    (0x3073, 0x3072, 0x3099),
+    (0x3074, 0x3072, 0x309A),
+    (0x3076, 0x3075, 0x3099),
+    (0x3077, 0x3075, 0x309A),
+    (0x3079, 0x3078, 0x3099),
+    (0x307A, 0x3078, 0x309A),
+    (0x307C, 0x307B, 0x3099),
+    (0x307D, 0x307B, 0x309A),
+    (0x3094, 0x3046, 0x3099),
+    (0x309E, 0x309D, 0x3099),
+    (0x30AC, 0x30AB, 0x3099),
+    (0x30AE, 0x30AD, 0x3099),
+    (0x30B0, 0x30AF, 0x3099),
+    (0x30B2, 0x30B1, 0x3099),
+    (0x30B4, 0x30B3, 0x3099),
+    (0x30B6, 0x30B5, 0x3099),
+    (0x30B8, 0x30B7, 0x3099),
+    (0x30BA, 0x30B9, 0x3099),
+    (0x30BC, 0x30BB, 0x3099),
+    (0x30BE, 0x30BD, 0x3099),
+    (0x30C0, 0x30BF, 0x3099),
+    (0x30C2, 0x30C1, 0x3099),
+    (0x30C5, 0x30C4, 0x3099),
+    (0x30C7, 0x30C6, 0x3099),
+    (0x30C9, 0x30C8, 0x3099),
+    (0x30D0, 0x30CF, 0x3099),
+    (0x30D1, 0x30CF, 0x309A),
+    (0x30D3, 0x30D2, 0x3099),
+    (0x30D4, 0x30D2, 0x309A),
+    (0x30D6, 0x30D5, 0x3099),
+    (0x30D7, 0x30D5, 0x309A),
+    (0x30D9, 0x30D8, 0x3099),
+    (0x30DA, 0x30D8, 0x309A),
+    (0x30DC, 0x30DB, 0x3099),
+    (0x30DD, 0x30DB, 0x309A),
+    (0x30F4, 0x30A6, 0x3099),
+    (0x30F7, 0x30EF, 0x3099),
+    (0x30F8, 0x30F0, 0x3099),
+    (0x30F9, 0x30F1, 0x3099),
+    (0x30FA, 0x30F2, 0x3099),
+    (0x30FE, 0x30FD, 0x3099),
+    (0xF900, 0x8C48, 0x0),
+    (0xF901, 0x66F4, 0x0),
+    (0xF902, 0x8ECA, 0x0),
+    (0xF903, 0x8CC8, 0x0),
+    (0xF904, 0x6ED1, 0x0),
+    (0xF905, 0x4E32, 0x0),
+    (0xF906, 0x53E5, 0x0),
+    (0xF907, 0x9F9C, 0x0),
+    (0xF908, 0x9F9C, 0x0),
+    (0xF909, 0x5951, 0x0),
+    (0xF90A, 0x91D1, 0x0),
+    (0xF90B, 0x5587, 0x0),
+    (0xF90C, 0x5948, 0x0),
+    (0xF90D, 0x61F6, 0x0),
+    (0xF90E, 0x7669, 0x0),
+    (0xF90F, 0x7F85, 0x0),
+    (0xF910, 0x863F, 0x0),
+    (0xF911, 0x87BA, 0x0),
+    (0xF912, 0x88F8, 0x0),
+    (0xF913, 0x908F, 0x0),
+    (0xF914, 0x6A02, 0x0),
+    (0xF915, 0x6D1B, 0x0),
+    (0xF916, 0x70D9, 0x0),
+    (0xF917, 0x73DE, 0x0),
+    (0xF918, 0x843D, 0x0),
+    (0xF919, 0x916A, 0x0),
+    (0xF91A, 0x99F1, 0x0),
+    (0xF91B, 0x4E82, 0x0),
+    (0xF91C, 0x5375, 0x0),
+    (0xF91D, 0x6B04, 0x0),
+    (0xF91E, 0x721B, 0x0),
+    (0xF91F, 0x862D, 0x0),
+    (0xF920, 0x9E1E, 0x0),
+    (0xF921, 0x5D50, 0x0),
+    (0xF922, 0x6FEB, 0x0),
+    (0xF923, 0x85CD, 0x0),
+    (0xF924, 0x8964, 0x0),
+    (0xF925, 0x62C9, 0x0),
+    (0xF926, 0x81D8, 0x0),
+    (0xF927, 0x881F, 0x0),
+    (0xF928, 0x5ECA, 0x0),
+    (0xF929, 0x6717, 0x0),
+    (0xF92A, 0x6D6A, 0x0),
+    (0xF92B, 0x72FC, 0x0),
+    (0xF92C, 0x90CE, 0x0),
+    (0xF92D, 0x4F86, 0x0),
+    (0xF92E, 0x51B7, 0x0),
+    (0xF92F, 0x52DE, 0x0),
+    (0xF930, 0x64C4, 0x0),
+    (0xF931, 0x6AD3, 0x0),
+    (0xF932, 0x7210, 0x0),
+    (0xF933, 0x76E7, 0x0),
+    (0xF934, 0x8001, 0x0),
+    (0xF935, 0x8606, 0x0),
+    (0xF936, 0x865C, 0x0),
+    (0xF937, 0x8DEF, 0x0),
+    (0xF938, 0x9732, 0x0),
+    (0xF939, 0x9B6F, 0x0),
+    (0xF93A, 0x9DFA, 0x0),
+    (0xF93B, 0x788C, 0x0),
+    (0xF93C, 0x797F, 0x0),
+    (0xF93D, 0x7DA0, 0x0),
+    (0xF93E, 0x83C9, 0x0),
+    (0xF93F, 0x9304, 0x0),
+    (0xF940, 0x9E7F, 0x0),
+    (0xF941, 0x8AD6, 0x0),
+    (0xF942, 0x58DF, 0x0),
+    (0xF943, 0x5F04, 0x0),
+    (0xF944, 0x7C60, 0x0),
+    (0xF945, 0x807E, 0x0),
+    (0xF946, 0x7262, 0x0),
+    (0xF947, 0x78CA, 0x0),
+    (0xF948, 0x8CC2, 0x0),
+    (0xF949, 0x96F7, 0x0),
+    (0xF94A, 0x58D8, 0x0),
+    (0xF94B, 0x5C62, 0x0),
+    (0xF94C, 0x6A13, 0x0),
+    (0xF94D, 0x6DDA, 0x0),
+    (0xF94E, 0x6F0F, 0x0),
+    (0xF94F, 0x7D2F, 0x0),
+    (0xF950, 0x7E37, 0x0),
+    (0xF951, 0x964B, 0x0),
+    (0xF952, 0x52D2, 0x0),
+    (0xF953, 0x808B, 0x0),
+    (0xF954, 0x51DC, 0x0),
+    (0xF955, 0x51CC, 0x0),
+    (0xF956, 0x7A1C, 0x0),
+    (0xF957, 0x7DBE, 0x0),
+    (0xF958, 0x83F1, 0x0),
+    (0xF959, 0x9675, 0x0),
+    (0xF95A, 0x8B80, 0x0),
+    (0xF95B, 0x62CF, 0x0),
+    (0xF95C, 0x6A02, 0x0),
+    (0xF95D, 0x8AFE, 0x0),
+    (0xF95E, 0x4E39, 0x0),
+    (0xF95F, 0x5BE7, 0x0),
+    (0xF960, 0x6012, 0x0),
+    (0xF961, 0x7387, 0x0),
+    (0xF962, 0x7570, 0x0),
+    (0xF963, 0x5317, 0x0),
+    (0xF964, 0x78FB, 0x0),
+    (0xF965, 0x4FBF, 0x0),
+    (0xF966, 0x5FA9, 0x0),
+    (0xF967, 0x4E0D, 0x0),
+    (0xF968, 0x6CCC, 0x0),
+    (0xF969, 0x6578, 0x0),
+    (0xF96A, 0x7D22, 0x0),
+    (0xF96B, 0x53C3, 0x0),
+    (0xF96C, 0x585E, 0x0),
+    (0xF96D, 0x7701, 0x0),
+    (0xF96E, 0x8449, 0x0),
+    (0xF96F, 0x8AAA, 0x0),
+    (0xF970, 0x6BBA, 0x0),
+    (0xF971, 0x8FB0, 0x0),
+    (0xF972, 0x6C88, 0x0),
+    (0xF973, 0x62FE, 0x0),
+    (0xF974, 0x82E5, 0x0),
+    (0xF975, 0x63A0, 0x0),
+    (0xF976, 0x7565, 0x0),
+    (0xF977, 0x4EAE, 0x0),
+    (0xF978, 0x5169, 0x0),
+    (0xF979, 0x51C9, 0x0),
+    (0xF97A, 0x6881, 0x0),
+    (0xF97B, 0x7CE7, 0x0),
+    (0xF97C, 0x826F, 0x0),
+    (0xF97D, 0x8AD2, 0x0),
+    (0xF97E, 0x91CF, 0x0),
+    (0xF97F, 0x52F5, 0x0),
+    (0xF980, 0x5442, 0x0),
+    (0xF981, 0x5973, 0x0),
+    (0xF982, 0x5EEC, 0x0),
+    (0xF983, 0x65C5, 0x0),
+    (0xF984, 0x6FFE, 0x0),
+    (0xF985, 0x792A, 0x0),
+    (0xF986, 0x95AD, 0x0),
+    (0xF987, 0x9A6A, 0x0),
+    (0xF988, 0x9E97, 0x0),
+    (0xF989, 0x9ECE, 0x0),
+    (0xF98A, 0x529B, 0x0),
+    (0xF98B, 0x66C6, 0x0),
+    (0xF98C, 0x6B77, 0x0),
+    (0xF98D, 0x8F62, 0x0),
+    (0xF98E, 0x5E74, 0x0),
+    (0xF98F, 0x6190, 0x0),
+    (0xF990, 0x6200, 0x0),
+    (0xF991, 0x649A, 0x0),
+    (0xF992, 0x6F23, 0x0),
+    (0xF993, 0x7149, 0x0),
+    (0xF994, 0x7489, 0x0),
+    (0xF995, 0x79CA, 0x0),
+    (0xF996, 0x7DF4, 0x0),
+    (0xF997, 0x806F, 0x0),
+    (0xF998, 0x8F26, 0x0),
+    (0xF999, 0x84EE, 0x0),
+    (0xF99A, 0x9023, 0x0),
+    (0xF99B, 0x934A, 0x0),
+    (0xF99C, 0x5217, 0x0),
+    (0xF99D, 0x52A3, 0x0),
+    (0xF99E, 0x54BD, 0x0),
+    (0xF99F, 0x70C8, 0x0),
+    (0xF9A0, 0x88C2, 0x0),
+    (0xF9A1, 0x8AAA, 0x0),
+    (0xF9A2, 0x5EC9, 0x0),
+    (0xF9A3, 0x5FF5, 0x0),
+    (0xF9A4, 0x637B, 0x0),
+    (0xF9A5, 0x6BAE, 0x0),
+    (0xF9A6, 0x7C3E, 0x0),
+    (0xF9A7, 0x7375, 0x0),
+    (0xF9A8, 0x4EE4, 0x0),
+    (0xF9A9, 0x56F9, 0x0),
+    (0xF9AA, 0x5BE7, 0x0),
+    (0xF9AB, 0x5DBA, 0x0),
+    (0xF9AC, 0x601C, 0x0),
+    (0xF9AD, 0x73B2, 0x0),
+    (0xF9AE, 0x7469, 0x0),
+    (0xF9AF, 0x7F9A, 0x0),
+    (0xF9B0, 0x8046, 0x0),
+    (0xF9B1, 0x9234, 0x0),
+    (0xF9B2, 0x96F6, 0x0),
+    (0xF9B3, 0x9748, 0x0),
+    (0xF9B4, 0x9818, 0x0),
+    (0xF9B5, 0x4F8B, 0x0),
+    (0xF9B6, 0x79AE, 0x0),
+    (0xF9B7, 0x91B4, 0x0),
+    (0xF9B8, 0x96B8, 0x0),
+    (0xF9B9, 0x60E1, 0x0),
+    (0xF9BA, 0x4E86, 0x0),
+    (0xF9BB, 0x50DA, 0x0),
+    (0xF9BC, 0x5BEE, 0x0),
+    (0xF9BD, 0x5C3F, 0x0),
+    (0xF9BE, 0x6599, 0x0),
+    (0xF9BF, 0x6A02, 0x0),
+    (0xF9C0, 0x71CE, 0x0),
+    (0xF9C1, 0x7642, 0x0),
+    (0xF9C2, 0x84FC, 0x0),
+    (0xF9C3, 0x907C, 0x0),
+    (0xF9C4, 0x9F8D, 0x0),
+    (0xF9C5, 0x6688, 0x0),
+    (0xF9C6, 0x962E, 0x0),
+    (0xF9C7, 0x5289, 0x0),
+    (0xF9C8, 0x677B, 0x0),
+    (0xF9C9, 0x67F3, 0x0),
+    (0xF9CA, 0x6D41, 0x0),
+    (0xF9CB, 0x6E9C, 0x0),
+    (0xF9CC, 0x7409, 0x0),
+    (0xF9CD, 0x7559, 0x0),
+    (0xF9CE, 0x786B, 0x0),
+    (0xF9CF, 0x7D10, 0x0),
+    (0xF9D0, 0x985E, 0x0),
+    (0xF9D1, 0x516D, 0x0),
+    (0xF9D2, 0x622E, 0x0),
+    (0xF9D3, 0x9678, 0x0),
+    (0xF9D4, 0x502B, 0x0),
+    (0xF9D5, 0x5D19, 0x0),
+    (0xF9D6, 0x6DEA, 0x0),
+    (0xF9D7, 0x8F2A, 0x0),
+    (0xF9D8, 0x5F8B, 0x0),
+    (0xF9D9, 0x6144, 0x0),
+    (0xF9DA, 0x6817, 0x0),
+    (0xF9DB, 0x7387, 0x0),
+    (0xF9DC, 0x9686, 0x0),
+    (0xF9DD, 0x5229, 0x0),
+    (0xF9DE, 0x540F, 0x0),
+    (0xF9DF, 0x5C65, 0x0),
+    (0xF9E0, 0x6613, 0x0),
+    (0xF9E1, 0x674E, 0x0),
+    (0xF9E2, 0x68A8, 0x0),
+    (0xF9E3, 0x6CE5, 0x0),
+    (0xF9E4, 0x7406, 0x0),
+    (0xF9E5, 0x75E2, 0x0),
+    (0xF9E6, 0x7F79, 0x0),
+    (0xF9E7, 0x88CF, 0x0),
+    (0xF9E8, 0x88E1, 0x0),
+    (0xF9E9, 0x91CC, 0x0),
+    (0xF9EA, 0x96E2, 0x0),
+    (0xF9EB, 0x533F, 0x0),
+    (0xF9EC, 0x6EBA, 0x0),
+    (0xF9ED, 0x541D, 0x0),
+    (0xF9EE, 0x71D0, 0x0),
+    (0xF9EF, 0x7498, 0x0),
+    (0xF9F0, 0x85FA, 0x0),
+    (0xF9F1, 0x96A3, 0x0),
+    (0xF9F2, 0x9C57, 0x0),
+    (0xF9F3, 0x9E9F, 0x0),
+    (0xF9F4, 0x6797, 0x0),
+    (0xF9F5, 0x6DCB, 0x0),
+    (0xF9F6, 0x81E8, 0x0),
+    (0xF9F7, 0x7ACB, 0x0),
+    (0xF9F8, 0x7B20, 0x0),
+    (0xF9F9, 0x7C92, 0x0),
+    (0xF9FA, 0x72C0, 0x0),
+    (0xF9FB, 0x7099, 0x0),
+    (0xF9FC, 0x8B58, 0x0),
+    (0xF9FD, 0x4EC0, 0x0),
+    (0xF9FE, 0x8336, 0x0),
+    (0xF9FF, 0x523A, 0x0),
+    (0xFA00, 0x5207, 0x0),
+    (0xFA01, 0x5EA6, 0x0),
+    (0xFA02, 0x62D3, 0x0),
+    (0xFA03, 0x7CD6, 0x0),
+    (0xFA04, 0x5B85, 0x0),
+    (0xFA05, 0x6D1E, 0x0),
+    (0xFA06, 0x66B4, 0x0),
+    (0xFA07, 0x8F3B, 0x0),
+    (0xFA08, 0x884C, 0x0),
+    (0xFA09, 0x964D, 0x0),
+    (0xFA0A, 0x898B, 0x0),
+    (0xFA0B, 0x5ED3, 0x0),
+    (0xFA0C, 0x5140, 0x0),
+    (0xFA0D, 0x55C0, 0x0),
+    (0xFA10, 0x585A, 0x0),
+    (0xFA12, 0x6674, 0x0),
+    (0xFA15, 0x51DE, 0x0),
+    (0xFA16, 0x732A, 0x0),
+    (0xFA17, 0x76CA, 0x0),
+    (0xFA18, 0x793C, 0x0),
+    (0xFA19, 0x795E, 0x0),
+    (0xFA1A, 0x7965, 0x0),
+    (0xFA1B, 0x798F, 0x0),
+    (0xFA1C, 0x9756, 0x0),
+    (0xFA1D, 0x7CBE, 0x0),
+    (0xFA1E, 0x7FBD, 0x0),
+    (0xFA20, 0x8612, 0x0),
+    (0xFA22, 0x8AF8, 0x0),
+    (0xFA25, 0x9038, 0x0),
+    (0xFA26, 0x90FD, 0x0),
+    (0xFA2A, 0x98EF, 0x0),
+    (0xFA2B, 0x98FC, 0x0),
+    (0xFA2C, 0x9928, 0x0),
+    (0xFA2D, 0x9DB4, 0x0),
+    (0xFA2E, 0x90DE, 0x0),
+    (0xFA2F, 0x96B7, 0x0),
+    (0xFA30, 0x4FAE, 0x0),
+    (0xFA31, 0x50E7, 0x0),
+    (0xFA32, 0x514D, 0x0),
+    (0xFA33, 0x52C9, 0x0),
+    (0xFA34, 0x52E4, 0x0),
+    (0xFA35, 0x5351, 0x0),
+    (0xFA36, 0x559D, 0x0),
+    (0xFA37, 0x5606, 0x0),
+    (0xFA38, 0x5668, 0x0),
+    (0xFA39, 0x5840, 0x0),
+    (0xFA3A, 0x58A8, 0x0),
+    (0xFA3B, 0x5C64, 0x0),
+    (0xFA3C, 0x5C6E, 0x0),
+    (0xFA3D, 0x6094, 0x0),
+    (0xFA3E, 0x6168, 0x0),
+    (0xFA3F, 0x618E, 0x0),
+    (0xFA40, 0x61F2, 0x0),
+    (0xFA41, 0x654F, 0x0),
+    (0xFA42, 0x65E2, 0x0),
+    (0xFA43, 0x6691, 0x0),
+    (0xFA44, 0x6885, 0x0),
+    (0xFA45, 0x6D77, 0x0),
+    (0xFA46, 0x6E1A, 0x0),
+    (0xFA47, 0x6F22, 0x0),
+    (0xFA48, 0x716E, 0x0),
+    (0xFA49, 0x722B, 0x0),
+    (0xFA4A, 0x7422, 0x0),
+    (0xFA4B, 0x7891, 0x0),
+    (0xFA4C, 0x793E, 0x0),
+    (0xFA4D, 0x7949, 0x0),
+    (0xFA4E, 0x7948, 0x0),
+    (0xFA4F, 0x7950, 0x0),
+    (0xFA50, 0x7956, 0x0),
+    (0xFA51, 0x795D, 0x0),
+    (0xFA52, 0x798D, 0x0),
+    (0xFA53, 0x798E, 0x0),
+    (0xFA54, 0x7A40, 0x0),
+    (0xFA55, 0x7A81, 0x0),
+    (0xFA56, 0x7BC0, 0x0),
+    (0xFA57, 0x7DF4, 0x0),
+    (0xFA58, 0x7E09, 0x0),
+    (0xFA59, 0x7E41, 0x0),
+    (0xFA5A, 0x7F72, 0x0),
+    (0xFA5B, 0x8005, 0x0),
+    (0xFA5C, 0x81ED, 0x0),
+    (0xFA5D, 0x8279, 0x0),
+    (0xFA5E, 0x8279, 0x0),
+    (0xFA5F, 0x8457, 0x0),
+    (0xFA60, 0x8910, 0x0),
+    (0xFA61, 0x8996, 0x0),
+    (0xFA62, 0x8B01, 0x0),
+    (0xFA63, 0x8B39, 0x0),
+    (0xFA64, 0x8CD3, 0x0),
+    (0xFA65, 0x8D08, 0x0),
+    (0xFA66, 0x8FB6, 0x0),
+    (0xFA67, 0x9038, 0x0),
+    (0xFA68, 0x96E3, 0x0),
+    (0xFA69, 0x97FF, 0x0),
+    (0xFA6A, 0x983B, 0x0),
+    (0xFA6B, 0x6075, 0x0),
+    (0xFA6C, 0x242EE, 0x0),
+    (0xFA6D, 0x8218, 0x0),
+    (0xFA70, 0x4E26, 0x0),
+    (0xFA71, 0x51B5, 0x0),
+    (0xFA72, 0x5168, 0x0),
+    (0xFA73, 0x4F80, 0x0),
+    (0xFA74, 0x5145, 0x0),
+    (0xFA75, 0x5180, 0x0),
+    (0xFA76, 0x52C7, 0x0),
+    (0xFA77, 0x52FA, 0x0),
+    (0xFA78, 0x559D, 0x0),
+    (0xFA79, 0x5555, 0x0),
+    (0xFA7A, 0x5599, 0x0),
+    (0xFA7B, 0x55E2, 0x0),
+    (0xFA7C, 0x585A, 0x0),
+    (0xFA7D, 0x58B3, 0x0),
+    (0xFA7E, 0x5944, 0x0),
+    (0xFA7F, 0x5954, 0x0),
+    (0xFA80, 0x5A62, 0x0),
+    (0xFA81, 0x5B28, 0x0),
+    (0xFA82, 0x5ED2, 0x0),
+    (0xFA83, 0x5ED9, 0x0),
+    (0xFA84, 0x5F69, 0x0),
+    (0xFA85, 0x5FAD, 0x0),
+    (0xFA86, 0x60D8, 0x0),
+    (0xFA87, 0x614E, 0x0),
+    (0xFA88, 0x6108, 0x0),
+    (0xFA89, 0x618E, 0x0),
+    (0xFA8A, 0x6160, 0x0),
+    (0xFA8B, 0x61F2, 0x0),
+    (0xFA8C, 0x6234, 0x0),
+    (0xFA8D, 0x63C4, 0x0),
+    (0xFA8E, 0x641C, 0x0),
+    (0xFA8F, 0x6452, 0x0),
+    (0xFA90, 0x6556, 0x0),
+    (0xFA91, 0x6674, 0x0),
+    (0xFA92, 0x6717, 0x0),
+    (0xFA93, 0x671B, 0x0),
+    (0xFA94, 0x6756, 0x0),
+    (0xFA95, 0x6B79, 0x0),
+    (0xFA96, 0x6BBA, 0x0),
+    (0xFA97, 0x6D41, 0x0),
+    (0xFA98, 0x6EDB, 0x0),
+    (0xFA99, 0x6ECB, 0x0),
+    (0xFA9A, 0x6F22, 0x0),
+    (0xFA9B, 0x701E, 0x0),
+    (0xFA9C, 0x716E, 0x0),
+    (0xFA9D, 0x77A7, 0x0),
+    (0xFA9E, 0x7235, 0x0),
+    (0xFA9F, 0x72AF, 0x0),
+    (0xFAA0, 0x732A, 0x0),
+    (0xFAA1, 0x7471, 0x0),
+    (0xFAA2, 0x7506, 0x0),
+    (0xFAA3, 0x753B, 0x0),
+    (0xFAA4, 0x761D, 0x0),
+    (0xFAA5, 0x761F, 0x0),
+    (0xFAA6, 0x76CA, 0x0),
+    (0xFAA7, 0x76DB, 0x0),
+    (0xFAA8, 0x76F4, 0x0),
+    (0xFAA9, 0x774A, 0x0),
+    (0xFAAA, 0x7740, 0x0),
+    (0xFAAB, 0x78CC, 0x0),
+    (0xFAAC, 0x7AB1, 0x0),
+    (0xFAAD, 0x7BC0, 0x0),
+    (0xFAAE, 0x7C7B, 0x0),
+    (0xFAAF, 0x7D5B, 0x0),
+    (0xFAB0, 0x7DF4, 0x0),
+    (0xFAB1, 0x7F3E, 0x0),
+    (0xFAB2, 0x8005, 0x0),
+    (0xFAB3, 0x8352, 0x0),
+    (0xFAB4, 0x83EF, 0x0),
+    (0xFAB5, 0x8779, 0x0),
+    (0xFAB6, 0x8941, 0x0),
+    (0xFAB7, 0x8986, 0x0),
+    (0xFAB8, 0x8996, 0x0),
+    (0xFAB9, 0x8ABF, 0x0),
+    (0xFABA, 0x8AF8, 0x0),
+    (0xFABB, 0x8ACB, 0x0),
+    (0xFABC, 0x8B01, 0x0),
+    (0xFABD, 0x8AFE, 0x0),
+    (0xFABE, 0x8AED, 0x0),
+    (0xFABF, 0x8B39, 0x0),
+    (0xFAC0, 0x8B8A, 0x0),
+    (0xFAC1, 0x8D08, 0x0),
+    (0xFAC2, 0x8F38, 0x0),
+    (0xFAC3, 0x9072, 0x0),
+    (0xFAC4, 0x9199, 0x0),
+    (0xFAC5, 0x9276, 0x0),
+    (0xFAC6, 0x967C, 0x0),
+    (0xFAC7, 0x96E3, 0x0),
+    (0xFAC8, 0x9756, 0x0),
+    (0xFAC9, 0x97DB, 0x0),
+    (0xFACA, 0x97FF, 0x0),
+    (0xFACB, 0x980B, 0x0),
+    (0xFACC, 0x983B, 0x0),
+    (0xFACD, 0x9B12, 0x0),
+    (0xFACE, 0x9F9C, 0x0),
+    (0xFACF, 0x2284A, 0x0),
+    (0xFAD0, 0x22844, 0x0),
+    (0xFAD1, 0x233D5, 0x0),
+    (0xFAD2, 0x3B9D, 0x0),
+    (0xFAD3, 0x4018, 0x0),
+    (0xFAD4, 0x4039, 0x0),
+    (0xFAD5, 0x25249, 0x0),
+    (0xFAD6, 0x25CD0, 0x0),
+    (0xFAD7, 0x27ED3, 0x0),
+    (0xFAD8, 0x9F43, 0x0),
+    (0xFAD9, 0x9F8E, 0x0),
+    (0xFB1D, 0x5D9, 0x5B4),
+    (0xFB1F, 0x5F2, 0x5B7),
+    (0xFB2A, 0x5E9, 0x5C1),
+    (0xFB2B, 0x5E9, 0x5C2),
+    (0xFB2C, 0xFB49, 0x5C1),
+    (0xFB2D, 0xFB49, 0x5C2),
+    (0xFB2E, 0x5D0, 0x5B7),
+    (0xFB2F, 0x5D0, 0x5B8),
+    (0xFB30, 0x5D0, 0x5BC),
+    (0xFB31, 0x5D1, 0x5BC),
+    (0xFB32, 0x5D2, 0x5BC),
+    (0xFB33, 0x5D3, 0x5BC),
+    (0xFB34, 0x5D4, 0x5BC),
+    (0xFB35, 0x5D5, 0x5BC),
+    (0xFB36, 0x5D6, 0x5BC),
+    (0xFB38, 0x5D8, 0x5BC),
+    (0xFB39, 0x5D9, 0x5BC),
+    (0xFB3A, 0x5DA, 0x5BC),
+    (0xFB3B, 0x5DB, 0x5BC),
+    (0xFB3C, 0x5DC, 0x5BC),
+    (0xFB3E, 0x5DE, 0x5BC),
+    (0xFB40, 0x5E0, 0x5BC),
+    (0xFB41, 0x5E1, 0x5BC),
+    (0xFB43, 0x5E3, 0x5BC),
+    (0xFB44, 0x5E4, 0x5BC),
+    (0xFB46, 0x5E6, 0x5BC),
+    (0xFB47, 0x5E7, 0x5BC),
+    (0xFB48, 0x5E8, 0x5BC),
+    (0xFB49, 0x5E9, 0x5BC),
+    (0xFB4A, 0x5EA, 0x5BC),
+    (0xFB4B, 0x5D5, 0x5B9),
+    (0xFB4C, 0x5D1, 0x5BF),
+    (0xFB4D, 0x5DB, 0x5BF),
+    (0xFB4E, 0x5E4, 0x5BF),
+    (0x1109A, 0x11099, 0x110BA),
+    (0x1109C, 0x1109B, 0x110BA),
+    (0x110AB, 0x110A5, 0x110BA),
+    (0x1112E, 0x11131, 0x11127),
+    (0x1112F, 0x11132, 0x11127),
+    (0x1134B, 0x11347, 0x1133E),
+    (0x1134C, 0x11347, 0x11357),
+    (0x114BB, 0x114B9, 0x114BA),
+    (0x114BC, 0x114B9, 0x114B0),
+    (0x114BE, 0x114B9, 0x114BD),
+    (0x115BA, 0x115B8, 0x115AF),
+    (0x115BB, 0x115B9, 0x115AF),
+    (0x11938, 0x11935, 0x11930),
+    (0x1D15E, 0x1D157, 0x1D165),
+    (0x1D15F, 0x1D158, 0x1D165),
+    (0x1D160, 0x1D15F, 0x1D16E),
+    (0x1D161, 0x1D15F, 0x1D16F),
+    (0x1D162, 0x1D15F, 0x1D170),
+    (0x1D163, 0x1D15F, 0x1D171),
+    (0x1D164, 0x1D15F, 0x1D172),
+    (0x1D1BB, 0x1D1B9, 0x1D165),
+    (0x1D1BC, 0x1D1BA, 0x1D165),
+    (0x1D1BD, 0x1D1BB, 0x1D16E),
+    (0x1D1BE, 0x1D1BC, 0x1D16E),
+    (0x1D1BF, 0x1D1BB, 0x1D16F),
+    (0x1D1C0, 0x1D1BC, 0x1D16F),
+    (0x2F800, 0x4E3D, 0x0),
+    (0x2F801, 0x4E38, 0x0),
+    (0x2F802, 0x4E41, 0x0),
+    (0x2F803, 0x20122, 0x0),
+    (0x2F804, 0x4F60, 0x0),
+    (0x2F805, 0x4FAE, 0x0),
+    (0x2F806, 0x4FBB, 0x0),
+    (0x2F807, 0x5002, 0x0),
+    (0x2F808, 0x507A, 0x0),
+    (0x2F809, 0x5099, 0x0),
+    (0x2F80A, 0x50E7, 0x0),
+    (0x2F80B, 0x50CF, 0x0),
+    (0x2F80C, 0x349E, 0x0),
+    (0x2F80D, 0x2063A, 0x0),
+    (0x2F80E, 0x514D, 0x0),
+    (0x2F80F, 0x5154, 0x0),
+    (0x2F810, 0x5164, 0x0),
+    (0x2F811, 0x5177, 0x0),
+    (0x2F812, 0x2051C, 0x0),
+    (0x2F813, 0x34B9, 0x0),
+    (0x2F814, 0x5167, 0x0),
+    (0x2F815, 0x518D, 0x0),
+    (0x2F816, 0x2054B, 0x0),
+    (0x2F817, 0x5197, 0x0),
+    (0x2F818, 0x51A4, 0x0),
+    (0x2F819, 0x4ECC, 0x0),
+    (0x2F81A, 0x51AC, 0x0),
+    (0x2F81B, 0x51B5, 0x0),
+    (0x2F81C, 0x291DF, 0x0),
+    (0x2F81D, 0x51F5, 0x0),
+    (0x2F81E, 0x5203, 0x0),
+    (0x2F81F, 0x34DF, 0x0),
+    (0x2F820, 0x523B, 0x0),
+    (0x2F821, 0x5246, 0x0),
+    (0x2F822, 0x5272, 0x0),
+    (0x2F823, 0x5277, 0x0),
+    (0x2F824, 0x3515, 0x0),
+    (0x2F825, 0x52C7, 0x0),
+    (0x2F826, 0x52C9, 0x0),
+    (0x2F827, 0x52E4, 0x0),
+    (0x2F828, 0x52FA, 0x0),
+    (0x2F829, 0x5305, 0x0),
+    (0x2F82A, 0x5306, 0x0),
+    (0x2F82B, 0x5317, 0x0),
+    (0x2F82C, 0x5349, 0x0),
+    (0x2F82D, 0x5351, 0x0),
+    (0x2F82E, 0x535A, 0x0),
+    (0x2F82F, 0x5373, 0x0),
+    (0x2F830, 0x537D, 0x0),
+    (0x2F831, 0x537F, 0x0),
+    (0x2F832, 0x537F, 0x0),
+    (0x2F833, 0x537F, 0x0),
+    (0x2F834, 0x20A2C, 0x0),
+    (0x2F835, 0x7070, 0x0),
+    (0x2F836, 0x53CA, 0x0),
+    (0x2F837, 0x53DF, 0x0),
+    (0x2F838, 0x20B63, 0x0),
+    (0x2F839, 0x53EB, 0x0),
+    (0x2F83A, 0x53F1, 0x0),
+    (0x2F83B, 0x5406, 0x0),
+    (0x2F83C, 0x549E, 0x0),
+    (0x2F83D, 0x5438, 0x0),
+    (0x2F83E, 0x5448, 0x0),
+    (0x2F83F, 0x5468, 0x0),
+    (0x2F840, 0x54A2, 0x0),
+    (0x2F841, 0x54F6, 0x0),
+    (0x2F842, 0x5510, 0x0),
+    (0x2F843, 0x5553, 0x0),
+    (0x2F844, 0x5563, 0x0),
+    (0x2F845, 0x5584, 0x0),
+    (0x2F846, 0x5584, 0x0),
+    (0x2F847, 0x5599, 0x0),
+    (0x2F848, 0x55AB, 0x0),
+    (0x2F849, 0x55B3, 0x0),
+    (0x2F84A, 0x55C2, 0x0),
+    (0x2F84B, 0x5716, 0x0),
+    (0x2F84C, 0x5606, 0x0),
+    (0x2F84D, 0x5717, 0x0),
+    (0x2F84E, 0x5651, 0x0),
+    (0x2F84F, 0x5674, 0x0),
+    (0x2F850, 0x5207, 0x0),
+    (0x2F851, 0x58EE, 0x0),
+    (0x2F852, 0x57CE, 0x0),
+    (0x2F853, 0x57F4, 0x0),
+    (0x2F854, 0x580D, 0x0),
+    (0x2F855, 0x578B, 0x0),
+    (0x2F856, 0x5832, 0x0),
+    (0x2F857, 0x5831, 0x0),
+    (0x2F858, 0x58AC, 0x0),
+    (0x2F859, 0x214E4, 0x0),
+    (0x2F85A, 0x58F2, 0x0),
+    (0x2F85B, 0x58F7, 0x0),
+    (0x2F85C, 0x5906, 0x0),
+    (0x2F85D, 0x591A, 0x0),
+    (0x2F85E, 0x5922, 0x0),
+    (0x2F85F, 0x5962, 0x0),
+    (0x2F860, 0x216A8, 0x0),
+    (0x2F861, 0x216EA, 0x0),
+    (0x2F862, 0x59EC, 0x0),
+    (0x2F863, 0x5A1B, 0x0),
+    (0x2F864, 0x5A27, 0x0),
+    (0x2F865, 0x59D8, 0x0),
+    (0x2F866, 0x5A66, 0x0),
+    (0x2F867, 0x36EE, 0x0),
+    (0x2F868, 0x36FC, 0x0),
+    (0x2F869, 0x5B08, 0x0),
+    (0x2F86A, 0x5B3E, 0x0),
+    (0x2F86B, 0x5B3E, 0x0),
+    (0x2F86C, 0x219C8, 0x0),
+    (0x2F86D, 0x5BC3, 0x0),
+    (0x2F86E, 0x5BD8, 0x0),
+    (0x2F86F, 0x5BE7, 0x0),
+    (0x2F870, 0x5BF3, 0x0),
+    (0x2F871, 0x21B18, 0x0),
+    (0x2F872, 0x5BFF, 0x0),
+    (0x2F873, 0x5C06, 0x0),
+    (0x2F874, 0x5F53, 0x0),
+    (0x2F875, 0x5C22, 0x0),
+    (0x2F876, 0x3781, 0x0),
+    (0x2F877, 0x5C60, 0x0),
+    (0x2F878, 0x5C6E, 0x0),
+    (0x2F879, 0x5CC0, 0x0),
+    (0x2F87A, 0x5C8D, 0x0),
+    (0x2F87B, 0x21DE4, 0x0),
+    (0x2F87C, 0x5D43, 0x0),
+    (0x2F87D, 0x21DE6, 0x0),
+    (0x2F87E, 0x5D6E, 0x0),
+    (0x2F87F, 0x5D6B, 0x0),
+    (0x2F880, 0x5D7C, 0x0),
+    (0x2F881, 0x5DE1, 0x0),
+    (0x2F882, 0x5DE2, 0x0),
+    (0x2F883, 0x382F, 0x0),
+    (0x2F884, 0x5DFD, 0x0),
+    (0x2F885, 0x5E28, 0x0),
+    (0x2F886, 0x5E3D, 0x0),
+    (0x2F887, 0x5E69, 0x0),
+    (0x2F888, 0x3862, 0x0),
+    (0x2F889, 0x22183, 0x0),
+    (0x2F88A, 0x387C, 0x0),
+    (0x2F88B, 0x5EB0, 0x0),
+    (0x2F88C, 0x5EB3, 0x0),
+    (0x2F88D, 0x5EB6, 0x0),
+    (0x2F88E, 0x5ECA, 0x0),
+    (0x2F88F, 0x2A392, 0x0),
+    (0x2F890, 0x5EFE, 0x0),
+    (0x2F891, 0x22331, 0x0),
+    (0x2F892, 0x22331, 0x0),
+    (0x2F893, 0x8201, 0x0),
+    (0x2F894, 0x5F22, 0x0),
+    (0x2F895, 0x5F22, 0x0),
+    (0x2F896, 0x38C7, 0x0),
+    (0x2F897, 0x232B8, 0x0),
+    (0x2F898, 0x261DA, 0x0),
+    (0x2F899, 0x5F62, 0x0),
+    (0x2F89A, 0x5F6B, 0x0),
+    (0x2F89B, 0x38E3, 0x0),
+    (0x2F89C, 0x5F9A, 0x0),
+    (0x2F89D, 0x5FCD, 0x0),
+    (0x2F89E, 0x5FD7, 0x0),
+    (0x2F89F, 0x5FF9, 0x0),
+    (0x2F8A0, 0x6081, 0x0),
+    (0x2F8A1, 0x393A, 0x0),
+    (0x2F8A2, 0x391C, 0x0),
+    (0x2F8A3, 0x6094, 0x0),
+    (0x2F8A4, 0x226D4, 0x0),
+    (0x2F8A5, 0x60C7, 0x0),
+    (0x2F8A6, 0x6148, 0x0),
+    (0x2F8A7, 0x614C, 0x0),
+    (0x2F8A8, 0x614E, 0x0),
+    (0x2F8A9, 0x614C, 0x0),
+    (0x2F8AA, 0x617A, 0x0),
+    (0x2F8AB, 0x618E, 0x0),
+    (0x2F8AC, 0x61B2, 0x0),
+    (0x2F8AD, 0x61A4, 0x0),
+    (0x2F8AE, 0x61AF, 0x0),
+    (0x2F8AF, 0x61DE, 0x0),
+    (0x2F8B0, 0x61F2, 0x0),
+    (0x2F8B1, 0x61F6, 0x0),
+    (0x2F8B2, 0x6210, 0x0),
+    (0x2F8B3, 0x621B, 0x0),
+    (0x2F8B4, 0x625D, 0x0),
+    (0x2F8B5, 0x62B1, 0x0),
+    (0x2F8B6, 0x62D4, 0x0),
+    (0x2F8B7, 0x6350, 0x0),
+    (0x2F8B8, 0x22B0C, 0x0),
+    (0x2F8B9, 0x633D, 0x0),
+    (0x2F8BA, 0x62FC, 0x0),
+    (0x2F8BB, 0x6368, 0x0),
+    (0x2F8BC, 0x6383, 0x0),
+    (0x2F8BD, 0x63E4, 0x0),
+    (0x2F8BE, 0x22BF1, 0x0),
+    (0x2F8BF, 0x6422, 0x0),
+    (0x2F8C0, 0x63C5, 0x0),
+    (0x2F8C1, 0x63A9, 0x0),
+    (0x2F8C2, 0x3A2E, 0x0),
+    (0x2F8C3, 0x6469, 0x0),
+    (0x2F8C4, 0x647E, 0x0),
+    (0x2F8C5, 0x649D, 0x0),
+    (0x2F8C6, 0x6477, 0x0),
+    (0x2F8C7, 0x3A6C, 0x0),
+    (0x2F8C8, 0x654F, 0x0),
+    (0x2F8C9, 0x656C, 0x0),
+    (0x2F8CA, 0x2300A, 0x0),
+    (0x2F8CB, 0x65E3, 0x0),
+    (0x2F8CC, 0x66F8, 0x0),
+    (0x2F8CD, 0x6649, 0x0),
+    (0x2F8CE, 0x3B19, 0x0),
+    (0x2F8CF, 0x6691, 0x0),
+    (0x2F8D0, 0x3B08, 0x0),
+    (0x2F8D1, 0x3AE4, 0x0),
+    (0x2F8D2, 0x5192, 0x0),
+    (0x2F8D3, 0x5195, 0x0),
+    (0x2F8D4, 0x6700, 0x0),
+    (0x2F8D5, 0x669C, 0x0),
+    (0x2F8D6, 0x80AD, 0x0),
+    (0x2F8D7, 0x43D9, 0x0),
+    (0x2F8D8, 0x6717, 0x0),
+    (0x2F8D9, 0x671B, 0x0),
+    (0x2F8DA, 0x6721, 0x0),
+    (0x2F8DB, 0x675E, 0x0),
+    (0x2F8DC, 0x6753, 0x0),
+    (0x2F8DD, 0x233C3, 0x0),
+    (0x2F8DE, 0x3B49, 0x0),
+    (0x2F8DF, 0x67FA, 0x0),
+    (0x2F8E0, 0x6785, 0x0),
+    (0x2F8E1, 0x6852, 0x0),
+    (0x2F8E2, 0x6885, 0x0),
+    (0x2F8E3, 0x2346D, 0x0),
+    (0x2F8E4, 0x688E, 0x0),
+    (0x2F8E5, 0x681F, 0x0),
+    (0x2F8E6, 0x6914, 0x0),
+    (0x2F8E7, 0x3B9D, 0x0),
+    (0x2F8E8, 0x6942, 0x0),
+    (0x2F8E9, 0x69A3, 0x0),
+    (0x2F8EA, 0x69EA, 0x0),
+    (0x2F8EB, 0x6AA8, 0x0),
+    (0x2F8EC, 0x236A3, 0x0),
+    (0x2F8ED, 0x6ADB, 0x0),
+    (0x2F8EE, 0x3C18, 0x0),
+    (0x2F8EF, 0x6B21, 0x0),
+    (0x2F8F0, 0x238A7, 0x0),
+    (0x2F8F1, 0x6B54, 0x0),
+    (0x2F8F2, 0x3C4E, 0x0),
+    (0x2F8F3, 0x6B72, 0x0),
+    (0x2F8F4, 0x6B9F, 0x0),
+    (0x2F8F5, 0x6BBA, 0x0),
+    (0x2F8F6, 0x6BBB, 0x0),
+    (0x2F8F7, 0x23A8D, 0x0),
+    (0x2F8F8, 0x21D0B, 0x0),
+    (0x2F8F9, 0x23AFA, 0x0),
+    (0x2F8FA, 0x6C4E, 0x0),
+    (0x2F8FB, 0x23CBC, 0x0),
+    (0x2F8FC, 0x6CBF, 0x0),
+    (0x2F8FD, 0x6CCD, 0x0),
+    (0x2F8FE, 0x6C67, 0x0),
+    (0x2F8FF, 0x6D16, 0x0),
+    (0x2F900, 0x6D3E, 0x0),
+    (0x2F901, 0x6D77, 0x0),
+    (0x2F902, 0x6D41, 0x0),
+    (0x2F903, 0x6D69, 0x0),
+    (0x2F904, 0x6D78, 0x0),
+    (0x2F905, 0x6D85, 0x0),
+    (0x2F906, 0x23D1E, 0x0),
+    (0x2F907, 0x6D34, 0x0),
+    (0x2F908, 0x6E2F, 0x0),
+    (0x2F909, 0x6E6E, 0x0),
+    (0x2F90A, 0x3D33, 0x0),
+    (0x2F90B, 0x6ECB, 0x0),
+    (0x2F90C, 0x6EC7, 0x0),
+    (0x2F90D, 0x23ED1, 0x0),
+    (0x2F90E, 0x6DF9, 0x0),
+    (0x2F90F, 0x6F6E, 0x0),
+    (0x2F910, 0x23F5E, 0x0),
+    (0x2F911, 0x23F8E, 0x0),
+    (0x2F912, 0x6FC6, 0x0),
+    (0x2F913, 0x7039, 0x0),
+    (0x2F914, 0x701E, 0x0),
+    (0x2F915, 0x701B, 0x0),
+    (0x2F916, 0x3D96, 0x0),
+    (0x2F917, 0x704A, 0x0),
+    (0x2F918, 0x707D, 0x0),
+    (0x2F919, 0x7077, 0x0),
+    (0x2F91A, 0x70AD, 0x0),
+    (0x2F91B, 0x20525, 0x0),
+    (0x2F91C, 0x7145, 0x0),
+    (0x2F91D, 0x24263, 0x0),
+    (0x2F91E, 0x719C, 0x0),
+    (0x2F91F, 0x243AB, 0x0),
+    (0x2F920, 0x7228, 0x0),
+    (0x2F921, 0x7235, 0x0),
+    (0x2F922, 0x7250, 0x0),
+    (0x2F923, 0x24608, 0x0),
+    (0x2F924, 0x7280, 0x0),
+    (0x2F925, 0x7295, 0x0),
+    (0x2F926, 0x24735, 0x0),
+    (0x2F927, 0x24814, 0x0),
+    (0x2F928, 0x737A, 0x0),
+    (0x2F929, 0x738B, 0x0),
+    (0x2F92A, 0x3EAC, 0x0),
+    (0x2F92B, 0x73A5, 0x0),
+    (0x2F92C, 0x3EB8, 0x0),
+    (0x2F92D, 0x3EB8, 0x0),
+    (0x2F92E, 0x7447, 0x0),
+    (0x2F92F, 0x745C, 0x0),
+    (0x2F930, 0x7471, 0x0),
+    (0x2F931, 0x7485, 0x0),
+    (0x2F932, 0x74CA, 0x0),
+    (0x2F933, 0x3F1B, 0x0),
+    (0x2F934, 0x7524, 0x0),
+    (0x2F935, 0x24C36, 0x0),
+    (0x2F936, 0x753E, 0x0),
+    (0x2F937, 0x24C92, 0x0),
+    (0x2F938, 0x7570, 0x0),
+    (0x2F939, 0x2219F, 0x0),
+    (0x2F93A, 0x7610, 0x0),
+    (0x2F93B, 0x24FA1, 0x0),
+    (0x2F93C, 0x24FB8, 0x0),
+    (0x2F93D, 0x25044, 0x0),
+    (0x2F93E, 0x3FFC, 0x0),
+    (0x2F93F, 0x4008, 0x0),
+    (0x2F940, 0x76F4, 0x0),
+    (0x2F941, 0x250F3, 0x0),
+    (0x2F942, 0x250F2, 0x0),
+    (0x2F943, 0x25119, 0x0),
+    (0x2F944, 0x25133, 0x0),
+    (0x2F945, 0x771E, 0x0),
+    (0x2F946, 0x771F, 0x0),
+    (0x2F947, 0x771F, 0x0),
+    (0x2F948, 0x774A, 0x0),
+    (0x2F949, 0x4039, 0x0),
+    (0x2F94A, 0x778B, 0x0),
+    (0x2F94B, 0x4046, 0x0),
+    (0x2F94C, 0x4096, 0x0),
+    (0x2F94D, 0x2541D, 0x0),
+    (0x2F94E, 0x784E, 0x0),
+    (0x2F94F, 0x788C, 0x0),
+    (0x2F950, 0x78CC, 0x0),
+    (0x2F951, 0x40E3, 0x0),
+    (0x2F952, 0x25626, 0x0),
+    (0x2F953, 0x7956, 0x0),
+    (0x2F954, 0x2569A, 0x0),
+    (0x2F955, 0x256C5, 0x0),
+    (0x2F956, 0x798F, 0x0),
+    (0x2F957, 0x79EB, 0x0),
+    (0x2F958, 0x412F, 0x0),
+    (0x2F959, 0x7A40, 0x0),
+    (0x2F95A, 0x7A4A, 0x0),
+    (0x2F95B, 0x7A4F, 0x0),
+    (0x2F95C, 0x2597C, 0x0),
+    (0x2F95D, 0x25AA7, 0x0),
+    (0x2F95E, 0x25AA7, 0x0),
+    (0x2F95F, 0x7AEE, 0x0),
+    (0x2F960, 0x4202, 0x0),
+    (0x2F961, 0x25BAB, 0x0),
+    (0x2F962, 0x7BC6, 0x0),
+    (0x2F963, 0x7BC9, 0x0),
+    (0x2F964, 0x4227, 0x0),
+    (0x2F965, 0x25C80, 0x0),
+    (0x2F966, 0x7CD2, 0x0),
+    (0x2F967, 0x42A0, 0x0),
+    (0x2F968, 0x7CE8, 0x0),
+    (0x2F969, 0x7CE3, 0x0),
+    (0x2F96A, 0x7D00, 0x0),
+    (0x2F96B, 0x25F86, 0x0),
+    (0x2F96C, 0x7D63, 0x0),
+    (0x2F96D, 0x4301, 0x0),
+    (0x2F96E, 0x7DC7, 0x0),
+    (0x2F96F, 0x7E02, 0x0),
+    (0x2F970, 0x7E45, 0x0),
+    (0x2F971, 0x4334, 0x0),
+    (0x2F972, 0x26228, 0x0),
+    (0x2F973, 0x26247, 0x0),
+    (0x2F974, 0x4359, 0x0),
+    (0x2F975, 0x262D9, 0x0),
+    (0x2F976, 0x7F7A, 0x0),
+    (0x2F977, 0x2633E, 0x0),
+    (0x2F978, 0x7F95, 0x0),
+    (0x2F979, 0x7FFA, 0x0),
+    (0x2F97A, 0x8005, 0x0),
+    (0x2F97B, 0x264DA, 0x0),
+    (0x2F97C, 0x26523, 0x0),
+    (0x2F97D, 0x8060, 0x0),
+    (0x2F97E, 0x265A8, 0x0),
+    (0x2F97F, 0x8070, 0x0),
+    (0x2F980, 0x2335F, 0x0),
+    (0x2F981, 0x43D5, 0x0),
+    (0x2F982, 0x80B2, 0x0),
+    (0x2F983, 0x8103, 0x0),
+    (0x2F984, 0x440B, 0x0),
+    (0x2F985, 0x813E, 0x0),
+    (0x2F986, 0x5AB5, 0x0),
+    (0x2F987, 0x267A7, 0x0),
+    (0x2F988, 0x267B5, 0x0),
+    (0x2F989, 0x23393, 0x0),
+    (0x2F98A, 0x2339C, 0x0),
+    (0x2F98B, 0x8201, 0x0),
+    (0x2F98C, 0x8204, 0x0),
+    (0x2F98D, 0x8F9E, 0x0),
+    (0x2F98E, 0x446B, 0x0),
+    (0x2F98F, 0x8291, 0x0),
+    (0x2F990, 0x828B, 0x0),
+    (0x2F991, 0x829D, 0x0),
+    (0x2F992, 0x52B3, 0x0),
+    (0x2F993, 0x82B1, 0x0),
+    (0x2F994, 0x82B3, 0x0),
+    (0x2F995, 0x82BD, 0x0),
+    (0x2F996, 0x82E6, 0x0),
+    (0x2F997, 0x26B3C, 0x0),
+    (0x2F998, 0x82E5, 0x0),
+    (0x2F999, 0x831D, 0x0),
+    (0x2F99A, 0x8363, 0x0),
+    (0x2F99B, 0x83AD, 0x0),
+    (0x2F99C, 0x8323, 0x0),
+    (0x2F99D, 0x83BD, 0x0),
+    (0x2F99E, 0x83E7, 0x0),
+    (0x2F99F, 0x8457, 0x0),
+    (0x2F9A0, 0x8353, 0x0),
+    (0x2F9A1, 0x83CA, 0x0),
+    (0x2F9A2, 0x83CC, 0x0),
+    (0x2F9A3, 0x83DC, 0x0),
+    (0x2F9A4, 0x26C36, 0x0),
+    (0x2F9A5, 0x26D6B, 0x0),
+    (0x2F9A6, 0x26CD5, 0x0),
+    (0x2F9A7, 0x452B, 0x0),
+    (0x2F9A8, 0x84F1, 0x0),
+    (0x2F9A9, 0x84F3, 0x0),
+    (0x2F9AA, 0x8516, 0x0),
+    (0x2F9AB, 0x273CA, 0x0),
+    (0x2F9AC, 0x8564, 0x0),
+    (0x2F9AD, 0x26F2C, 0x0),
+    (0x2F9AE, 0x455D, 0x0),
+    (0x2F9AF, 0x4561, 0x0),
+    (0x2F9B0, 0x26FB1, 0x0),
+    (0x2F9B1, 0x270D2, 0x0),
+    (0x2F9B2, 0x456B, 0x0),
+    (0x2F9B3, 0x8650, 0x0),
+    (0x2F9B4, 0x865C, 0x0),
+    (0x2F9B5, 0x8667, 0x0),
+    (0x2F9B6, 0x8669, 0x0),
+    (0x2F9B7, 0x86A9, 0x0),
+    (0x2F9B8, 0x8688, 0x0),
+    (0x2F9B9, 0x870E, 0x0),
+    (0x2F9BA, 0x86E2, 0x0),
+    (0x2F9BB, 0x8779, 0x0),
+    (0x2F9BC, 0x8728, 0x0),
+    (0x2F9BD, 0x876B, 0x0),
+    (0x2F9BE, 0x8786, 0x0),
+    (0x2F9BF, 0x45D7, 0x0),
+    (0x2F9C0, 0x87E1, 0x0),
+    (0x2F9C1, 0x8801, 0x0),
+    (0x2F9C2, 0x45F9, 0x0),
+    (0x2F9C3, 0x8860, 0x0),
+    (0x2F9C4, 0x8863, 0x0),
+    (0x2F9C5, 0x27667, 0x0),
+    (0x2F9C6, 0x88D7, 0x0),
+    (0x2F9C7, 0x88DE, 0x0),
+    (0x2F9C8, 0x4635, 0x0),
+    (0x2F9C9, 0x88FA, 0x0),
+    (0x2F9CA, 0x34BB, 0x0),
+    (0x2F9CB, 0x278AE, 0x0),
+    (0x2F9CC, 0x27966, 0x0),
+    (0x2F9CD, 0x46BE, 0x0),
+    (0x2F9CE, 0x46C7, 0x0),
+    (0x2F9CF, 0x8AA0, 0x0),
+    (0x2F9D0, 0x8AED, 0x0),
+    (0x2F9D1, 0x8B8A, 0x0),
+    (0x2F9D2, 0x8C55, 0x0),
+    (0x2F9D3, 0x27CA8, 0x0),
+    (0x2F9D4, 0x8CAB, 0x0),
+    (0x2F9D5, 0x8CC1, 0x0),
+    (0x2F9D6, 0x8D1B, 0x0),
+    (0x2F9D7, 0x8D77, 0x0),
+    (0x2F9D8, 0x27F2F, 0x0),
+    (0x2F9D9, 0x20804, 0x0),
+    (0x2F9DA, 0x8DCB, 0x0),
+    (0x2F9DB, 0x8DBC, 0x0),
+    (0x2F9DC, 0x8DF0, 0x0),
+    (0x2F9DD, 0x208DE, 0x0),
+    (0x2F9DE, 0x8ED4, 0x0),
+    (0x2F9DF, 0x8F38, 0x0),
+    (0x2F9E0, 0x285D2, 0x0),
+    (0x2F9E1, 0x285ED, 0x0),
+    (0x2F9E2, 0x9094, 0x0),
+    (0x2F9E3, 0x90F1, 0x0),
+    (0x2F9E4, 0x9111, 0x0),
+    (0x2F9E5, 0x2872E, 0x0),
+    (0x2F9E6, 0x911B, 0x0),
+    (0x2F9E7, 0x9238, 0x0),
+    (0x2F9E8, 0x92D7, 0x0),
+    (0x2F9E9, 0x92D8, 0x0),
+    (0x2F9EA, 0x927C, 0x0),
+    (0x2F9EB, 0x93F9, 0x0),
+    (0x2F9EC, 0x9415, 0x0),
+    (0x2F9ED, 0x28BFA, 0x0),
+    (0x2F9EE, 0x958B, 0x0),
+    (0x2F9EF, 0x4995, 0x0),
+    (0x2F9F0, 0x95B7, 0x0),
+    (0x2F9F1, 0x28D77, 0x0),
+    (0x2F9F2, 0x49E6, 0x0),
+    (0x2F9F3, 0x96C3, 0x0),
+    (0x2F9F4, 0x5DB2, 0x0),
+    (0x2F9F5, 0x9723, 0x0),
+    (0x2F9F6, 0x29145, 0x0),
+    (0x2F9F7, 0x2921A, 0x0),
+    (0x2F9F8, 0x4A6E, 0x0),
+    (0x2F9F9, 0x4A76, 0x0),
+    (0x2F9FA, 0x97E0, 0x0),
+    (0x2F9FB, 0x2940A, 0x0),
+    (0x2F9FC, 0x4AB2, 0x0),
+    (0x2F9FD, 0x29496, 0x0),
+    (0x2F9FE, 0x980B, 0x0),
+    (0x2F9FF, 0x980B, 0x0),
+    (0x2FA00, 0x9829, 0x0),
+    (0x2FA01, 0x295B6, 0x0),
+    (0x2FA02, 0x98E2, 0x0),
+    (0x2FA03, 0x4B33, 0x0),
+    (0x2FA04, 0x9929, 0x0),
+    (0x2FA05, 0x99A7, 0x0),
+    (0x2FA06, 0x99C2, 0x0),
+    (0x2FA07, 0x99FE, 0x0),
+    (0x2FA08, 0x4BCE, 0x0),
+    (0x2FA09, 0x29B30, 0x0),
+    (0x2FA0A, 0x9B12, 0x0),
+    (0x2FA0B, 0x9C40, 0x0),
+    (0x2FA0C, 0x9CFD, 0x0),
+    (0x2FA0D, 0x4CCE, 0x0),
+    (0x2FA0E, 0x4CED, 0x0),
+    (0x2FA0F, 0x9D67, 0x0),
+    (0x2FA10, 0x2A0CE, 0x0),
+    (0x2FA11, 0x4CF8, 0x0),
+    (0x2FA12, 0x2A105, 0x0),
+    (0x2FA13, 0x2A20E, 0x0),
+    (0x2FA14, 0x2A291, 0x0),
+    (0x2FA15, 0x9EBB, 0x0),
+    (0x2FA16, 0x4D56, 0x0),
+    (0x2FA17, 0x9EF9, 0x0),
+    (0x2FA18, 0x9EFE, 0x0),
+    (0x2FA19, 0x9F05, 0x0),
+    (0x2FA1A, 0x9F0F, 0x0),
+    (0x2FA1B, 0x9F16, 0x0),
+    (0x2FA1C, 0x9F3B, 0x0),
+    (0x2FA1D, 0x2A600, 0x0),
+];
+
+// (1文字目, 2文字目, 合成後のコードポイント)
+pub static CANONICAL_COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E),
+    (0x3D, 0x338, 0x2260),
+    (0x3E, 0x338, 0x226F),
+    (0x41, 0x300, 0xC0),
+    (0x41, 0x301, 0xC1),
+    (0x41, 0x302, 0xC2),
+    (0x41, 0x303, 0xC3),
+    (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102),
+    (0x41, 0x307, 0x226),
+    (0x41, 0x308, 0xC4),
+    (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5),
+    (0x41, 0x30C, 0x1CD),
+    (0x41, 0x30F, 0x200),
+    (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1EA0),
+    (0x41, 0x325, 0x1E00),
+    (0x41, 0x328, 0x104),
+    (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04),
+    (0x42, 0x331, 0x1E06),
+    (0x43, 0x301, 0x106),
+    (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A),
+    (0x43, 0x30C, 0x10C),
+    (0x43, 0x327, 0xC7),
+    (0x44, 0x307, 0x1E0A),
+    (0x44, 0x30C, 0x10E),
+    (0x44, 0x323, 0x1E0C),
+    (0x44, 0x327, 0x1E10),
+    (0x44, 0x32D, 0x1E12),
+    (0x44, 0x331, 0x1E0E),
+    (0x45, 0x300, 0xC8),
+    (0x45, 0x301, 0xC9),
+    (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC),
+    (0x45, 0x304, 0x112),
+    (0x45, 0x306, 0x114),
+    (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB),
+    (0x45, 0x309, 0x1EBA),
+    (0x45, 0x30C, 0x11A),
+    (0x45, 0x30F, 0x204),
+    (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1EB8),
+    (0x45, 0x327, 0x228),
+    (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18),
+    (0x45, 0x330, 0x1E1A),
+    (0x46, 0x307, 0x1E1E),
+    (0x47, 0x301, 0x1F4),
+    (0x47, 0x302, 0x11C),
+    (0x47, 0x304, 0x1E20),
+    (0x47, 0x306, 0x11E),
+    (0x47, 0x307, 0x120),
+    (0x47, 0x30C, 0x1E6),
+    (0x47, 0x327, 0x122),
+    (0x48, 0x302, 0x124),
+    (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26),
+    (0x48, 0x30C, 0x21E),
+    (0x48, 0x323, 0x1E24),
+    (0x48, 0x327, 0x1E28),
+    (0x48, 0x32E, 0x1E2A),
+    (0x49, 0x300, 0xCC),
+    (0x49, 0x301, 0xCD),
+    (0x49, 0x302, 0xCE),
+    (0x49, 0x303, 0x128),
+    (0x49, 0x304, 0x12A),
+    (0x49, 0x306, 0x12C),
+    (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF),
+    (0x49, 0x309, 0x1EC8),
+    (0x49, 0x30C, 0x1CF),
+    (0x49, 0x30F, 0x208),
+    (0x49, 0x311, 0x20A),
+    (0x49, 0x323, 0x1ECA),
+    (0x49, 0x328, 0x12E),
+    (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134),
+    (0x4B, 0x301, 0x1E30),
+    (0x4B, 0x30C, 0x1E8),
+    (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136),
+    (0x4B, 0x331, 0x1E34),
+    (0x4C, 0x301, 0x139),
+    (0x4C, 0x30C, 0x13D),
+    (0x4C, 0x323, 0x1E36),
+    (0x4C, 0x327, 0x13B),
+    (0x4C, 0x32D, 0x1E3C),
+    (0x4C, 0x331, 0x1E3A),
+    (0x4D, 0x301, 0x1E3E),
+    (0x4D, 0x307, 0x1E40),
+    (0x4D, 0x323, 0x1E42),
+    (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143),
+    (0x4E, 0x303, 0xD1),
+    (0x4E, 0x307, 0x1E44),
+    (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46),
+    (0x4E, 0x327, 0x145),
+    (0x4E, 0x32D, 0x1E4A),
+    (0x4E, 0x331, 0x1E48),
+    (0x4F, 0x300, 0xD2),
+    (0x4F, 0x301, 0xD3),
+    (0x4F, 0x302, 0xD4),
+    (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C),
+    (0x4F, 0x306, 0x14E),
+    (0x4F, 0x307, 0x22E),
+    (0x4F, 0x308, 0xD6),
+    (0x4F, 0x309, 0x1ECE),
+    (0x4F, 0x30B, 0x150),
+    (0x4F, 0x30C, 0x1D1),
+    (0x4F, 0x30F, 0x20C),
+    (0x4F, 0x311, 0x20E),
+    (0x4F, 0x31B, 0x1A0),
+    (0x4F, 0x323, 0x1ECC),
+    (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54),
+    (0x50, 0x307, 0x1E56),
+    (0x52, 0x301, 0x154),
+    (0x52, 0x307, 0x1E58),
+    (0x52, 0x30C, 0x158),
+    (0x52, 0x30F, 0x210),
+    (0x52, 0x311, 0x212),
+    (0x52, 0x323, 0x1E5A),
+    (0x52, 0x327, 0x156),
+    (0x52, 0x331, 0x1E5E),
+    (0x53, 0x301, 0x15A),
+    (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60),
+    (0x53, 0x30C, 0x160),
+    (0x53, 0x323, 0x1E62),
+    (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15E),
+    (0x54, 0x307, 0x1E6A),
+    (0x54, 0x30C, 0x164),
+    (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A),
+    (0x54, 0x327, 0x162),
+    (0x54, 0x32D, 0x1E70),
+    (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9),
+    (0x55, 0x301, 0xDA),
+    (0x55, 0x302, 0xDB),
+    (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16A),
+    (0x55, 0x306, 0x16C),
+    (0x55, 0x308, 0xDC),
+    (0x55, 0x309, 0x1EE6),
+    (0x55, 0x30A, 0x16E),
+    (0x55, 0x30B, 0x170),
+    (0x55, 0x30C, 0x1D3),
+    (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216),
+    (0x55, 0x31B, 0x1AF),
+    (0x55, 0x323, 0x1EE4),
+    (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172),
+    (0x55, 0x32D, 0x1E76),
+    (0x55, 0x330, 0x1E74),
+    (0x56, 0x303, 0x1E7C),
+    (0x56, 0x323, 0x1E7E),
+    (0x57, 0x300, 0x1E80),
+    (0x57, 0x301, 0x1E82),
+    (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86),
+    (0x57, 0x308, 0x1E84),
+    (0x57, 0x323, 0x1E88),
+    (0x58, 0x307, 0x1E8A),
+    (0x58, 0x308, 0x1E8C),
+    (0x59, 0x300, 0x1EF2),
+    (0x59, 0x301, 0xDD),
+    (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1EF8),
+    (0x59, 0x304, 0x232),
+    (0x59, 0x307, 0x1E8E),
+    (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6),
+    (0x59, 0x323, 0x1EF4),
+    (0x5A, 0x301, 0x179),
+    (0x5A, 0x302, 0x1E90),
+    (0x5A, 0x307, 0x17B),
+    (0x5A, 0x30C, 0x17D),
+    (0x5A, 0x323, 0x1E92),
+    (0x5A, 0x331, 0x1E94),
+    (0x61, 0x300, 0xE0),
+    (0x61, 0x301, 0xE1),
+    (0x61, 0x302, 0xE2),
+    (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101),
+    (0x61, 0x306, 0x103),
+    (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xE4),
+    (0x61, 0x309, 0x1EA3),
+    (0x61, 0x30A, 0xE5),
+    (0x61, 0x30C, 0x1CE),
+    (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203),
+    (0x61, 0x323, 0x1EA1),
+    (0x61, 0x325, 0x1E01),
+    (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03),
+    (0x62, 0x323, 0x1E05),
+    (0x62, 0x331, 0x1E07),
+    (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109),
+    (0x63, 0x307, 0x10B),
+    (0x63, 0x30C, 0x10D),
+    (0x63, 0x327, 0xE7),
+    (0x64, 0x307, 0x1E0B),
+    (0x64, 0x30C, 0x10F),
+    (0x64, 0x323, 0x1E0D),
+    (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13),
+    (0x64, 0x331, 0x1E0F),
+    (0x65, 0x300, 0xE8),
+    (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA),
+    (0x65, 0x303, 0x1EBD),
+    (0x65, 0x304, 0x113),
+    (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xEB),
+    (0x65, 0x309, 0x1EBB),
+    (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205),
+    (0x65, 0x311, 0x207),
+    (0x65, 0x323, 0x1EB9),
+    (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119),
+    (0x65, 0x32D, 0x1E19),
+    (0x65, 0x330, 0x1E1B),
+    (0x66, 0x307, 0x1E1F),
+    (0x67, 0x301, 0x1F5),
+    (0x67, 0x302, 0x11D),
+    (0x67, 0x304, 0x1E21),
+    (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121),
+    (0x67, 0x30C, 0x1E7),
+    (0x67, 0x327, 0x123),
+    (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1E23),
+    (0x68, 0x308, 0x1E27),
+    (0x68, 0x30C, 0x21F),
+    (0x68, 0x323, 0x1E25),
+    (0x68, 0x327, 0x1E29),
+    (0x68, 0x32E, 0x1E2B),
+    (0x68, 0x331, 0x1E96),
+    (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED),
+    (0x69, 0x302, 0xEE),
+    (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12B),
+    (0x69, 0x306, 0x12D),
+    (0x69, 0x308, 0xEF),
+    (0x69, 0x309, 0x1EC9),
+    (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209),
+    (0x69, 0x311, 0x20B),
+    (0x69, 0x323, 0x1ECB),
+    (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D),
+    (0x6A, 0x302, 0x135),
+    (0x6A, 0x30C, 0x1F0),
+    (0x6B, 0x301, 0x1E31),
+    (0x6B, 0x30C, 0x1E9),
+    (0x6B, 0x323, 0x1E33),
+    (0x6B, 0x327, 0x137),
+    (0x6B, 0x331, 0x1E35),
+    (0x6C, 0x301, 0x13A),
+    (0x6C, 0x30C, 0x13E),
+    (0x6C, 0x323, 0x1E37),
+    (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D),
+    (0x6C, 0x331, 0x1E3B),
+    (0x6D, 0x301, 0x1E3F),
+    (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43),
+    (0x6E, 0x300, 0x1F9),
+    (0x6E, 0x301, 0x144),
+    (0x6E, 0x303, 0xF1),
+    (0x6E, 0x307, 0x1E45),
+    (0x6E, 0x30C, 0x148),
+    (0x6E, 0x323, 0x1E47),
+    (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B),
+    (0x6E, 0x331, 0x1E49),
+    (0x6F, 0x300, 0xF2),
+    (0x6F, 0x301, 0xF3),
+    (0x6F, 0x302, 0xF4),
+    (0x6F, 0x303, 0xF5),
+    (0x6F, 0x304, 0x14D),
+    (0x6F, 0x306, 0x14F),
+    (0x6F, 0x307, 0x22F),
+    (0x6F, 0x308, 0xF6),
+    (0x6F, 0x309, 0x1ECF),
+    (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2),
+    (0x6F, 0x30F, 0x20D),
+    (0x6F, 0x311, 0x20F),
+    (0x6F, 0x31B, 0x1A1),
+    (0x6F, 0x323, 0x1ECD),
+    (0x6F, 0x328, 0x1EB),
+    (0x70, 0x301, 0x1E55),
+    (0x70, 0x307, 0x1E57),
+    (0x72, 0x301, 0x155),
+    (0x72, 0x307, 0x1E59),
+    (0x72, 0x30C, 0x159),
+    (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213),
+    (0x72, 0x323, 0x1E5B),
+    (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1E5F),
+    (0x73, 0x301, 0x15B),
+    (0x73, 0x302, 0x15D),
+    (0x73, 0x307, 0x1E61),
+    (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63),
+    (0x73, 0x326, 0x219),
+    (0x73, 0x327, 0x15F),
+    (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97),
+    (0x74, 0x30C, 0x165),
+    (0x74, 0x323, 0x1E6D),
+    (0x74, 0x326, 0x21B),
+    (0x74, 0x327, 0x163),
+    (0x74, 0x32D, 0x1E71),
+    (0x74, 0x331, 0x1E6F),
+    (0x75, 0x300, 0xF9),
+    (0x75, 0x301, 0xFA),
+    (0x75, 0x302, 0xFB),
+    (0x75, 0x303, 0x169),
+    (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D),
+    (0x75, 0x308, 0xFC),
+    (0x75, 0x309, 0x1EE7),
+    (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171),
+    (0x75, 0x30C, 0x1D4),
+    (0x75, 0x30F, 0x215),
+    (0x75, 0x311, 0x217),
+    (0x75, 0x31B, 0x1B0),
+    (0x75, 0x323, 0x1EE5),
+    (0x75, 0x324, 0x1E73),
+    (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77),
+    (0x75, 0x330, 0x1E75),
+    (0x76, 0x303, 0x1E7D),
+    (0x76, 0x323, 0x1E7F),
+    (0x77, 0x300, 0x1E81),
+    (0x77, 0x301, 0x1E83),
+    (0x77, 0x302, 0x175),
+    (0x77, 0x307, 0x1E87),
+    (0x77, 0x308, 0x1E85),
+    (0x77, 0x30A, 0x1E98),
+    (0x77, 0x323, 0x1E89),
+    (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D),
+    (0x79, 0x300, 0x1EF3),
+    (0x79, 0x301, 0xFD),
+    (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1EF9),
+    (0x79, 0x304, 0x233),
+    (0x79, 0x307, 0x1E8F),
+    (0x79, 0x308, 0xFF),
+    (0x79, 0x309, 0x1EF7),
+    (0x79, 0x30A, 0x1E99),
+    (0x79, 0x323, 0x1EF5),
+    (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91),
+    (0x7A, 0x307, 0x17C),
+    (0x7A, 0x30C, 0x17E),
+    (0x7A, 0x323, 0x1E93),
+    (0x7A, 0x331, 0x1E95),
+    (0xA8, 0x300, 0x1FED),
+    (0xA8, 0x301, 0x385),
+    (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6),
+    (0xC2, 0x301, 0x1EA4),
+    (0xC2, 0x303, 0x1EAA),
+    (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE),
+    (0xC5, 0x301, 0x1FA),
+    (0xC6, 0x301, 0x1FC),
+    (0xC6, 0x304, 0x1E2),
+    (0xC7, 0x301, 0x1E08),
+    (0xCA, 0x300, 0x1EC0),
+    (0xCA, 0x301, 0x1EBE),
+    (0xCA, 0x303, 0x1EC4),
+    (0xCA, 0x309, 0x1EC2),
+    (0xCF, 0x301, 0x1E2E),
+    (0xD4, 0x300, 0x1ED2),
+    (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6),
+    (0xD4, 0x309, 0x1ED4),
+    (0xD5, 0x301, 0x1E4C),
+    (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E),
+    (0xD6, 0x304, 0x22A),
+    (0xD8, 0x301, 0x1FE),
+    (0xDC, 0x300, 0x1DB),
+    (0xDC, 0x301, 0x1D7),
+    (0xDC, 0x304, 0x1D5),
+    (0xDC, 0x30C, 0x1D9),
+    (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5),
+    (0xE2, 0x303, 0x1EAB),
+    (0xE2, 0x309, 0x1EA9),
+    (0xE4, 0x304, 0x1DF),
+    (0xE5, 0x301, 0x1FB),
+    (0xE6, 0x301, 0x1FD),
+    (0xE6, 0x304, 0x1E3),
+    (0xE7, 0x301, 0x1E09),
+    (0xEA, 0x300, 0x1EC1),
+    (0xEA, 0x301, 0x1EBF),
+    (0xEA, 0x303, 0x1EC5),
+    (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F),
+    (0xF4, 0x300, 0x1ED3),
+    (0xF4, 0x301, 0x1ED1),
+    (0xF4, 0x303, 0x1ED7),
+    (0xF4, 0x309, 0x1ED5),
+    (0xF5, 0x301, 0x1E4D),
+    (0xF5, 0x304, 0x22D),
+    (0xF5, 0x308, 0x1E4F),
+    (0xF6, 0x304, 0x22B),
+    (0xF8, 0x301, 0x1FF),
+    (0xFC, 0x300, 0x1DC),
+    (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6),
+    (0xFC, 0x30C, 0x1DA),
+    (0x102, 0x300, 0x1EB0),
+    (0x102, 0x301, 0x1EAE),
+    (0x102, 0x303, 0x1EB4),
+    (0x102, 0x309, 0x1EB2),
+    (0x103, 0x300, 0x1EB1),
+    (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5),
+    (0x103, 0x309, 0x1EB3),
+    (0x112, 0x300, 0x1E14),
+    (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15),
+    (0x113, 0x301, 0x1E17),
+    (0x14C, 0x300, 0x1E50),
+    (0x14C, 0x301, 0x1E52),
+    (0x14D, 0x300, 0x1E51),
+    (0x14D, 0x301, 0x1E53),
+    (0x15A, 0x307, 0x1E64),
+    (0x15B, 0x307, 0x1E65),
+    (0x160, 0x307, 0x1E66),
+    (0x161, 0x307, 0x1E67),
+    (0x168, 0x301, 0x1E78),
+    (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A),
+    (0x16B, 0x308, 0x1E7B),
+    (0x17F, 0x307, 0x1E9B),
+    (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA),
+    (0x1A0, 0x303, 0x1EE0),
+    (0x1A0, 0x309, 0x1EDE),
+    (0x1A0, 0x323, 0x1EE2),
+    (0x1A1, 0x300, 0x1EDD),
+    (0x1A1, 0x301, 0x1EDB),
+    (0x1A1, 0x303, 0x1EE1),
+    (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3),
+    (0x1AF, 0x300, 0x1EEA),
+    (0x1AF, 0x301, 0x1EE8),
+    (0x1AF, 0x303, 0x1EEE),
+    (0x1AF, 0x309, 0x1EEC),
+    (0x1AF, 0x323, 0x1EF0),
+    (0x1B0, 0x300, 0x1EEB),
+    (0x1B0, 0x301, 0x1EE9),
+    (0x1B0, 0x303, 0x1EEF),
+    (0x1B0, 0x309, 0x1EED),
+    (0x1B0, 0x323, 0x1EF1),
+    (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC),
+    (0x1EB, 0x304, 0x1ED),
+    (0x226, 0x304, 0x1E0),
+    (0x227, 0x304, 0x1E1),
+    (0x228, 0x306, 0x1E1C),
+    (0x229, 0x306, 0x1E1D),
+    (0x22E, 0x304, 0x230),
+    (0x22F, 0x304, 0x231),
+    (0x292, 0x30C, 0x1EF),
+    (0x391, 0x300, 0x1FBA),
+    (0x391, 0x301, 0x386),
+    (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8),
+    (0x391, 0x313, 0x1F08),
+    (0x391, 0x314, 0x1F09),
+    (0x391, 0x345, 0x1FBC),
+    (0x395, 0x300, 0x1FC8),
+    (0x395, 0x301, 0x388),
+    (0x395, 0x313, 0x1F18),
+    (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA),
+    (0x397, 0x301, 0x389),
+    (0x397, 0x313, 0x1F28),
+    (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC),
+    (0x399, 0x300, 0x1FDA),
+    (0x399, 0x301, 0x38A),
+    (0x399, 0x304, 0x1FD9),
+    (0x399, 0x306, 0x1FD8),
+    (0x399, 0x308, 0x3AA),
+    (0x399, 0x313, 0x1F38),
+    (0x399, 0x314, 0x1F39),
+    (0x39F, 0x300, 0x1FF8),
+    (0x39F, 0x301, 0x38C),
+    (0x39F, 0x313, 0x1F48),
+    (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC),
+    (0x3A5, 0x300, 0x1FEA),
+    (0x3A5, 0x301, 0x38E),
+    (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8),
+    (0x3A5, 0x308, 0x3AB),
+    (0x3A5, 0x314, 0x1F59),
+    (0x3A9, 0x300, 0x1FFA),
+    (0x3A9, 0x301, 0x38F),
+    (0x3A9, 0x313, 0x1F68),
+    (0x3A9, 0x314, 0x1F69),
+    (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4),
+    (0x3AE, 0x345, 0x1FC4),
+    (0x3B1, 0x300, 0x1F70),
+    (0x3B1, 0x301, 0x3AC),
+    (0x3B1, 0x304, 0x1FB1),
+    (0x3B1, 0x306, 0x1FB0),
+    (0x3B1, 0x313, 0x1F00),
+    (0x3B1, 0x314, 0x1F01),
+    (0x3B1, 0x342, 0x1FB6),
+    (0x3B1, 0x345, 0x1FB3),
+    (0x3B5, 0x300, 0x1F72),
+    (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10),
+    (0x3B5, 0x314, 0x1F11),
+    (0x3B7, 0x300, 0x1F74),
+    (0x3B7, 0x301, 0x3AE),
+    (0x3B7, 0x313, 0x1F20),
+    (0x3B7, 0x314, 0x1F21),
+    (0x3B7, 0x342, 0x1FC6),
+    (0x3B7, 0x345, 0x1FC3),
+    (0x3B9, 0x300, 0x1F76),
+    (0x3B9, 0x301, 0x3AF),
+    (0x3B9, 0x304, 0x1FD1),
+    (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA),
+    (0x3B9, 0x313, 0x1F30),
+    (0x3B9, 0x314, 0x1F31),
+    (0x3B9, 0x342, 0x1FD6),
+    (0x3BF, 0x300, 0x1F78),
+    (0x3BF, 0x301, 0x3CC),
+    (0x3BF, 0x313, 0x1F40),
+    (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4),
+    (0x3C1, 0x314, 0x1FE5),
+    (0x3C5, 0x300, 0x1F7A),
+    (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1),
+    (0x3C5, 0x306, 0x1FE0),
+    (0x3C5, 0x308, 0x3CB),
+    (0x3C5, 0x313, 0x1F50),
+    (0x3C5, 0x314, 0x1F51),
+    (0x3C5, 0x342, 0x1FE6),
+    (0x3C9, 0x300, 0x1F7C),
+    (0x3C9, 0x301, 0x3CE),
+    (0x3C9, 0x313, 0x1F60),
+    (0x3C9, 0x314, 0x1F61),
+    (0x3C9, 0x342, 0x1FF6),
+    (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2),
+    (0x3CA, 0x301, 0x390),
+    (0x3CA, 0x342, 0x1FD7),
+    (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0),
+    (0x3CB, 0x342, 0x1FE7),
+    (0x3CE, 0x345, 0x1FF4),
+    (0x3D2, 0x301, 0x3D3),
+    (0x3D2, 0x308, 0x3D4),
+    (0x406, 0x308, 0x407),
+    (0x410, 0x306, 0x4D0),
+    (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403),
+    (0x415, 0x300, 0x400),
+    (0x415, 0x306, 0x4D6),
+    (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4C1),
+    (0x416, 0x308, 0x4DC),
+    (0x417, 0x308, 0x4DE),
+    (0x418, 0x300, 0x40D),
+    (0x418, 0x304, 0x4E2),
+    (0x418, 0x306, 0x419),
+    (0x418, 0x308, 0x4E4),
+    (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6),
+    (0x423, 0x304, 0x4EE),
+    (0x423, 0x306, 0x40E),
+    (0x423, 0x308, 0x4F0),
+    (0x423, 0x30B, 0x4F2),
+    (0x427, 0x308, 0x4F4),
+    (0x42B, 0x308, 0x4F8),
+    (0x42D, 0x308, 0x4EC),
+    (0x430, 0x306, 0x4D1),
+    (0x430, 0x308, 0x4D3),
+    (0x433, 0x301, 0x453),
+    (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7),
+    (0x435, 0x308, 0x451),
+    (0x436, 0x306, 0x4C2),
+    (0x436, 0x308, 0x4DD),
+    (0x437, 0x308, 0x4DF),
+    (0x438, 0x300, 0x45D),
+    (0x438, 0x304, 0x4E3),
+    (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5),
+    (0x43A, 0x301, 0x45C),
+    (0x43E, 0x308, 0x4E7),
+    (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E),
+    (0x443, 0x308, 0x4F1),
+    (0x443, 0x30B, 0x4F3),
+    (0x447, 0x308, 0x4F5),
+    (0x44B, 0x308, 0x4F9),
+    (0x44D, 0x308, 0x4ED),
+    (0x456, 0x308, 0x457),
+    (0x474, 0x30F, 0x476),
+    (0x475, 0x30F, 0x477),
+    (0x4D8, 0x308, 0x4DA),
+    (0x4D9, 0x308, 0x4DB),
+    (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB),
+    (0x627, 0x653, 0x622),
+    (0x627, 0x654, 0x623),
+    (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624),
+    (0x64A, 0x654, 0x626),
+    (0x6C1, 0x654, 0x6C2),
+    (0x6D2, 0x654, 0x6D3),
+    (0x6D5, 0x654, 0x6C0),
+    (0x928, 0x93C, 0x929),
+    (0x930, 0x93C, 0x931),
+    (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB),
+    (0x9C7, 0x9D7, 0x9CC),
+    (0xB47, 0xB3E, 0xB4B),
+    (0xB47, 0xB56, 0xB48),
+    (0xB47, 0xB57, 0xB4C),
+    (0xB92, 0xBD7, 0xB94),
+    (0xBC6, 0xBBE, 0xBCA),
+    (0xBC6, 0xBD7, 0xBCC),
+    (0xBC7, 0xBBE, 0xBCB),
+    (0xC46, 0xC56, 0xC48),
+    (0xCBF, 0xCD5, 0xCC0),
+    (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7),
+    (0xCC6, 0xCD6, 0xCC8),
+    (0xCCA, 0xCD5, 0xCCB),
+    (0xD46, 0xD3E, 0xD4A),
+    (0xD46, 0xD57, 0xD4C),
+    (0xD47, 0xD3E, 0xD4B),
+    (0xDD9, 0xDCA, 0xDDA),
+    (0xDD9, 0xDCF, 0xDDC),
+    (0xDD9, 0xDDF, 0xDDE),
+    (0xDDC, 0xDCA, 0xDDD),
+    (0x1025, 0x102E, 0x1026),
+    (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08),
+    (0x1B09, 0x1B35, 0x1B0A),
+    (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12),
+    (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D),
+    (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43),
+    (0x1E36, 0x304, 0x1E38),
+    (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C),
+    (0x1E5B, 0x304, 0x1E5D),
+    (0x1E62, 0x307, 0x1E68),
+    (0x1E63, 0x307, 0x1E69),
+    (0x1EA0, 0x302, 0x1EAC),
+    (0x1EA0, 0x306, 0x1EB6),
+    (0x1EA1, 0x302, 0x1EAD),
+    (0x1EA1, 0x306, 0x1EB7),
+    (0x1EB8, 0x302, 0x1EC6),
+    (0x1EB9, 0x302, 0x1EC7),
+    (0x1ECC, 0x302, 0x1ED8),
+    (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02),
+    (0x1F00, 0x301, 0x1F04),
+    (0x1F00, 0x342, 0x1F06),
+    (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03),
+    (0x1F01, 0x301, 0x1F05),
+    (0x1F01, 0x342, 0x1F07),
+    (0x1F01, 0x345, 0x1F81),
+    (0x1F02, 0x345, 0x1F82),
+    (0x1F03, 0x345, 0x1F83),
+    (0x1F04, 0x345, 0x1F84),
+    (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86),
+    (0x1F07, 0x345, 0x1F87),
+    (0x1F08, 0x300, 0x1F0A),
+    (0x1F08, 0x301, 0x1F0C),
+    (0x1F08, 0x342, 0x1F0E),
+    (0x1F08, 0x345, 0x1F88),
+    (0x1F09, 0x300, 0x1F0B),
+    (0x1F09, 0x301, 0x1F0D),
+    (0x1F09, 0x342, 0x1F0F),
+    (0x1F09, 0x345, 0x1F89),
+    (0x1F0A, 0x345, 0x1F8A),
+    (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C),
+    (0x1F0D, 0x345, 0x1F8D),
+    (0x1F0E, 0x345, 0x1F8E),
+    (0x1F0F, 0x345, 0x1F8F),
+    (0x1F10, 0x300, 0x1F12),
+    (0x1F10, 0x301, 0x1F14),
+    (0x1F11, 0x300, 0x1F13),
+    (0x1F11, 0x301, 0x1F15),
+    (0x1F18, 0x300, 0x1F1A),
+    (0x1F18, 0x301, 0x1F1C),
+    (0x1F19, 0x300, 0x1F1B),
+    (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22),
+    (0x1F20, 0x301, 0x1F24),
+    (0x1F20, 0x342, 0x1F26),
+    (0x1F20, 0x345, 0x1F90),
+    (0x1F21, 0x300, 0x1F23),
+    (0x1F21, 0x301, 0x1F25),
+    (0x1F21, 0x342, 0x1F27),
+    (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92),
+    (0x1F23, 0x345, 0x1F93),
+    (0x1F24, 0x345, 0x1F94),
+    (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96),
+    (0x1F27, 0x345, 0x1F97),
+    (0x1F28, 0x300, 0x1F2A),
+    (0x1F28, 0x301, 0x1F2C),
+    (0x1F28, 0x342, 0x1F2E),
+    (0x1F28, 0x345, 0x1F98),
+    (0x1F29, 0x300, 0x1F2B),
+    (0x1F29, 0x301, 0x1F2D),
+    (0x1F29, 0x342, 0x1F2F),
+    (0x1F29, 0x345, 0x1F99),
+    (0x1F2A, 0x345, 0x1F9A),
+    (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C),
+    (0x1F2D, 0x345, 0x1F9D),
+    (0x1F2E, 0x345, 0x1F9E),
+    (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32),
+    (0x1F30, 0x301, 0x1F34),
+    (0x1F30, 0x342, 0x1F36),
+    (0x1F31, 0x300, 0x1F33),
+    (0x1F31, 0x301, 0x1F35),
+    (0x1F31, 0x342, 0x1F37),
+    (0x1F38, 0x300, 0x1F3A),
+    (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E),
+    (0x1F39, 0x300, 0x1F3B),
+    (0x1F39, 0x301, 0x1F3D),
+    (0x1F39, 0x342, 0x1F3F),
+    (0x1F40, 0x300, 0x1F42),
+    (0x1F40, 0x301, 0x1F44),
+    (0x1F41, 0x300, 0x1F43),
+    (0x1F41, 0x301, 0x1F45),
+    (0x1F48, 0x300, 0x1F4A),
+    (0x1F48, 0x301, 0x1F4C),
+    (0x1F49, 0x300, 0x1F4B),
+    (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52),
+    (0x1F50, 0x301, 0x1F54),
+    (0x1F50, 0x342, 0x1F56),
+    (0x1F51, 0x300, 0x1F53),
+    (0x1F51, 0x301, 0x1F55),
+    (0x1F51, 0x342, 0x1F57),
+    (0x1F59, 0x300, 0x1F5B),
+    (0x1F59, 0x301, 0x1F5D),
+    (0x1F59, 0x342, 0x1F5F),
+    (0x1F60, 0x300, 0x1F62),
+    (0x1F60, 0x301, 0x1F64),
+    (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0),
+    (0x1F61, 0x300, 0x1F63),
+    (0x1F61, 0x301, 0x1F65),
+    (0x1F61, 0x342, 0x1F67),
+    (0x1F61, 0x345, 0x1FA1),
+    (0x1F62, 0x345, 0x1FA2),
+    (0x1F63, 0x345, 0x1FA3),
+    (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5),
+    (0x1F66, 0x345, 0x1FA6),
+    (0x1F67, 0x345, 0x1FA7),
+    (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C),
+    (0x1F68, 0x342, 0x1F6E),
+    (0x1F68, 0x345, 0x1FA8),
+    (0x1F69, 0x300, 0x1F6B),
+    (0x1F69, 0x301, 0x1F6D),
+    (0x1F69, 0x342, 0x1F6F),
+    (0x1F69, 0x345, 0x1FA9),
+    (0x1F6A, 0x345, 0x1FAA),
+    (0x1F6B, 0x345, 0x1FAB),
+    (0x1F6C, 0x345, 0x1FAC),
+    (0x1F6D, 0x345, 0x1FAD),
+    (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF),
+    (0x1F70, 0x345, 0x1FB2),
+    (0x1F74, 0x345, 0x1FC2),
+    (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7),
+    (0x1FBF, 0x300, 0x1FCD),
+    (0x1FBF, 0x301, 0x1FCE),
+    (0x1FBF, 0x342, 0x1FCF),
+    (0x1FC6, 0x345, 0x1FC7),
+    (0x1FF6, 0x345, 0x1FF7),
+    (0x1FFE, 0x300, 0x1FDD),
+    (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF),
+    (0x2190, 0x338, 0x219A),
+    (0x2192, 0x338, 0x219B),
+    (0x2194, 0x338, 0x21AE),
+    (0x21D0, 0x338, 0x21CD),
+    (0x21D2, 0x338, 0x21CF),
+    (0x21D4, 0x338, 0x21CE),
+    (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209),
+    (0x220B, 0x338, 0x220C),
+    (0x2223, 0x338, 0x2224),
+    (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241),
+    (0x2243, 0x338, 0x2244),
+    (0x2245, 0x338, 0x2247),
+    (0x2248, 0x338, 0x2249),
+    (0x224D, 0x338, 0x226D),
+    (0x2261, 0x338, 0x2262),
+    (0x2264, 0x338, 0x2270),
+    (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274),
+    (0x2273, 0x338, 0x2275),
+    (0x2276, 0x338, 0x2278),
+    (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280),
+    (0x227B, 0x338, 0x2281),
+    (0x227C, 0x338, 0x22E0),
+    (0x227D, 0x338, 0x22E1),
+    (0x2282, 0x338, 0x2284),
+    (0x2283, 0x338, 0x2285),
+    (0x2286, 0x338, 0x2288),
+    (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2),
+    (0x2292, 0x338, 0x22E3),
+    (0x22A2, 0x338, 0x22AC),
+    (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE),
+    (0x22AB, 0x338, 0x22AF),
+    (0x22B2, 0x338, 0x22EA),
+    (0x22B3, 0x338, 0x22EB),
+    (0x22B4, 0x338, 0x22EC),
+    (0x22B5, 0x338, 0x22ED),
+    (0x3046, 0x3099, 0x3094),
+    (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050),
+    (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056),
+    (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E),
+    (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062),
+    (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069),
+    (0x306F, 0x3099, 0x3070),
+    (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073),
+    (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4),
+    (0x30AB, 0x3099, 0x30AC),
+    (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0),
+    (0x30B1, 0x3099, 0x30B2),
+    (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC),
+    (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5),
+    (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9),
+    (0x30CF, 0x3099, 0x30D0),
+    (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4),
+    (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7),
+    (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC),
+    (0x30DB, 0x309A, 0x30DD),
+    (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9),
+    (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A),
+    (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB),
+    (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C),
+    (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB),
+    (0x114B9, 0x114BD, 0x114BE),
+    (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
@@ -0,0 +1,156 @@
+//! https://html.spec.whatwg.org/multipage/browsers.html#origin
+
+use crate::url::host::Host;
+use alloc::string::String;
+use core::fmt;
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+// 不透明なオリジンを区別するための通し番号
+static NEXT_OPAQUE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// https://html.spec.whatwg.org/multipage/browsers.html#concept-origin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    /// https://html.spec.whatwg.org/multipage/browsers.html#concept-origin-tuple
+    Tuple {
+        scheme: String,
+        host: Host,
+        port: Option<u16>,
+    },
+    // "data:"や"file:"のURLのオリジン。自分自身とだけ同じオリジンになる
+    /// https://html.spec.whatwg.org/multipage/browsers.html#concept-origin-opaque
+    Opaque(u64),
+}
+
+impl Origin {
+    pub fn new_tuple(scheme: &str, host: Host, port: Option<u16>) -> Self {
+        Origin::Tuple {
+            scheme: String::from(scheme),
+            host,
+            port,
+        }
+    }
+
+    // 作るたびに他のどのオリジンとも異なるオリジンになる
+    pub fn new_opaque() -> Self {
+        Origin::Opaque(NEXT_OPAQUE_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, Origin::Opaque(_))
+    }
+
+    /// https://html.spec.whatwg.org/multipage/browsers.html#same-origin
+    pub fn is_same_origin(&self, other: &Origin) -> bool {
+        self == other
+    }
+
+    /// https://html.spec.whatwg.org/multipage/browsers.html#same-site
+    pub fn is_same_site(&self, other: &Origin) -> bool {
+        if !self.is_schemelessly_same_site(other) {
+            return false;
+        }
+
+        match (self, other) {
+            (Origin::Opaque(_), Origin::Opaque(_)) => true,
+            (Origin::Tuple { scheme: a, .. }, Origin::Tuple { scheme: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+
+    /// https://html.spec.whatwg.org/multipage/browsers.html#schemelessly-same-site
+    pub fn is_schemelessly_same_site(&self, other: &Origin) -> bool {
+        match (self, other) {
+            (Origin::Opaque(_), Origin::Opaque(_)) => self.is_same_origin(other),
+            (Origin::Tuple { host: a, .. }, Origin::Tuple { host: b, .. }) => {
+                // "a.example.com"と"b.example.com"は登録可能ドメインが同じなので同じサイト
+                match a.registrable_domain() {
+                    Some(domain) => b.registrable_domain() == Some(domain),
+                    None => a == b,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    /// https://html.spec.whatwg.org/multipage/browsers.html#ascii-serialisation-of-an-origin
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Opaque(_) => f.write_str("null"),
+            Origin::Tuple { scheme, host, port } => {
+                write!(f, "{}://{}", scheme, host)?;
+                if let Some(port) = port {
+                    write!(f, ":{}", port)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::url::Url;
+    use alloc::string::ToString;
+
+    fn origin(url: &str) -> super::Origin {
+        Url::new(url.to_string())
+            .parse()
+            .expect("failed to parse url")
+            .origin()
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            origin("http://example.com/a?b#c").to_string(),
+            "http://example.com"
+        );
+        assert_eq!(
+            origin("https://example.com:443/").to_string(),
+            "https://example.com"
+        );
+        assert_eq!(
+            origin("http://[::1]:8000/").to_string(),
+            "http://[::1]:8000"
+        );
+        assert_eq!(origin("data:,hello").to_string(), "null");
+        assert_eq!(origin("file:///tmp/a.html").to_string(), "null");
+    }
+
+    #[test]
+    fn test_same_origin() {
+        assert!(origin("http://example.com/a").is_same_origin(&origin("http://EXAMPLE.com:80/b")));
+        assert!(!origin("http://example.com/").is_same_origin(&origin("https://example.com/")));
+        assert!(!origin("http://example.com/").is_same_origin(&origin("http://example.com:8000/")));
+        assert!(!origin("http://example.com/").is_same_origin(&origin("http://www.example.com/")));
+    }
+
+    #[test]
+    fn test_opaque() {
+        let data = origin("data:,hello");
+        assert!(data.is_opaque());
+        assert!(data.is_same_origin(&data.clone()));
+        assert!(!data.is_same_origin(&origin("data:,hello")));
+        assert!(!data.is_same_site(&origin("http://example.com/")));
+    }
+
+    #[test]
+    fn test_same_site() {
+        let a = origin("http://example.com/");
+        assert!(a.is_same_site(&origin("http://example.com:8000/")));
+        assert!(!a.is_same_site(&origin("https://example.com/")));
+        assert!(a.is_schemelessly_same_site(&origin("https://example.com/")));
+
+        // 登録可能ドメインが同じなら同じサイト
+        let a = origin("http://a.example.co.jp/");
+        assert!(a.is_same_site(&origin("http://b.example.co.jp/")));
+        assert!(!a.is_same_site(&origin("http://other.co.jp/")));
+        assert!(!origin("http://foo.bar.kawasaki.jp/")
+            .is_same_site(&origin("http://baz.bar.kawasaki.jp/")));
+        assert!(origin("http://127.0.0.1/").is_same_site(&origin("http://127.0.0.1:8000/")));
+    }
+}
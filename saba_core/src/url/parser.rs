@@ -0,0 +1,658 @@
+//! https://url.spec.whatwg.org/#concept-basic-url-parser
+
+use crate::url::host::Host;
+use crate::url::is_special_scheme;
+use crate::url::percent_encoding::utf8_percent_encode;
+use crate::url::percent_encoding::utf8_percent_encode_char;
+use crate::url::percent_encoding::EncodeSet;
+use crate::url::default_port;
+use crate::url::Url;
+use crate::url::UrlError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// https://url.spec.whatwg.org/#scheme-start-state
+    SchemeStart,
+    /// https://url.spec.whatwg.org/#scheme-state
+    Scheme,
+    /// https://url.spec.whatwg.org/#no-scheme-state
+    NoScheme,
+    /// https://url.spec.whatwg.org/#special-relative-or-authority-state
+    SpecialRelativeOrAuthority,
+    /// https://url.spec.whatwg.org/#path-or-authority-state
+    PathOrAuthority,
+    /// https://url.spec.whatwg.org/#relative-state
+    Relative,
+    /// https://url.spec.whatwg.org/#relative-slash-state
+    RelativeSlash,
+    /// https://url.spec.whatwg.org/#special-authority-slashes-state
+    SpecialAuthoritySlashes,
+    /// https://url.spec.whatwg.org/#special-authority-ignore-slashes-state
+    SpecialAuthorityIgnoreSlashes,
+    /// https://url.spec.whatwg.org/#authority-state
+    Authority,
+    /// https://url.spec.whatwg.org/#host-state
+    Host,
+    /// https://url.spec.whatwg.org/#port-state
+    Port,
+    /// https://url.spec.whatwg.org/#file-state
+    File,
+    /// https://url.spec.whatwg.org/#file-slash-state
+    FileSlash,
+    /// https://url.spec.whatwg.org/#file-host-state
+    FileHost,
+    /// https://url.spec.whatwg.org/#path-start-state
+    PathStart,
+    /// https://url.spec.whatwg.org/#path-state
+    Path,
+    /// https://url.spec.whatwg.org/#cannot-be-a-base-url-path-state
+    OpaquePath,
+    /// https://url.spec.whatwg.org/#query-state
+    Query,
+    /// https://url.spec.whatwg.org/#fragment-state
+    Fragment,
+}
+
+pub struct UrlParser {
+    state: State,
+    input: Vec<char>,
+    // 仕様の"pointer"。先頭より前（-1）を指すことがあるため符号付き整数にする
+    pointer: isize,
+    buf: String,
+    at_sign_seen: bool,
+    inside_brackets: bool,
+    password_token_seen: bool,
+    // 相対URLを解決するための基準URL
+    base: Option<Url>,
+    url: Url,
+}
+
+impl UrlParser {
+    pub fn new(input: &str, base: Option<&Url>) -> Self {
+        // 前後のC0制御文字と空白を取り除き、タブと改行をすべて削除する
+        let input: Vec<char> = input
+            .trim_matches(|c: char| c <= ' ')
+            .chars()
+            .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+            .collect();
+
+        Self {
+            state: State::SchemeStart,
+            input,
+            pointer: 0,
+            buf: String::new(),
+            at_sign_seen: false,
+            inside_brackets: false,
+            password_token_seen: false,
+            base: base.cloned(),
+            url: Url::new(String::new()),
+        }
+    }
+
+    pub fn parse(mut self) -> Result<Url, UrlError> {
+        loop {
+            let c = self.c();
+            self.step(c)?;
+
+            // EOFを処理し終えたら終了する
+            if self.pointer >= self.input.len() as isize {
+                break;
+            }
+            self.pointer += 1;
+        }
+
+        Ok(self.url)
+    }
+
+    // 現在の位置の文字。EOFの場合はNoneを返す
+    fn c(&self) -> Option<char> {
+        self.char_at(self.pointer)
+    }
+
+    fn char_at(&self, pointer: isize) -> Option<char> {
+        if pointer < 0 {
+            return None;
+        }
+        self.input.get(pointer as usize).copied()
+    }
+
+    // 現在の位置より後ろの文字列が指定の文字列で始まるかどうか
+    fn remaining_starts_with(&self, s: &str) -> bool {
+        s.chars()
+            .enumerate()
+            .all(|(i, c)| self.char_at(self.pointer + 1 + i as isize) == Some(c))
+    }
+
+    // 現在の位置から始まる文字列がWindowsのドライブレター（C:など）で始まるかどうか
+    /// https://url.spec.whatwg.org/#start-with-a-windows-drive-letter
+    fn starts_with_windows_drive_letter(&self) -> bool {
+        let first = self.char_at(self.pointer);
+        let second = self.char_at(self.pointer + 1);
+        if !first.is_some_and(|c| c.is_ascii_alphabetic()) || !matches!(second, Some(':' | '|')) {
+            return false;
+        }
+        matches!(
+            self.char_at(self.pointer + 2),
+            None | Some('/' | '\\' | '?' | '#')
+        )
+    }
+
+    // 基準URLのスキームが指定のスキームと一致するかどうか
+    fn base_scheme_is(&self, scheme: &str) -> bool {
+        self.base.as_ref().is_some_and(|base| base.scheme == scheme)
+    }
+
+    fn is_special(&self) -> bool {
+        is_special_scheme(&self.url.scheme)
+    }
+
+    // `\`を`/`と同じように扱う特別なスキームかどうかを考慮した区切り文字の判定
+    fn is_path_separator(&self, c: Option<char>) -> bool {
+        c == Some('/') || (self.is_special() && c == Some('\\'))
+    }
+
+    fn step(&mut self, c: Option<char>) -> Result<(), UrlError> {
+        match self.state {
+            State::SchemeStart => {
+                if let Some(c) = c.filter(|c| c.is_ascii_alphabetic()) {
+                    self.buf.push(c.to_ascii_lowercase());
+                    self.state = State::Scheme;
+                    return Ok(());
+                }
+
+                self.state = State::NoScheme;
+                self.pointer -= 1;
+            }
+
+            State::Scheme => {
+                if let Some(c) = c.filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) {
+                    self.buf.push(c.to_ascii_lowercase());
+                    return Ok(());
+                }
+
+                if c == Some(':') {
+                    self.url.scheme = core::mem::take(&mut self.buf);
+
+                    if self.url.scheme == "file" {
+                        self.state = State::File;
+                    } else if self.is_special() && self.base_scheme_is(&self.url.scheme) {
+                        self.state = State::SpecialRelativeOrAuthority;
+                    } else if self.is_special() {
+                        self.state = State::SpecialAuthoritySlashes;
+                    } else if self.remaining_starts_with("/") {
+                        self.state = State::PathOrAuthority;
+                        self.pointer += 1;
+                    } else {
+                        self.url.opaque_path = true;
+                        self.url.path = Vec::from([String::new()]);
+                        self.state = State::OpaquePath;
+                    }
+                    return Ok(());
+                }
+
+                // スキームではなかったので最初からやり直す
+                self.buf.clear();
+                self.state = State::NoScheme;
+                self.pointer = -1;
+            }
+
+            State::NoScheme => {
+                let base = match &self.base {
+                    Some(base) => base,
+                    None => return Err(UrlError::MissingScheme),
+                };
+
+                if base.opaque_path {
+                    // "mailto:"のようなURLを基準にできるのはフラグメントだけ
+                    if c != Some('#') {
+                        return Err(UrlError::MissingScheme);
+                    }
+                    self.url.scheme = base.scheme.clone();
+                    self.url.path = base.path.clone();
+                    self.url.opaque_path = true;
+                    self.url.query = base.query.clone();
+                    self.url.fragment = Some(String::new());
+                    self.state = State::Fragment;
+                } else if base.scheme != "file" {
+                    self.state = State::Relative;
+                    self.pointer -= 1;
+                } else {
+                    self.state = State::File;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::SpecialRelativeOrAuthority => {
+                if c == Some('/') && self.remaining_starts_with("/") {
+                    self.state = State::SpecialAuthorityIgnoreSlashes;
+                    self.pointer += 1;
+                } else {
+                    self.state = State::Relative;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::PathOrAuthority => {
+                if c == Some('/') {
+                    self.state = State::Authority;
+                } else {
+                    self.state = State::Path;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::Relative => {
+                let base = match self.base.clone() {
+                    Some(base) => base,
+                    None => return Err(UrlError::MissingScheme),
+                };
+                self.url.scheme = base.scheme.clone();
+
+                if self.is_path_separator(c) {
+                    self.state = State::RelativeSlash;
+                    return Ok(());
+                }
+
+                // "?q"や"#frag"、"g"のような参照は基準URLのホストとパスを引き継ぐ
+                self.url.username = base.username;
+                self.url.password = base.password;
+                self.url.host = base.host;
+                self.url.port = base.port;
+                self.url.path = base.path;
+                self.url.query = base.query;
+
+                match c {
+                    Some('?') => {
+                        self.url.query = Some(String::new());
+                        self.state = State::Query;
+                    }
+                    Some('#') => {
+                        self.url.fragment = Some(String::new());
+                        self.state = State::Fragment;
+                    }
+                    Some(_) => {
+                        self.url.query = None;
+                        self.url.shorten_path();
+                        self.state = State::Path;
+                        self.pointer -= 1;
+                    }
+                    None => {}
+                }
+            }
+
+            State::RelativeSlash => {
+                if self.is_special() && (c == Some('/') || c == Some('\\')) {
+                    self.state = State::SpecialAuthorityIgnoreSlashes;
+                } else if c == Some('/') {
+                    self.state = State::Authority;
+                } else {
+                    // "/g"のような参照は基準URLのホストだけを引き継ぐ
+                    if let Some(base) = &self.base {
+                        self.url.username = base.username.clone();
+                        self.url.password = base.password.clone();
+                        self.url.host = base.host.clone();
+                        self.url.port = base.port;
+                    }
+                    self.state = State::Path;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::SpecialAuthoritySlashes => {
+                if c == Some('/') && self.remaining_starts_with("/") {
+                    self.state = State::SpecialAuthorityIgnoreSlashes;
+                    self.pointer += 1;
+                } else {
+                    self.state = State::SpecialAuthorityIgnoreSlashes;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::SpecialAuthorityIgnoreSlashes => {
+                if c != Some('/') && c != Some('\\') {
+                    self.state = State::Authority;
+                    self.pointer -= 1;
+                }
+            }
+
+            State::Authority => {
+                if c == Some('@') {
+                    // 2つ目以降の`@`はユーザー情報の一部として扱う
+                    if self.at_sign_seen {
+                        self.buf.insert_str(0, "%40");
+                    }
+                    self.at_sign_seen = true;
+
+                    let buf = core::mem::take(&mut self.buf);
+                    for code_point in buf.chars() {
+                        if code_point == ':' && !self.password_token_seen {
+                            self.password_token_seen = true;
+                            continue;
+                        }
+
+                        if self.password_token_seen {
+                            utf8_percent_encode_char(code_point, EncodeSet::Userinfo, &mut self.url.password);
+                        } else {
+                            utf8_percent_encode_char(code_point, EncodeSet::Userinfo, &mut self.url.username);
+                        }
+                    }
+                    return Ok(());
+                }
+
+                if c.is_none() || matches!(c, Some('/' | '?' | '#')) || self.is_path_separator(c) {
+                    if self.at_sign_seen && self.buf.is_empty() {
+                        return Err(UrlError::MissingHost);
+                    }
+
+                    // バッファの先頭まで戻ってホストとして読み直す
+                    self.pointer -= self.buf.chars().count() as isize + 1;
+                    self.buf.clear();
+                    self.state = State::Host;
+                    return Ok(());
+                }
+
+                if let Some(c) = c {
+                    self.buf.push(c);
+                }
+            }
+
+            State::Host => {
+                if c == Some(':') && !self.inside_brackets {
+                    if self.buf.is_empty() {
+                        return Err(UrlError::MissingHost);
+                    }
+
+                    self.url.host = Some(Host::parse(&self.buf, !self.is_special())?);
+                    self.buf.clear();
+                    self.state = State::Port;
+                    return Ok(());
+                }
+
+                if c.is_none() || matches!(c, Some('/' | '?' | '#')) || self.is_path_separator(c) {
+                    self.pointer -= 1;
+
+                    if self.is_special() && self.buf.is_empty() {
+                        return Err(UrlError::MissingHost);
+                    }
+
+                    self.url.host = Some(Host::parse(&self.buf, !self.is_special())?);
+                    self.buf.clear();
+                    self.state = State::PathStart;
+                    return Ok(());
+                }
+
+                if let Some(c) = c {
+                    if c == '[' {
+                        self.inside_brackets = true;
+                    }
+                    if c == ']' {
+                        self.inside_brackets = false;
+                    }
+                    self.buf.push(c);
+                }
+            }
+
+            State::Port => {
+                if let Some(c) = c.filter(|c| c.is_ascii_digit()) {
+                    self.buf.push(c);
+                    return Ok(());
+                }
+
+                if c.is_none() || matches!(c, Some('/' | '?' | '#')) || self.is_path_separator(c) {
+                    if !self.buf.is_empty() {
+                        let port: u16 = match self.buf.parse() {
+                            Ok(port) => port,
+                            Err(_) => return Err(UrlError::InvalidPort),
+                        };
+
+                        // スキームのデフォルトポートと同じ場合は保持しない
+                        self.url.port = if default_port(&self.url.scheme) == Some(port) {
+                            None
+                        } else {
+                            Some(port)
+                        };
+                        self.buf.clear();
+                    }
+
+                    self.state = State::PathStart;
+                    self.pointer -= 1;
+                    return Ok(());
+                }
+
+                return Err(UrlError::InvalidPort);
+            }
+
+            State::File => {
+                self.url.scheme = String::from("file");
+                self.url.host = Some(Host::Empty);
+
+                if c == Some('/') || c == Some('\\') {
+                    self.state = State::FileSlash;
+                    return Ok(());
+                }
+
+                let base = match &self.base {
+                    Some(base) if base.scheme == "file" => base.clone(),
+                    _ => {
+                        self.state = State::Path;
+                        self.pointer -= 1;
+                        return Ok(());
+                    }
+                };
+
+                self.url.host = base.host;
+                self.url.path = base.path;
+                self.url.query = base.query;
+
+                match c {
+                    Some('?') => {
+                        self.url.query = Some(String::new());
+                        self.state = State::Query;
+                    }
+                    Some('#') => {
+                        self.url.fragment = Some(String::new());
+                        self.state = State::Fragment;
+                    }
+                    Some(_) => {
+                        self.url.query = None;
+                        if self.starts_with_windows_drive_letter() {
+                            self.url.path = Vec::new();
+                        } else {
+                            self.url.shorten_path();
+                        }
+                        self.state = State::Path;
+                        self.pointer -= 1;
+                    }
+                    None => {}
+                }
+            }
+
+            State::FileSlash => {
+                if c == Some('/') || c == Some('\\') {
+                    self.state = State::FileHost;
+                    return Ok(());
+                }
+
+                if let Some(base) = self.base.clone().filter(|base| base.scheme == "file") {
+                    self.url.host = base.host;
+                    // "/g"のような参照でも基準URLのドライブレターは引き継ぐ
+                    if !self.starts_with_windows_drive_letter() {
+                        if let Some(drive) = base.path.first().filter(|p| is_normalized_windows_drive_letter(p)) {
+                            self.url.path.push(drive.clone());
+                        }
+                    }
+                }
+                self.state = State::Path;
+                self.pointer -= 1;
+            }
+
+            State::FileHost => {
+                if c.is_none() || matches!(c, Some('/' | '\\' | '?' | '#')) {
+                    self.pointer -= 1;
+
+                    if is_windows_drive_letter(&self.buf) {
+                        // `file://C:/`のような場合はホストではなくパスとして扱う
+                        self.state = State::Path;
+                    } else if self.buf.is_empty() {
+                        self.url.host = Some(Host::Empty);
+                        self.state = State::PathStart;
+                    } else {
+                        let mut host = Host::parse(&self.buf, !self.is_special())?;
+                        if host == Host::Domain(String::from("localhost")) {
+                            host = Host::Empty;
+                        }
+                        self.url.host = Some(host);
+                        self.buf.clear();
+                        self.state = State::PathStart;
+                    }
+                    return Ok(());
+                }
+
+                if let Some(c) = c {
+                    self.buf.push(c);
+                }
+            }
+
+            State::PathStart => {
+                if self.is_special() {
+                    self.state = State::Path;
+                    if c != Some('/') && c != Some('\\') {
+                        self.pointer -= 1;
+                    }
+                } else if c == Some('?') {
+                    self.url.query = Some(String::new());
+                    self.state = State::Query;
+                } else if c == Some('#') {
+                    self.url.fragment = Some(String::new());
+                    self.state = State::Fragment;
+                } else if c.is_some() {
+                    self.state = State::Path;
+                    if c != Some('/') {
+                        self.pointer -= 1;
+                    }
+                }
+            }
+
+            State::Path => {
+                if c.is_none() || matches!(c, Some('?' | '#')) || self.is_path_separator(c) {
+                    let is_separator = self.is_path_separator(c);
+
+                    if is_double_dot_segment(&self.buf) {
+                        self.url.shorten_path();
+                        if !is_separator {
+                            self.url.path.push(String::new());
+                        }
+                    } else if is_single_dot_segment(&self.buf) {
+                        if !is_separator {
+                            self.url.path.push(String::new());
+                        }
+                    } else {
+                        if self.url.scheme == "file"
+                            && self.url.path.is_empty()
+                            && is_windows_drive_letter(&self.buf)
+                        {
+                            // `C|`を`C:`に正規化する
+                            self.buf.replace_range(1..2, ":");
+                        }
+                        self.url.path.push(core::mem::take(&mut self.buf));
+                    }
+                    self.buf.clear();
+
+                    if c == Some('?') {
+                        self.url.query = Some(String::new());
+                        self.state = State::Query;
+                    }
+                    if c == Some('#') {
+                        self.url.fragment = Some(String::new());
+                        self.state = State::Fragment;
+                    }
+                    return Ok(());
+                }
+
+                if let Some(c) = c {
+                    utf8_percent_encode_char(c, EncodeSet::Path, &mut self.buf);
+                }
+            }
+
+            State::OpaquePath => {
+                match c {
+                    Some('?') => {
+                        self.url.query = Some(String::new());
+                        self.state = State::Query;
+                    }
+                    Some('#') => {
+                        self.url.fragment = Some(String::new());
+                        self.state = State::Fragment;
+                    }
+                    Some(c) => {
+                        if let Some(path) = self.url.path.first_mut() {
+                            utf8_percent_encode_char(c, EncodeSet::C0Control, path);
+                        }
+                    }
+                    None => {}
+                }
+            }
+
+            State::Query => {
+                if c.is_none() || c == Some('#') {
+                    let set = if self.is_special() {
+                        EncodeSet::SpecialQuery
+                    } else {
+                        EncodeSet::Query
+                    };
+                    let encoded = utf8_percent_encode(&self.buf, set);
+                    self.url.query.get_or_insert_with(String::new).push_str(&encoded);
+                    self.buf.clear();
+
+                    if c == Some('#') {
+                        self.url.fragment = Some(String::new());
+                        self.state = State::Fragment;
+                    }
+                    return Ok(());
+                }
+
+                if let Some(c) = c {
+                    self.buf.push(c);
+                }
+            }
+
+            State::Fragment => {
+                if let Some(c) = c {
+                    let fragment = self.url.fragment.get_or_insert_with(String::new);
+                    utf8_percent_encode_char(c, EncodeSet::Fragment, fragment);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// https://url.spec.whatwg.org/#windows-drive-letter
+pub fn is_windows_drive_letter(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(
+        (chars.next(), chars.next(), chars.next()),
+        (Some(a), Some(':' | '|'), None) if a.is_ascii_alphabetic()
+    )
+}
+
+/// https://url.spec.whatwg.org/#normalized-windows-drive-letter
+pub fn is_normalized_windows_drive_letter(s: &str) -> bool {
+    is_windows_drive_letter(s) && s.ends_with(':')
+}
+
+/// https://url.spec.whatwg.org/#single-dot-path-segment
+fn is_single_dot_segment(s: &str) -> bool {
+    s == "." || s.eq_ignore_ascii_case("%2e")
+}
+
+/// https://url.spec.whatwg.org/#double-dot-path-segment
+fn is_double_dot_segment(s: &str) -> bool {
+    matches!(
+        s.to_ascii_lowercase().as_str(),
+        ".." | ".%2e" | "%2e." | "%2e%2e"
+    )
+}
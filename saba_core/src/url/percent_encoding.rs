@@ -0,0 +1,143 @@
+//! https://url.spec.whatwg.org/#percent-encoded-bytes
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// https://url.spec.whatwg.org/#percent-encoded-bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeSet {
+    /// https://url.spec.whatwg.org/#c0-control-percent-encode-set
+    C0Control,
+    /// https://url.spec.whatwg.org/#fragment-percent-encode-set
+    Fragment,
+    /// https://url.spec.whatwg.org/#query-percent-encode-set
+    Query,
+    /// https://url.spec.whatwg.org/#special-query-percent-encode-set
+    SpecialQuery,
+    /// https://url.spec.whatwg.org/#path-percent-encode-set
+    Path,
+    /// https://url.spec.whatwg.org/#userinfo-percent-encode-set
+    Userinfo,
+    /// https://url.spec.whatwg.org/#component-percent-encode-set
+    Component,
+    /// https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set
+    FormUrlencoded,
+}
+
+impl EncodeSet {
+    // バイトがこのセットに含まれる（＝エンコードが必要）かどうか
+    pub fn contains(&self, b: u8) -> bool {
+        // C0制御文字と0x7Eより大きいバイトはすべてのセットに含まれる
+        if !(0x20..=0x7E).contains(&b) {
+            return true;
+        }
+
+        match self {
+            EncodeSet::C0Control => false,
+            EncodeSet::Fragment => matches!(b, b' ' | b'"' | b'<' | b'>' | b'`'),
+            EncodeSet::Query => matches!(b, b' ' | b'"' | b'#' | b'<' | b'>'),
+            EncodeSet::SpecialQuery => EncodeSet::Query.contains(b) || b == b'\'',
+            EncodeSet::Path => {
+                EncodeSet::Query.contains(b) || matches!(b, b'?' | b'^' | b'`' | b'{' | b'}')
+            }
+            EncodeSet::Userinfo => {
+                EncodeSet::Path.contains(b)
+                    || matches!(b, b'/' | b':' | b';' | b'=' | b'@' | b'[' | b'\\' | b']' | b'|')
+            }
+            EncodeSet::Component => {
+                EncodeSet::Userinfo.contains(b) || matches!(b, b'$' | b'%' | b'&' | b'+' | b',')
+            }
+            EncodeSet::FormUrlencoded => {
+                EncodeSet::Component.contains(b) || matches!(b, b'!' | b'\'' | b'(' | b')' | b'~')
+            }
+        }
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// https://url.spec.whatwg.org/#percent-encode
+pub fn percent_encode_byte(b: u8, out: &mut String) {
+    out.push('%');
+    out.push(HEX_DIGITS[(b >> 4) as usize] as char);
+    out.push(HEX_DIGITS[(b & 0x0F) as usize] as char);
+}
+
+/// https://url.spec.whatwg.org/#utf-8-percent-encode
+pub fn utf8_percent_encode_char(c: char, set: EncodeSet, out: &mut String) {
+    let mut buf = [0u8; 4];
+    for b in c.encode_utf8(&mut buf).bytes() {
+        if set.contains(b) {
+            percent_encode_byte(b, out);
+        } else {
+            out.push(b as char);
+        }
+    }
+}
+
+/// https://url.spec.whatwg.org/#string-utf-8-percent-encode
+pub fn utf8_percent_encode(input: &str, set: EncodeSet) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        utf8_percent_encode_char(c, set, &mut out);
+    }
+    out
+}
+
+/// https://url.spec.whatwg.org/#percent-decode
+pub fn percent_decode(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let b = input[i];
+        if b == b'%' && i + 2 < input.len() {
+            if let (Some(h), Some(l)) = (hex_value(input[i + 1]), hex_value(input[i + 2])) {
+                out.push(h << 4 | l);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(b);
+        i += 1;
+    }
+    out
+}
+
+/// https://url.spec.whatwg.org/#string-percent-decode
+pub fn percent_decode_str(input: &str) -> String {
+    String::from_utf8_lossy(&percent_decode(input.as_bytes())).into_owned()
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_encode_path() {
+        assert_eq!(utf8_percent_encode("a b/c", EncodeSet::Path), "a%20b/c".to_string());
+        assert_eq!(utf8_percent_encode("日本", EncodeSet::Path), "%E6%97%A5%E6%9C%AC".to_string());
+    }
+
+    #[test]
+    fn test_encode_userinfo() {
+        assert_eq!(utf8_percent_encode("a:b@c", EncodeSet::Userinfo), "a%3Ab%40c".to_string());
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(percent_decode_str("%E6%97%A5%e6%9c%ac"), "日本".to_string());
+        // 不正なシーケンスはそのまま残す
+        assert_eq!(percent_decode_str("100%"), "100%".to_string());
+        assert_eq!(percent_decode_str("%zz%4"), "%zz%4".to_string());
+    }
+}
@@ -0,0 +1,140 @@
+//! https://url.spec.whatwg.org/#host-public-suffix
+//! https://publicsuffix.org/list/
+
+use crate::url::public_suffix_table::EXCEPTION_RULES;
+use crate::url::public_suffix_table::RULES;
+use crate::url::public_suffix_table::WILDCARD_RULES;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// "foo.example.co.jp"なら"co.jp"を返す。domainはASCIIに変換済みのドメインであること
+/// https://url.spec.whatwg.org/#host-public-suffix
+pub fn public_suffix(domain: &str) -> String {
+    let (domain, trailing_dot) = split_trailing_dot(domain);
+    let mut suffix = String::from(&domain[public_suffix_start(domain)..]);
+    suffix.push_str(trailing_dot);
+    suffix
+}
+
+// "foo.example.co.jp"なら"example.co.jp"を返す。ドメインが公開サフィックスそのものの場合はNone
+/// https://url.spec.whatwg.org/#host-registrable-domain
+pub fn registrable_domain(domain: &str) -> Option<String> {
+    let (domain, trailing_dot) = split_trailing_dot(domain);
+    let suffix_start = public_suffix_start(domain);
+    if suffix_start == 0 {
+        return None;
+    }
+
+    // 公開サフィックスの1つ左のラベルを加える
+    let start = match domain[..suffix_start - 1].rfind('.') {
+        Some(i) => i + 1,
+        None => 0,
+    };
+    if start == suffix_start - 1 {
+        // "a..co.jp"のようにラベルが空の場合
+        return None;
+    }
+
+    let mut registrable = String::from(&domain[start..]);
+    registrable.push_str(trailing_dot);
+    Some(registrable)
+}
+
+fn split_trailing_dot(domain: &str) -> (&str, &str) {
+    match domain.strip_suffix('.') {
+        Some(stripped) => (stripped, "."),
+        None => (domain, ""),
+    }
+}
+
+// 公開サフィックスが始まるバイト位置を返す
+// 一致するルールのうち最も長いものを使い、例外のルールはそれより優先する
+// どのルールにも一致しない場合は"*"に一致したものとして、最後のラベルを公開サフィックスにする
+fn public_suffix_start(domain: &str) -> usize {
+    // 各ラベルの開始位置
+    let mut starts: Vec<usize> = Vec::from([0]);
+    starts.extend(domain.match_indices('.').map(|(i, _)| i + 1));
+
+    for (n, &start) in starts.iter().enumerate() {
+        let suffix = &domain[start..];
+        if contains(EXCEPTION_RULES, suffix) {
+            // "!city.kawasaki.jp"なら"kawasaki.jp"が公開サフィックス
+            return starts.get(n + 1).copied().unwrap_or(domain.len());
+        }
+        if contains(RULES, suffix) {
+            return start;
+        }
+        // "*.kawasaki.jp"は"kawasaki.jp"の1つ左のラベルまでを公開サフィックスにする
+        if let Some(&next) = starts.get(n + 1) {
+            if contains(WILDCARD_RULES, &domain[next..]) {
+                return start;
+            }
+        }
+    }
+
+    *starts.last().unwrap_or(&0)
+}
+
+fn contains(table: &[&str], suffix: &str) -> bool {
+    table.binary_search(&suffix).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_public_suffix() {
+        assert_eq!(public_suffix("example.com"), "com");
+        assert_eq!(public_suffix("foo.example.co.jp"), "co.jp");
+        assert_eq!(public_suffix("example.co.jp."), "co.jp.");
+        // どのルールにも一致しない場合は最後のラベル
+        assert_eq!(public_suffix("host.test"), "test");
+        assert_eq!(public_suffix("localhost"), "localhost");
+    }
+
+    #[test]
+    fn test_registrable_domain() {
+        assert_eq!(
+            registrable_domain("foo.example.co.jp"),
+            Some("example.co.jp".to_string())
+        );
+        assert_eq!(
+            registrable_domain("www.example.com."),
+            Some("example.com.".to_string())
+        );
+        assert_eq!(registrable_domain("co.jp"), None);
+        assert_eq!(registrable_domain("com"), None);
+        assert_eq!(registrable_domain("localhost"), None);
+    }
+
+    #[test]
+    fn test_wildcard_and_exception() {
+        // *.kawasaki.jp と !city.kawasaki.jp
+        assert_eq!(public_suffix("foo.bar.kawasaki.jp"), "bar.kawasaki.jp");
+        assert_eq!(
+            registrable_domain("foo.bar.kawasaki.jp"),
+            Some("foo.bar.kawasaki.jp".to_string())
+        );
+        assert_eq!(registrable_domain("bar.kawasaki.jp"), None);
+        assert_eq!(public_suffix("www.city.kawasaki.jp"), "kawasaki.jp");
+        assert_eq!(
+            registrable_domain("www.city.kawasaki.jp"),
+            Some("city.kawasaki.jp".to_string())
+        );
+    }
+
+    #[test]
+    fn test_idn() {
+        // 公開サフィックスのルールも"xn--"の形で照合する
+        assert_eq!(
+            registrable_domain("www.xn--r8jz45g.xn--p1ai"),
+            Some("xn--r8jz45g.xn--p1ai".to_string())
+        );
+        assert_eq!(
+            public_suffix("example.xn--0trq7p7nn.jp"),
+            "xn--0trq7p7nn.jp"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! https://url.spec.whatwg.org/#interface-urlsearchparams
+
+use crate::url::percent_encoding::percent_decode;
+use crate::url::percent_encoding::utf8_percent_encode_char;
+use crate::url::percent_encoding::EncodeSet;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// クエリ文字列（application/x-www-form-urlencoded）を名前と値の組のリストとして扱う
+/// https://url.spec.whatwg.org/#urlsearchparams
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UrlSearchParams {
+    list: Vec<(String, String)>,
+}
+
+impl UrlSearchParams {
+    pub fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+
+    // "a=1&b=2"のような文字列を解析する。先頭の"?"は無視する
+    /// https://url.spec.whatwg.org/#concept-urlencoded-parser
+    pub fn parse(input: &str) -> Self {
+        let input = input.strip_prefix('?').unwrap_or(input);
+
+        let mut list = Vec::new();
+        for sequence in input.split('&') {
+            if sequence.is_empty() {
+                continue;
+            }
+
+            let (name, value) = match sequence.split_once('=') {
+                Some((n, v)) => (n, v),
+                None => (sequence, ""),
+            };
+            list.push((decode(name), decode(value)));
+        }
+
+        Self { list }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        self.list.clone()
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.list.push((name.to_string(), value.to_string()));
+    }
+
+    pub fn delete(&mut self, name: &str) {
+        self.list.retain(|(n, _)| n != name);
+    }
+
+    // 最初に見つかった値を返す
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.list
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+    }
+
+    pub fn get_all(&self, name: &str) -> Vec<String> {
+        self.list
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+            .collect()
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.list.iter().any(|(n, _)| n == name)
+    }
+
+    // 最初に見つかった組の値を置き換え、同じ名前の残りの組は削除する
+    /// https://url.spec.whatwg.org/#dom-urlsearchparams-set
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.list.iter().position(|(n, _)| n == name) {
+            Some(index) => {
+                self.list[index].1 = value.to_string();
+                let mut i = 0;
+                self.list.retain(|(n, _)| {
+                    let keep = n != name || i == index;
+                    i += 1;
+                    keep
+                });
+            }
+            None => self.append(name, value),
+        }
+    }
+
+    // 名前のUTF-16コード単位の順で安定ソートする
+    /// https://url.spec.whatwg.org/#dom-urlsearchparams-sort
+    pub fn sort(&mut self) {
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+    }
+}
+
+impl fmt::Display for UrlSearchParams {
+    /// https://url.spec.whatwg.org/#concept-urlencoded-serializer
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.list.iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            f.write_str(&encode(name))?;
+            f.write_str("=")?;
+            f.write_str(&encode(value))?;
+        }
+        Ok(())
+    }
+}
+
+// "+"を空白に戻してからパーセントデコードする
+fn decode(input: &str) -> String {
+    let bytes: Vec<u8> = input
+        .bytes()
+        .map(|b| if b == b'+' { b' ' } else { b })
+        .collect();
+    String::from_utf8_lossy(&percent_decode(&bytes)).into_owned()
+}
+
+// 空白は"+"にし、それ以外はapplication/x-www-form-urlencodedのセットでエンコードする
+/// https://url.spec.whatwg.org/#concept-urlencoded-byte-serializer
+pub fn encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        if c == ' ' {
+            out.push('+');
+        } else {
+            utf8_percent_encode_char(c, EncodeSet::FormUrlencoded, &mut out);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse() {
+        let params = UrlSearchParams::parse("?a=123&b=456&&c&d=x=y&e=%E6%97%A5+%2B");
+        assert_eq!(
+            params.pairs(),
+            vec![
+                ("a".to_string(), "123".to_string()),
+                ("b".to_string(), "456".to_string()),
+                ("c".to_string(), "".to_string()),
+                ("d".to_string(), "x=y".to_string()),
+                ("e".to_string(), "日 +".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let params = UrlSearchParams::parse("a=1&b=2&a=3");
+        assert_eq!(params.get("a"), Some("1".to_string()));
+        assert_eq!(params.get_all("a"), vec!["1".to_string(), "3".to_string()]);
+        assert_eq!(params.get("c"), None);
+        assert!(params.has("b"));
+        assert!(!params.has("c"));
+    }
+
+    #[test]
+    fn test_append_set_delete() {
+        let mut params = UrlSearchParams::parse("a=1&b=2&a=3");
+        params.set("a", "4");
+        assert_eq!(params.to_string(), "a=4&b=2");
+
+        params.set("c", "5");
+        params.append("b", "6");
+        assert_eq!(params.to_string(), "a=4&b=2&c=5&b=6");
+
+        params.delete("b");
+        assert_eq!(params.to_string(), "a=4&c=5");
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut params = UrlSearchParams::parse("z=1&a=2&z=0&b=3");
+        params.sort();
+        assert_eq!(params.to_string(), "a=2&b=3&z=1&z=0");
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut params = UrlSearchParams::new();
+        params.append("q", "saba browser");
+        params.append("lang", "日本語");
+        params.append("sym", "a&b=c~*");
+        assert_eq!(
+            params.to_string(),
+            "q=saba+browser&lang=%E6%97%A5%E6%9C%AC%E8%AA%9E&sym=a%26b%3Dc%7E*"
+        );
+    }
+}
@@ -1,23 +1,77 @@
-#![no_std]
-#![no_main]
+// stdフィーチャーが有効な場合はLinuxなどのOSで動くプログラムとしてビルドする
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_main)]
+
+#[cfg(not(any(feature = "wasabi", feature = "std")))]
+compile_error!("either the \"wasabi\" or the \"std\" feature must be enabled");
 
 extern crate alloc;
 
 use crate::alloc::string::ToString;
+#[cfg(feature = "std")]
+use net_std::http::HttpClient;
+#[cfg(feature = "std")]
+use net_std::http::StdTransport as PlatformTransport;
+#[cfg(not(feature = "std"))]
 use net_wasabi::http::HttpClient;
+#[cfg(not(feature = "std"))]
+use net_wasabi::http::NoliTransport as PlatformTransport;
+#[cfg(not(feature = "std"))]
 use noli::prelude::*;
+use saba_core::data_url::DataUrl;
+use saba_core::http::HttpRequest;
+use saba_core::http::Method;
+use saba_core::url::Scheme;
+use saba_core::url::Url;
 
-fn main() -> u64 {
-    let client = HttpClient::new();
-    match client.get("host.test".to_string(), 8000, "/test.html".to_string()) {
-        Ok(res) => {
-            print!("response:\n{:#?}", res);
-        }
+fn run() -> u64 {
+    let url = match Url::new("http://host.test:8000/test.html".to_string()).parse() {
+        Ok(url) => url,
         Err(e) => {
             print!("error: \n{:#?}", e);
+            return 1;
+        }
+    };
+
+    // スキームごとに取得方法を切り替える
+    match url.scheme_kind() {
+        Some(Scheme::Http) => {
+            // リダイレクトされた場合は、たどり着いたURLを以降の基準にする
+            let client = HttpClient::new(PlatformTransport);
+            let request = HttpRequest::for_url(Method::Get, &url);
+            match client.fetch(url, request) {
+                Ok((url, res)) => {
+                    print!("url: {}\nresponse:\n{:#?}", url, res);
+                }
+                Err(e) => {
+                    print!("error: \n{:#?}", e);
+                }
+            }
+        }
+        Some(Scheme::Data) => match DataUrl::parse(&url) {
+            Ok(data) => {
+                print!("response:\n{:#?}", data.to_response());
+            }
+            Err(e) => {
+                print!("error: \n{:#?}", e);
+            }
+        },
+        _ => {
+            print!("error: \nunsupported scheme {}", url.scheme());
         }
     }
     0
 }
 
+#[cfg(not(feature = "std"))]
+fn main() -> u64 {
+    run()
+}
+
+#[cfg(not(feature = "std"))]
 entry_point!(main);
+
+#[cfg(feature = "std")]
+fn main() {
+    std::process::exit(run() as i32);
+}